@@ -0,0 +1,276 @@
+//! Command-line arguments.
+
+use std::path::PathBuf;
+
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use solana_sdk::pubkey::Pubkey;
+
+/// Command-line tool for the Paladin stake program.
+#[derive(Debug, Parser)]
+#[command(name = "paladin-stake", version)]
+pub struct Cli {
+    /// URL of the cluster JSON RPC.
+    #[arg(
+        long,
+        short = 'u',
+        global = true,
+        default_value = "http://127.0.0.1:8899"
+    )]
+    pub url: String,
+
+    /// Keypair file of the fee payer, also used as the default signer of
+    /// every authority [default: ~/.config/solana/id.json].
+    #[arg(long, short = 'k', global = true)]
+    pub keypair: Option<PathBuf>,
+
+    /// Stake config account.
+    #[arg(long, global = true)]
+    pub config: Option<Pubkey>,
+
+    /// Output format.
+    #[arg(long, global = true, value_enum, default_value_t = OutputFormat::Display)]
+    pub output: OutputFormat,
+
+    #[command(subcommand)]
+    pub command: Command,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, ValueEnum)]
+pub enum OutputFormat {
+    Display,
+    Json,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum Command {
+    /// Initializes a config account at the PDA derived from the mint, the
+    /// config authority and the namespace.
+    InitConfig(InitConfigArgs),
+
+    /// Initializes the validator stake account of a vote account.
+    ///
+    /// The vote account withdraw authority must have signed the DUNA document.
+    InitValidatorStake {
+        /// Validator vote account.
+        #[arg(long)]
+        vote: Pubkey,
+    },
+
+    /// Initializes the SOL staker stake account of a native stake account.
+    ///
+    /// The stake account withdrawer must have signed the DUNA document.
+    InitSolStakerStake {
+        /// Native stake account.
+        #[arg(long)]
+        sol_stake: Pubkey,
+    },
+
+    /// Stakes tokens from the associated token account of the authority.
+    Stake {
+        /// Validator or SOL staker stake account.
+        stake: Pubkey,
+        /// Amount of tokens.
+        amount: u64,
+        /// Keypair file of the token account owner.
+        #[arg(long)]
+        authority: Option<PathBuf>,
+    },
+
+    /// Unstakes tokens to the associated token account of the stake authority.
+    Unstake {
+        /// Validator or SOL staker stake account.
+        stake: Pubkey,
+        /// Amount of tokens.
+        amount: u64,
+        /// Keypair file of the stake authority.
+        #[arg(long)]
+        authority: Option<PathBuf>,
+    },
+
+    /// Moves staked tokens between two SOL staker stake accounts of the same
+    /// authority.
+    Move {
+        /// Source SOL staker stake account.
+        source: Pubkey,
+        /// Destination SOL staker stake account.
+        destination: Pubkey,
+        /// Amount of tokens.
+        amount: u64,
+        /// Keypair file of the stake authority.
+        #[arg(long)]
+        authority: Option<PathBuf>,
+    },
+
+    /// Harvests the rewards of a stake account to its authority.
+    Harvest {
+        /// Validator or SOL staker stake account.
+        stake: Pubkey,
+    },
+
+    /// Syncs the SOL amount of a SOL staker stake account with its native
+    /// stake.
+    Sync {
+        /// SOL staker stake account.
+        stake: Pubkey,
+        /// Recipient of the sync bounty [default: fee payer].
+        #[arg(long)]
+        keeper_recipient: Option<Pubkey>,
+    },
+
+    /// Slashes tokens from a stake account.
+    Slash {
+        /// Validator or SOL staker stake account.
+        stake: Pubkey,
+        /// Amount of tokens.
+        amount: u64,
+        /// Keypair file of the config slash authority.
+        #[arg(long)]
+        slash_authority: Option<PathBuf>,
+    },
+
+    /// Updates a field of the config account.
+    UpdateConfig {
+        /// Config field to update.
+        #[arg(value_enum)]
+        field: ConfigFieldArg,
+        /// New value of the field.
+        value: u64,
+        /// Keypair file of the config authority.
+        #[arg(long)]
+        config_authority: Option<PathBuf>,
+    },
+
+    /// Sets an authority of the config account.
+    SetAuthority {
+        /// Authority to set.
+        #[arg(value_enum)]
+        authority_type: AuthorityTypeArg,
+        /// New authority.
+        new_authority: Pubkey,
+        /// Keypair file of the config authority.
+        #[arg(long)]
+        config_authority: Option<PathBuf>,
+    },
+
+    /// Displays a program account.
+    #[command(subcommand)]
+    Show(ShowCommand),
+}
+
+#[derive(Debug, Args)]
+pub struct InitConfigArgs {
+    /// Stake token mint.
+    #[arg(long)]
+    pub mint: Pubkey,
+
+    /// Namespace of the config PDA.
+    #[arg(long, default_value_t = 0)]
+    pub namespace: u64,
+
+    /// Keypair file of the config authority [default: fee payer].
+    #[arg(long)]
+    pub config_authority: Option<PathBuf>,
+
+    /// Slash authority [default: fee payer].
+    #[arg(long)]
+    pub slash_authority: Option<Pubkey>,
+
+    /// Cooldown time between unstakes, in seconds.
+    #[arg(long)]
+    pub cooldown_time_seconds: u64,
+
+    /// Maximum amount that can be unstaked at once, in basis points.
+    #[arg(long)]
+    pub max_deactivation_basis_points: u16,
+
+    /// Bounty paid to keepers syncing SOL stakes, in lamports.
+    #[arg(long)]
+    pub sync_rewards_lamports: u64,
+
+    /// Hash of the DUNA document, as 64 hexadecimal characters.
+    #[arg(long, value_parser = parse_hash)]
+    pub duna_document_hash: [u8; 32],
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, ValueEnum)]
+pub enum ConfigFieldArg {
+    CooldownTimeSeconds,
+    MaxDeactivationBasisPoints,
+    SyncRewardsLamports,
+    RewardStreamingPeriodSeconds,
+    ProtocolFeeBasisPoints,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, ValueEnum)]
+pub enum AuthorityTypeArg {
+    Config,
+    Slash,
+    FeeRecipient,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum ShowCommand {
+    /// Displays the config account.
+    Config,
+    /// Displays a validator stake account and its pending rewards.
+    ValidatorStake {
+        /// Validator stake account.
+        address: Pubkey,
+    },
+    /// Displays a SOL staker stake account and its pending rewards.
+    SolStakerStake {
+        /// SOL staker stake account.
+        address: Pubkey,
+    },
+}
+
+fn parse_hash(value: &str) -> Result<[u8; 32], String> {
+    let invalid = || format!("expected 64 hexadecimal characters, got '{value}'");
+    if value.len() != 64 {
+        return Err(invalid());
+    }
+
+    let mut hash = [0; 32];
+    for (i, byte) in hash.iter_mut().enumerate() {
+        *byte = u8::from_str_radix(value.get(i * 2..i * 2 + 2).ok_or_else(invalid)?, 16)
+            .map_err(|_| invalid())?;
+    }
+    Ok(hash)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_update_config() {
+        let cli = Cli::try_parse_from([
+            "paladin-stake",
+            "--config",
+            "GQurxHCYQCNfYR37nHNb6ZiLWg3jpbh2fWv2RpzwGqRK",
+            "update-config",
+            "protocol-fee-basis-points",
+            "250",
+        ])
+        .unwrap();
+
+        assert_eq!(cli.output, OutputFormat::Display);
+        assert!(matches!(
+            cli.command,
+            Command::UpdateConfig {
+                field: ConfigFieldArg::ProtocolFeeBasisPoints,
+                value: 250,
+                config_authority: None,
+            }
+        ));
+    }
+
+    #[test]
+    fn parse_duna_document_hash() {
+        let hash = parse_hash(&"0a".repeat(32)).unwrap();
+        assert_eq!(hash, [10; 32]);
+
+        assert!(parse_hash("0a").is_err());
+        assert!(parse_hash(&"zz".repeat(32)).is_err());
+    }
+}
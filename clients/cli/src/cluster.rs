@@ -0,0 +1,137 @@
+//! Access to the cluster the commands are executed against.
+
+use std::future::Future;
+
+use base64::{prelude::BASE64_STANDARD, Engine};
+use paladin_stake_program_client::client::{RpcAccount, StakeRpc};
+use solana_client::{nonblocking::rpc_client::RpcClient, rpc_config::RpcSimulateTransactionConfig};
+use solana_sdk::{
+    commitment_config::CommitmentConfig,
+    instruction::Instruction,
+    pubkey::Pubkey,
+    signature::{Signature, Signer},
+    transaction::Transaction,
+};
+
+use crate::Error;
+
+/// Cluster executing the transactions of the commands.
+///
+/// Implemented over the JSON RPC by [`RpcCluster`]; tests implement it over a
+/// `BanksClient`.
+pub trait Cluster: StakeRpc<Error = Error> {
+    /// Minimum balance for an account with `data_len` bytes to be rent exempt.
+    fn minimum_balance(&mut self, data_len: usize) -> impl Future<Output = Result<u64, Error>>;
+
+    /// Signs and sends a transaction, waiting for its confirmation.
+    fn send(
+        &mut self,
+        instructions: &[Instruction],
+        payer: &dyn Signer,
+        signers: &[&dyn Signer],
+    ) -> impl Future<Output = Result<Signature, Error>>;
+
+    /// Simulates a transaction, returning the program and data of its return
+    /// data (if any).
+    fn simulate(
+        &mut self,
+        instructions: &[Instruction],
+        payer: &dyn Signer,
+    ) -> impl Future<Output = Result<Option<(Pubkey, Vec<u8>)>, Error>>;
+}
+
+/// Cluster accessed through its JSON RPC.
+pub struct RpcCluster {
+    client: RpcClient,
+}
+
+impl RpcCluster {
+    pub fn new(url: String) -> Self {
+        Self {
+            client: RpcClient::new_with_commitment(url, CommitmentConfig::confirmed()),
+        }
+    }
+}
+
+impl StakeRpc for RpcCluster {
+    type Error = Error;
+
+    async fn get_account(&mut self, address: &Pubkey) -> Result<Option<RpcAccount>, Error> {
+        let account = self
+            .client
+            .get_account_with_commitment(address, self.client.commitment())
+            .await?
+            .value;
+        Ok(account.map(|account| RpcAccount {
+            owner: account.owner,
+            data: account.data,
+        }))
+    }
+}
+
+impl Cluster for RpcCluster {
+    async fn minimum_balance(&mut self, data_len: usize) -> Result<u64, Error> {
+        Ok(self
+            .client
+            .get_minimum_balance_for_rent_exemption(data_len)
+            .await?)
+    }
+
+    async fn send(
+        &mut self,
+        instructions: &[Instruction],
+        payer: &dyn Signer,
+        signers: &[&dyn Signer],
+    ) -> Result<Signature, Error> {
+        let mut all_signers = vec![payer];
+        all_signers.extend_from_slice(signers);
+
+        let transaction = Transaction::new_signed_with_payer(
+            instructions,
+            Some(&payer.pubkey()),
+            &all_signers,
+            self.client.get_latest_blockhash().await?,
+        );
+        Ok(self
+            .client
+            .send_and_confirm_transaction(&transaction)
+            .await?)
+    }
+
+    async fn simulate(
+        &mut self,
+        instructions: &[Instruction],
+        payer: &dyn Signer,
+    ) -> Result<Option<(Pubkey, Vec<u8>)>, Error> {
+        let transaction = Transaction::new_signed_with_payer(
+            instructions,
+            Some(&payer.pubkey()),
+            &[payer],
+            self.client.get_latest_blockhash().await?,
+        );
+        let result = self
+            .client
+            .simulate_transaction_with_config(
+                &transaction,
+                RpcSimulateTransactionConfig {
+                    commitment: Some(self.client.commitment()),
+                    ..Default::default()
+                },
+            )
+            .await?
+            .value;
+
+        if let Some(err) = result.err {
+            return Err(format!("Simulation failed: {err}").into());
+        }
+
+        result
+            .return_data
+            .map(|return_data| -> Result<_, Error> {
+                let program_id = return_data.program_id.parse::<Pubkey>()?;
+                let data = BASE64_STANDARD.decode(return_data.data.0)?;
+                Ok((program_id, data))
+            })
+            .transpose()
+    }
+}
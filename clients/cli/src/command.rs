@@ -0,0 +1,415 @@
+//! Execution of the commands.
+
+use std::path::{Path, PathBuf};
+
+use paladin_rewards_program_client::accounts::HolderRewards;
+use paladin_stake_program_client::{
+    accounts::{Config, SolStakerStake, ValidatorStake},
+    client::{StakeAccount, StakeClient, StakeClientError, StakeRpc},
+    instructions::{
+        InitializeConfigPdaBuilder, InitializeSolStakerStakeBuilder,
+        InitializeValidatorStakeBuilder, SetAuthorityBuilder, UpdateConfigBuilder,
+    },
+    pdas::{
+        find_config_pda, find_duna_document_pda, find_sol_staker_authority_override_pda,
+        find_validator_authority_override_pda, find_vault_pda,
+    },
+    types::{AuthorityType, ConfigField},
+};
+use solana_sdk::{
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+    signature::{read_keypair_file, Keypair, Signer},
+    stake::state::StakeStateV2,
+    system_instruction,
+    vote::state::VoteState,
+};
+use spl_associated_token_account::{
+    get_associated_token_address_with_program_id,
+    instruction::create_associated_token_account_idempotent,
+};
+
+use crate::{
+    args::{AuthorityTypeArg, Cli, Command, ConfigFieldArg, InitConfigArgs, ShowCommand},
+    cluster::Cluster,
+    output::{Output, PendingRewards},
+    Error,
+};
+
+/// Executes the command of the command line against the cluster.
+pub async fn process<C: Cluster>(cluster: C, cli: Cli) -> Result<Output, Error> {
+    let payer = load_keypair(cli.keypair.as_deref())?;
+    let signer = |path: Option<PathBuf>| match path {
+        Some(path) => load_keypair(Some(&path)),
+        None => Ok(payer.insecure_clone()),
+    };
+
+    let config = match &cli.command {
+        Command::InitConfig(args) => {
+            let config_authority = signer(args.config_authority.clone())?;
+            find_config_pda(&args.mint, &config_authority.pubkey(), args.namespace).0
+        }
+        _ => cli.config.ok_or("the --config account is required")?,
+    };
+    let mut client = StakeClient::new(cluster, config);
+
+    match cli.command {
+        Command::InitConfig(args) => {
+            let config_authority = signer(args.config_authority.clone())?;
+            init_config(&mut client, &payer, &config_authority, args).await
+        }
+        Command::InitValidatorStake { vote } => {
+            init_validator_stake(&mut client, &payer, &vote).await
+        }
+        Command::InitSolStakerStake { sol_stake } => {
+            init_sol_staker_stake(&mut client, &payer, &sol_stake).await
+        }
+        Command::Stake {
+            stake,
+            amount,
+            authority,
+        } => {
+            let authority = signer(authority)?;
+            let instruction = client
+                .stake(&stake, &authority.pubkey(), amount)
+                .await
+                .map_err(client_error)?;
+            send(&mut client, &[instruction], &payer, &[&authority]).await
+        }
+        Command::Unstake {
+            stake,
+            amount,
+            authority,
+        } => {
+            let authority = signer(authority)?;
+            let instruction = client.unstake(&stake, amount).await.map_err(client_error)?;
+            send(&mut client, &[instruction], &payer, &[&authority]).await
+        }
+        Command::Move {
+            source,
+            destination,
+            amount,
+            authority,
+        } => {
+            let authority = signer(authority)?;
+            let instruction = client
+                .move_tokens(&source, &destination, amount)
+                .await
+                .map_err(client_error)?;
+            send(&mut client, &[instruction], &payer, &[&authority]).await
+        }
+        Command::Harvest { stake } => {
+            let instruction = client.harvest(&stake).await.map_err(client_error)?;
+            send(&mut client, &[instruction], &payer, &[]).await
+        }
+        Command::Sync {
+            stake,
+            keeper_recipient,
+        } => {
+            let keeper_recipient = keeper_recipient.unwrap_or(payer.pubkey());
+            let instruction = client
+                .sync(&stake, &keeper_recipient)
+                .await
+                .map_err(client_error)?;
+            send(&mut client, &[instruction], &payer, &[]).await
+        }
+        Command::Slash {
+            stake,
+            amount,
+            slash_authority,
+        } => {
+            let slash_authority = signer(slash_authority)?;
+            let instruction = client.slash(&stake, amount).await.map_err(client_error)?;
+            send(&mut client, &[instruction], &payer, &[&slash_authority]).await
+        }
+        Command::UpdateConfig {
+            field,
+            value,
+            config_authority,
+        } => {
+            let config_authority = signer(config_authority)?;
+            let instruction = UpdateConfigBuilder::new()
+                .config(config)
+                .config_authority(config_authority.pubkey())
+                .config_field(config_field(field, value)?)
+                .instruction();
+            send(&mut client, &[instruction], &payer, &[&config_authority]).await
+        }
+        Command::SetAuthority {
+            authority_type,
+            new_authority,
+            config_authority,
+        } => {
+            let config_authority = signer(config_authority)?;
+            let instruction = SetAuthorityBuilder::new()
+                .account(config)
+                .authority(config_authority.pubkey())
+                .new_authority(new_authority)
+                .authority_type(match authority_type {
+                    AuthorityTypeArg::Config => AuthorityType::Config,
+                    AuthorityTypeArg::Slash => AuthorityType::Slash,
+                    AuthorityTypeArg::FeeRecipient => AuthorityType::FeeRecipient,
+                })
+                .instruction();
+            send(&mut client, &[instruction], &payer, &[&config_authority]).await
+        }
+        Command::Show(ShowCommand::Config) => Ok(Output::Config {
+            address: config.to_string(),
+            config: client.get_config().await.map_err(client_error)?,
+        }),
+        Command::Show(ShowCommand::ValidatorStake { address }) => {
+            let StakeAccount::Validator(stake) =
+                client.get_stake(&address).await.map_err(client_error)?
+            else {
+                return Err(format!("{address} is not a validator stake account").into());
+            };
+            Ok(Output::ValidatorStake {
+                address: address.to_string(),
+                stake,
+                pending_rewards: pending_rewards(&mut client, &payer, &address).await?,
+            })
+        }
+        Command::Show(ShowCommand::SolStakerStake { address }) => {
+            let StakeAccount::SolStaker(stake) =
+                client.get_stake(&address).await.map_err(client_error)?
+            else {
+                return Err(format!("{address} is not a SOL staker stake account").into());
+            };
+            Ok(Output::SolStakerStake {
+                address: address.to_string(),
+                stake,
+                pending_rewards: pending_rewards(&mut client, &payer, &address).await?,
+            })
+        }
+    }
+}
+
+async fn init_config<C: Cluster>(
+    client: &mut StakeClient<C>,
+    payer: &Keypair,
+    config_authority: &Keypair,
+    args: InitConfigArgs,
+) -> Result<Output, Error> {
+    let config = *client.config();
+    let token_program = client
+        .rpc()
+        .get_account(&args.mint)
+        .await?
+        .ok_or_else(|| format!("Mint not found: {}", args.mint))?
+        .owner;
+    let vault_pda = find_vault_pda(&config).0;
+    let vault =
+        get_associated_token_address_with_program_id(&vault_pda, &args.mint, &token_program);
+    let vault_holder_rewards = HolderRewards::find_pda(&vault_pda).0;
+
+    // The config PDA, vault authority and vault holder rewards accounts are
+    // pre-funded and allocated by the programs.
+    let mut instructions = Vec::new();
+    for (address, data_len) in [
+        (config, Config::LEN),
+        (vault_pda, 0),
+        (vault_holder_rewards, HolderRewards::LEN),
+    ] {
+        let lamports = client.rpc().minimum_balance(data_len).await?;
+        instructions.push(system_instruction::transfer(
+            &payer.pubkey(),
+            &address,
+            lamports,
+        ));
+    }
+    instructions.push(create_associated_token_account_idempotent(
+        &payer.pubkey(),
+        &vault_pda,
+        &args.mint,
+        &token_program,
+    ));
+    instructions.push(
+        InitializeConfigPdaBuilder::new()
+            .config(config)
+            .mint(args.mint)
+            .vault_pda(vault_pda)
+            .vault(vault)
+            .vault_holder_rewards(vault_holder_rewards)
+            .rewards_program(paladin_rewards_program_client::ID)
+            .namespace(args.namespace)
+            .config_authority(config_authority.pubkey())
+            .slash_authority(args.slash_authority.unwrap_or(payer.pubkey()))
+            .cooldown_time_seconds(args.cooldown_time_seconds)
+            .max_deactivation_basis_points(args.max_deactivation_basis_points)
+            .sync_rewards_lamports(args.sync_rewards_lamports)
+            .duna_document_hash(args.duna_document_hash)
+            .instruction(),
+    );
+
+    initialize(client, &instructions, payer, &[config_authority], config).await
+}
+
+async fn init_validator_stake<C: Cluster>(
+    client: &mut StakeClient<C>,
+    payer: &Keypair,
+    vote: &Pubkey,
+) -> Result<Output, Error> {
+    let config = *client.config();
+    let duna_document_hash = client
+        .get_config()
+        .await
+        .map_err(client_error)?
+        .duna_document_hash;
+    let vote_account = client
+        .rpc()
+        .get_account(vote)
+        .await?
+        .ok_or_else(|| format!("Vote account not found: {vote}"))?;
+    let withdraw_authority = VoteState::deserialize(&vote_account.data)?.authorized_withdrawer;
+
+    let stake = client.validator_stake_address(vote);
+    let lamports = client.rpc().minimum_balance(ValidatorStake::LEN).await?;
+    let instructions = [
+        system_instruction::transfer(&payer.pubkey(), &stake, lamports),
+        InitializeValidatorStakeBuilder::new()
+            .config(config)
+            .duna_document_pda(find_duna_document_pda(&withdraw_authority, &duna_document_hash).0)
+            .validator_stake(stake)
+            .validator_vote(*vote)
+            .add_remaining_account(AccountMeta::new_readonly(
+                find_validator_authority_override_pda(vote, &config).0,
+                false,
+            ))
+            .instruction(),
+    ];
+
+    initialize(client, &instructions, payer, &[], stake).await
+}
+
+async fn init_sol_staker_stake<C: Cluster>(
+    client: &mut StakeClient<C>,
+    payer: &Keypair,
+    sol_stake: &Pubkey,
+) -> Result<Output, Error> {
+    let config = *client.config();
+    let duna_document_hash = client
+        .get_config()
+        .await
+        .map_err(client_error)?
+        .duna_document_hash;
+    let sol_stake_account = client
+        .rpc()
+        .get_account(sol_stake)
+        .await?
+        .ok_or_else(|| format!("Stake account not found: {sol_stake}"))?;
+    let (withdrawer, vote) = match bincode::deserialize::<StakeStateV2>(&sol_stake_account.data)? {
+        StakeStateV2::Stake(meta, stake, _) => {
+            (meta.authorized.withdrawer, stake.delegation.voter_pubkey)
+        }
+        _ => return Err(format!("Stake account is not delegated: {sol_stake}").into()),
+    };
+
+    let stake = client.sol_staker_stake_address(sol_stake);
+    let lamports = client.rpc().minimum_balance(SolStakerStake::LEN).await?;
+    let instructions = [
+        system_instruction::transfer(&payer.pubkey(), &stake, lamports),
+        InitializeSolStakerStakeBuilder::new()
+            .config(config)
+            .duna_document_pda(find_duna_document_pda(&withdrawer, &duna_document_hash).0)
+            .sol_staker_stake(stake)
+            .sol_staker_authority_override(
+                find_sol_staker_authority_override_pda(&withdrawer, &config).0,
+            )
+            .validator_stake(client.validator_stake_address(&vote))
+            .sol_staker_native_stake(*sol_stake)
+            .sol_stake_view_program(paladin_sol_stake_view_program_client::ID)
+            .instruction(),
+    ];
+
+    initialize(client, &instructions, payer, &[], stake).await
+}
+
+/// Simulates `GetPendingRewards` on a stake account.
+async fn pending_rewards<C: Cluster>(
+    client: &mut StakeClient<C>,
+    payer: &Keypair,
+    stake: &Pubkey,
+) -> Result<PendingRewards, Error> {
+    let instruction = client.pending_rewards(stake);
+    match client.rpc().simulate(&[instruction], payer).await? {
+        Some((program_id, data)) if program_id == paladin_stake_program_client::ID => {
+            PendingRewards::from_return_data(&data)
+                .ok_or_else(|| "Invalid pending rewards return data".into())
+        }
+        _ => Ok(PendingRewards::default()),
+    }
+}
+
+async fn send<C: Cluster>(
+    client: &mut StakeClient<C>,
+    instructions: &[Instruction],
+    payer: &Keypair,
+    signers: &[&dyn Signer],
+) -> Result<Output, Error> {
+    let signature = client.rpc().send(instructions, payer, signers).await?;
+    Ok(Output::Transaction {
+        signature: signature.to_string(),
+    })
+}
+
+async fn initialize<C: Cluster>(
+    client: &mut StakeClient<C>,
+    instructions: &[Instruction],
+    payer: &Keypair,
+    signers: &[&dyn Signer],
+    address: Pubkey,
+) -> Result<Output, Error> {
+    let signature = client.rpc().send(instructions, payer, signers).await?;
+    Ok(Output::Initialized {
+        address: address.to_string(),
+        signature: signature.to_string(),
+    })
+}
+
+fn config_field(field: ConfigFieldArg, value: u64) -> Result<ConfigField, Error> {
+    let basis_points = || u16::try_from(value).map_err(|_| format!("{value} is not a u16"));
+    Ok(match field {
+        ConfigFieldArg::CooldownTimeSeconds => ConfigField::CooldownTimeSeconds(value),
+        ConfigFieldArg::MaxDeactivationBasisPoints => {
+            ConfigField::MaxDeactivationBasisPoints(basis_points()?)
+        }
+        ConfigFieldArg::SyncRewardsLamports => ConfigField::SyncRewardsLamports(value),
+        ConfigFieldArg::RewardStreamingPeriodSeconds => {
+            ConfigField::RewardStreamingPeriodSeconds(value)
+        }
+        ConfigFieldArg::ProtocolFeeBasisPoints => {
+            ConfigField::ProtocolFeeBasisPoints(basis_points()?)
+        }
+    })
+}
+
+/// Loads a keypair file, defaulting to the Solana CLI keypair.
+fn load_keypair(path: Option<&Path>) -> Result<Keypair, Error> {
+    let path = match path {
+        Some(path) => path.to_path_buf(),
+        None => PathBuf::from(std::env::var("HOME")?).join(".config/solana/id.json"),
+    };
+    read_keypair_file(&path).map_err(|err| format!("{}: {err}", path.display()).into())
+}
+
+/// Unwraps the RPC errors of the stake client.
+fn client_error(error: StakeClientError<Error>) -> Error {
+    match error {
+        StakeClientError::Rpc(error) => error,
+        error => error.to_string().into(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn basis_points_fields_must_fit_u16() {
+        assert_eq!(
+            config_field(ConfigFieldArg::ProtocolFeeBasisPoints, 250).unwrap(),
+            ConfigField::ProtocolFeeBasisPoints(250)
+        );
+        assert!(config_field(ConfigFieldArg::MaxDeactivationBasisPoints, 70_000).is_err());
+    }
+}
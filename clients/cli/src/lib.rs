@@ -0,0 +1,13 @@
+//! Command-line tool for operators of the Paladin stake program.
+//!
+//! The commands are parsed by [`args::Cli`] and executed by
+//! [`command::process`] against a [`cluster::Cluster`], which is the JSON RPC
+//! of a cluster for the `paladin-stake` binary.
+
+pub mod args;
+pub mod cluster;
+pub mod command;
+pub mod output;
+
+/// Error returned by the commands.
+pub type Error = Box<dyn std::error::Error>;
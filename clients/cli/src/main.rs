@@ -0,0 +1,17 @@
+use clap::Parser;
+use paladin_stake_cli::{args::Cli, cluster::RpcCluster, command::process};
+
+#[tokio::main]
+async fn main() {
+    let cli = Cli::parse();
+    let output_format = cli.output;
+    let cluster = RpcCluster::new(cli.url.clone());
+
+    match process(cluster, cli).await {
+        Ok(output) => println!("{}", output.format(output_format)),
+        Err(err) => {
+            eprintln!("Error: {err}");
+            std::process::exit(1);
+        }
+    }
+}
@@ -0,0 +1,159 @@
+//! Results of the commands.
+
+use std::fmt;
+
+use paladin_stake_program_client::{
+    accounts::{Config, SolStakerStake, ValidatorStake},
+    return_data::HarvestReturnData,
+};
+use serde::Serialize;
+
+use crate::args::OutputFormat;
+
+/// Result of a command.
+///
+/// Addresses and signatures are kept as base-58 strings so the JSON output
+/// matches the one of the Solana CLI.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase", tag = "type")]
+pub enum Output {
+    /// Signature of the transaction sent by the command.
+    Transaction {
+        signature: String,
+    },
+    /// Signature of the transaction initializing an account.
+    Initialized {
+        address: String,
+        signature: String,
+    },
+    Config {
+        address: String,
+        config: Config,
+    },
+    ValidatorStake {
+        address: String,
+        stake: ValidatorStake,
+        pending_rewards: PendingRewards,
+    },
+    SolStakerStake {
+        address: String,
+        stake: SolStakerStake,
+        pending_rewards: PendingRewards,
+    },
+}
+
+/// Rewards a harvest of the stake account would pay out, in lamports.
+#[derive(Debug, Default, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PendingRewards {
+    pub staking_reward: u64,
+    pub holder_reward: u64,
+}
+
+impl PendingRewards {
+    /// Reads the rewards from the `HarvestReturnData` returned by
+    /// `GetPendingRewards`.
+    pub fn from_return_data(data: &[u8]) -> Option<Self> {
+        HarvestReturnData::from_return_data(&paladin_stake_program_client::ID, data).map(
+            |harvest| Self {
+                staking_reward: harvest.staking_reward,
+                holder_reward: harvest.holder_reward,
+            },
+        )
+    }
+}
+
+impl Output {
+    /// Formats the output for the terminal.
+    pub fn format(&self, format: OutputFormat) -> String {
+        match format {
+            OutputFormat::Display => self.to_string(),
+            OutputFormat::Json => serde_json::to_string_pretty(self).unwrap_or_default(),
+        }
+    }
+}
+
+impl fmt::Display for Output {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Transaction { signature } => write!(f, "Signature: {signature}"),
+            Self::Initialized { address, signature } => {
+                writeln!(f, "Address: {address}")?;
+                write!(f, "Signature: {signature}")
+            }
+            Self::Config { address, config } => {
+                writeln!(f, "Address: {address}")?;
+                write!(f, "{config:#?}")
+            }
+            Self::ValidatorStake {
+                address,
+                stake,
+                pending_rewards,
+            } => {
+                writeln!(f, "Address: {address}")?;
+                writeln!(f, "{stake:#?}")?;
+                write!(f, "{pending_rewards}")
+            }
+            Self::SolStakerStake {
+                address,
+                stake,
+                pending_rewards,
+            } => {
+                writeln!(f, "Address: {address}")?;
+                writeln!(f, "{stake:#?}")?;
+                write!(f, "{pending_rewards}")
+            }
+        }
+    }
+}
+
+impl fmt::Display for PendingRewards {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "Pending staking rewards: {} lamports",
+            self.staking_reward
+        )?;
+        write!(f, "Pending holder rewards: {} lamports", self.holder_reward)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pending_rewards_from_return_data() {
+        // The discriminator of `HarvestReturnData` followed by
+        // staking_reward, holder_reward, keeper_reward and the two u128
+        // checkpoints.
+        let mut data = HarvestReturnData::DISCRIMINATOR.to_vec();
+        data.extend_from_slice(&5u64.to_le_bytes());
+        data.extend_from_slice(&7u64.to_le_bytes());
+        data.extend_from_slice(&0u64.to_le_bytes());
+        data.extend_from_slice(&[0; 32]);
+
+        assert_eq!(
+            PendingRewards::from_return_data(&data),
+            Some(PendingRewards {
+                staking_reward: 5,
+                holder_reward: 7,
+            })
+        );
+        assert_eq!(PendingRewards::from_return_data(&data[..20]), None);
+        assert_eq!(PendingRewards::from_return_data(&data[8..]), None);
+    }
+
+    #[test]
+    fn transaction_json() {
+        let output = Output::Transaction {
+            signature: "sig".to_string(),
+        };
+
+        assert_eq!(
+            output.format(OutputFormat::Json),
+            "{\n  \"type\": \"transaction\",\n  \"signature\": \"sig\"\n}"
+        );
+        assert_eq!(output.format(OutputFormat::Display), "Signature: sig");
+    }
+}
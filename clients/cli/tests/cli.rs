@@ -0,0 +1,910 @@
+#![cfg(feature = "test-sbf")]
+
+#[path = "../../rust/tests/setup/mod.rs"]
+mod setup;
+
+use borsh::BorshSerialize;
+use clap::Parser;
+use paladin_stake_cli::{
+    args::{Cli, OutputFormat},
+    cluster::Cluster,
+    command::process,
+    output::{Output, PendingRewards},
+    Error,
+};
+use paladin_stake_program_client::{
+    accounts::{Config, SolStakerStake, ValidatorStake},
+    client::{RpcAccount, StakeRpc},
+    pdas::{find_sol_staker_stake_pda, find_validator_stake_pda},
+    NullableAddress,
+};
+use setup::{
+    config::{create_ata, ConfigManager},
+    sign_duna_document, sign_duna_document_with_vote,
+    sol_staker_stake::SolStakerStakeManager,
+    stake::{create_stake_account, delegate_stake_account, warp_to_next_epoch},
+    token::mint_to,
+    validator_stake::ValidatorStakeManager,
+    vote::create_vote_account,
+};
+use solana_program_test::{BanksClient, ProgramTest, ProgramTestContext};
+use solana_sdk::{
+    account::Account,
+    instruction::Instruction,
+    program_pack::Pack,
+    pubkey::Pubkey,
+    signature::{write_keypair_file, Keypair, Signature, Signer},
+    stake::state::{Authorized, Lockup},
+    system_instruction,
+    transaction::Transaction,
+};
+use spl_associated_token_account::get_associated_token_address;
+use spl_discriminator::SplDiscriminate;
+
+/// Cluster of a `ProgramTestContext`.
+struct BanksCluster(BanksClient);
+
+impl StakeRpc for BanksCluster {
+    type Error = Error;
+
+    async fn get_account(&mut self, address: &Pubkey) -> Result<Option<RpcAccount>, Error> {
+        Ok(self
+            .0
+            .get_account(*address)
+            .await?
+            .map(|account| RpcAccount {
+                owner: account.owner,
+                data: account.data,
+            }))
+    }
+}
+
+impl Cluster for BanksCluster {
+    async fn minimum_balance(&mut self, data_len: usize) -> Result<u64, Error> {
+        Ok(self.0.get_rent().await?.minimum_balance(data_len))
+    }
+
+    async fn send(
+        &mut self,
+        instructions: &[Instruction],
+        payer: &dyn Signer,
+        signers: &[&dyn Signer],
+    ) -> Result<Signature, Error> {
+        let mut all_signers = vec![payer];
+        all_signers.extend_from_slice(signers);
+
+        let transaction = Transaction::new_signed_with_payer(
+            instructions,
+            Some(&payer.pubkey()),
+            &all_signers,
+            self.0.get_latest_blockhash().await?,
+        );
+        let signature = transaction.signatures[0];
+        self.0.process_transaction(transaction).await?;
+        Ok(signature)
+    }
+
+    async fn simulate(
+        &mut self,
+        instructions: &[Instruction],
+        payer: &dyn Signer,
+    ) -> Result<Option<(Pubkey, Vec<u8>)>, Error> {
+        let transaction = Transaction::new_signed_with_payer(
+            instructions,
+            Some(&payer.pubkey()),
+            &[payer],
+            self.0.get_latest_blockhash().await?,
+        );
+        let simulation = self.0.simulate_transaction(transaction).await?;
+        if let Some(Err(err)) = simulation.result {
+            return Err(format!("Simulation failed: {err}").into());
+        }
+
+        Ok(simulation
+            .simulation_details
+            .and_then(|details| details.return_data)
+            .map(|return_data| (return_data.program_id, return_data.data)))
+    }
+}
+
+/// Starts the stake program with a config account whose authority is the
+/// payer, returning the config address and the payer keypair file.
+async fn setup() -> (ProgramTestContext, Pubkey, String) {
+    let mut program_test = ProgramTest::new(
+        "paladin_stake_program",
+        paladin_stake_program_client::ID,
+        None,
+    );
+
+    let payer = Keypair::new();
+    program_test.add_account(
+        payer.pubkey(),
+        Account {
+            lamports: 10_000_000_000,
+            ..Default::default()
+        },
+    );
+
+    let config = Pubkey::new_unique();
+    let data = Config {
+        discriminator: paladin_stake_program::state::Config::SPL_DISCRIMINATOR_SLICE
+            .try_into()
+            .unwrap(),
+        authority: NullableAddress::from(payer.pubkey()),
+        slash_authority: NullableAddress::from(payer.pubkey()),
+        vault: Pubkey::new_unique(),
+        cooldown_time_seconds: 1,
+        token_amount_effective: 0,
+        sync_rewards_lamports: 1_000_000,
+        lamports_last: 0,
+        duna_document_hash: [0; 32],
+        accumulated_stake_rewards_per_token: 0,
+        max_deactivation_basis_points: 500,
+        vault_authority_bump: 0,
+        version: paladin_stake_program::state::Config::VERSION,
+        padding: [0; 4],
+        reward_streaming_period_seconds: 0,
+        reward_period_end: 0,
+        reward_last_update: 0,
+        reward_rate: 0,
+        stake_rewards_remainder: 0,
+        undistributed_rewards: 0,
+        fee_recipient: NullableAddress::from(None),
+        unclaimed_protocol_fees: 0,
+        protocol_fee_basis_points: 0,
+        fee_padding: [0; 6],
+        reward_stream_remainder: 0,
+    }
+    .try_to_vec()
+    .unwrap();
+    program_test.add_account(
+        config,
+        Account {
+            lamports: 1_000_000_000,
+            data,
+            owner: paladin_stake_program_client::ID,
+            ..Default::default()
+        },
+    );
+
+    (
+        program_test.start_with_context().await,
+        config,
+        keypair_file(&payer),
+    )
+}
+
+/// Writes a keypair to a temporary file, returning its path.
+fn keypair_file(keypair: &Keypair) -> String {
+    let path = std::env::temp_dir()
+        .join(format!("paladin-stake-cli-{}.json", keypair.pubkey()))
+        .to_string_lossy()
+        .into_owned();
+    write_keypair_file(keypair, &path).unwrap();
+    path
+}
+
+async fn run(context: &ProgramTestContext, args: &[&str]) -> Result<Output, Error> {
+    let cli = Cli::try_parse_from(std::iter::once("paladin-stake").chain(args.iter().copied()))?;
+    process(BanksCluster(context.banks_client.clone()), cli).await
+}
+
+async fn show_validator_stake(
+    context: &ProgramTestContext,
+    keypair_file: &str,
+    config: &Pubkey,
+    stake: &Pubkey,
+) -> (ValidatorStake, PendingRewards) {
+    let output = run(
+        context,
+        &[
+            "-k",
+            keypair_file,
+            "--config",
+            &config.to_string(),
+            "show",
+            "validator-stake",
+            &stake.to_string(),
+        ],
+    )
+    .await
+    .unwrap();
+    let Output::ValidatorStake {
+        address,
+        stake: stake_account,
+        pending_rewards,
+    } = output
+    else {
+        panic!("expected a validator stake output");
+    };
+    assert_eq!(address, stake.to_string());
+    (stake_account, pending_rewards)
+}
+
+async fn show_sol_staker_stake(
+    context: &ProgramTestContext,
+    keypair_file: &str,
+    config: &Pubkey,
+    stake: &Pubkey,
+) -> (SolStakerStake, PendingRewards) {
+    let output = run(
+        context,
+        &[
+            "-k",
+            keypair_file,
+            "--config",
+            &config.to_string(),
+            "show",
+            "sol-staker-stake",
+            &stake.to_string(),
+        ],
+    )
+    .await
+    .unwrap();
+    let Output::SolStakerStake {
+        address,
+        stake: stake_account,
+        pending_rewards,
+    } = output
+    else {
+        panic!("expected a SOL staker stake output");
+    };
+    assert_eq!(address, stake.to_string());
+    (stake_account, pending_rewards)
+}
+
+async fn token_balance(context: &mut ProgramTestContext, token_account: &Pubkey) -> u64 {
+    let account = get_account!(context, *token_account);
+    spl_token::state::Account::unpack(&account.data)
+        .unwrap()
+        .amount
+}
+
+/// Creates the associated token account of `owner` with `amount` tokens.
+async fn fund_token_account(
+    context: &mut ProgramTestContext,
+    config_manager: &ConfigManager,
+    owner: &Pubkey,
+    amount: u64,
+) -> Pubkey {
+    let token_account = get_associated_token_address(owner, &config_manager.mint);
+    create_ata(context, owner, &config_manager.mint)
+        .await
+        .unwrap();
+    mint_to(
+        context,
+        &config_manager.mint,
+        &config_manager.mint_authority,
+        &token_account,
+        amount,
+    )
+    .await
+    .unwrap();
+    token_account
+}
+
+/// Sets the SOL amount backing a validator stake, so its tokens can become
+/// effective.
+async fn set_validator_staked_lamports(context: &mut ProgramTestContext, stake: &Pubkey) {
+    let mut account = get_account!(context, *stake);
+    let mut stake_account = ValidatorStake::from_bytes(&account.data).unwrap();
+    stake_account.total_staked_lamports_amount = 1_000_000_000;
+    account.data = stake_account.try_to_vec().unwrap();
+    context.set_account(stake, &account.into());
+}
+
+#[tokio::test]
+async fn update_config_and_show_config() {
+    let (context, config, keypair_file) = setup().await;
+    let config_arg = config.to_string();
+
+    // When we update the cooldown time with the payer as the config authority.
+    let output = run(
+        &context,
+        &[
+            "-k",
+            &keypair_file,
+            "--config",
+            &config_arg,
+            "update-config",
+            "cooldown-time-seconds",
+            "100",
+        ],
+    )
+    .await
+    .unwrap();
+    assert!(matches!(output, Output::Transaction { .. }));
+
+    // Then the config account shows the new value.
+    let output = run(
+        &context,
+        &[
+            "-k",
+            &keypair_file,
+            "--config",
+            &config_arg,
+            "show",
+            "config",
+        ],
+    )
+    .await
+    .unwrap();
+    let Output::Config {
+        address,
+        config: config_account,
+    } = &output
+    else {
+        panic!("expected a config output");
+    };
+    assert_eq!(address, &config_arg);
+    assert_eq!(config_account.cooldown_time_seconds, 100);
+
+    // And the JSON output contains the decoded account.
+    let json: serde_json::Value = serde_json::from_str(&output.format(OutputFormat::Json)).unwrap();
+    assert_eq!(json["type"], "config");
+    assert_eq!(json["config"]["cooldown_time_seconds"], 100);
+}
+
+#[tokio::test]
+async fn set_slash_authority() {
+    let (context, config, keypair_file) = setup().await;
+    let config_arg = config.to_string();
+    let new_authority = Pubkey::new_unique();
+
+    // When we set a new slash authority.
+    run(
+        &context,
+        &[
+            "-k",
+            &keypair_file,
+            "--config",
+            &config_arg,
+            "set-authority",
+            "slash",
+            &new_authority.to_string(),
+        ],
+    )
+    .await
+    .unwrap();
+
+    // Then the config has the new slash authority.
+    let Output::Config {
+        config: config_account,
+        ..
+    } = run(
+        &context,
+        &[
+            "-k",
+            &keypair_file,
+            "--config",
+            &config_arg,
+            "show",
+            "config",
+        ],
+    )
+    .await
+    .unwrap()
+    else {
+        panic!("expected a config output");
+    };
+    assert_eq!(config_account.slash_authority.value(), Some(new_authority));
+}
+
+#[tokio::test]
+async fn fail_update_config_with_wrong_authority() {
+    let (context, config, keypair_file) = setup().await;
+
+    // Given a keypair that is not the config authority.
+    let authority_file = keypair_file(&Keypair::new());
+
+    // When we try to update the config signing with it.
+    let result = run(
+        &context,
+        &[
+            "-k",
+            &keypair_file,
+            "--config",
+            &config.to_string(),
+            "update-config",
+            "sync-rewards-lamports",
+            "5",
+            "--config-authority",
+            &authority_file,
+        ],
+    )
+    .await;
+
+    // Then the transaction fails.
+    assert!(result.is_err());
+}
+
+#[tokio::test]
+async fn fail_command_without_config() {
+    let (context, _, keypair_file) = setup().await;
+
+    let err = run(&context, &["-k", &keypair_file, "show", "config"])
+        .await
+        .unwrap_err();
+
+    assert_eq!(err.to_string(), "the --config account is required");
+}
+
+#[tokio::test]
+async fn init_validator_stake_and_sol_staker_stake() {
+    let mut context = setup::setup(&[]).await;
+    let config_manager = ConfigManager::new(&mut context).await;
+    let config_arg = config_manager.config.to_string();
+    let payer_file = keypair_file(&context.payer);
+
+    // Given a vote account whose withdraw authority signed the DUNA document.
+    let vote =
+        create_vote_account(&mut context, &Pubkey::new_unique(), &Pubkey::new_unique()).await;
+    sign_duna_document_with_vote(&mut context, vote).await;
+
+    // When we initialize its validator stake account.
+    let output = run(
+        &context,
+        &[
+            "-k",
+            &payer_file,
+            "--config",
+            &config_arg,
+            "init-validator-stake",
+            "--vote",
+            &vote.to_string(),
+        ],
+    )
+    .await
+    .unwrap();
+
+    // Then the validator stake account is created at its PDA.
+    let validator_stake = find_validator_stake_pda(&vote, &config_manager.config).0;
+    let Output::Initialized { address, .. } = output else {
+        panic!("expected an initialized output");
+    };
+    assert_eq!(address, validator_stake.to_string());
+    let account = get_account!(context, validator_stake);
+    let stake_account = ValidatorStake::from_bytes(&account.data).unwrap();
+    assert_eq!(stake_account.delegation.validator_vote, vote);
+
+    // Given a native stake delegated to the validator whose withdrawer signed
+    // the DUNA document.
+    let staker = Keypair::new();
+    let sol_stake = Keypair::new();
+    create_stake_account(
+        &mut context,
+        &sol_stake,
+        &Authorized::auto(&staker.pubkey()),
+        &Lockup::default(),
+        1_000_000_000,
+    )
+    .await;
+    delegate_stake_account(&mut context, &sol_stake.pubkey(), &vote, &staker).await;
+    sign_duna_document(&mut context, &staker.pubkey());
+
+    // When we initialize its SOL staker stake account.
+    let output = run(
+        &context,
+        &[
+            "-k",
+            &payer_file,
+            "--config",
+            &config_arg,
+            "init-sol-staker-stake",
+            "--sol-stake",
+            &sol_stake.pubkey().to_string(),
+        ],
+    )
+    .await
+    .unwrap();
+
+    // Then the SOL staker stake account is created at its PDA.
+    let sol_staker_stake = find_sol_staker_stake_pda(&sol_stake.pubkey(), &config_manager.config).0;
+    let Output::Initialized { address, .. } = output else {
+        panic!("expected an initialized output");
+    };
+    assert_eq!(address, sol_staker_stake.to_string());
+    let account = get_account!(context, sol_staker_stake);
+    let stake_account = SolStakerStake::from_bytes(&account.data).unwrap();
+    assert_eq!(stake_account.delegation.authority, staker.pubkey());
+    assert_eq!(stake_account.sol_stake, sol_stake.pubkey());
+}
+
+#[tokio::test]
+async fn stake_harvest_unstake_and_slash_validator_stake() {
+    let mut context = setup::setup(&[]).await;
+    let config_manager = ConfigManager::new(&mut context).await;
+    let config_arg = config_manager.config.to_string();
+    let payer_file = keypair_file(&context.payer);
+    let slash_authority_file = keypair_file(&config_manager.config_authority);
+
+    // Given a validator stake account backed by 1 SOL.
+    let validator_stake_manager =
+        ValidatorStakeManager::new(&mut context, &config_manager.config).await;
+    let stake = validator_stake_manager.stake;
+    let stake_arg = stake.to_string();
+    set_validator_staked_lamports(&mut context, &stake).await;
+
+    // And a token account of the validator authority with 100 tokens.
+    let authority = &validator_stake_manager.authority;
+    let authority_file = keypair_file(authority);
+    let token_account =
+        fund_token_account(&mut context, &config_manager, &authority.pubkey(), 100).await;
+
+    // When we stake the 100 tokens.
+    let output = run(
+        &context,
+        &[
+            "-k",
+            &payer_file,
+            "--config",
+            &config_arg,
+            "stake",
+            &stake_arg,
+            "100",
+            "--authority",
+            &authority_file,
+        ],
+    )
+    .await
+    .unwrap();
+    assert!(matches!(output, Output::Transaction { .. }));
+
+    // Then the tokens are staked and activating.
+    let account = get_account!(context, stake);
+    let stake_account = ValidatorStake::from_bytes(&account.data).unwrap();
+    assert_eq!(stake_account.delegation.staked_amount, 100);
+    assert_eq!(stake_account.delegation.effective_amount, 0);
+    assert_eq!(token_balance(&mut context, &token_account).await, 0);
+
+    // When we harvest in the next epoch.
+    warp_to_next_epoch(&mut context).await;
+    run(
+        &context,
+        &[
+            "-k",
+            &payer_file,
+            "--config",
+            &config_arg,
+            "harvest",
+            &stake_arg,
+        ],
+    )
+    .await
+    .unwrap();
+
+    // Then the tokens are effective.
+    let account = get_account!(context, stake);
+    let stake_account = ValidatorStake::from_bytes(&account.data).unwrap();
+    assert_eq!(stake_account.delegation.effective_amount, 100);
+
+    // When we unstake the maximum deactivation amount (5%).
+    run(
+        &context,
+        &[
+            "-k",
+            &payer_file,
+            "--config",
+            &config_arg,
+            "unstake",
+            &stake_arg,
+            "5",
+            "--authority",
+            &authority_file,
+        ],
+    )
+    .await
+    .unwrap();
+
+    // Then the tokens are back in the token account of the authority.
+    let account = get_account!(context, stake);
+    let stake_account = ValidatorStake::from_bytes(&account.data).unwrap();
+    assert_eq!(stake_account.delegation.staked_amount, 95);
+    assert_eq!(token_balance(&mut context, &token_account).await, 5);
+
+    // When the slash authority slashes 10 tokens.
+    run(
+        &context,
+        &[
+            "-k",
+            &payer_file,
+            "--config",
+            &config_arg,
+            "slash",
+            &stake_arg,
+            "10",
+            "--slash-authority",
+            &slash_authority_file,
+        ],
+    )
+    .await
+    .unwrap();
+
+    // Then the tokens are removed from the stake and the vault.
+    let account = get_account!(context, stake);
+    let stake_account = ValidatorStake::from_bytes(&account.data).unwrap();
+    assert_eq!(stake_account.delegation.staked_amount, 85);
+    assert_eq!(stake_account.delegation.effective_amount, 85);
+    assert_eq!(token_balance(&mut context, &config_manager.vault).await, 85);
+}
+
+#[tokio::test]
+async fn fail_slash_without_slash_authority() {
+    let mut context = setup::setup(&[]).await;
+    let config_manager = ConfigManager::new(&mut context).await;
+    let payer_file = keypair_file(&context.payer);
+
+    // Given a validator stake account.
+    let validator_stake_manager =
+        ValidatorStakeManager::new(&mut context, &config_manager.config).await;
+
+    // When we try to slash it signing with the payer.
+    let result = run(
+        &context,
+        &[
+            "-k",
+            &payer_file,
+            "--config",
+            &config_manager.config.to_string(),
+            "slash",
+            &validator_stake_manager.stake.to_string(),
+            "1",
+        ],
+    )
+    .await;
+
+    // Then the transaction fails.
+    assert!(result.is_err());
+}
+
+#[tokio::test]
+async fn move_tokens_between_sol_staker_stakes() {
+    let mut context = setup::setup(&[]).await;
+    let config_manager = ConfigManager::new(&mut context).await;
+    let config_arg = config_manager.config.to_string();
+    let payer_file = keypair_file(&context.payer);
+
+    // Given two SOL staker stake accounts of the same authority.
+    let validator_stake_manager =
+        ValidatorStakeManager::new(&mut context, &config_manager.config).await;
+    let staker = Keypair::new();
+    let staker_file = keypair_file(&staker);
+    let source = SolStakerStakeManager::new_with_authority(
+        &mut context,
+        &config_manager.config,
+        &validator_stake_manager.stake,
+        &validator_stake_manager.vote,
+        staker.insecure_clone(),
+        5_000_000_000, // 5 SOL staked
+    )
+    .await;
+    let destination = SolStakerStakeManager::new_with_authority(
+        &mut context,
+        &config_manager.config,
+        &validator_stake_manager.stake,
+        &validator_stake_manager.vote,
+        staker.insecure_clone(),
+        5_000_000_000, // 5 SOL staked
+    )
+    .await;
+
+    // And 10 tokens staked on the source.
+    fund_token_account(&mut context, &config_manager, &staker.pubkey(), 10).await;
+    run(
+        &context,
+        &[
+            "-k",
+            &payer_file,
+            "--config",
+            &config_arg,
+            "stake",
+            &source.stake.to_string(),
+            "10",
+            "--authority",
+            &staker_file,
+        ],
+    )
+    .await
+    .unwrap();
+
+    // When we move 3 tokens to the destination.
+    let output = run(
+        &context,
+        &[
+            "-k",
+            &payer_file,
+            "--config",
+            &config_arg,
+            "move",
+            &source.stake.to_string(),
+            &destination.stake.to_string(),
+            "3",
+            "--authority",
+            &staker_file,
+        ],
+    )
+    .await
+    .unwrap();
+    assert!(matches!(output, Output::Transaction { .. }));
+
+    // Then both stake accounts show the moved tokens.
+    let (source_stake, _) =
+        show_sol_staker_stake(&context, &payer_file, &config_manager.config, &source.stake).await;
+    assert_eq!(source_stake.delegation.staked_amount, 7);
+    let (destination_stake, _) = show_sol_staker_stake(
+        &context,
+        &payer_file,
+        &config_manager.config,
+        &destination.stake,
+    )
+    .await;
+    assert_eq!(destination_stake.delegation.staked_amount, 3);
+}
+
+#[tokio::test]
+async fn show_stakes_with_pending_rewards() {
+    let mut context = setup::setup(&[]).await;
+    let config_manager = ConfigManager::new(&mut context).await;
+    let config_arg = config_manager.config.to_string();
+    let payer_file = keypair_file(&context.payer);
+
+    // Given a validator stake account backed by 1 SOL with 100 tokens staked.
+    let validator_stake_manager =
+        ValidatorStakeManager::new(&mut context, &config_manager.config).await;
+    let validator_authority = validator_stake_manager.authority.pubkey();
+    set_validator_staked_lamports(&mut context, &validator_stake_manager.stake).await;
+    fund_token_account(&mut context, &config_manager, &validator_authority, 100).await;
+    run(
+        &context,
+        &[
+            "-k",
+            &payer_file,
+            "--config",
+            &config_arg,
+            "stake",
+            &validator_stake_manager.stake.to_string(),
+            "100",
+            "--authority",
+            &keypair_file(&validator_stake_manager.authority),
+        ],
+    )
+    .await
+    .unwrap();
+
+    // And a SOL staker stake account backed by 5 SOL with 100 tokens staked.
+    let sol_staker_stake_manager = SolStakerStakeManager::new(
+        &mut context,
+        &config_manager.config,
+        &validator_stake_manager.stake,
+        &validator_stake_manager.vote,
+        5_000_000_000, // 5 SOL staked
+    )
+    .await;
+    let staker = sol_staker_stake_manager.authority.pubkey();
+    fund_token_account(&mut context, &config_manager, &staker, 100).await;
+    run(
+        &context,
+        &[
+            "-k",
+            &payer_file,
+            "--config",
+            &config_arg,
+            "stake",
+            &sol_staker_stake_manager.stake.to_string(),
+            "100",
+            "--authority",
+            &keypair_file(&sol_staker_stake_manager.authority),
+        ],
+    )
+    .await
+    .unwrap();
+
+    // And both stakes are activated in the next epoch.
+    warp_to_next_epoch(&mut context).await;
+    for stake in [
+        validator_stake_manager.stake,
+        sol_staker_stake_manager.stake,
+    ] {
+        run(
+            &context,
+            &[
+                "-k",
+                &payer_file,
+                "--config",
+                &config_arg,
+                "harvest",
+                &stake.to_string(),
+            ],
+        )
+        .await
+        .unwrap();
+    }
+
+    // When the config receives 1 SOL of rewards.
+    context.get_new_latest_blockhash().await.unwrap();
+    let transaction = Transaction::new_signed_with_payer(
+        &[system_instruction::transfer(
+            &context.payer.pubkey(),
+            &config_manager.config,
+            1_000_000_000,
+        )],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    // Then both stakes show pending staking rewards.
+    let (validator_stake, validator_rewards) = show_validator_stake(
+        &context,
+        &payer_file,
+        &config_manager.config,
+        &validator_stake_manager.stake,
+    )
+    .await;
+    assert_eq!(validator_stake.delegation.effective_amount, 100);
+    assert!(validator_rewards.staking_reward > 0);
+
+    let (sol_staker_stake, sol_staker_rewards) = show_sol_staker_stake(
+        &context,
+        &payer_file,
+        &config_manager.config,
+        &sol_staker_stake_manager.stake,
+    )
+    .await;
+    assert_eq!(sol_staker_stake.delegation.effective_amount, 100);
+    assert!(sol_staker_rewards.staking_reward > 0);
+
+    // And harvesting pays out the pending rewards to each authority.
+    context.get_new_latest_blockhash().await.unwrap();
+    for (stake, authority, pending_rewards) in [
+        (
+            validator_stake_manager.stake,
+            validator_authority,
+            validator_rewards,
+        ),
+        (sol_staker_stake_manager.stake, staker, sol_staker_rewards),
+    ] {
+        let lamports_before = context.banks_client.get_balance(authority).await.unwrap();
+        run(
+            &context,
+            &[
+                "-k",
+                &payer_file,
+                "--config",
+                &config_arg,
+                "harvest",
+                &stake.to_string(),
+            ],
+        )
+        .await
+        .unwrap();
+        let lamports_after = context.banks_client.get_balance(authority).await.unwrap();
+        assert_eq!(
+            lamports_after - lamports_before,
+            pending_rewards.staking_reward + pending_rewards.holder_reward
+        );
+    }
+
+    // And nothing is pending anymore.
+    let (_, validator_rewards) = show_validator_stake(
+        &context,
+        &payer_file,
+        &config_manager.config,
+        &validator_stake_manager.stake,
+    )
+    .await;
+    assert_eq!(validator_rewards, PendingRewards::default());
+    let (_, sol_staker_rewards) = show_sol_staker_stake(
+        &context,
+        &payer_file,
+        &config_manager.config,
+        &sol_staker_stake_manager.stake,
+    )
+    .await;
+    assert_eq!(sol_staker_rewards, PendingRewards::default());
+}
@@ -0,0 +1,552 @@
+//! Decoding of the ledger entries of a transaction.
+//!
+//! Token amounts are read from the stake program instructions, while the
+//! harvested rewards and the balances are derived from the stake accounts data
+//! before and after the transaction.
+
+use std::{collections::HashSet, fmt, str::FromStr};
+
+use paladin_stake_program::instruction::StakeInstruction;
+use paladin_stake_program_client::{
+    accounts::{Config, SolStakerStake, ValidatorStake},
+    filters::{SOL_STAKER_STAKE_DISCRIMINATOR, VALIDATOR_STAKE_DISCRIMINATOR},
+    math::calculate_eligible_rewards,
+    types::Delegation,
+};
+use solana_program::pubkey::Pubkey;
+
+use crate::{
+    transaction::{AccountChange, ConfirmedTransaction},
+    Error,
+};
+
+/// Kind of a ledger entry.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum EntryKind {
+    Stake,
+    Unstake,
+    Harvest,
+    Slash,
+    /// Tokens moved out of the stake account to another one.
+    MoveOut,
+    /// Tokens moved into the stake account from another one.
+    MoveIn,
+}
+
+impl EntryKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Stake => "stake",
+            Self::Unstake => "unstake",
+            Self::Harvest => "harvest",
+            Self::Slash => "slash",
+            Self::MoveOut => "move_out",
+            Self::MoveIn => "move_in",
+        }
+    }
+}
+
+impl fmt::Display for EntryKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for EntryKind {
+    type Err = Error;
+
+    fn from_str(value: &str) -> Result<Self, Error> {
+        match value {
+            "stake" => Ok(Self::Stake),
+            "unstake" => Ok(Self::Unstake),
+            "harvest" => Ok(Self::Harvest),
+            "slash" => Ok(Self::Slash),
+            "move_out" => Ok(Self::MoveOut),
+            "move_in" => Ok(Self::MoveIn),
+            _ => Err(Error::InvalidEntryKind(value.to_string())),
+        }
+    }
+}
+
+/// Change of a stake account balance.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LedgerEntry {
+    pub signature: String,
+    pub slot: u64,
+    pub block_time: Option<i64>,
+    pub instruction_index: u32,
+    pub kind: EntryKind,
+    pub stake: Pubkey,
+    pub authority: Pubkey,
+    /// Tokens staked, unstaked, slashed or moved.
+    pub amount: u64,
+    /// Staking rewards harvested, in lamports (net of the keeper bounty).
+    pub staking_reward: u64,
+    /// Holder rewards harvested, in lamports (net of the keeper bounty).
+    pub holder_reward: u64,
+    /// Staked tokens of the stake account after the transaction.
+    pub staked_amount: u64,
+}
+
+/// Decodes the ledger entries of a transaction.
+///
+/// Rewards are harvested by every instruction changing a stake balance, so a
+/// harvest entry is added before the first entry of a stake account whenever
+/// its reward checkpoints moved. Keeper bounties paid out of the rewards of a
+/// synced SOL staker stake are not part of the harvested rewards.
+///
+/// Slashed amounts are read from the stake balances, since the program slashes
+/// at most the staked amount.
+pub fn decode_transaction(transaction: &ConfirmedTransaction) -> Result<Vec<LedgerEntry>, Error> {
+    let mut entries = Vec::new();
+    let mut harvested = HashSet::new();
+
+    for (index, instruction) in transaction.instructions.iter().enumerate() {
+        if instruction.program_id != paladin_stake_program_client::ID {
+            continue;
+        }
+
+        let invalid_instruction = || Error::InvalidInstruction {
+            signature: transaction.signature.clone(),
+            index,
+        };
+        let stake_instruction =
+            StakeInstruction::unpack(&instruction.data).map_err(|_| invalid_instruction())?;
+        // Only the harvest of SOL staker stakes pays a keeper, when it syncs.
+        let pays_keeper = matches!(stake_instruction, StakeInstruction::HarvestSolStakerRewards);
+
+        // Position of the config account and of the stake accounts, with the
+        // tokens moved on each of them.
+        let (config_position, changes) = match stake_instruction {
+            StakeInstruction::ValidatorStakeTokens(amount)
+            | StakeInstruction::SolStakerStakeTokens(amount) => {
+                (0, vec![(3, Some((EntryKind::Stake, amount)))])
+            }
+            StakeInstruction::UnstakeTokens { amount } => {
+                (0, vec![(3, Some((EntryKind::Unstake, amount)))])
+            }
+            StakeInstruction::SlashValidatorStake(amount)
+            | StakeInstruction::SlashSolStakerStake(amount) => {
+                (0, vec![(1, Some((EntryKind::Slash, amount)))])
+            }
+            StakeInstruction::SolStakerMoveTokens { amount } => (
+                0,
+                vec![
+                    (3, Some((EntryKind::MoveOut, amount))),
+                    (4, Some((EntryKind::MoveIn, amount))),
+                ],
+            ),
+            StakeInstruction::TransferStake { amount } => (
+                0,
+                vec![
+                    (2, Some((EntryKind::MoveOut, amount))),
+                    (4, Some((EntryKind::MoveIn, amount))),
+                ],
+            ),
+            StakeInstruction::HarvestValidatorRewards => (0, vec![(2, None)]),
+            StakeInstruction::HarvestSolStakerRewards => (1, vec![(3, None)]),
+            _ => continue,
+        };
+
+        let account = |position: usize| {
+            instruction
+                .accounts
+                .get(position)
+                .copied()
+                .ok_or_else(invalid_instruction)
+        };
+        let config = transaction
+            .account(&account(config_position)?)
+            .and_then(|change| change.post.as_deref())
+            .and_then(|data| Config::from_bytes(data).ok());
+
+        for (position, change) in changes {
+            let stake = account(position)?;
+            let account_change = transaction
+                .account(&stake)
+                .ok_or(Error::MissingAccount(stake))?;
+            let pre = account_change.pre.as_deref().and_then(delegation);
+            let post = account_change
+                .post
+                .as_deref()
+                .and_then(delegation)
+                .ok_or(Error::MissingAccount(stake))?;
+
+            let entry = |kind, amount| LedgerEntry {
+                signature: transaction.signature.clone(),
+                slot: transaction.slot,
+                block_time: transaction.block_time,
+                instruction_index: index as u32,
+                kind,
+                stake,
+                authority: post.authority,
+                amount,
+                staking_reward: 0,
+                holder_reward: 0,
+                staked_amount: post.staked_amount,
+            };
+
+            if let (Some(pre), Some(config)) = (&pre, &config) {
+                if harvested.insert(stake) {
+                    let keeper_paid = pays_keeper && synced(account_change);
+                    let (staking_reward, holder_reward) =
+                        harvested_rewards(config, pre, &post, keeper_paid)
+                            .ok_or(Error::ArithmeticOverflow(stake))?;
+                    if staking_reward > 0 || holder_reward > 0 {
+                        entries.push(LedgerEntry {
+                            staking_reward,
+                            holder_reward,
+                            ..entry(EntryKind::Harvest, 0)
+                        });
+                    }
+                }
+            }
+
+            if let Some((kind, amount)) = change {
+                let amount = match (kind, &pre) {
+                    (EntryKind::Slash, Some(pre)) => {
+                        pre.staked_amount.saturating_sub(post.staked_amount)
+                    }
+                    _ => amount,
+                };
+                entries.push(entry(kind, amount));
+            }
+        }
+    }
+
+    Ok(entries)
+}
+
+/// Decodes the delegation of a validator or SOL staker stake account.
+fn delegation(data: &[u8]) -> Option<Delegation> {
+    let discriminator: [u8; 8] = data.get(..8)?.try_into().ok()?;
+    match discriminator {
+        VALIDATOR_STAKE_DISCRIMINATOR => Some(ValidatorStake::from_bytes(data).ok()?.delegation),
+        SOL_STAKER_STAKE_DISCRIMINATOR => Some(SolStakerStake::from_bytes(data).ok()?.delegation),
+        _ => None,
+    }
+}
+
+/// Whether a SOL staker stake was synced with its native stake, i.e. its
+/// delegated vote account, SOL amount or pending validator stake changed.
+fn synced(account_change: &AccountChange) -> bool {
+    let sol_staker_stake = |data: Option<&[u8]>| {
+        let stake = SolStakerStake::from_bytes(data?).ok()?;
+        Some((
+            stake.delegation.validator_vote,
+            stake.lamports_amount,
+            stake.awaiting_validator_stake,
+        ))
+    };
+    match (
+        sol_staker_stake(account_change.pre.as_deref()),
+        sol_staker_stake(account_change.post.as_deref()),
+    ) {
+        (Some(pre), Some(post)) => pre != post,
+        _ => false,
+    }
+}
+
+/// Rewards paid out to the stake authority between two delegation
+/// checkpoints, net of the keeper bounty (if paid).
+///
+/// The protocol fee is withheld when the config account receives the stake
+/// rewards, so it is already excluded from the rewards per token.
+///
+/// The keeper is paid first, out of the staking and then the holder rewards.
+fn harvested_rewards(
+    config: &Config,
+    pre: &Delegation,
+    post: &Delegation,
+    keeper_paid: bool,
+) -> Option<(u64, u64)> {
+    let staking_reward = calculate_eligible_rewards(
+        post.last_seen_stake_rewards_per_token,
+        pre.last_seen_stake_rewards_per_token,
+        pre.effective_amount,
+    )?;
+    let holder_reward = calculate_eligible_rewards(
+        post.last_seen_holder_rewards_per_token,
+        pre.last_seen_holder_rewards_per_token,
+        pre.staked_amount,
+    )?;
+
+    let keeper_reward = match keeper_paid {
+        true => std::cmp::min(
+            staking_reward.checked_add(holder_reward)?,
+            config.sync_rewards_lamports,
+        ),
+        false => 0,
+    };
+    let keeper_staking_reward = std::cmp::min(keeper_reward, staking_reward);
+
+    Some((
+        staking_reward - keeper_staking_reward,
+        holder_reward.checked_sub(keeper_reward - keeper_staking_reward)?,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use bytemuck::Zeroable;
+    use paladin_stake_program::state as program;
+
+    use super::*;
+    use crate::transaction::ConfirmedInstruction;
+
+    const SCALING_FACTOR: u128 = 1_000_000_000_000_000_000;
+
+    fn validator_stake(authority: Pubkey, staked_amount: u64, rewards_per_token: u128) -> Vec<u8> {
+        let mut stake = program::ValidatorStake::zeroed();
+        stake._discriminator = VALIDATOR_STAKE_DISCRIMINATOR;
+        stake.delegation.authority = authority;
+        stake.delegation.staked_amount = staked_amount;
+        stake.delegation.effective_amount = staked_amount;
+        stake.delegation.last_seen_stake_rewards_per_token = (2 * rewards_per_token).into();
+        stake.delegation.last_seen_holder_rewards_per_token = rewards_per_token.into();
+        bytemuck::bytes_of(&stake).to_vec()
+    }
+
+    fn sol_staker_stake(
+        authority: Pubkey,
+        staked_amount: u64,
+        rewards_per_token: u128,
+        lamports_amount: u64,
+    ) -> Vec<u8> {
+        let mut stake = program::SolStakerStake::zeroed();
+        stake._discriminator = SOL_STAKER_STAKE_DISCRIMINATOR;
+        stake.delegation.authority = authority;
+        stake.delegation.staked_amount = staked_amount;
+        stake.delegation.effective_amount = staked_amount;
+        stake.delegation.last_seen_stake_rewards_per_token = (2 * rewards_per_token).into();
+        stake.delegation.last_seen_holder_rewards_per_token = rewards_per_token.into();
+        stake.lamports_amount = lamports_amount;
+        bytemuck::bytes_of(&stake).to_vec()
+    }
+
+    fn single_instruction(
+        instruction: StakeInstruction,
+        accounts: Vec<Pubkey>,
+        changes: Vec<AccountChange>,
+    ) -> ConfirmedTransaction {
+        ConfirmedTransaction {
+            signature: "signature".to_string(),
+            slot: 10,
+            block_time: None,
+            instructions: vec![ConfirmedInstruction {
+                program_id: paladin_stake_program_client::ID,
+                accounts,
+                data: instruction.pack(),
+            }],
+            accounts: changes,
+        }
+    }
+
+    #[test]
+    fn stake_entries_with_harvested_rewards() {
+        let config = Pubkey::new_unique();
+        let stake = Pubkey::new_unique();
+        let authority = Pubkey::new_unique();
+
+        // Given a 10% protocol fee (withheld before the rewards per token are
+        // updated) and a stake of 50 tokens on a stake account with 100 tokens,
+        // whose rewards per token moved by 2 (staking) and 1 (holder) lamports.
+        let mut config_state = program::Config::zeroed();
+        config_state.protocol_fee_basis_points = 1_000;
+
+        let mut accounts = vec![Pubkey::new_unique(); 13];
+        accounts[0] = config;
+        accounts[3] = stake;
+        let transaction = ConfirmedTransaction {
+            signature: "signature".to_string(),
+            slot: 10,
+            block_time: Some(1_000),
+            instructions: vec![
+                ConfirmedInstruction {
+                    program_id: Pubkey::new_unique(),
+                    accounts: vec![],
+                    data: vec![255],
+                },
+                ConfirmedInstruction {
+                    program_id: paladin_stake_program_client::ID,
+                    accounts,
+                    data: StakeInstruction::ValidatorStakeTokens(50).pack(),
+                },
+            ],
+            accounts: vec![
+                AccountChange {
+                    address: config,
+                    pre: None,
+                    post: Some(bytemuck::bytes_of(&config_state).to_vec()),
+                },
+                AccountChange {
+                    address: stake,
+                    pre: Some(validator_stake(authority, 100, 0)),
+                    post: Some(validator_stake(authority, 150, SCALING_FACTOR)),
+                },
+            ],
+        };
+
+        // When we decode the transaction.
+        let entries = decode_transaction(&transaction).unwrap();
+
+        // Then the harvest precedes the stake, both at instruction 1.
+        let harvest = LedgerEntry {
+            signature: "signature".to_string(),
+            slot: 10,
+            block_time: Some(1_000),
+            instruction_index: 1,
+            kind: EntryKind::Harvest,
+            stake,
+            authority,
+            amount: 0,
+            staking_reward: 200,
+            holder_reward: 100,
+            staked_amount: 150,
+        };
+        assert_eq!(
+            entries,
+            vec![
+                harvest.clone(),
+                LedgerEntry {
+                    kind: EntryKind::Stake,
+                    amount: 50,
+                    staking_reward: 0,
+                    holder_reward: 0,
+                    ..harvest
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn slash_entry_with_slashed_amount() {
+        let config = Pubkey::new_unique();
+        let stake = Pubkey::new_unique();
+        let authority = Pubkey::new_unique();
+
+        // Given a slash of 100 tokens on a stake account with 30 tokens.
+        let mut accounts = vec![Pubkey::new_unique(); 9];
+        accounts[0] = config;
+        accounts[1] = stake;
+        let transaction = single_instruction(
+            StakeInstruction::SlashValidatorStake(100),
+            accounts,
+            vec![
+                AccountChange {
+                    address: config,
+                    pre: None,
+                    post: Some(bytemuck::bytes_of(&program::Config::zeroed()).to_vec()),
+                },
+                AccountChange {
+                    address: stake,
+                    pre: Some(validator_stake(authority, 30, 0)),
+                    post: Some(validator_stake(authority, 0, 0)),
+                },
+            ],
+        );
+
+        // When we decode the transaction.
+        let entries = decode_transaction(&transaction).unwrap();
+
+        // Then only the 30 tokens staked are slashed.
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].kind, EntryKind::Slash);
+        assert_eq!(entries[0].amount, 30);
+        assert_eq!(entries[0].staked_amount, 0);
+    }
+
+    #[test]
+    fn harvest_entry_without_keeper_reward() {
+        let config = Pubkey::new_unique();
+        let stake = Pubkey::new_unique();
+        let authority = Pubkey::new_unique();
+
+        // Given a sync bounty of 50 lamports and a SOL staker stake account
+        // with 100 tokens, whose rewards per token moved by 2 (staking) and 1
+        // (holder) lamports.
+        let mut config_state = program::Config::zeroed();
+        config_state.sync_rewards_lamports = 50;
+
+        let mut accounts = vec![Pubkey::new_unique(); 12];
+        accounts[1] = config;
+        accounts[3] = stake;
+        let harvest = |lamports_amount| {
+            single_instruction(
+                StakeInstruction::HarvestSolStakerRewards,
+                accounts.clone(),
+                vec![
+                    AccountChange {
+                        address: config,
+                        pre: None,
+                        post: Some(bytemuck::bytes_of(&config_state).to_vec()),
+                    },
+                    AccountChange {
+                        address: stake,
+                        pre: Some(sol_staker_stake(authority, 100, 0, 1_000)),
+                        post: Some(sol_staker_stake(
+                            authority,
+                            100,
+                            SCALING_FACTOR,
+                            lamports_amount,
+                        )),
+                    },
+                ],
+            )
+        };
+
+        // When we decode a harvest that does not sync the stake.
+        let entries = decode_transaction(&harvest(1_000)).unwrap();
+
+        // Then the authority receives all the rewards.
+        assert_eq!(entries.len(), 1);
+        assert_eq!(
+            (entries[0].staking_reward, entries[0].holder_reward),
+            (200, 100)
+        );
+
+        // When we decode a harvest syncing the SOL amount of the stake.
+        let entries = decode_transaction(&harvest(2_000)).unwrap();
+
+        // Then the keeper bounty is paid out of the staking rewards.
+        assert_eq!(entries.len(), 1);
+        assert_eq!(
+            (entries[0].staking_reward, entries[0].holder_reward),
+            (150, 100)
+        );
+    }
+
+    #[test]
+    fn fail_with_invalid_instruction_data() {
+        let transaction = ConfirmedTransaction {
+            signature: "signature".to_string(),
+            slot: 10,
+            block_time: None,
+            instructions: vec![ConfirmedInstruction {
+                program_id: paladin_stake_program_client::ID,
+                accounts: vec![],
+                data: vec![255],
+            }],
+            accounts: vec![],
+        };
+
+        assert!(matches!(
+            decode_transaction(&transaction),
+            Err(Error::InvalidInstruction { index: 0, .. })
+        ));
+    }
+
+    #[test]
+    fn entry_kind_round_trip() {
+        for kind in [
+            EntryKind::Stake,
+            EntryKind::Unstake,
+            EntryKind::Harvest,
+            EntryKind::Slash,
+            EntryKind::MoveOut,
+            EntryKind::MoveIn,
+        ] {
+            assert_eq!(kind.as_str().parse::<EntryKind>().unwrap(), kind);
+        }
+    }
+}
@@ -0,0 +1,240 @@
+//! SQLite ledger of the stake accounts history.
+
+use std::path::Path;
+
+use rusqlite::{params, types::Type, Connection, OptionalExtension, Row};
+use solana_program::pubkey::Pubkey;
+
+use crate::{
+    decode::{decode_transaction, LedgerEntry},
+    transaction::ConfirmedTransaction,
+    Error,
+};
+
+const SCHEMA: &str = "
+CREATE TABLE IF NOT EXISTS transactions (
+    signature TEXT PRIMARY KEY,
+    slot INTEGER NOT NULL,
+    block_time INTEGER
+);
+
+CREATE TABLE IF NOT EXISTS entries (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    signature TEXT NOT NULL REFERENCES transactions (signature),
+    slot INTEGER NOT NULL,
+    block_time INTEGER,
+    instruction_index INTEGER NOT NULL,
+    kind TEXT NOT NULL,
+    stake TEXT NOT NULL,
+    authority TEXT NOT NULL,
+    amount INTEGER NOT NULL,
+    staking_reward INTEGER NOT NULL,
+    holder_reward INTEGER NOT NULL,
+    staked_amount INTEGER NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS entries_stake ON entries (stake, slot);
+CREATE INDEX IF NOT EXISTS entries_authority ON entries (authority, slot);
+";
+
+const ENTRY_COLUMNS: &str = "signature, slot, block_time, instruction_index, kind, stake, \
+                             authority, amount, staking_reward, holder_reward, staked_amount";
+
+/// Ledger of the stake, unstake, harvest, slash and move entries of the
+/// indexed transactions.
+pub struct Ledger {
+    connection: Connection,
+}
+
+impl Ledger {
+    /// Opens (or creates) the ledger database at the given path.
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, Error> {
+        Self::new(Connection::open(path)?)
+    }
+
+    /// Opens a ledger held in memory.
+    pub fn open_in_memory() -> Result<Self, Error> {
+        Self::new(Connection::open_in_memory()?)
+    }
+
+    fn new(connection: Connection) -> Result<Self, Error> {
+        connection.execute_batch(SCHEMA)?;
+        Ok(Self { connection })
+    }
+
+    /// Indexes a transaction, returning `false` if it was already indexed.
+    ///
+    /// Transactions must be indexed in the order they were executed.
+    pub fn index(&mut self, transaction: &ConfirmedTransaction) -> Result<bool, Error> {
+        let entries = decode_transaction(transaction)?;
+
+        let db_transaction = self.connection.transaction()?;
+        let inserted = db_transaction.execute(
+            "INSERT OR IGNORE INTO transactions (signature, slot, block_time) VALUES (?1, ?2, ?3)",
+            params![
+                transaction.signature,
+                transaction.slot,
+                transaction.block_time
+            ],
+        )?;
+        if inserted == 0 {
+            return Ok(false);
+        }
+
+        {
+            let mut statement = db_transaction.prepare(&format!(
+                "INSERT INTO entries ({ENTRY_COLUMNS}) \
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11)"
+            ))?;
+            for entry in entries {
+                statement.execute(params![
+                    entry.signature,
+                    entry.slot,
+                    entry.block_time,
+                    entry.instruction_index,
+                    entry.kind.as_str(),
+                    entry.stake.to_string(),
+                    entry.authority.to_string(),
+                    entry.amount,
+                    entry.staking_reward,
+                    entry.holder_reward,
+                    entry.staked_amount,
+                ])?;
+            }
+        }
+        db_transaction.commit()?;
+
+        Ok(true)
+    }
+
+    /// Returns the entries of a stake account, in execution order.
+    pub fn entries(&self, stake: &Pubkey) -> Result<Vec<LedgerEntry>, Error> {
+        self.query_entries("stake", stake)
+    }
+
+    /// Returns the entries of the stake accounts of an authority, in execution
+    /// order.
+    pub fn entries_by_authority(&self, authority: &Pubkey) -> Result<Vec<LedgerEntry>, Error> {
+        self.query_entries("authority", authority)
+    }
+
+    /// Returns the staked tokens of a stake account at the end of the given
+    /// slot, or `None` if the account has no entries up to that slot.
+    pub fn staked_amount_at(&self, stake: &Pubkey, slot: u64) -> Result<Option<u64>, Error> {
+        Ok(self
+            .connection
+            .query_row(
+                "SELECT staked_amount FROM entries WHERE stake = ?1 AND slot <= ?2 \
+                 ORDER BY slot DESC, id DESC LIMIT 1",
+                params![stake.to_string(), slot],
+                |row| row.get(0),
+            )
+            .optional()?)
+    }
+
+    fn query_entries(&self, column: &str, address: &Pubkey) -> Result<Vec<LedgerEntry>, Error> {
+        let mut statement = self.connection.prepare(&format!(
+            "SELECT {ENTRY_COLUMNS} FROM entries WHERE {column} = ?1 ORDER BY slot, id"
+        ))?;
+        let entries = statement
+            .query_map([address.to_string()], entry_from_row)?
+            .collect::<Result<_, _>>()?;
+        Ok(entries)
+    }
+}
+
+fn entry_from_row(row: &Row) -> rusqlite::Result<LedgerEntry> {
+    Ok(LedgerEntry {
+        signature: row.get(0)?,
+        slot: row.get(1)?,
+        block_time: row.get(2)?,
+        instruction_index: row.get(3)?,
+        kind: parse_column(row, 4)?,
+        stake: parse_column(row, 5)?,
+        authority: parse_column(row, 6)?,
+        amount: row.get(7)?,
+        staking_reward: row.get(8)?,
+        holder_reward: row.get(9)?,
+        staked_amount: row.get(10)?,
+    })
+}
+
+fn parse_column<T>(row: &Row, index: usize) -> rusqlite::Result<T>
+where
+    T: std::str::FromStr,
+    T::Err: std::error::Error + Send + Sync + 'static,
+{
+    row.get::<_, String>(index)?
+        .parse()
+        .map_err(|err| rusqlite::Error::FromSqlConversionFailure(index, Type::Text, Box::new(err)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::decode::EntryKind;
+
+    #[test]
+    fn index_transaction_once() {
+        let mut ledger = Ledger::open_in_memory().unwrap();
+        let transaction = ConfirmedTransaction {
+            signature: "signature".to_string(),
+            slot: 1,
+            block_time: None,
+            instructions: vec![],
+            accounts: vec![],
+        };
+
+        assert!(ledger.index(&transaction).unwrap());
+        assert!(!ledger.index(&transaction).unwrap());
+    }
+
+    #[test]
+    fn query_entries_by_stake_and_slot() {
+        let ledger = Ledger::open_in_memory().unwrap();
+        let stake = Pubkey::new_unique();
+        let authority = Pubkey::new_unique();
+
+        for (slot, kind, amount, staked_amount) in [
+            (5, EntryKind::Stake, 100, 100),
+            (8, EntryKind::Unstake, 40, 60),
+        ] {
+            ledger
+                .connection
+                .execute(
+                    "INSERT INTO transactions (signature, slot) VALUES (?1, ?2)",
+                    params![slot.to_string(), slot],
+                )
+                .unwrap();
+            ledger
+                .connection
+                .execute(
+                    &format!(
+                        "INSERT INTO entries ({ENTRY_COLUMNS}) \
+                         VALUES (?1, ?2, NULL, 0, ?3, ?4, ?5, ?6, 0, 0, ?7)"
+                    ),
+                    params![
+                        slot.to_string(),
+                        slot,
+                        kind.as_str(),
+                        stake.to_string(),
+                        authority.to_string(),
+                        amount,
+                        staked_amount,
+                    ],
+                )
+                .unwrap();
+        }
+
+        let entries = ledger.entries_by_authority(&authority).unwrap();
+        assert_eq!(
+            entries.iter().map(|entry| entry.kind).collect::<Vec<_>>(),
+            [EntryKind::Stake, EntryKind::Unstake]
+        );
+        assert_eq!(ledger.entries(&stake).unwrap(), entries);
+
+        assert_eq!(ledger.staked_amount_at(&stake, 4).unwrap(), None);
+        assert_eq!(ledger.staked_amount_at(&stake, 7).unwrap(), Some(100));
+        assert_eq!(ledger.staked_amount_at(&stake, 8).unwrap(), Some(60));
+    }
+}
@@ -0,0 +1,33 @@
+//! Offline indexer of the stake program history.
+//!
+//! The program only persists the current state of the stake accounts, so the
+//! indexer replays confirmed transactions ([`transaction::ConfirmedTransaction`])
+//! to materialize the stake, unstake, harvest, slash and move history of every
+//! stake account into a SQLite [`ledger::Ledger`].
+
+pub mod decode;
+pub mod ledger;
+pub mod transaction;
+
+use solana_program::pubkey::Pubkey;
+use thiserror::Error;
+
+/// Errors returned by the indexer.
+#[derive(Debug, Error)]
+pub enum Error {
+    /// The ledger database failed.
+    #[error("Database error: {0}")]
+    Database(#[from] rusqlite::Error),
+    /// A stake program instruction could not be decoded.
+    #[error("Invalid instruction {index} of transaction {signature}")]
+    InvalidInstruction { signature: String, index: usize },
+    /// The data of a stake account written by a transaction is missing.
+    #[error("Missing data of account {0}")]
+    MissingAccount(Pubkey),
+    /// The rewards of a stake account overflowed.
+    #[error("Arithmetic overflow computing the rewards of {0}")]
+    ArithmeticOverflow(Pubkey),
+    /// A ledger entry kind is unknown.
+    #[error("Invalid entry kind: {0}")]
+    InvalidEntryKind(String),
+}
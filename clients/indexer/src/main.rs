@@ -0,0 +1,48 @@
+use std::{
+    fs::File,
+    io::{self, BufRead, BufReader},
+    path::PathBuf,
+};
+
+use clap::Parser;
+use paladin_stake_indexer::{ledger::Ledger, transaction::ConfirmedTransaction};
+
+/// Indexes the stake program transactions of a JSON lines file into a SQLite
+/// ledger.
+#[derive(Debug, Parser)]
+#[command(name = "paladin-stake-indexer", version)]
+struct Args {
+    /// Path of the SQLite ledger.
+    #[arg(long, default_value = "stake-ledger.sqlite")]
+    db: PathBuf,
+
+    /// JSON lines file of confirmed transactions, in execution order
+    /// [default: stdin].
+    input: Option<PathBuf>,
+}
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let args = Args::parse();
+    let mut ledger = Ledger::open(&args.db)?;
+    let input: Box<dyn BufRead> = match &args.input {
+        Some(path) => Box::new(BufReader::new(File::open(path)?)),
+        None => Box::new(io::stdin().lock()),
+    };
+
+    let (mut indexed, mut skipped) = (0, 0);
+    for line in input.lines() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let transaction: ConfirmedTransaction = serde_json::from_str(&line)?;
+        match ledger.index(&transaction)? {
+            true => indexed += 1,
+            false => skipped += 1,
+        }
+    }
+
+    println!("Indexed {indexed} transactions ({skipped} already indexed)");
+    Ok(())
+}
@@ -0,0 +1,58 @@
+//! Confirmed transactions replayed by the indexer.
+
+use serde::{Deserialize, Serialize};
+use serde_with::{base64::Base64, serde_as, DisplayFromStr};
+use solana_program::pubkey::Pubkey;
+
+/// Confirmed transaction invoking the stake program.
+///
+/// Transactions are read as JSON lines, as exported by a Geyser plugin or
+/// recorded by tests. `accounts` holds the data of the stake program accounts
+/// written by the transaction, before and after its execution.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConfirmedTransaction {
+    pub signature: String,
+    pub slot: u64,
+    pub block_time: Option<i64>,
+    /// Instructions of the transaction in execution order, including the
+    /// inner instructions invoked through CPI.
+    pub instructions: Vec<ConfirmedInstruction>,
+    pub accounts: Vec<AccountChange>,
+}
+
+impl ConfirmedTransaction {
+    /// Returns the change of an account, if recorded.
+    pub fn account(&self, address: &Pubkey) -> Option<&AccountChange> {
+        self.accounts
+            .iter()
+            .find(|account| &account.address == address)
+    }
+}
+
+/// Instruction of a confirmed transaction, with its account keys resolved.
+#[serde_as]
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConfirmedInstruction {
+    #[serde_as(as = "DisplayFromStr")]
+    pub program_id: Pubkey,
+    #[serde_as(as = "Vec<DisplayFromStr>")]
+    pub accounts: Vec<Pubkey>,
+    #[serde_as(as = "Base64")]
+    pub data: Vec<u8>,
+}
+
+/// Data of an account before and after a transaction (`None` if the account
+/// did not exist).
+#[serde_as]
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountChange {
+    #[serde_as(as = "DisplayFromStr")]
+    pub address: Pubkey,
+    #[serde_as(as = "Option<Base64>")]
+    pub pre: Option<Vec<u8>>,
+    #[serde_as(as = "Option<Base64>")]
+    pub post: Option<Vec<u8>>,
+}
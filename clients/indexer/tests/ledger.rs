@@ -0,0 +1,610 @@
+#![cfg(feature = "test-sbf")]
+
+#[path = "../../rust/tests/setup/mod.rs"]
+mod setup;
+
+use borsh::BorshSerialize;
+use paladin_stake_indexer::{
+    decode::EntryKind,
+    ledger::Ledger,
+    transaction::{AccountChange, ConfirmedInstruction, ConfirmedTransaction},
+};
+use paladin_stake_program_client::{
+    accounts::{Config, SolStakerStake, ValidatorStake},
+    client::StakeClient,
+    instructions::{
+        HarvestSolStakerRewardsBuilder, HarvestValidatorRewardsBuilder, SlashValidatorStakeBuilder,
+        SolStakerMoveTokens, SolStakerMoveTokensInstructionArgs, TransferStake,
+        TransferStakeInstructionArgs,
+    },
+    pdas::find_vault_pda,
+};
+use setup::{
+    calculate_stake_rewards_per_token,
+    config::{create_ata, ConfigManager},
+    harvest::setup_keeper,
+    setup,
+    sol_staker_stake::SolStakerStakeManager,
+    stake::{deactivate_stake_account, warp_to_next_epoch},
+    token::mint_to,
+    validator_stake::ValidatorStakeManager,
+};
+use solana_program_test::{tokio, ProgramTestContext};
+use solana_sdk::{
+    account::{Account, AccountSharedData},
+    clock::Clock,
+    instruction::Instruction,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    system_instruction,
+    transaction::Transaction,
+};
+use spl_associated_token_account::get_associated_token_address;
+
+/// Executes the instructions, recording the data of their accounts before and
+/// after the transaction as an indexer source would.
+async fn record(
+    context: &mut ProgramTestContext,
+    instructions: &[Instruction],
+    signers: &[&Keypair],
+) -> ConfirmedTransaction {
+    let mut addresses = Vec::new();
+    for meta in instructions
+        .iter()
+        .flat_map(|instruction| &instruction.accounts)
+    {
+        if !addresses.contains(&meta.pubkey) {
+            addresses.push(meta.pubkey);
+        }
+    }
+
+    let mut pre = Vec::new();
+    for address in &addresses {
+        let account = context.banks_client.get_account(*address).await.unwrap();
+        pre.push(account.map(|account| account.data));
+    }
+
+    let blockhash = context.get_new_latest_blockhash().await.unwrap();
+    let mut all_signers = vec![&context.payer];
+    all_signers.extend_from_slice(signers);
+    let transaction = Transaction::new_signed_with_payer(
+        instructions,
+        Some(&context.payer.pubkey()),
+        &all_signers,
+        blockhash,
+    );
+    let signature = transaction.signatures[0].to_string();
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    let clock = context.banks_client.get_sysvar::<Clock>().await.unwrap();
+    let mut accounts = Vec::new();
+    for (address, pre) in addresses.into_iter().zip(pre) {
+        let account = context.banks_client.get_account(address).await.unwrap();
+        accounts.push(AccountChange {
+            address,
+            pre,
+            post: account.map(|account| account.data),
+        });
+    }
+
+    let transaction = ConfirmedTransaction {
+        signature,
+        slot: clock.slot,
+        block_time: Some(clock.unix_timestamp),
+        instructions: instructions
+            .iter()
+            .map(|instruction| ConfirmedInstruction {
+                program_id: instruction.program_id,
+                accounts: instruction
+                    .accounts
+                    .iter()
+                    .map(|meta| meta.pubkey)
+                    .collect(),
+                data: instruction.data.clone(),
+            })
+            .collect(),
+        accounts,
+    };
+
+    // Transactions are read by the indexer as JSON lines.
+    serde_json::from_str(&serde_json::to_string(&transaction).unwrap()).unwrap()
+}
+
+#[tokio::test]
+async fn index_validator_stake_history() {
+    let mut context = setup(&[]).await;
+
+    // Given a config account and a validator stake account with 1 SOL staked.
+    let config_manager = ConfigManager::new(&mut context).await;
+    let validator_stake_manager =
+        ValidatorStakeManager::new(&mut context, &config_manager.config).await;
+    let stake = validator_stake_manager.stake;
+
+    let mut account = get_account!(context, stake);
+    let mut stake_account = ValidatorStake::from_bytes(account.data.as_ref()).unwrap();
+    stake_account.total_staked_lamports_amount = 1_000_000_000;
+    account.data = stake_account.try_to_vec().unwrap();
+    context.set_account(&stake, &account.into());
+
+    // And a token account of the validator authority with 100 tokens.
+    let authority = validator_stake_manager.authority.pubkey();
+    let token_account = get_associated_token_address(&authority, &config_manager.mint);
+    create_ata(&mut context, &authority, &config_manager.mint)
+        .await
+        .unwrap();
+    mint_to(
+        &mut context,
+        &config_manager.mint,
+        &config_manager.mint_authority,
+        &token_account,
+        100,
+    )
+    .await
+    .unwrap();
+
+    let mut client = StakeClient::new(context.banks_client.clone(), config_manager.config);
+    let mut ledger = Ledger::open_in_memory().unwrap();
+
+    // When we stake 100 tokens.
+    let ix = client.stake(&stake, &authority, 100).await.unwrap();
+    let transaction = record(&mut context, &[ix], &[&validator_stake_manager.authority]).await;
+    let stake_slot = transaction.slot;
+    assert!(ledger.index(&transaction).unwrap());
+
+    // And activate them in the next epoch.
+    warp_to_next_epoch(&mut context).await;
+    let ix = client.harvest(&stake).await.unwrap();
+    let transaction = record(&mut context, &[ix], &[]).await;
+    assert!(ledger.index(&transaction).unwrap());
+
+    // And harvest 1 SOL of rewards.
+    let transfer_ix = system_instruction::transfer(
+        &context.payer.pubkey(),
+        &config_manager.config,
+        1_000_000_000,
+    );
+    record(&mut context, &[transfer_ix], &[]).await;
+
+    let lamports_before = context.banks_client.get_balance(authority).await.unwrap();
+    let ix = client.harvest(&stake).await.unwrap();
+    let transaction = record(&mut context, &[ix], &[]).await;
+    assert!(ledger.index(&transaction).unwrap());
+    let lamports_after = context.banks_client.get_balance(authority).await.unwrap();
+
+    // And unstake 5 tokens.
+    let ix = client.unstake(&stake, 5).await.unwrap();
+    let transaction = record(&mut context, &[ix], &[&validator_stake_manager.authority]).await;
+    assert!(ledger.index(&transaction).unwrap());
+
+    // And slash 10 tokens.
+    let ix = client.slash(&stake, 10).await.unwrap();
+    let transaction = record(&mut context, &[ix], &[&config_manager.config_authority]).await;
+    assert!(ledger.index(&transaction).unwrap());
+
+    // Indexing a transaction twice is a no-op.
+    assert!(!ledger.index(&transaction).unwrap());
+
+    // Then the ledger has the history of the stake account.
+    let entries = ledger.entries(&stake).unwrap();
+    assert_eq!(
+        entries
+            .iter()
+            .map(|entry| (entry.kind, entry.amount, entry.staked_amount))
+            .collect::<Vec<_>>(),
+        [
+            (EntryKind::Stake, 100, 100),
+            (EntryKind::Harvest, 0, 100),
+            (EntryKind::Unstake, 5, 95),
+            (EntryKind::Slash, 10, 85),
+        ]
+    );
+    assert!(entries.iter().all(|entry| entry.authority == authority));
+
+    // And the harvested rewards are the lamports paid to the authority.
+    let harvest = &entries[1];
+    assert!(harvest.staking_reward > 0);
+    assert_eq!(
+        harvest.staking_reward + harvest.holder_reward,
+        lamports_after - lamports_before
+    );
+
+    // And the balance is known at every slot.
+    assert_eq!(
+        ledger.staked_amount_at(&stake, stake_slot - 1).unwrap(),
+        None
+    );
+    assert_eq!(
+        ledger.staked_amount_at(&stake, stake_slot).unwrap(),
+        Some(100)
+    );
+    assert_eq!(
+        ledger.staked_amount_at(&stake, u64::MAX >> 1).unwrap(),
+        Some(85)
+    );
+}
+
+/// Replays the `harvest_validator_rewards` scenario of the program tests.
+#[tokio::test]
+async fn index_harvest_validator_rewards() {
+    let mut context = setup(&[]).await;
+
+    // Given a config account with 26 lamports rewards and 130 staked amount.
+    let config_manager = ConfigManager::new(&mut context).await;
+    let config = config_manager.config;
+
+    // And a validator stake account with a 65 staked amount.
+    let validator_stake_manager = ValidatorStakeManager::new(&mut context, &config).await;
+    let stake = validator_stake_manager.stake;
+    let authority = validator_stake_manager.authority.pubkey();
+
+    let mut account = get_account!(context, stake);
+    let mut stake_account = ValidatorStake::from_bytes(account.data.as_ref()).unwrap();
+    stake_account.delegation.staked_amount = 65;
+    stake_account.delegation.effective_amount = 65;
+    stake_account.total_staked_lamports_amount = 50;
+    account.data = stake_account.try_to_vec().unwrap();
+    context.set_account(&stake, &account.into());
+
+    context.set_account(
+        &authority,
+        &AccountSharedData::from(Account {
+            lamports: 100_000_000,
+            ..Default::default()
+        }),
+    );
+
+    let mut account = get_account!(context, config);
+    let mut config_account = Config::from_bytes(account.data.as_ref()).unwrap();
+    config_account.token_amount_effective = 130;
+    config_account.accumulated_stake_rewards_per_token = calculate_stake_rewards_per_token(26, 130);
+    account.lamports += 26;
+    config_account.lamports_last = account.lamports;
+    account.data = config_account.try_to_vec().unwrap();
+    context.set_account(&config, &account.into());
+
+    let mut ledger = Ledger::open_in_memory().unwrap();
+
+    // When we harvest the stake rewards.
+    let ix = HarvestValidatorRewardsBuilder::new()
+        .config(config)
+        .vault_holder_rewards(config_manager.vault_holder_rewards)
+        .validator_stake(stake)
+        .validator_stake_authority(authority)
+        .instruction();
+    let transaction = record(&mut context, &[ix], &[]).await;
+    assert!(ledger.index(&transaction).unwrap());
+
+    // Then the ledger has the 13 lamports paid to the authority.
+    let account = get_account!(context, authority);
+    assert_eq!(account.lamports, 100_000_000 + 13);
+
+    let entries = ledger.entries(&stake).unwrap();
+    assert_eq!(
+        entries
+            .iter()
+            .map(|entry| (
+                entry.kind,
+                entry.staking_reward,
+                entry.holder_reward,
+                entry.staked_amount
+            ))
+            .collect::<Vec<_>>(),
+        [(EntryKind::Harvest, 13, 0, 65)]
+    );
+}
+
+/// Replays the `harvest_sync_rewards_base` scenario of the program tests.
+#[tokio::test]
+async fn index_harvest_sync_rewards_base() {
+    let mut context = setup(&[]).await;
+
+    // Given a config, validator stake and sol staker stake accounts with 1 SOL
+    // staked.
+    let config_manager = ConfigManager::new(&mut context).await;
+    let validator_stake_manager =
+        ValidatorStakeManager::new(&mut context, &config_manager.config).await;
+    let sol_staker_stake_manager = SolStakerStakeManager::new(
+        &mut context,
+        &config_manager.config,
+        &validator_stake_manager.stake,
+        &validator_stake_manager.vote,
+        1_000_000_000, // 1 SOL staked
+    )
+    .await;
+    let stake = sol_staker_stake_manager.stake;
+    let authority = sol_staker_stake_manager.authority.pubkey();
+
+    // And there are 1.3 SOL for stake rewards on the config.
+    let mut account = get_account!(context, config_manager.config);
+    let mut config_account = Config::from_bytes(account.data.as_ref()).unwrap();
+    config_account.token_amount_effective = 1_300_000_000;
+    config_account.accumulated_stake_rewards_per_token =
+        calculate_stake_rewards_per_token(1_300_000_000, 1_300_000_000);
+    account.lamports += 1_300_000_000;
+    config_account.lamports_last = account.lamports;
+    account.data = config_account.try_to_vec().unwrap();
+    context.set_account(&config_manager.config, &account.into());
+
+    // And the SOL staker stake has 1_300_000_000 tokens staked.
+    let mut account = get_account!(context, stake);
+    let mut stake_account = SolStakerStake::from_bytes(account.data.as_ref()).unwrap();
+    stake_account.delegation.staked_amount = 1_300_000_000;
+    stake_account.delegation.effective_amount = 1_300_000_000;
+    stake_account.lamports_amount = 1_000_000_000;
+    account.data = stake_account.try_to_vec().unwrap();
+    context.set_account(&stake, &account.into());
+
+    // And the native stake is deactivated.
+    deactivate_stake_account(
+        &mut context,
+        &sol_staker_stake_manager.sol_stake,
+        &sol_staker_stake_manager.authority,
+    )
+    .await;
+
+    context.set_account(
+        &authority,
+        &AccountSharedData::from(Account {
+            lamports: 100_000_000,
+            ..Default::default()
+        }),
+    );
+    let keeper = setup_keeper(&mut context);
+
+    let mut ledger = Ledger::open_in_memory().unwrap();
+
+    // When a keeper harvests the rewards syncing the SOL stake.
+    let ix = HarvestSolStakerRewardsBuilder::new()
+        .config(config_manager.config)
+        .vault_holder_rewards(config_manager.vault_holder_rewards)
+        .sol_staker_stake(stake)
+        .sol_staker_stake_authority(authority)
+        .sol_staker_native_stake(sol_staker_stake_manager.sol_stake)
+        .previous_validator_stake(validator_stake_manager.stake)
+        .previous_validator_stake_authority(validator_stake_manager.authority.pubkey())
+        .current_validator_stake(validator_stake_manager.stake)
+        .current_validator_stake_authority(validator_stake_manager.authority.pubkey())
+        .sol_stake_view_program(paladin_sol_stake_view_program_client::ID)
+        .keeper_recipient(Some(keeper))
+        .instruction();
+    let transaction = record(&mut context, &[ix], &[]).await;
+    assert!(ledger.index(&transaction).unwrap());
+
+    // Then the keeper has the sync rewards.
+    let account = get_account!(context, keeper);
+    assert_eq!(account.lamports, 100_000_000 + 1_000_000);
+
+    // And the ledger has only the rewards paid to the authority.
+    let account = get_account!(context, authority);
+    assert_eq!(account.lamports, 100_000_000 + 1_299_000_000);
+
+    let entries = ledger.entries(&stake).unwrap();
+    assert_eq!(
+        entries
+            .iter()
+            .map(|entry| (entry.kind, entry.authority, entry.staking_reward))
+            .collect::<Vec<_>>(),
+        [(EntryKind::Harvest, authority, 1_299_000_000)]
+    );
+}
+
+/// Replays the `slash_validator_stake_with_insufficient_stake_amount`
+/// scenario of the program tests.
+#[tokio::test]
+async fn index_slash_validator_stake_with_insufficient_stake_amount() {
+    let mut context = setup(&[]).await;
+
+    // Given a config and validator stake accounts.
+    let config_manager = ConfigManager::new(&mut context).await;
+    let stake_manager = ValidatorStakeManager::new(&mut context, &config_manager.config).await;
+    let stake = stake_manager.stake;
+
+    // And we set 1000 tokens to the vault account.
+    let mut account = get_account!(context, config_manager.config);
+    let mut config_account = Config::from_bytes(account.data.as_ref()).unwrap();
+    config_account.token_amount_effective = 900;
+    account.data = config_account.try_to_vec().unwrap();
+    context.set_account(&config_manager.config, &account.into());
+    mint_to(
+        &mut context,
+        &config_manager.mint,
+        &config_manager.mint_authority,
+        &config_manager.vault,
+        1000,
+    )
+    .await
+    .unwrap();
+
+    // And we set 500 active tokens on the stake account.
+    let mut account = get_account!(context, stake);
+    let mut stake_account = ValidatorStake::from_bytes(account.data.as_ref()).unwrap();
+    stake_account.delegation.staked_amount = 500;
+    stake_account.delegation.effective_amount = 500;
+    stake_account.total_staked_lamports_amount = 500;
+    account.data = stake_account.try_to_vec().unwrap();
+    context.set_account(&stake, &account.into());
+
+    let mut ledger = Ledger::open_in_memory().unwrap();
+
+    // When we slash 600 tokens from the stake account.
+    let ix = SlashValidatorStakeBuilder::new()
+        .config(config_manager.config)
+        .validator_stake(stake)
+        .validator_stake_authority(stake_manager.authority.pubkey())
+        .slash_authority(config_manager.config_authority.pubkey())
+        .mint(config_manager.mint)
+        .vault(config_manager.vault)
+        .vault_holder_rewards(config_manager.vault_holder_rewards)
+        .vault_authority(find_vault_pda(&config_manager.config).0)
+        .token_program(spl_token::ID)
+        .amount(600)
+        .instruction();
+    let transaction = record(&mut context, &[ix], &[&config_manager.config_authority]).await;
+    assert!(ledger.index(&transaction).unwrap());
+
+    // Then the ledger has the 500 tokens burned.
+    let entries = ledger.entries(&stake).unwrap();
+    assert_eq!(
+        entries
+            .iter()
+            .map(|entry| (entry.kind, entry.amount, entry.staked_amount))
+            .collect::<Vec<_>>(),
+        [(EntryKind::Slash, 500, 0)]
+    );
+}
+
+/// Replays the `transfer_to_not_empty` scenario of the program tests.
+#[tokio::test]
+async fn index_transfer_to_not_empty() {
+    let mut context = setup(&[]).await;
+
+    // Given two SOL staker stake accounts of the same authority with 10 PAL
+    // staked each.
+    let config_manager = ConfigManager::new(&mut context).await;
+    let validator_stake_manager =
+        ValidatorStakeManager::new(&mut context, &config_manager.config).await;
+    let stake_authority = Keypair::new();
+    let source = SolStakerStakeManager::new_with_authority(
+        &mut context,
+        &config_manager.config,
+        &validator_stake_manager.stake,
+        &validator_stake_manager.vote,
+        stake_authority.insecure_clone(),
+        5_000_000_000, // 5 SOL staked
+    )
+    .await;
+    let destination = SolStakerStakeManager::new_with_authority(
+        &mut context,
+        &config_manager.config,
+        &validator_stake_manager.stake,
+        &validator_stake_manager.vote,
+        stake_authority.insecure_clone(),
+        5_000_000_000, // 5 SOL staked
+    )
+    .await;
+
+    for stake in [source.stake, destination.stake] {
+        let mut account = get_account!(context, stake);
+        let mut stake_account = SolStakerStake::from_bytes(&account.data).unwrap();
+        stake_account.delegation.staked_amount = 10;
+        account.data = stake_account.try_to_vec().unwrap();
+        context.set_account(&stake, &account.into());
+    }
+
+    let mut ledger = Ledger::open_in_memory().unwrap();
+
+    // When we move 5 PAL to the destination.
+    let ix = SolStakerMoveTokens {
+        config: config_manager.config,
+        vault_holder_rewards: config_manager.vault_holder_rewards,
+        sol_staker_authority: stake_authority.pubkey(),
+        source_sol_staker_stake: source.stake,
+        destination_sol_staker_stake: destination.stake,
+    }
+    .instruction(SolStakerMoveTokensInstructionArgs { amount: 5 });
+    let transaction = record(&mut context, &[ix], &[&stake_authority]).await;
+    assert!(ledger.index(&transaction).unwrap());
+
+    // Then the ledger has both sides of the move for the authority.
+    let entries = ledger
+        .entries_by_authority(&stake_authority.pubkey())
+        .unwrap();
+    assert_eq!(
+        entries
+            .iter()
+            .map(|entry| (entry.kind, entry.stake, entry.amount, entry.staked_amount))
+            .collect::<Vec<_>>(),
+        [
+            (EntryKind::MoveOut, source.stake, 5, 5),
+            (EntryKind::MoveIn, destination.stake, 5, 15),
+        ]
+    );
+
+    // And other authorities have no entries.
+    assert!(ledger
+        .entries_by_authority(&Pubkey::new_unique())
+        .unwrap()
+        .is_empty());
+}
+
+/// Replays the `transfer_stake_between_authorities` scenario of the program
+/// tests.
+#[tokio::test]
+async fn index_transfer_stake_between_authorities() {
+    let mut context = setup(&[]).await;
+
+    // Given two SOL staker stake accounts with different authorities, the
+    // source with 10 PAL staked.
+    let config_manager = ConfigManager::new(&mut context).await;
+    let validator_stake_manager =
+        ValidatorStakeManager::new(&mut context, &config_manager.config).await;
+    let source = SolStakerStakeManager::new(
+        &mut context,
+        &config_manager.config,
+        &validator_stake_manager.stake,
+        &validator_stake_manager.vote,
+        5_000_000_000, // 5 SOL staked
+    )
+    .await;
+    let destination = SolStakerStakeManager::new(
+        &mut context,
+        &config_manager.config,
+        &validator_stake_manager.stake,
+        &validator_stake_manager.vote,
+        5_000_000_000, // 5 SOL staked
+    )
+    .await;
+
+    let mut account = get_account!(context, source.stake);
+    let mut stake_account = SolStakerStake::from_bytes(&account.data).unwrap();
+    stake_account.delegation.staked_amount = 10;
+    stake_account.delegation.unstake_cooldown = 50;
+    account.data = stake_account.try_to_vec().unwrap();
+    context.set_account(&source.stake, &account.into());
+
+    let mut ledger = Ledger::open_in_memory().unwrap();
+
+    // When both authorities transfer 4 PAL to the destination.
+    let ix = TransferStake {
+        config: config_manager.config,
+        vault_holder_rewards: config_manager.vault_holder_rewards,
+        source_sol_staker_stake: source.stake,
+        source_authority: source.authority.pubkey(),
+        destination_sol_staker_stake: destination.stake,
+        destination_authority: destination.authority.pubkey(),
+    }
+    .instruction(TransferStakeInstructionArgs { amount: 4 });
+    let transaction = record(
+        &mut context,
+        &[ix],
+        &[&source.authority, &destination.authority],
+    )
+    .await;
+    assert!(ledger.index(&transaction).unwrap());
+
+    // Then each authority has its side of the transfer.
+    let entries = ledger
+        .entries_by_authority(&source.authority.pubkey())
+        .unwrap();
+    assert_eq!(
+        entries
+            .iter()
+            .map(|entry| (entry.kind, entry.stake, entry.amount, entry.staked_amount))
+            .collect::<Vec<_>>(),
+        [(EntryKind::MoveOut, source.stake, 4, 6)]
+    );
+    let entries = ledger
+        .entries_by_authority(&destination.authority.pubkey())
+        .unwrap();
+    assert_eq!(
+        entries
+            .iter()
+            .map(|entry| (entry.kind, entry.stake, entry.amount, entry.staked_amount))
+            .collect::<Vec<_>>(),
+        [(EntryKind::MoveIn, destination.stake, 4, 4)]
+    );
+}
@@ -63,7 +63,19 @@ export type Config = {
   accumulatedStakeRewardsPerToken: bigint;
   maxDeactivationBasisPoints: number;
   vaultAuthorityBump: number;
+  version: number;
   padding: Array<number>;
+  rewardStreamingPeriodSeconds: bigint;
+  rewardPeriodEnd: bigint;
+  rewardLastUpdate: bigint;
+  rewardRate: bigint;
+  stakeRewardsRemainder: bigint;
+  undistributedRewards: bigint;
+  feeRecipient: NullableAddress;
+  unclaimedProtocolFees: bigint;
+  protocolFeeBasisPoints: number;
+  feePadding: Array<number>;
+  rewardStreamRemainder: bigint;
 };
 
 export type ConfigArgs = {
@@ -79,7 +91,19 @@ export type ConfigArgs = {
   accumulatedStakeRewardsPerToken: number | bigint;
   maxDeactivationBasisPoints: number;
   vaultAuthorityBump: number;
+  version: number;
   padding: Array<number>;
+  rewardStreamingPeriodSeconds: number | bigint;
+  rewardPeriodEnd: number | bigint;
+  rewardLastUpdate: number | bigint;
+  rewardRate: number | bigint;
+  stakeRewardsRemainder: number | bigint;
+  undistributedRewards: number | bigint;
+  feeRecipient: NullableAddressArgs;
+  unclaimedProtocolFees: number | bigint;
+  protocolFeeBasisPoints: number;
+  feePadding: Array<number>;
+  rewardStreamRemainder: number | bigint;
 };
 
 export function getConfigEncoder(): Encoder<ConfigArgs> {
@@ -96,7 +120,19 @@ export function getConfigEncoder(): Encoder<ConfigArgs> {
     ['accumulatedStakeRewardsPerToken', getU128Encoder()],
     ['maxDeactivationBasisPoints', getU16Encoder()],
     ['vaultAuthorityBump', getU8Encoder()],
-    ['padding', getArrayEncoder(getU8Encoder(), { size: 5 })],
+    ['version', getU8Encoder()],
+    ['padding', getArrayEncoder(getU8Encoder(), { size: 4 })],
+    ['rewardStreamingPeriodSeconds', getU64Encoder()],
+    ['rewardPeriodEnd', getU64Encoder()],
+    ['rewardLastUpdate', getU64Encoder()],
+    ['rewardRate', getU128Encoder()],
+    ['stakeRewardsRemainder', getU64Encoder()],
+    ['undistributedRewards', getU64Encoder()],
+    ['feeRecipient', getNullableAddressEncoder()],
+    ['unclaimedProtocolFees', getU64Encoder()],
+    ['protocolFeeBasisPoints', getU16Encoder()],
+    ['feePadding', getArrayEncoder(getU8Encoder(), { size: 6 })],
+    ['rewardStreamRemainder', getU64Encoder()],
   ]);
 }
 
@@ -114,7 +150,19 @@ export function getConfigDecoder(): Decoder<Config> {
     ['accumulatedStakeRewardsPerToken', getU128Decoder()],
     ['maxDeactivationBasisPoints', getU16Decoder()],
     ['vaultAuthorityBump', getU8Decoder()],
-    ['padding', getArrayDecoder(getU8Decoder(), { size: 5 })],
+    ['version', getU8Decoder()],
+    ['padding', getArrayDecoder(getU8Decoder(), { size: 4 })],
+    ['rewardStreamingPeriodSeconds', getU64Decoder()],
+    ['rewardPeriodEnd', getU64Decoder()],
+    ['rewardLastUpdate', getU64Decoder()],
+    ['rewardRate', getU128Decoder()],
+    ['stakeRewardsRemainder', getU64Decoder()],
+    ['undistributedRewards', getU64Decoder()],
+    ['feeRecipient', getNullableAddressDecoder()],
+    ['unclaimedProtocolFees', getU64Decoder()],
+    ['protocolFeeBasisPoints', getU16Decoder()],
+    ['feePadding', getArrayDecoder(getU8Decoder(), { size: 6 })],
+    ['rewardStreamRemainder', getU64Decoder()],
   ]);
 }
 
@@ -176,5 +224,5 @@ export async function fetchAllMaybeConfig(
 }
 
 export function getConfigSize(): number {
-  return 192;
+  return 304;
 }
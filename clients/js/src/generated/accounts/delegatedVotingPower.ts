@@ -0,0 +1,169 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  assertAccountExists,
+  assertAccountsExist,
+  combineCodec,
+  decodeAccount,
+  fetchEncodedAccount,
+  fetchEncodedAccounts,
+  getAddressDecoder,
+  getAddressEncoder,
+  getArrayDecoder,
+  getArrayEncoder,
+  getStructDecoder,
+  getStructEncoder,
+  getU64Decoder,
+  getU64Encoder,
+  getU8Decoder,
+  getU8Encoder,
+  type Account,
+  type Address,
+  type Codec,
+  type Decoder,
+  type EncodedAccount,
+  type Encoder,
+  type FetchAccountConfig,
+  type FetchAccountsConfig,
+  type MaybeAccount,
+  type MaybeEncodedAccount,
+} from '@solana/web3.js';
+import {
+  getVotingPowerCheckpointsDecoder,
+  getVotingPowerCheckpointsEncoder,
+  type VotingPowerCheckpoints,
+  type VotingPowerCheckpointsArgs,
+} from '../types';
+
+export type DelegatedVotingPower = {
+  discriminator: Array<number>;
+  config: Address;
+  delegate: Address;
+  votingPower: bigint;
+  version: number;
+  padding: Array<number>;
+  checkpoints: VotingPowerCheckpoints;
+};
+
+export type DelegatedVotingPowerArgs = {
+  discriminator: Array<number>;
+  config: Address;
+  delegate: Address;
+  votingPower: number | bigint;
+  version: number;
+  padding: Array<number>;
+  checkpoints: VotingPowerCheckpointsArgs;
+};
+
+export function getDelegatedVotingPowerEncoder(): Encoder<DelegatedVotingPowerArgs> {
+  return getStructEncoder([
+    ['discriminator', getArrayEncoder(getU8Encoder(), { size: 8 })],
+    ['config', getAddressEncoder()],
+    ['delegate', getAddressEncoder()],
+    ['votingPower', getU64Encoder()],
+    ['version', getU8Encoder()],
+    ['padding', getArrayEncoder(getU8Encoder(), { size: 7 })],
+    ['checkpoints', getVotingPowerCheckpointsEncoder()],
+  ]);
+}
+
+export function getDelegatedVotingPowerDecoder(): Decoder<DelegatedVotingPower> {
+  return getStructDecoder([
+    ['discriminator', getArrayDecoder(getU8Decoder(), { size: 8 })],
+    ['config', getAddressDecoder()],
+    ['delegate', getAddressDecoder()],
+    ['votingPower', getU64Decoder()],
+    ['version', getU8Decoder()],
+    ['padding', getArrayDecoder(getU8Decoder(), { size: 7 })],
+    ['checkpoints', getVotingPowerCheckpointsDecoder()],
+  ]);
+}
+
+export function getDelegatedVotingPowerCodec(): Codec<
+  DelegatedVotingPowerArgs,
+  DelegatedVotingPower
+> {
+  return combineCodec(
+    getDelegatedVotingPowerEncoder(),
+    getDelegatedVotingPowerDecoder()
+  );
+}
+
+export function decodeDelegatedVotingPower<TAddress extends string = string>(
+  encodedAccount: EncodedAccount<TAddress>
+): Account<DelegatedVotingPower, TAddress>;
+export function decodeDelegatedVotingPower<TAddress extends string = string>(
+  encodedAccount: MaybeEncodedAccount<TAddress>
+): MaybeAccount<DelegatedVotingPower, TAddress>;
+export function decodeDelegatedVotingPower<TAddress extends string = string>(
+  encodedAccount: EncodedAccount<TAddress> | MaybeEncodedAccount<TAddress>
+):
+  | Account<DelegatedVotingPower, TAddress>
+  | MaybeAccount<DelegatedVotingPower, TAddress> {
+  return decodeAccount(
+    encodedAccount as MaybeEncodedAccount<TAddress>,
+    getDelegatedVotingPowerDecoder()
+  );
+}
+
+export async function fetchDelegatedVotingPower<
+  TAddress extends string = string,
+>(
+  rpc: Parameters<typeof fetchEncodedAccount>[0],
+  address: Address<TAddress>,
+  config?: FetchAccountConfig
+): Promise<Account<DelegatedVotingPower, TAddress>> {
+  const maybeAccount = await fetchMaybeDelegatedVotingPower(
+    rpc,
+    address,
+    config
+  );
+  assertAccountExists(maybeAccount);
+  return maybeAccount;
+}
+
+export async function fetchMaybeDelegatedVotingPower<
+  TAddress extends string = string,
+>(
+  rpc: Parameters<typeof fetchEncodedAccount>[0],
+  address: Address<TAddress>,
+  config?: FetchAccountConfig
+): Promise<MaybeAccount<DelegatedVotingPower, TAddress>> {
+  const maybeAccount = await fetchEncodedAccount(rpc, address, config);
+  return decodeDelegatedVotingPower(maybeAccount);
+}
+
+export async function fetchAllDelegatedVotingPower(
+  rpc: Parameters<typeof fetchEncodedAccounts>[0],
+  addresses: Array<Address>,
+  config?: FetchAccountsConfig
+): Promise<Account<DelegatedVotingPower>[]> {
+  const maybeAccounts = await fetchAllMaybeDelegatedVotingPower(
+    rpc,
+    addresses,
+    config
+  );
+  assertAccountsExist(maybeAccounts);
+  return maybeAccounts;
+}
+
+export async function fetchAllMaybeDelegatedVotingPower(
+  rpc: Parameters<typeof fetchEncodedAccounts>[0],
+  addresses: Array<Address>,
+  config?: FetchAccountsConfig
+): Promise<MaybeAccount<DelegatedVotingPower>[]> {
+  const maybeAccounts = await fetchEncodedAccounts(rpc, addresses, config);
+  return maybeAccounts.map((maybeAccount) =>
+    decodeDelegatedVotingPower(maybeAccount)
+  );
+}
+
+export function getDelegatedVotingPowerSize(): number {
+  return 352;
+}
@@ -7,5 +7,8 @@
  */
 
 export * from './config';
+export * from './delegatedVotingPower';
+export * from './liquidStakePool';
+export * from './receiptRewards';
 export * from './solStakerStake';
 export * from './validatorStake';
@@ -0,0 +1,168 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  assertAccountExists,
+  assertAccountsExist,
+  combineCodec,
+  decodeAccount,
+  fetchEncodedAccount,
+  fetchEncodedAccounts,
+  getAddressDecoder,
+  getAddressEncoder,
+  getArrayDecoder,
+  getArrayEncoder,
+  getStructDecoder,
+  getStructEncoder,
+  getU128Decoder,
+  getU128Encoder,
+  getU64Decoder,
+  getU64Encoder,
+  getU8Decoder,
+  getU8Encoder,
+  type Account,
+  type Address,
+  type Codec,
+  type Decoder,
+  type EncodedAccount,
+  type Encoder,
+  type FetchAccountConfig,
+  type FetchAccountsConfig,
+  type MaybeAccount,
+  type MaybeEncodedAccount,
+} from '@solana/web3.js';
+
+export type LiquidStakePool = {
+  discriminator: Array<number>;
+  config: Address;
+  validatorStake: Address;
+  receiptMint: Address;
+  accumulatedRewardsPerToken: bigint;
+  rewardsRemainder: bigint;
+  lamportsLast: bigint;
+  trackedSupply: bigint;
+  version: number;
+  padding: Array<number>;
+};
+
+export type LiquidStakePoolArgs = {
+  discriminator: Array<number>;
+  config: Address;
+  validatorStake: Address;
+  receiptMint: Address;
+  accumulatedRewardsPerToken: number | bigint;
+  rewardsRemainder: number | bigint;
+  lamportsLast: number | bigint;
+  trackedSupply: number | bigint;
+  version: number;
+  padding: Array<number>;
+};
+
+export function getLiquidStakePoolEncoder(): Encoder<LiquidStakePoolArgs> {
+  return getStructEncoder([
+    ['discriminator', getArrayEncoder(getU8Encoder(), { size: 8 })],
+    ['config', getAddressEncoder()],
+    ['validatorStake', getAddressEncoder()],
+    ['receiptMint', getAddressEncoder()],
+    ['accumulatedRewardsPerToken', getU128Encoder()],
+    ['rewardsRemainder', getU64Encoder()],
+    ['lamportsLast', getU64Encoder()],
+    ['trackedSupply', getU64Encoder()],
+    ['version', getU8Encoder()],
+    ['padding', getArrayEncoder(getU8Encoder(), { size: 7 })],
+  ]);
+}
+
+export function getLiquidStakePoolDecoder(): Decoder<LiquidStakePool> {
+  return getStructDecoder([
+    ['discriminator', getArrayDecoder(getU8Decoder(), { size: 8 })],
+    ['config', getAddressDecoder()],
+    ['validatorStake', getAddressDecoder()],
+    ['receiptMint', getAddressDecoder()],
+    ['accumulatedRewardsPerToken', getU128Decoder()],
+    ['rewardsRemainder', getU64Decoder()],
+    ['lamportsLast', getU64Decoder()],
+    ['trackedSupply', getU64Decoder()],
+    ['version', getU8Decoder()],
+    ['padding', getArrayDecoder(getU8Decoder(), { size: 7 })],
+  ]);
+}
+
+export function getLiquidStakePoolCodec(): Codec<
+  LiquidStakePoolArgs,
+  LiquidStakePool
+> {
+  return combineCodec(getLiquidStakePoolEncoder(), getLiquidStakePoolDecoder());
+}
+
+export function decodeLiquidStakePool<TAddress extends string = string>(
+  encodedAccount: EncodedAccount<TAddress>
+): Account<LiquidStakePool, TAddress>;
+export function decodeLiquidStakePool<TAddress extends string = string>(
+  encodedAccount: MaybeEncodedAccount<TAddress>
+): MaybeAccount<LiquidStakePool, TAddress>;
+export function decodeLiquidStakePool<TAddress extends string = string>(
+  encodedAccount: EncodedAccount<TAddress> | MaybeEncodedAccount<TAddress>
+):
+  | Account<LiquidStakePool, TAddress>
+  | MaybeAccount<LiquidStakePool, TAddress> {
+  return decodeAccount(
+    encodedAccount as MaybeEncodedAccount<TAddress>,
+    getLiquidStakePoolDecoder()
+  );
+}
+
+export async function fetchLiquidStakePool<TAddress extends string = string>(
+  rpc: Parameters<typeof fetchEncodedAccount>[0],
+  address: Address<TAddress>,
+  config?: FetchAccountConfig
+): Promise<Account<LiquidStakePool, TAddress>> {
+  const maybeAccount = await fetchMaybeLiquidStakePool(rpc, address, config);
+  assertAccountExists(maybeAccount);
+  return maybeAccount;
+}
+
+export async function fetchMaybeLiquidStakePool<
+  TAddress extends string = string,
+>(
+  rpc: Parameters<typeof fetchEncodedAccount>[0],
+  address: Address<TAddress>,
+  config?: FetchAccountConfig
+): Promise<MaybeAccount<LiquidStakePool, TAddress>> {
+  const maybeAccount = await fetchEncodedAccount(rpc, address, config);
+  return decodeLiquidStakePool(maybeAccount);
+}
+
+export async function fetchAllLiquidStakePool(
+  rpc: Parameters<typeof fetchEncodedAccounts>[0],
+  addresses: Array<Address>,
+  config?: FetchAccountsConfig
+): Promise<Account<LiquidStakePool>[]> {
+  const maybeAccounts = await fetchAllMaybeLiquidStakePool(
+    rpc,
+    addresses,
+    config
+  );
+  assertAccountsExist(maybeAccounts);
+  return maybeAccounts;
+}
+
+export async function fetchAllMaybeLiquidStakePool(
+  rpc: Parameters<typeof fetchEncodedAccounts>[0],
+  addresses: Array<Address>,
+  config?: FetchAccountsConfig
+): Promise<MaybeAccount<LiquidStakePool>[]> {
+  const maybeAccounts = await fetchEncodedAccounts(rpc, addresses, config);
+  return maybeAccounts.map((maybeAccount) =>
+    decodeLiquidStakePool(maybeAccount)
+  );
+}
+
+export function getLiquidStakePoolSize(): number {
+  return 152;
+}
@@ -0,0 +1,166 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  assertAccountExists,
+  assertAccountsExist,
+  combineCodec,
+  decodeAccount,
+  fetchEncodedAccount,
+  fetchEncodedAccounts,
+  getAddressDecoder,
+  getAddressEncoder,
+  getArrayDecoder,
+  getArrayEncoder,
+  getStructDecoder,
+  getStructEncoder,
+  getU128Decoder,
+  getU128Encoder,
+  getU64Decoder,
+  getU64Encoder,
+  getU8Decoder,
+  getU8Encoder,
+  type Account,
+  type Address,
+  type Codec,
+  type Decoder,
+  type EncodedAccount,
+  type Encoder,
+  type FetchAccountConfig,
+  type FetchAccountsConfig,
+  type MaybeAccount,
+  type MaybeEncodedAccount,
+} from '@solana/web3.js';
+
+export type ReceiptRewards = {
+  discriminator: Array<number>;
+  liquidStakePool: Address;
+  tokenAccount: Address;
+  lastAccumulatedRewardsPerToken: bigint;
+  balance: bigint;
+  unclaimedRewards: bigint;
+  activatingBalance: bigint;
+  activationEpoch: bigint;
+  version: number;
+  padding: Array<number>;
+};
+
+export type ReceiptRewardsArgs = {
+  discriminator: Array<number>;
+  liquidStakePool: Address;
+  tokenAccount: Address;
+  lastAccumulatedRewardsPerToken: number | bigint;
+  balance: number | bigint;
+  unclaimedRewards: number | bigint;
+  activatingBalance: number | bigint;
+  activationEpoch: number | bigint;
+  version: number;
+  padding: Array<number>;
+};
+
+export function getReceiptRewardsEncoder(): Encoder<ReceiptRewardsArgs> {
+  return getStructEncoder([
+    ['discriminator', getArrayEncoder(getU8Encoder(), { size: 8 })],
+    ['liquidStakePool', getAddressEncoder()],
+    ['tokenAccount', getAddressEncoder()],
+    ['lastAccumulatedRewardsPerToken', getU128Encoder()],
+    ['balance', getU64Encoder()],
+    ['unclaimedRewards', getU64Encoder()],
+    ['activatingBalance', getU64Encoder()],
+    ['activationEpoch', getU64Encoder()],
+    ['version', getU8Encoder()],
+    ['padding', getArrayEncoder(getU8Encoder(), { size: 7 })],
+  ]);
+}
+
+export function getReceiptRewardsDecoder(): Decoder<ReceiptRewards> {
+  return getStructDecoder([
+    ['discriminator', getArrayDecoder(getU8Decoder(), { size: 8 })],
+    ['liquidStakePool', getAddressDecoder()],
+    ['tokenAccount', getAddressDecoder()],
+    ['lastAccumulatedRewardsPerToken', getU128Decoder()],
+    ['balance', getU64Decoder()],
+    ['unclaimedRewards', getU64Decoder()],
+    ['activatingBalance', getU64Decoder()],
+    ['activationEpoch', getU64Decoder()],
+    ['version', getU8Decoder()],
+    ['padding', getArrayDecoder(getU8Decoder(), { size: 7 })],
+  ]);
+}
+
+export function getReceiptRewardsCodec(): Codec<
+  ReceiptRewardsArgs,
+  ReceiptRewards
+> {
+  return combineCodec(getReceiptRewardsEncoder(), getReceiptRewardsDecoder());
+}
+
+export function decodeReceiptRewards<TAddress extends string = string>(
+  encodedAccount: EncodedAccount<TAddress>
+): Account<ReceiptRewards, TAddress>;
+export function decodeReceiptRewards<TAddress extends string = string>(
+  encodedAccount: MaybeEncodedAccount<TAddress>
+): MaybeAccount<ReceiptRewards, TAddress>;
+export function decodeReceiptRewards<TAddress extends string = string>(
+  encodedAccount: EncodedAccount<TAddress> | MaybeEncodedAccount<TAddress>
+): Account<ReceiptRewards, TAddress> | MaybeAccount<ReceiptRewards, TAddress> {
+  return decodeAccount(
+    encodedAccount as MaybeEncodedAccount<TAddress>,
+    getReceiptRewardsDecoder()
+  );
+}
+
+export async function fetchReceiptRewards<TAddress extends string = string>(
+  rpc: Parameters<typeof fetchEncodedAccount>[0],
+  address: Address<TAddress>,
+  config?: FetchAccountConfig
+): Promise<Account<ReceiptRewards, TAddress>> {
+  const maybeAccount = await fetchMaybeReceiptRewards(rpc, address, config);
+  assertAccountExists(maybeAccount);
+  return maybeAccount;
+}
+
+export async function fetchMaybeReceiptRewards<
+  TAddress extends string = string,
+>(
+  rpc: Parameters<typeof fetchEncodedAccount>[0],
+  address: Address<TAddress>,
+  config?: FetchAccountConfig
+): Promise<MaybeAccount<ReceiptRewards, TAddress>> {
+  const maybeAccount = await fetchEncodedAccount(rpc, address, config);
+  return decodeReceiptRewards(maybeAccount);
+}
+
+export async function fetchAllReceiptRewards(
+  rpc: Parameters<typeof fetchEncodedAccounts>[0],
+  addresses: Array<Address>,
+  config?: FetchAccountsConfig
+): Promise<Account<ReceiptRewards>[]> {
+  const maybeAccounts = await fetchAllMaybeReceiptRewards(
+    rpc,
+    addresses,
+    config
+  );
+  assertAccountsExist(maybeAccounts);
+  return maybeAccounts;
+}
+
+export async function fetchAllMaybeReceiptRewards(
+  rpc: Parameters<typeof fetchEncodedAccounts>[0],
+  addresses: Array<Address>,
+  config?: FetchAccountsConfig
+): Promise<MaybeAccount<ReceiptRewards>[]> {
+  const maybeAccounts = await fetchEncodedAccounts(rpc, addresses, config);
+  return maybeAccounts.map((maybeAccount) =>
+    decodeReceiptRewards(maybeAccount)
+  );
+}
+
+export function getReceiptRewardsSize(): number {
+  return 128;
+}
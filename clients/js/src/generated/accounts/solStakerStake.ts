@@ -35,10 +35,24 @@ import {
   type MaybeEncodedAccount,
 } from '@solana/web3.js';
 import {
+  getNullableAddressDecoder,
+  getNullableAddressEncoder,
+  type NullableAddress,
+  type NullableAddressArgs,
+} from '../../hooked';
+import {
+  getActivationDecoder,
+  getActivationEncoder,
   getDelegationDecoder,
   getDelegationEncoder,
+  getVotingPowerCheckpointsDecoder,
+  getVotingPowerCheckpointsEncoder,
+  type Activation,
+  type ActivationArgs,
   type Delegation,
   type DelegationArgs,
+  type VotingPowerCheckpoints,
+  type VotingPowerCheckpointsArgs,
 } from '../types';
 
 export type SolStakerStake = {
@@ -46,6 +60,13 @@ export type SolStakerStake = {
   delegation: Delegation;
   lamportsAmount: bigint;
   solStake: Address;
+  version: number;
+  awaitingValidatorStake: number;
+  padding: Array<number>;
+  checkpoints: VotingPowerCheckpoints;
+  voteDelegate: NullableAddress;
+  activation: Activation;
+  delegatedVotingPower: bigint;
 };
 
 export type SolStakerStakeArgs = {
@@ -53,6 +74,13 @@ export type SolStakerStakeArgs = {
   delegation: DelegationArgs;
   lamportsAmount: number | bigint;
   solStake: Address;
+  version: number;
+  awaitingValidatorStake: number;
+  padding: Array<number>;
+  checkpoints: VotingPowerCheckpointsArgs;
+  voteDelegate: NullableAddressArgs;
+  activation: ActivationArgs;
+  delegatedVotingPower: number | bigint;
 };
 
 export function getSolStakerStakeEncoder(): Encoder<SolStakerStakeArgs> {
@@ -61,6 +89,13 @@ export function getSolStakerStakeEncoder(): Encoder<SolStakerStakeArgs> {
     ['delegation', getDelegationEncoder()],
     ['lamportsAmount', getU64Encoder()],
     ['solStake', getAddressEncoder()],
+    ['version', getU8Encoder()],
+    ['awaitingValidatorStake', getU8Encoder()],
+    ['padding', getArrayEncoder(getU8Encoder(), { size: 6 })],
+    ['checkpoints', getVotingPowerCheckpointsEncoder()],
+    ['voteDelegate', getNullableAddressEncoder()],
+    ['activation', getActivationEncoder()],
+    ['delegatedVotingPower', getU64Encoder()],
   ]);
 }
 
@@ -70,6 +105,13 @@ export function getSolStakerStakeDecoder(): Decoder<SolStakerStake> {
     ['delegation', getDelegationDecoder()],
     ['lamportsAmount', getU64Decoder()],
     ['solStake', getAddressDecoder()],
+    ['version', getU8Decoder()],
+    ['awaitingValidatorStake', getU8Decoder()],
+    ['padding', getArrayDecoder(getU8Decoder(), { size: 6 })],
+    ['checkpoints', getVotingPowerCheckpointsDecoder()],
+    ['voteDelegate', getNullableAddressDecoder()],
+    ['activation', getActivationDecoder()],
+    ['delegatedVotingPower', getU64Decoder()],
   ]);
 }
 
@@ -142,5 +184,5 @@ export async function fetchAllMaybeSolStakerStake(
 }
 
 export function getSolStakerStakeSize(): number {
-  return 168;
+  return 496;
 }
@@ -33,10 +33,24 @@ import {
   type MaybeEncodedAccount,
 } from '@solana/web3.js';
 import {
+  getNullableAddressDecoder,
+  getNullableAddressEncoder,
+  type NullableAddress,
+  type NullableAddressArgs,
+} from '../../hooked';
+import {
+  getActivationDecoder,
+  getActivationEncoder,
   getDelegationDecoder,
   getDelegationEncoder,
+  getVotingPowerCheckpointsDecoder,
+  getVotingPowerCheckpointsEncoder,
+  type Activation,
+  type ActivationArgs,
   type Delegation,
   type DelegationArgs,
+  type VotingPowerCheckpoints,
+  type VotingPowerCheckpointsArgs,
 } from '../types';
 
 export type ValidatorStake = {
@@ -44,6 +58,12 @@ export type ValidatorStake = {
   delegation: Delegation;
   totalStakedLamportsAmount: bigint;
   totalStakedLamportsAmountMin: bigint;
+  version: number;
+  padding: Array<number>;
+  checkpoints: VotingPowerCheckpoints;
+  voteDelegate: NullableAddress;
+  activation: Activation;
+  delegatedVotingPower: bigint;
 };
 
 export type ValidatorStakeArgs = {
@@ -51,6 +71,12 @@ export type ValidatorStakeArgs = {
   delegation: DelegationArgs;
   totalStakedLamportsAmount: number | bigint;
   totalStakedLamportsAmountMin: number | bigint;
+  version: number;
+  padding: Array<number>;
+  checkpoints: VotingPowerCheckpointsArgs;
+  voteDelegate: NullableAddressArgs;
+  activation: ActivationArgs;
+  delegatedVotingPower: number | bigint;
 };
 
 export function getValidatorStakeEncoder(): Encoder<ValidatorStakeArgs> {
@@ -59,6 +85,12 @@ export function getValidatorStakeEncoder(): Encoder<ValidatorStakeArgs> {
     ['delegation', getDelegationEncoder()],
     ['totalStakedLamportsAmount', getU64Encoder()],
     ['totalStakedLamportsAmountMin', getU64Encoder()],
+    ['version', getU8Encoder()],
+    ['padding', getArrayEncoder(getU8Encoder(), { size: 7 })],
+    ['checkpoints', getVotingPowerCheckpointsEncoder()],
+    ['voteDelegate', getNullableAddressEncoder()],
+    ['activation', getActivationEncoder()],
+    ['delegatedVotingPower', getU64Encoder()],
   ]);
 }
 
@@ -68,6 +100,12 @@ export function getValidatorStakeDecoder(): Decoder<ValidatorStake> {
     ['delegation', getDelegationDecoder()],
     ['totalStakedLamportsAmount', getU64Decoder()],
     ['totalStakedLamportsAmountMin', getU64Decoder()],
+    ['version', getU8Decoder()],
+    ['padding', getArrayDecoder(getU8Decoder(), { size: 7 })],
+    ['checkpoints', getVotingPowerCheckpointsDecoder()],
+    ['voteDelegate', getNullableAddressDecoder()],
+    ['activation', getActivationDecoder()],
+    ['delegatedVotingPower', getU64Decoder()],
   ]);
 }
 
@@ -140,5 +178,5 @@ export async function fetchAllMaybeValidatorStake(
 }
 
 export function getValidatorStakeSize(): number {
-  return 144;
+  return 472;
 }
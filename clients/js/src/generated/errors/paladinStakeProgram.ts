@@ -66,8 +66,17 @@ export const PALADIN_STAKE_PROGRAM_ERROR__INCORRECT_VAULT_PDA_ACCOUNT = 0x17; //
 export const PALADIN_STAKE_PROGRAM_ERROR__INVALID_VAULT_PDA_OWNER = 0x18; // 24
 /** InvalidVaultHolderRewardsSeeds: Invalid vault holder rewards seeds */
 export const PALADIN_STAKE_PROGRAM_ERROR__INVALID_VAULT_HOLDER_REWARDS_SEEDS = 0x19; // 25
+/** AccountMigrationRequired: Account must be migrated to the current layout */
+export const PALADIN_STAKE_PROGRAM_ERROR__ACCOUNT_MIGRATION_REQUIRED = 0x1a; // 26
+/** InvalidMintExtension: Invalid mint extension */
+export const PALADIN_STAKE_PROGRAM_ERROR__INVALID_MINT_EXTENSION = 0x1b; // 27
+/** VotingPowerUnavailable: Voting power history is not available for the slot */
+export const PALADIN_STAKE_PROGRAM_ERROR__VOTING_POWER_UNAVAILABLE = 0x1c; // 28
+/** MissingDelegatedVotingPower: Missing delegated voting power account */
+export const PALADIN_STAKE_PROGRAM_ERROR__MISSING_DELEGATED_VOTING_POWER = 0x1d; // 29
 
 export type PaladinStakeProgramError =
+  | typeof PALADIN_STAKE_PROGRAM_ERROR__ACCOUNT_MIGRATION_REQUIRED
   | typeof PALADIN_STAKE_PROGRAM_ERROR__ACTIVE_UNSTAKE_COOLDOWN
   | typeof PALADIN_STAKE_PROGRAM_ERROR__AMOUNT_GREATER_THAN_ZERO
   | typeof PALADIN_STAKE_PROGRAM_ERROR__AUTHORITY_NOT_SET
@@ -84,6 +93,7 @@ export type PaladinStakeProgramError =
   | typeof PALADIN_STAKE_PROGRAM_ERROR__INVALID_DESTINATION_ACCOUNT
   | typeof PALADIN_STAKE_PROGRAM_ERROR__INVALID_HOLDER_REWARDS
   | typeof PALADIN_STAKE_PROGRAM_ERROR__INVALID_MINT
+  | typeof PALADIN_STAKE_PROGRAM_ERROR__INVALID_MINT_EXTENSION
   | typeof PALADIN_STAKE_PROGRAM_ERROR__INVALID_SLASH_AMOUNT
   | typeof PALADIN_STAKE_PROGRAM_ERROR__INVALID_TOKEN_ACCOUNT_EXTENSION
   | typeof PALADIN_STAKE_PROGRAM_ERROR__INVALID_TOKEN_OWNER
@@ -91,15 +101,18 @@ export type PaladinStakeProgramError =
   | typeof PALADIN_STAKE_PROGRAM_ERROR__INVALID_VAULT_HOLDER_REWARDS_SEEDS
   | typeof PALADIN_STAKE_PROGRAM_ERROR__INVALID_VAULT_PDA_OWNER
   | typeof PALADIN_STAKE_PROGRAM_ERROR__MAXIMUM_DEACTIVATION_AMOUNT_EXCEEDED
+  | typeof PALADIN_STAKE_PROGRAM_ERROR__MISSING_DELEGATED_VOTING_POWER
   | typeof PALADIN_STAKE_PROGRAM_ERROR__MISSING_TRANSFER_HOOK
   | typeof PALADIN_STAKE_PROGRAM_ERROR__TOTAL_STAKE_AMOUNT_EXCEEDS_SOL_LIMIT
-  | typeof PALADIN_STAKE_PROGRAM_ERROR__UNDELEGATED_SOL_STAKE_ACCOUNT;
+  | typeof PALADIN_STAKE_PROGRAM_ERROR__UNDELEGATED_SOL_STAKE_ACCOUNT
+  | typeof PALADIN_STAKE_PROGRAM_ERROR__VOTING_POWER_UNAVAILABLE;
 
 let paladinStakeProgramErrorMessages:
   | Record<PaladinStakeProgramError, string>
   | undefined;
 if (process.env.NODE_ENV !== 'production') {
   paladinStakeProgramErrorMessages = {
+    [PALADIN_STAKE_PROGRAM_ERROR__ACCOUNT_MIGRATION_REQUIRED]: `Account must be migrated to the current layout`,
     [PALADIN_STAKE_PROGRAM_ERROR__ACTIVE_UNSTAKE_COOLDOWN]: `Active unstake cooldown`,
     [PALADIN_STAKE_PROGRAM_ERROR__AMOUNT_GREATER_THAN_ZERO]: `Amount cannot be greater than zero`,
     [PALADIN_STAKE_PROGRAM_ERROR__AUTHORITY_NOT_SET]: `Authority is not set`,
@@ -116,6 +129,7 @@ if (process.env.NODE_ENV !== 'production') {
     [PALADIN_STAKE_PROGRAM_ERROR__INVALID_DESTINATION_ACCOUNT]: `Invalid destination account`,
     [PALADIN_STAKE_PROGRAM_ERROR__INVALID_HOLDER_REWARDS]: `Invalid holder rewards`,
     [PALADIN_STAKE_PROGRAM_ERROR__INVALID_MINT]: `Invalid mint`,
+    [PALADIN_STAKE_PROGRAM_ERROR__INVALID_MINT_EXTENSION]: `Invalid mint extension`,
     [PALADIN_STAKE_PROGRAM_ERROR__INVALID_SLASH_AMOUNT]: `Invalid slash amount`,
     [PALADIN_STAKE_PROGRAM_ERROR__INVALID_TOKEN_ACCOUNT_EXTENSION]: `Invalid token account extension`,
     [PALADIN_STAKE_PROGRAM_ERROR__INVALID_TOKEN_OWNER]: `Invalid token owner`,
@@ -123,9 +137,11 @@ if (process.env.NODE_ENV !== 'production') {
     [PALADIN_STAKE_PROGRAM_ERROR__INVALID_VAULT_HOLDER_REWARDS_SEEDS]: `Invalid vault holder rewards seeds`,
     [PALADIN_STAKE_PROGRAM_ERROR__INVALID_VAULT_PDA_OWNER]: `Invalid vault pda owner`,
     [PALADIN_STAKE_PROGRAM_ERROR__MAXIMUM_DEACTIVATION_AMOUNT_EXCEEDED]: `Amount exeeds maximum deactivation amount`,
+    [PALADIN_STAKE_PROGRAM_ERROR__MISSING_DELEGATED_VOTING_POWER]: `Missing delegated voting power account`,
     [PALADIN_STAKE_PROGRAM_ERROR__MISSING_TRANSFER_HOOK]: `Missing transfer hook`,
     [PALADIN_STAKE_PROGRAM_ERROR__TOTAL_STAKE_AMOUNT_EXCEEDS_SOL_LIMIT]: `Total stake amount exceeds SOL limit`,
     [PALADIN_STAKE_PROGRAM_ERROR__UNDELEGATED_SOL_STAKE_ACCOUNT]: `Undelegated SOL stake account`,
+    [PALADIN_STAKE_PROGRAM_ERROR__VOTING_POWER_UNAVAILABLE]: `Voting power history is not available for the slot`,
   };
 }
 
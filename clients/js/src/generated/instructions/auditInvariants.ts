@@ -0,0 +1,191 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getStructDecoder,
+  getStructEncoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlyAccount,
+} from '@solana/web3.js';
+import { PALADIN_STAKE_PROGRAM_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+
+export const AUDIT_INVARIANTS_DISCRIMINATOR = 31;
+
+export function getAuditInvariantsDiscriminatorBytes() {
+  return getU8Encoder().encode(AUDIT_INVARIANTS_DISCRIMINATOR);
+}
+
+export type AuditInvariantsInstruction<
+  TProgram extends string = typeof PALADIN_STAKE_PROGRAM_PROGRAM_ADDRESS,
+  TAccountConfig extends string | IAccountMeta<string> = string,
+  TAccountVault extends string | IAccountMeta<string> = string,
+  TAccountVaultHolderRewards extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountConfig extends string
+        ? ReadonlyAccount<TAccountConfig>
+        : TAccountConfig,
+      TAccountVault extends string
+        ? ReadonlyAccount<TAccountVault>
+        : TAccountVault,
+      TAccountVaultHolderRewards extends string
+        ? ReadonlyAccount<TAccountVaultHolderRewards>
+        : TAccountVaultHolderRewards,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type AuditInvariantsInstructionData = { discriminator: number };
+
+export type AuditInvariantsInstructionDataArgs = {};
+
+export function getAuditInvariantsInstructionDataEncoder(): Encoder<AuditInvariantsInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([['discriminator', getU8Encoder()]]),
+    (value) => ({ ...value, discriminator: AUDIT_INVARIANTS_DISCRIMINATOR })
+  );
+}
+
+export function getAuditInvariantsInstructionDataDecoder(): Decoder<AuditInvariantsInstructionData> {
+  return getStructDecoder([['discriminator', getU8Decoder()]]);
+}
+
+export function getAuditInvariantsInstructionDataCodec(): Codec<
+  AuditInvariantsInstructionDataArgs,
+  AuditInvariantsInstructionData
+> {
+  return combineCodec(
+    getAuditInvariantsInstructionDataEncoder(),
+    getAuditInvariantsInstructionDataDecoder()
+  );
+}
+
+export type AuditInvariantsInput<
+  TAccountConfig extends string = string,
+  TAccountVault extends string = string,
+  TAccountVaultHolderRewards extends string = string,
+> = {
+  /** Stake config account */
+  config: Address<TAccountConfig>;
+  /** Vault token account */
+  vault: Address<TAccountVault>;
+  /** Vault holder rewards */
+  vaultHolderRewards: Address<TAccountVaultHolderRewards>;
+};
+
+export function getAuditInvariantsInstruction<
+  TAccountConfig extends string,
+  TAccountVault extends string,
+  TAccountVaultHolderRewards extends string,
+>(
+  input: AuditInvariantsInput<
+    TAccountConfig,
+    TAccountVault,
+    TAccountVaultHolderRewards
+  >
+): AuditInvariantsInstruction<
+  typeof PALADIN_STAKE_PROGRAM_PROGRAM_ADDRESS,
+  TAccountConfig,
+  TAccountVault,
+  TAccountVaultHolderRewards
+> {
+  // Program address.
+  const programAddress = PALADIN_STAKE_PROGRAM_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: false },
+    vault: { value: input.vault ?? null, isWritable: false },
+    vaultHolderRewards: {
+      value: input.vaultHolderRewards ?? null,
+      isWritable: false,
+    },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.vault),
+      getAccountMeta(accounts.vaultHolderRewards),
+    ],
+    programAddress,
+    data: getAuditInvariantsInstructionDataEncoder().encode({}),
+  } as AuditInvariantsInstruction<
+    typeof PALADIN_STAKE_PROGRAM_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountVault,
+    TAccountVaultHolderRewards
+  >;
+
+  return instruction;
+}
+
+export type ParsedAuditInvariantsInstruction<
+  TProgram extends string = typeof PALADIN_STAKE_PROGRAM_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    /** Stake config account */
+    config: TAccountMetas[0];
+    /** Vault token account */
+    vault: TAccountMetas[1];
+    /** Vault holder rewards */
+    vaultHolderRewards: TAccountMetas[2];
+  };
+  data: AuditInvariantsInstructionData;
+};
+
+export function parseAuditInvariantsInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedAuditInvariantsInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 3) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      config: getNextAccount(),
+      vault: getNextAccount(),
+      vaultHolderRewards: getNextAccount(),
+    },
+    data: getAuditInvariantsInstructionDataDecoder().decode(instruction.data),
+  };
+}
@@ -0,0 +1,191 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getStructDecoder,
+  getStructEncoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlyAccount,
+} from '@solana/web3.js';
+import { PALADIN_STAKE_PROGRAM_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+
+export const GET_PENDING_REWARDS_DISCRIMINATOR = 16;
+
+export function getGetPendingRewardsDiscriminatorBytes() {
+  return getU8Encoder().encode(GET_PENDING_REWARDS_DISCRIMINATOR);
+}
+
+export type GetPendingRewardsInstruction<
+  TProgram extends string = typeof PALADIN_STAKE_PROGRAM_PROGRAM_ADDRESS,
+  TAccountConfig extends string | IAccountMeta<string> = string,
+  TAccountVaultHolderRewards extends string | IAccountMeta<string> = string,
+  TAccountStake extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountConfig extends string
+        ? ReadonlyAccount<TAccountConfig>
+        : TAccountConfig,
+      TAccountVaultHolderRewards extends string
+        ? ReadonlyAccount<TAccountVaultHolderRewards>
+        : TAccountVaultHolderRewards,
+      TAccountStake extends string
+        ? ReadonlyAccount<TAccountStake>
+        : TAccountStake,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type GetPendingRewardsInstructionData = { discriminator: number };
+
+export type GetPendingRewardsInstructionDataArgs = {};
+
+export function getGetPendingRewardsInstructionDataEncoder(): Encoder<GetPendingRewardsInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([['discriminator', getU8Encoder()]]),
+    (value) => ({ ...value, discriminator: GET_PENDING_REWARDS_DISCRIMINATOR })
+  );
+}
+
+export function getGetPendingRewardsInstructionDataDecoder(): Decoder<GetPendingRewardsInstructionData> {
+  return getStructDecoder([['discriminator', getU8Decoder()]]);
+}
+
+export function getGetPendingRewardsInstructionDataCodec(): Codec<
+  GetPendingRewardsInstructionDataArgs,
+  GetPendingRewardsInstructionData
+> {
+  return combineCodec(
+    getGetPendingRewardsInstructionDataEncoder(),
+    getGetPendingRewardsInstructionDataDecoder()
+  );
+}
+
+export type GetPendingRewardsInput<
+  TAccountConfig extends string = string,
+  TAccountVaultHolderRewards extends string = string,
+  TAccountStake extends string = string,
+> = {
+  /** Stake config account */
+  config: Address<TAccountConfig>;
+  /** Vault holder rewards */
+  vaultHolderRewards: Address<TAccountVaultHolderRewards>;
+  /** Validator or sol staker stake account */
+  stake: Address<TAccountStake>;
+};
+
+export function getGetPendingRewardsInstruction<
+  TAccountConfig extends string,
+  TAccountVaultHolderRewards extends string,
+  TAccountStake extends string,
+>(
+  input: GetPendingRewardsInput<
+    TAccountConfig,
+    TAccountVaultHolderRewards,
+    TAccountStake
+  >
+): GetPendingRewardsInstruction<
+  typeof PALADIN_STAKE_PROGRAM_PROGRAM_ADDRESS,
+  TAccountConfig,
+  TAccountVaultHolderRewards,
+  TAccountStake
+> {
+  // Program address.
+  const programAddress = PALADIN_STAKE_PROGRAM_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: false },
+    vaultHolderRewards: {
+      value: input.vaultHolderRewards ?? null,
+      isWritable: false,
+    },
+    stake: { value: input.stake ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.vaultHolderRewards),
+      getAccountMeta(accounts.stake),
+    ],
+    programAddress,
+    data: getGetPendingRewardsInstructionDataEncoder().encode({}),
+  } as GetPendingRewardsInstruction<
+    typeof PALADIN_STAKE_PROGRAM_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountVaultHolderRewards,
+    TAccountStake
+  >;
+
+  return instruction;
+}
+
+export type ParsedGetPendingRewardsInstruction<
+  TProgram extends string = typeof PALADIN_STAKE_PROGRAM_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    /** Stake config account */
+    config: TAccountMetas[0];
+    /** Vault holder rewards */
+    vaultHolderRewards: TAccountMetas[1];
+    /** Validator or sol staker stake account */
+    stake: TAccountMetas[2];
+  };
+  data: GetPendingRewardsInstructionData;
+};
+
+export function parseGetPendingRewardsInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedGetPendingRewardsInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 3) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      config: getNextAccount(),
+      vaultHolderRewards: getNextAccount(),
+      stake: getNextAccount(),
+    },
+    data: getGetPendingRewardsInstructionDataDecoder().decode(instruction.data),
+  };
+}
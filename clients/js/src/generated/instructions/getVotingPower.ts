@@ -0,0 +1,186 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getStructDecoder,
+  getStructEncoder,
+  getU64Decoder,
+  getU64Encoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlyAccount,
+} from '@solana/web3.js';
+import { PALADIN_STAKE_PROGRAM_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+
+export const GET_VOTING_POWER_DISCRIMINATOR = 19;
+
+export function getGetVotingPowerDiscriminatorBytes() {
+  return getU8Encoder().encode(GET_VOTING_POWER_DISCRIMINATOR);
+}
+
+export type GetVotingPowerInstruction<
+  TProgram extends string = typeof PALADIN_STAKE_PROGRAM_PROGRAM_ADDRESS,
+  TAccountConfig extends string | IAccountMeta<string> = string,
+  TAccountStake extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountConfig extends string
+        ? ReadonlyAccount<TAccountConfig>
+        : TAccountConfig,
+      TAccountStake extends string
+        ? ReadonlyAccount<TAccountStake>
+        : TAccountStake,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type GetVotingPowerInstructionData = {
+  discriminator: number;
+  slot: bigint;
+};
+
+export type GetVotingPowerInstructionDataArgs = { slot: number | bigint };
+
+export function getGetVotingPowerInstructionDataEncoder(): Encoder<GetVotingPowerInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([
+      ['discriminator', getU8Encoder()],
+      ['slot', getU64Encoder()],
+    ]),
+    (value) => ({ ...value, discriminator: GET_VOTING_POWER_DISCRIMINATOR })
+  );
+}
+
+export function getGetVotingPowerInstructionDataDecoder(): Decoder<GetVotingPowerInstructionData> {
+  return getStructDecoder([
+    ['discriminator', getU8Decoder()],
+    ['slot', getU64Decoder()],
+  ]);
+}
+
+export function getGetVotingPowerInstructionDataCodec(): Codec<
+  GetVotingPowerInstructionDataArgs,
+  GetVotingPowerInstructionData
+> {
+  return combineCodec(
+    getGetVotingPowerInstructionDataEncoder(),
+    getGetVotingPowerInstructionDataDecoder()
+  );
+}
+
+export type GetVotingPowerInput<
+  TAccountConfig extends string = string,
+  TAccountStake extends string = string,
+> = {
+  /** Stake config account */
+  config: Address<TAccountConfig>;
+  /** Validator or sol staker stake, or delegated voting power account */
+  stake: Address<TAccountStake>;
+  slot: GetVotingPowerInstructionDataArgs['slot'];
+};
+
+export function getGetVotingPowerInstruction<
+  TAccountConfig extends string,
+  TAccountStake extends string,
+>(
+  input: GetVotingPowerInput<TAccountConfig, TAccountStake>
+): GetVotingPowerInstruction<
+  typeof PALADIN_STAKE_PROGRAM_PROGRAM_ADDRESS,
+  TAccountConfig,
+  TAccountStake
+> {
+  // Program address.
+  const programAddress = PALADIN_STAKE_PROGRAM_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: false },
+    stake: { value: input.stake ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.stake),
+    ],
+    programAddress,
+    data: getGetVotingPowerInstructionDataEncoder().encode(
+      args as GetVotingPowerInstructionDataArgs
+    ),
+  } as GetVotingPowerInstruction<
+    typeof PALADIN_STAKE_PROGRAM_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountStake
+  >;
+
+  return instruction;
+}
+
+export type ParsedGetVotingPowerInstruction<
+  TProgram extends string = typeof PALADIN_STAKE_PROGRAM_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    /** Stake config account */
+    config: TAccountMetas[0];
+    /** Validator or sol staker stake, or delegated voting power account */
+    stake: TAccountMetas[1];
+  };
+  data: GetVotingPowerInstructionData;
+};
+
+export function parseGetVotingPowerInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedGetVotingPowerInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 2) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      config: getNextAccount(),
+      stake: getNextAccount(),
+    },
+    data: getGetVotingPowerInstructionDataDecoder().decode(instruction.data),
+  };
+}
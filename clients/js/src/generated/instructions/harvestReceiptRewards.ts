@@ -0,0 +1,234 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getStructDecoder,
+  getStructEncoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlyAccount,
+  type WritableAccount,
+} from '@solana/web3.js';
+import { PALADIN_STAKE_PROGRAM_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+
+export const HARVEST_RECEIPT_REWARDS_DISCRIMINATOR = 28;
+
+export function getHarvestReceiptRewardsDiscriminatorBytes() {
+  return getU8Encoder().encode(HARVEST_RECEIPT_REWARDS_DISCRIMINATOR);
+}
+
+export type HarvestReceiptRewardsInstruction<
+  TProgram extends string = typeof PALADIN_STAKE_PROGRAM_PROGRAM_ADDRESS,
+  TAccountLiquidStakePool extends string | IAccountMeta<string> = string,
+  TAccountReceiptMint extends string | IAccountMeta<string> = string,
+  TAccountReceiptTokenAccount extends string | IAccountMeta<string> = string,
+  TAccountReceiptRewards extends string | IAccountMeta<string> = string,
+  TAccountReceiptTokenAccountOwner extends
+    | string
+    | IAccountMeta<string> = string,
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountLiquidStakePool extends string
+        ? WritableAccount<TAccountLiquidStakePool>
+        : TAccountLiquidStakePool,
+      TAccountReceiptMint extends string
+        ? ReadonlyAccount<TAccountReceiptMint>
+        : TAccountReceiptMint,
+      TAccountReceiptTokenAccount extends string
+        ? ReadonlyAccount<TAccountReceiptTokenAccount>
+        : TAccountReceiptTokenAccount,
+      TAccountReceiptRewards extends string
+        ? WritableAccount<TAccountReceiptRewards>
+        : TAccountReceiptRewards,
+      TAccountReceiptTokenAccountOwner extends string
+        ? WritableAccount<TAccountReceiptTokenAccountOwner>
+        : TAccountReceiptTokenAccountOwner,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type HarvestReceiptRewardsInstructionData = { discriminator: number };
+
+export type HarvestReceiptRewardsInstructionDataArgs = {};
+
+export function getHarvestReceiptRewardsInstructionDataEncoder(): Encoder<HarvestReceiptRewardsInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([['discriminator', getU8Encoder()]]),
+    (value) => ({
+      ...value,
+      discriminator: HARVEST_RECEIPT_REWARDS_DISCRIMINATOR,
+    })
+  );
+}
+
+export function getHarvestReceiptRewardsInstructionDataDecoder(): Decoder<HarvestReceiptRewardsInstructionData> {
+  return getStructDecoder([['discriminator', getU8Decoder()]]);
+}
+
+export function getHarvestReceiptRewardsInstructionDataCodec(): Codec<
+  HarvestReceiptRewardsInstructionDataArgs,
+  HarvestReceiptRewardsInstructionData
+> {
+  return combineCodec(
+    getHarvestReceiptRewardsInstructionDataEncoder(),
+    getHarvestReceiptRewardsInstructionDataDecoder()
+  );
+}
+
+export type HarvestReceiptRewardsInput<
+  TAccountLiquidStakePool extends string = string,
+  TAccountReceiptMint extends string = string,
+  TAccountReceiptTokenAccount extends string = string,
+  TAccountReceiptRewards extends string = string,
+  TAccountReceiptTokenAccountOwner extends string = string,
+> = {
+  /** Liquid stake pool account */
+  liquidStakePool: Address<TAccountLiquidStakePool>;
+  /** Receipt token mint */
+  receiptMint: Address<TAccountReceiptMint>;
+  /** Receipt token account */
+  receiptTokenAccount: Address<TAccountReceiptTokenAccount>;
+  /** Receipt rewards account */
+  receiptRewards: Address<TAccountReceiptRewards>;
+  /** Owner of the receipt token account */
+  receiptTokenAccountOwner: Address<TAccountReceiptTokenAccountOwner>;
+};
+
+export function getHarvestReceiptRewardsInstruction<
+  TAccountLiquidStakePool extends string,
+  TAccountReceiptMint extends string,
+  TAccountReceiptTokenAccount extends string,
+  TAccountReceiptRewards extends string,
+  TAccountReceiptTokenAccountOwner extends string,
+>(
+  input: HarvestReceiptRewardsInput<
+    TAccountLiquidStakePool,
+    TAccountReceiptMint,
+    TAccountReceiptTokenAccount,
+    TAccountReceiptRewards,
+    TAccountReceiptTokenAccountOwner
+  >
+): HarvestReceiptRewardsInstruction<
+  typeof PALADIN_STAKE_PROGRAM_PROGRAM_ADDRESS,
+  TAccountLiquidStakePool,
+  TAccountReceiptMint,
+  TAccountReceiptTokenAccount,
+  TAccountReceiptRewards,
+  TAccountReceiptTokenAccountOwner
+> {
+  // Program address.
+  const programAddress = PALADIN_STAKE_PROGRAM_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    liquidStakePool: { value: input.liquidStakePool ?? null, isWritable: true },
+    receiptMint: { value: input.receiptMint ?? null, isWritable: false },
+    receiptTokenAccount: {
+      value: input.receiptTokenAccount ?? null,
+      isWritable: false,
+    },
+    receiptRewards: { value: input.receiptRewards ?? null, isWritable: true },
+    receiptTokenAccountOwner: {
+      value: input.receiptTokenAccountOwner ?? null,
+      isWritable: true,
+    },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.liquidStakePool),
+      getAccountMeta(accounts.receiptMint),
+      getAccountMeta(accounts.receiptTokenAccount),
+      getAccountMeta(accounts.receiptRewards),
+      getAccountMeta(accounts.receiptTokenAccountOwner),
+    ],
+    programAddress,
+    data: getHarvestReceiptRewardsInstructionDataEncoder().encode({}),
+  } as HarvestReceiptRewardsInstruction<
+    typeof PALADIN_STAKE_PROGRAM_PROGRAM_ADDRESS,
+    TAccountLiquidStakePool,
+    TAccountReceiptMint,
+    TAccountReceiptTokenAccount,
+    TAccountReceiptRewards,
+    TAccountReceiptTokenAccountOwner
+  >;
+
+  return instruction;
+}
+
+export type ParsedHarvestReceiptRewardsInstruction<
+  TProgram extends string = typeof PALADIN_STAKE_PROGRAM_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    /** Liquid stake pool account */
+    liquidStakePool: TAccountMetas[0];
+    /** Receipt token mint */
+    receiptMint: TAccountMetas[1];
+    /** Receipt token account */
+    receiptTokenAccount: TAccountMetas[2];
+    /** Receipt rewards account */
+    receiptRewards: TAccountMetas[3];
+    /** Owner of the receipt token account */
+    receiptTokenAccountOwner: TAccountMetas[4];
+  };
+  data: HarvestReceiptRewardsInstructionData;
+};
+
+export function parseHarvestReceiptRewardsInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedHarvestReceiptRewardsInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 5) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      liquidStakePool: getNextAccount(),
+      receiptMint: getNextAccount(),
+      receiptTokenAccount: getNextAccount(),
+      receiptRewards: getNextAccount(),
+      receiptTokenAccountOwner: getNextAccount(),
+    },
+    data: getHarvestReceiptRewardsInstructionDataDecoder().decode(
+      instruction.data
+    ),
+  };
+}
@@ -6,19 +6,35 @@
  * @see https://github.com/kinobi-so/kinobi
  */
 
+export * from './auditInvariants';
+export * from './getPendingRewards';
+export * from './getVotingPower';
 export * from './harvestHolderRewards';
+export * from './harvestReceiptRewards';
 export * from './harvestSolStakerRewards';
 export * from './harvestValidatorRewards';
 export * from './initializeConfig';
+export * from './initializeConfigPda';
+export * from './initializeDelegatedVotingPower';
+export * from './initializeLiquidStakePool';
+export * from './initializeReceiptRewards';
 export * from './initializeSolStakerStake';
 export * from './initializeValidatorStake';
+export * from './liquidStakeTokens';
+export * from './liquidUnstakeTokens';
+export * from './migrateAccount';
 export * from './setAuthority';
+export * from './setValidatorAuthorityOverride';
+export * from './setVoteDelegate';
 export * from './slashSolStakerStake';
 export * from './slashValidatorStake';
 export * from './solStakerMoveTokens';
 export * from './solStakerStakeTokens';
+export * from './sweepUndistributedRewards';
+export * from './transferStake';
 export * from './unstakeTokens';
 export * from './updateConfig';
 export * from './validatorOverrideStakedLamports';
 export * from './validatorStakeTokens';
 export * from './validatorSyncAuthority';
+export * from './withdrawProtocolFees';
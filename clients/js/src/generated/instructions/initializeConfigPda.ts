@@ -0,0 +1,345 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  fixDecoderSize,
+  fixEncoderSize,
+  getAddressDecoder,
+  getAddressEncoder,
+  getBytesDecoder,
+  getBytesEncoder,
+  getStructDecoder,
+  getStructEncoder,
+  getU16Decoder,
+  getU16Encoder,
+  getU64Decoder,
+  getU64Encoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IAccountSignerMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlyAccount,
+  type ReadonlySignerAccount,
+  type ReadonlyUint8Array,
+  type TransactionSigner,
+  type WritableAccount,
+} from '@solana/web3.js';
+import { PALADIN_STAKE_PROGRAM_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+
+export const INITIALIZE_CONFIG_PDA_DISCRIMINATOR = 18;
+
+export function getInitializeConfigPdaDiscriminatorBytes() {
+  return getU8Encoder().encode(INITIALIZE_CONFIG_PDA_DISCRIMINATOR);
+}
+
+export type InitializeConfigPdaInstruction<
+  TProgram extends string = typeof PALADIN_STAKE_PROGRAM_PROGRAM_ADDRESS,
+  TAccountConfig extends string | IAccountMeta<string> = string,
+  TAccountMint extends string | IAccountMeta<string> = string,
+  TAccountVaultPda extends string | IAccountMeta<string> = string,
+  TAccountVault extends string | IAccountMeta<string> = string,
+  TAccountVaultHolderRewards extends string | IAccountMeta<string> = string,
+  TAccountSystemProgram extends
+    | string
+    | IAccountMeta<string> = '11111111111111111111111111111111',
+  TAccountRewardsProgram extends string | IAccountMeta<string> = string,
+  TAccountConfigAuthority extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountConfig extends string
+        ? WritableAccount<TAccountConfig>
+        : TAccountConfig,
+      TAccountMint extends string
+        ? ReadonlyAccount<TAccountMint>
+        : TAccountMint,
+      TAccountVaultPda extends string
+        ? WritableAccount<TAccountVaultPda>
+        : TAccountVaultPda,
+      TAccountVault extends string
+        ? ReadonlyAccount<TAccountVault>
+        : TAccountVault,
+      TAccountVaultHolderRewards extends string
+        ? WritableAccount<TAccountVaultHolderRewards>
+        : TAccountVaultHolderRewards,
+      TAccountSystemProgram extends string
+        ? ReadonlyAccount<TAccountSystemProgram>
+        : TAccountSystemProgram,
+      TAccountRewardsProgram extends string
+        ? ReadonlyAccount<TAccountRewardsProgram>
+        : TAccountRewardsProgram,
+      TAccountConfigAuthority extends string
+        ? ReadonlySignerAccount<TAccountConfigAuthority> &
+            IAccountSignerMeta<TAccountConfigAuthority>
+        : TAccountConfigAuthority,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type InitializeConfigPdaInstructionData = {
+  discriminator: number;
+  namespace: bigint;
+  slashAuthority: Address;
+  cooldownTimeSeconds: bigint;
+  maxDeactivationBasisPoints: number;
+  syncRewardsLamports: bigint;
+  dunaDocumentHash: ReadonlyUint8Array;
+};
+
+export type InitializeConfigPdaInstructionDataArgs = {
+  namespace: number | bigint;
+  slashAuthority: Address;
+  cooldownTimeSeconds: number | bigint;
+  maxDeactivationBasisPoints: number;
+  syncRewardsLamports: number | bigint;
+  dunaDocumentHash: ReadonlyUint8Array;
+};
+
+export function getInitializeConfigPdaInstructionDataEncoder(): Encoder<InitializeConfigPdaInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([
+      ['discriminator', getU8Encoder()],
+      ['namespace', getU64Encoder()],
+      ['slashAuthority', getAddressEncoder()],
+      ['cooldownTimeSeconds', getU64Encoder()],
+      ['maxDeactivationBasisPoints', getU16Encoder()],
+      ['syncRewardsLamports', getU64Encoder()],
+      ['dunaDocumentHash', fixEncoderSize(getBytesEncoder(), 32)],
+    ]),
+    (value) => ({
+      ...value,
+      discriminator: INITIALIZE_CONFIG_PDA_DISCRIMINATOR,
+    })
+  );
+}
+
+export function getInitializeConfigPdaInstructionDataDecoder(): Decoder<InitializeConfigPdaInstructionData> {
+  return getStructDecoder([
+    ['discriminator', getU8Decoder()],
+    ['namespace', getU64Decoder()],
+    ['slashAuthority', getAddressDecoder()],
+    ['cooldownTimeSeconds', getU64Decoder()],
+    ['maxDeactivationBasisPoints', getU16Decoder()],
+    ['syncRewardsLamports', getU64Decoder()],
+    ['dunaDocumentHash', fixDecoderSize(getBytesDecoder(), 32)],
+  ]);
+}
+
+export function getInitializeConfigPdaInstructionDataCodec(): Codec<
+  InitializeConfigPdaInstructionDataArgs,
+  InitializeConfigPdaInstructionData
+> {
+  return combineCodec(
+    getInitializeConfigPdaInstructionDataEncoder(),
+    getInitializeConfigPdaInstructionDataDecoder()
+  );
+}
+
+export type InitializeConfigPdaInput<
+  TAccountConfig extends string = string,
+  TAccountMint extends string = string,
+  TAccountVaultPda extends string = string,
+  TAccountVault extends string = string,
+  TAccountVaultHolderRewards extends string = string,
+  TAccountSystemProgram extends string = string,
+  TAccountRewardsProgram extends string = string,
+  TAccountConfigAuthority extends string = string,
+> = {
+  /** Stake config account */
+  config: Address<TAccountConfig>;
+  /** Stake token mint */
+  mint: Address<TAccountMint>;
+  /** Stake vault pda */
+  vaultPda: Address<TAccountVaultPda>;
+  /** Stake vault token account */
+  vault: Address<TAccountVault>;
+  /** Stake vault holder rewards account */
+  vaultHolderRewards: Address<TAccountVaultHolderRewards>;
+  /** System program. */
+  systemProgram?: Address<TAccountSystemProgram>;
+  /** Paladin rewards program */
+  rewardsProgram: Address<TAccountRewardsProgram>;
+  /** Config authority */
+  configAuthority: TransactionSigner<TAccountConfigAuthority>;
+  namespace: InitializeConfigPdaInstructionDataArgs['namespace'];
+  slashAuthority: InitializeConfigPdaInstructionDataArgs['slashAuthority'];
+  cooldownTimeSeconds: InitializeConfigPdaInstructionDataArgs['cooldownTimeSeconds'];
+  maxDeactivationBasisPoints: InitializeConfigPdaInstructionDataArgs['maxDeactivationBasisPoints'];
+  syncRewardsLamports: InitializeConfigPdaInstructionDataArgs['syncRewardsLamports'];
+  dunaDocumentHash: InitializeConfigPdaInstructionDataArgs['dunaDocumentHash'];
+};
+
+export function getInitializeConfigPdaInstruction<
+  TAccountConfig extends string,
+  TAccountMint extends string,
+  TAccountVaultPda extends string,
+  TAccountVault extends string,
+  TAccountVaultHolderRewards extends string,
+  TAccountSystemProgram extends string,
+  TAccountRewardsProgram extends string,
+  TAccountConfigAuthority extends string,
+>(
+  input: InitializeConfigPdaInput<
+    TAccountConfig,
+    TAccountMint,
+    TAccountVaultPda,
+    TAccountVault,
+    TAccountVaultHolderRewards,
+    TAccountSystemProgram,
+    TAccountRewardsProgram,
+    TAccountConfigAuthority
+  >
+): InitializeConfigPdaInstruction<
+  typeof PALADIN_STAKE_PROGRAM_PROGRAM_ADDRESS,
+  TAccountConfig,
+  TAccountMint,
+  TAccountVaultPda,
+  TAccountVault,
+  TAccountVaultHolderRewards,
+  TAccountSystemProgram,
+  TAccountRewardsProgram,
+  TAccountConfigAuthority
+> {
+  // Program address.
+  const programAddress = PALADIN_STAKE_PROGRAM_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: true },
+    mint: { value: input.mint ?? null, isWritable: false },
+    vaultPda: { value: input.vaultPda ?? null, isWritable: true },
+    vault: { value: input.vault ?? null, isWritable: false },
+    vaultHolderRewards: {
+      value: input.vaultHolderRewards ?? null,
+      isWritable: true,
+    },
+    systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    rewardsProgram: { value: input.rewardsProgram ?? null, isWritable: false },
+    configAuthority: {
+      value: input.configAuthority ?? null,
+      isWritable: false,
+    },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  // Resolve default values.
+  if (!accounts.systemProgram.value) {
+    accounts.systemProgram.value =
+      '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.mint),
+      getAccountMeta(accounts.vaultPda),
+      getAccountMeta(accounts.vault),
+      getAccountMeta(accounts.vaultHolderRewards),
+      getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.rewardsProgram),
+      getAccountMeta(accounts.configAuthority),
+    ],
+    programAddress,
+    data: getInitializeConfigPdaInstructionDataEncoder().encode(
+      args as InitializeConfigPdaInstructionDataArgs
+    ),
+  } as InitializeConfigPdaInstruction<
+    typeof PALADIN_STAKE_PROGRAM_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountMint,
+    TAccountVaultPda,
+    TAccountVault,
+    TAccountVaultHolderRewards,
+    TAccountSystemProgram,
+    TAccountRewardsProgram,
+    TAccountConfigAuthority
+  >;
+
+  return instruction;
+}
+
+export type ParsedInitializeConfigPdaInstruction<
+  TProgram extends string = typeof PALADIN_STAKE_PROGRAM_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    /** Stake config account */
+    config: TAccountMetas[0];
+    /** Stake token mint */
+    mint: TAccountMetas[1];
+    /** Stake vault pda */
+    vaultPda: TAccountMetas[2];
+    /** Stake vault token account */
+    vault: TAccountMetas[3];
+    /** Stake vault holder rewards account */
+    vaultHolderRewards: TAccountMetas[4];
+    /** System program. */
+    systemProgram: TAccountMetas[5];
+    /** Paladin rewards program */
+    rewardsProgram: TAccountMetas[6];
+    /** Config authority */
+    configAuthority: TAccountMetas[7];
+  };
+  data: InitializeConfigPdaInstructionData;
+};
+
+export function parseInitializeConfigPdaInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedInitializeConfigPdaInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 8) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      config: getNextAccount(),
+      mint: getNextAccount(),
+      vaultPda: getNextAccount(),
+      vault: getNextAccount(),
+      vaultHolderRewards: getNextAccount(),
+      systemProgram: getNextAccount(),
+      rewardsProgram: getNextAccount(),
+      configAuthority: getNextAccount(),
+    },
+    data: getInitializeConfigPdaInstructionDataDecoder().decode(
+      instruction.data
+    ),
+  };
+}
@@ -0,0 +1,223 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getStructDecoder,
+  getStructEncoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlyAccount,
+  type WritableAccount,
+} from '@solana/web3.js';
+import { PALADIN_STAKE_PROGRAM_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+
+export const INITIALIZE_DELEGATED_VOTING_POWER_DISCRIMINATOR = 20;
+
+export function getInitializeDelegatedVotingPowerDiscriminatorBytes() {
+  return getU8Encoder().encode(INITIALIZE_DELEGATED_VOTING_POWER_DISCRIMINATOR);
+}
+
+export type InitializeDelegatedVotingPowerInstruction<
+  TProgram extends string = typeof PALADIN_STAKE_PROGRAM_PROGRAM_ADDRESS,
+  TAccountConfig extends string | IAccountMeta<string> = string,
+  TAccountVoteDelegate extends string | IAccountMeta<string> = string,
+  TAccountDelegatedVotingPower extends string | IAccountMeta<string> = string,
+  TAccountSystemProgram extends
+    | string
+    | IAccountMeta<string> = '11111111111111111111111111111111',
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountConfig extends string
+        ? ReadonlyAccount<TAccountConfig>
+        : TAccountConfig,
+      TAccountVoteDelegate extends string
+        ? ReadonlyAccount<TAccountVoteDelegate>
+        : TAccountVoteDelegate,
+      TAccountDelegatedVotingPower extends string
+        ? WritableAccount<TAccountDelegatedVotingPower>
+        : TAccountDelegatedVotingPower,
+      TAccountSystemProgram extends string
+        ? ReadonlyAccount<TAccountSystemProgram>
+        : TAccountSystemProgram,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type InitializeDelegatedVotingPowerInstructionData = {
+  discriminator: number;
+};
+
+export type InitializeDelegatedVotingPowerInstructionDataArgs = {};
+
+export function getInitializeDelegatedVotingPowerInstructionDataEncoder(): Encoder<InitializeDelegatedVotingPowerInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([['discriminator', getU8Encoder()]]),
+    (value) => ({
+      ...value,
+      discriminator: INITIALIZE_DELEGATED_VOTING_POWER_DISCRIMINATOR,
+    })
+  );
+}
+
+export function getInitializeDelegatedVotingPowerInstructionDataDecoder(): Decoder<InitializeDelegatedVotingPowerInstructionData> {
+  return getStructDecoder([['discriminator', getU8Decoder()]]);
+}
+
+export function getInitializeDelegatedVotingPowerInstructionDataCodec(): Codec<
+  InitializeDelegatedVotingPowerInstructionDataArgs,
+  InitializeDelegatedVotingPowerInstructionData
+> {
+  return combineCodec(
+    getInitializeDelegatedVotingPowerInstructionDataEncoder(),
+    getInitializeDelegatedVotingPowerInstructionDataDecoder()
+  );
+}
+
+export type InitializeDelegatedVotingPowerInput<
+  TAccountConfig extends string = string,
+  TAccountVoteDelegate extends string = string,
+  TAccountDelegatedVotingPower extends string = string,
+  TAccountSystemProgram extends string = string,
+> = {
+  /** Stake config account */
+  config: Address<TAccountConfig>;
+  /** Vote delegate */
+  voteDelegate: Address<TAccountVoteDelegate>;
+  /** Delegated voting power account */
+  delegatedVotingPower: Address<TAccountDelegatedVotingPower>;
+  /** System program */
+  systemProgram?: Address<TAccountSystemProgram>;
+};
+
+export function getInitializeDelegatedVotingPowerInstruction<
+  TAccountConfig extends string,
+  TAccountVoteDelegate extends string,
+  TAccountDelegatedVotingPower extends string,
+  TAccountSystemProgram extends string,
+>(
+  input: InitializeDelegatedVotingPowerInput<
+    TAccountConfig,
+    TAccountVoteDelegate,
+    TAccountDelegatedVotingPower,
+    TAccountSystemProgram
+  >
+): InitializeDelegatedVotingPowerInstruction<
+  typeof PALADIN_STAKE_PROGRAM_PROGRAM_ADDRESS,
+  TAccountConfig,
+  TAccountVoteDelegate,
+  TAccountDelegatedVotingPower,
+  TAccountSystemProgram
+> {
+  // Program address.
+  const programAddress = PALADIN_STAKE_PROGRAM_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: false },
+    voteDelegate: { value: input.voteDelegate ?? null, isWritable: false },
+    delegatedVotingPower: {
+      value: input.delegatedVotingPower ?? null,
+      isWritable: true,
+    },
+    systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Resolve default values.
+  if (!accounts.systemProgram.value) {
+    accounts.systemProgram.value =
+      '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.voteDelegate),
+      getAccountMeta(accounts.delegatedVotingPower),
+      getAccountMeta(accounts.systemProgram),
+    ],
+    programAddress,
+    data: getInitializeDelegatedVotingPowerInstructionDataEncoder().encode({}),
+  } as InitializeDelegatedVotingPowerInstruction<
+    typeof PALADIN_STAKE_PROGRAM_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountVoteDelegate,
+    TAccountDelegatedVotingPower,
+    TAccountSystemProgram
+  >;
+
+  return instruction;
+}
+
+export type ParsedInitializeDelegatedVotingPowerInstruction<
+  TProgram extends string = typeof PALADIN_STAKE_PROGRAM_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    /** Stake config account */
+    config: TAccountMetas[0];
+    /** Vote delegate */
+    voteDelegate: TAccountMetas[1];
+    /** Delegated voting power account */
+    delegatedVotingPower: TAccountMetas[2];
+    /** System program */
+    systemProgram: TAccountMetas[3];
+  };
+  data: InitializeDelegatedVotingPowerInstructionData;
+};
+
+export function parseInitializeDelegatedVotingPowerInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedInitializeDelegatedVotingPowerInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 4) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      config: getNextAccount(),
+      voteDelegate: getNextAccount(),
+      delegatedVotingPower: getNextAccount(),
+      systemProgram: getNextAccount(),
+    },
+    data: getInitializeDelegatedVotingPowerInstructionDataDecoder().decode(
+      instruction.data
+    ),
+  };
+}
@@ -0,0 +1,287 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getStructDecoder,
+  getStructEncoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlyAccount,
+  type WritableAccount,
+} from '@solana/web3.js';
+import { PALADIN_STAKE_PROGRAM_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+
+export const INITIALIZE_LIQUID_STAKE_POOL_DISCRIMINATOR = 24;
+
+export function getInitializeLiquidStakePoolDiscriminatorBytes() {
+  return getU8Encoder().encode(INITIALIZE_LIQUID_STAKE_POOL_DISCRIMINATOR);
+}
+
+export type InitializeLiquidStakePoolInstruction<
+  TProgram extends string = typeof PALADIN_STAKE_PROGRAM_PROGRAM_ADDRESS,
+  TAccountConfig extends string | IAccountMeta<string> = string,
+  TAccountValidatorStake extends string | IAccountMeta<string> = string,
+  TAccountLiquidStakePool extends string | IAccountMeta<string> = string,
+  TAccountVault extends string | IAccountMeta<string> = string,
+  TAccountMint extends string | IAccountMeta<string> = string,
+  TAccountReceiptMint extends string | IAccountMeta<string> = string,
+  TAccountReceiptTokenProgram extends string | IAccountMeta<string> = string,
+  TAccountSystemProgram extends
+    | string
+    | IAccountMeta<string> = '11111111111111111111111111111111',
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountConfig extends string
+        ? ReadonlyAccount<TAccountConfig>
+        : TAccountConfig,
+      TAccountValidatorStake extends string
+        ? ReadonlyAccount<TAccountValidatorStake>
+        : TAccountValidatorStake,
+      TAccountLiquidStakePool extends string
+        ? WritableAccount<TAccountLiquidStakePool>
+        : TAccountLiquidStakePool,
+      TAccountVault extends string
+        ? ReadonlyAccount<TAccountVault>
+        : TAccountVault,
+      TAccountMint extends string
+        ? ReadonlyAccount<TAccountMint>
+        : TAccountMint,
+      TAccountReceiptMint extends string
+        ? ReadonlyAccount<TAccountReceiptMint>
+        : TAccountReceiptMint,
+      TAccountReceiptTokenProgram extends string
+        ? ReadonlyAccount<TAccountReceiptTokenProgram>
+        : TAccountReceiptTokenProgram,
+      TAccountSystemProgram extends string
+        ? ReadonlyAccount<TAccountSystemProgram>
+        : TAccountSystemProgram,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type InitializeLiquidStakePoolInstructionData = {
+  discriminator: number;
+};
+
+export type InitializeLiquidStakePoolInstructionDataArgs = {};
+
+export function getInitializeLiquidStakePoolInstructionDataEncoder(): Encoder<InitializeLiquidStakePoolInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([['discriminator', getU8Encoder()]]),
+    (value) => ({
+      ...value,
+      discriminator: INITIALIZE_LIQUID_STAKE_POOL_DISCRIMINATOR,
+    })
+  );
+}
+
+export function getInitializeLiquidStakePoolInstructionDataDecoder(): Decoder<InitializeLiquidStakePoolInstructionData> {
+  return getStructDecoder([['discriminator', getU8Decoder()]]);
+}
+
+export function getInitializeLiquidStakePoolInstructionDataCodec(): Codec<
+  InitializeLiquidStakePoolInstructionDataArgs,
+  InitializeLiquidStakePoolInstructionData
+> {
+  return combineCodec(
+    getInitializeLiquidStakePoolInstructionDataEncoder(),
+    getInitializeLiquidStakePoolInstructionDataDecoder()
+  );
+}
+
+export type InitializeLiquidStakePoolInput<
+  TAccountConfig extends string = string,
+  TAccountValidatorStake extends string = string,
+  TAccountLiquidStakePool extends string = string,
+  TAccountVault extends string = string,
+  TAccountMint extends string = string,
+  TAccountReceiptMint extends string = string,
+  TAccountReceiptTokenProgram extends string = string,
+  TAccountSystemProgram extends string = string,
+> = {
+  /** Stake config account */
+  config: Address<TAccountConfig>;
+  /** Pooled validator stake account */
+  validatorStake: Address<TAccountValidatorStake>;
+  /** Liquid stake pool account */
+  liquidStakePool: Address<TAccountLiquidStakePool>;
+  /** Vault token account */
+  vault: Address<TAccountVault>;
+  /** Stake token mint */
+  mint: Address<TAccountMint>;
+  /** Receipt token mint */
+  receiptMint: Address<TAccountReceiptMint>;
+  /** Receipt token program */
+  receiptTokenProgram: Address<TAccountReceiptTokenProgram>;
+  /** System program */
+  systemProgram?: Address<TAccountSystemProgram>;
+};
+
+export function getInitializeLiquidStakePoolInstruction<
+  TAccountConfig extends string,
+  TAccountValidatorStake extends string,
+  TAccountLiquidStakePool extends string,
+  TAccountVault extends string,
+  TAccountMint extends string,
+  TAccountReceiptMint extends string,
+  TAccountReceiptTokenProgram extends string,
+  TAccountSystemProgram extends string,
+>(
+  input: InitializeLiquidStakePoolInput<
+    TAccountConfig,
+    TAccountValidatorStake,
+    TAccountLiquidStakePool,
+    TAccountVault,
+    TAccountMint,
+    TAccountReceiptMint,
+    TAccountReceiptTokenProgram,
+    TAccountSystemProgram
+  >
+): InitializeLiquidStakePoolInstruction<
+  typeof PALADIN_STAKE_PROGRAM_PROGRAM_ADDRESS,
+  TAccountConfig,
+  TAccountValidatorStake,
+  TAccountLiquidStakePool,
+  TAccountVault,
+  TAccountMint,
+  TAccountReceiptMint,
+  TAccountReceiptTokenProgram,
+  TAccountSystemProgram
+> {
+  // Program address.
+  const programAddress = PALADIN_STAKE_PROGRAM_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: false },
+    validatorStake: { value: input.validatorStake ?? null, isWritable: false },
+    liquidStakePool: { value: input.liquidStakePool ?? null, isWritable: true },
+    vault: { value: input.vault ?? null, isWritable: false },
+    mint: { value: input.mint ?? null, isWritable: false },
+    receiptMint: { value: input.receiptMint ?? null, isWritable: false },
+    receiptTokenProgram: {
+      value: input.receiptTokenProgram ?? null,
+      isWritable: false,
+    },
+    systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Resolve default values.
+  if (!accounts.systemProgram.value) {
+    accounts.systemProgram.value =
+      '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.validatorStake),
+      getAccountMeta(accounts.liquidStakePool),
+      getAccountMeta(accounts.vault),
+      getAccountMeta(accounts.mint),
+      getAccountMeta(accounts.receiptMint),
+      getAccountMeta(accounts.receiptTokenProgram),
+      getAccountMeta(accounts.systemProgram),
+    ],
+    programAddress,
+    data: getInitializeLiquidStakePoolInstructionDataEncoder().encode({}),
+  } as InitializeLiquidStakePoolInstruction<
+    typeof PALADIN_STAKE_PROGRAM_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountValidatorStake,
+    TAccountLiquidStakePool,
+    TAccountVault,
+    TAccountMint,
+    TAccountReceiptMint,
+    TAccountReceiptTokenProgram,
+    TAccountSystemProgram
+  >;
+
+  return instruction;
+}
+
+export type ParsedInitializeLiquidStakePoolInstruction<
+  TProgram extends string = typeof PALADIN_STAKE_PROGRAM_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    /** Stake config account */
+    config: TAccountMetas[0];
+    /** Pooled validator stake account */
+    validatorStake: TAccountMetas[1];
+    /** Liquid stake pool account */
+    liquidStakePool: TAccountMetas[2];
+    /** Vault token account */
+    vault: TAccountMetas[3];
+    /** Stake token mint */
+    mint: TAccountMetas[4];
+    /** Receipt token mint */
+    receiptMint: TAccountMetas[5];
+    /** Receipt token program */
+    receiptTokenProgram: TAccountMetas[6];
+    /** System program */
+    systemProgram: TAccountMetas[7];
+  };
+  data: InitializeLiquidStakePoolInstructionData;
+};
+
+export function parseInitializeLiquidStakePoolInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedInitializeLiquidStakePoolInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 8) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      config: getNextAccount(),
+      validatorStake: getNextAccount(),
+      liquidStakePool: getNextAccount(),
+      vault: getNextAccount(),
+      mint: getNextAccount(),
+      receiptMint: getNextAccount(),
+      receiptTokenProgram: getNextAccount(),
+      systemProgram: getNextAccount(),
+    },
+    data: getInitializeLiquidStakePoolInstructionDataDecoder().decode(
+      instruction.data
+    ),
+  };
+}
@@ -0,0 +1,237 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getStructDecoder,
+  getStructEncoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlyAccount,
+  type WritableAccount,
+} from '@solana/web3.js';
+import { PALADIN_STAKE_PROGRAM_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+
+export const INITIALIZE_RECEIPT_REWARDS_DISCRIMINATOR = 25;
+
+export function getInitializeReceiptRewardsDiscriminatorBytes() {
+  return getU8Encoder().encode(INITIALIZE_RECEIPT_REWARDS_DISCRIMINATOR);
+}
+
+export type InitializeReceiptRewardsInstruction<
+  TProgram extends string = typeof PALADIN_STAKE_PROGRAM_PROGRAM_ADDRESS,
+  TAccountLiquidStakePool extends string | IAccountMeta<string> = string,
+  TAccountReceiptMint extends string | IAccountMeta<string> = string,
+  TAccountReceiptTokenAccount extends string | IAccountMeta<string> = string,
+  TAccountReceiptRewards extends string | IAccountMeta<string> = string,
+  TAccountSystemProgram extends
+    | string
+    | IAccountMeta<string> = '11111111111111111111111111111111',
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountLiquidStakePool extends string
+        ? WritableAccount<TAccountLiquidStakePool>
+        : TAccountLiquidStakePool,
+      TAccountReceiptMint extends string
+        ? ReadonlyAccount<TAccountReceiptMint>
+        : TAccountReceiptMint,
+      TAccountReceiptTokenAccount extends string
+        ? ReadonlyAccount<TAccountReceiptTokenAccount>
+        : TAccountReceiptTokenAccount,
+      TAccountReceiptRewards extends string
+        ? WritableAccount<TAccountReceiptRewards>
+        : TAccountReceiptRewards,
+      TAccountSystemProgram extends string
+        ? ReadonlyAccount<TAccountSystemProgram>
+        : TAccountSystemProgram,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type InitializeReceiptRewardsInstructionData = { discriminator: number };
+
+export type InitializeReceiptRewardsInstructionDataArgs = {};
+
+export function getInitializeReceiptRewardsInstructionDataEncoder(): Encoder<InitializeReceiptRewardsInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([['discriminator', getU8Encoder()]]),
+    (value) => ({
+      ...value,
+      discriminator: INITIALIZE_RECEIPT_REWARDS_DISCRIMINATOR,
+    })
+  );
+}
+
+export function getInitializeReceiptRewardsInstructionDataDecoder(): Decoder<InitializeReceiptRewardsInstructionData> {
+  return getStructDecoder([['discriminator', getU8Decoder()]]);
+}
+
+export function getInitializeReceiptRewardsInstructionDataCodec(): Codec<
+  InitializeReceiptRewardsInstructionDataArgs,
+  InitializeReceiptRewardsInstructionData
+> {
+  return combineCodec(
+    getInitializeReceiptRewardsInstructionDataEncoder(),
+    getInitializeReceiptRewardsInstructionDataDecoder()
+  );
+}
+
+export type InitializeReceiptRewardsInput<
+  TAccountLiquidStakePool extends string = string,
+  TAccountReceiptMint extends string = string,
+  TAccountReceiptTokenAccount extends string = string,
+  TAccountReceiptRewards extends string = string,
+  TAccountSystemProgram extends string = string,
+> = {
+  /** Liquid stake pool account */
+  liquidStakePool: Address<TAccountLiquidStakePool>;
+  /** Receipt token mint */
+  receiptMint: Address<TAccountReceiptMint>;
+  /** Receipt token account */
+  receiptTokenAccount: Address<TAccountReceiptTokenAccount>;
+  /** Receipt rewards account */
+  receiptRewards: Address<TAccountReceiptRewards>;
+  /** System program */
+  systemProgram?: Address<TAccountSystemProgram>;
+};
+
+export function getInitializeReceiptRewardsInstruction<
+  TAccountLiquidStakePool extends string,
+  TAccountReceiptMint extends string,
+  TAccountReceiptTokenAccount extends string,
+  TAccountReceiptRewards extends string,
+  TAccountSystemProgram extends string,
+>(
+  input: InitializeReceiptRewardsInput<
+    TAccountLiquidStakePool,
+    TAccountReceiptMint,
+    TAccountReceiptTokenAccount,
+    TAccountReceiptRewards,
+    TAccountSystemProgram
+  >
+): InitializeReceiptRewardsInstruction<
+  typeof PALADIN_STAKE_PROGRAM_PROGRAM_ADDRESS,
+  TAccountLiquidStakePool,
+  TAccountReceiptMint,
+  TAccountReceiptTokenAccount,
+  TAccountReceiptRewards,
+  TAccountSystemProgram
+> {
+  // Program address.
+  const programAddress = PALADIN_STAKE_PROGRAM_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    liquidStakePool: { value: input.liquidStakePool ?? null, isWritable: true },
+    receiptMint: { value: input.receiptMint ?? null, isWritable: false },
+    receiptTokenAccount: {
+      value: input.receiptTokenAccount ?? null,
+      isWritable: false,
+    },
+    receiptRewards: { value: input.receiptRewards ?? null, isWritable: true },
+    systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Resolve default values.
+  if (!accounts.systemProgram.value) {
+    accounts.systemProgram.value =
+      '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.liquidStakePool),
+      getAccountMeta(accounts.receiptMint),
+      getAccountMeta(accounts.receiptTokenAccount),
+      getAccountMeta(accounts.receiptRewards),
+      getAccountMeta(accounts.systemProgram),
+    ],
+    programAddress,
+    data: getInitializeReceiptRewardsInstructionDataEncoder().encode({}),
+  } as InitializeReceiptRewardsInstruction<
+    typeof PALADIN_STAKE_PROGRAM_PROGRAM_ADDRESS,
+    TAccountLiquidStakePool,
+    TAccountReceiptMint,
+    TAccountReceiptTokenAccount,
+    TAccountReceiptRewards,
+    TAccountSystemProgram
+  >;
+
+  return instruction;
+}
+
+export type ParsedInitializeReceiptRewardsInstruction<
+  TProgram extends string = typeof PALADIN_STAKE_PROGRAM_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    /** Liquid stake pool account */
+    liquidStakePool: TAccountMetas[0];
+    /** Receipt token mint */
+    receiptMint: TAccountMetas[1];
+    /** Receipt token account */
+    receiptTokenAccount: TAccountMetas[2];
+    /** Receipt rewards account */
+    receiptRewards: TAccountMetas[3];
+    /** System program */
+    systemProgram: TAccountMetas[4];
+  };
+  data: InitializeReceiptRewardsInstructionData;
+};
+
+export function parseInitializeReceiptRewardsInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedInitializeReceiptRewardsInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 5) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      liquidStakePool: getNextAccount(),
+      receiptMint: getNextAccount(),
+      receiptTokenAccount: getNextAccount(),
+      receiptRewards: getNextAccount(),
+      systemProgram: getNextAccount(),
+    },
+    data: getInitializeReceiptRewardsInstructionDataDecoder().decode(
+      instruction.data
+    ),
+  };
+}
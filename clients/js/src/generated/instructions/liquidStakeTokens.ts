@@ -0,0 +1,490 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getStructDecoder,
+  getStructEncoder,
+  getU64Decoder,
+  getU64Encoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IAccountSignerMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlyAccount,
+  type ReadonlySignerAccount,
+  type TransactionSigner,
+  type WritableAccount,
+} from '@solana/web3.js';
+import { PALADIN_STAKE_PROGRAM_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+
+export const LIQUID_STAKE_TOKENS_DISCRIMINATOR = 26;
+
+export function getLiquidStakeTokensDiscriminatorBytes() {
+  return getU8Encoder().encode(LIQUID_STAKE_TOKENS_DISCRIMINATOR);
+}
+
+export type LiquidStakeTokensInstruction<
+  TProgram extends string = typeof PALADIN_STAKE_PROGRAM_PROGRAM_ADDRESS,
+  TAccountConfig extends string | IAccountMeta<string> = string,
+  TAccountHolderRewardsPool extends string | IAccountMeta<string> = string,
+  TAccountHolderRewardsPoolTokenAccount extends
+    | string
+    | IAccountMeta<string> = string,
+  TAccountValidatorStake extends string | IAccountMeta<string> = string,
+  TAccountLiquidStakePool extends string | IAccountMeta<string> = string,
+  TAccountSourceTokenAccount extends string | IAccountMeta<string> = string,
+  TAccountSourceTokenAccountAuthority extends
+    | string
+    | IAccountMeta<string> = string,
+  TAccountMint extends string | IAccountMeta<string> = string,
+  TAccountVaultPda extends string | IAccountMeta<string> = string,
+  TAccountVault extends string | IAccountMeta<string> = string,
+  TAccountVaultHolderRewards extends string | IAccountMeta<string> = string,
+  TAccountTokenProgram extends
+    | string
+    | IAccountMeta<string> = 'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA',
+  TAccountRewardsProgram extends string | IAccountMeta<string> = string,
+  TAccountReceiptMint extends string | IAccountMeta<string> = string,
+  TAccountDestinationReceiptAccount extends
+    | string
+    | IAccountMeta<string> = string,
+  TAccountDestinationReceiptRewards extends
+    | string
+    | IAccountMeta<string> = string,
+  TAccountReceiptTokenProgram extends string | IAccountMeta<string> = string,
+  TAccountStakeProgram extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountConfig extends string
+        ? WritableAccount<TAccountConfig>
+        : TAccountConfig,
+      TAccountHolderRewardsPool extends string
+        ? WritableAccount<TAccountHolderRewardsPool>
+        : TAccountHolderRewardsPool,
+      TAccountHolderRewardsPoolTokenAccount extends string
+        ? WritableAccount<TAccountHolderRewardsPoolTokenAccount>
+        : TAccountHolderRewardsPoolTokenAccount,
+      TAccountValidatorStake extends string
+        ? WritableAccount<TAccountValidatorStake>
+        : TAccountValidatorStake,
+      TAccountLiquidStakePool extends string
+        ? WritableAccount<TAccountLiquidStakePool>
+        : TAccountLiquidStakePool,
+      TAccountSourceTokenAccount extends string
+        ? WritableAccount<TAccountSourceTokenAccount>
+        : TAccountSourceTokenAccount,
+      TAccountSourceTokenAccountAuthority extends string
+        ? ReadonlySignerAccount<TAccountSourceTokenAccountAuthority> &
+            IAccountSignerMeta<TAccountSourceTokenAccountAuthority>
+        : TAccountSourceTokenAccountAuthority,
+      TAccountMint extends string
+        ? ReadonlyAccount<TAccountMint>
+        : TAccountMint,
+      TAccountVaultPda extends string
+        ? WritableAccount<TAccountVaultPda>
+        : TAccountVaultPda,
+      TAccountVault extends string
+        ? WritableAccount<TAccountVault>
+        : TAccountVault,
+      TAccountVaultHolderRewards extends string
+        ? WritableAccount<TAccountVaultHolderRewards>
+        : TAccountVaultHolderRewards,
+      TAccountTokenProgram extends string
+        ? ReadonlyAccount<TAccountTokenProgram>
+        : TAccountTokenProgram,
+      TAccountRewardsProgram extends string
+        ? ReadonlyAccount<TAccountRewardsProgram>
+        : TAccountRewardsProgram,
+      TAccountReceiptMint extends string
+        ? WritableAccount<TAccountReceiptMint>
+        : TAccountReceiptMint,
+      TAccountDestinationReceiptAccount extends string
+        ? WritableAccount<TAccountDestinationReceiptAccount>
+        : TAccountDestinationReceiptAccount,
+      TAccountDestinationReceiptRewards extends string
+        ? WritableAccount<TAccountDestinationReceiptRewards>
+        : TAccountDestinationReceiptRewards,
+      TAccountReceiptTokenProgram extends string
+        ? ReadonlyAccount<TAccountReceiptTokenProgram>
+        : TAccountReceiptTokenProgram,
+      TAccountStakeProgram extends string
+        ? ReadonlyAccount<TAccountStakeProgram>
+        : TAccountStakeProgram,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type LiquidStakeTokensInstructionData = {
+  discriminator: number;
+  amount: bigint;
+};
+
+export type LiquidStakeTokensInstructionDataArgs = { amount: number | bigint };
+
+export function getLiquidStakeTokensInstructionDataEncoder(): Encoder<LiquidStakeTokensInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([
+      ['discriminator', getU8Encoder()],
+      ['amount', getU64Encoder()],
+    ]),
+    (value) => ({ ...value, discriminator: LIQUID_STAKE_TOKENS_DISCRIMINATOR })
+  );
+}
+
+export function getLiquidStakeTokensInstructionDataDecoder(): Decoder<LiquidStakeTokensInstructionData> {
+  return getStructDecoder([
+    ['discriminator', getU8Decoder()],
+    ['amount', getU64Decoder()],
+  ]);
+}
+
+export function getLiquidStakeTokensInstructionDataCodec(): Codec<
+  LiquidStakeTokensInstructionDataArgs,
+  LiquidStakeTokensInstructionData
+> {
+  return combineCodec(
+    getLiquidStakeTokensInstructionDataEncoder(),
+    getLiquidStakeTokensInstructionDataDecoder()
+  );
+}
+
+export type LiquidStakeTokensInput<
+  TAccountConfig extends string = string,
+  TAccountHolderRewardsPool extends string = string,
+  TAccountHolderRewardsPoolTokenAccount extends string = string,
+  TAccountValidatorStake extends string = string,
+  TAccountLiquidStakePool extends string = string,
+  TAccountSourceTokenAccount extends string = string,
+  TAccountSourceTokenAccountAuthority extends string = string,
+  TAccountMint extends string = string,
+  TAccountVaultPda extends string = string,
+  TAccountVault extends string = string,
+  TAccountVaultHolderRewards extends string = string,
+  TAccountTokenProgram extends string = string,
+  TAccountRewardsProgram extends string = string,
+  TAccountReceiptMint extends string = string,
+  TAccountDestinationReceiptAccount extends string = string,
+  TAccountDestinationReceiptRewards extends string = string,
+  TAccountReceiptTokenProgram extends string = string,
+  TAccountStakeProgram extends string = string,
+> = {
+  /** Stake config account */
+  config: Address<TAccountConfig>;
+  /** Holder rewards pool account */
+  holderRewardsPool: Address<TAccountHolderRewardsPool>;
+  /** Holder rewards pool account token account */
+  holderRewardsPoolTokenAccount: Address<TAccountHolderRewardsPoolTokenAccount>;
+  /** Pooled validator stake account */
+  validatorStake: Address<TAccountValidatorStake>;
+  /** Liquid stake pool account */
+  liquidStakePool: Address<TAccountLiquidStakePool>;
+  /** Token account */
+  sourceTokenAccount: Address<TAccountSourceTokenAccount>;
+  /** Owner or delegate of the token account */
+  sourceTokenAccountAuthority: TransactionSigner<TAccountSourceTokenAccountAuthority>;
+  /** Stake Token Mint */
+  mint: Address<TAccountMint>;
+  /** Vault authority */
+  vaultPda: Address<TAccountVaultPda>;
+  /** Stake token Vault */
+  vault: Address<TAccountVault>;
+  /** Vault holder rewards account */
+  vaultHolderRewards: Address<TAccountVaultHolderRewards>;
+  /** Token program */
+  tokenProgram?: Address<TAccountTokenProgram>;
+  /** Paladin rewards program */
+  rewardsProgram: Address<TAccountRewardsProgram>;
+  /** Receipt token mint */
+  receiptMint: Address<TAccountReceiptMint>;
+  /** Destination receipt token account */
+  destinationReceiptAccount: Address<TAccountDestinationReceiptAccount>;
+  /** Receipt rewards account of the destination */
+  destinationReceiptRewards: Address<TAccountDestinationReceiptRewards>;
+  /** Receipt token program */
+  receiptTokenProgram: Address<TAccountReceiptTokenProgram>;
+  /** Stake program */
+  stakeProgram: Address<TAccountStakeProgram>;
+  amount: LiquidStakeTokensInstructionDataArgs['amount'];
+};
+
+export function getLiquidStakeTokensInstruction<
+  TAccountConfig extends string,
+  TAccountHolderRewardsPool extends string,
+  TAccountHolderRewardsPoolTokenAccount extends string,
+  TAccountValidatorStake extends string,
+  TAccountLiquidStakePool extends string,
+  TAccountSourceTokenAccount extends string,
+  TAccountSourceTokenAccountAuthority extends string,
+  TAccountMint extends string,
+  TAccountVaultPda extends string,
+  TAccountVault extends string,
+  TAccountVaultHolderRewards extends string,
+  TAccountTokenProgram extends string,
+  TAccountRewardsProgram extends string,
+  TAccountReceiptMint extends string,
+  TAccountDestinationReceiptAccount extends string,
+  TAccountDestinationReceiptRewards extends string,
+  TAccountReceiptTokenProgram extends string,
+  TAccountStakeProgram extends string,
+>(
+  input: LiquidStakeTokensInput<
+    TAccountConfig,
+    TAccountHolderRewardsPool,
+    TAccountHolderRewardsPoolTokenAccount,
+    TAccountValidatorStake,
+    TAccountLiquidStakePool,
+    TAccountSourceTokenAccount,
+    TAccountSourceTokenAccountAuthority,
+    TAccountMint,
+    TAccountVaultPda,
+    TAccountVault,
+    TAccountVaultHolderRewards,
+    TAccountTokenProgram,
+    TAccountRewardsProgram,
+    TAccountReceiptMint,
+    TAccountDestinationReceiptAccount,
+    TAccountDestinationReceiptRewards,
+    TAccountReceiptTokenProgram,
+    TAccountStakeProgram
+  >
+): LiquidStakeTokensInstruction<
+  typeof PALADIN_STAKE_PROGRAM_PROGRAM_ADDRESS,
+  TAccountConfig,
+  TAccountHolderRewardsPool,
+  TAccountHolderRewardsPoolTokenAccount,
+  TAccountValidatorStake,
+  TAccountLiquidStakePool,
+  TAccountSourceTokenAccount,
+  TAccountSourceTokenAccountAuthority,
+  TAccountMint,
+  TAccountVaultPda,
+  TAccountVault,
+  TAccountVaultHolderRewards,
+  TAccountTokenProgram,
+  TAccountRewardsProgram,
+  TAccountReceiptMint,
+  TAccountDestinationReceiptAccount,
+  TAccountDestinationReceiptRewards,
+  TAccountReceiptTokenProgram,
+  TAccountStakeProgram
+> {
+  // Program address.
+  const programAddress = PALADIN_STAKE_PROGRAM_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: true },
+    holderRewardsPool: {
+      value: input.holderRewardsPool ?? null,
+      isWritable: true,
+    },
+    holderRewardsPoolTokenAccount: {
+      value: input.holderRewardsPoolTokenAccount ?? null,
+      isWritable: true,
+    },
+    validatorStake: { value: input.validatorStake ?? null, isWritable: true },
+    liquidStakePool: { value: input.liquidStakePool ?? null, isWritable: true },
+    sourceTokenAccount: {
+      value: input.sourceTokenAccount ?? null,
+      isWritable: true,
+    },
+    sourceTokenAccountAuthority: {
+      value: input.sourceTokenAccountAuthority ?? null,
+      isWritable: false,
+    },
+    mint: { value: input.mint ?? null, isWritable: false },
+    vaultPda: { value: input.vaultPda ?? null, isWritable: true },
+    vault: { value: input.vault ?? null, isWritable: true },
+    vaultHolderRewards: {
+      value: input.vaultHolderRewards ?? null,
+      isWritable: true,
+    },
+    tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
+    rewardsProgram: { value: input.rewardsProgram ?? null, isWritable: false },
+    receiptMint: { value: input.receiptMint ?? null, isWritable: true },
+    destinationReceiptAccount: {
+      value: input.destinationReceiptAccount ?? null,
+      isWritable: true,
+    },
+    destinationReceiptRewards: {
+      value: input.destinationReceiptRewards ?? null,
+      isWritable: true,
+    },
+    receiptTokenProgram: {
+      value: input.receiptTokenProgram ?? null,
+      isWritable: false,
+    },
+    stakeProgram: { value: input.stakeProgram ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  // Resolve default values.
+  if (!accounts.tokenProgram.value) {
+    accounts.tokenProgram.value =
+      'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA' as Address<'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA'>;
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.holderRewardsPool),
+      getAccountMeta(accounts.holderRewardsPoolTokenAccount),
+      getAccountMeta(accounts.validatorStake),
+      getAccountMeta(accounts.liquidStakePool),
+      getAccountMeta(accounts.sourceTokenAccount),
+      getAccountMeta(accounts.sourceTokenAccountAuthority),
+      getAccountMeta(accounts.mint),
+      getAccountMeta(accounts.vaultPda),
+      getAccountMeta(accounts.vault),
+      getAccountMeta(accounts.vaultHolderRewards),
+      getAccountMeta(accounts.tokenProgram),
+      getAccountMeta(accounts.rewardsProgram),
+      getAccountMeta(accounts.receiptMint),
+      getAccountMeta(accounts.destinationReceiptAccount),
+      getAccountMeta(accounts.destinationReceiptRewards),
+      getAccountMeta(accounts.receiptTokenProgram),
+      getAccountMeta(accounts.stakeProgram),
+    ],
+    programAddress,
+    data: getLiquidStakeTokensInstructionDataEncoder().encode(
+      args as LiquidStakeTokensInstructionDataArgs
+    ),
+  } as LiquidStakeTokensInstruction<
+    typeof PALADIN_STAKE_PROGRAM_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountHolderRewardsPool,
+    TAccountHolderRewardsPoolTokenAccount,
+    TAccountValidatorStake,
+    TAccountLiquidStakePool,
+    TAccountSourceTokenAccount,
+    TAccountSourceTokenAccountAuthority,
+    TAccountMint,
+    TAccountVaultPda,
+    TAccountVault,
+    TAccountVaultHolderRewards,
+    TAccountTokenProgram,
+    TAccountRewardsProgram,
+    TAccountReceiptMint,
+    TAccountDestinationReceiptAccount,
+    TAccountDestinationReceiptRewards,
+    TAccountReceiptTokenProgram,
+    TAccountStakeProgram
+  >;
+
+  return instruction;
+}
+
+export type ParsedLiquidStakeTokensInstruction<
+  TProgram extends string = typeof PALADIN_STAKE_PROGRAM_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    /** Stake config account */
+    config: TAccountMetas[0];
+    /** Holder rewards pool account */
+    holderRewardsPool: TAccountMetas[1];
+    /** Holder rewards pool account token account */
+    holderRewardsPoolTokenAccount: TAccountMetas[2];
+    /** Pooled validator stake account */
+    validatorStake: TAccountMetas[3];
+    /** Liquid stake pool account */
+    liquidStakePool: TAccountMetas[4];
+    /** Token account */
+    sourceTokenAccount: TAccountMetas[5];
+    /** Owner or delegate of the token account */
+    sourceTokenAccountAuthority: TAccountMetas[6];
+    /** Stake Token Mint */
+    mint: TAccountMetas[7];
+    /** Vault authority */
+    vaultPda: TAccountMetas[8];
+    /** Stake token Vault */
+    vault: TAccountMetas[9];
+    /** Vault holder rewards account */
+    vaultHolderRewards: TAccountMetas[10];
+    /** Token program */
+    tokenProgram: TAccountMetas[11];
+    /** Paladin rewards program */
+    rewardsProgram: TAccountMetas[12];
+    /** Receipt token mint */
+    receiptMint: TAccountMetas[13];
+    /** Destination receipt token account */
+    destinationReceiptAccount: TAccountMetas[14];
+    /** Receipt rewards account of the destination */
+    destinationReceiptRewards: TAccountMetas[15];
+    /** Receipt token program */
+    receiptTokenProgram: TAccountMetas[16];
+    /** Stake program */
+    stakeProgram: TAccountMetas[17];
+  };
+  data: LiquidStakeTokensInstructionData;
+};
+
+export function parseLiquidStakeTokensInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedLiquidStakeTokensInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 18) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      config: getNextAccount(),
+      holderRewardsPool: getNextAccount(),
+      holderRewardsPoolTokenAccount: getNextAccount(),
+      validatorStake: getNextAccount(),
+      liquidStakePool: getNextAccount(),
+      sourceTokenAccount: getNextAccount(),
+      sourceTokenAccountAuthority: getNextAccount(),
+      mint: getNextAccount(),
+      vaultPda: getNextAccount(),
+      vault: getNextAccount(),
+      vaultHolderRewards: getNextAccount(),
+      tokenProgram: getNextAccount(),
+      rewardsProgram: getNextAccount(),
+      receiptMint: getNextAccount(),
+      destinationReceiptAccount: getNextAccount(),
+      destinationReceiptRewards: getNextAccount(),
+      receiptTokenProgram: getNextAccount(),
+      stakeProgram: getNextAccount(),
+    },
+    data: getLiquidStakeTokensInstructionDataDecoder().decode(instruction.data),
+  };
+}
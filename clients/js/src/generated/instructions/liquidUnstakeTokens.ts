@@ -0,0 +1,493 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getStructDecoder,
+  getStructEncoder,
+  getU64Decoder,
+  getU64Encoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IAccountSignerMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlyAccount,
+  type ReadonlySignerAccount,
+  type TransactionSigner,
+  type WritableAccount,
+} from '@solana/web3.js';
+import { PALADIN_STAKE_PROGRAM_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+
+export const LIQUID_UNSTAKE_TOKENS_DISCRIMINATOR = 27;
+
+export function getLiquidUnstakeTokensDiscriminatorBytes() {
+  return getU8Encoder().encode(LIQUID_UNSTAKE_TOKENS_DISCRIMINATOR);
+}
+
+export type LiquidUnstakeTokensInstruction<
+  TProgram extends string = typeof PALADIN_STAKE_PROGRAM_PROGRAM_ADDRESS,
+  TAccountConfig extends string | IAccountMeta<string> = string,
+  TAccountHolderRewardsPool extends string | IAccountMeta<string> = string,
+  TAccountHolderRewardsPoolTokenAccount extends
+    | string
+    | IAccountMeta<string> = string,
+  TAccountValidatorStake extends string | IAccountMeta<string> = string,
+  TAccountLiquidStakePool extends string | IAccountMeta<string> = string,
+  TAccountVault extends string | IAccountMeta<string> = string,
+  TAccountVaultPda extends string | IAccountMeta<string> = string,
+  TAccountVaultHolderRewards extends string | IAccountMeta<string> = string,
+  TAccountMint extends string | IAccountMeta<string> = string,
+  TAccountDestinationTokenAccount extends
+    | string
+    | IAccountMeta<string> = string,
+  TAccountTokenProgram extends
+    | string
+    | IAccountMeta<string> = 'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA',
+  TAccountRewardsProgram extends string | IAccountMeta<string> = string,
+  TAccountReceiptMint extends string | IAccountMeta<string> = string,
+  TAccountSourceReceiptAccount extends string | IAccountMeta<string> = string,
+  TAccountSourceReceiptRewards extends string | IAccountMeta<string> = string,
+  TAccountSourceReceiptAuthority extends string | IAccountMeta<string> = string,
+  TAccountReceiptTokenProgram extends string | IAccountMeta<string> = string,
+  TAccountStakeProgram extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountConfig extends string
+        ? WritableAccount<TAccountConfig>
+        : TAccountConfig,
+      TAccountHolderRewardsPool extends string
+        ? WritableAccount<TAccountHolderRewardsPool>
+        : TAccountHolderRewardsPool,
+      TAccountHolderRewardsPoolTokenAccount extends string
+        ? WritableAccount<TAccountHolderRewardsPoolTokenAccount>
+        : TAccountHolderRewardsPoolTokenAccount,
+      TAccountValidatorStake extends string
+        ? WritableAccount<TAccountValidatorStake>
+        : TAccountValidatorStake,
+      TAccountLiquidStakePool extends string
+        ? WritableAccount<TAccountLiquidStakePool>
+        : TAccountLiquidStakePool,
+      TAccountVault extends string
+        ? WritableAccount<TAccountVault>
+        : TAccountVault,
+      TAccountVaultPda extends string
+        ? WritableAccount<TAccountVaultPda>
+        : TAccountVaultPda,
+      TAccountVaultHolderRewards extends string
+        ? WritableAccount<TAccountVaultHolderRewards>
+        : TAccountVaultHolderRewards,
+      TAccountMint extends string
+        ? ReadonlyAccount<TAccountMint>
+        : TAccountMint,
+      TAccountDestinationTokenAccount extends string
+        ? WritableAccount<TAccountDestinationTokenAccount>
+        : TAccountDestinationTokenAccount,
+      TAccountTokenProgram extends string
+        ? ReadonlyAccount<TAccountTokenProgram>
+        : TAccountTokenProgram,
+      TAccountRewardsProgram extends string
+        ? ReadonlyAccount<TAccountRewardsProgram>
+        : TAccountRewardsProgram,
+      TAccountReceiptMint extends string
+        ? WritableAccount<TAccountReceiptMint>
+        : TAccountReceiptMint,
+      TAccountSourceReceiptAccount extends string
+        ? WritableAccount<TAccountSourceReceiptAccount>
+        : TAccountSourceReceiptAccount,
+      TAccountSourceReceiptRewards extends string
+        ? WritableAccount<TAccountSourceReceiptRewards>
+        : TAccountSourceReceiptRewards,
+      TAccountSourceReceiptAuthority extends string
+        ? ReadonlySignerAccount<TAccountSourceReceiptAuthority> &
+            IAccountSignerMeta<TAccountSourceReceiptAuthority>
+        : TAccountSourceReceiptAuthority,
+      TAccountReceiptTokenProgram extends string
+        ? ReadonlyAccount<TAccountReceiptTokenProgram>
+        : TAccountReceiptTokenProgram,
+      TAccountStakeProgram extends string
+        ? ReadonlyAccount<TAccountStakeProgram>
+        : TAccountStakeProgram,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type LiquidUnstakeTokensInstructionData = {
+  discriminator: number;
+  amount: bigint;
+};
+
+export type LiquidUnstakeTokensInstructionDataArgs = {
+  amount: number | bigint;
+};
+
+export function getLiquidUnstakeTokensInstructionDataEncoder(): Encoder<LiquidUnstakeTokensInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([
+      ['discriminator', getU8Encoder()],
+      ['amount', getU64Encoder()],
+    ]),
+    (value) => ({
+      ...value,
+      discriminator: LIQUID_UNSTAKE_TOKENS_DISCRIMINATOR,
+    })
+  );
+}
+
+export function getLiquidUnstakeTokensInstructionDataDecoder(): Decoder<LiquidUnstakeTokensInstructionData> {
+  return getStructDecoder([
+    ['discriminator', getU8Decoder()],
+    ['amount', getU64Decoder()],
+  ]);
+}
+
+export function getLiquidUnstakeTokensInstructionDataCodec(): Codec<
+  LiquidUnstakeTokensInstructionDataArgs,
+  LiquidUnstakeTokensInstructionData
+> {
+  return combineCodec(
+    getLiquidUnstakeTokensInstructionDataEncoder(),
+    getLiquidUnstakeTokensInstructionDataDecoder()
+  );
+}
+
+export type LiquidUnstakeTokensInput<
+  TAccountConfig extends string = string,
+  TAccountHolderRewardsPool extends string = string,
+  TAccountHolderRewardsPoolTokenAccount extends string = string,
+  TAccountValidatorStake extends string = string,
+  TAccountLiquidStakePool extends string = string,
+  TAccountVault extends string = string,
+  TAccountVaultPda extends string = string,
+  TAccountVaultHolderRewards extends string = string,
+  TAccountMint extends string = string,
+  TAccountDestinationTokenAccount extends string = string,
+  TAccountTokenProgram extends string = string,
+  TAccountRewardsProgram extends string = string,
+  TAccountReceiptMint extends string = string,
+  TAccountSourceReceiptAccount extends string = string,
+  TAccountSourceReceiptRewards extends string = string,
+  TAccountSourceReceiptAuthority extends string = string,
+  TAccountReceiptTokenProgram extends string = string,
+  TAccountStakeProgram extends string = string,
+> = {
+  /** Stake config account */
+  config: Address<TAccountConfig>;
+  /** Holder rewards pool account */
+  holderRewardsPool: Address<TAccountHolderRewardsPool>;
+  /** Holder rewards pool account token account */
+  holderRewardsPoolTokenAccount: Address<TAccountHolderRewardsPoolTokenAccount>;
+  /** Pooled validator stake account */
+  validatorStake: Address<TAccountValidatorStake>;
+  /** Liquid stake pool account */
+  liquidStakePool: Address<TAccountLiquidStakePool>;
+  /** Vault account */
+  vault: Address<TAccountVault>;
+  /** Vault authority */
+  vaultPda: Address<TAccountVaultPda>;
+  /** Vault holder rewards account */
+  vaultHolderRewards: Address<TAccountVaultHolderRewards>;
+  /** Mint account */
+  mint: Address<TAccountMint>;
+  /** Destination token account */
+  destinationTokenAccount: Address<TAccountDestinationTokenAccount>;
+  /** Token program */
+  tokenProgram?: Address<TAccountTokenProgram>;
+  /** Paladin rewards program */
+  rewardsProgram: Address<TAccountRewardsProgram>;
+  /** Receipt token mint */
+  receiptMint: Address<TAccountReceiptMint>;
+  /** Source receipt token account */
+  sourceReceiptAccount: Address<TAccountSourceReceiptAccount>;
+  /** Receipt rewards account of the source */
+  sourceReceiptRewards: Address<TAccountSourceReceiptRewards>;
+  /** Owner or delegate of the source receipt token account */
+  sourceReceiptAuthority: TransactionSigner<TAccountSourceReceiptAuthority>;
+  /** Receipt token program */
+  receiptTokenProgram: Address<TAccountReceiptTokenProgram>;
+  /** Stake program */
+  stakeProgram: Address<TAccountStakeProgram>;
+  amount: LiquidUnstakeTokensInstructionDataArgs['amount'];
+};
+
+export function getLiquidUnstakeTokensInstruction<
+  TAccountConfig extends string,
+  TAccountHolderRewardsPool extends string,
+  TAccountHolderRewardsPoolTokenAccount extends string,
+  TAccountValidatorStake extends string,
+  TAccountLiquidStakePool extends string,
+  TAccountVault extends string,
+  TAccountVaultPda extends string,
+  TAccountVaultHolderRewards extends string,
+  TAccountMint extends string,
+  TAccountDestinationTokenAccount extends string,
+  TAccountTokenProgram extends string,
+  TAccountRewardsProgram extends string,
+  TAccountReceiptMint extends string,
+  TAccountSourceReceiptAccount extends string,
+  TAccountSourceReceiptRewards extends string,
+  TAccountSourceReceiptAuthority extends string,
+  TAccountReceiptTokenProgram extends string,
+  TAccountStakeProgram extends string,
+>(
+  input: LiquidUnstakeTokensInput<
+    TAccountConfig,
+    TAccountHolderRewardsPool,
+    TAccountHolderRewardsPoolTokenAccount,
+    TAccountValidatorStake,
+    TAccountLiquidStakePool,
+    TAccountVault,
+    TAccountVaultPda,
+    TAccountVaultHolderRewards,
+    TAccountMint,
+    TAccountDestinationTokenAccount,
+    TAccountTokenProgram,
+    TAccountRewardsProgram,
+    TAccountReceiptMint,
+    TAccountSourceReceiptAccount,
+    TAccountSourceReceiptRewards,
+    TAccountSourceReceiptAuthority,
+    TAccountReceiptTokenProgram,
+    TAccountStakeProgram
+  >
+): LiquidUnstakeTokensInstruction<
+  typeof PALADIN_STAKE_PROGRAM_PROGRAM_ADDRESS,
+  TAccountConfig,
+  TAccountHolderRewardsPool,
+  TAccountHolderRewardsPoolTokenAccount,
+  TAccountValidatorStake,
+  TAccountLiquidStakePool,
+  TAccountVault,
+  TAccountVaultPda,
+  TAccountVaultHolderRewards,
+  TAccountMint,
+  TAccountDestinationTokenAccount,
+  TAccountTokenProgram,
+  TAccountRewardsProgram,
+  TAccountReceiptMint,
+  TAccountSourceReceiptAccount,
+  TAccountSourceReceiptRewards,
+  TAccountSourceReceiptAuthority,
+  TAccountReceiptTokenProgram,
+  TAccountStakeProgram
+> {
+  // Program address.
+  const programAddress = PALADIN_STAKE_PROGRAM_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: true },
+    holderRewardsPool: {
+      value: input.holderRewardsPool ?? null,
+      isWritable: true,
+    },
+    holderRewardsPoolTokenAccount: {
+      value: input.holderRewardsPoolTokenAccount ?? null,
+      isWritable: true,
+    },
+    validatorStake: { value: input.validatorStake ?? null, isWritable: true },
+    liquidStakePool: { value: input.liquidStakePool ?? null, isWritable: true },
+    vault: { value: input.vault ?? null, isWritable: true },
+    vaultPda: { value: input.vaultPda ?? null, isWritable: true },
+    vaultHolderRewards: {
+      value: input.vaultHolderRewards ?? null,
+      isWritable: true,
+    },
+    mint: { value: input.mint ?? null, isWritable: false },
+    destinationTokenAccount: {
+      value: input.destinationTokenAccount ?? null,
+      isWritable: true,
+    },
+    tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
+    rewardsProgram: { value: input.rewardsProgram ?? null, isWritable: false },
+    receiptMint: { value: input.receiptMint ?? null, isWritable: true },
+    sourceReceiptAccount: {
+      value: input.sourceReceiptAccount ?? null,
+      isWritable: true,
+    },
+    sourceReceiptRewards: {
+      value: input.sourceReceiptRewards ?? null,
+      isWritable: true,
+    },
+    sourceReceiptAuthority: {
+      value: input.sourceReceiptAuthority ?? null,
+      isWritable: false,
+    },
+    receiptTokenProgram: {
+      value: input.receiptTokenProgram ?? null,
+      isWritable: false,
+    },
+    stakeProgram: { value: input.stakeProgram ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  // Resolve default values.
+  if (!accounts.tokenProgram.value) {
+    accounts.tokenProgram.value =
+      'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA' as Address<'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA'>;
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.holderRewardsPool),
+      getAccountMeta(accounts.holderRewardsPoolTokenAccount),
+      getAccountMeta(accounts.validatorStake),
+      getAccountMeta(accounts.liquidStakePool),
+      getAccountMeta(accounts.vault),
+      getAccountMeta(accounts.vaultPda),
+      getAccountMeta(accounts.vaultHolderRewards),
+      getAccountMeta(accounts.mint),
+      getAccountMeta(accounts.destinationTokenAccount),
+      getAccountMeta(accounts.tokenProgram),
+      getAccountMeta(accounts.rewardsProgram),
+      getAccountMeta(accounts.receiptMint),
+      getAccountMeta(accounts.sourceReceiptAccount),
+      getAccountMeta(accounts.sourceReceiptRewards),
+      getAccountMeta(accounts.sourceReceiptAuthority),
+      getAccountMeta(accounts.receiptTokenProgram),
+      getAccountMeta(accounts.stakeProgram),
+    ],
+    programAddress,
+    data: getLiquidUnstakeTokensInstructionDataEncoder().encode(
+      args as LiquidUnstakeTokensInstructionDataArgs
+    ),
+  } as LiquidUnstakeTokensInstruction<
+    typeof PALADIN_STAKE_PROGRAM_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountHolderRewardsPool,
+    TAccountHolderRewardsPoolTokenAccount,
+    TAccountValidatorStake,
+    TAccountLiquidStakePool,
+    TAccountVault,
+    TAccountVaultPda,
+    TAccountVaultHolderRewards,
+    TAccountMint,
+    TAccountDestinationTokenAccount,
+    TAccountTokenProgram,
+    TAccountRewardsProgram,
+    TAccountReceiptMint,
+    TAccountSourceReceiptAccount,
+    TAccountSourceReceiptRewards,
+    TAccountSourceReceiptAuthority,
+    TAccountReceiptTokenProgram,
+    TAccountStakeProgram
+  >;
+
+  return instruction;
+}
+
+export type ParsedLiquidUnstakeTokensInstruction<
+  TProgram extends string = typeof PALADIN_STAKE_PROGRAM_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    /** Stake config account */
+    config: TAccountMetas[0];
+    /** Holder rewards pool account */
+    holderRewardsPool: TAccountMetas[1];
+    /** Holder rewards pool account token account */
+    holderRewardsPoolTokenAccount: TAccountMetas[2];
+    /** Pooled validator stake account */
+    validatorStake: TAccountMetas[3];
+    /** Liquid stake pool account */
+    liquidStakePool: TAccountMetas[4];
+    /** Vault account */
+    vault: TAccountMetas[5];
+    /** Vault authority */
+    vaultPda: TAccountMetas[6];
+    /** Vault holder rewards account */
+    vaultHolderRewards: TAccountMetas[7];
+    /** Mint account */
+    mint: TAccountMetas[8];
+    /** Destination token account */
+    destinationTokenAccount: TAccountMetas[9];
+    /** Token program */
+    tokenProgram: TAccountMetas[10];
+    /** Paladin rewards program */
+    rewardsProgram: TAccountMetas[11];
+    /** Receipt token mint */
+    receiptMint: TAccountMetas[12];
+    /** Source receipt token account */
+    sourceReceiptAccount: TAccountMetas[13];
+    /** Receipt rewards account of the source */
+    sourceReceiptRewards: TAccountMetas[14];
+    /** Owner or delegate of the source receipt token account */
+    sourceReceiptAuthority: TAccountMetas[15];
+    /** Receipt token program */
+    receiptTokenProgram: TAccountMetas[16];
+    /** Stake program */
+    stakeProgram: TAccountMetas[17];
+  };
+  data: LiquidUnstakeTokensInstructionData;
+};
+
+export function parseLiquidUnstakeTokensInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedLiquidUnstakeTokensInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 18) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      config: getNextAccount(),
+      holderRewardsPool: getNextAccount(),
+      holderRewardsPoolTokenAccount: getNextAccount(),
+      validatorStake: getNextAccount(),
+      liquidStakePool: getNextAccount(),
+      vault: getNextAccount(),
+      vaultPda: getNextAccount(),
+      vaultHolderRewards: getNextAccount(),
+      mint: getNextAccount(),
+      destinationTokenAccount: getNextAccount(),
+      tokenProgram: getNextAccount(),
+      rewardsProgram: getNextAccount(),
+      receiptMint: getNextAccount(),
+      sourceReceiptAccount: getNextAccount(),
+      sourceReceiptRewards: getNextAccount(),
+      sourceReceiptAuthority: getNextAccount(),
+      receiptTokenProgram: getNextAccount(),
+      stakeProgram: getNextAccount(),
+    },
+    data: getLiquidUnstakeTokensInstructionDataDecoder().decode(
+      instruction.data
+    ),
+  };
+}
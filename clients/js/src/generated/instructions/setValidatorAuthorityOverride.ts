@@ -0,0 +1,264 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getStructDecoder,
+  getStructEncoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IAccountSignerMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlyAccount,
+  type ReadonlySignerAccount,
+  type TransactionSigner,
+  type WritableAccount,
+} from '@solana/web3.js';
+import { PALADIN_STAKE_PROGRAM_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+
+export const SET_VALIDATOR_AUTHORITY_OVERRIDE_DISCRIMINATOR = 22;
+
+export function getSetValidatorAuthorityOverrideDiscriminatorBytes() {
+  return getU8Encoder().encode(SET_VALIDATOR_AUTHORITY_OVERRIDE_DISCRIMINATOR);
+}
+
+export type SetValidatorAuthorityOverrideInstruction<
+  TProgram extends string = typeof PALADIN_STAKE_PROGRAM_PROGRAM_ADDRESS,
+  TAccountConfig extends string | IAccountMeta<string> = string,
+  TAccountValidatorVote extends string | IAccountMeta<string> = string,
+  TAccountWithdrawAuthority extends string | IAccountMeta<string> = string,
+  TAccountValidatorAuthorityOverride extends
+    | string
+    | IAccountMeta<string> = string,
+  TAccountNewAuthority extends string | IAccountMeta<string> = string,
+  TAccountSystemProgram extends
+    | string
+    | IAccountMeta<string> = '11111111111111111111111111111111',
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountConfig extends string
+        ? ReadonlyAccount<TAccountConfig>
+        : TAccountConfig,
+      TAccountValidatorVote extends string
+        ? ReadonlyAccount<TAccountValidatorVote>
+        : TAccountValidatorVote,
+      TAccountWithdrawAuthority extends string
+        ? ReadonlySignerAccount<TAccountWithdrawAuthority> &
+            IAccountSignerMeta<TAccountWithdrawAuthority>
+        : TAccountWithdrawAuthority,
+      TAccountValidatorAuthorityOverride extends string
+        ? WritableAccount<TAccountValidatorAuthorityOverride>
+        : TAccountValidatorAuthorityOverride,
+      TAccountNewAuthority extends string
+        ? ReadonlyAccount<TAccountNewAuthority>
+        : TAccountNewAuthority,
+      TAccountSystemProgram extends string
+        ? ReadonlyAccount<TAccountSystemProgram>
+        : TAccountSystemProgram,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type SetValidatorAuthorityOverrideInstructionData = {
+  discriminator: number;
+};
+
+export type SetValidatorAuthorityOverrideInstructionDataArgs = {};
+
+export function getSetValidatorAuthorityOverrideInstructionDataEncoder(): Encoder<SetValidatorAuthorityOverrideInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([['discriminator', getU8Encoder()]]),
+    (value) => ({
+      ...value,
+      discriminator: SET_VALIDATOR_AUTHORITY_OVERRIDE_DISCRIMINATOR,
+    })
+  );
+}
+
+export function getSetValidatorAuthorityOverrideInstructionDataDecoder(): Decoder<SetValidatorAuthorityOverrideInstructionData> {
+  return getStructDecoder([['discriminator', getU8Decoder()]]);
+}
+
+export function getSetValidatorAuthorityOverrideInstructionDataCodec(): Codec<
+  SetValidatorAuthorityOverrideInstructionDataArgs,
+  SetValidatorAuthorityOverrideInstructionData
+> {
+  return combineCodec(
+    getSetValidatorAuthorityOverrideInstructionDataEncoder(),
+    getSetValidatorAuthorityOverrideInstructionDataDecoder()
+  );
+}
+
+export type SetValidatorAuthorityOverrideInput<
+  TAccountConfig extends string = string,
+  TAccountValidatorVote extends string = string,
+  TAccountWithdrawAuthority extends string = string,
+  TAccountValidatorAuthorityOverride extends string = string,
+  TAccountNewAuthority extends string = string,
+  TAccountSystemProgram extends string = string,
+> = {
+  /** Stake config account */
+  config: Address<TAccountConfig>;
+  /** Validator vote account */
+  validatorVote: Address<TAccountValidatorVote>;
+  /** Validator vote account withdraw authority */
+  withdrawAuthority: TransactionSigner<TAccountWithdrawAuthority>;
+  /** Validator authority override */
+  validatorAuthorityOverride: Address<TAccountValidatorAuthorityOverride>;
+  /** Authority to use for the validator stake accounts */
+  newAuthority: Address<TAccountNewAuthority>;
+  /** System program */
+  systemProgram?: Address<TAccountSystemProgram>;
+};
+
+export function getSetValidatorAuthorityOverrideInstruction<
+  TAccountConfig extends string,
+  TAccountValidatorVote extends string,
+  TAccountWithdrawAuthority extends string,
+  TAccountValidatorAuthorityOverride extends string,
+  TAccountNewAuthority extends string,
+  TAccountSystemProgram extends string,
+>(
+  input: SetValidatorAuthorityOverrideInput<
+    TAccountConfig,
+    TAccountValidatorVote,
+    TAccountWithdrawAuthority,
+    TAccountValidatorAuthorityOverride,
+    TAccountNewAuthority,
+    TAccountSystemProgram
+  >
+): SetValidatorAuthorityOverrideInstruction<
+  typeof PALADIN_STAKE_PROGRAM_PROGRAM_ADDRESS,
+  TAccountConfig,
+  TAccountValidatorVote,
+  TAccountWithdrawAuthority,
+  TAccountValidatorAuthorityOverride,
+  TAccountNewAuthority,
+  TAccountSystemProgram
+> {
+  // Program address.
+  const programAddress = PALADIN_STAKE_PROGRAM_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: false },
+    validatorVote: { value: input.validatorVote ?? null, isWritable: false },
+    withdrawAuthority: {
+      value: input.withdrawAuthority ?? null,
+      isWritable: false,
+    },
+    validatorAuthorityOverride: {
+      value: input.validatorAuthorityOverride ?? null,
+      isWritable: true,
+    },
+    newAuthority: { value: input.newAuthority ?? null, isWritable: false },
+    systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Resolve default values.
+  if (!accounts.systemProgram.value) {
+    accounts.systemProgram.value =
+      '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.validatorVote),
+      getAccountMeta(accounts.withdrawAuthority),
+      getAccountMeta(accounts.validatorAuthorityOverride),
+      getAccountMeta(accounts.newAuthority),
+      getAccountMeta(accounts.systemProgram),
+    ],
+    programAddress,
+    data: getSetValidatorAuthorityOverrideInstructionDataEncoder().encode({}),
+  } as SetValidatorAuthorityOverrideInstruction<
+    typeof PALADIN_STAKE_PROGRAM_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountValidatorVote,
+    TAccountWithdrawAuthority,
+    TAccountValidatorAuthorityOverride,
+    TAccountNewAuthority,
+    TAccountSystemProgram
+  >;
+
+  return instruction;
+}
+
+export type ParsedSetValidatorAuthorityOverrideInstruction<
+  TProgram extends string = typeof PALADIN_STAKE_PROGRAM_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    /** Stake config account */
+    config: TAccountMetas[0];
+    /** Validator vote account */
+    validatorVote: TAccountMetas[1];
+    /** Validator vote account withdraw authority */
+    withdrawAuthority: TAccountMetas[2];
+    /** Validator authority override */
+    validatorAuthorityOverride: TAccountMetas[3];
+    /** Authority to use for the validator stake accounts */
+    newAuthority: TAccountMetas[4];
+    /** System program */
+    systemProgram: TAccountMetas[5];
+  };
+  data: SetValidatorAuthorityOverrideInstructionData;
+};
+
+export function parseSetValidatorAuthorityOverrideInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedSetValidatorAuthorityOverrideInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 6) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      config: getNextAccount(),
+      validatorVote: getNextAccount(),
+      withdrawAuthority: getNextAccount(),
+      validatorAuthorityOverride: getNextAccount(),
+      newAuthority: getNextAccount(),
+      systemProgram: getNextAccount(),
+    },
+    data: getSetValidatorAuthorityOverrideInstructionDataDecoder().decode(
+      instruction.data
+    ),
+  };
+}
@@ -0,0 +1,216 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getStructDecoder,
+  getStructEncoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IAccountSignerMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlyAccount,
+  type ReadonlySignerAccount,
+  type TransactionSigner,
+  type WritableAccount,
+} from '@solana/web3.js';
+import { PALADIN_STAKE_PROGRAM_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+
+export const SET_VOTE_DELEGATE_DISCRIMINATOR = 21;
+
+export function getSetVoteDelegateDiscriminatorBytes() {
+  return getU8Encoder().encode(SET_VOTE_DELEGATE_DISCRIMINATOR);
+}
+
+export type SetVoteDelegateInstruction<
+  TProgram extends string = typeof PALADIN_STAKE_PROGRAM_PROGRAM_ADDRESS,
+  TAccountConfig extends string | IAccountMeta<string> = string,
+  TAccountStake extends string | IAccountMeta<string> = string,
+  TAccountStakeAuthority extends string | IAccountMeta<string> = string,
+  TAccountVoteDelegate extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountConfig extends string
+        ? ReadonlyAccount<TAccountConfig>
+        : TAccountConfig,
+      TAccountStake extends string
+        ? WritableAccount<TAccountStake>
+        : TAccountStake,
+      TAccountStakeAuthority extends string
+        ? ReadonlySignerAccount<TAccountStakeAuthority> &
+            IAccountSignerMeta<TAccountStakeAuthority>
+        : TAccountStakeAuthority,
+      TAccountVoteDelegate extends string
+        ? ReadonlySignerAccount<TAccountVoteDelegate> &
+            IAccountSignerMeta<TAccountVoteDelegate>
+        : TAccountVoteDelegate,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type SetVoteDelegateInstructionData = { discriminator: number };
+
+export type SetVoteDelegateInstructionDataArgs = {};
+
+export function getSetVoteDelegateInstructionDataEncoder(): Encoder<SetVoteDelegateInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([['discriminator', getU8Encoder()]]),
+    (value) => ({ ...value, discriminator: SET_VOTE_DELEGATE_DISCRIMINATOR })
+  );
+}
+
+export function getSetVoteDelegateInstructionDataDecoder(): Decoder<SetVoteDelegateInstructionData> {
+  return getStructDecoder([['discriminator', getU8Decoder()]]);
+}
+
+export function getSetVoteDelegateInstructionDataCodec(): Codec<
+  SetVoteDelegateInstructionDataArgs,
+  SetVoteDelegateInstructionData
+> {
+  return combineCodec(
+    getSetVoteDelegateInstructionDataEncoder(),
+    getSetVoteDelegateInstructionDataDecoder()
+  );
+}
+
+export type SetVoteDelegateInput<
+  TAccountConfig extends string = string,
+  TAccountStake extends string = string,
+  TAccountStakeAuthority extends string = string,
+  TAccountVoteDelegate extends string = string,
+> = {
+  /** Stake config account */
+  config: Address<TAccountConfig>;
+  /** Validator or sol staker stake account */
+  stake: Address<TAccountStake>;
+  /** Stake authority */
+  stakeAuthority: TransactionSigner<TAccountStakeAuthority>;
+  /** Vote delegate (clears the delegate when omitted) */
+  voteDelegate?: TransactionSigner<TAccountVoteDelegate>;
+};
+
+export function getSetVoteDelegateInstruction<
+  TAccountConfig extends string,
+  TAccountStake extends string,
+  TAccountStakeAuthority extends string,
+  TAccountVoteDelegate extends string,
+>(
+  input: SetVoteDelegateInput<
+    TAccountConfig,
+    TAccountStake,
+    TAccountStakeAuthority,
+    TAccountVoteDelegate
+  >
+): SetVoteDelegateInstruction<
+  typeof PALADIN_STAKE_PROGRAM_PROGRAM_ADDRESS,
+  TAccountConfig,
+  TAccountStake,
+  TAccountStakeAuthority,
+  TAccountVoteDelegate
+> {
+  // Program address.
+  const programAddress = PALADIN_STAKE_PROGRAM_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: false },
+    stake: { value: input.stake ?? null, isWritable: true },
+    stakeAuthority: { value: input.stakeAuthority ?? null, isWritable: false },
+    voteDelegate: { value: input.voteDelegate ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.stake),
+      getAccountMeta(accounts.stakeAuthority),
+      getAccountMeta(accounts.voteDelegate),
+    ],
+    programAddress,
+    data: getSetVoteDelegateInstructionDataEncoder().encode({}),
+  } as SetVoteDelegateInstruction<
+    typeof PALADIN_STAKE_PROGRAM_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountStake,
+    TAccountStakeAuthority,
+    TAccountVoteDelegate
+  >;
+
+  return instruction;
+}
+
+export type ParsedSetVoteDelegateInstruction<
+  TProgram extends string = typeof PALADIN_STAKE_PROGRAM_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    /** Stake config account */
+    config: TAccountMetas[0];
+    /** Validator or sol staker stake account */
+    stake: TAccountMetas[1];
+    /** Stake authority */
+    stakeAuthority: TAccountMetas[2];
+    /** Vote delegate (clears the delegate when omitted) */
+    voteDelegate?: TAccountMetas[3] | undefined;
+  };
+  data: SetVoteDelegateInstructionData;
+};
+
+export function parseSetVoteDelegateInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedSetVoteDelegateInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 4) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  const getNextOptionalAccount = () => {
+    const accountMeta = getNextAccount();
+    return accountMeta.address === PALADIN_STAKE_PROGRAM_PROGRAM_ADDRESS
+      ? undefined
+      : accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      config: getNextAccount(),
+      stake: getNextAccount(),
+      stakeAuthority: getNextAccount(),
+      voteDelegate: getNextOptionalAccount(),
+    },
+    data: getSetVoteDelegateInstructionDataDecoder().decode(instruction.data),
+  };
+}
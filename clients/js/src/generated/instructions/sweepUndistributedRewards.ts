@@ -0,0 +1,202 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getStructDecoder,
+  getStructEncoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IAccountSignerMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlySignerAccount,
+  type TransactionSigner,
+  type WritableAccount,
+} from '@solana/web3.js';
+import { PALADIN_STAKE_PROGRAM_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+
+export const SWEEP_UNDISTRIBUTED_REWARDS_DISCRIMINATOR = 23;
+
+export function getSweepUndistributedRewardsDiscriminatorBytes() {
+  return getU8Encoder().encode(SWEEP_UNDISTRIBUTED_REWARDS_DISCRIMINATOR);
+}
+
+export type SweepUndistributedRewardsInstruction<
+  TProgram extends string = typeof PALADIN_STAKE_PROGRAM_PROGRAM_ADDRESS,
+  TAccountConfig extends string | IAccountMeta<string> = string,
+  TAccountConfigAuthority extends string | IAccountMeta<string> = string,
+  TAccountDestination extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountConfig extends string
+        ? WritableAccount<TAccountConfig>
+        : TAccountConfig,
+      TAccountConfigAuthority extends string
+        ? ReadonlySignerAccount<TAccountConfigAuthority> &
+            IAccountSignerMeta<TAccountConfigAuthority>
+        : TAccountConfigAuthority,
+      TAccountDestination extends string
+        ? WritableAccount<TAccountDestination>
+        : TAccountDestination,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type SweepUndistributedRewardsInstructionData = {
+  discriminator: number;
+};
+
+export type SweepUndistributedRewardsInstructionDataArgs = {};
+
+export function getSweepUndistributedRewardsInstructionDataEncoder(): Encoder<SweepUndistributedRewardsInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([['discriminator', getU8Encoder()]]),
+    (value) => ({
+      ...value,
+      discriminator: SWEEP_UNDISTRIBUTED_REWARDS_DISCRIMINATOR,
+    })
+  );
+}
+
+export function getSweepUndistributedRewardsInstructionDataDecoder(): Decoder<SweepUndistributedRewardsInstructionData> {
+  return getStructDecoder([['discriminator', getU8Decoder()]]);
+}
+
+export function getSweepUndistributedRewardsInstructionDataCodec(): Codec<
+  SweepUndistributedRewardsInstructionDataArgs,
+  SweepUndistributedRewardsInstructionData
+> {
+  return combineCodec(
+    getSweepUndistributedRewardsInstructionDataEncoder(),
+    getSweepUndistributedRewardsInstructionDataDecoder()
+  );
+}
+
+export type SweepUndistributedRewardsInput<
+  TAccountConfig extends string = string,
+  TAccountConfigAuthority extends string = string,
+  TAccountDestination extends string = string,
+> = {
+  /** Stake config account */
+  config: Address<TAccountConfig>;
+  /** Stake config authority */
+  configAuthority: TransactionSigner<TAccountConfigAuthority>;
+  /** Destination account for the undistributed rewards */
+  destination: Address<TAccountDestination>;
+};
+
+export function getSweepUndistributedRewardsInstruction<
+  TAccountConfig extends string,
+  TAccountConfigAuthority extends string,
+  TAccountDestination extends string,
+>(
+  input: SweepUndistributedRewardsInput<
+    TAccountConfig,
+    TAccountConfigAuthority,
+    TAccountDestination
+  >
+): SweepUndistributedRewardsInstruction<
+  typeof PALADIN_STAKE_PROGRAM_PROGRAM_ADDRESS,
+  TAccountConfig,
+  TAccountConfigAuthority,
+  TAccountDestination
+> {
+  // Program address.
+  const programAddress = PALADIN_STAKE_PROGRAM_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: true },
+    configAuthority: {
+      value: input.configAuthority ?? null,
+      isWritable: false,
+    },
+    destination: { value: input.destination ?? null, isWritable: true },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.configAuthority),
+      getAccountMeta(accounts.destination),
+    ],
+    programAddress,
+    data: getSweepUndistributedRewardsInstructionDataEncoder().encode({}),
+  } as SweepUndistributedRewardsInstruction<
+    typeof PALADIN_STAKE_PROGRAM_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountConfigAuthority,
+    TAccountDestination
+  >;
+
+  return instruction;
+}
+
+export type ParsedSweepUndistributedRewardsInstruction<
+  TProgram extends string = typeof PALADIN_STAKE_PROGRAM_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    /** Stake config account */
+    config: TAccountMetas[0];
+    /** Stake config authority */
+    configAuthority: TAccountMetas[1];
+    /** Destination account for the undistributed rewards */
+    destination: TAccountMetas[2];
+  };
+  data: SweepUndistributedRewardsInstructionData;
+};
+
+export function parseSweepUndistributedRewardsInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedSweepUndistributedRewardsInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 3) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      config: getNextAccount(),
+      configAuthority: getNextAccount(),
+      destination: getNextAccount(),
+    },
+    data: getSweepUndistributedRewardsInstructionDataDecoder().decode(
+      instruction.data
+    ),
+  };
+}
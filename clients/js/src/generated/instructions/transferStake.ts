@@ -0,0 +1,273 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getStructDecoder,
+  getStructEncoder,
+  getU64Decoder,
+  getU64Encoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IAccountSignerMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlyAccount,
+  type TransactionSigner,
+  type WritableAccount,
+  type WritableSignerAccount,
+} from '@solana/web3.js';
+import { PALADIN_STAKE_PROGRAM_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+
+export const TRANSFER_STAKE_DISCRIMINATOR = 29;
+
+export function getTransferStakeDiscriminatorBytes() {
+  return getU8Encoder().encode(TRANSFER_STAKE_DISCRIMINATOR);
+}
+
+export type TransferStakeInstruction<
+  TProgram extends string = typeof PALADIN_STAKE_PROGRAM_PROGRAM_ADDRESS,
+  TAccountConfig extends string | IAccountMeta<string> = string,
+  TAccountVaultHolderRewards extends string | IAccountMeta<string> = string,
+  TAccountSourceSolStakerStake extends string | IAccountMeta<string> = string,
+  TAccountSourceAuthority extends string | IAccountMeta<string> = string,
+  TAccountDestinationSolStakerStake extends
+    | string
+    | IAccountMeta<string> = string,
+  TAccountDestinationAuthority extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountConfig extends string
+        ? WritableAccount<TAccountConfig>
+        : TAccountConfig,
+      TAccountVaultHolderRewards extends string
+        ? ReadonlyAccount<TAccountVaultHolderRewards>
+        : TAccountVaultHolderRewards,
+      TAccountSourceSolStakerStake extends string
+        ? WritableAccount<TAccountSourceSolStakerStake>
+        : TAccountSourceSolStakerStake,
+      TAccountSourceAuthority extends string
+        ? WritableSignerAccount<TAccountSourceAuthority> &
+            IAccountSignerMeta<TAccountSourceAuthority>
+        : TAccountSourceAuthority,
+      TAccountDestinationSolStakerStake extends string
+        ? WritableAccount<TAccountDestinationSolStakerStake>
+        : TAccountDestinationSolStakerStake,
+      TAccountDestinationAuthority extends string
+        ? WritableSignerAccount<TAccountDestinationAuthority> &
+            IAccountSignerMeta<TAccountDestinationAuthority>
+        : TAccountDestinationAuthority,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type TransferStakeInstructionData = {
+  discriminator: number;
+  amount: bigint;
+};
+
+export type TransferStakeInstructionDataArgs = { amount: number | bigint };
+
+export function getTransferStakeInstructionDataEncoder(): Encoder<TransferStakeInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([
+      ['discriminator', getU8Encoder()],
+      ['amount', getU64Encoder()],
+    ]),
+    (value) => ({ ...value, discriminator: TRANSFER_STAKE_DISCRIMINATOR })
+  );
+}
+
+export function getTransferStakeInstructionDataDecoder(): Decoder<TransferStakeInstructionData> {
+  return getStructDecoder([
+    ['discriminator', getU8Decoder()],
+    ['amount', getU64Decoder()],
+  ]);
+}
+
+export function getTransferStakeInstructionDataCodec(): Codec<
+  TransferStakeInstructionDataArgs,
+  TransferStakeInstructionData
+> {
+  return combineCodec(
+    getTransferStakeInstructionDataEncoder(),
+    getTransferStakeInstructionDataDecoder()
+  );
+}
+
+export type TransferStakeInput<
+  TAccountConfig extends string = string,
+  TAccountVaultHolderRewards extends string = string,
+  TAccountSourceSolStakerStake extends string = string,
+  TAccountSourceAuthority extends string = string,
+  TAccountDestinationSolStakerStake extends string = string,
+  TAccountDestinationAuthority extends string = string,
+> = {
+  /** Stake config account */
+  config: Address<TAccountConfig>;
+  /** Vault holder rewards account */
+  vaultHolderRewards: Address<TAccountVaultHolderRewards>;
+  /** Source SOL staker stake account */
+  sourceSolStakerStake: Address<TAccountSourceSolStakerStake>;
+  /** Source SOL staker stake authority */
+  sourceAuthority: TransactionSigner<TAccountSourceAuthority>;
+  /** Destination SOL staker stake account */
+  destinationSolStakerStake: Address<TAccountDestinationSolStakerStake>;
+  /** Destination SOL staker stake authority */
+  destinationAuthority: TransactionSigner<TAccountDestinationAuthority>;
+  amount: TransferStakeInstructionDataArgs['amount'];
+};
+
+export function getTransferStakeInstruction<
+  TAccountConfig extends string,
+  TAccountVaultHolderRewards extends string,
+  TAccountSourceSolStakerStake extends string,
+  TAccountSourceAuthority extends string,
+  TAccountDestinationSolStakerStake extends string,
+  TAccountDestinationAuthority extends string,
+>(
+  input: TransferStakeInput<
+    TAccountConfig,
+    TAccountVaultHolderRewards,
+    TAccountSourceSolStakerStake,
+    TAccountSourceAuthority,
+    TAccountDestinationSolStakerStake,
+    TAccountDestinationAuthority
+  >
+): TransferStakeInstruction<
+  typeof PALADIN_STAKE_PROGRAM_PROGRAM_ADDRESS,
+  TAccountConfig,
+  TAccountVaultHolderRewards,
+  TAccountSourceSolStakerStake,
+  TAccountSourceAuthority,
+  TAccountDestinationSolStakerStake,
+  TAccountDestinationAuthority
+> {
+  // Program address.
+  const programAddress = PALADIN_STAKE_PROGRAM_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: true },
+    vaultHolderRewards: {
+      value: input.vaultHolderRewards ?? null,
+      isWritable: false,
+    },
+    sourceSolStakerStake: {
+      value: input.sourceSolStakerStake ?? null,
+      isWritable: true,
+    },
+    sourceAuthority: { value: input.sourceAuthority ?? null, isWritable: true },
+    destinationSolStakerStake: {
+      value: input.destinationSolStakerStake ?? null,
+      isWritable: true,
+    },
+    destinationAuthority: {
+      value: input.destinationAuthority ?? null,
+      isWritable: true,
+    },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.vaultHolderRewards),
+      getAccountMeta(accounts.sourceSolStakerStake),
+      getAccountMeta(accounts.sourceAuthority),
+      getAccountMeta(accounts.destinationSolStakerStake),
+      getAccountMeta(accounts.destinationAuthority),
+    ],
+    programAddress,
+    data: getTransferStakeInstructionDataEncoder().encode(
+      args as TransferStakeInstructionDataArgs
+    ),
+  } as TransferStakeInstruction<
+    typeof PALADIN_STAKE_PROGRAM_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountVaultHolderRewards,
+    TAccountSourceSolStakerStake,
+    TAccountSourceAuthority,
+    TAccountDestinationSolStakerStake,
+    TAccountDestinationAuthority
+  >;
+
+  return instruction;
+}
+
+export type ParsedTransferStakeInstruction<
+  TProgram extends string = typeof PALADIN_STAKE_PROGRAM_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    /** Stake config account */
+    config: TAccountMetas[0];
+    /** Vault holder rewards account */
+    vaultHolderRewards: TAccountMetas[1];
+    /** Source SOL staker stake account */
+    sourceSolStakerStake: TAccountMetas[2];
+    /** Source SOL staker stake authority */
+    sourceAuthority: TAccountMetas[3];
+    /** Destination SOL staker stake account */
+    destinationSolStakerStake: TAccountMetas[4];
+    /** Destination SOL staker stake authority */
+    destinationAuthority: TAccountMetas[5];
+  };
+  data: TransferStakeInstructionData;
+};
+
+export function parseTransferStakeInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedTransferStakeInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 6) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      config: getNextAccount(),
+      vaultHolderRewards: getNextAccount(),
+      sourceSolStakerStake: getNextAccount(),
+      sourceAuthority: getNextAccount(),
+      destinationSolStakerStake: getNextAccount(),
+      destinationAuthority: getNextAccount(),
+    },
+    data: getTransferStakeInstructionDataDecoder().decode(instruction.data),
+  };
+}
@@ -21,6 +21,7 @@ import {
   type IInstruction,
   type IInstructionWithAccounts,
   type IInstructionWithData,
+  type ReadonlyAccount,
   type WritableAccount,
 } from '@solana/web3.js';
 import { PALADIN_STAKE_PROGRAM_PROGRAM_ADDRESS } from '../programs';
@@ -37,6 +38,9 @@ export type ValidatorSyncAuthorityInstruction<
   TAccountConfig extends string | IAccountMeta<string> = string,
   TAccountValidatorStake extends string | IAccountMeta<string> = string,
   TAccountValidatorVote extends string | IAccountMeta<string> = string,
+  TAccountValidatorAuthorityOverride extends
+    | string
+    | IAccountMeta<string> = string,
   TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
 > = IInstruction<TProgram> &
   IInstructionWithData<Uint8Array> &
@@ -51,6 +55,9 @@ export type ValidatorSyncAuthorityInstruction<
       TAccountValidatorVote extends string
         ? WritableAccount<TAccountValidatorVote>
         : TAccountValidatorVote,
+      TAccountValidatorAuthorityOverride extends string
+        ? ReadonlyAccount<TAccountValidatorAuthorityOverride>
+        : TAccountValidatorAuthorityOverride,
       ...TRemainingAccounts,
     ]
   >;
@@ -87,6 +94,7 @@ export type ValidatorSyncAuthorityInput<
   TAccountConfig extends string = string,
   TAccountValidatorStake extends string = string,
   TAccountValidatorVote extends string = string,
+  TAccountValidatorAuthorityOverride extends string = string,
 > = {
   /** Config */
   config: Address<TAccountConfig>;
@@ -94,23 +102,28 @@ export type ValidatorSyncAuthorityInput<
   validatorStake: Address<TAccountValidatorStake>;
   /** Validator vote */
   validatorVote: Address<TAccountValidatorVote>;
+  /** Validator authority override */
+  validatorAuthorityOverride: Address<TAccountValidatorAuthorityOverride>;
 };
 
 export function getValidatorSyncAuthorityInstruction<
   TAccountConfig extends string,
   TAccountValidatorStake extends string,
   TAccountValidatorVote extends string,
+  TAccountValidatorAuthorityOverride extends string,
 >(
   input: ValidatorSyncAuthorityInput<
     TAccountConfig,
     TAccountValidatorStake,
-    TAccountValidatorVote
+    TAccountValidatorVote,
+    TAccountValidatorAuthorityOverride
   >
 ): ValidatorSyncAuthorityInstruction<
   typeof PALADIN_STAKE_PROGRAM_PROGRAM_ADDRESS,
   TAccountConfig,
   TAccountValidatorStake,
-  TAccountValidatorVote
+  TAccountValidatorVote,
+  TAccountValidatorAuthorityOverride
 > {
   // Program address.
   const programAddress = PALADIN_STAKE_PROGRAM_PROGRAM_ADDRESS;
@@ -120,6 +133,10 @@ export function getValidatorSyncAuthorityInstruction<
     config: { value: input.config ?? null, isWritable: true },
     validatorStake: { value: input.validatorStake ?? null, isWritable: true },
     validatorVote: { value: input.validatorVote ?? null, isWritable: true },
+    validatorAuthorityOverride: {
+      value: input.validatorAuthorityOverride ?? null,
+      isWritable: false,
+    },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -132,6 +149,7 @@ export function getValidatorSyncAuthorityInstruction<
       getAccountMeta(accounts.config),
       getAccountMeta(accounts.validatorStake),
       getAccountMeta(accounts.validatorVote),
+      getAccountMeta(accounts.validatorAuthorityOverride),
     ],
     programAddress,
     data: getValidatorSyncAuthorityInstructionDataEncoder().encode({}),
@@ -139,7 +157,8 @@ export function getValidatorSyncAuthorityInstruction<
     typeof PALADIN_STAKE_PROGRAM_PROGRAM_ADDRESS,
     TAccountConfig,
     TAccountValidatorStake,
-    TAccountValidatorVote
+    TAccountValidatorVote,
+    TAccountValidatorAuthorityOverride
   >;
 
   return instruction;
@@ -157,6 +176,8 @@ export type ParsedValidatorSyncAuthorityInstruction<
     validatorStake: TAccountMetas[1];
     /** Validator vote */
     validatorVote: TAccountMetas[2];
+    /** Validator authority override */
+    validatorAuthorityOverride: TAccountMetas[3];
   };
   data: ValidatorSyncAuthorityInstructionData;
 };
@@ -169,7 +190,7 @@ export function parseValidatorSyncAuthorityInstruction<
     IInstructionWithAccounts<TAccountMetas> &
     IInstructionWithData<Uint8Array>
 ): ParsedValidatorSyncAuthorityInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 3) {
+  if (instruction.accounts.length < 4) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -185,6 +206,7 @@ export function parseValidatorSyncAuthorityInstruction<
       config: getNextAccount(),
       validatorStake: getNextAccount(),
       validatorVote: getNextAccount(),
+      validatorAuthorityOverride: getNextAccount(),
     },
     data: getValidatorSyncAuthorityInstructionDataDecoder().decode(
       instruction.data
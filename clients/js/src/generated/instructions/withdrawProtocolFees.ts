@@ -0,0 +1,200 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getStructDecoder,
+  getStructEncoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IAccountSignerMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlySignerAccount,
+  type TransactionSigner,
+  type WritableAccount,
+} from '@solana/web3.js';
+import { PALADIN_STAKE_PROGRAM_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+
+export const WITHDRAW_PROTOCOL_FEES_DISCRIMINATOR = 30;
+
+export function getWithdrawProtocolFeesDiscriminatorBytes() {
+  return getU8Encoder().encode(WITHDRAW_PROTOCOL_FEES_DISCRIMINATOR);
+}
+
+export type WithdrawProtocolFeesInstruction<
+  TProgram extends string = typeof PALADIN_STAKE_PROGRAM_PROGRAM_ADDRESS,
+  TAccountConfig extends string | IAccountMeta<string> = string,
+  TAccountConfigAuthority extends string | IAccountMeta<string> = string,
+  TAccountFeeRecipient extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountConfig extends string
+        ? WritableAccount<TAccountConfig>
+        : TAccountConfig,
+      TAccountConfigAuthority extends string
+        ? ReadonlySignerAccount<TAccountConfigAuthority> &
+            IAccountSignerMeta<TAccountConfigAuthority>
+        : TAccountConfigAuthority,
+      TAccountFeeRecipient extends string
+        ? WritableAccount<TAccountFeeRecipient>
+        : TAccountFeeRecipient,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type WithdrawProtocolFeesInstructionData = { discriminator: number };
+
+export type WithdrawProtocolFeesInstructionDataArgs = {};
+
+export function getWithdrawProtocolFeesInstructionDataEncoder(): Encoder<WithdrawProtocolFeesInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([['discriminator', getU8Encoder()]]),
+    (value) => ({
+      ...value,
+      discriminator: WITHDRAW_PROTOCOL_FEES_DISCRIMINATOR,
+    })
+  );
+}
+
+export function getWithdrawProtocolFeesInstructionDataDecoder(): Decoder<WithdrawProtocolFeesInstructionData> {
+  return getStructDecoder([['discriminator', getU8Decoder()]]);
+}
+
+export function getWithdrawProtocolFeesInstructionDataCodec(): Codec<
+  WithdrawProtocolFeesInstructionDataArgs,
+  WithdrawProtocolFeesInstructionData
+> {
+  return combineCodec(
+    getWithdrawProtocolFeesInstructionDataEncoder(),
+    getWithdrawProtocolFeesInstructionDataDecoder()
+  );
+}
+
+export type WithdrawProtocolFeesInput<
+  TAccountConfig extends string = string,
+  TAccountConfigAuthority extends string = string,
+  TAccountFeeRecipient extends string = string,
+> = {
+  /** Stake config account */
+  config: Address<TAccountConfig>;
+  /** Stake config authority */
+  configAuthority: TransactionSigner<TAccountConfigAuthority>;
+  /** Fee recipient set on the config */
+  feeRecipient: Address<TAccountFeeRecipient>;
+};
+
+export function getWithdrawProtocolFeesInstruction<
+  TAccountConfig extends string,
+  TAccountConfigAuthority extends string,
+  TAccountFeeRecipient extends string,
+>(
+  input: WithdrawProtocolFeesInput<
+    TAccountConfig,
+    TAccountConfigAuthority,
+    TAccountFeeRecipient
+  >
+): WithdrawProtocolFeesInstruction<
+  typeof PALADIN_STAKE_PROGRAM_PROGRAM_ADDRESS,
+  TAccountConfig,
+  TAccountConfigAuthority,
+  TAccountFeeRecipient
+> {
+  // Program address.
+  const programAddress = PALADIN_STAKE_PROGRAM_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: true },
+    configAuthority: {
+      value: input.configAuthority ?? null,
+      isWritable: false,
+    },
+    feeRecipient: { value: input.feeRecipient ?? null, isWritable: true },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.configAuthority),
+      getAccountMeta(accounts.feeRecipient),
+    ],
+    programAddress,
+    data: getWithdrawProtocolFeesInstructionDataEncoder().encode({}),
+  } as WithdrawProtocolFeesInstruction<
+    typeof PALADIN_STAKE_PROGRAM_PROGRAM_ADDRESS,
+    TAccountConfig,
+    TAccountConfigAuthority,
+    TAccountFeeRecipient
+  >;
+
+  return instruction;
+}
+
+export type ParsedWithdrawProtocolFeesInstruction<
+  TProgram extends string = typeof PALADIN_STAKE_PROGRAM_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    /** Stake config account */
+    config: TAccountMetas[0];
+    /** Stake config authority */
+    configAuthority: TAccountMetas[1];
+    /** Fee recipient set on the config */
+    feeRecipient: TAccountMetas[2];
+  };
+  data: WithdrawProtocolFeesInstructionData;
+};
+
+export function parseWithdrawProtocolFeesInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedWithdrawProtocolFeesInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 3) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      config: getNextAccount(),
+      configAuthority: getNextAccount(),
+      feeRecipient: getNextAccount(),
+    },
+    data: getWithdrawProtocolFeesInstructionDataDecoder().decode(
+      instruction.data
+    ),
+  };
+}
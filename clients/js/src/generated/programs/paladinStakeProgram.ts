@@ -13,22 +13,38 @@ import {
   type ReadonlyUint8Array,
 } from '@solana/web3.js';
 import {
+  type ParsedAuditInvariantsInstruction,
+  type ParsedGetPendingRewardsInstruction,
+  type ParsedGetVotingPowerInstruction,
   type ParsedHarvestHolderRewardsInstruction,
+  type ParsedHarvestReceiptRewardsInstruction,
   type ParsedHarvestSolStakerRewardsInstruction,
   type ParsedHarvestValidatorRewardsInstruction,
   type ParsedInitializeConfigInstruction,
+  type ParsedInitializeConfigPdaInstruction,
+  type ParsedInitializeDelegatedVotingPowerInstruction,
+  type ParsedInitializeLiquidStakePoolInstruction,
+  type ParsedInitializeReceiptRewardsInstruction,
   type ParsedInitializeSolStakerStakeInstruction,
   type ParsedInitializeValidatorStakeInstruction,
+  type ParsedLiquidStakeTokensInstruction,
+  type ParsedLiquidUnstakeTokensInstruction,
+  type ParsedMigrateAccountInstruction,
   type ParsedSetAuthorityInstruction,
+  type ParsedSetValidatorAuthorityOverrideInstruction,
+  type ParsedSetVoteDelegateInstruction,
   type ParsedSlashSolStakerStakeInstruction,
   type ParsedSlashValidatorStakeInstruction,
   type ParsedSolStakerMoveTokensInstruction,
   type ParsedSolStakerStakeTokensInstruction,
+  type ParsedSweepUndistributedRewardsInstruction,
+  type ParsedTransferStakeInstruction,
   type ParsedUnstakeTokensInstruction,
   type ParsedUpdateConfigInstruction,
   type ParsedValidatorOverrideStakedLamportsInstruction,
   type ParsedValidatorStakeTokensInstruction,
   type ParsedValidatorSyncAuthorityInstruction,
+  type ParsedWithdrawProtocolFeesInstruction,
 } from '../instructions';
 
 export const PALADIN_STAKE_PROGRAM_PROGRAM_ADDRESS =
@@ -36,6 +52,9 @@ export const PALADIN_STAKE_PROGRAM_PROGRAM_ADDRESS =
 
 export enum PaladinStakeProgramAccount {
   Config,
+  DelegatedVotingPower,
+  LiquidStakePool,
+  ReceiptRewards,
   SolStakerStake,
   ValidatorStake,
 }
@@ -57,6 +76,22 @@ export enum PaladinStakeProgramInstruction {
   SolStakerMoveTokens,
   ValidatorOverrideStakedLamports,
   ValidatorSyncAuthority,
+  GetPendingRewards,
+  MigrateAccount,
+  InitializeConfigPda,
+  GetVotingPower,
+  InitializeDelegatedVotingPower,
+  SetVoteDelegate,
+  SetValidatorAuthorityOverride,
+  SweepUndistributedRewards,
+  InitializeLiquidStakePool,
+  InitializeReceiptRewards,
+  LiquidStakeTokens,
+  LiquidUnstakeTokens,
+  HarvestReceiptRewards,
+  TransferStake,
+  WithdrawProtocolFees,
+  AuditInvariants,
 }
 
 export function identifyPaladinStakeProgramInstruction(
@@ -111,6 +146,54 @@ export function identifyPaladinStakeProgramInstruction(
   if (containsBytes(data, getU8Encoder().encode(15), 0)) {
     return PaladinStakeProgramInstruction.ValidatorSyncAuthority;
   }
+  if (containsBytes(data, getU8Encoder().encode(16), 0)) {
+    return PaladinStakeProgramInstruction.GetPendingRewards;
+  }
+  if (containsBytes(data, getU8Encoder().encode(17), 0)) {
+    return PaladinStakeProgramInstruction.MigrateAccount;
+  }
+  if (containsBytes(data, getU8Encoder().encode(18), 0)) {
+    return PaladinStakeProgramInstruction.InitializeConfigPda;
+  }
+  if (containsBytes(data, getU8Encoder().encode(19), 0)) {
+    return PaladinStakeProgramInstruction.GetVotingPower;
+  }
+  if (containsBytes(data, getU8Encoder().encode(20), 0)) {
+    return PaladinStakeProgramInstruction.InitializeDelegatedVotingPower;
+  }
+  if (containsBytes(data, getU8Encoder().encode(21), 0)) {
+    return PaladinStakeProgramInstruction.SetVoteDelegate;
+  }
+  if (containsBytes(data, getU8Encoder().encode(22), 0)) {
+    return PaladinStakeProgramInstruction.SetValidatorAuthorityOverride;
+  }
+  if (containsBytes(data, getU8Encoder().encode(23), 0)) {
+    return PaladinStakeProgramInstruction.SweepUndistributedRewards;
+  }
+  if (containsBytes(data, getU8Encoder().encode(24), 0)) {
+    return PaladinStakeProgramInstruction.InitializeLiquidStakePool;
+  }
+  if (containsBytes(data, getU8Encoder().encode(25), 0)) {
+    return PaladinStakeProgramInstruction.InitializeReceiptRewards;
+  }
+  if (containsBytes(data, getU8Encoder().encode(26), 0)) {
+    return PaladinStakeProgramInstruction.LiquidStakeTokens;
+  }
+  if (containsBytes(data, getU8Encoder().encode(27), 0)) {
+    return PaladinStakeProgramInstruction.LiquidUnstakeTokens;
+  }
+  if (containsBytes(data, getU8Encoder().encode(28), 0)) {
+    return PaladinStakeProgramInstruction.HarvestReceiptRewards;
+  }
+  if (containsBytes(data, getU8Encoder().encode(29), 0)) {
+    return PaladinStakeProgramInstruction.TransferStake;
+  }
+  if (containsBytes(data, getU8Encoder().encode(30), 0)) {
+    return PaladinStakeProgramInstruction.WithdrawProtocolFees;
+  }
+  if (containsBytes(data, getU8Encoder().encode(31), 0)) {
+    return PaladinStakeProgramInstruction.AuditInvariants;
+  }
   throw new Error(
     'The provided instruction could not be identified as a paladinStakeProgram instruction.'
   );
@@ -166,4 +249,52 @@ export type ParsedPaladinStakeProgramInstruction<
     } & ParsedValidatorOverrideStakedLamportsInstruction<TProgram>)
   | ({
       instructionType: PaladinStakeProgramInstruction.ValidatorSyncAuthority;
-    } & ParsedValidatorSyncAuthorityInstruction<TProgram>);
+    } & ParsedValidatorSyncAuthorityInstruction<TProgram>)
+  | ({
+      instructionType: PaladinStakeProgramInstruction.GetPendingRewards;
+    } & ParsedGetPendingRewardsInstruction<TProgram>)
+  | ({
+      instructionType: PaladinStakeProgramInstruction.MigrateAccount;
+    } & ParsedMigrateAccountInstruction<TProgram>)
+  | ({
+      instructionType: PaladinStakeProgramInstruction.InitializeConfigPda;
+    } & ParsedInitializeConfigPdaInstruction<TProgram>)
+  | ({
+      instructionType: PaladinStakeProgramInstruction.GetVotingPower;
+    } & ParsedGetVotingPowerInstruction<TProgram>)
+  | ({
+      instructionType: PaladinStakeProgramInstruction.InitializeDelegatedVotingPower;
+    } & ParsedInitializeDelegatedVotingPowerInstruction<TProgram>)
+  | ({
+      instructionType: PaladinStakeProgramInstruction.SetVoteDelegate;
+    } & ParsedSetVoteDelegateInstruction<TProgram>)
+  | ({
+      instructionType: PaladinStakeProgramInstruction.SetValidatorAuthorityOverride;
+    } & ParsedSetValidatorAuthorityOverrideInstruction<TProgram>)
+  | ({
+      instructionType: PaladinStakeProgramInstruction.SweepUndistributedRewards;
+    } & ParsedSweepUndistributedRewardsInstruction<TProgram>)
+  | ({
+      instructionType: PaladinStakeProgramInstruction.InitializeLiquidStakePool;
+    } & ParsedInitializeLiquidStakePoolInstruction<TProgram>)
+  | ({
+      instructionType: PaladinStakeProgramInstruction.InitializeReceiptRewards;
+    } & ParsedInitializeReceiptRewardsInstruction<TProgram>)
+  | ({
+      instructionType: PaladinStakeProgramInstruction.LiquidStakeTokens;
+    } & ParsedLiquidStakeTokensInstruction<TProgram>)
+  | ({
+      instructionType: PaladinStakeProgramInstruction.LiquidUnstakeTokens;
+    } & ParsedLiquidUnstakeTokensInstruction<TProgram>)
+  | ({
+      instructionType: PaladinStakeProgramInstruction.HarvestReceiptRewards;
+    } & ParsedHarvestReceiptRewardsInstruction<TProgram>)
+  | ({
+      instructionType: PaladinStakeProgramInstruction.TransferStake;
+    } & ParsedTransferStakeInstruction<TProgram>)
+  | ({
+      instructionType: PaladinStakeProgramInstruction.WithdrawProtocolFees;
+    } & ParsedWithdrawProtocolFeesInstruction<TProgram>)
+  | ({
+      instructionType: PaladinStakeProgramInstruction.AuditInvariants;
+    } & ParsedAuditInvariantsInstruction<TProgram>);
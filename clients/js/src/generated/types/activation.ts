@@ -0,0 +1,43 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getStructDecoder,
+  getStructEncoder,
+  getU64Decoder,
+  getU64Encoder,
+  type Codec,
+  type Decoder,
+  type Encoder,
+} from '@solana/web3.js';
+
+export type Activation = { activatingAmount: bigint; activationEpoch: bigint };
+
+export type ActivationArgs = {
+  activatingAmount: number | bigint;
+  activationEpoch: number | bigint;
+};
+
+export function getActivationEncoder(): Encoder<ActivationArgs> {
+  return getStructEncoder([
+    ['activatingAmount', getU64Encoder()],
+    ['activationEpoch', getU64Encoder()],
+  ]);
+}
+
+export function getActivationDecoder(): Decoder<Activation> {
+  return getStructDecoder([
+    ['activatingAmount', getU64Decoder()],
+    ['activationEpoch', getU64Decoder()],
+  ]);
+}
+
+export function getActivationCodec(): Codec<ActivationArgs, Activation> {
+  return combineCodec(getActivationEncoder(), getActivationDecoder());
+}
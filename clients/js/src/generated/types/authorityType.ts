@@ -18,6 +18,7 @@ import {
 export enum AuthorityType {
   Config,
   Slash,
+  FeeRecipient,
 }
 
 export type AuthorityTypeArgs = AuthorityType;
@@ -0,0 +1,43 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getStructDecoder,
+  getStructEncoder,
+  getU64Decoder,
+  getU64Encoder,
+  type Codec,
+  type Decoder,
+  type Encoder,
+} from '@solana/web3.js';
+
+export type Checkpoint = { slot: bigint; effectiveAmount: bigint };
+
+export type CheckpointArgs = {
+  slot: number | bigint;
+  effectiveAmount: number | bigint;
+};
+
+export function getCheckpointEncoder(): Encoder<CheckpointArgs> {
+  return getStructEncoder([
+    ['slot', getU64Encoder()],
+    ['effectiveAmount', getU64Encoder()],
+  ]);
+}
+
+export function getCheckpointDecoder(): Decoder<Checkpoint> {
+  return getStructDecoder([
+    ['slot', getU64Decoder()],
+    ['effectiveAmount', getU64Decoder()],
+  ]);
+}
+
+export function getCheckpointCodec(): Codec<CheckpointArgs, Checkpoint> {
+  return combineCodec(getCheckpointEncoder(), getCheckpointDecoder());
+}
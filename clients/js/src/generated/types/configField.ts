@@ -28,12 +28,16 @@ import {
 export type ConfigField =
   | { __kind: 'CooldownTimeSeconds'; fields: readonly [bigint] }
   | { __kind: 'MaxDeactivationBasisPoints'; fields: readonly [number] }
-  | { __kind: 'SyncRewardsLamports'; fields: readonly [bigint] };
+  | { __kind: 'SyncRewardsLamports'; fields: readonly [bigint] }
+  | { __kind: 'RewardStreamingPeriodSeconds'; fields: readonly [bigint] }
+  | { __kind: 'ProtocolFeeBasisPoints'; fields: readonly [number] };
 
 export type ConfigFieldArgs =
   | { __kind: 'CooldownTimeSeconds'; fields: readonly [number | bigint] }
   | { __kind: 'MaxDeactivationBasisPoints'; fields: readonly [number] }
-  | { __kind: 'SyncRewardsLamports'; fields: readonly [number | bigint] };
+  | { __kind: 'SyncRewardsLamports'; fields: readonly [number | bigint] }
+  | { __kind: 'RewardStreamingPeriodSeconds'; fields: readonly [number | bigint] }
+  | { __kind: 'ProtocolFeeBasisPoints'; fields: readonly [number] };
 
 export function getConfigFieldEncoder(): Encoder<ConfigFieldArgs> {
   return getDiscriminatedUnionEncoder([
@@ -49,6 +53,14 @@ export function getConfigFieldEncoder(): Encoder<ConfigFieldArgs> {
       'SyncRewardsLamports',
       getStructEncoder([['fields', getTupleEncoder([getU64Encoder()])]]),
     ],
+    [
+      'RewardStreamingPeriodSeconds',
+      getStructEncoder([['fields', getTupleEncoder([getU64Encoder()])]]),
+    ],
+    [
+      'ProtocolFeeBasisPoints',
+      getStructEncoder([['fields', getTupleEncoder([getU16Encoder()])]]),
+    ],
   ]);
 }
 
@@ -66,6 +78,14 @@ export function getConfigFieldDecoder(): Decoder<ConfigField> {
       'SyncRewardsLamports',
       getStructDecoder([['fields', getTupleDecoder([getU64Decoder()])]]),
     ],
+    [
+      'RewardStreamingPeriodSeconds',
+      getStructDecoder([['fields', getTupleDecoder([getU64Decoder()])]]),
+    ],
+    [
+      'ProtocolFeeBasisPoints',
+      getStructDecoder([['fields', getTupleDecoder([getU16Decoder()])]]),
+    ],
   ]);
 }
 
@@ -110,6 +130,30 @@ export function configField(
   '__kind',
   'SyncRewardsLamports'
 >;
+export function configField(
+  kind: 'RewardStreamingPeriodSeconds',
+  data: GetDiscriminatedUnionVariantContent<
+    ConfigFieldArgs,
+    '__kind',
+    'RewardStreamingPeriodSeconds'
+  >['fields']
+): GetDiscriminatedUnionVariant<
+  ConfigFieldArgs,
+  '__kind',
+  'RewardStreamingPeriodSeconds'
+>;
+export function configField(
+  kind: 'ProtocolFeeBasisPoints',
+  data: GetDiscriminatedUnionVariantContent<
+    ConfigFieldArgs,
+    '__kind',
+    'ProtocolFeeBasisPoints'
+  >['fields']
+): GetDiscriminatedUnionVariant<
+  ConfigFieldArgs,
+  '__kind',
+  'ProtocolFeeBasisPoints'
+>;
 export function configField<K extends ConfigFieldArgs['__kind'], Data>(
   kind: K,
   data?: Data
@@ -6,6 +6,9 @@
  * @see https://github.com/kinobi-so/kinobi
  */
 
+export * from './activation';
 export * from './authorityType';
+export * from './checkpoint';
 export * from './configField';
 export * from './delegation';
+export * from './votingPowerCheckpoints';
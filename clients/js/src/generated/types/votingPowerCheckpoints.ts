@@ -0,0 +1,60 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getArrayDecoder,
+  getArrayEncoder,
+  getStructDecoder,
+  getStructEncoder,
+  getU64Decoder,
+  getU64Encoder,
+  type Codec,
+  type Decoder,
+  type Encoder,
+} from '@solana/web3.js';
+import {
+  getCheckpointDecoder,
+  getCheckpointEncoder,
+  type Checkpoint,
+  type CheckpointArgs,
+} from '.';
+
+export type VotingPowerCheckpoints = {
+  count: bigint;
+  checkpoints: Array<Checkpoint>;
+};
+
+export type VotingPowerCheckpointsArgs = {
+  count: number | bigint;
+  checkpoints: Array<CheckpointArgs>;
+};
+
+export function getVotingPowerCheckpointsEncoder(): Encoder<VotingPowerCheckpointsArgs> {
+  return getStructEncoder([
+    ['count', getU64Encoder()],
+    ['checkpoints', getArrayEncoder(getCheckpointEncoder(), { size: 16 })],
+  ]);
+}
+
+export function getVotingPowerCheckpointsDecoder(): Decoder<VotingPowerCheckpoints> {
+  return getStructDecoder([
+    ['count', getU64Decoder()],
+    ['checkpoints', getArrayDecoder(getCheckpointDecoder(), { size: 16 })],
+  ]);
+}
+
+export function getVotingPowerCheckpointsCodec(): Codec<
+  VotingPowerCheckpointsArgs,
+  VotingPowerCheckpoints
+> {
+  return combineCodec(
+    getVotingPowerCheckpointsEncoder(),
+    getVotingPowerCheckpointsDecoder()
+  );
+}
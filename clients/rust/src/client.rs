@@ -0,0 +1,592 @@
+//! High-level client resolving the accounts of the stake program instructions.
+//!
+//! [`StakeClient`] fetches the config, stake and native stake accounts through
+//! a [`StakeRpc`] implementation and derives every other account (vault, vault
+//! holder rewards, holder rewards pool, token accounts, validator stakes and
+//! vote delegate accounts), so instructions can be built from the stake
+//! account and the signer alone.
+//!
+//! The client only builds instructions; signing and sending the transaction is
+//! left to the caller.
+
+use std::future::Future;
+
+use paladin_rewards_program_client::accounts::{HolderRewards, HolderRewardsPool};
+use solana_program::{
+    instruction::{AccountMeta, Instruction},
+    pubkey,
+    pubkey::Pubkey,
+    sysvar,
+};
+use thiserror::Error;
+
+use crate::{
+    accounts::{Config, SolStakerStake, ValidatorStake},
+    filters::{SOL_STAKER_STAKE_DISCRIMINATOR, VALIDATOR_STAKE_DISCRIMINATOR},
+    instructions::{
+        GetPendingRewards, HarvestSolStakerRewards, HarvestValidatorRewards, SlashSolStakerStake,
+        SlashSolStakerStakeInstructionArgs, SlashValidatorStake,
+        SlashValidatorStakeInstructionArgs, SolStakerMoveTokens,
+        SolStakerMoveTokensInstructionArgs, SolStakerStakeTokens,
+        SolStakerStakeTokensInstructionArgs, UnstakeTokens, UnstakeTokensInstructionArgs,
+        ValidatorStakeTokens, ValidatorStakeTokensInstructionArgs,
+    },
+    pdas::{
+        find_delegated_voting_power_pda, find_sol_staker_stake_pda, find_validator_stake_pda,
+        find_vault_pda,
+    },
+    types::Delegation,
+};
+
+/// Associated token account program.
+const ASSOCIATED_TOKEN_PROGRAM_ID: Pubkey =
+    pubkey!("ATokenGPvbd2Ktbmwu4n9cqRoLCEgkkqNrxXrJBSigsgRhD");
+
+/// Offset of the voter pubkey in a delegated native stake account
+/// (`StakeStateV2::Stake`).
+const NATIVE_STAKE_VOTER_OFFSET: usize = 124;
+
+/// Account data returned by a [`StakeRpc`].
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct RpcAccount {
+    pub owner: Pubkey,
+    pub data: Vec<u8>,
+}
+
+/// Read access to the accounts of a cluster, implemented by `BanksClient`
+/// (with the `banks-client` feature) and implementable over any RPC client.
+pub trait StakeRpc {
+    type Error;
+
+    /// Fetches an account, returning `None` if it does not exist.
+    fn get_account(
+        &mut self,
+        address: &Pubkey,
+    ) -> impl Future<Output = Result<Option<RpcAccount>, Self::Error>>;
+}
+
+#[cfg(feature = "banks-client")]
+impl StakeRpc for solana_banks_client::BanksClient {
+    type Error = solana_banks_client::BanksClientError;
+
+    async fn get_account(&mut self, address: &Pubkey) -> Result<Option<RpcAccount>, Self::Error> {
+        let account = solana_banks_client::BanksClient::get_account(self, *address).await?;
+        Ok(account.map(|account| RpcAccount {
+            owner: account.owner,
+            data: account.data,
+        }))
+    }
+}
+
+/// Errors returned by the [`StakeClient`].
+#[derive(Debug, Error)]
+pub enum StakeClientError<E> {
+    /// The RPC request failed.
+    #[error("RPC error: {0:?}")]
+    Rpc(E),
+    /// The account does not exist.
+    #[error("Account not found: {0}")]
+    AccountNotFound(Pubkey),
+    /// The account is not of the expected type.
+    #[error("Invalid account data: {0}")]
+    InvalidAccountData(Pubkey),
+}
+
+/// Stake account of either a validator or a SOL staker.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum StakeAccount {
+    Validator(ValidatorStake),
+    SolStaker(SolStakerStake),
+}
+
+impl StakeAccount {
+    /// Returns the delegation of the stake account.
+    pub fn delegation(&self) -> &Delegation {
+        match self {
+            Self::Validator(stake) => &stake.delegation,
+            Self::SolStaker(stake) => &stake.delegation,
+        }
+    }
+
+    /// Returns the vote delegate of the stake account.
+    pub fn vote_delegate(&self) -> Option<Pubkey> {
+        match self {
+            Self::Validator(stake) => stake.vote_delegate.value(),
+            Self::SolStaker(stake) => stake.vote_delegate.value(),
+        }
+    }
+}
+
+/// Accounts derived from the config account.
+struct ConfigAccounts {
+    mint: Pubkey,
+    token_program: Pubkey,
+    vault: Pubkey,
+    vault_pda: Pubkey,
+    vault_holder_rewards: Pubkey,
+    holder_rewards_pool: Pubkey,
+    holder_rewards_pool_token_account: Pubkey,
+}
+
+/// Client building stake program instructions for a config account.
+pub struct StakeClient<R> {
+    rpc: R,
+    config: Pubkey,
+}
+
+impl<R: StakeRpc> StakeClient<R> {
+    pub fn new(rpc: R, config: Pubkey) -> Self {
+        Self { rpc, config }
+    }
+
+    pub fn config(&self) -> &Pubkey {
+        &self.config
+    }
+
+    pub fn rpc(&mut self) -> &mut R {
+        &mut self.rpc
+    }
+
+    /// Returns the validator stake account of a vote account.
+    pub fn validator_stake_address(&self, vote: &Pubkey) -> Pubkey {
+        find_validator_stake_pda(vote, &self.config).0
+    }
+
+    /// Returns the SOL staker stake account of a native stake account.
+    pub fn sol_staker_stake_address(&self, sol_stake: &Pubkey) -> Pubkey {
+        find_sol_staker_stake_pda(sol_stake, &self.config).0
+    }
+
+    /// Fetches the config account.
+    pub async fn get_config(&mut self) -> Result<Config, StakeClientError<R::Error>> {
+        let config = self.config;
+        let account = self.get_owned_account(&config).await?;
+        Config::from_bytes(&account.data).map_err(|_| StakeClientError::InvalidAccountData(config))
+    }
+
+    /// Fetches a validator or SOL staker stake account.
+    pub async fn get_stake(
+        &mut self,
+        stake: &Pubkey,
+    ) -> Result<StakeAccount, StakeClientError<R::Error>> {
+        let account = self.get_owned_account(stake).await?;
+        let invalid = || StakeClientError::InvalidAccountData(*stake);
+
+        match account.data.get(..8) {
+            Some(discriminator) if discriminator == VALIDATOR_STAKE_DISCRIMINATOR => {
+                ValidatorStake::from_bytes(&account.data)
+                    .map(StakeAccount::Validator)
+                    .map_err(|_| invalid())
+            }
+            Some(discriminator) if discriminator == SOL_STAKER_STAKE_DISCRIMINATOR => {
+                SolStakerStake::from_bytes(&account.data)
+                    .map(StakeAccount::SolStaker)
+                    .map_err(|_| invalid())
+            }
+            _ => Err(invalid()),
+        }
+    }
+
+    /// Stakes `amount` tokens from the associated token account of `authority`
+    /// on a validator or SOL staker stake account.
+    ///
+    /// `authority` must sign the transaction.
+    pub async fn stake(
+        &mut self,
+        stake: &Pubkey,
+        authority: &Pubkey,
+        amount: u64,
+    ) -> Result<Instruction, StakeClientError<R::Error>> {
+        let accounts = self.config_accounts().await?;
+        let stake_account = self.get_stake(stake).await?;
+        let source_token_account = accounts.token_account(authority);
+
+        let instruction = match &stake_account {
+            StakeAccount::Validator(validator_stake) => ValidatorStakeTokens {
+                config: self.config,
+                holder_rewards_pool: accounts.holder_rewards_pool,
+                holder_rewards_pool_token_account: accounts.holder_rewards_pool_token_account,
+                validator_stake: *stake,
+                validator_stake_authority: validator_stake.delegation.authority,
+                source_token_account,
+                source_token_account_authority: *authority,
+                mint: accounts.mint,
+                vault_pda: accounts.vault_pda,
+                vault: accounts.vault,
+                vault_holder_rewards: accounts.vault_holder_rewards,
+                token_program: accounts.token_program,
+                rewards_program: paladin_rewards_program_client::ID,
+            }
+            .instruction_with_remaining_accounts(
+                ValidatorStakeTokensInstructionArgs { amount },
+                &self.voting_power_accounts(&[&stake_account]),
+            ),
+            StakeAccount::SolStaker(sol_staker_stake) => SolStakerStakeTokens {
+                config: self.config,
+                holder_rewards_pool: accounts.holder_rewards_pool,
+                holder_rewards_pool_token_account: accounts.holder_rewards_pool_token_account,
+                sol_staker_stake: *stake,
+                sol_staker_stake_authority: sol_staker_stake.delegation.authority,
+                source_token_account,
+                source_token_account_authority: *authority,
+                mint: accounts.mint,
+                vault_pda: accounts.vault_pda,
+                vault: accounts.vault,
+                vault_holder_rewards: accounts.vault_holder_rewards,
+                token_program: accounts.token_program,
+                rewards_program: paladin_rewards_program_client::ID,
+            }
+            .instruction_with_remaining_accounts(
+                SolStakerStakeTokensInstructionArgs { amount },
+                &self.voting_power_accounts(&[&stake_account]),
+            ),
+        };
+
+        Ok(instruction)
+    }
+
+    /// Unstakes `amount` tokens from a stake account to the associated token
+    /// account of its authority.
+    ///
+    /// The stake authority must sign the transaction.
+    pub async fn unstake(
+        &mut self,
+        stake: &Pubkey,
+        amount: u64,
+    ) -> Result<Instruction, StakeClientError<R::Error>> {
+        let accounts = self.config_accounts().await?;
+        let stake_account = self.get_stake(stake).await?;
+        let authority = stake_account.delegation().authority;
+
+        Ok(UnstakeTokens {
+            config: self.config,
+            holder_rewards_pool: accounts.holder_rewards_pool,
+            holder_rewards_pool_token_account: accounts.holder_rewards_pool_token_account,
+            stake: *stake,
+            stake_authority: authority,
+            vault: accounts.vault,
+            vault_pda: accounts.vault_pda,
+            vault_holder_rewards: accounts.vault_holder_rewards,
+            mint: accounts.mint,
+            destination_token_account: accounts.token_account(&authority),
+            token_program: accounts.token_program,
+            rewards_program: paladin_rewards_program_client::ID,
+        }
+        .instruction_with_remaining_accounts(
+            UnstakeTokensInstructionArgs { amount },
+            &self.voting_power_accounts(&[&stake_account]),
+        ))
+    }
+
+    /// Harvests the rewards of a stake account to its authority.
+    ///
+    /// This instruction is permissionless. SOL staker stakes whose native stake
+    /// changed must be synced with [`StakeClient::sync`] instead.
+    pub async fn harvest(
+        &mut self,
+        stake: &Pubkey,
+    ) -> Result<Instruction, StakeClientError<R::Error>> {
+        self.harvest_with_keeper(stake, None).await
+    }
+
+    /// Syncs the SOL amount of a SOL staker stake account with its native
+    /// stake, paying the sync bounty to `keeper_recipient`.
+    ///
+    /// This instruction is permissionless and also harvests the stake rewards.
+    pub async fn sync(
+        &mut self,
+        sol_staker_stake: &Pubkey,
+        keeper_recipient: &Pubkey,
+    ) -> Result<Instruction, StakeClientError<R::Error>> {
+        let StakeAccount::SolStaker(_) = self.get_stake(sol_staker_stake).await? else {
+            return Err(StakeClientError::InvalidAccountData(*sol_staker_stake));
+        };
+        self.harvest_with_keeper(sol_staker_stake, Some(*keeper_recipient))
+            .await
+    }
+
+    /// Moves `amount` staked tokens between two SOL staker stake accounts of
+    /// the same authority.
+    ///
+    /// The stake authority must sign the transaction.
+    pub async fn move_tokens(
+        &mut self,
+        source: &Pubkey,
+        destination: &Pubkey,
+        amount: u64,
+    ) -> Result<Instruction, StakeClientError<R::Error>> {
+        let accounts = self.config_accounts().await?;
+        let source_account = self.get_stake(source).await?;
+        let StakeAccount::SolStaker(source_stake) = &source_account else {
+            return Err(StakeClientError::InvalidAccountData(*source));
+        };
+        let destination_account = self.get_stake(destination).await?;
+
+        Ok(SolStakerMoveTokens {
+            config: self.config,
+            vault_holder_rewards: accounts.vault_holder_rewards,
+            sol_staker_authority: source_stake.delegation.authority,
+            source_sol_staker_stake: *source,
+            destination_sol_staker_stake: *destination,
+        }
+        .instruction_with_remaining_accounts(
+            SolStakerMoveTokensInstructionArgs { amount },
+            &self.voting_power_accounts(&[&source_account, &destination_account]),
+        ))
+    }
+
+    /// Slashes `amount` tokens from a validator or SOL staker stake account.
+    ///
+    /// The config slash authority must sign the transaction.
+    pub async fn slash(
+        &mut self,
+        stake: &Pubkey,
+        amount: u64,
+    ) -> Result<Instruction, StakeClientError<R::Error>> {
+        let config = self.get_config().await?;
+        let slash_authority = config
+            .slash_authority
+            .value()
+            .ok_or(StakeClientError::InvalidAccountData(self.config))?;
+        let accounts = self.config_accounts().await?;
+        let stake_account = self.get_stake(stake).await?;
+        let voting_power_accounts = self.voting_power_accounts(&[&stake_account]);
+
+        let instruction = match &stake_account {
+            StakeAccount::Validator(validator_stake) => SlashValidatorStake {
+                config: self.config,
+                validator_stake: *stake,
+                validator_stake_authority: validator_stake.delegation.authority,
+                slash_authority,
+                vault: accounts.vault,
+                vault_holder_rewards: accounts.vault_holder_rewards,
+                vault_authority: accounts.vault_pda,
+                mint: accounts.mint,
+                token_program: accounts.token_program,
+            }
+            .instruction_with_remaining_accounts(
+                SlashValidatorStakeInstructionArgs { amount },
+                &voting_power_accounts,
+            ),
+            StakeAccount::SolStaker(sol_staker_stake) => SlashSolStakerStake {
+                config: self.config,
+                sol_staker_stake: *stake,
+                sol_staker_stake_authority: sol_staker_stake.delegation.authority,
+                slash_authority,
+                mint: accounts.mint,
+                vault: accounts.vault,
+                vault_holder_rewards: accounts.vault_holder_rewards,
+                vault_authority: accounts.vault_pda,
+                token_program: accounts.token_program,
+            }
+            .instruction_with_remaining_accounts(
+                SlashSolStakerStakeInstructionArgs { amount },
+                &voting_power_accounts,
+            ),
+        };
+
+        Ok(instruction)
+    }
+
+    /// Builds the `GetPendingRewards` instruction of a stake account, to be
+    /// simulated to read the rewards a harvest would pay out.
+    pub fn pending_rewards(&self, stake: &Pubkey) -> Instruction {
+        let vault_pda = find_vault_pda(&self.config).0;
+        GetPendingRewards {
+            config: self.config,
+            vault_holder_rewards: HolderRewards::find_pda(&vault_pda).0,
+            stake: *stake,
+        }
+        .instruction()
+    }
+
+    async fn harvest_with_keeper(
+        &mut self,
+        stake: &Pubkey,
+        keeper_recipient: Option<Pubkey>,
+    ) -> Result<Instruction, StakeClientError<R::Error>> {
+        let stake_account = self.get_stake(stake).await?;
+        let vault_pda = find_vault_pda(&self.config).0;
+        let vault_holder_rewards = HolderRewards::find_pda(&vault_pda).0;
+        let voting_power_accounts = self.voting_power_accounts(&[&stake_account]);
+
+        let instruction = match &stake_account {
+            StakeAccount::Validator(validator_stake) => HarvestValidatorRewards {
+                config: self.config,
+                vault_holder_rewards,
+                validator_stake: *stake,
+                validator_stake_authority: validator_stake.delegation.authority,
+            }
+            .instruction_with_remaining_accounts(&voting_power_accounts),
+            StakeAccount::SolStaker(sol_staker_stake) => {
+                // The previous validator is the one recorded on the stake, while
+                // the current one is the vote account of the native stake.
+                let native_stake = self.get_account(&sol_staker_stake.sol_stake).await?;
+                let previous_vote = sol_staker_stake.delegation.validator_vote;
+                let current_vote = native_stake_voter(&native_stake.data).unwrap_or(previous_vote);
+                let previous_vote = match previous_vote == Pubkey::default() {
+                    true => current_vote,
+                    false => previous_vote,
+                };
+
+                let previous_validator_stake = self.validator_stake_address(&previous_vote);
+                let previous_validator_stake_authority = self
+                    .validator_stake_authority(&previous_validator_stake)
+                    .await?;
+                let current_validator_stake = self.validator_stake_address(&current_vote);
+                let current_validator_stake_authority = self
+                    .validator_stake_authority(&current_validator_stake)
+                    .await?;
+
+                HarvestSolStakerRewards {
+                    sol_stake_view_program: paladin_sol_stake_view_program_client::ID,
+                    config: self.config,
+                    vault_holder_rewards,
+                    sol_staker_stake: *stake,
+                    sol_staker_stake_authority: sol_staker_stake.delegation.authority,
+                    sol_staker_native_stake: sol_staker_stake.sol_stake,
+                    previous_validator_stake,
+                    previous_validator_stake_authority,
+                    current_validator_stake,
+                    current_validator_stake_authority,
+                    sysvar_stake_history: sysvar::stake_history::ID,
+                    keeper_recipient,
+                }
+                .instruction_with_remaining_accounts(&voting_power_accounts)
+            }
+        };
+
+        Ok(instruction)
+    }
+
+    /// Returns the authority of a validator stake account, or the account
+    /// itself if it was not created yet (the authority is then not used).
+    async fn validator_stake_authority(
+        &mut self,
+        validator_stake: &Pubkey,
+    ) -> Result<Pubkey, StakeClientError<R::Error>> {
+        match self.get_stake(validator_stake).await {
+            Ok(StakeAccount::Validator(stake)) => Ok(stake.delegation.authority),
+            Ok(StakeAccount::SolStaker(_)) => {
+                Err(StakeClientError::InvalidAccountData(*validator_stake))
+            }
+            Err(StakeClientError::AccountNotFound(_)) => Ok(*validator_stake),
+            Err(error) => Err(error),
+        }
+    }
+
+    async fn config_accounts(&mut self) -> Result<ConfigAccounts, StakeClientError<R::Error>> {
+        let config = self.get_config().await?;
+
+        // NB: The config does not store the mint, which is read from the vault
+        // token account (the mint is the first field of a token account).
+        let vault = self.get_account(&config.vault).await?;
+        let mint = vault
+            .data
+            .get(..32)
+            .map(|mint| Pubkey::try_from(mint).unwrap())
+            .ok_or(StakeClientError::InvalidAccountData(config.vault))?;
+        let token_program = vault.owner;
+
+        let vault_pda = find_vault_pda(&self.config).0;
+        let holder_rewards_pool = HolderRewardsPool::find_pda(&mint).0;
+
+        Ok(ConfigAccounts {
+            mint,
+            token_program,
+            vault: config.vault,
+            vault_pda,
+            vault_holder_rewards: HolderRewards::find_pda(&vault_pda).0,
+            holder_rewards_pool,
+            holder_rewards_pool_token_account: associated_token_address(
+                &holder_rewards_pool,
+                &mint,
+                &token_program,
+            ),
+        })
+    }
+
+    /// Returns the `DelegatedVotingPower` accounts of the vote delegates of the
+    /// given stake accounts.
+    fn voting_power_accounts(&self, stakes: &[&StakeAccount]) -> Vec<AccountMeta> {
+        let mut accounts: Vec<AccountMeta> = Vec::new();
+
+        for stake in stakes {
+            if let Some(delegate) = stake.vote_delegate() {
+                let address = find_delegated_voting_power_pda(&delegate, &self.config).0;
+                if !accounts.iter().any(|account| account.pubkey == address) {
+                    accounts.push(AccountMeta::new(address, false));
+                }
+            }
+        }
+
+        accounts
+    }
+
+    async fn get_account(
+        &mut self,
+        address: &Pubkey,
+    ) -> Result<RpcAccount, StakeClientError<R::Error>> {
+        self.rpc
+            .get_account(address)
+            .await
+            .map_err(StakeClientError::Rpc)?
+            .ok_or(StakeClientError::AccountNotFound(*address))
+    }
+
+    /// Fetches an account owned by the stake program.
+    async fn get_owned_account(
+        &mut self,
+        address: &Pubkey,
+    ) -> Result<RpcAccount, StakeClientError<R::Error>> {
+        let account = self.get_account(address).await?;
+        if account.owner != crate::ID {
+            return Err(StakeClientError::InvalidAccountData(*address));
+        }
+        Ok(account)
+    }
+}
+
+impl ConfigAccounts {
+    fn token_account(&self, owner: &Pubkey) -> Pubkey {
+        associated_token_address(owner, &self.mint, &self.token_program)
+    }
+}
+
+fn associated_token_address(owner: &Pubkey, mint: &Pubkey, token_program: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[owner.as_ref(), token_program.as_ref(), mint.as_ref()],
+        &ASSOCIATED_TOKEN_PROGRAM_ID,
+    )
+    .0
+}
+
+/// Returns the vote account a native stake account is delegated to, if any.
+fn native_stake_voter(data: &[u8]) -> Option<Pubkey> {
+    // `StakeStateV2::Stake` (discriminant 2) followed by the stake meta.
+    match data.get(..4) {
+        Some([2, 0, 0, 0]) => data
+            .get(NATIVE_STAKE_VOTER_OFFSET..NATIVE_STAKE_VOTER_OFFSET + 32)
+            .and_then(|voter| Pubkey::try_from(voter).ok()),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn native_stake_voter_is_read_from_delegated_stake() {
+        let voter = Pubkey::new_unique();
+        let mut data = vec![0; 200];
+        data[..4].copy_from_slice(&2u32.to_le_bytes());
+        data[NATIVE_STAKE_VOTER_OFFSET..NATIVE_STAKE_VOTER_OFFSET + 32]
+            .copy_from_slice(voter.as_ref());
+        assert_eq!(native_stake_voter(&data), Some(voter));
+
+        // Initialized (not delegated) stake.
+        data[..4].copy_from_slice(&1u32.to_le_bytes());
+        assert_eq!(native_stake_voter(&data), None);
+    }
+}
@@ -0,0 +1,490 @@
+//! Decoding of the structured events emitted by the stake program.
+//!
+//! Events are written by the program with `sol_log_data` and show up in the
+//! transaction logs as `Program data: <discriminator> <event>` lines, both
+//! fields base64-encoded. The types below mirror the on-chain `Pod` layouts;
+//! decoding log lines requires the `client` feature.
+
+#[cfg(feature = "client")]
+use base64::{engine::general_purpose::STANDARD, Engine};
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::pubkey::Pubkey;
+
+/// Tokens were staked into a stake account.
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+pub struct StakeTokensEvent {
+    pub config: Pubkey,
+    pub stake: Pubkey,
+    pub authority: Pubkey,
+    pub amount: u64,
+    pub staked_amount: u64,
+    pub effective_amount: u64,
+}
+
+impl StakeTokensEvent {
+    /// `sha256("stake::event::stake_tokens::v1")[..8]`
+    pub const DISCRIMINATOR: [u8; 8] = [108, 223, 93, 197, 162, 179, 237, 33];
+}
+
+/// Tokens were unstaked from a stake account.
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+pub struct UnstakeTokensEvent {
+    pub config: Pubkey,
+    pub stake: Pubkey,
+    pub authority: Pubkey,
+    pub amount: u64,
+    pub staked_amount: u64,
+    pub effective_amount: u64,
+    pub unstake_cooldown: u64,
+}
+
+impl UnstakeTokensEvent {
+    /// `sha256("stake::event::unstake_tokens::v1")[..8]`
+    pub const DISCRIMINATOR: [u8; 8] = [129, 62, 230, 213, 143, 110, 99, 2];
+}
+
+/// Rewards were harvested for a stake account.
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+pub struct HarvestEvent {
+    pub config: Pubkey,
+    pub stake: Pubkey,
+    pub authority: Pubkey,
+    pub staking_reward: u64,
+    pub holder_reward: u64,
+    pub keeper_reward: u64,
+}
+
+impl HarvestEvent {
+    /// `sha256("stake::event::harvest::v1")[..8]`
+    pub const DISCRIMINATOR: [u8; 8] = [119, 168, 175, 159, 220, 93, 130, 147];
+}
+
+/// A stake account was slashed.
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+pub struct SlashEvent {
+    pub config: Pubkey,
+    pub stake: Pubkey,
+    pub amount: u64,
+    pub slashed_amount: u64,
+    pub staked_amount: u64,
+    pub effective_amount: u64,
+}
+
+impl SlashEvent {
+    /// `sha256("stake::event::slash::v1")[..8]`
+    pub const DISCRIMINATOR: [u8; 8] = [1, 187, 202, 60, 52, 132, 104, 119];
+}
+
+/// Tokens were moved between two SOL staker stake accounts.
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+pub struct MoveTokensEvent {
+    pub config: Pubkey,
+    pub source: Pubkey,
+    pub destination: Pubkey,
+    pub authority: Pubkey,
+    pub amount: u64,
+}
+
+impl MoveTokensEvent {
+    /// `sha256("stake::event::move_tokens::v1")[..8]`
+    pub const DISCRIMINATOR: [u8; 8] = [206, 115, 100, 159, 43, 199, 189, 193];
+}
+
+/// A config field was updated.
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+pub struct UpdateConfigEvent {
+    pub config: Pubkey,
+    /// Discriminant of the updated `ConfigField`.
+    pub field: u8,
+    pub padding: [u8; 7],
+    pub value: u64,
+}
+
+impl UpdateConfigEvent {
+    /// `sha256("stake::event::update_config::v1")[..8]`
+    pub const DISCRIMINATOR: [u8; 8] = [11, 134, 142, 43, 171, 187, 215, 174];
+}
+
+/// An authority was changed.
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+pub struct AuthorityChangeEvent {
+    pub account: Pubkey,
+    pub previous_authority: Pubkey,
+    pub new_authority: Pubkey,
+    /// `0` for config, `1` for slash and `2` for stake authorities, `3` for
+    /// the fee recipient, `4` for vote delegates and `5` for validator
+    /// authority overrides.
+    pub kind: u8,
+    pub padding: [u8; 7],
+}
+
+impl AuthorityChangeEvent {
+    /// `sha256("stake::event::authority_change::v1")[..8]`
+    pub const DISCRIMINATOR: [u8; 8] = [136, 141, 13, 172, 183, 37, 41, 232];
+}
+
+/// The SOL stake lamports tracked by a SOL staker stake account were synced.
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+pub struct LamportsSyncEvent {
+    pub config: Pubkey,
+    pub stake: Pubkey,
+    pub previous_validator_vote: Pubkey,
+    pub current_validator_vote: Pubkey,
+    pub previous_lamports: u64,
+    pub current_lamports: u64,
+}
+
+impl LamportsSyncEvent {
+    /// `sha256("stake::event::lamports_sync::v1")[..8]`
+    pub const DISCRIMINATOR: [u8; 8] = [5, 68, 70, 87, 232, 43, 13, 65];
+}
+
+/// Undistributed stake rewards were swept from the config account.
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+pub struct SweepUndistributedRewardsEvent {
+    pub config: Pubkey,
+    pub destination: Pubkey,
+    pub amount: u64,
+}
+
+impl SweepUndistributedRewardsEvent {
+    /// `sha256("stake::event::sweep_undistributed_rewards::v1")[..8]`
+    pub const DISCRIMINATOR: [u8; 8] = [86, 246, 230, 164, 254, 76, 152, 98];
+}
+
+/// Staked tokens were transferred between stake accounts of different
+/// authorities.
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+pub struct TransferStakeEvent {
+    pub config: Pubkey,
+    pub source: Pubkey,
+    pub destination: Pubkey,
+    pub source_authority: Pubkey,
+    pub destination_authority: Pubkey,
+    pub amount: u64,
+}
+
+impl TransferStakeEvent {
+    /// `sha256("stake::event::transfer_stake::v1")[..8]`
+    pub const DISCRIMINATOR: [u8; 8] = [101, 173, 195, 156, 203, 159, 101, 87];
+}
+
+/// Unclaimed protocol fees were withdrawn from the config account.
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+pub struct WithdrawProtocolFeesEvent {
+    pub config: Pubkey,
+    pub fee_recipient: Pubkey,
+    pub amount: u64,
+}
+
+impl WithdrawProtocolFeesEvent {
+    /// `sha256("stake::event::withdraw_protocol_fees::v1")[..8]`
+    pub const DISCRIMINATOR: [u8; 8] = [185, 204, 17, 173, 71, 50, 222, 76];
+}
+
+/// A config or stake account was migrated to the current layout version.
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+pub struct MigrateAccountEvent {
+    pub account: Pubkey,
+    pub previous_version: u8,
+    pub version: u8,
+    pub padding: [u8; 6],
+}
+
+impl MigrateAccountEvent {
+    /// `sha256("stake::event::migrate_account::v1")[..8]`
+    pub const DISCRIMINATOR: [u8; 8] = [150, 70, 44, 160, 59, 110, 38, 2];
+}
+
+/// Tokens were staked into a liquid stake pool for receipt tokens.
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+pub struct LiquidStakeTokensEvent {
+    pub liquid_stake_pool: Pubkey,
+    pub validator_stake: Pubkey,
+    pub authority: Pubkey,
+    pub amount: u64,
+    pub receipt_amount: u64,
+}
+
+impl LiquidStakeTokensEvent {
+    /// `sha256("stake::event::liquid_stake_tokens::v1")[..8]`
+    pub const DISCRIMINATOR: [u8; 8] = [37, 242, 149, 101, 106, 104, 87, 247];
+}
+
+/// Receipt tokens of a liquid stake pool were burned to unstake tokens.
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+pub struct LiquidUnstakeTokensEvent {
+    pub liquid_stake_pool: Pubkey,
+    pub validator_stake: Pubkey,
+    pub authority: Pubkey,
+    pub receipt_amount: u64,
+    pub amount: u64,
+    pub unstake_cooldown: u64,
+}
+
+impl LiquidUnstakeTokensEvent {
+    /// `sha256("stake::event::liquid_unstake_tokens::v1")[..8]`
+    pub const DISCRIMINATOR: [u8; 8] = [112, 202, 19, 116, 13, 152, 140, 241];
+}
+
+/// Any of the events emitted by the stake program.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum StakeEvent {
+    StakeTokens(StakeTokensEvent),
+    UnstakeTokens(UnstakeTokensEvent),
+    Harvest(HarvestEvent),
+    Slash(SlashEvent),
+    MoveTokens(MoveTokensEvent),
+    UpdateConfig(UpdateConfigEvent),
+    AuthorityChange(AuthorityChangeEvent),
+    LamportsSync(LamportsSyncEvent),
+    SweepUndistributedRewards(SweepUndistributedRewardsEvent),
+    TransferStake(TransferStakeEvent),
+    WithdrawProtocolFees(WithdrawProtocolFeesEvent),
+    MigrateAccount(MigrateAccountEvent),
+    LiquidStakeTokens(LiquidStakeTokensEvent),
+    LiquidUnstakeTokens(LiquidUnstakeTokensEvent),
+}
+
+impl StakeEvent {
+    /// Decodes an event from its discriminator and data fields.
+    ///
+    /// Returns `None` when the discriminator is unknown (e.g. an event version
+    /// this client does not know about) or the data does not match the layout.
+    pub fn decode(discriminator: &[u8], data: &[u8]) -> Option<Self> {
+        let discriminator: [u8; 8] = discriminator.try_into().ok()?;
+
+        match discriminator {
+            StakeTokensEvent::DISCRIMINATOR => StakeTokensEvent::try_from_slice(data)
+                .ok()
+                .map(Self::StakeTokens),
+            UnstakeTokensEvent::DISCRIMINATOR => UnstakeTokensEvent::try_from_slice(data)
+                .ok()
+                .map(Self::UnstakeTokens),
+            HarvestEvent::DISCRIMINATOR => {
+                HarvestEvent::try_from_slice(data).ok().map(Self::Harvest)
+            }
+            SlashEvent::DISCRIMINATOR => SlashEvent::try_from_slice(data).ok().map(Self::Slash),
+            MoveTokensEvent::DISCRIMINATOR => MoveTokensEvent::try_from_slice(data)
+                .ok()
+                .map(Self::MoveTokens),
+            UpdateConfigEvent::DISCRIMINATOR => UpdateConfigEvent::try_from_slice(data)
+                .ok()
+                .map(Self::UpdateConfig),
+            AuthorityChangeEvent::DISCRIMINATOR => AuthorityChangeEvent::try_from_slice(data)
+                .ok()
+                .map(Self::AuthorityChange),
+            LamportsSyncEvent::DISCRIMINATOR => LamportsSyncEvent::try_from_slice(data)
+                .ok()
+                .map(Self::LamportsSync),
+            SweepUndistributedRewardsEvent::DISCRIMINATOR => {
+                SweepUndistributedRewardsEvent::try_from_slice(data)
+                    .ok()
+                    .map(Self::SweepUndistributedRewards)
+            }
+            TransferStakeEvent::DISCRIMINATOR => TransferStakeEvent::try_from_slice(data)
+                .ok()
+                .map(Self::TransferStake),
+            WithdrawProtocolFeesEvent::DISCRIMINATOR => {
+                WithdrawProtocolFeesEvent::try_from_slice(data)
+                    .ok()
+                    .map(Self::WithdrawProtocolFees)
+            }
+            MigrateAccountEvent::DISCRIMINATOR => MigrateAccountEvent::try_from_slice(data)
+                .ok()
+                .map(Self::MigrateAccount),
+            LiquidStakeTokensEvent::DISCRIMINATOR => LiquidStakeTokensEvent::try_from_slice(data)
+                .ok()
+                .map(Self::LiquidStakeTokens),
+            LiquidUnstakeTokensEvent::DISCRIMINATOR => {
+                LiquidUnstakeTokensEvent::try_from_slice(data)
+                    .ok()
+                    .map(Self::LiquidUnstakeTokens)
+            }
+            _ => None,
+        }
+    }
+
+    /// Decodes the fields of a single `Program data:` log line.
+    #[cfg(feature = "client")]
+    pub fn decode_log_data(fields: &str) -> Option<Self> {
+        let mut fields = fields.split_whitespace();
+        let discriminator = STANDARD.decode(fields.next()?).ok()?;
+        let data = STANDARD.decode(fields.next()?).ok()?;
+
+        if fields.next().is_some() {
+            return None;
+        }
+
+        Self::decode(&discriminator, &data)
+    }
+}
+
+/// Parses all stake program events from the log messages of a transaction.
+///
+/// Only `Program data:` lines logged while the stake program is the currently
+/// executing program are considered, so data emitted by other programs (or by
+/// programs invoked through CPI from the stake program) is ignored.
+#[cfg(feature = "client")]
+pub fn parse_events<S: AsRef<str>>(logs: &[S]) -> Vec<StakeEvent> {
+    let program_id = crate::ID.to_string();
+    let mut stack: Vec<String> = Vec::new();
+    let mut events = Vec::new();
+
+    for log in logs {
+        let log = log.as_ref();
+
+        if let Some(fields) = log.strip_prefix("Program data: ") {
+            if stack.last() == Some(&program_id) {
+                if let Some(event) = StakeEvent::decode_log_data(fields) {
+                    events.push(event);
+                }
+            }
+        } else if let Some(rest) = log.strip_prefix("Program ") {
+            let mut parts = rest.split_whitespace();
+            let (Some(program), Some(status)) = (parts.next(), parts.next()) else {
+                continue;
+            };
+
+            match status {
+                "invoke" => stack.push(program.to_string()),
+                "success" | "failed:" => {
+                    stack.pop();
+                }
+                _ => {}
+            }
+        }
+    }
+
+    events
+}
+
+#[cfg(test)]
+mod tests {
+    use paladin_stake_program::events as program;
+    use spl_discriminator::SplDiscriminate;
+
+    use super::*;
+
+    #[cfg(feature = "client")]
+    fn log_data(discriminator: &[u8], data: &[u8]) -> String {
+        format!(
+            "Program data: {} {}",
+            STANDARD.encode(discriminator),
+            STANDARD.encode(data)
+        )
+    }
+
+    fn harvest_event() -> HarvestEvent {
+        HarvestEvent {
+            config: Pubkey::new_unique(),
+            stake: Pubkey::new_unique(),
+            authority: Pubkey::new_unique(),
+            staking_reward: 100,
+            holder_reward: 50,
+            keeper_reward: 10,
+        }
+    }
+
+    #[test]
+    fn decode_event() {
+        let event = harvest_event();
+        let data = event.try_to_vec().unwrap();
+
+        assert_eq!(
+            StakeEvent::decode(&HarvestEvent::DISCRIMINATOR, &data),
+            Some(StakeEvent::Harvest(event))
+        );
+        assert_eq!(StakeEvent::decode(&[0; 8], &data), None);
+        assert_eq!(
+            StakeEvent::decode(&HarvestEvent::DISCRIMINATOR, &data[..data.len() - 1]),
+            None
+        );
+    }
+
+    #[cfg(feature = "client")]
+    #[test]
+    fn parse_events_only_from_stake_program() {
+        let event = harvest_event();
+        let data = event.try_to_vec().unwrap();
+        let other = Pubkey::new_unique();
+
+        let logs = vec![
+            format!("Program {} invoke [1]", other),
+            log_data(&HarvestEvent::DISCRIMINATOR, &data),
+            format!("Program {} success", other),
+            format!("Program {} invoke [1]", crate::ID),
+            "Program log: Instruction: HarvestValidatorRewards".to_string(),
+            format!("Program {} invoke [2]", other),
+            log_data(&HarvestEvent::DISCRIMINATOR, &data),
+            format!("Program {} success", other),
+            log_data(&HarvestEvent::DISCRIMINATOR, &data),
+            format!(
+                "Program {} consumed 1000 of 200000 compute units",
+                crate::ID
+            ),
+            format!("Program {} success", crate::ID),
+        ];
+
+        assert_eq!(parse_events(&logs), vec![StakeEvent::Harvest(event)]);
+    }
+
+    /// Checks that the discriminator and the size of a client event match the
+    /// program event.
+    fn assert_parity<T: BorshSerialize + BorshDeserialize, P: program::Event>(
+        discriminator: [u8; 8],
+    ) {
+        assert_eq!(
+            discriminator.as_slice(),
+            P::SPL_DISCRIMINATOR_SLICE,
+            "discriminator of {}",
+            std::any::type_name::<T>()
+        );
+
+        // Every client event field is fixed-size, so decoding the zeroed
+        // program event must consume all of its bytes.
+        let data = vec![0; std::mem::size_of::<P>()];
+        let event = T::try_from_slice(&data).unwrap();
+        assert_eq!(event.try_to_vec().unwrap().len(), data.len());
+    }
+
+    #[test]
+    fn events_match_program() {
+        assert_parity::<StakeTokensEvent, program::StakeTokensEvent>(
+            StakeTokensEvent::DISCRIMINATOR,
+        );
+        assert_parity::<UnstakeTokensEvent, program::UnstakeTokensEvent>(
+            UnstakeTokensEvent::DISCRIMINATOR,
+        );
+        assert_parity::<HarvestEvent, program::HarvestEvent>(HarvestEvent::DISCRIMINATOR);
+        assert_parity::<SlashEvent, program::SlashEvent>(SlashEvent::DISCRIMINATOR);
+        assert_parity::<MoveTokensEvent, program::MoveTokensEvent>(MoveTokensEvent::DISCRIMINATOR);
+        assert_parity::<UpdateConfigEvent, program::UpdateConfigEvent>(
+            UpdateConfigEvent::DISCRIMINATOR,
+        );
+        assert_parity::<AuthorityChangeEvent, program::AuthorityChangeEvent>(
+            AuthorityChangeEvent::DISCRIMINATOR,
+        );
+        assert_parity::<LamportsSyncEvent, program::LamportsSyncEvent>(
+            LamportsSyncEvent::DISCRIMINATOR,
+        );
+        assert_parity::<SweepUndistributedRewardsEvent, program::SweepUndistributedRewardsEvent>(
+            SweepUndistributedRewardsEvent::DISCRIMINATOR,
+        );
+        assert_parity::<TransferStakeEvent, program::TransferStakeEvent>(
+            TransferStakeEvent::DISCRIMINATOR,
+        );
+        assert_parity::<WithdrawProtocolFeesEvent, program::WithdrawProtocolFeesEvent>(
+            WithdrawProtocolFeesEvent::DISCRIMINATOR,
+        );
+        assert_parity::<MigrateAccountEvent, program::MigrateAccountEvent>(
+            MigrateAccountEvent::DISCRIMINATOR,
+        );
+        assert_parity::<LiquidStakeTokensEvent, program::LiquidStakeTokensEvent>(
+            LiquidStakeTokensEvent::DISCRIMINATOR,
+        );
+        assert_parity::<LiquidUnstakeTokensEvent, program::LiquidUnstakeTokensEvent>(
+            LiquidUnstakeTokensEvent::DISCRIMINATOR,
+        );
+    }
+}
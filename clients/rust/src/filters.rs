@@ -0,0 +1,294 @@
+//! `getProgramAccounts` filters over the stake program accounts.
+//!
+//! The offsets are those of the program's `Pod` account layouts, so accounts
+//! can be queried by discriminator, delegation authority, validator vote or
+//! SOL stake account without writing raw `memcmp` filters by hand.
+//!
+//! ```ignore
+//! let query = ProgramAccountsFilter::sol_staker_stakes().authority(&authority);
+//! let accounts = rpc_client
+//!     .get_program_accounts_with_config(&paladin_stake_program_client::ID, config(query.rpc_filters()))
+//!     .await?;
+//! let stakes = query.decode_all(accounts.iter().map(|(key, account)| (key, &account.data[..])));
+//! ```
+
+use std::marker::PhantomData;
+
+use solana_program::pubkey::Pubkey;
+
+use crate::accounts::{SolStakerStake, ValidatorStake};
+
+/// Discriminator of an initialized `ValidatorStake` account.
+pub const VALIDATOR_STAKE_DISCRIMINATOR: [u8; 8] = [197, 173, 152, 124, 46, 252, 77, 146];
+
+/// Discriminator of an initialized `SolStakerStake` account.
+pub const SOL_STAKER_STAKE_DISCRIMINATOR: [u8; 8] = [192, 163, 150, 81, 162, 252, 198, 62];
+
+/// Offset of the `delegation` of stake accounts (after the discriminator).
+const DELEGATION_OFFSET: usize = 8;
+
+/// Offset of `delegation.authority` on stake accounts.
+pub const DELEGATION_AUTHORITY_OFFSET: usize = DELEGATION_OFFSET + 24;
+
+/// Offset of `delegation.validator_vote` on stake accounts.
+pub const DELEGATION_VALIDATOR_VOTE_OFFSET: usize = DELEGATION_OFFSET + 56;
+
+/// Offset of `sol_stake` on `SolStakerStake` accounts (after the delegation and
+/// `lamports_amount`).
+pub const SOL_STAKE_OFFSET: usize = DELEGATION_OFFSET + 120 + 8;
+
+/// Filter applied to the data of program accounts.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum AccountFilter {
+    /// Matches accounts with the given bytes at the given offset.
+    Memcmp { offset: usize, bytes: Vec<u8> },
+}
+
+impl AccountFilter {
+    fn pubkey(offset: usize, pubkey: &Pubkey) -> Self {
+        Self::Memcmp {
+            offset,
+            bytes: pubkey.to_bytes().to_vec(),
+        }
+    }
+
+    /// Returns whether the account data matches the filter.
+    pub fn matches(&self, data: &[u8]) -> bool {
+        match self {
+            Self::Memcmp { offset, bytes } => data
+                .get(*offset..offset.saturating_add(bytes.len()))
+                .is_some_and(|slice| slice == bytes.as_slice()),
+        }
+    }
+}
+
+#[cfg(feature = "rpc")]
+impl From<AccountFilter> for solana_rpc_client_api::filter::RpcFilterType {
+    fn from(filter: AccountFilter) -> Self {
+        match filter {
+            AccountFilter::Memcmp { offset, bytes } => Self::Memcmp(
+                solana_rpc_client_api::filter::Memcmp::new_raw_bytes(offset, bytes),
+            ),
+        }
+    }
+}
+
+/// Program account that can be queried with an [`ProgramAccountsFilter`].
+pub trait FilterableAccount: Sized {
+    const DISCRIMINATOR: [u8; 8];
+
+    fn decode(data: &[u8]) -> Option<Self>;
+}
+
+impl FilterableAccount for ValidatorStake {
+    const DISCRIMINATOR: [u8; 8] = VALIDATOR_STAKE_DISCRIMINATOR;
+
+    fn decode(data: &[u8]) -> Option<Self> {
+        Self::from_bytes(data).ok()
+    }
+}
+
+impl FilterableAccount for SolStakerStake {
+    const DISCRIMINATOR: [u8; 8] = SOL_STAKER_STAKE_DISCRIMINATOR;
+
+    fn decode(data: &[u8]) -> Option<Self> {
+        Self::from_bytes(data).ok()
+    }
+}
+
+/// Typed set of filters selecting program accounts of type `T`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProgramAccountsFilter<T> {
+    filters: Vec<AccountFilter>,
+    _account: PhantomData<T>,
+}
+
+impl<T: FilterableAccount> ProgramAccountsFilter<T> {
+    /// Selects every initialized account of type `T`.
+    pub fn new() -> Self {
+        Self {
+            filters: vec![AccountFilter::Memcmp {
+                offset: 0,
+                bytes: T::DISCRIMINATOR.to_vec(),
+            }],
+            _account: PhantomData,
+        }
+    }
+
+    /// Returns the filters, to be passed to `getProgramAccounts`.
+    pub fn filters(&self) -> &[AccountFilter] {
+        &self.filters
+    }
+
+    /// Returns the filters as RPC filters.
+    #[cfg(feature = "rpc")]
+    pub fn rpc_filters(&self) -> Vec<solana_rpc_client_api::filter::RpcFilterType> {
+        self.filters.iter().cloned().map(Into::into).collect()
+    }
+
+    /// Returns whether the account data matches every filter.
+    pub fn matches(&self, data: &[u8]) -> bool {
+        self.filters.iter().all(|filter| filter.matches(data))
+    }
+
+    /// Decodes the account data, returning `None` if it does not match the
+    /// filters or cannot be decoded.
+    pub fn decode(&self, data: &[u8]) -> Option<T> {
+        self.matches(data).then(|| T::decode(data)).flatten()
+    }
+
+    /// Decodes the accounts returned by `getProgramAccounts`, skipping the
+    /// ones not matching the filters.
+    pub fn decode_all<'a>(
+        &self,
+        accounts: impl IntoIterator<Item = (&'a Pubkey, &'a [u8])>,
+    ) -> Vec<(Pubkey, T)> {
+        accounts
+            .into_iter()
+            .filter_map(|(address, data)| Some((*address, self.decode(data)?)))
+            .collect()
+    }
+
+    fn with(mut self, filter: AccountFilter) -> Self {
+        self.filters.push(filter);
+        self
+    }
+}
+
+impl<T: FilterableAccount> Default for ProgramAccountsFilter<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ProgramAccountsFilter<ValidatorStake> {
+    /// Selects every `ValidatorStake` account.
+    pub fn validator_stakes() -> Self {
+        Self::new()
+    }
+
+    /// Selects the accounts whose stake authority is `authority`.
+    pub fn authority(self, authority: &Pubkey) -> Self {
+        self.with(AccountFilter::pubkey(
+            DELEGATION_AUTHORITY_OFFSET,
+            authority,
+        ))
+    }
+
+    /// Selects the account of the validator `vote` account.
+    pub fn validator_vote(self, vote: &Pubkey) -> Self {
+        self.with(AccountFilter::pubkey(
+            DELEGATION_VALIDATOR_VOTE_OFFSET,
+            vote,
+        ))
+    }
+}
+
+impl ProgramAccountsFilter<SolStakerStake> {
+    /// Selects every `SolStakerStake` account.
+    pub fn sol_staker_stakes() -> Self {
+        Self::new()
+    }
+
+    /// Selects the accounts whose stake authority is `authority`.
+    pub fn authority(self, authority: &Pubkey) -> Self {
+        self.with(AccountFilter::pubkey(
+            DELEGATION_AUTHORITY_OFFSET,
+            authority,
+        ))
+    }
+
+    /// Selects the delegators of the validator `vote` account.
+    pub fn validator_vote(self, vote: &Pubkey) -> Self {
+        self.with(AccountFilter::pubkey(
+            DELEGATION_VALIDATOR_VOTE_OFFSET,
+            vote,
+        ))
+    }
+
+    /// Selects the account of the native `sol_stake` account.
+    pub fn sol_stake(self, sol_stake: &Pubkey) -> Self {
+        self.with(AccountFilter::pubkey(SOL_STAKE_OFFSET, sol_stake))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::mem::{offset_of, size_of};
+
+    use bytemuck::Zeroable;
+    use paladin_stake_program::state as program;
+    use spl_discriminator::SplDiscriminate;
+
+    use super::*;
+
+    #[test]
+    fn offsets_match_program_layout() {
+        assert_eq!(
+            DELEGATION_OFFSET,
+            offset_of!(program::ValidatorStake, delegation)
+        );
+        assert_eq!(
+            DELEGATION_OFFSET,
+            offset_of!(program::SolStakerStake, delegation)
+        );
+        assert_eq!(
+            DELEGATION_AUTHORITY_OFFSET,
+            DELEGATION_OFFSET + offset_of!(program::Delegation, authority)
+        );
+        assert_eq!(
+            DELEGATION_VALIDATOR_VOTE_OFFSET,
+            DELEGATION_OFFSET + offset_of!(program::Delegation, validator_vote)
+        );
+        assert_eq!(
+            SOL_STAKE_OFFSET,
+            offset_of!(program::SolStakerStake, sol_stake)
+        );
+        assert_eq!(
+            SOL_STAKE_OFFSET,
+            DELEGATION_OFFSET + size_of::<program::Delegation>() + 8
+        );
+    }
+
+    #[test]
+    fn discriminators_match_program() {
+        assert_eq!(
+            VALIDATOR_STAKE_DISCRIMINATOR.as_slice(),
+            program::ValidatorStake::SPL_DISCRIMINATOR_SLICE
+        );
+        assert_eq!(
+            SOL_STAKER_STAKE_DISCRIMINATOR.as_slice(),
+            program::SolStakerStake::SPL_DISCRIMINATOR_SLICE
+        );
+    }
+
+    #[test]
+    fn filters_match_program_accounts() {
+        let authority = Pubkey::new_unique();
+        let vote = Pubkey::new_unique();
+        let sol_stake = Pubkey::new_unique();
+
+        let mut stake = program::SolStakerStake::zeroed();
+        stake._discriminator = SOL_STAKER_STAKE_DISCRIMINATOR;
+        stake.delegation.authority = authority;
+        stake.delegation.validator_vote = vote;
+        stake.sol_stake = sol_stake;
+        let data = bytemuck::bytes_of(&stake);
+
+        let query = ProgramAccountsFilter::sol_staker_stakes()
+            .authority(&authority)
+            .validator_vote(&vote)
+            .sol_stake(&sol_stake);
+        let decoded = query.decode(data).unwrap();
+        assert_eq!(decoded.delegation.authority, authority);
+        assert_eq!(decoded.sol_stake, sol_stake);
+
+        // Other authorities and account types are filtered out.
+        let other = ProgramAccountsFilter::sol_staker_stakes().authority(&Pubkey::new_unique());
+        assert!(other.decode(data).is_none());
+        assert!(ProgramAccountsFilter::validator_stakes()
+            .validator_vote(&vote)
+            .decode(data)
+            .is_none());
+    }
+}
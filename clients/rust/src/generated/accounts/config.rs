@@ -29,11 +29,23 @@ pub struct Config {
     pub accumulated_stake_rewards_per_token: u128,
     pub max_deactivation_basis_points: u16,
     pub vault_authority_bump: u8,
-    pub padding: [u8; 5],
+    pub version: u8,
+    pub padding: [u8; 4],
+    pub reward_streaming_period_seconds: u64,
+    pub reward_period_end: u64,
+    pub reward_last_update: u64,
+    pub reward_rate: u128,
+    pub stake_rewards_remainder: u64,
+    pub undistributed_rewards: u64,
+    pub fee_recipient: NullableAddress,
+    pub unclaimed_protocol_fees: u64,
+    pub protocol_fee_basis_points: u16,
+    pub fee_padding: [u8; 6],
+    pub reward_stream_remainder: u64,
 }
 
 impl Config {
-    pub const LEN: usize = 192;
+    pub const LEN: usize = 304;
 
     #[inline(always)]
     pub fn from_bytes(data: &[u8]) -> Result<Self, std::io::Error> {
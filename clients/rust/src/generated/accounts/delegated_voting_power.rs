@@ -0,0 +1,77 @@
+//! This code was AUTOGENERATED using the kinobi library.
+//! Please DO NOT EDIT THIS FILE, instead use visitors
+//! to add features, then rerun kinobi to update it.
+//!
+//! <https://github.com/kinobi-so/kinobi>
+//!
+
+use crate::generated::types::VotingPowerCheckpoints;
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+use solana_program::pubkey::Pubkey;
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DelegatedVotingPower {
+    pub discriminator: [u8; 8],
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub config: Pubkey,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub delegate: Pubkey,
+    pub voting_power: u64,
+    pub version: u8,
+    pub padding: [u8; 7],
+    pub checkpoints: VotingPowerCheckpoints,
+}
+
+impl DelegatedVotingPower {
+    pub const LEN: usize = 352;
+
+    #[inline(always)]
+    pub fn from_bytes(data: &[u8]) -> Result<Self, std::io::Error> {
+        let mut data = data;
+        Self::deserialize(&mut data)
+    }
+}
+
+impl<'a> TryFrom<&solana_program::account_info::AccountInfo<'a>> for DelegatedVotingPower {
+    type Error = std::io::Error;
+
+    fn try_from(
+        account_info: &solana_program::account_info::AccountInfo<'a>,
+    ) -> Result<Self, Self::Error> {
+        let mut data: &[u8] = &(*account_info.data).borrow();
+        Self::deserialize(&mut data)
+    }
+}
+
+#[cfg(feature = "anchor")]
+impl anchor_lang::AccountDeserialize for DelegatedVotingPower {
+    fn try_deserialize_unchecked(buf: &mut &[u8]) -> anchor_lang::Result<Self> {
+        Ok(Self::deserialize(buf)?)
+    }
+}
+
+#[cfg(feature = "anchor")]
+impl anchor_lang::AccountSerialize for DelegatedVotingPower {}
+
+#[cfg(feature = "anchor")]
+impl anchor_lang::Owner for DelegatedVotingPower {
+    fn owner() -> Pubkey {
+        crate::PALADIN_STAKE_PROGRAM_ID
+    }
+}
+
+#[cfg(feature = "anchor-idl-build")]
+impl anchor_lang::IdlBuild for DelegatedVotingPower {}
+
+#[cfg(feature = "anchor-idl-build")]
+impl anchor_lang::Discriminator for DelegatedVotingPower {
+    const DISCRIMINATOR: [u8; 8] = [0; 8];
+}
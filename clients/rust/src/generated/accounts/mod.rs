@@ -6,9 +6,15 @@
 //!
 
 pub(crate) mod r#config;
+pub(crate) mod r#delegated_voting_power;
+pub(crate) mod r#liquid_stake_pool;
+pub(crate) mod r#receipt_rewards;
 pub(crate) mod r#sol_staker_stake;
 pub(crate) mod r#validator_stake;
 
 pub use self::r#config::*;
+pub use self::r#delegated_voting_power::*;
+pub use self::r#liquid_stake_pool::*;
+pub use self::r#receipt_rewards::*;
 pub use self::r#sol_staker_stake::*;
 pub use self::r#validator_stake::*;
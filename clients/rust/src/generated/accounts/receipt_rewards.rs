@@ -0,0 +1,79 @@
+//! This code was AUTOGENERATED using the kinobi library.
+//! Please DO NOT EDIT THIS FILE, instead use visitors
+//! to add features, then rerun kinobi to update it.
+//!
+//! <https://github.com/kinobi-so/kinobi>
+//!
+
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+use solana_program::pubkey::Pubkey;
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ReceiptRewards {
+    pub discriminator: [u8; 8],
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub liquid_stake_pool: Pubkey,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub token_account: Pubkey,
+    pub last_accumulated_rewards_per_token: u128,
+    pub balance: u64,
+    pub unclaimed_rewards: u64,
+    pub activating_balance: u64,
+    pub activation_epoch: u64,
+    pub version: u8,
+    pub padding: [u8; 7],
+}
+
+impl ReceiptRewards {
+    pub const LEN: usize = 128;
+
+    #[inline(always)]
+    pub fn from_bytes(data: &[u8]) -> Result<Self, std::io::Error> {
+        let mut data = data;
+        Self::deserialize(&mut data)
+    }
+}
+
+impl<'a> TryFrom<&solana_program::account_info::AccountInfo<'a>> for ReceiptRewards {
+    type Error = std::io::Error;
+
+    fn try_from(
+        account_info: &solana_program::account_info::AccountInfo<'a>,
+    ) -> Result<Self, Self::Error> {
+        let mut data: &[u8] = &(*account_info.data).borrow();
+        Self::deserialize(&mut data)
+    }
+}
+
+#[cfg(feature = "anchor")]
+impl anchor_lang::AccountDeserialize for ReceiptRewards {
+    fn try_deserialize_unchecked(buf: &mut &[u8]) -> anchor_lang::Result<Self> {
+        Ok(Self::deserialize(buf)?)
+    }
+}
+
+#[cfg(feature = "anchor")]
+impl anchor_lang::AccountSerialize for ReceiptRewards {}
+
+#[cfg(feature = "anchor")]
+impl anchor_lang::Owner for ReceiptRewards {
+    fn owner() -> Pubkey {
+        crate::PALADIN_STAKE_PROGRAM_ID
+    }
+}
+
+#[cfg(feature = "anchor-idl-build")]
+impl anchor_lang::IdlBuild for ReceiptRewards {}
+
+#[cfg(feature = "anchor-idl-build")]
+impl anchor_lang::Discriminator for ReceiptRewards {
+    const DISCRIMINATOR: [u8; 8] = [0; 8];
+}
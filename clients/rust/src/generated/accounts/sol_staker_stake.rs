@@ -5,7 +5,10 @@
 //! <https://github.com/kinobi-so/kinobi>
 //!
 
+use crate::generated::types::Activation;
 use crate::generated::types::Delegation;
+use crate::generated::types::VotingPowerCheckpoints;
+use crate::hooked::NullableAddress;
 use borsh::BorshDeserialize;
 use borsh::BorshSerialize;
 use solana_program::pubkey::Pubkey;
@@ -21,10 +24,17 @@ pub struct SolStakerStake {
         serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
     )]
     pub sol_stake: Pubkey,
+    pub version: u8,
+    pub awaiting_validator_stake: u8,
+    pub padding: [u8; 6],
+    pub checkpoints: VotingPowerCheckpoints,
+    pub vote_delegate: NullableAddress,
+    pub activation: Activation,
+    pub delegated_voting_power: u64,
 }
 
 impl SolStakerStake {
-    pub const LEN: usize = 168;
+    pub const LEN: usize = 496;
 
     #[inline(always)]
     pub fn from_bytes(data: &[u8]) -> Result<Self, std::io::Error> {
@@ -5,7 +5,10 @@
 //! <https://github.com/kinobi-so/kinobi>
 //!
 
+use crate::generated::types::Activation;
 use crate::generated::types::Delegation;
+use crate::generated::types::VotingPowerCheckpoints;
+use crate::hooked::NullableAddress;
 use borsh::BorshDeserialize;
 use borsh::BorshSerialize;
 
@@ -16,10 +19,16 @@ pub struct ValidatorStake {
     pub delegation: Delegation,
     pub total_staked_lamports_amount: u64,
     pub total_staked_lamports_amount_min: u64,
+    pub version: u8,
+    pub padding: [u8; 7],
+    pub checkpoints: VotingPowerCheckpoints,
+    pub vote_delegate: NullableAddress,
+    pub activation: Activation,
+    pub delegated_voting_power: u64,
 }
 
 impl ValidatorStake {
-    pub const LEN: usize = 144;
+    pub const LEN: usize = 472;
 
     #[inline(always)]
     pub fn from_bytes(data: &[u8]) -> Result<Self, std::io::Error> {
@@ -88,6 +88,18 @@ pub enum PaladinStakeProgramError {
     /// 25 - Invalid vault holder rewards seeds
     #[error("Invalid vault holder rewards seeds")]
     InvalidVaultHolderRewardsSeeds = 0x19,
+    /// 26 - Account must be migrated to the current layout
+    #[error("Account must be migrated to the current layout")]
+    AccountMigrationRequired = 0x1A,
+    /// 27 - Invalid mint extension
+    #[error("Invalid mint extension")]
+    InvalidMintExtension = 0x1B,
+    /// 28 - Voting power history is not available for the slot
+    #[error("Voting power history is not available for the slot")]
+    VotingPowerUnavailable = 0x1C,
+    /// 29 - Missing delegated voting power account
+    #[error("Missing delegated voting power account")]
+    MissingDelegatedVotingPower = 0x1D,
 }
 
 impl solana_program::program_error::PrintProgramError for PaladinStakeProgramError {
@@ -0,0 +1,483 @@
+//! This code was AUTOGENERATED using the kinobi library.
+//! Please DO NOT EDIT THIS FILE, instead use visitors
+//! to add features, then rerun kinobi to update it.
+//!
+//! <https://github.com/kinobi-so/kinobi>
+//!
+
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+
+/// Accounts.
+pub struct HarvestReceiptRewards {
+    /// Liquid stake pool account
+    pub liquid_stake_pool: solana_program::pubkey::Pubkey,
+    /// Receipt token mint
+    pub receipt_mint: solana_program::pubkey::Pubkey,
+    /// Receipt token account
+    pub receipt_token_account: solana_program::pubkey::Pubkey,
+    /// Receipt rewards account
+    pub receipt_rewards: solana_program::pubkey::Pubkey,
+    /// Owner of the receipt token account
+    pub receipt_token_account_owner: solana_program::pubkey::Pubkey,
+}
+
+impl HarvestReceiptRewards {
+    pub fn instruction(&self) -> solana_program::instruction::Instruction {
+        self.instruction_with_remaining_accounts(&[])
+    }
+    #[allow(clippy::vec_init_then_push)]
+    pub fn instruction_with_remaining_accounts(
+        &self,
+        remaining_accounts: &[solana_program::instruction::AccountMeta],
+    ) -> solana_program::instruction::Instruction {
+        let mut accounts = Vec::with_capacity(5 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.liquid_stake_pool,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.receipt_mint,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.receipt_token_account,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.receipt_rewards,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.receipt_token_account_owner,
+            false,
+        ));
+        accounts.extend_from_slice(remaining_accounts);
+        let data = HarvestReceiptRewardsInstructionData::new()
+            .try_to_vec()
+            .unwrap();
+
+        solana_program::instruction::Instruction {
+            program_id: crate::PALADIN_STAKE_PROGRAM_ID,
+            accounts,
+            data,
+        }
+    }
+}
+
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct HarvestReceiptRewardsInstructionData {
+    discriminator: u8,
+}
+
+impl HarvestReceiptRewardsInstructionData {
+    pub fn new() -> Self {
+        Self { discriminator: 28 }
+    }
+}
+
+impl Default for HarvestReceiptRewardsInstructionData {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Instruction builder for `HarvestReceiptRewards`.
+///
+/// ### Accounts:
+///
+///   0. `[writable]` liquid_stake_pool
+///   1. `[]` receipt_mint
+///   2. `[]` receipt_token_account
+///   3. `[writable]` receipt_rewards
+///   4. `[writable]` receipt_token_account_owner
+#[derive(Clone, Debug, Default)]
+pub struct HarvestReceiptRewardsBuilder {
+    liquid_stake_pool: Option<solana_program::pubkey::Pubkey>,
+    receipt_mint: Option<solana_program::pubkey::Pubkey>,
+    receipt_token_account: Option<solana_program::pubkey::Pubkey>,
+    receipt_rewards: Option<solana_program::pubkey::Pubkey>,
+    receipt_token_account_owner: Option<solana_program::pubkey::Pubkey>,
+    __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
+}
+
+impl HarvestReceiptRewardsBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Liquid stake pool account
+    #[inline(always)]
+    pub fn liquid_stake_pool(
+        &mut self,
+        liquid_stake_pool: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.liquid_stake_pool = Some(liquid_stake_pool);
+        self
+    }
+    /// Receipt token mint
+    #[inline(always)]
+    pub fn receipt_mint(&mut self, receipt_mint: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.receipt_mint = Some(receipt_mint);
+        self
+    }
+    /// Receipt token account
+    #[inline(always)]
+    pub fn receipt_token_account(
+        &mut self,
+        receipt_token_account: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.receipt_token_account = Some(receipt_token_account);
+        self
+    }
+    /// Receipt rewards account
+    #[inline(always)]
+    pub fn receipt_rewards(
+        &mut self,
+        receipt_rewards: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.receipt_rewards = Some(receipt_rewards);
+        self
+    }
+    /// Owner of the receipt token account
+    #[inline(always)]
+    pub fn receipt_token_account_owner(
+        &mut self,
+        receipt_token_account_owner: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.receipt_token_account_owner = Some(receipt_token_account_owner);
+        self
+    }
+    /// Add an additional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: solana_program::instruction::AccountMeta,
+    ) -> &mut Self {
+        self.__remaining_accounts.push(account);
+        self
+    }
+    /// Add additional accounts to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[solana_program::instruction::AccountMeta],
+    ) -> &mut Self {
+        self.__remaining_accounts.extend_from_slice(accounts);
+        self
+    }
+    #[allow(clippy::clone_on_copy)]
+    pub fn instruction(&self) -> solana_program::instruction::Instruction {
+        let accounts = HarvestReceiptRewards {
+            liquid_stake_pool: self
+                .liquid_stake_pool
+                .expect("liquid_stake_pool is not set"),
+            receipt_mint: self.receipt_mint.expect("receipt_mint is not set"),
+            receipt_token_account: self
+                .receipt_token_account
+                .expect("receipt_token_account is not set"),
+            receipt_rewards: self.receipt_rewards.expect("receipt_rewards is not set"),
+            receipt_token_account_owner: self
+                .receipt_token_account_owner
+                .expect("receipt_token_account_owner is not set"),
+        };
+
+        accounts.instruction_with_remaining_accounts(&self.__remaining_accounts)
+    }
+}
+
+/// `harvest_receipt_rewards` CPI accounts.
+pub struct HarvestReceiptRewardsCpiAccounts<'a, 'b> {
+    /// Liquid stake pool account
+    pub liquid_stake_pool: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Receipt token mint
+    pub receipt_mint: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Receipt token account
+    pub receipt_token_account: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Receipt rewards account
+    pub receipt_rewards: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Owner of the receipt token account
+    pub receipt_token_account_owner: &'b solana_program::account_info::AccountInfo<'a>,
+}
+
+/// `harvest_receipt_rewards` CPI instruction.
+pub struct HarvestReceiptRewardsCpi<'a, 'b> {
+    /// The program to invoke.
+    pub __program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Liquid stake pool account
+    pub liquid_stake_pool: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Receipt token mint
+    pub receipt_mint: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Receipt token account
+    pub receipt_token_account: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Receipt rewards account
+    pub receipt_rewards: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Owner of the receipt token account
+    pub receipt_token_account_owner: &'b solana_program::account_info::AccountInfo<'a>,
+}
+
+impl<'a, 'b> HarvestReceiptRewardsCpi<'a, 'b> {
+    pub fn new(
+        program: &'b solana_program::account_info::AccountInfo<'a>,
+        accounts: HarvestReceiptRewardsCpiAccounts<'a, 'b>,
+    ) -> Self {
+        Self {
+            __program: program,
+            liquid_stake_pool: accounts.liquid_stake_pool,
+            receipt_mint: accounts.receipt_mint,
+            receipt_token_account: accounts.receipt_token_account,
+            receipt_rewards: accounts.receipt_rewards,
+            receipt_token_account_owner: accounts.receipt_token_account_owner,
+        }
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], &[])
+    }
+    #[inline(always)]
+    pub fn invoke_with_remaining_accounts(
+        &self,
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], remaining_accounts)
+    }
+    #[inline(always)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(signers_seeds, &[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed_with_remaining_accounts(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let mut accounts = Vec::with_capacity(5 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.liquid_stake_pool.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.receipt_mint.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.receipt_token_account.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.receipt_rewards.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.receipt_token_account_owner.key,
+            false,
+        ));
+        remaining_accounts.iter().for_each(|remaining_account| {
+            accounts.push(solana_program::instruction::AccountMeta {
+                pubkey: *remaining_account.0.key,
+                is_signer: remaining_account.1,
+                is_writable: remaining_account.2,
+            })
+        });
+        let data = HarvestReceiptRewardsInstructionData::new()
+            .try_to_vec()
+            .unwrap();
+
+        let instruction = solana_program::instruction::Instruction {
+            program_id: crate::PALADIN_STAKE_PROGRAM_ID,
+            accounts,
+            data,
+        };
+        let mut account_infos = Vec::with_capacity(5 + 1 + remaining_accounts.len());
+        account_infos.push(self.__program.clone());
+        account_infos.push(self.liquid_stake_pool.clone());
+        account_infos.push(self.receipt_mint.clone());
+        account_infos.push(self.receipt_token_account.clone());
+        account_infos.push(self.receipt_rewards.clone());
+        account_infos.push(self.receipt_token_account_owner.clone());
+        remaining_accounts
+            .iter()
+            .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
+
+        if signers_seeds.is_empty() {
+            solana_program::program::invoke(&instruction, &account_infos)
+        } else {
+            solana_program::program::invoke_signed(&instruction, &account_infos, signers_seeds)
+        }
+    }
+}
+
+/// Instruction builder for `HarvestReceiptRewards` via CPI.
+///
+/// ### Accounts:
+///
+///   0. `[writable]` liquid_stake_pool
+///   1. `[]` receipt_mint
+///   2. `[]` receipt_token_account
+///   3. `[writable]` receipt_rewards
+///   4. `[writable]` receipt_token_account_owner
+#[derive(Clone, Debug)]
+pub struct HarvestReceiptRewardsCpiBuilder<'a, 'b> {
+    instruction: Box<HarvestReceiptRewardsCpiBuilderInstruction<'a, 'b>>,
+}
+
+impl<'a, 'b> HarvestReceiptRewardsCpiBuilder<'a, 'b> {
+    pub fn new(program: &'b solana_program::account_info::AccountInfo<'a>) -> Self {
+        let instruction = Box::new(HarvestReceiptRewardsCpiBuilderInstruction {
+            __program: program,
+            liquid_stake_pool: None,
+            receipt_mint: None,
+            receipt_token_account: None,
+            receipt_rewards: None,
+            receipt_token_account_owner: None,
+            __remaining_accounts: Vec::new(),
+        });
+        Self { instruction }
+    }
+    /// Liquid stake pool account
+    #[inline(always)]
+    pub fn liquid_stake_pool(
+        &mut self,
+        liquid_stake_pool: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.liquid_stake_pool = Some(liquid_stake_pool);
+        self
+    }
+    /// Receipt token mint
+    #[inline(always)]
+    pub fn receipt_mint(
+        &mut self,
+        receipt_mint: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.receipt_mint = Some(receipt_mint);
+        self
+    }
+    /// Receipt token account
+    #[inline(always)]
+    pub fn receipt_token_account(
+        &mut self,
+        receipt_token_account: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.receipt_token_account = Some(receipt_token_account);
+        self
+    }
+    /// Receipt rewards account
+    #[inline(always)]
+    pub fn receipt_rewards(
+        &mut self,
+        receipt_rewards: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.receipt_rewards = Some(receipt_rewards);
+        self
+    }
+    /// Owner of the receipt token account
+    #[inline(always)]
+    pub fn receipt_token_account_owner(
+        &mut self,
+        receipt_token_account_owner: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.receipt_token_account_owner = Some(receipt_token_account_owner);
+        self
+    }
+    /// Add an additional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: &'b solana_program::account_info::AccountInfo<'a>,
+        is_writable: bool,
+        is_signer: bool,
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .push((account, is_writable, is_signer));
+        self
+    }
+    /// Add additional accounts to the instruction.
+    ///
+    /// Each account is represented by a tuple of the `AccountInfo`, a `bool` indicating whether the account is writable or not,
+    /// and a `bool` indicating whether the account is a signer or not.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .extend_from_slice(accounts);
+        self
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed(&[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let instruction = HarvestReceiptRewardsCpi {
+            __program: self.instruction.__program,
+
+            liquid_stake_pool: self
+                .instruction
+                .liquid_stake_pool
+                .expect("liquid_stake_pool is not set"),
+
+            receipt_mint: self
+                .instruction
+                .receipt_mint
+                .expect("receipt_mint is not set"),
+
+            receipt_token_account: self
+                .instruction
+                .receipt_token_account
+                .expect("receipt_token_account is not set"),
+
+            receipt_rewards: self
+                .instruction
+                .receipt_rewards
+                .expect("receipt_rewards is not set"),
+
+            receipt_token_account_owner: self
+                .instruction
+                .receipt_token_account_owner
+                .expect("receipt_token_account_owner is not set"),
+        };
+        instruction.invoke_signed_with_remaining_accounts(
+            signers_seeds,
+            &self.instruction.__remaining_accounts,
+        )
+    }
+}
+
+#[derive(Clone, Debug)]
+struct HarvestReceiptRewardsCpiBuilderInstruction<'a, 'b> {
+    __program: &'b solana_program::account_info::AccountInfo<'a>,
+    liquid_stake_pool: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    receipt_mint: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    receipt_token_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    receipt_rewards: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    receipt_token_account_owner: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
+    __remaining_accounts: Vec<(
+        &'b solana_program::account_info::AccountInfo<'a>,
+        bool,
+        bool,
+    )>,
+}
@@ -0,0 +1,590 @@
+//! This code was AUTOGENERATED using the kinobi library.
+//! Please DO NOT EDIT THIS FILE, instead use visitors
+//! to add features, then rerun kinobi to update it.
+//!
+//! <https://github.com/kinobi-so/kinobi>
+//!
+
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+
+/// Accounts.
+pub struct InitializeLiquidStakePool {
+    /// Stake config account
+    pub config: solana_program::pubkey::Pubkey,
+    /// Pooled validator stake account
+    pub validator_stake: solana_program::pubkey::Pubkey,
+    /// Liquid stake pool account
+    pub liquid_stake_pool: solana_program::pubkey::Pubkey,
+    /// Vault token account
+    pub vault: solana_program::pubkey::Pubkey,
+    /// Stake token mint
+    pub mint: solana_program::pubkey::Pubkey,
+    /// Receipt token mint
+    pub receipt_mint: solana_program::pubkey::Pubkey,
+    /// Receipt token program
+    pub receipt_token_program: solana_program::pubkey::Pubkey,
+    /// System program
+    pub system_program: solana_program::pubkey::Pubkey,
+}
+
+impl InitializeLiquidStakePool {
+    pub fn instruction(&self) -> solana_program::instruction::Instruction {
+        self.instruction_with_remaining_accounts(&[])
+    }
+    #[allow(clippy::vec_init_then_push)]
+    pub fn instruction_with_remaining_accounts(
+        &self,
+        remaining_accounts: &[solana_program::instruction::AccountMeta],
+    ) -> solana_program::instruction::Instruction {
+        let mut accounts = Vec::with_capacity(8 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.config,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.validator_stake,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.liquid_stake_pool,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.vault, false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.mint, false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.receipt_mint,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.receipt_token_program,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.system_program,
+            false,
+        ));
+        accounts.extend_from_slice(remaining_accounts);
+        let data = InitializeLiquidStakePoolInstructionData::new()
+            .try_to_vec()
+            .unwrap();
+
+        solana_program::instruction::Instruction {
+            program_id: crate::PALADIN_STAKE_PROGRAM_ID,
+            accounts,
+            data,
+        }
+    }
+}
+
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct InitializeLiquidStakePoolInstructionData {
+    discriminator: u8,
+}
+
+impl InitializeLiquidStakePoolInstructionData {
+    pub fn new() -> Self {
+        Self { discriminator: 24 }
+    }
+}
+
+impl Default for InitializeLiquidStakePoolInstructionData {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Instruction builder for `InitializeLiquidStakePool`.
+///
+/// ### Accounts:
+///
+///   0. `[]` config
+///   1. `[]` validator_stake
+///   2. `[writable]` liquid_stake_pool
+///   3. `[]` vault
+///   4. `[]` mint
+///   5. `[]` receipt_mint
+///   6. `[]` receipt_token_program
+///   7. `[optional]` system_program (default to `11111111111111111111111111111111`)
+#[derive(Clone, Debug, Default)]
+pub struct InitializeLiquidStakePoolBuilder {
+    config: Option<solana_program::pubkey::Pubkey>,
+    validator_stake: Option<solana_program::pubkey::Pubkey>,
+    liquid_stake_pool: Option<solana_program::pubkey::Pubkey>,
+    vault: Option<solana_program::pubkey::Pubkey>,
+    mint: Option<solana_program::pubkey::Pubkey>,
+    receipt_mint: Option<solana_program::pubkey::Pubkey>,
+    receipt_token_program: Option<solana_program::pubkey::Pubkey>,
+    system_program: Option<solana_program::pubkey::Pubkey>,
+    __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
+}
+
+impl InitializeLiquidStakePoolBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Stake config account
+    #[inline(always)]
+    pub fn config(&mut self, config: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.config = Some(config);
+        self
+    }
+    /// Pooled validator stake account
+    #[inline(always)]
+    pub fn validator_stake(
+        &mut self,
+        validator_stake: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.validator_stake = Some(validator_stake);
+        self
+    }
+    /// Liquid stake pool account
+    #[inline(always)]
+    pub fn liquid_stake_pool(
+        &mut self,
+        liquid_stake_pool: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.liquid_stake_pool = Some(liquid_stake_pool);
+        self
+    }
+    /// Vault token account
+    #[inline(always)]
+    pub fn vault(&mut self, vault: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.vault = Some(vault);
+        self
+    }
+    /// Stake token mint
+    #[inline(always)]
+    pub fn mint(&mut self, mint: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.mint = Some(mint);
+        self
+    }
+    /// Receipt token mint
+    #[inline(always)]
+    pub fn receipt_mint(&mut self, receipt_mint: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.receipt_mint = Some(receipt_mint);
+        self
+    }
+    /// Receipt token program
+    #[inline(always)]
+    pub fn receipt_token_program(
+        &mut self,
+        receipt_token_program: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.receipt_token_program = Some(receipt_token_program);
+        self
+    }
+    /// `[optional account, default to '11111111111111111111111111111111']`
+    /// System program
+    #[inline(always)]
+    pub fn system_program(&mut self, system_program: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.system_program = Some(system_program);
+        self
+    }
+    /// Add an additional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: solana_program::instruction::AccountMeta,
+    ) -> &mut Self {
+        self.__remaining_accounts.push(account);
+        self
+    }
+    /// Add additional accounts to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[solana_program::instruction::AccountMeta],
+    ) -> &mut Self {
+        self.__remaining_accounts.extend_from_slice(accounts);
+        self
+    }
+    #[allow(clippy::clone_on_copy)]
+    pub fn instruction(&self) -> solana_program::instruction::Instruction {
+        let accounts = InitializeLiquidStakePool {
+            config: self.config.expect("config is not set"),
+            validator_stake: self.validator_stake.expect("validator_stake is not set"),
+            liquid_stake_pool: self
+                .liquid_stake_pool
+                .expect("liquid_stake_pool is not set"),
+            vault: self.vault.expect("vault is not set"),
+            mint: self.mint.expect("mint is not set"),
+            receipt_mint: self.receipt_mint.expect("receipt_mint is not set"),
+            receipt_token_program: self
+                .receipt_token_program
+                .expect("receipt_token_program is not set"),
+            system_program: self
+                .system_program
+                .unwrap_or(solana_program::pubkey!("11111111111111111111111111111111")),
+        };
+
+        accounts.instruction_with_remaining_accounts(&self.__remaining_accounts)
+    }
+}
+
+/// `initialize_liquid_stake_pool` CPI accounts.
+pub struct InitializeLiquidStakePoolCpiAccounts<'a, 'b> {
+    /// Stake config account
+    pub config: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Pooled validator stake account
+    pub validator_stake: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Liquid stake pool account
+    pub liquid_stake_pool: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Vault token account
+    pub vault: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Stake token mint
+    pub mint: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Receipt token mint
+    pub receipt_mint: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Receipt token program
+    pub receipt_token_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// System program
+    pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+}
+
+/// `initialize_liquid_stake_pool` CPI instruction.
+pub struct InitializeLiquidStakePoolCpi<'a, 'b> {
+    /// The program to invoke.
+    pub __program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Stake config account
+    pub config: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Pooled validator stake account
+    pub validator_stake: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Liquid stake pool account
+    pub liquid_stake_pool: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Vault token account
+    pub vault: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Stake token mint
+    pub mint: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Receipt token mint
+    pub receipt_mint: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Receipt token program
+    pub receipt_token_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// System program
+    pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+}
+
+impl<'a, 'b> InitializeLiquidStakePoolCpi<'a, 'b> {
+    pub fn new(
+        program: &'b solana_program::account_info::AccountInfo<'a>,
+        accounts: InitializeLiquidStakePoolCpiAccounts<'a, 'b>,
+    ) -> Self {
+        Self {
+            __program: program,
+            config: accounts.config,
+            validator_stake: accounts.validator_stake,
+            liquid_stake_pool: accounts.liquid_stake_pool,
+            vault: accounts.vault,
+            mint: accounts.mint,
+            receipt_mint: accounts.receipt_mint,
+            receipt_token_program: accounts.receipt_token_program,
+            system_program: accounts.system_program,
+        }
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], &[])
+    }
+    #[inline(always)]
+    pub fn invoke_with_remaining_accounts(
+        &self,
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], remaining_accounts)
+    }
+    #[inline(always)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(signers_seeds, &[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed_with_remaining_accounts(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let mut accounts = Vec::with_capacity(8 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.config.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.validator_stake.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.liquid_stake_pool.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.vault.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.mint.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.receipt_mint.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.receipt_token_program.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.system_program.key,
+            false,
+        ));
+        remaining_accounts.iter().for_each(|remaining_account| {
+            accounts.push(solana_program::instruction::AccountMeta {
+                pubkey: *remaining_account.0.key,
+                is_signer: remaining_account.1,
+                is_writable: remaining_account.2,
+            })
+        });
+        let data = InitializeLiquidStakePoolInstructionData::new()
+            .try_to_vec()
+            .unwrap();
+
+        let instruction = solana_program::instruction::Instruction {
+            program_id: crate::PALADIN_STAKE_PROGRAM_ID,
+            accounts,
+            data,
+        };
+        let mut account_infos = Vec::with_capacity(8 + 1 + remaining_accounts.len());
+        account_infos.push(self.__program.clone());
+        account_infos.push(self.config.clone());
+        account_infos.push(self.validator_stake.clone());
+        account_infos.push(self.liquid_stake_pool.clone());
+        account_infos.push(self.vault.clone());
+        account_infos.push(self.mint.clone());
+        account_infos.push(self.receipt_mint.clone());
+        account_infos.push(self.receipt_token_program.clone());
+        account_infos.push(self.system_program.clone());
+        remaining_accounts
+            .iter()
+            .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
+
+        if signers_seeds.is_empty() {
+            solana_program::program::invoke(&instruction, &account_infos)
+        } else {
+            solana_program::program::invoke_signed(&instruction, &account_infos, signers_seeds)
+        }
+    }
+}
+
+/// Instruction builder for `InitializeLiquidStakePool` via CPI.
+///
+/// ### Accounts:
+///
+///   0. `[]` config
+///   1. `[]` validator_stake
+///   2. `[writable]` liquid_stake_pool
+///   3. `[]` vault
+///   4. `[]` mint
+///   5. `[]` receipt_mint
+///   6. `[]` receipt_token_program
+///   7. `[]` system_program
+#[derive(Clone, Debug)]
+pub struct InitializeLiquidStakePoolCpiBuilder<'a, 'b> {
+    instruction: Box<InitializeLiquidStakePoolCpiBuilderInstruction<'a, 'b>>,
+}
+
+impl<'a, 'b> InitializeLiquidStakePoolCpiBuilder<'a, 'b> {
+    pub fn new(program: &'b solana_program::account_info::AccountInfo<'a>) -> Self {
+        let instruction = Box::new(InitializeLiquidStakePoolCpiBuilderInstruction {
+            __program: program,
+            config: None,
+            validator_stake: None,
+            liquid_stake_pool: None,
+            vault: None,
+            mint: None,
+            receipt_mint: None,
+            receipt_token_program: None,
+            system_program: None,
+            __remaining_accounts: Vec::new(),
+        });
+        Self { instruction }
+    }
+    /// Stake config account
+    #[inline(always)]
+    pub fn config(
+        &mut self,
+        config: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.config = Some(config);
+        self
+    }
+    /// Pooled validator stake account
+    #[inline(always)]
+    pub fn validator_stake(
+        &mut self,
+        validator_stake: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.validator_stake = Some(validator_stake);
+        self
+    }
+    /// Liquid stake pool account
+    #[inline(always)]
+    pub fn liquid_stake_pool(
+        &mut self,
+        liquid_stake_pool: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.liquid_stake_pool = Some(liquid_stake_pool);
+        self
+    }
+    /// Vault token account
+    #[inline(always)]
+    pub fn vault(&mut self, vault: &'b solana_program::account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.vault = Some(vault);
+        self
+    }
+    /// Stake token mint
+    #[inline(always)]
+    pub fn mint(&mut self, mint: &'b solana_program::account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.mint = Some(mint);
+        self
+    }
+    /// Receipt token mint
+    #[inline(always)]
+    pub fn receipt_mint(
+        &mut self,
+        receipt_mint: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.receipt_mint = Some(receipt_mint);
+        self
+    }
+    /// Receipt token program
+    #[inline(always)]
+    pub fn receipt_token_program(
+        &mut self,
+        receipt_token_program: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.receipt_token_program = Some(receipt_token_program);
+        self
+    }
+    /// System program
+    #[inline(always)]
+    pub fn system_program(
+        &mut self,
+        system_program: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.system_program = Some(system_program);
+        self
+    }
+    /// Add an additional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: &'b solana_program::account_info::AccountInfo<'a>,
+        is_writable: bool,
+        is_signer: bool,
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .push((account, is_writable, is_signer));
+        self
+    }
+    /// Add additional accounts to the instruction.
+    ///
+    /// Each account is represented by a tuple of the `AccountInfo`, a `bool` indicating whether the account is writable or not,
+    /// and a `bool` indicating whether the account is a signer or not.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .extend_from_slice(accounts);
+        self
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed(&[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let instruction = InitializeLiquidStakePoolCpi {
+            __program: self.instruction.__program,
+
+            config: self.instruction.config.expect("config is not set"),
+
+            validator_stake: self
+                .instruction
+                .validator_stake
+                .expect("validator_stake is not set"),
+
+            liquid_stake_pool: self
+                .instruction
+                .liquid_stake_pool
+                .expect("liquid_stake_pool is not set"),
+
+            vault: self.instruction.vault.expect("vault is not set"),
+
+            mint: self.instruction.mint.expect("mint is not set"),
+
+            receipt_mint: self
+                .instruction
+                .receipt_mint
+                .expect("receipt_mint is not set"),
+
+            receipt_token_program: self
+                .instruction
+                .receipt_token_program
+                .expect("receipt_token_program is not set"),
+
+            system_program: self
+                .instruction
+                .system_program
+                .expect("system_program is not set"),
+        };
+        instruction.invoke_signed_with_remaining_accounts(
+            signers_seeds,
+            &self.instruction.__remaining_accounts,
+        )
+    }
+}
+
+#[derive(Clone, Debug)]
+struct InitializeLiquidStakePoolCpiBuilderInstruction<'a, 'b> {
+    __program: &'b solana_program::account_info::AccountInfo<'a>,
+    config: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    validator_stake: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    liquid_stake_pool: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    vault: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    mint: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    receipt_mint: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    receipt_token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
+    __remaining_accounts: Vec<(
+        &'b solana_program::account_info::AccountInfo<'a>,
+        bool,
+        bool,
+    )>,
+}
@@ -0,0 +1,1084 @@
+//! This code was AUTOGENERATED using the kinobi library.
+//! Please DO NOT EDIT THIS FILE, instead use visitors
+//! to add features, then rerun kinobi to update it.
+//!
+//! <https://github.com/kinobi-so/kinobi>
+//!
+
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+
+/// Accounts.
+pub struct LiquidUnstakeTokens {
+    /// Stake config account
+    pub config: solana_program::pubkey::Pubkey,
+    /// Holder rewards pool account
+    pub holder_rewards_pool: solana_program::pubkey::Pubkey,
+    /// Holder rewards pool account token account
+    pub holder_rewards_pool_token_account: solana_program::pubkey::Pubkey,
+    /// Pooled validator stake account
+    pub validator_stake: solana_program::pubkey::Pubkey,
+    /// Liquid stake pool account
+    pub liquid_stake_pool: solana_program::pubkey::Pubkey,
+    /// Vault account
+    pub vault: solana_program::pubkey::Pubkey,
+    /// Vault authority
+    pub vault_pda: solana_program::pubkey::Pubkey,
+    /// Vault holder rewards account
+    pub vault_holder_rewards: solana_program::pubkey::Pubkey,
+    /// Mint account
+    pub mint: solana_program::pubkey::Pubkey,
+    /// Destination token account
+    pub destination_token_account: solana_program::pubkey::Pubkey,
+    /// Token program
+    pub token_program: solana_program::pubkey::Pubkey,
+    /// Paladin rewards program
+    pub rewards_program: solana_program::pubkey::Pubkey,
+    /// Receipt token mint
+    pub receipt_mint: solana_program::pubkey::Pubkey,
+    /// Source receipt token account
+    pub source_receipt_account: solana_program::pubkey::Pubkey,
+    /// Receipt rewards account of the source
+    pub source_receipt_rewards: solana_program::pubkey::Pubkey,
+    /// Owner or delegate of the source receipt token account
+    pub source_receipt_authority: solana_program::pubkey::Pubkey,
+    /// Receipt token program
+    pub receipt_token_program: solana_program::pubkey::Pubkey,
+    /// Stake program
+    pub stake_program: solana_program::pubkey::Pubkey,
+}
+
+impl LiquidUnstakeTokens {
+    pub fn instruction(
+        &self,
+        args: LiquidUnstakeTokensInstructionArgs,
+    ) -> solana_program::instruction::Instruction {
+        self.instruction_with_remaining_accounts(args, &[])
+    }
+    #[allow(clippy::vec_init_then_push)]
+    pub fn instruction_with_remaining_accounts(
+        &self,
+        args: LiquidUnstakeTokensInstructionArgs,
+        remaining_accounts: &[solana_program::instruction::AccountMeta],
+    ) -> solana_program::instruction::Instruction {
+        let mut accounts = Vec::with_capacity(18 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.config,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.holder_rewards_pool,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.holder_rewards_pool_token_account,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.validator_stake,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.liquid_stake_pool,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.vault, false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.vault_pda,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.vault_holder_rewards,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.mint, false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.destination_token_account,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.token_program,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.rewards_program,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.receipt_mint,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.source_receipt_account,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.source_receipt_rewards,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.source_receipt_authority,
+            true,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.receipt_token_program,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.stake_program,
+            false,
+        ));
+        accounts.extend_from_slice(remaining_accounts);
+        let mut data = LiquidUnstakeTokensInstructionData::new()
+            .try_to_vec()
+            .unwrap();
+        let mut args = args.try_to_vec().unwrap();
+        data.append(&mut args);
+
+        solana_program::instruction::Instruction {
+            program_id: crate::PALADIN_STAKE_PROGRAM_ID,
+            accounts,
+            data,
+        }
+    }
+}
+
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct LiquidUnstakeTokensInstructionData {
+    discriminator: u8,
+}
+
+impl LiquidUnstakeTokensInstructionData {
+    pub fn new() -> Self {
+        Self { discriminator: 27 }
+    }
+}
+
+impl Default for LiquidUnstakeTokensInstructionData {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LiquidUnstakeTokensInstructionArgs {
+    pub amount: u64,
+}
+
+/// Instruction builder for `LiquidUnstakeTokens`.
+///
+/// ### Accounts:
+///
+///   0. `[writable]` config
+///   1. `[writable]` holder_rewards_pool
+///   2. `[writable]` holder_rewards_pool_token_account
+///   3. `[writable]` validator_stake
+///   4. `[writable]` liquid_stake_pool
+///   5. `[writable]` vault
+///   6. `[writable]` vault_pda
+///   7. `[writable]` vault_holder_rewards
+///   8. `[]` mint
+///   9. `[writable]` destination_token_account
+///   10. `[optional]` token_program (default to `TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA`)
+///   11. `[]` rewards_program
+///   12. `[writable]` receipt_mint
+///   13. `[writable]` source_receipt_account
+///   14. `[writable]` source_receipt_rewards
+///   15. `[signer]` source_receipt_authority
+///   16. `[]` receipt_token_program
+///   17. `[]` stake_program
+#[derive(Clone, Debug, Default)]
+pub struct LiquidUnstakeTokensBuilder {
+    config: Option<solana_program::pubkey::Pubkey>,
+    holder_rewards_pool: Option<solana_program::pubkey::Pubkey>,
+    holder_rewards_pool_token_account: Option<solana_program::pubkey::Pubkey>,
+    validator_stake: Option<solana_program::pubkey::Pubkey>,
+    liquid_stake_pool: Option<solana_program::pubkey::Pubkey>,
+    vault: Option<solana_program::pubkey::Pubkey>,
+    vault_pda: Option<solana_program::pubkey::Pubkey>,
+    vault_holder_rewards: Option<solana_program::pubkey::Pubkey>,
+    mint: Option<solana_program::pubkey::Pubkey>,
+    destination_token_account: Option<solana_program::pubkey::Pubkey>,
+    token_program: Option<solana_program::pubkey::Pubkey>,
+    rewards_program: Option<solana_program::pubkey::Pubkey>,
+    receipt_mint: Option<solana_program::pubkey::Pubkey>,
+    source_receipt_account: Option<solana_program::pubkey::Pubkey>,
+    source_receipt_rewards: Option<solana_program::pubkey::Pubkey>,
+    source_receipt_authority: Option<solana_program::pubkey::Pubkey>,
+    receipt_token_program: Option<solana_program::pubkey::Pubkey>,
+    stake_program: Option<solana_program::pubkey::Pubkey>,
+    amount: Option<u64>,
+    __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
+}
+
+impl LiquidUnstakeTokensBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Stake config account
+    #[inline(always)]
+    pub fn config(&mut self, config: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.config = Some(config);
+        self
+    }
+    /// Holder rewards pool account
+    #[inline(always)]
+    pub fn holder_rewards_pool(
+        &mut self,
+        holder_rewards_pool: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.holder_rewards_pool = Some(holder_rewards_pool);
+        self
+    }
+    /// Holder rewards pool account token account
+    #[inline(always)]
+    pub fn holder_rewards_pool_token_account(
+        &mut self,
+        holder_rewards_pool_token_account: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.holder_rewards_pool_token_account = Some(holder_rewards_pool_token_account);
+        self
+    }
+    /// Pooled validator stake account
+    #[inline(always)]
+    pub fn validator_stake(
+        &mut self,
+        validator_stake: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.validator_stake = Some(validator_stake);
+        self
+    }
+    /// Liquid stake pool account
+    #[inline(always)]
+    pub fn liquid_stake_pool(
+        &mut self,
+        liquid_stake_pool: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.liquid_stake_pool = Some(liquid_stake_pool);
+        self
+    }
+    /// Vault account
+    #[inline(always)]
+    pub fn vault(&mut self, vault: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.vault = Some(vault);
+        self
+    }
+    /// Vault authority
+    #[inline(always)]
+    pub fn vault_pda(&mut self, vault_pda: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.vault_pda = Some(vault_pda);
+        self
+    }
+    /// Vault holder rewards account
+    #[inline(always)]
+    pub fn vault_holder_rewards(
+        &mut self,
+        vault_holder_rewards: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.vault_holder_rewards = Some(vault_holder_rewards);
+        self
+    }
+    /// Mint account
+    #[inline(always)]
+    pub fn mint(&mut self, mint: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.mint = Some(mint);
+        self
+    }
+    /// Destination token account
+    #[inline(always)]
+    pub fn destination_token_account(
+        &mut self,
+        destination_token_account: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.destination_token_account = Some(destination_token_account);
+        self
+    }
+    /// `[optional account, default to 'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA']`
+    /// Token program
+    #[inline(always)]
+    pub fn token_program(&mut self, token_program: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.token_program = Some(token_program);
+        self
+    }
+    /// Paladin rewards program
+    #[inline(always)]
+    pub fn rewards_program(
+        &mut self,
+        rewards_program: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.rewards_program = Some(rewards_program);
+        self
+    }
+    /// Receipt token mint
+    #[inline(always)]
+    pub fn receipt_mint(&mut self, receipt_mint: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.receipt_mint = Some(receipt_mint);
+        self
+    }
+    /// Source receipt token account
+    #[inline(always)]
+    pub fn source_receipt_account(
+        &mut self,
+        source_receipt_account: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.source_receipt_account = Some(source_receipt_account);
+        self
+    }
+    /// Receipt rewards account of the source
+    #[inline(always)]
+    pub fn source_receipt_rewards(
+        &mut self,
+        source_receipt_rewards: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.source_receipt_rewards = Some(source_receipt_rewards);
+        self
+    }
+    /// Owner or delegate of the source receipt token account
+    #[inline(always)]
+    pub fn source_receipt_authority(
+        &mut self,
+        source_receipt_authority: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.source_receipt_authority = Some(source_receipt_authority);
+        self
+    }
+    /// Receipt token program
+    #[inline(always)]
+    pub fn receipt_token_program(
+        &mut self,
+        receipt_token_program: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.receipt_token_program = Some(receipt_token_program);
+        self
+    }
+    /// Stake program
+    #[inline(always)]
+    pub fn stake_program(&mut self, stake_program: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.stake_program = Some(stake_program);
+        self
+    }
+    #[inline(always)]
+    pub fn amount(&mut self, amount: u64) -> &mut Self {
+        self.amount = Some(amount);
+        self
+    }
+    /// Add an additional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: solana_program::instruction::AccountMeta,
+    ) -> &mut Self {
+        self.__remaining_accounts.push(account);
+        self
+    }
+    /// Add additional accounts to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[solana_program::instruction::AccountMeta],
+    ) -> &mut Self {
+        self.__remaining_accounts.extend_from_slice(accounts);
+        self
+    }
+    #[allow(clippy::clone_on_copy)]
+    pub fn instruction(&self) -> solana_program::instruction::Instruction {
+        let accounts = LiquidUnstakeTokens {
+            config: self.config.expect("config is not set"),
+            holder_rewards_pool: self
+                .holder_rewards_pool
+                .expect("holder_rewards_pool is not set"),
+            holder_rewards_pool_token_account: self
+                .holder_rewards_pool_token_account
+                .expect("holder_rewards_pool_token_account is not set"),
+            validator_stake: self.validator_stake.expect("validator_stake is not set"),
+            liquid_stake_pool: self
+                .liquid_stake_pool
+                .expect("liquid_stake_pool is not set"),
+            vault: self.vault.expect("vault is not set"),
+            vault_pda: self.vault_pda.expect("vault_pda is not set"),
+            vault_holder_rewards: self
+                .vault_holder_rewards
+                .expect("vault_holder_rewards is not set"),
+            mint: self.mint.expect("mint is not set"),
+            destination_token_account: self
+                .destination_token_account
+                .expect("destination_token_account is not set"),
+            token_program: self.token_program.unwrap_or(solana_program::pubkey!(
+                "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+            )),
+            rewards_program: self.rewards_program.expect("rewards_program is not set"),
+            receipt_mint: self.receipt_mint.expect("receipt_mint is not set"),
+            source_receipt_account: self
+                .source_receipt_account
+                .expect("source_receipt_account is not set"),
+            source_receipt_rewards: self
+                .source_receipt_rewards
+                .expect("source_receipt_rewards is not set"),
+            source_receipt_authority: self
+                .source_receipt_authority
+                .expect("source_receipt_authority is not set"),
+            receipt_token_program: self
+                .receipt_token_program
+                .expect("receipt_token_program is not set"),
+            stake_program: self.stake_program.expect("stake_program is not set"),
+        };
+        let args = LiquidUnstakeTokensInstructionArgs {
+            amount: self.amount.clone().expect("amount is not set"),
+        };
+
+        accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
+    }
+}
+
+/// `liquid_unstake_tokens` CPI accounts.
+pub struct LiquidUnstakeTokensCpiAccounts<'a, 'b> {
+    /// Stake config account
+    pub config: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Holder rewards pool account
+    pub holder_rewards_pool: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Holder rewards pool account token account
+    pub holder_rewards_pool_token_account: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Pooled validator stake account
+    pub validator_stake: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Liquid stake pool account
+    pub liquid_stake_pool: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Vault account
+    pub vault: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Vault authority
+    pub vault_pda: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Vault holder rewards account
+    pub vault_holder_rewards: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Mint account
+    pub mint: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Destination token account
+    pub destination_token_account: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Token program
+    pub token_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Paladin rewards program
+    pub rewards_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Receipt token mint
+    pub receipt_mint: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Source receipt token account
+    pub source_receipt_account: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Receipt rewards account of the source
+    pub source_receipt_rewards: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Owner or delegate of the source receipt token account
+    pub source_receipt_authority: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Receipt token program
+    pub receipt_token_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Stake program
+    pub stake_program: &'b solana_program::account_info::AccountInfo<'a>,
+}
+
+/// `liquid_unstake_tokens` CPI instruction.
+pub struct LiquidUnstakeTokensCpi<'a, 'b> {
+    /// The program to invoke.
+    pub __program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Stake config account
+    pub config: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Holder rewards pool account
+    pub holder_rewards_pool: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Holder rewards pool account token account
+    pub holder_rewards_pool_token_account: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Pooled validator stake account
+    pub validator_stake: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Liquid stake pool account
+    pub liquid_stake_pool: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Vault account
+    pub vault: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Vault authority
+    pub vault_pda: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Vault holder rewards account
+    pub vault_holder_rewards: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Mint account
+    pub mint: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Destination token account
+    pub destination_token_account: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Token program
+    pub token_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Paladin rewards program
+    pub rewards_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Receipt token mint
+    pub receipt_mint: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Source receipt token account
+    pub source_receipt_account: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Receipt rewards account of the source
+    pub source_receipt_rewards: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Owner or delegate of the source receipt token account
+    pub source_receipt_authority: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Receipt token program
+    pub receipt_token_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Stake program
+    pub stake_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The arguments for the instruction.
+    pub __args: LiquidUnstakeTokensInstructionArgs,
+}
+
+impl<'a, 'b> LiquidUnstakeTokensCpi<'a, 'b> {
+    pub fn new(
+        program: &'b solana_program::account_info::AccountInfo<'a>,
+        accounts: LiquidUnstakeTokensCpiAccounts<'a, 'b>,
+        args: LiquidUnstakeTokensInstructionArgs,
+    ) -> Self {
+        Self {
+            __program: program,
+            config: accounts.config,
+            holder_rewards_pool: accounts.holder_rewards_pool,
+            holder_rewards_pool_token_account: accounts.holder_rewards_pool_token_account,
+            validator_stake: accounts.validator_stake,
+            liquid_stake_pool: accounts.liquid_stake_pool,
+            vault: accounts.vault,
+            vault_pda: accounts.vault_pda,
+            vault_holder_rewards: accounts.vault_holder_rewards,
+            mint: accounts.mint,
+            destination_token_account: accounts.destination_token_account,
+            token_program: accounts.token_program,
+            rewards_program: accounts.rewards_program,
+            receipt_mint: accounts.receipt_mint,
+            source_receipt_account: accounts.source_receipt_account,
+            source_receipt_rewards: accounts.source_receipt_rewards,
+            source_receipt_authority: accounts.source_receipt_authority,
+            receipt_token_program: accounts.receipt_token_program,
+            stake_program: accounts.stake_program,
+            __args: args,
+        }
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], &[])
+    }
+    #[inline(always)]
+    pub fn invoke_with_remaining_accounts(
+        &self,
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], remaining_accounts)
+    }
+    #[inline(always)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(signers_seeds, &[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed_with_remaining_accounts(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let mut accounts = Vec::with_capacity(18 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.config.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.holder_rewards_pool.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.holder_rewards_pool_token_account.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.validator_stake.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.liquid_stake_pool.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.vault.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.vault_pda.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.vault_holder_rewards.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.mint.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.destination_token_account.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.token_program.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.rewards_program.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.receipt_mint.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.source_receipt_account.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.source_receipt_rewards.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.source_receipt_authority.key,
+            true,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.receipt_token_program.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.stake_program.key,
+            false,
+        ));
+        remaining_accounts.iter().for_each(|remaining_account| {
+            accounts.push(solana_program::instruction::AccountMeta {
+                pubkey: *remaining_account.0.key,
+                is_signer: remaining_account.1,
+                is_writable: remaining_account.2,
+            })
+        });
+        let mut data = LiquidUnstakeTokensInstructionData::new()
+            .try_to_vec()
+            .unwrap();
+        let mut args = self.__args.try_to_vec().unwrap();
+        data.append(&mut args);
+
+        let instruction = solana_program::instruction::Instruction {
+            program_id: crate::PALADIN_STAKE_PROGRAM_ID,
+            accounts,
+            data,
+        };
+        let mut account_infos = Vec::with_capacity(18 + 1 + remaining_accounts.len());
+        account_infos.push(self.__program.clone());
+        account_infos.push(self.config.clone());
+        account_infos.push(self.holder_rewards_pool.clone());
+        account_infos.push(self.holder_rewards_pool_token_account.clone());
+        account_infos.push(self.validator_stake.clone());
+        account_infos.push(self.liquid_stake_pool.clone());
+        account_infos.push(self.vault.clone());
+        account_infos.push(self.vault_pda.clone());
+        account_infos.push(self.vault_holder_rewards.clone());
+        account_infos.push(self.mint.clone());
+        account_infos.push(self.destination_token_account.clone());
+        account_infos.push(self.token_program.clone());
+        account_infos.push(self.rewards_program.clone());
+        account_infos.push(self.receipt_mint.clone());
+        account_infos.push(self.source_receipt_account.clone());
+        account_infos.push(self.source_receipt_rewards.clone());
+        account_infos.push(self.source_receipt_authority.clone());
+        account_infos.push(self.receipt_token_program.clone());
+        account_infos.push(self.stake_program.clone());
+        remaining_accounts
+            .iter()
+            .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
+
+        if signers_seeds.is_empty() {
+            solana_program::program::invoke(&instruction, &account_infos)
+        } else {
+            solana_program::program::invoke_signed(&instruction, &account_infos, signers_seeds)
+        }
+    }
+}
+
+/// Instruction builder for `LiquidUnstakeTokens` via CPI.
+///
+/// ### Accounts:
+///
+///   0. `[writable]` config
+///   1. `[writable]` holder_rewards_pool
+///   2. `[writable]` holder_rewards_pool_token_account
+///   3. `[writable]` validator_stake
+///   4. `[writable]` liquid_stake_pool
+///   5. `[writable]` vault
+///   6. `[writable]` vault_pda
+///   7. `[writable]` vault_holder_rewards
+///   8. `[]` mint
+///   9. `[writable]` destination_token_account
+///   10. `[]` token_program
+///   11. `[]` rewards_program
+///   12. `[writable]` receipt_mint
+///   13. `[writable]` source_receipt_account
+///   14. `[writable]` source_receipt_rewards
+///   15. `[signer]` source_receipt_authority
+///   16. `[]` receipt_token_program
+///   17. `[]` stake_program
+#[derive(Clone, Debug)]
+pub struct LiquidUnstakeTokensCpiBuilder<'a, 'b> {
+    instruction: Box<LiquidUnstakeTokensCpiBuilderInstruction<'a, 'b>>,
+}
+
+impl<'a, 'b> LiquidUnstakeTokensCpiBuilder<'a, 'b> {
+    pub fn new(program: &'b solana_program::account_info::AccountInfo<'a>) -> Self {
+        let instruction = Box::new(LiquidUnstakeTokensCpiBuilderInstruction {
+            __program: program,
+            config: None,
+            holder_rewards_pool: None,
+            holder_rewards_pool_token_account: None,
+            validator_stake: None,
+            liquid_stake_pool: None,
+            vault: None,
+            vault_pda: None,
+            vault_holder_rewards: None,
+            mint: None,
+            destination_token_account: None,
+            token_program: None,
+            rewards_program: None,
+            receipt_mint: None,
+            source_receipt_account: None,
+            source_receipt_rewards: None,
+            source_receipt_authority: None,
+            receipt_token_program: None,
+            stake_program: None,
+            amount: None,
+            __remaining_accounts: Vec::new(),
+        });
+        Self { instruction }
+    }
+    /// Stake config account
+    #[inline(always)]
+    pub fn config(
+        &mut self,
+        config: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.config = Some(config);
+        self
+    }
+    /// Holder rewards pool account
+    #[inline(always)]
+    pub fn holder_rewards_pool(
+        &mut self,
+        holder_rewards_pool: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.holder_rewards_pool = Some(holder_rewards_pool);
+        self
+    }
+    /// Holder rewards pool account token account
+    #[inline(always)]
+    pub fn holder_rewards_pool_token_account(
+        &mut self,
+        holder_rewards_pool_token_account: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.holder_rewards_pool_token_account =
+            Some(holder_rewards_pool_token_account);
+        self
+    }
+    /// Pooled validator stake account
+    #[inline(always)]
+    pub fn validator_stake(
+        &mut self,
+        validator_stake: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.validator_stake = Some(validator_stake);
+        self
+    }
+    /// Liquid stake pool account
+    #[inline(always)]
+    pub fn liquid_stake_pool(
+        &mut self,
+        liquid_stake_pool: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.liquid_stake_pool = Some(liquid_stake_pool);
+        self
+    }
+    /// Vault account
+    #[inline(always)]
+    pub fn vault(&mut self, vault: &'b solana_program::account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.vault = Some(vault);
+        self
+    }
+    /// Vault authority
+    #[inline(always)]
+    pub fn vault_pda(
+        &mut self,
+        vault_pda: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.vault_pda = Some(vault_pda);
+        self
+    }
+    /// Vault holder rewards account
+    #[inline(always)]
+    pub fn vault_holder_rewards(
+        &mut self,
+        vault_holder_rewards: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.vault_holder_rewards = Some(vault_holder_rewards);
+        self
+    }
+    /// Mint account
+    #[inline(always)]
+    pub fn mint(&mut self, mint: &'b solana_program::account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.mint = Some(mint);
+        self
+    }
+    /// Destination token account
+    #[inline(always)]
+    pub fn destination_token_account(
+        &mut self,
+        destination_token_account: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.destination_token_account = Some(destination_token_account);
+        self
+    }
+    /// Token program
+    #[inline(always)]
+    pub fn token_program(
+        &mut self,
+        token_program: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.token_program = Some(token_program);
+        self
+    }
+    /// Paladin rewards program
+    #[inline(always)]
+    pub fn rewards_program(
+        &mut self,
+        rewards_program: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.rewards_program = Some(rewards_program);
+        self
+    }
+    /// Receipt token mint
+    #[inline(always)]
+    pub fn receipt_mint(
+        &mut self,
+        receipt_mint: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.receipt_mint = Some(receipt_mint);
+        self
+    }
+    /// Source receipt token account
+    #[inline(always)]
+    pub fn source_receipt_account(
+        &mut self,
+        source_receipt_account: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.source_receipt_account = Some(source_receipt_account);
+        self
+    }
+    /// Receipt rewards account of the source
+    #[inline(always)]
+    pub fn source_receipt_rewards(
+        &mut self,
+        source_receipt_rewards: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.source_receipt_rewards = Some(source_receipt_rewards);
+        self
+    }
+    /// Owner or delegate of the source receipt token account
+    #[inline(always)]
+    pub fn source_receipt_authority(
+        &mut self,
+        source_receipt_authority: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.source_receipt_authority = Some(source_receipt_authority);
+        self
+    }
+    /// Receipt token program
+    #[inline(always)]
+    pub fn receipt_token_program(
+        &mut self,
+        receipt_token_program: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.receipt_token_program = Some(receipt_token_program);
+        self
+    }
+    /// Stake program
+    #[inline(always)]
+    pub fn stake_program(
+        &mut self,
+        stake_program: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.stake_program = Some(stake_program);
+        self
+    }
+    #[inline(always)]
+    pub fn amount(&mut self, amount: u64) -> &mut Self {
+        self.instruction.amount = Some(amount);
+        self
+    }
+    /// Add an additional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: &'b solana_program::account_info::AccountInfo<'a>,
+        is_writable: bool,
+        is_signer: bool,
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .push((account, is_writable, is_signer));
+        self
+    }
+    /// Add additional accounts to the instruction.
+    ///
+    /// Each account is represented by a tuple of the `AccountInfo`, a `bool` indicating whether the account is writable or not,
+    /// and a `bool` indicating whether the account is a signer or not.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .extend_from_slice(accounts);
+        self
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed(&[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let args = LiquidUnstakeTokensInstructionArgs {
+            amount: self.instruction.amount.clone().expect("amount is not set"),
+        };
+        let instruction = LiquidUnstakeTokensCpi {
+            __program: self.instruction.__program,
+
+            config: self.instruction.config.expect("config is not set"),
+
+            holder_rewards_pool: self
+                .instruction
+                .holder_rewards_pool
+                .expect("holder_rewards_pool is not set"),
+
+            holder_rewards_pool_token_account: self
+                .instruction
+                .holder_rewards_pool_token_account
+                .expect("holder_rewards_pool_token_account is not set"),
+
+            validator_stake: self
+                .instruction
+                .validator_stake
+                .expect("validator_stake is not set"),
+
+            liquid_stake_pool: self
+                .instruction
+                .liquid_stake_pool
+                .expect("liquid_stake_pool is not set"),
+
+            vault: self.instruction.vault.expect("vault is not set"),
+
+            vault_pda: self.instruction.vault_pda.expect("vault_pda is not set"),
+
+            vault_holder_rewards: self
+                .instruction
+                .vault_holder_rewards
+                .expect("vault_holder_rewards is not set"),
+
+            mint: self.instruction.mint.expect("mint is not set"),
+
+            destination_token_account: self
+                .instruction
+                .destination_token_account
+                .expect("destination_token_account is not set"),
+
+            token_program: self
+                .instruction
+                .token_program
+                .expect("token_program is not set"),
+
+            rewards_program: self
+                .instruction
+                .rewards_program
+                .expect("rewards_program is not set"),
+
+            receipt_mint: self
+                .instruction
+                .receipt_mint
+                .expect("receipt_mint is not set"),
+
+            source_receipt_account: self
+                .instruction
+                .source_receipt_account
+                .expect("source_receipt_account is not set"),
+
+            source_receipt_rewards: self
+                .instruction
+                .source_receipt_rewards
+                .expect("source_receipt_rewards is not set"),
+
+            source_receipt_authority: self
+                .instruction
+                .source_receipt_authority
+                .expect("source_receipt_authority is not set"),
+
+            receipt_token_program: self
+                .instruction
+                .receipt_token_program
+                .expect("receipt_token_program is not set"),
+
+            stake_program: self
+                .instruction
+                .stake_program
+                .expect("stake_program is not set"),
+            __args: args,
+        };
+        instruction.invoke_signed_with_remaining_accounts(
+            signers_seeds,
+            &self.instruction.__remaining_accounts,
+        )
+    }
+}
+
+#[derive(Clone, Debug)]
+struct LiquidUnstakeTokensCpiBuilderInstruction<'a, 'b> {
+    __program: &'b solana_program::account_info::AccountInfo<'a>,
+    config: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    holder_rewards_pool: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    holder_rewards_pool_token_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    validator_stake: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    liquid_stake_pool: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    vault: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    vault_pda: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    vault_holder_rewards: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    mint: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    destination_token_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    rewards_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    receipt_mint: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    source_receipt_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    source_receipt_rewards: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    source_receipt_authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    receipt_token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    stake_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    amount: Option<u64>,
+    /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
+    __remaining_accounts: Vec<(
+        &'b solana_program::account_info::AccountInfo<'a>,
+        bool,
+        bool,
+    )>,
+}
@@ -5,36 +5,68 @@
 //! <https://github.com/kinobi-so/kinobi>
 //!
 
+pub(crate) mod r#audit_invariants;
+pub(crate) mod r#get_pending_rewards;
+pub(crate) mod r#get_voting_power;
 pub(crate) mod r#harvest_holder_rewards;
+pub(crate) mod r#harvest_receipt_rewards;
 pub(crate) mod r#harvest_sol_staker_rewards;
 pub(crate) mod r#harvest_validator_rewards;
 pub(crate) mod r#initialize_config;
+pub(crate) mod r#initialize_config_pda;
+pub(crate) mod r#initialize_delegated_voting_power;
+pub(crate) mod r#initialize_liquid_stake_pool;
+pub(crate) mod r#initialize_receipt_rewards;
 pub(crate) mod r#initialize_sol_staker_stake;
 pub(crate) mod r#initialize_validator_stake;
+pub(crate) mod r#liquid_stake_tokens;
+pub(crate) mod r#liquid_unstake_tokens;
+pub(crate) mod r#migrate_account;
 pub(crate) mod r#set_authority;
+pub(crate) mod r#set_validator_authority_override;
+pub(crate) mod r#set_vote_delegate;
 pub(crate) mod r#slash_sol_staker_stake;
 pub(crate) mod r#slash_validator_stake;
 pub(crate) mod r#sol_staker_move_tokens;
 pub(crate) mod r#sol_staker_stake_tokens;
+pub(crate) mod r#sweep_undistributed_rewards;
+pub(crate) mod r#transfer_stake;
 pub(crate) mod r#unstake_tokens;
 pub(crate) mod r#update_config;
 pub(crate) mod r#validator_override_staked_lamports;
 pub(crate) mod r#validator_stake_tokens;
 pub(crate) mod r#validator_sync_authority;
+pub(crate) mod r#withdraw_protocol_fees;
 
+pub use self::r#audit_invariants::*;
+pub use self::r#get_pending_rewards::*;
+pub use self::r#get_voting_power::*;
 pub use self::r#harvest_holder_rewards::*;
+pub use self::r#harvest_receipt_rewards::*;
 pub use self::r#harvest_sol_staker_rewards::*;
 pub use self::r#harvest_validator_rewards::*;
 pub use self::r#initialize_config::*;
+pub use self::r#initialize_config_pda::*;
+pub use self::r#initialize_delegated_voting_power::*;
+pub use self::r#initialize_liquid_stake_pool::*;
+pub use self::r#initialize_receipt_rewards::*;
 pub use self::r#initialize_sol_staker_stake::*;
 pub use self::r#initialize_validator_stake::*;
+pub use self::r#liquid_stake_tokens::*;
+pub use self::r#liquid_unstake_tokens::*;
+pub use self::r#migrate_account::*;
 pub use self::r#set_authority::*;
+pub use self::r#set_validator_authority_override::*;
+pub use self::r#set_vote_delegate::*;
 pub use self::r#slash_sol_staker_stake::*;
 pub use self::r#slash_validator_stake::*;
 pub use self::r#sol_staker_move_tokens::*;
 pub use self::r#sol_staker_stake_tokens::*;
+pub use self::r#sweep_undistributed_rewards::*;
+pub use self::r#transfer_stake::*;
 pub use self::r#unstake_tokens::*;
 pub use self::r#update_config::*;
 pub use self::r#validator_override_staked_lamports::*;
 pub use self::r#validator_stake_tokens::*;
 pub use self::r#validator_sync_authority::*;
+pub use self::r#withdraw_protocol_fees::*;
@@ -0,0 +1,517 @@
+//! This code was AUTOGENERATED using the kinobi library.
+//! Please DO NOT EDIT THIS FILE, instead use visitors
+//! to add features, then rerun kinobi to update it.
+//!
+//! <https://github.com/kinobi-so/kinobi>
+//!
+
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+
+/// Accounts.
+pub struct SetValidatorAuthorityOverride {
+    /// Stake config account
+    pub config: solana_program::pubkey::Pubkey,
+    /// Validator vote account
+    pub validator_vote: solana_program::pubkey::Pubkey,
+    /// Validator vote account withdraw authority
+    pub withdraw_authority: solana_program::pubkey::Pubkey,
+    /// Validator authority override
+    pub validator_authority_override: solana_program::pubkey::Pubkey,
+    /// Authority to use for the validator stake accounts
+    pub new_authority: solana_program::pubkey::Pubkey,
+    /// System program
+    pub system_program: solana_program::pubkey::Pubkey,
+}
+
+impl SetValidatorAuthorityOverride {
+    pub fn instruction(&self) -> solana_program::instruction::Instruction {
+        self.instruction_with_remaining_accounts(&[])
+    }
+    #[allow(clippy::vec_init_then_push)]
+    pub fn instruction_with_remaining_accounts(
+        &self,
+        remaining_accounts: &[solana_program::instruction::AccountMeta],
+    ) -> solana_program::instruction::Instruction {
+        let mut accounts = Vec::with_capacity(6 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.config,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.validator_vote,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.withdraw_authority,
+            true,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.validator_authority_override,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.new_authority,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.system_program,
+            false,
+        ));
+        accounts.extend_from_slice(remaining_accounts);
+        let data = SetValidatorAuthorityOverrideInstructionData::new()
+            .try_to_vec()
+            .unwrap();
+
+        solana_program::instruction::Instruction {
+            program_id: crate::PALADIN_STAKE_PROGRAM_ID,
+            accounts,
+            data,
+        }
+    }
+}
+
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct SetValidatorAuthorityOverrideInstructionData {
+    discriminator: u8,
+}
+
+impl SetValidatorAuthorityOverrideInstructionData {
+    pub fn new() -> Self {
+        Self { discriminator: 22 }
+    }
+}
+
+impl Default for SetValidatorAuthorityOverrideInstructionData {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Instruction builder for `SetValidatorAuthorityOverride`.
+///
+/// ### Accounts:
+///
+///   0. `[]` config
+///   1. `[]` validator_vote
+///   2. `[signer]` withdraw_authority
+///   3. `[writable]` validator_authority_override
+///   4. `[]` new_authority
+///   5. `[optional]` system_program (default to `11111111111111111111111111111111`)
+#[derive(Clone, Debug, Default)]
+pub struct SetValidatorAuthorityOverrideBuilder {
+    config: Option<solana_program::pubkey::Pubkey>,
+    validator_vote: Option<solana_program::pubkey::Pubkey>,
+    withdraw_authority: Option<solana_program::pubkey::Pubkey>,
+    validator_authority_override: Option<solana_program::pubkey::Pubkey>,
+    new_authority: Option<solana_program::pubkey::Pubkey>,
+    system_program: Option<solana_program::pubkey::Pubkey>,
+    __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
+}
+
+impl SetValidatorAuthorityOverrideBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Stake config account
+    #[inline(always)]
+    pub fn config(&mut self, config: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.config = Some(config);
+        self
+    }
+    /// Validator vote account
+    #[inline(always)]
+    pub fn validator_vote(&mut self, validator_vote: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.validator_vote = Some(validator_vote);
+        self
+    }
+    /// Validator vote account withdraw authority
+    #[inline(always)]
+    pub fn withdraw_authority(
+        &mut self,
+        withdraw_authority: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.withdraw_authority = Some(withdraw_authority);
+        self
+    }
+    /// Validator authority override
+    #[inline(always)]
+    pub fn validator_authority_override(
+        &mut self,
+        validator_authority_override: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.validator_authority_override = Some(validator_authority_override);
+        self
+    }
+    /// Authority to use for the validator stake accounts
+    #[inline(always)]
+    pub fn new_authority(&mut self, new_authority: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.new_authority = Some(new_authority);
+        self
+    }
+    /// `[optional account, default to '11111111111111111111111111111111']`
+    /// System program
+    #[inline(always)]
+    pub fn system_program(&mut self, system_program: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.system_program = Some(system_program);
+        self
+    }
+    /// Add an additional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: solana_program::instruction::AccountMeta,
+    ) -> &mut Self {
+        self.__remaining_accounts.push(account);
+        self
+    }
+    /// Add additional accounts to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[solana_program::instruction::AccountMeta],
+    ) -> &mut Self {
+        self.__remaining_accounts.extend_from_slice(accounts);
+        self
+    }
+    #[allow(clippy::clone_on_copy)]
+    pub fn instruction(&self) -> solana_program::instruction::Instruction {
+        let accounts = SetValidatorAuthorityOverride {
+            config: self.config.expect("config is not set"),
+            validator_vote: self.validator_vote.expect("validator_vote is not set"),
+            withdraw_authority: self
+                .withdraw_authority
+                .expect("withdraw_authority is not set"),
+            validator_authority_override: self
+                .validator_authority_override
+                .expect("validator_authority_override is not set"),
+            new_authority: self.new_authority.expect("new_authority is not set"),
+            system_program: self
+                .system_program
+                .unwrap_or(solana_program::pubkey!("11111111111111111111111111111111")),
+        };
+
+        accounts.instruction_with_remaining_accounts(&self.__remaining_accounts)
+    }
+}
+
+/// `set_validator_authority_override` CPI accounts.
+pub struct SetValidatorAuthorityOverrideCpiAccounts<'a, 'b> {
+    /// Stake config account
+    pub config: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Validator vote account
+    pub validator_vote: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Validator vote account withdraw authority
+    pub withdraw_authority: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Validator authority override
+    pub validator_authority_override: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Authority to use for the validator stake accounts
+    pub new_authority: &'b solana_program::account_info::AccountInfo<'a>,
+    /// System program
+    pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+}
+
+/// `set_validator_authority_override` CPI instruction.
+pub struct SetValidatorAuthorityOverrideCpi<'a, 'b> {
+    /// The program to invoke.
+    pub __program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Stake config account
+    pub config: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Validator vote account
+    pub validator_vote: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Validator vote account withdraw authority
+    pub withdraw_authority: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Validator authority override
+    pub validator_authority_override: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Authority to use for the validator stake accounts
+    pub new_authority: &'b solana_program::account_info::AccountInfo<'a>,
+    /// System program
+    pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+}
+
+impl<'a, 'b> SetValidatorAuthorityOverrideCpi<'a, 'b> {
+    pub fn new(
+        program: &'b solana_program::account_info::AccountInfo<'a>,
+        accounts: SetValidatorAuthorityOverrideCpiAccounts<'a, 'b>,
+    ) -> Self {
+        Self {
+            __program: program,
+            config: accounts.config,
+            validator_vote: accounts.validator_vote,
+            withdraw_authority: accounts.withdraw_authority,
+            validator_authority_override: accounts.validator_authority_override,
+            new_authority: accounts.new_authority,
+            system_program: accounts.system_program,
+        }
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], &[])
+    }
+    #[inline(always)]
+    pub fn invoke_with_remaining_accounts(
+        &self,
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], remaining_accounts)
+    }
+    #[inline(always)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(signers_seeds, &[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed_with_remaining_accounts(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let mut accounts = Vec::with_capacity(6 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.config.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.validator_vote.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.withdraw_authority.key,
+            true,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.validator_authority_override.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.new_authority.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.system_program.key,
+            false,
+        ));
+        remaining_accounts.iter().for_each(|remaining_account| {
+            accounts.push(solana_program::instruction::AccountMeta {
+                pubkey: *remaining_account.0.key,
+                is_signer: remaining_account.1,
+                is_writable: remaining_account.2,
+            })
+        });
+        let data = SetValidatorAuthorityOverrideInstructionData::new()
+            .try_to_vec()
+            .unwrap();
+
+        let instruction = solana_program::instruction::Instruction {
+            program_id: crate::PALADIN_STAKE_PROGRAM_ID,
+            accounts,
+            data,
+        };
+        let mut account_infos = Vec::with_capacity(6 + 1 + remaining_accounts.len());
+        account_infos.push(self.__program.clone());
+        account_infos.push(self.config.clone());
+        account_infos.push(self.validator_vote.clone());
+        account_infos.push(self.withdraw_authority.clone());
+        account_infos.push(self.validator_authority_override.clone());
+        account_infos.push(self.new_authority.clone());
+        account_infos.push(self.system_program.clone());
+        remaining_accounts
+            .iter()
+            .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
+
+        if signers_seeds.is_empty() {
+            solana_program::program::invoke(&instruction, &account_infos)
+        } else {
+            solana_program::program::invoke_signed(&instruction, &account_infos, signers_seeds)
+        }
+    }
+}
+
+/// Instruction builder for `SetValidatorAuthorityOverride` via CPI.
+///
+/// ### Accounts:
+///
+///   0. `[]` config
+///   1. `[]` validator_vote
+///   2. `[signer]` withdraw_authority
+///   3. `[writable]` validator_authority_override
+///   4. `[]` new_authority
+///   5. `[]` system_program
+#[derive(Clone, Debug)]
+pub struct SetValidatorAuthorityOverrideCpiBuilder<'a, 'b> {
+    instruction: Box<SetValidatorAuthorityOverrideCpiBuilderInstruction<'a, 'b>>,
+}
+
+impl<'a, 'b> SetValidatorAuthorityOverrideCpiBuilder<'a, 'b> {
+    pub fn new(program: &'b solana_program::account_info::AccountInfo<'a>) -> Self {
+        let instruction = Box::new(SetValidatorAuthorityOverrideCpiBuilderInstruction {
+            __program: program,
+            config: None,
+            validator_vote: None,
+            withdraw_authority: None,
+            validator_authority_override: None,
+            new_authority: None,
+            system_program: None,
+            __remaining_accounts: Vec::new(),
+        });
+        Self { instruction }
+    }
+    /// Stake config account
+    #[inline(always)]
+    pub fn config(
+        &mut self,
+        config: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.config = Some(config);
+        self
+    }
+    /// Validator vote account
+    #[inline(always)]
+    pub fn validator_vote(
+        &mut self,
+        validator_vote: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.validator_vote = Some(validator_vote);
+        self
+    }
+    /// Validator vote account withdraw authority
+    #[inline(always)]
+    pub fn withdraw_authority(
+        &mut self,
+        withdraw_authority: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.withdraw_authority = Some(withdraw_authority);
+        self
+    }
+    /// Validator authority override
+    #[inline(always)]
+    pub fn validator_authority_override(
+        &mut self,
+        validator_authority_override: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.validator_authority_override = Some(validator_authority_override);
+        self
+    }
+    /// Authority to use for the validator stake accounts
+    #[inline(always)]
+    pub fn new_authority(
+        &mut self,
+        new_authority: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.new_authority = Some(new_authority);
+        self
+    }
+    /// System program
+    #[inline(always)]
+    pub fn system_program(
+        &mut self,
+        system_program: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.system_program = Some(system_program);
+        self
+    }
+    /// Add an additional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: &'b solana_program::account_info::AccountInfo<'a>,
+        is_writable: bool,
+        is_signer: bool,
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .push((account, is_writable, is_signer));
+        self
+    }
+    /// Add additional accounts to the instruction.
+    ///
+    /// Each account is represented by a tuple of the `AccountInfo`, a `bool` indicating whether the account is writable or not,
+    /// and a `bool` indicating whether the account is a signer or not.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .extend_from_slice(accounts);
+        self
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed(&[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let instruction = SetValidatorAuthorityOverrideCpi {
+            __program: self.instruction.__program,
+
+            config: self.instruction.config.expect("config is not set"),
+
+            validator_vote: self
+                .instruction
+                .validator_vote
+                .expect("validator_vote is not set"),
+
+            withdraw_authority: self
+                .instruction
+                .withdraw_authority
+                .expect("withdraw_authority is not set"),
+
+            validator_authority_override: self
+                .instruction
+                .validator_authority_override
+                .expect("validator_authority_override is not set"),
+
+            new_authority: self
+                .instruction
+                .new_authority
+                .expect("new_authority is not set"),
+
+            system_program: self
+                .instruction
+                .system_program
+                .expect("system_program is not set"),
+        };
+        instruction.invoke_signed_with_remaining_accounts(
+            signers_seeds,
+            &self.instruction.__remaining_accounts,
+        )
+    }
+}
+
+#[derive(Clone, Debug)]
+struct SetValidatorAuthorityOverrideCpiBuilderInstruction<'a, 'b> {
+    __program: &'b solana_program::account_info::AccountInfo<'a>,
+    config: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    validator_vote: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    withdraw_authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    validator_authority_override: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    new_authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
+    __remaining_accounts: Vec<(
+        &'b solana_program::account_info::AccountInfo<'a>,
+        bool,
+        bool,
+    )>,
+}
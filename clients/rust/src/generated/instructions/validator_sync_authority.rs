@@ -16,6 +16,8 @@ pub struct ValidatorSyncAuthority {
     pub validator_stake: solana_program::pubkey::Pubkey,
     /// Validator vote
     pub validator_vote: solana_program::pubkey::Pubkey,
+    /// Validator authority override
+    pub validator_authority_override: solana_program::pubkey::Pubkey,
 }
 
 impl ValidatorSyncAuthority {
@@ -27,7 +29,7 @@ impl ValidatorSyncAuthority {
         &self,
         remaining_accounts: &[solana_program::instruction::AccountMeta],
     ) -> solana_program::instruction::Instruction {
-        let mut accounts = Vec::with_capacity(3 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(4 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             self.config,
             false,
@@ -40,6 +42,10 @@ impl ValidatorSyncAuthority {
             self.validator_vote,
             false,
         ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.validator_authority_override,
+            false,
+        ));
         accounts.extend_from_slice(remaining_accounts);
         let data = ValidatorSyncAuthorityInstructionData::new()
             .try_to_vec()
@@ -77,11 +83,13 @@ impl Default for ValidatorSyncAuthorityInstructionData {
 ///   0. `[writable]` config
 ///   1. `[writable]` validator_stake
 ///   2. `[writable]` validator_vote
+///   3. `[]` validator_authority_override
 #[derive(Clone, Debug, Default)]
 pub struct ValidatorSyncAuthorityBuilder {
     config: Option<solana_program::pubkey::Pubkey>,
     validator_stake: Option<solana_program::pubkey::Pubkey>,
     validator_vote: Option<solana_program::pubkey::Pubkey>,
+    validator_authority_override: Option<solana_program::pubkey::Pubkey>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
 }
 
@@ -110,6 +118,15 @@ impl ValidatorSyncAuthorityBuilder {
         self.validator_vote = Some(validator_vote);
         self
     }
+    /// Validator authority override
+    #[inline(always)]
+    pub fn validator_authority_override(
+        &mut self,
+        validator_authority_override: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.validator_authority_override = Some(validator_authority_override);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -134,6 +151,9 @@ impl ValidatorSyncAuthorityBuilder {
             config: self.config.expect("config is not set"),
             validator_stake: self.validator_stake.expect("validator_stake is not set"),
             validator_vote: self.validator_vote.expect("validator_vote is not set"),
+            validator_authority_override: self
+                .validator_authority_override
+                .expect("validator_authority_override is not set"),
         };
 
         accounts.instruction_with_remaining_accounts(&self.__remaining_accounts)
@@ -148,6 +168,8 @@ pub struct ValidatorSyncAuthorityCpiAccounts<'a, 'b> {
     pub validator_stake: &'b solana_program::account_info::AccountInfo<'a>,
     /// Validator vote
     pub validator_vote: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Validator authority override
+    pub validator_authority_override: &'b solana_program::account_info::AccountInfo<'a>,
 }
 
 /// `validator_sync_authority` CPI instruction.
@@ -160,6 +182,8 @@ pub struct ValidatorSyncAuthorityCpi<'a, 'b> {
     pub validator_stake: &'b solana_program::account_info::AccountInfo<'a>,
     /// Validator vote
     pub validator_vote: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Validator authority override
+    pub validator_authority_override: &'b solana_program::account_info::AccountInfo<'a>,
 }
 
 impl<'a, 'b> ValidatorSyncAuthorityCpi<'a, 'b> {
@@ -172,6 +196,7 @@ impl<'a, 'b> ValidatorSyncAuthorityCpi<'a, 'b> {
             config: accounts.config,
             validator_stake: accounts.validator_stake,
             validator_vote: accounts.validator_vote,
+            validator_authority_override: accounts.validator_authority_override,
         }
     }
     #[inline(always)]
@@ -207,7 +232,7 @@ impl<'a, 'b> ValidatorSyncAuthorityCpi<'a, 'b> {
             bool,
         )],
     ) -> solana_program::entrypoint::ProgramResult {
-        let mut accounts = Vec::with_capacity(3 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(4 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             *self.config.key,
             false,
@@ -220,6 +245,10 @@ impl<'a, 'b> ValidatorSyncAuthorityCpi<'a, 'b> {
             *self.validator_vote.key,
             false,
         ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.validator_authority_override.key,
+            false,
+        ));
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_program::instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -236,11 +265,12 @@ impl<'a, 'b> ValidatorSyncAuthorityCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(3 + 1 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(4 + 1 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.config.clone());
         account_infos.push(self.validator_stake.clone());
         account_infos.push(self.validator_vote.clone());
+        account_infos.push(self.validator_authority_override.clone());
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -260,6 +290,7 @@ impl<'a, 'b> ValidatorSyncAuthorityCpi<'a, 'b> {
 ///   0. `[writable]` config
 ///   1. `[writable]` validator_stake
 ///   2. `[writable]` validator_vote
+///   3. `[]` validator_authority_override
 #[derive(Clone, Debug)]
 pub struct ValidatorSyncAuthorityCpiBuilder<'a, 'b> {
     instruction: Box<ValidatorSyncAuthorityCpiBuilderInstruction<'a, 'b>>,
@@ -272,6 +303,7 @@ impl<'a, 'b> ValidatorSyncAuthorityCpiBuilder<'a, 'b> {
             config: None,
             validator_stake: None,
             validator_vote: None,
+            validator_authority_override: None,
             __remaining_accounts: Vec::new(),
         });
         Self { instruction }
@@ -303,6 +335,15 @@ impl<'a, 'b> ValidatorSyncAuthorityCpiBuilder<'a, 'b> {
         self.instruction.validator_vote = Some(validator_vote);
         self
     }
+    /// Validator authority override
+    #[inline(always)]
+    pub fn validator_authority_override(
+        &mut self,
+        validator_authority_override: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.validator_authority_override = Some(validator_authority_override);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -358,6 +399,11 @@ impl<'a, 'b> ValidatorSyncAuthorityCpiBuilder<'a, 'b> {
                 .instruction
                 .validator_vote
                 .expect("validator_vote is not set"),
+
+            validator_authority_override: self
+                .instruction
+                .validator_authority_override
+                .expect("validator_authority_override is not set"),
         };
         instruction.invoke_signed_with_remaining_accounts(
             signers_seeds,
@@ -372,6 +418,7 @@ struct ValidatorSyncAuthorityCpiBuilderInstruction<'a, 'b> {
     config: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     validator_stake: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     validator_vote: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    validator_authority_override: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(
         &'b solana_program::account_info::AccountInfo<'a>,
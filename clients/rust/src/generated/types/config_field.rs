@@ -14,4 +14,6 @@ pub enum ConfigField {
     CooldownTimeSeconds(u64),
     MaxDeactivationBasisPoints(u16),
     SyncRewardsLamports(u64),
+    RewardStreamingPeriodSeconds(u64),
+    ProtocolFeeBasisPoints(u16),
 }
@@ -5,10 +5,16 @@
 //! <https://github.com/kinobi-so/kinobi>
 //!
 
+pub(crate) mod r#activation;
 pub(crate) mod r#authority_type;
+pub(crate) mod r#checkpoint;
 pub(crate) mod r#config_field;
 pub(crate) mod r#delegation;
+pub(crate) mod r#voting_power_checkpoints;
 
+pub use self::r#activation::*;
 pub use self::r#authority_type::*;
+pub use self::r#checkpoint::*;
 pub use self::r#config_field::*;
 pub use self::r#delegation::*;
+pub use self::r#voting_power_checkpoints::*;
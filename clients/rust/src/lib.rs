@@ -1,6 +1,14 @@
+#[cfg(feature = "client")]
+pub mod client;
+pub mod events;
+pub mod filters;
 mod generated;
 mod hooked;
+pub mod math;
+#[cfg(feature = "serde")]
+pub mod parser;
 pub mod pdas;
+pub mod return_data;
 
 pub use generated::programs::PALADIN_STAKE_PROGRAM_ID as ID;
 pub use generated::*;
@@ -0,0 +1,565 @@
+//! Rewards and limits calculations over the program accounts.
+//!
+//! These mirror the calculations performed by the program, so clients can
+//! display pending rewards and staking limits without sending a transaction.
+//! Arithmetic overflows are reported as `None`.
+
+use crate::{
+    accounts::{Config, SolStakerStake, ValidatorStake},
+    types::{Activation, Delegation, VotingPowerCheckpoints},
+};
+
+/// Scaling factor for rewards per token (1e18).
+pub const REWARDS_PER_TOKEN_SCALING_FACTOR: u128 = 1_000_000_000_000_000_000;
+
+/// Defined the maximum value for basis points (100%).
+pub const MAX_BASIS_POINTS: u128 = 10_000;
+
+/// Stake factor for the maximum amount of staked tokens as a percentage of the total
+/// SOL staked (STAKE_FACTOR / STAKE_SCALING_FACTOR).
+pub const STAKE_FACTOR: u128 = 13;
+
+/// Scaling factor for stake amount.
+pub const STAKE_SCALING_FACTOR: u128 = 10;
+
+/// Calculate the eligible rewards for a given token account balance.
+pub fn calculate_eligible_rewards(
+    current_accumulated_rewards_per_token: u128,
+    last_accumulated_rewards_per_token: u128,
+    token_account_balance: u64,
+) -> Option<u64> {
+    let marginal_rate =
+        current_accumulated_rewards_per_token.wrapping_sub(last_accumulated_rewards_per_token);
+
+    if marginal_rate == 0 {
+        Some(0)
+    } else {
+        marginal_rate
+            .checked_mul(token_account_balance as u128)
+            .and_then(|product| product.checked_div(REWARDS_PER_TOKEN_SCALING_FACTOR))
+            .and_then(|product| product.try_into().ok())
+    }
+}
+
+/// Calculate the rewards per token for the given rewards and stake amount.
+pub fn calculate_stake_rewards_per_token(rewards: u64, stake_amount: u64) -> Option<u128> {
+    if stake_amount == 0 {
+        Some(0)
+    } else {
+        (rewards as u128)
+            .checked_mul(REWARDS_PER_TOKEN_SCALING_FACTOR)
+            .and_then(|product| product.checked_div(stake_amount as u128))
+    }
+}
+
+/// Calculate the maximum effective stake for the given SOL amount.
+pub fn calculate_maximum_stake_for_lamports_amount(lamports_amount: u64) -> Option<u64> {
+    if lamports_amount == 0 {
+        Some(0)
+    } else {
+        (lamports_amount as u128)
+            .checked_mul(STAKE_FACTOR)
+            .and_then(|product| product.checked_div(STAKE_SCALING_FACTOR))
+            .and_then(|product| product.try_into().ok())
+    }
+}
+
+impl Config {
+    /// Lamports released as stake rewards when syncing the given config
+    /// account lamports balance at the given time.
+    ///
+    /// The protocol fee is withheld from the lamports received, and lamports
+    /// received while reward streaming is enabled are released linearly over
+    /// `reward_streaming_period_seconds`, with the fraction of a lamport left
+    /// over from the previous release carried over.
+    pub fn released_rewards(&self, config_lamports: u64, unix_timestamp: i64) -> Option<u64> {
+        let lamports_received = config_lamports.checked_sub(self.lamports_last)?;
+        let lamports_received =
+            lamports_received.checked_sub(self.protocol_fee(lamports_received)?)?;
+        let timestamp = unix_timestamp.max(0) as u64;
+
+        let elapsed = std::cmp::min(timestamp, self.reward_period_end)
+            .saturating_sub(self.reward_last_update);
+        let streamed = self
+            .reward_rate
+            .checked_mul(elapsed as u128)?
+            .checked_add(self.reward_stream_remainder as u128)?
+            .checked_div(REWARDS_PER_TOKEN_SCALING_FACTOR)?
+            .try_into()
+            .ok()?;
+
+        match self.reward_streaming_period_seconds {
+            0 => lamports_received.checked_add(streamed),
+            _ => Some(streamed),
+        }
+    }
+
+    /// Accumulated stake rewards per token after syncing the given config
+    /// account lamports balance at the given time.
+    ///
+    /// The remainder of the previous accrual is carried over, and rewards
+    /// released while no tokens are effective are left undistributed.
+    pub fn synced_stake_rewards_per_token(
+        &self,
+        config_lamports: u64,
+        unix_timestamp: i64,
+    ) -> Option<u128> {
+        let rewards = self.released_rewards(config_lamports, unix_timestamp)?;
+        if self.token_amount_effective == 0 {
+            return Some(self.accumulated_stake_rewards_per_token);
+        }
+
+        let rewards_per_token = (rewards as u128)
+            .checked_mul(REWARDS_PER_TOKEN_SCALING_FACTOR)?
+            .checked_add(self.stake_rewards_remainder as u128)?
+            / self.token_amount_effective as u128;
+
+        Some(
+            self.accumulated_stake_rewards_per_token
+                .wrapping_add(rewards_per_token),
+        )
+    }
+
+    /// Maximum amount that can be unstaked at once from the given staked amount.
+    pub fn max_deactivation_amount(&self, staked_amount: u64) -> Option<u64> {
+        (staked_amount as u128)
+            .checked_mul(self.max_deactivation_basis_points as u128)
+            .and_then(|product| product.checked_div(MAX_BASIS_POINTS))
+            .and_then(|amount| amount.try_into().ok())
+    }
+
+    /// Portion of the given stake rewards withheld as protocol fee when they
+    /// are received by the config account.
+    pub fn protocol_fee(&self, reward: u64) -> Option<u64> {
+        (reward as u128)
+            .checked_mul(self.protocol_fee_basis_points as u128)
+            .and_then(|product| product.checked_div(MAX_BASIS_POINTS))
+            .and_then(|fee| fee.try_into().ok())
+    }
+}
+
+impl Delegation {
+    /// Staking rewards that a harvest would pay out at the given time, given
+    /// the current lamports balance of the config account.
+    pub fn pending_stake_rewards(
+        &self,
+        config: &Config,
+        config_lamports: u64,
+        unix_timestamp: i64,
+    ) -> Option<u64> {
+        calculate_eligible_rewards(
+            config.synced_stake_rewards_per_token(config_lamports, unix_timestamp)?,
+            self.last_seen_stake_rewards_per_token,
+            self.effective_amount,
+        )
+    }
+
+    /// Holder rewards that a harvest would pay out, given the
+    /// `last_accumulated_rewards_per_token` of the vault `HolderRewards` account.
+    pub fn pending_holder_rewards(&self, holder_rewards_per_token: u128) -> Option<u64> {
+        calculate_eligible_rewards(
+            holder_rewards_per_token,
+            self.last_seen_holder_rewards_per_token,
+            self.staked_amount,
+        )
+    }
+
+    /// Staked tokens still activating at the given epoch, which do not count
+    /// towards the effective amount yet.
+    pub fn activating_at(&self, activation: &Activation, epoch: u64) -> u64 {
+        if epoch > activation.activation_epoch {
+            0
+        } else {
+            std::cmp::min(activation.activating_amount, self.staked_amount)
+        }
+    }
+
+    /// Seconds until the next unstake can occur (`0` if it can occur now).
+    pub fn time_until_unstake(&self, unix_timestamp: i64) -> u64 {
+        self.unstake_cooldown
+            .saturating_sub(unix_timestamp.max(0) as u64)
+    }
+
+    /// Maximum amount that can be unstaked at the given time.
+    pub fn max_unstakeable(&self, config: &Config, unix_timestamp: i64) -> Option<u64> {
+        if self.time_until_unstake(unix_timestamp) > 0 {
+            return Some(0);
+        }
+
+        config.max_deactivation_amount(self.staked_amount)
+    }
+
+    /// Additional tokens that can be staked while still being fully effective,
+    /// given the SOL amount backing the delegation.
+    fn max_stakeable(&self, lamports_amount: u64) -> Option<u64> {
+        Some(
+            calculate_maximum_stake_for_lamports_amount(lamports_amount)?
+                .saturating_sub(self.staked_amount),
+        )
+    }
+}
+
+impl ValidatorStake {
+    /// Maximum effective stake given the total SOL staked to the validator.
+    pub fn maximum_stake(&self) -> Option<u64> {
+        calculate_maximum_stake_for_lamports_amount(std::cmp::max(
+            self.total_staked_lamports_amount,
+            self.total_staked_lamports_amount_min,
+        ))
+    }
+
+    /// Additional tokens that can be staked while still being fully effective.
+    pub fn max_stakeable(&self) -> Option<u64> {
+        self.delegation.max_stakeable(std::cmp::max(
+            self.total_staked_lamports_amount,
+            self.total_staked_lamports_amount_min,
+        ))
+    }
+}
+
+impl SolStakerStake {
+    /// Maximum effective stake given the SOL amount of the stake account.
+    pub fn maximum_stake(&self) -> Option<u64> {
+        calculate_maximum_stake_for_lamports_amount(self.lamports_amount)
+    }
+
+    /// Additional tokens that can be staked while still being fully effective.
+    pub fn max_stakeable(&self) -> Option<u64> {
+        self.delegation.max_stakeable(self.lamports_amount)
+    }
+}
+
+impl VotingPowerCheckpoints {
+    /// Effective amount at the end of the given slot.
+    ///
+    /// When the slot is covered by merged checkpoints, the returned amount is
+    /// a lower bound of the actual effective amount.
+    pub fn voting_power_at(&self, slot: u64) -> u64 {
+        let len = std::cmp::min(self.count, self.checkpoints.len() as u64) as usize;
+        self.checkpoints[..len]
+            .iter()
+            .filter(|checkpoint| checkpoint.slot <= slot)
+            .max_by_key(|checkpoint| checkpoint.slot)
+            .map(|checkpoint| checkpoint.effective_amount)
+            .unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use paladin_stake_program::state as program;
+    use solana_program::pubkey::Pubkey;
+
+    use super::*;
+    use crate::{hooked::NullableAddress, types::Checkpoint};
+
+    const VALUES: [u64; 7] = [
+        0,
+        1,
+        13,
+        1_000_000_000,
+        123_456_789_012,
+        u32::MAX as u64,
+        u64::MAX,
+    ];
+    const RATES: [u128; 6] = [
+        0,
+        1,
+        200_000_000_000_000_000,
+        REWARDS_PER_TOKEN_SCALING_FACTOR,
+        u64::MAX as u128,
+        u128::MAX,
+    ];
+
+    fn checkpoints() -> VotingPowerCheckpoints {
+        VotingPowerCheckpoints {
+            count: 0,
+            checkpoints: std::array::from_fn(|_| Checkpoint {
+                slot: 0,
+                effective_amount: 0,
+            }),
+        }
+    }
+
+    fn activation() -> Activation {
+        Activation {
+            activating_amount: 0,
+            activation_epoch: 0,
+        }
+    }
+
+    fn config(lamports_last: u64, token_amount_effective: u64) -> Config {
+        Config {
+            discriminator: [0; 8],
+            authority: NullableAddress::from(None),
+            slash_authority: NullableAddress::from(None),
+            vault: Pubkey::default(),
+            cooldown_time_seconds: 0,
+            token_amount_effective,
+            sync_rewards_lamports: 0,
+            lamports_last,
+            duna_document_hash: [0; 32],
+            accumulated_stake_rewards_per_token: 0,
+            max_deactivation_basis_points: 500,
+            vault_authority_bump: 0,
+            version: 1,
+            padding: [0; 4],
+            reward_streaming_period_seconds: 0,
+            reward_period_end: 0,
+            reward_last_update: 0,
+            reward_rate: 0,
+            stake_rewards_remainder: 0,
+            undistributed_rewards: 0,
+            fee_recipient: NullableAddress::from(None),
+            unclaimed_protocol_fees: 0,
+            protocol_fee_basis_points: 0,
+            fee_padding: [0; 6],
+            reward_stream_remainder: 0,
+        }
+    }
+
+    fn delegation(staked_amount: u64, unstake_cooldown: u64) -> Delegation {
+        Delegation {
+            staked_amount,
+            effective_amount: staked_amount,
+            unstake_cooldown,
+            authority: Pubkey::default(),
+            validator_vote: Pubkey::default(),
+            last_seen_holder_rewards_per_token: 0,
+            last_seen_stake_rewards_per_token: 0,
+        }
+    }
+
+    #[test]
+    fn parity_calculate_eligible_rewards() {
+        for current in RATES {
+            for last in RATES {
+                for balance in VALUES {
+                    assert_eq!(
+                        calculate_eligible_rewards(current, last, balance),
+                        program::calculate_eligible_rewards(current, last, balance).ok(),
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn parity_calculate_stake_rewards_per_token() {
+        for rewards in VALUES {
+            for stake_amount in VALUES {
+                assert_eq!(
+                    calculate_stake_rewards_per_token(rewards, stake_amount),
+                    program::calculate_stake_rewards_per_token(rewards, stake_amount).ok(),
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn parity_calculate_maximum_stake_for_lamports_amount() {
+        for lamports in VALUES {
+            assert_eq!(
+                calculate_maximum_stake_for_lamports_amount(lamports),
+                program::calculate_maximum_stake_for_lamports_amount(lamports).ok(),
+            );
+        }
+    }
+
+    #[test]
+    fn protocol_fee_matches_program() {
+        for basis_points in [0, 1, 250, 10_000] {
+            let mut config = config(0, 0);
+            config.protocol_fee_basis_points = basis_points;
+            let expected = program::Config {
+                protocol_fee_basis_points: basis_points,
+                ..Default::default()
+            };
+
+            for reward in VALUES {
+                assert_eq!(
+                    config.protocol_fee(reward),
+                    expected.protocol_fee(reward).ok()
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn pending_stake_rewards_includes_unsynced_lamports() {
+        // 26 lamports received since the last sync, 130 tokens effective.
+        let config = config(1_000, 130);
+        let delegation = delegation(65, 0);
+
+        assert_eq!(
+            config.synced_stake_rewards_per_token(1_026, 0),
+            Some(200_000_000_000_000_000)
+        );
+        assert_eq!(
+            delegation.pending_stake_rewards(&config, 1_026, 0),
+            Some(13)
+        );
+        assert_eq!(delegation.pending_stake_rewards(&config, 1_000, 0), Some(0));
+        assert_eq!(delegation.pending_stake_rewards(&config, 999, 0), None);
+    }
+
+    #[test]
+    fn pending_stake_rewards_excludes_protocol_fee() {
+        // 1,300 lamports received since the last sync with a 10% fee, 130
+        // tokens effective.
+        let mut config = config(1_000, 130);
+        config.protocol_fee_basis_points = 1_000;
+        let delegation = delegation(65, 0);
+
+        assert_eq!(config.released_rewards(2_300, 0), Some(1_170));
+        assert_eq!(
+            delegation.pending_stake_rewards(&config, 2_300, 0),
+            Some(585)
+        );
+    }
+
+    #[test]
+    fn synced_stake_rewards_per_token_matches_program() {
+        // 1 lamport received over 3 tokens, with a remainder of 2 carried over.
+        let mut config = config(1_000, 3);
+        config.stake_rewards_remainder = 2;
+
+        let mut expected = program::Config {
+            token_amount_effective: 3,
+            stake_rewards_remainder: config.stake_rewards_remainder,
+            ..Default::default()
+        };
+        expected.accrue_stake_rewards(1).unwrap();
+
+        assert_eq!(
+            config.synced_stake_rewards_per_token(1_001, 0),
+            Some(u128::from(expected.accumulated_stake_rewards_per_token))
+        );
+    }
+
+    #[test]
+    fn released_rewards_matches_program() {
+        let mut expected = program::Config {
+            lamports_last: 1_000,
+            reward_streaming_period_seconds: 100,
+            protocol_fee_basis_points: 1_000,
+            ..Default::default()
+        };
+        let mut lamports = 1_000;
+
+        for (timestamp, received) in [
+            (10, 1_000),
+            (60, 0),
+            (70, 333),
+            (71, 0),
+            (72, 0),
+            (200, 0),
+            (210, 7),
+        ] {
+            lamports += received;
+
+            let mut config = config(expected.lamports_last, 130);
+            config.reward_streaming_period_seconds = expected.reward_streaming_period_seconds;
+            config.reward_period_end = expected.reward_period_end;
+            config.reward_last_update = expected.reward_last_update;
+            config.reward_rate = expected.reward_rate.into();
+            config.reward_stream_remainder = expected.reward_stream_remainder;
+            config.protocol_fee_basis_points = expected.protocol_fee_basis_points;
+
+            let received = expected
+                .withhold_protocol_fee(lamports - expected.lamports_last)
+                .unwrap();
+            let released = expected.stream_rewards(received, timestamp).ok();
+            expected.lamports_last = lamports;
+
+            assert_eq!(
+                config.released_rewards(lamports, timestamp as i64),
+                released
+            );
+        }
+    }
+
+    #[test]
+    fn pending_holder_rewards() {
+        let delegation = delegation(65, 0);
+
+        assert_eq!(
+            delegation.pending_holder_rewards(200_000_000_000_000_000),
+            Some(13)
+        );
+        assert_eq!(delegation.pending_holder_rewards(0), Some(0));
+    }
+
+    #[test]
+    fn unstake_limits() {
+        let config = config(0, 0);
+        let delegation = delegation(1_000, 100);
+
+        // Still cooling down.
+        assert_eq!(delegation.time_until_unstake(40), 60);
+        assert_eq!(delegation.max_unstakeable(&config, 40), Some(0));
+
+        // Cooldown elapsed: 5% of the staked amount.
+        assert_eq!(delegation.time_until_unstake(100), 0);
+        assert_eq!(delegation.max_unstakeable(&config, 100), Some(50));
+    }
+
+    #[test]
+    fn stake_limits() {
+        let stake = ValidatorStake {
+            discriminator: [0; 8],
+            delegation: delegation(50, 0),
+            total_staked_lamports_amount: 50,
+            total_staked_lamports_amount_min: 100,
+            version: 1,
+            padding: [0; 7],
+            checkpoints: checkpoints(),
+            vote_delegate: NullableAddress::from(None),
+            activation: activation(),
+            delegated_voting_power: 0,
+        };
+
+        assert_eq!(stake.maximum_stake(), Some(130));
+        assert_eq!(stake.max_stakeable(), Some(80));
+
+        let stake = SolStakerStake {
+            discriminator: [0; 8],
+            delegation: delegation(100, 0),
+            lamports_amount: 50,
+            sol_stake: Pubkey::default(),
+            version: 1,
+            awaiting_validator_stake: 0,
+            padding: [0; 6],
+            checkpoints: checkpoints(),
+            vote_delegate: NullableAddress::from(None),
+            activation: activation(),
+            delegated_voting_power: 0,
+        };
+
+        assert_eq!(stake.maximum_stake(), Some(65));
+        assert_eq!(stake.max_stakeable(), Some(0));
+    }
+
+    #[test]
+    fn voting_power_at_matches_program() {
+        let mut expected = program::VotingPowerCheckpoints::default();
+
+        for (index, slot) in (10..=200).step_by(10).enumerate() {
+            expected.record(slot, (index as u64 * 7) % 5);
+
+            let mut history = checkpoints();
+            history.count = expected.count;
+            for (checkpoint, recorded) in history.checkpoints.iter_mut().zip(expected.checkpoints) {
+                checkpoint.slot = recorded.slot;
+                checkpoint.effective_amount = recorded.effective_amount;
+            }
+
+            for slot in [0, 5, 10, 15, 100, 195, 200, u64::MAX] {
+                assert_eq!(
+                    history.voting_power_at(slot),
+                    expected.voting_power_at(slot)
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,740 @@
+//! Human-readable parsing of the stake program instructions.
+//!
+//! Instructions are parsed into the `{ "type": ..., "info": ... }` structure
+//! of the `jsonParsed` encoding of `solana-transaction-status`, so explorers
+//! and wallets can render them next to the built-in programs. `info` holds the
+//! named accounts of the instruction followed by its decoded arguments, using
+//! camelCase keys and base58 addresses.
+
+use borsh::BorshDeserialize;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Map, Value};
+use solana_program::{
+    hash::Hash, instruction::CompiledInstruction, message::AccountKeys, pubkey::Pubkey,
+};
+use thiserror::Error;
+
+use crate::{
+    instructions::{
+        GetVotingPowerInstructionArgs, InitializeConfigInstructionArgs,
+        InitializeConfigPdaInstructionArgs, LiquidStakeTokensInstructionArgs,
+        LiquidUnstakeTokensInstructionArgs, SetAuthorityInstructionArgs,
+        SlashSolStakerStakeInstructionArgs, SlashValidatorStakeInstructionArgs,
+        SolStakerMoveTokensInstructionArgs, SolStakerStakeTokensInstructionArgs,
+        TransferStakeInstructionArgs, UnstakeTokensInstructionArgs, UpdateConfigInstructionArgs,
+        ValidatorOverrideStakedLamportsInstructionArgs, ValidatorStakeTokensInstructionArgs,
+    },
+    types::{AuthorityType, ConfigField},
+};
+
+/// Name of the program in parsed instructions.
+pub const PROGRAM_NAME: &str = "paladin-stake";
+
+/// Optional accounts, passed as the program ID when omitted.
+const OPTIONAL_ACCOUNTS: [&str; 2] = ["keeperRecipient", "voteDelegate"];
+
+/// Parsed instruction, as in the `jsonParsed` encoding of a transaction.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ParsedInstruction {
+    pub program: String,
+    pub program_id: String,
+    pub parsed: Value,
+    pub stack_height: Option<u32>,
+}
+
+/// Type and named accounts and arguments of a parsed instruction.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ParsedInstructionEnum {
+    #[serde(rename = "type")]
+    pub instruction_type: String,
+    #[serde(default, skip_serializing_if = "Value::is_null")]
+    pub info: Value,
+}
+
+/// Errors returned when parsing an instruction.
+#[derive(Debug, Error, Eq, PartialEq)]
+pub enum ParseInstructionError {
+    /// The instruction data is not a stake program instruction.
+    #[error("Instruction not parsable")]
+    InstructionNotParsable,
+    /// The instruction is missing accounts or refers to unknown account keys.
+    #[error("Instruction key mismatch")]
+    InstructionKeyMismatch,
+}
+
+/// Parses a stake program instruction into the `ParsedInstruction` format.
+pub fn parse_instruction(
+    instruction: &CompiledInstruction,
+    account_keys: &AccountKeys,
+    stack_height: Option<u32>,
+) -> Result<ParsedInstruction, ParseInstructionError> {
+    let parsed = parse_stake_instruction(instruction, account_keys)?;
+
+    Ok(ParsedInstruction {
+        program: PROGRAM_NAME.to_string(),
+        program_id: crate::ID.to_string(),
+        parsed: serde_json::to_value(parsed)
+            .map_err(|_| ParseInstructionError::InstructionNotParsable)?,
+        stack_height,
+    })
+}
+
+/// Parses a stake program instruction into its type and named accounts and
+/// arguments.
+pub fn parse_stake_instruction(
+    instruction: &CompiledInstruction,
+    account_keys: &AccountKeys,
+) -> Result<ParsedInstructionEnum, ParseInstructionError> {
+    let (&discriminator, data) = instruction
+        .data
+        .split_first()
+        .ok_or(ParseInstructionError::InstructionNotParsable)?;
+
+    let (instruction_type, accounts, args): (_, &[&str], _) = match discriminator {
+        0 => {
+            let args: InitializeConfigInstructionArgs = unpack_args(data)?;
+            (
+                "initializeConfig",
+                &[
+                    "config",
+                    "mint",
+                    "vaultPda",
+                    "vault",
+                    "vaultHolderRewards",
+                    "systemProgram",
+                    "rewardsProgram",
+                ],
+                json!({
+                    "slashAuthority": args.slash_authority.to_string(),
+                    "configAuthority": args.config_authority.to_string(),
+                    "cooldownTimeSeconds": args.cooldown_time_seconds,
+                    "maxDeactivationBasisPoints": args.max_deactivation_basis_points,
+                    "syncRewardsLamports": args.sync_rewards_lamports,
+                    "dunaDocumentHash": Hash::new_from_array(args.duna_document_hash).to_string(),
+                }),
+            )
+        }
+        1 => (
+            "initializeValidatorStake",
+            &[
+                "config",
+                "dunaDocumentPda",
+                "validatorStake",
+                "validatorVote",
+                "systemProgram",
+            ],
+            json!({}),
+        ),
+        2 => {
+            let args: ValidatorStakeTokensInstructionArgs = unpack_args(data)?;
+            (
+                "validatorStakeTokens",
+                &[
+                    "config",
+                    "holderRewardsPool",
+                    "holderRewardsPoolTokenAccount",
+                    "validatorStake",
+                    "validatorStakeAuthority",
+                    "sourceTokenAccount",
+                    "sourceTokenAccountAuthority",
+                    "mint",
+                    "vaultPda",
+                    "vault",
+                    "vaultHolderRewards",
+                    "tokenProgram",
+                    "rewardsProgram",
+                ],
+                json!({ "amount": args.amount }),
+            )
+        }
+        3 => (
+            "harvestHolderRewards",
+            &[
+                "config",
+                "holderRewardsPool",
+                "holderRewardsPoolTokenAccount",
+                "vault",
+                "vaultHolderRewards",
+                "vaultPda",
+                "mint",
+                "tokenProgram",
+                "paladinRewardsProgram",
+                "systemProgram",
+            ],
+            json!({}),
+        ),
+        4 => (
+            "harvestValidatorRewards",
+            &[
+                "config",
+                "vaultHolderRewards",
+                "validatorStake",
+                "validatorStakeAuthority",
+            ],
+            json!({}),
+        ),
+        5 => {
+            let args: SlashValidatorStakeInstructionArgs = unpack_args(data)?;
+            (
+                "slashValidatorStake",
+                &[
+                    "config",
+                    "validatorStake",
+                    "validatorStakeAuthority",
+                    "slashAuthority",
+                    "vault",
+                    "vaultHolderRewards",
+                    "vaultAuthority",
+                    "mint",
+                    "tokenProgram",
+                ],
+                json!({ "amount": args.amount }),
+            )
+        }
+        6 => {
+            let args: SetAuthorityInstructionArgs = unpack_args(data)?;
+            let authority_type = match args.authority_type {
+                AuthorityType::Config => "config",
+                AuthorityType::Slash => "slash",
+                AuthorityType::FeeRecipient => "feeRecipient",
+            };
+            (
+                "setAuthority",
+                &["account", "authority", "newAuthority"],
+                json!({ "authorityType": authority_type }),
+            )
+        }
+        7 => {
+            let args: UpdateConfigInstructionArgs = unpack_args(data)?;
+            let (field, value) = match args.config_field {
+                ConfigField::CooldownTimeSeconds(value) => ("cooldownTimeSeconds", value),
+                ConfigField::MaxDeactivationBasisPoints(value) => {
+                    ("maxDeactivationBasisPoints", value.into())
+                }
+                ConfigField::SyncRewardsLamports(value) => ("syncRewardsLamports", value),
+                ConfigField::RewardStreamingPeriodSeconds(value) => {
+                    ("rewardStreamingPeriodSeconds", value)
+                }
+                ConfigField::ProtocolFeeBasisPoints(value) => {
+                    ("protocolFeeBasisPoints", value.into())
+                }
+            };
+            (
+                "updateConfig",
+                &["config", "configAuthority"],
+                json!({ "field": field, "value": value }),
+            )
+        }
+        8 => (
+            "initializeSolStakerStake",
+            &[
+                "config",
+                "dunaDocumentPda",
+                "solStakerStake",
+                "solStakerAuthorityOverride",
+                "validatorStake",
+                "solStakerNativeStake",
+                "sysvarStakeHistory",
+                "systemProgram",
+                "solStakeViewProgram",
+            ],
+            json!({}),
+        ),
+        9 => {
+            let args: SolStakerStakeTokensInstructionArgs = unpack_args(data)?;
+            (
+                "solStakerStakeTokens",
+                &[
+                    "config",
+                    "holderRewardsPool",
+                    "holderRewardsPoolTokenAccount",
+                    "solStakerStake",
+                    "solStakerStakeAuthority",
+                    "sourceTokenAccount",
+                    "sourceTokenAccountAuthority",
+                    "mint",
+                    "vaultPda",
+                    "vault",
+                    "vaultHolderRewards",
+                    "tokenProgram",
+                    "rewardsProgram",
+                ],
+                json!({ "amount": args.amount }),
+            )
+        }
+        10 => (
+            "harvestSolStakerRewards",
+            &[
+                "solStakeViewProgram",
+                "config",
+                "vaultHolderRewards",
+                "solStakerStake",
+                "solStakerStakeAuthority",
+                "solStakerNativeStake",
+                "previousValidatorStake",
+                "previousValidatorStakeAuthority",
+                "currentValidatorStake",
+                "currentValidatorStakeAuthority",
+                "sysvarStakeHistory",
+                "keeperRecipient",
+            ],
+            json!({}),
+        ),
+        11 => {
+            let args: UnstakeTokensInstructionArgs = unpack_args(data)?;
+            (
+                "unstakeTokens",
+                &[
+                    "config",
+                    "holderRewardsPool",
+                    "holderRewardsPoolTokenAccount",
+                    "stake",
+                    "stakeAuthority",
+                    "vault",
+                    "vaultPda",
+                    "vaultHolderRewards",
+                    "mint",
+                    "destinationTokenAccount",
+                    "tokenProgram",
+                    "rewardsProgram",
+                ],
+                json!({ "amount": args.amount }),
+            )
+        }
+        12 => {
+            let args: SlashSolStakerStakeInstructionArgs = unpack_args(data)?;
+            (
+                "slashSolStakerStake",
+                &[
+                    "config",
+                    "solStakerStake",
+                    "solStakerStakeAuthority",
+                    "slashAuthority",
+                    "mint",
+                    "vault",
+                    "vaultHolderRewards",
+                    "vaultAuthority",
+                    "tokenProgram",
+                ],
+                json!({ "amount": args.amount }),
+            )
+        }
+        13 => {
+            let args: SolStakerMoveTokensInstructionArgs = unpack_args(data)?;
+            (
+                "solStakerMoveTokens",
+                &[
+                    "config",
+                    "vaultHolderRewards",
+                    "solStakerAuthority",
+                    "sourceSolStakerStake",
+                    "destinationSolStakerStake",
+                ],
+                json!({ "amount": args.amount }),
+            )
+        }
+        14 => {
+            let args: ValidatorOverrideStakedLamportsInstructionArgs = unpack_args(data)?;
+            (
+                "validatorOverrideStakedLamports",
+                &[
+                    "config",
+                    "configAuthority",
+                    "validatorStake",
+                    "validatorStakeAuthority",
+                    "vaultHolderRewards",
+                ],
+                json!({ "amountMin": args.amount_min }),
+            )
+        }
+        15 => (
+            "validatorSyncAuthority",
+            &[
+                "config",
+                "validatorStake",
+                "validatorVote",
+                "validatorAuthorityOverride",
+            ],
+            json!({}),
+        ),
+        16 => (
+            "getPendingRewards",
+            &["config", "vaultHolderRewards", "stake"],
+            json!({}),
+        ),
+        17 => (
+            "migrateAccount",
+            &["account", "payer", "systemProgram"],
+            json!({}),
+        ),
+        18 => {
+            let args: InitializeConfigPdaInstructionArgs = unpack_args(data)?;
+            (
+                "initializeConfigPda",
+                &[
+                    "config",
+                    "mint",
+                    "vaultPda",
+                    "vault",
+                    "vaultHolderRewards",
+                    "systemProgram",
+                    "rewardsProgram",
+                    "configAuthority",
+                ],
+                json!({
+                    "namespace": args.namespace,
+                    "slashAuthority": args.slash_authority.to_string(),
+                    "cooldownTimeSeconds": args.cooldown_time_seconds,
+                    "maxDeactivationBasisPoints": args.max_deactivation_basis_points,
+                    "syncRewardsLamports": args.sync_rewards_lamports,
+                    "dunaDocumentHash": Hash::new_from_array(args.duna_document_hash).to_string(),
+                }),
+            )
+        }
+        19 => {
+            let args: GetVotingPowerInstructionArgs = unpack_args(data)?;
+            (
+                "getVotingPower",
+                &["config", "stake"],
+                json!({ "slot": args.slot }),
+            )
+        }
+        20 => (
+            "initializeDelegatedVotingPower",
+            &[
+                "config",
+                "voteDelegate",
+                "delegatedVotingPower",
+                "systemProgram",
+            ],
+            json!({}),
+        ),
+        21 => (
+            "setVoteDelegate",
+            &["config", "stake", "stakeAuthority", "voteDelegate"],
+            json!({}),
+        ),
+        22 => (
+            "setValidatorAuthorityOverride",
+            &[
+                "config",
+                "validatorVote",
+                "withdrawAuthority",
+                "validatorAuthorityOverride",
+                "newAuthority",
+                "systemProgram",
+            ],
+            json!({}),
+        ),
+        23 => (
+            "sweepUndistributedRewards",
+            &["config", "configAuthority", "destination"],
+            json!({}),
+        ),
+        24 => (
+            "initializeLiquidStakePool",
+            &[
+                "config",
+                "validatorStake",
+                "liquidStakePool",
+                "vault",
+                "mint",
+                "receiptMint",
+                "receiptTokenProgram",
+                "systemProgram",
+            ],
+            json!({}),
+        ),
+        25 => (
+            "initializeReceiptRewards",
+            &[
+                "liquidStakePool",
+                "receiptMint",
+                "receiptTokenAccount",
+                "receiptRewards",
+                "systemProgram",
+            ],
+            json!({}),
+        ),
+        26 => {
+            let args: LiquidStakeTokensInstructionArgs = unpack_args(data)?;
+            (
+                "liquidStakeTokens",
+                &[
+                    "config",
+                    "holderRewardsPool",
+                    "holderRewardsPoolTokenAccount",
+                    "validatorStake",
+                    "liquidStakePool",
+                    "sourceTokenAccount",
+                    "sourceTokenAccountAuthority",
+                    "mint",
+                    "vaultPda",
+                    "vault",
+                    "vaultHolderRewards",
+                    "tokenProgram",
+                    "rewardsProgram",
+                    "receiptMint",
+                    "destinationReceiptAccount",
+                    "destinationReceiptRewards",
+                    "receiptTokenProgram",
+                    "stakeProgram",
+                ],
+                json!({ "amount": args.amount }),
+            )
+        }
+        27 => {
+            let args: LiquidUnstakeTokensInstructionArgs = unpack_args(data)?;
+            (
+                "liquidUnstakeTokens",
+                &[
+                    "config",
+                    "holderRewardsPool",
+                    "holderRewardsPoolTokenAccount",
+                    "validatorStake",
+                    "liquidStakePool",
+                    "vault",
+                    "vaultPda",
+                    "vaultHolderRewards",
+                    "mint",
+                    "destinationTokenAccount",
+                    "tokenProgram",
+                    "rewardsProgram",
+                    "receiptMint",
+                    "sourceReceiptAccount",
+                    "sourceReceiptRewards",
+                    "sourceReceiptAuthority",
+                    "receiptTokenProgram",
+                    "stakeProgram",
+                ],
+                json!({ "amount": args.amount }),
+            )
+        }
+        28 => (
+            "harvestReceiptRewards",
+            &[
+                "liquidStakePool",
+                "receiptMint",
+                "receiptTokenAccount",
+                "receiptRewards",
+                "receiptTokenAccountOwner",
+            ],
+            json!({}),
+        ),
+        29 => {
+            let args: TransferStakeInstructionArgs = unpack_args(data)?;
+            (
+                "transferStake",
+                &[
+                    "config",
+                    "vaultHolderRewards",
+                    "sourceSolStakerStake",
+                    "sourceAuthority",
+                    "destinationSolStakerStake",
+                    "destinationAuthority",
+                ],
+                json!({ "amount": args.amount }),
+            )
+        }
+        30 => (
+            "withdrawProtocolFees",
+            &["config", "configAuthority", "feeRecipient"],
+            json!({}),
+        ),
+        31 => (
+            "auditInvariants",
+            &["config", "vault", "vaultHolderRewards"],
+            json!({}),
+        ),
+        _ => return Err(ParseInstructionError::InstructionNotParsable),
+    };
+
+    if instruction.accounts.len() < accounts.len() {
+        return Err(ParseInstructionError::InstructionKeyMismatch);
+    }
+
+    let mut info = Map::new();
+    for (name, &index) in accounts.iter().zip(&instruction.accounts) {
+        let address = account_keys
+            .get(index as usize)
+            .ok_or(ParseInstructionError::InstructionKeyMismatch)?;
+        let value = if OPTIONAL_ACCOUNTS.contains(name) && address == &crate::ID {
+            Value::Null
+        } else {
+            Value::String(address.to_string())
+        };
+        info.insert(name.to_string(), value);
+    }
+    if let Value::Object(args) = args {
+        info.extend(args);
+    }
+
+    Ok(ParsedInstructionEnum {
+        instruction_type: instruction_type.to_string(),
+        info: Value::Object(info),
+    })
+}
+
+/// Deserializes the arguments of an instruction, which must span all its
+/// data.
+fn unpack_args<T: BorshDeserialize>(data: &[u8]) -> Result<T, ParseInstructionError> {
+    T::try_from_slice(data).map_err(|_| ParseInstructionError::InstructionNotParsable)
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_program::{instruction::Instruction, message::Message};
+
+    use super::*;
+    use crate::instructions::{
+        HarvestSolStakerRewardsBuilder, SetAuthorityBuilder, UpdateConfigBuilder,
+        ValidatorStakeTokensBuilder,
+    };
+
+    /// Compiles an instruction into a message and parses it back.
+    fn parse(instruction: Instruction) -> Result<ParsedInstructionEnum, ParseInstructionError> {
+        let message = Message::new(&[instruction], None);
+        let account_keys = AccountKeys::new(&message.account_keys, None);
+        parse_stake_instruction(&message.instructions[0], &account_keys)
+    }
+
+    #[test]
+    fn parse_validator_stake_tokens() {
+        let config = Pubkey::new_unique();
+        let validator_stake = Pubkey::new_unique();
+        let instruction = ValidatorStakeTokensBuilder::new()
+            .config(config)
+            .holder_rewards_pool(Pubkey::new_unique())
+            .holder_rewards_pool_token_account(Pubkey::new_unique())
+            .validator_stake(validator_stake)
+            .validator_stake_authority(Pubkey::new_unique())
+            .source_token_account(Pubkey::new_unique())
+            .source_token_account_authority(Pubkey::new_unique())
+            .mint(Pubkey::new_unique())
+            .vault_pda(Pubkey::new_unique())
+            .vault(Pubkey::new_unique())
+            .vault_holder_rewards(Pubkey::new_unique())
+            .rewards_program(Pubkey::new_unique())
+            .amount(100)
+            .instruction();
+
+        let parsed = parse(instruction).unwrap();
+
+        assert_eq!(parsed.instruction_type, "validatorStakeTokens");
+        assert_eq!(parsed.info["config"], config.to_string());
+        assert_eq!(parsed.info["validatorStake"], validator_stake.to_string());
+        assert_eq!(parsed.info["tokenProgram"], spl_token::ID.to_string());
+        assert_eq!(parsed.info["amount"], 100);
+        assert_eq!(parsed.info.as_object().unwrap().len(), 14);
+    }
+
+    #[test]
+    fn parse_enum_arguments() {
+        let instruction = UpdateConfigBuilder::new()
+            .config(Pubkey::new_unique())
+            .config_authority(Pubkey::new_unique())
+            .config_field(ConfigField::ProtocolFeeBasisPoints(250))
+            .instruction();
+        let parsed = parse(instruction).unwrap();
+        assert_eq!(parsed.instruction_type, "updateConfig");
+        assert_eq!(parsed.info["field"], "protocolFeeBasisPoints");
+        assert_eq!(parsed.info["value"], 250);
+
+        let new_authority = Pubkey::new_unique();
+        let instruction = SetAuthorityBuilder::new()
+            .account(Pubkey::new_unique())
+            .authority(Pubkey::new_unique())
+            .new_authority(new_authority)
+            .authority_type(AuthorityType::FeeRecipient)
+            .instruction();
+        let parsed = parse(instruction).unwrap();
+        assert_eq!(parsed.instruction_type, "setAuthority");
+        assert_eq!(parsed.info["newAuthority"], new_authority.to_string());
+        assert_eq!(parsed.info["authorityType"], "feeRecipient");
+    }
+
+    #[test]
+    fn parse_omitted_optional_account_as_null() {
+        let mut builder = HarvestSolStakerRewardsBuilder::new();
+        builder
+            .sol_stake_view_program(Pubkey::new_unique())
+            .config(Pubkey::new_unique())
+            .vault_holder_rewards(Pubkey::new_unique())
+            .sol_staker_stake(Pubkey::new_unique())
+            .sol_staker_stake_authority(Pubkey::new_unique())
+            .sol_staker_native_stake(Pubkey::new_unique())
+            .previous_validator_stake(Pubkey::new_unique())
+            .previous_validator_stake_authority(Pubkey::new_unique())
+            .current_validator_stake(Pubkey::new_unique())
+            .current_validator_stake_authority(Pubkey::new_unique());
+
+        let parsed = parse(builder.instruction()).unwrap();
+        assert_eq!(parsed.instruction_type, "harvestSolStakerRewards");
+        assert_eq!(parsed.info["keeperRecipient"], Value::Null);
+
+        let keeper = Pubkey::new_unique();
+        let parsed = parse(builder.keeper_recipient(Some(keeper)).instruction()).unwrap();
+        assert_eq!(parsed.info["keeperRecipient"], keeper.to_string());
+    }
+
+    #[test]
+    fn serialize_parsed_instruction() {
+        let instruction = CompiledInstruction::new_from_raw_parts(0, vec![4], vec![1, 2, 3, 4]);
+        let keys = [
+            crate::ID,
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        ];
+        let account_keys = AccountKeys::new(&keys, None);
+
+        let parsed = parse_instruction(&instruction, &account_keys, Some(1)).unwrap();
+        let json = serde_json::to_value(&parsed).unwrap();
+
+        assert_eq!(json["program"], PROGRAM_NAME);
+        assert_eq!(json["programId"], crate::ID.to_string());
+        assert_eq!(json["stackHeight"], 1);
+        assert_eq!(json["parsed"]["type"], "harvestValidatorRewards");
+        assert_eq!(
+            json["parsed"]["info"]["validatorStake"],
+            keys[3].to_string()
+        );
+    }
+
+    #[test]
+    fn fail_with_invalid_instruction() {
+        let keys = [Pubkey::new_unique()];
+        let account_keys = AccountKeys::new(&keys, None);
+
+        // Unknown discriminator.
+        let instruction = CompiledInstruction::new_from_raw_parts(0, vec![255], vec![]);
+        assert_eq!(
+            parse_stake_instruction(&instruction, &account_keys),
+            Err(ParseInstructionError::InstructionNotParsable)
+        );
+
+        // Truncated arguments.
+        let instruction = CompiledInstruction::new_from_raw_parts(0, vec![2, 1, 0], vec![0; 13]);
+        assert_eq!(
+            parse_stake_instruction(&instruction, &account_keys),
+            Err(ParseInstructionError::InstructionNotParsable)
+        );
+
+        // Missing accounts.
+        let instruction = CompiledInstruction::new_from_raw_parts(0, vec![4], vec![0, 0, 0]);
+        assert_eq!(
+            parse_stake_instruction(&instruction, &account_keys),
+            Err(ParseInstructionError::InstructionKeyMismatch)
+        );
+
+        // Unknown account keys.
+        let instruction = CompiledInstruction::new_from_raw_parts(0, vec![4], vec![0, 0, 0, 1]);
+        assert_eq!(
+            parse_stake_instruction(&instruction, &account_keys),
+            Err(ParseInstructionError::InstructionKeyMismatch)
+        );
+    }
+}
@@ -1,4 +1,30 @@
-use solana_program::pubkey::Pubkey;
+use solana_program::{pubkey, pubkey::Pubkey};
+
+/// DUNA document program.
+pub const DUNA_PROGRAM_ID: Pubkey = pubkey!("8TwDM3rkxQuFCiS2iPB1HB3Q3qnN7b6J4SCTDCpw9SS1");
+
+/// Finds the config account created with `InitializeConfigPda` for the given
+/// stake mint, config authority and namespace.
+pub fn find_config_pda(mint: &Pubkey, config_authority: &Pubkey, namespace: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            "config".as_bytes(),
+            mint.as_ref(),
+            config_authority.as_ref(),
+            &namespace.to_le_bytes(),
+        ],
+        &crate::ID,
+    )
+}
+
+/// Finds the DUNA document account signed by `signer` for the given document
+/// hash.
+pub fn find_duna_document_pda(signer: &Pubkey, doc_hash: &[u8; 32]) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"consitution", signer.as_ref(), doc_hash],
+        &DUNA_PROGRAM_ID,
+    )
+}
 
 pub fn find_vault_pda(config: &Pubkey) -> (Pubkey, u8) {
     Pubkey::find_program_address(&["token-owner".as_bytes(), config.as_ref()], &crate::ID)
@@ -29,6 +55,33 @@ pub fn find_sol_staker_authority_override_pda(
     )
 }
 
+/// Finds the authority override of the validator stake of a vote account.
+pub fn find_validator_authority_override_pda(
+    validator_vote: &Pubkey,
+    config: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            "validator_authority_override".as_bytes(),
+            validator_vote.as_ref(),
+            config.as_ref(),
+        ],
+        &crate::ID,
+    )
+}
+
+/// Finds the `DelegatedVotingPower` account of a vote delegate.
+pub fn find_delegated_voting_power_pda(delegate: &Pubkey, config: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            "delegated_voting_power".as_bytes(),
+            delegate.as_ref(),
+            config.as_ref(),
+        ],
+        &crate::ID,
+    )
+}
+
 pub fn find_validator_stake_pda(validator_vote: &Pubkey, config: &Pubkey) -> (Pubkey, u8) {
     Pubkey::find_program_address(
         &[
@@ -39,3 +92,26 @@ pub fn find_validator_stake_pda(validator_vote: &Pubkey, config: &Pubkey) -> (Pu
         &crate::ID,
     )
 }
+
+/// Finds the `LiquidStakePool` account of a validator stake account.
+pub fn find_liquid_stake_pool_pda(validator_stake: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &["liquid_stake_pool".as_bytes(), validator_stake.as_ref()],
+        &crate::ID,
+    )
+}
+
+/// Finds the `ReceiptRewards` account of a receipt token account.
+pub fn find_receipt_rewards_pda(
+    token_account: &Pubkey,
+    liquid_stake_pool: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            "receipt_rewards".as_bytes(),
+            token_account.as_ref(),
+            liquid_stake_pool.as_ref(),
+        ],
+        &crate::ID,
+    )
+}
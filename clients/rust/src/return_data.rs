@@ -0,0 +1,212 @@
+//! Parsing of the return data set by the stake program.
+//!
+//! Return data is written by the program as the 8-byte discriminator of its
+//! type followed by its `Pod` bytes. The types below mirror the on-chain
+//! layouts, without the discriminator.
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::pubkey::Pubkey;
+
+/// Rewards paid out by a harvest, set as return data by every instruction
+/// that harvests a stake account.
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Default, Eq, PartialEq)]
+pub struct HarvestReturnData {
+    pub staking_reward: u64,
+    pub holder_reward: u64,
+    pub keeper_reward: u64,
+    pub last_seen_stake_rewards_per_token: u128,
+    pub last_seen_holder_rewards_per_token: u128,
+}
+
+impl HarvestReturnData {
+    /// `sha256("stake::return_data::harvest::v1")[..8]`
+    pub const DISCRIMINATOR: [u8; 8] = [136, 146, 131, 167, 187, 130, 69, 128];
+
+    pub const LEN: usize = 56;
+
+    /// Total lamports harvested (net of the protocol fee).
+    ///
+    /// The keeper reward is paid out of this total, so the stake authority
+    /// receives `total_reward() - keeper_reward`.
+    pub fn total_reward(&self) -> u64 {
+        self.staking_reward.saturating_add(self.holder_reward)
+    }
+
+    #[inline(always)]
+    pub fn from_bytes(data: &[u8]) -> Result<Self, std::io::Error> {
+        Self::try_from_slice(data)
+    }
+
+    /// Parses the return data of a transaction or CPI.
+    ///
+    /// Returns `None` if the data was not set by the stake program or does not
+    /// start with the discriminator of the type.
+    pub fn from_return_data(program_id: &Pubkey, data: &[u8]) -> Option<Self> {
+        if program_id != &crate::ID {
+            return None;
+        }
+
+        data.strip_prefix(&Self::DISCRIMINATOR)
+            .and_then(|data| Self::from_bytes(data).ok())
+    }
+}
+
+/// Voting power of a stake account at a slot, set as return data by
+/// `GetVotingPower`.
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Default, Eq, PartialEq)]
+pub struct VotingPowerReturnData {
+    pub slot: u64,
+    pub voting_power: u64,
+}
+
+impl VotingPowerReturnData {
+    /// `sha256("stake::return_data::voting_power::v1")[..8]`
+    pub const DISCRIMINATOR: [u8; 8] = [111, 138, 71, 93, 127, 94, 214, 30];
+
+    pub const LEN: usize = 16;
+
+    #[inline(always)]
+    pub fn from_bytes(data: &[u8]) -> Result<Self, std::io::Error> {
+        Self::try_from_slice(data)
+    }
+
+    /// Parses the return data of a transaction or CPI.
+    ///
+    /// Returns `None` if the data was not set by the stake program or does not
+    /// start with the discriminator of the type.
+    pub fn from_return_data(program_id: &Pubkey, data: &[u8]) -> Option<Self> {
+        if program_id != &crate::ID {
+            return None;
+        }
+
+        data.strip_prefix(&Self::DISCRIMINATOR)
+            .and_then(|data| Self::from_bytes(data).ok())
+    }
+}
+
+/// Report of the accounting invariants of a config, set as return data by
+/// `AuditInvariants`.
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Default, Eq, PartialEq)]
+pub struct AuditReturnData {
+    pub stake_accounts: u64,
+    pub staked_amount: u64,
+    pub effective_amount: u64,
+    pub vault_amount: u64,
+    pub config_lamports: u64,
+    pub unpaid_rewards: u64,
+    pub required_lamports: u64,
+    pub failed_invariants: u64,
+}
+
+impl AuditReturnData {
+    /// `sha256("stake::return_data::audit::v1")[..8]`
+    pub const DISCRIMINATOR: [u8; 8] = [173, 5, 227, 90, 52, 216, 51, 162];
+
+    pub const LEN: usize = 64;
+
+    /// The vault holds fewer tokens than the audited stake accounts or than
+    /// the effective tokens of the config.
+    pub const VAULT_BALANCE: u64 = 1 << 0;
+
+    /// The audited stake accounts have more effective tokens than the config.
+    pub const TOKEN_AMOUNT_EFFECTIVE: u64 = 1 << 1;
+
+    /// A stake account has more effective tokens than its staked tokens or
+    /// than the limit for the SOL backing it.
+    pub const EFFECTIVE_CAP: u64 = 1 << 2;
+
+    /// The SOL staker stake accounts of a validator hold more lamports than
+    /// the total staked lamports of the validator stake account.
+    pub const VALIDATOR_LAMPORTS: u64 = 1 << 3;
+
+    /// The config account holds fewer lamports than required.
+    pub const CONFIG_LAMPORTS: u64 = 1 << 4;
+
+    /// Whether all the invariants hold.
+    pub fn is_consistent(&self) -> bool {
+        self.failed_invariants == 0
+    }
+
+    #[inline(always)]
+    pub fn from_bytes(data: &[u8]) -> Result<Self, std::io::Error> {
+        Self::try_from_slice(data)
+    }
+
+    /// Parses the return data of a transaction or CPI.
+    ///
+    /// Returns `None` if the data was not set by the stake program or does not
+    /// start with the discriminator of the type.
+    pub fn from_return_data(program_id: &Pubkey, data: &[u8]) -> Option<Self> {
+        if program_id != &crate::ID {
+            return None;
+        }
+
+        data.strip_prefix(&Self::DISCRIMINATOR)
+            .and_then(|data| Self::from_bytes(data).ok())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_return_data() {
+        let expected = HarvestReturnData {
+            staking_reward: 1,
+            holder_reward: 2,
+            keeper_reward: 3,
+            last_seen_stake_rewards_per_token: 4,
+            last_seen_holder_rewards_per_token: u128::MAX,
+        };
+        let data = [
+            HarvestReturnData::DISCRIMINATOR.as_slice(),
+            &expected.try_to_vec().unwrap(),
+        ]
+        .concat();
+
+        assert_eq!(data.len(), 8 + HarvestReturnData::LEN);
+        assert_eq!(
+            HarvestReturnData::from_return_data(&crate::ID, &data),
+            Some(expected)
+        );
+        assert_eq!(
+            HarvestReturnData::from_return_data(&Pubkey::new_unique(), &data),
+            None
+        );
+        assert_eq!(
+            HarvestReturnData::from_return_data(&crate::ID, &data[..data.len() - 1]),
+            None
+        );
+    }
+
+    #[test]
+    fn return_data_discriminators_are_distinct() {
+        let harvest = [
+            HarvestReturnData::DISCRIMINATOR.as_slice(),
+            &HarvestReturnData::default().try_to_vec().unwrap(),
+        ]
+        .concat();
+        let voting_power = [
+            VotingPowerReturnData::DISCRIMINATOR.as_slice(),
+            &VotingPowerReturnData::default().try_to_vec().unwrap(),
+        ]
+        .concat();
+        let audit = [
+            AuditReturnData::DISCRIMINATOR.as_slice(),
+            &AuditReturnData::default().try_to_vec().unwrap(),
+        ]
+        .concat();
+
+        // Each return data is rejected by the parsers of the others.
+        assert!(HarvestReturnData::from_return_data(&crate::ID, &audit).is_none());
+        assert!(HarvestReturnData::from_return_data(&crate::ID, &voting_power).is_none());
+        assert!(AuditReturnData::from_return_data(&crate::ID, &harvest).is_none());
+        assert!(AuditReturnData::from_return_data(&crate::ID, &voting_power).is_none());
+        assert!(VotingPowerReturnData::from_return_data(&crate::ID, &harvest).is_none());
+        assert!(VotingPowerReturnData::from_return_data(&crate::ID, &audit).is_none());
+
+        // The payload is rejected without the discriminator.
+        assert!(HarvestReturnData::from_return_data(&crate::ID, &harvest[8..]).is_none());
+    }
+}
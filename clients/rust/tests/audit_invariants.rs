@@ -0,0 +1,319 @@
+#![cfg(feature = "test-sbf")]
+
+mod setup;
+
+use borsh::BorshSerialize;
+use paladin_rewards_program_client::accounts::HolderRewards;
+use paladin_stake_program_client::{
+    accounts::{Config, SolStakerStake, ValidatorStake},
+    instructions::{AuditInvariantsBuilder, ValidatorStakeTokensBuilder},
+    return_data::AuditReturnData,
+};
+use setup::{
+    config::ConfigManager,
+    setup,
+    sol_staker_stake::SolStakerStakeManager,
+    token::mint_to,
+    validator_stake::{activate_validator_stake, ValidatorStakeManager},
+};
+use solana_program_test::{tokio, BanksClientError, ProgramTestContext};
+use solana_sdk::{
+    instruction::{AccountMeta, InstructionError},
+    program_pack::Pack,
+    pubkey::Pubkey,
+    signature::Signer,
+    transaction::Transaction,
+};
+use spl_token::state::Account as TokenAccount;
+
+struct Fixture {
+    config_manager: ConfigManager,
+    validator_stake: Pubkey,
+    sol_staker_stake: Pubkey,
+}
+
+/// Creates a config with a validator stake account (65 staked tokens) and a
+/// SOL staker stake account of the same validator (35 staked tokens, 5 SOL),
+/// with 100 effective tokens on the config.
+async fn fixture(context: &mut ProgramTestContext) -> Fixture {
+    let config_manager = ConfigManager::new(context).await;
+    let validator_stake_manager = ValidatorStakeManager::new(context, &config_manager.config).await;
+    let sol_staker_stake_manager = SolStakerStakeManager::new(
+        context,
+        &config_manager.config,
+        &validator_stake_manager.stake,
+        &validator_stake_manager.vote,
+        5_000_000_000, // 5 SOL staked
+    )
+    .await;
+
+    let mut account = get_account!(context, validator_stake_manager.stake);
+    let mut validator_stake = ValidatorStake::from_bytes(account.data.as_ref()).unwrap();
+    validator_stake.delegation.staked_amount = 65;
+    validator_stake.delegation.effective_amount = 65;
+    account.data = validator_stake.try_to_vec().unwrap();
+    context.set_account(&validator_stake_manager.stake, &account.into());
+
+    let mut account = get_account!(context, sol_staker_stake_manager.stake);
+    let mut sol_staker_stake = SolStakerStake::from_bytes(account.data.as_ref()).unwrap();
+    sol_staker_stake.delegation.staked_amount = 35;
+    sol_staker_stake.delegation.effective_amount = 35;
+    account.data = sol_staker_stake.try_to_vec().unwrap();
+    context.set_account(&sol_staker_stake_manager.stake, &account.into());
+
+    let mut account = get_account!(context, config_manager.config);
+    let mut config = Config::from_bytes(account.data.as_ref()).unwrap();
+    config.token_amount_effective = 100;
+    account.data = config.try_to_vec().unwrap();
+    context.set_account(&config_manager.config, &account.into());
+
+    Fixture {
+        config_manager,
+        validator_stake: validator_stake_manager.stake,
+        sol_staker_stake: sol_staker_stake_manager.stake,
+    }
+}
+
+/// Simulates the audit of the given stake accounts, returning its report.
+async fn audit(
+    context: &mut ProgramTestContext,
+    config_manager: &ConfigManager,
+    stakes: &[Pubkey],
+) -> Result<AuditReturnData, BanksClientError> {
+    let ix = AuditInvariantsBuilder::new()
+        .config(config_manager.config)
+        .vault(config_manager.vault)
+        .vault_holder_rewards(config_manager.vault_holder_rewards)
+        .add_remaining_accounts(
+            &stakes
+                .iter()
+                .map(|stake| AccountMeta::new_readonly(*stake, false))
+                .collect::<Vec<_>>(),
+        )
+        .instruction();
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    let simulation = context.banks_client.simulate_transaction(tx).await?;
+    if let Some(Err(err)) = simulation.result {
+        return Err(err.into());
+    }
+    let return_data = simulation.simulation_details.unwrap().return_data.unwrap();
+
+    Ok(AuditReturnData::from_return_data(&return_data.program_id, &return_data.data).unwrap())
+}
+
+#[tokio::test]
+async fn audit_consistent_accounts() {
+    let mut context = setup(&[]).await;
+
+    // Given a config with two stake accounts and a vault holding their 100
+    // staked tokens.
+    let fixture = fixture(&mut context).await;
+    let config_manager = &fixture.config_manager;
+    mint_to(
+        &mut context,
+        &config_manager.mint,
+        &config_manager.mint_authority,
+        &config_manager.vault,
+        100,
+    )
+    .await
+    .unwrap();
+
+    // When we audit the stake accounts.
+    let report = audit(
+        &mut context,
+        config_manager,
+        &[fixture.validator_stake, fixture.sol_staker_stake],
+    )
+    .await
+    .unwrap();
+
+    // Then all the invariants hold.
+    let config = get_account!(context, config_manager.config);
+    let rent = context.banks_client.get_rent().await.unwrap();
+    assert_eq!(
+        report,
+        AuditReturnData {
+            stake_accounts: 2,
+            staked_amount: 100,
+            effective_amount: 100,
+            vault_amount: 100,
+            config_lamports: config.lamports,
+            unpaid_rewards: 0,
+            required_lamports: rent.minimum_balance(config.data.len()),
+            failed_invariants: 0,
+        }
+    );
+    assert!(report.is_consistent());
+}
+
+#[tokio::test]
+async fn audit_staked_tokens_deposited_on_rewards_program() {
+    let mut context = setup(&[]).await;
+
+    // Given a config account and a validator stake with 50 SOL staked.
+    let config_manager = ConfigManager::new(&mut context).await;
+    let validator_stake_manager =
+        ValidatorStakeManager::new(&mut context, &config_manager.config).await;
+
+    let mut account = get_account!(context, validator_stake_manager.stake);
+    let mut validator_stake = ValidatorStake::from_bytes(account.data.as_ref()).unwrap();
+    validator_stake.total_staked_lamports_amount = 50;
+    account.data = validator_stake.try_to_vec().unwrap();
+    context.set_account(&validator_stake_manager.stake, &account.into());
+
+    // And 65 tokens staked through the program, which deposits them on the
+    // rewards program.
+    mint_to(
+        &mut context,
+        &config_manager.mint,
+        &config_manager.mint_authority,
+        &config_manager.rewards_manager.owner_token_account,
+        65,
+    )
+    .await
+    .unwrap();
+    let stake_ix = ValidatorStakeTokensBuilder::new()
+        .config(config_manager.config)
+        .holder_rewards_pool(config_manager.rewards_manager.pool)
+        .holder_rewards_pool_token_account(config_manager.rewards_manager.pool_token_account)
+        .validator_stake(validator_stake_manager.stake)
+        .validator_stake_authority(validator_stake_manager.authority.pubkey())
+        .source_token_account(config_manager.rewards_manager.owner_token_account)
+        .source_token_account_authority(config_manager.rewards_manager.owner.pubkey())
+        .mint(config_manager.mint)
+        .vault(config_manager.vault)
+        .vault_pda(config_manager.vault_pda)
+        .vault_holder_rewards(config_manager.vault_holder_rewards)
+        .token_program(spl_token::ID)
+        .rewards_program(paladin_rewards_program_client::ID)
+        .amount(65)
+        .instruction();
+    let tx = Transaction::new_signed_with_payer(
+        &[stake_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &config_manager.rewards_manager.owner],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+    activate_validator_stake(&mut context, &config_manager, &validator_stake_manager).await;
+
+    let account = get_account!(context, config_manager.vault);
+    assert_eq!(TokenAccount::unpack(&account.data).unwrap().amount, 0);
+    let account = get_account!(context, config_manager.vault_holder_rewards);
+    assert_eq!(
+        HolderRewards::from_bytes(&account.data).unwrap().deposited,
+        65
+    );
+
+    // When we audit the validator stake account.
+    let report = audit(
+        &mut context,
+        &config_manager,
+        &[validator_stake_manager.stake],
+    )
+    .await
+    .unwrap();
+
+    // Then the vault balance counts the deposited tokens once and all the
+    // invariants hold.
+    assert_eq!(report.stake_accounts, 1);
+    assert_eq!(report.staked_amount, 65);
+    assert_eq!(report.effective_amount, 65);
+    assert_eq!(report.vault_amount, 65);
+    assert_eq!(report.failed_invariants, 0);
+    assert!(report.is_consistent());
+}
+
+#[tokio::test]
+async fn audit_reports_failed_invariants() {
+    let mut context = setup(&[]).await;
+
+    // Given a config with two stake accounts and an empty vault.
+    let fixture = fixture(&mut context).await;
+    let config_manager = &fixture.config_manager;
+
+    // And a validator stake account without the lamports of its SOL staker.
+    let mut account = get_account!(context, fixture.validator_stake);
+    let mut validator_stake = ValidatorStake::from_bytes(account.data.as_ref()).unwrap();
+    validator_stake.total_staked_lamports_amount = 0;
+    account.data = validator_stake.try_to_vec().unwrap();
+    context.set_account(&fixture.validator_stake, &account.into());
+
+    // And a config with fewer effective tokens and more undistributed rewards
+    // than its lamports.
+    let mut account = get_account!(context, config_manager.config);
+    let mut config = Config::from_bytes(account.data.as_ref()).unwrap();
+    config.token_amount_effective = 50;
+    config.undistributed_rewards = account.lamports;
+    account.data = config.try_to_vec().unwrap();
+    context.set_account(&config_manager.config, &account.into());
+
+    // When we audit the stake accounts.
+    let report = audit(
+        &mut context,
+        config_manager,
+        &[fixture.validator_stake, fixture.sol_staker_stake],
+    )
+    .await
+    .unwrap();
+
+    // Then every invariant fails.
+    assert_eq!(report.vault_amount, 0);
+    assert_eq!(
+        report.failed_invariants,
+        AuditReturnData::VAULT_BALANCE
+            | AuditReturnData::TOKEN_AMOUNT_EFFECTIVE
+            | AuditReturnData::EFFECTIVE_CAP
+            | AuditReturnData::VALIDATOR_LAMPORTS
+            | AuditReturnData::CONFIG_LAMPORTS
+    );
+    assert!(!report.is_consistent());
+}
+
+#[tokio::test]
+async fn fail_audit_with_duplicate_stake_account() {
+    let mut context = setup(&[]).await;
+
+    // Given a config with two stake accounts.
+    let fixture = fixture(&mut context).await;
+
+    // When we audit the same stake account twice.
+    let err = audit(
+        &mut context,
+        &fixture.config_manager,
+        &[fixture.validator_stake, fixture.validator_stake],
+    )
+    .await
+    .unwrap_err();
+
+    // Then we expect an error.
+    assert_instruction_error!(err, InstructionError::InvalidArgument);
+}
+
+#[tokio::test]
+async fn fail_audit_with_stake_account_of_another_config() {
+    let mut context = setup(&[]).await;
+
+    // Given a config with two stake accounts and another config.
+    let fixture = fixture(&mut context).await;
+    let other_config_manager = ConfigManager::new(&mut context).await;
+
+    // When we audit the stake accounts against the other config.
+    let err = audit(
+        &mut context,
+        &other_config_manager,
+        &[fixture.validator_stake],
+    )
+    .await
+    .unwrap_err();
+
+    // Then we expect an error.
+    assert_instruction_error!(err, InstructionError::InvalidSeeds);
+}
@@ -0,0 +1,302 @@
+#![cfg(feature = "test-sbf")]
+
+mod setup;
+
+use borsh::BorshSerialize;
+use paladin_stake_program::cpi::{
+    HarvestValidatorRewardsCpi, UnstakeTokensCpi, ValidatorStakeTokensCpi,
+};
+use paladin_stake_program_client::{
+    accounts::ValidatorStake,
+    instructions::{
+        HarvestValidatorRewardsBuilder, UnstakeTokensBuilder, ValidatorStakeTokensBuilder,
+    },
+    return_data::HarvestReturnData,
+};
+use setup::{
+    config::{create_ata, ConfigManager},
+    program_test,
+    stake::warp_to_next_epoch,
+    start,
+    token::mint_to,
+    validator_stake::ValidatorStakeManager,
+};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError, pubkey,
+};
+use solana_program_test::{processor, tokio, ProgramTestContext};
+use solana_sdk::{
+    instruction::{AccountMeta, Instruction},
+    program_pack::Pack,
+    pubkey::Pubkey,
+    signature::Signer,
+    transaction::Transaction,
+};
+use spl_associated_token_account::get_associated_token_address;
+use spl_token::state::Account as TokenAccount;
+
+/// Test program invoking the stake program through the `cpi` helpers.
+const CALLER_ID: Pubkey = pubkey!("2FaD14APAnN9hq855BNv2jpS2mCHvQvTaKTJfVPbvj2R");
+
+const STAKE: u8 = 0;
+const HARVEST: u8 = 1;
+const UNSTAKE: u8 = 2;
+
+/// Processor of the test program.
+///
+/// The first account is the stake program, followed by the accounts of the
+/// stake instruction. The instruction data is a tag (stake, harvest or
+/// unstake) followed by the amount of tokens, if any.
+fn process_caller_instruction(
+    _program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let Some((&tag, data)) = instruction_data.split_first() else {
+        return Err(ProgramError::InvalidInstructionData);
+    };
+    let amount = || {
+        data.try_into()
+            .map(u64::from_le_bytes)
+            .map_err(|_| ProgramError::InvalidInstructionData)
+    };
+    let Some((program, accounts)) = accounts.split_first() else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    match tag {
+        STAKE => {
+            let [config, holder_rewards_pool, holder_rewards_pool_token_account, validator_stake, validator_stake_authority, source_token_account, source_token_account_authority, mint, vault_pda, vault, vault_holder_rewards, token_program, rewards_program, remaining_accounts @ ..] =
+                accounts
+            else {
+                return Err(ProgramError::NotEnoughAccountKeys);
+            };
+            ValidatorStakeTokensCpi {
+                program,
+                config,
+                holder_rewards_pool,
+                holder_rewards_pool_token_account,
+                validator_stake,
+                validator_stake_authority,
+                source_token_account,
+                source_token_account_authority,
+                mint,
+                vault_pda,
+                vault,
+                vault_holder_rewards,
+                token_program,
+                rewards_program,
+                remaining_accounts,
+                amount: amount()?,
+            }
+            .invoke()
+        }
+        HARVEST => {
+            let [config, vault_holder_rewards, validator_stake, validator_stake_authority, remaining_accounts @ ..] =
+                accounts
+            else {
+                return Err(ProgramError::NotEnoughAccountKeys);
+            };
+            HarvestValidatorRewardsCpi {
+                program,
+                config,
+                vault_holder_rewards,
+                validator_stake,
+                validator_stake_authority,
+                remaining_accounts,
+            }
+            .invoke()
+        }
+        UNSTAKE => {
+            let [config, holder_rewards_pool, holder_rewards_pool_token_account, stake, stake_authority, vault, vault_pda, vault_holder_rewards, mint, destination_token_account, token_program, rewards_program, remaining_accounts @ ..] =
+                accounts
+            else {
+                return Err(ProgramError::NotEnoughAccountKeys);
+            };
+            UnstakeTokensCpi {
+                program,
+                config,
+                holder_rewards_pool,
+                holder_rewards_pool_token_account,
+                stake,
+                stake_authority,
+                vault,
+                vault_pda,
+                vault_holder_rewards,
+                mint,
+                destination_token_account,
+                token_program,
+                rewards_program,
+                remaining_accounts,
+                amount: amount()?,
+            }
+            .invoke()
+        }
+        _ => Err(ProgramError::InvalidInstructionData),
+    }
+}
+
+/// Wraps a stake program instruction into an instruction of the test program.
+fn caller_ix(tag: u8, amount: Option<u64>, instruction: Instruction) -> Instruction {
+    let mut data = vec![tag];
+    if let Some(amount) = amount {
+        data.extend_from_slice(&amount.to_le_bytes());
+    }
+
+    let mut accounts = vec![AccountMeta::new_readonly(instruction.program_id, false)];
+    accounts.extend(instruction.accounts);
+
+    Instruction {
+        program_id: CALLER_ID,
+        accounts,
+        data,
+    }
+}
+
+async fn setup_caller() -> ProgramTestContext {
+    let mut program_test = program_test(&[]);
+    program_test.add_program(
+        "paladin_stake_caller",
+        CALLER_ID,
+        processor!(process_caller_instruction),
+    );
+    start(program_test).await
+}
+
+#[tokio::test]
+async fn stake_harvest_and_unstake_through_cpi() {
+    let mut context = setup_caller().await;
+
+    // Given a config account and a validator stake account with 1 SOL staked.
+    let config_manager = ConfigManager::new(&mut context).await;
+    let validator_stake_manager =
+        ValidatorStakeManager::new(&mut context, &config_manager.config).await;
+
+    let mut account = get_account!(context, validator_stake_manager.stake);
+    let mut stake_account = ValidatorStake::from_bytes(account.data.as_ref()).unwrap();
+    stake_account.total_staked_lamports_amount = 1_000_000_000;
+    account.data = stake_account.try_to_vec().unwrap();
+    context.set_account(&validator_stake_manager.stake, &account.into());
+
+    // And a token account of the validator authority with 100 tokens.
+    let token_account = get_associated_token_address(
+        &validator_stake_manager.authority.pubkey(),
+        &config_manager.mint,
+    );
+    create_ata(
+        &mut context,
+        &validator_stake_manager.authority.pubkey(),
+        &config_manager.mint,
+    )
+    .await
+    .unwrap();
+    mint_to(
+        &mut context,
+        &config_manager.mint,
+        &config_manager.mint_authority,
+        &token_account,
+        100,
+    )
+    .await
+    .unwrap();
+
+    // When the caller program stakes 100 tokens.
+    let ix = ValidatorStakeTokensBuilder::new()
+        .config(config_manager.config)
+        .holder_rewards_pool(config_manager.rewards_manager.pool)
+        .holder_rewards_pool_token_account(config_manager.rewards_manager.pool_token_account)
+        .validator_stake(validator_stake_manager.stake)
+        .validator_stake_authority(validator_stake_manager.authority.pubkey())
+        .source_token_account(token_account)
+        .source_token_account_authority(validator_stake_manager.authority.pubkey())
+        .mint(config_manager.mint)
+        .vault(config_manager.vault)
+        .vault_pda(config_manager.vault_pda)
+        .vault_holder_rewards(config_manager.vault_holder_rewards)
+        .token_program(spl_token::ID)
+        .rewards_program(paladin_rewards_program_client::ID)
+        .amount(100)
+        .instruction();
+    let tx = Transaction::new_signed_with_payer(
+        &[caller_ix(STAKE, Some(100), ix)],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &validator_stake_manager.authority],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // Then the tokens are staked.
+    let account = get_account!(context, validator_stake_manager.stake);
+    let stake_account = ValidatorStake::from_bytes(account.data.as_ref()).unwrap();
+    assert_eq!(stake_account.delegation.staked_amount, 100);
+
+    // And when the caller program harvests in the next epoch.
+    warp_to_next_epoch(&mut context).await;
+
+    let ix = HarvestValidatorRewardsBuilder::new()
+        .config(config_manager.config)
+        .vault_holder_rewards(config_manager.vault_holder_rewards)
+        .validator_stake(validator_stake_manager.stake)
+        .validator_stake_authority(validator_stake_manager.authority.pubkey())
+        .instruction();
+    let tx = Transaction::new_signed_with_payer(
+        &[caller_ix(HARVEST, None, ix)],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    let return_data = context
+        .banks_client
+        .process_transaction_with_metadata(tx)
+        .await
+        .unwrap()
+        .metadata
+        .unwrap()
+        .return_data
+        .unwrap();
+
+    // Then the staked tokens are activated.
+    let account = get_account!(context, validator_stake_manager.stake);
+    let stake_account = ValidatorStake::from_bytes(account.data.as_ref()).unwrap();
+    assert_eq!(stake_account.delegation.effective_amount, 100);
+
+    // And the harvest return data of the stake program is available.
+    assert!(
+        HarvestReturnData::from_return_data(&return_data.program_id, &return_data.data).is_some()
+    );
+
+    // And when the caller program unstakes 5 tokens.
+    let ix = UnstakeTokensBuilder::new()
+        .config(config_manager.config)
+        .holder_rewards_pool(config_manager.rewards_manager.pool)
+        .holder_rewards_pool_token_account(config_manager.rewards_manager.pool_token_account)
+        .stake(validator_stake_manager.stake)
+        .stake_authority(validator_stake_manager.authority.pubkey())
+        .vault(config_manager.vault)
+        .vault_pda(config_manager.vault_pda)
+        .vault_holder_rewards(config_manager.vault_holder_rewards)
+        .mint(config_manager.mint)
+        .destination_token_account(token_account)
+        .token_program(spl_token::ID)
+        .rewards_program(paladin_rewards_program_client::ID)
+        .amount(5)
+        .instruction();
+    let tx = Transaction::new_signed_with_payer(
+        &[caller_ix(UNSTAKE, Some(5), ix)],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &validator_stake_manager.authority],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // Then the stake has 95 tokens.
+    let account = get_account!(context, validator_stake_manager.stake);
+    let stake_account = ValidatorStake::from_bytes(account.data.as_ref()).unwrap();
+    assert_eq!(stake_account.delegation.staked_amount, 95);
+    assert_eq!(stake_account.delegation.effective_amount, 95);
+
+    // And the token account has 5 tokens back.
+    let account = get_account!(context, token_account);
+    let account = TokenAccount::unpack(&account.data).unwrap();
+    assert_eq!(u64::from(account.amount), 5);
+}
@@ -0,0 +1,176 @@
+#![cfg(feature = "test-sbf")]
+
+mod setup;
+
+use paladin_stake_program_client::{
+    accounts::DelegatedVotingPower,
+    events::{parse_events, AuthorityChangeEvent, StakeEvent, UpdateConfigEvent},
+    instructions::{
+        InitializeDelegatedVotingPowerBuilder, SetAuthorityBuilder, SetVoteDelegateBuilder,
+        UpdateConfigBuilder,
+    },
+    pdas::find_delegated_voting_power_pda,
+    types::{AuthorityType, ConfigField},
+};
+use setup::{config::ConfigManager, setup, validator_stake::ValidatorStakeManager};
+use solana_program_test::tokio;
+use solana_sdk::{
+    instruction::AccountMeta,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    system_instruction,
+    transaction::Transaction,
+};
+
+#[tokio::test]
+async fn update_config_emits_event() {
+    let mut context = setup(&[]).await;
+    let config_manager = ConfigManager::new(&mut context).await;
+
+    // When we update the sync rewards lamports.
+
+    let ix = UpdateConfigBuilder::new()
+        .config(config_manager.config)
+        .config_authority(config_manager.config_authority.pubkey())
+        .config_field(ConfigField::SyncRewardsLamports(5_000_000))
+        .instruction();
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &config_manager.config_authority],
+        context.last_blockhash,
+    );
+    let metadata = context
+        .banks_client
+        .process_transaction_with_metadata(tx)
+        .await
+        .unwrap()
+        .metadata
+        .unwrap();
+
+    // Then an update config event is emitted.
+
+    assert_eq!(
+        parse_events(&metadata.log_messages),
+        vec![StakeEvent::UpdateConfig(UpdateConfigEvent {
+            config: config_manager.config,
+            field: 2,
+            padding: [0; 7],
+            value: 5_000_000,
+        })]
+    );
+}
+
+#[tokio::test]
+async fn set_authority_emits_event() {
+    let mut context = setup(&[]).await;
+    let config_manager = ConfigManager::new(&mut context).await;
+    let new_authority = Keypair::new();
+
+    // When we set a new config authority.
+
+    let ix = SetAuthorityBuilder::new()
+        .account(config_manager.config)
+        .authority(config_manager.config_authority.pubkey())
+        .new_authority(new_authority.pubkey())
+        .authority_type(AuthorityType::Config)
+        .instruction();
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &config_manager.config_authority],
+        context.last_blockhash,
+    );
+    let metadata = context
+        .banks_client
+        .process_transaction_with_metadata(tx)
+        .await
+        .unwrap()
+        .metadata
+        .unwrap();
+
+    // Then an authority change event is emitted.
+
+    assert_eq!(
+        parse_events(&metadata.log_messages),
+        vec![StakeEvent::AuthorityChange(AuthorityChangeEvent {
+            account: config_manager.config,
+            previous_authority: config_manager.config_authority.pubkey(),
+            new_authority: new_authority.pubkey(),
+            kind: 0,
+            padding: [0; 7],
+        })]
+    );
+}
+
+#[tokio::test]
+async fn set_vote_delegate_emits_event() {
+    let mut context = setup(&[]).await;
+    let config_manager = ConfigManager::new(&mut context).await;
+    let validator_stake_manager =
+        ValidatorStakeManager::new(&mut context, &config_manager.config).await;
+
+    // And a delegated voting power account for the delegate.
+
+    let delegate = Keypair::new();
+    let (delegated_voting_power, _) =
+        find_delegated_voting_power_pda(&delegate.pubkey(), &config_manager.config);
+    let rent = context.banks_client.get_rent().await.unwrap();
+    let transfer_ix = system_instruction::transfer(
+        &context.payer.pubkey(),
+        &delegated_voting_power,
+        rent.minimum_balance(DelegatedVotingPower::LEN),
+    );
+    let initialize_ix = InitializeDelegatedVotingPowerBuilder::new()
+        .config(config_manager.config)
+        .vote_delegate(delegate.pubkey())
+        .delegated_voting_power(delegated_voting_power)
+        .instruction();
+    let tx = Transaction::new_signed_with_payer(
+        &[transfer_ix, initialize_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // When we set the vote delegate of the stake account.
+
+    let ix = SetVoteDelegateBuilder::new()
+        .config(config_manager.config)
+        .stake(validator_stake_manager.stake)
+        .stake_authority(validator_stake_manager.authority.pubkey())
+        .vote_delegate(Some(delegate.pubkey()))
+        .add_remaining_account(AccountMeta::new(delegated_voting_power, false))
+        .instruction();
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[
+            &context.payer,
+            &validator_stake_manager.authority,
+            &delegate,
+        ],
+        context.last_blockhash,
+    );
+    let metadata = context
+        .banks_client
+        .process_transaction_with_metadata(tx)
+        .await
+        .unwrap()
+        .metadata
+        .unwrap();
+
+    // Then an authority change event is emitted for the vote delegate.
+
+    assert_eq!(
+        parse_events(&metadata.log_messages),
+        vec![StakeEvent::AuthorityChange(AuthorityChangeEvent {
+            account: validator_stake_manager.stake,
+            previous_authority: Pubkey::default(),
+            new_authority: delegate.pubkey(),
+            kind: 4,
+            padding: [0; 7],
+        })]
+    );
+}
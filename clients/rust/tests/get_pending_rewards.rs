@@ -0,0 +1,181 @@
+#![cfg(feature = "test-sbf")]
+
+mod setup;
+
+use borsh::BorshSerialize;
+use paladin_stake_program_client::{
+    accounts::{Config, ValidatorStake},
+    instructions::{GetPendingRewardsBuilder, HarvestValidatorRewardsBuilder},
+    return_data::HarvestReturnData,
+};
+use setup::{config::ConfigManager, setup, validator_stake::ValidatorStakeManager};
+use solana_program_test::tokio;
+use solana_sdk::{
+    account::{Account, AccountSharedData},
+    instruction::InstructionError,
+    pubkey::Pubkey,
+    signature::Signer,
+    transaction::Transaction,
+};
+
+#[tokio::test]
+async fn get_pending_rewards_matches_harvest() {
+    let mut context = setup(&[]).await;
+
+    // Given a config account with 130 effective staked amount and 26 lamports
+    // of rewards that were not synced yet.
+    let config_manager = ConfigManager::new(&mut context).await;
+    let config = config_manager.config;
+
+    let mut account = get_account!(context, config);
+    let mut config_account = Config::from_bytes(account.data.as_ref()).unwrap();
+    config_account.token_amount_effective = 130;
+    config_account.lamports_last = account.lamports;
+    account.lamports += 26;
+    account.data = config_account.try_to_vec().unwrap();
+    context.set_account(&config, &account.into());
+
+    // And a validator stake account with a 65 staked amount.
+    let validator_stake_manager = ValidatorStakeManager::new(&mut context, &config).await;
+
+    let mut account = get_account!(context, validator_stake_manager.stake);
+    let mut stake_account = ValidatorStake::from_bytes(account.data.as_ref()).unwrap();
+    stake_account.delegation.staked_amount = 65;
+    stake_account.delegation.effective_amount = 65;
+    stake_account.total_staked_lamports_amount = 50;
+    account.data = stake_account.try_to_vec().unwrap();
+    context.set_account(&validator_stake_manager.stake, &account.into());
+
+    context.set_account(
+        &validator_stake_manager.authority.pubkey(),
+        &AccountSharedData::from(Account {
+            lamports: 100_000_000,
+            ..Default::default()
+        }),
+    );
+
+    // When we simulate the pending rewards instruction.
+    //
+    // We are expecting the rewards to be 13 lamports (26 / 130 * 65).
+    let ix = GetPendingRewardsBuilder::new()
+        .config(config)
+        .vault_holder_rewards(config_manager.vault_holder_rewards)
+        .stake(validator_stake_manager.stake)
+        .instruction();
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    let simulation = context.banks_client.simulate_transaction(tx).await.unwrap();
+    simulation.result.unwrap().unwrap();
+    let return_data = simulation.simulation_details.unwrap().return_data.unwrap();
+    let pending =
+        HarvestReturnData::from_return_data(&return_data.program_id, &return_data.data).unwrap();
+
+    assert_eq!(pending.staking_reward, 13);
+    assert_eq!(pending.holder_reward, 0);
+    assert_eq!(pending.keeper_reward, 0);
+
+    // Then harvesting pays out exactly the pending amounts.
+    let ix = HarvestValidatorRewardsBuilder::new()
+        .config(config)
+        .vault_holder_rewards(config_manager.vault_holder_rewards)
+        .validator_stake(validator_stake_manager.stake)
+        .validator_stake_authority(validator_stake_manager.authority.pubkey())
+        .instruction();
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    let return_data = context
+        .banks_client
+        .process_transaction_with_metadata(tx)
+        .await
+        .unwrap()
+        .metadata
+        .unwrap()
+        .return_data
+        .unwrap();
+
+    assert_eq!(
+        HarvestReturnData::from_return_data(&return_data.program_id, &return_data.data),
+        Some(pending)
+    );
+    let account = get_account!(context, validator_stake_manager.authority.pubkey());
+    assert_eq!(account.lamports, 100_000_000 + 13);
+}
+
+#[tokio::test]
+async fn fail_get_pending_rewards_with_wrong_config_account() {
+    let mut context = setup(&[]).await;
+
+    // Given two config accounts and a validator stake account for the first.
+    let config_manager = ConfigManager::new(&mut context).await;
+    let other_config_manager = ConfigManager::new(&mut context).await;
+    let validator_stake_manager =
+        ValidatorStakeManager::new(&mut context, &config_manager.config).await;
+
+    // When we get the pending rewards using the wrong config account.
+    let ix = GetPendingRewardsBuilder::new()
+        .config(other_config_manager.config)
+        .vault_holder_rewards(other_config_manager.vault_holder_rewards)
+        .stake(validator_stake_manager.stake)
+        .instruction();
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    let err = context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .unwrap_err();
+
+    // Then we expect an error.
+    assert_instruction_error!(err, InstructionError::InvalidSeeds);
+}
+
+#[tokio::test]
+async fn fail_get_pending_rewards_with_uninitialized_stake_account() {
+    let mut context = setup(&[]).await;
+
+    // Given a config account and an uninitialized stake account.
+    let config_manager = ConfigManager::new(&mut context).await;
+    let stake = Pubkey::new_unique();
+    context.set_account(
+        &stake,
+        &AccountSharedData::from(Account {
+            lamports: 100_000_000,
+            data: vec![0; ValidatorStake::LEN],
+            owner: paladin_stake_program_client::ID,
+            ..Default::default()
+        }),
+    );
+
+    // When we get the pending rewards for the uninitialized account.
+    let ix = GetPendingRewardsBuilder::new()
+        .config(config_manager.config)
+        .vault_holder_rewards(config_manager.vault_holder_rewards)
+        .stake(stake)
+        .instruction();
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    let err = context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .unwrap_err();
+
+    // Then we expect an error.
+    assert_instruction_error!(err, InstructionError::InvalidAccountData);
+}
@@ -0,0 +1,314 @@
+#![cfg(feature = "test-sbf")]
+
+mod setup;
+
+use borsh::BorshSerialize;
+use paladin_stake_program_client::{
+    accounts::ValidatorStake,
+    errors::PaladinStakeProgramError,
+    instructions::{GetVotingPowerBuilder, ValidatorStakeTokensBuilder},
+    return_data::VotingPowerReturnData,
+};
+use setup::{
+    config::ConfigManager,
+    setup,
+    token::mint_to,
+    validator_stake::{activate_validator_stake, ValidatorStakeManager},
+};
+use solana_program_test::{tokio, BanksClientError, ProgramTestContext};
+use solana_sdk::{clock::Clock, pubkey::Pubkey, signature::Signer, transaction::Transaction};
+
+/// Simulates `GetVotingPower` and returns the voting power set as return data.
+async fn get_voting_power(
+    context: &mut ProgramTestContext,
+    config: Pubkey,
+    stake: Pubkey,
+    slot: u64,
+) -> u64 {
+    let ix = GetVotingPowerBuilder::new()
+        .config(config)
+        .stake(stake)
+        .slot(slot)
+        .instruction();
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    let simulation = context.banks_client.simulate_transaction(tx).await.unwrap();
+    simulation.result.unwrap().unwrap();
+    let return_data = simulation.simulation_details.unwrap().return_data.unwrap();
+    let voting_power =
+        VotingPowerReturnData::from_return_data(&return_data.program_id, &return_data.data)
+            .unwrap();
+
+    assert_eq!(voting_power.slot, slot);
+    voting_power.voting_power
+}
+
+/// Sends `GetVotingPower` and returns the error of the transaction.
+async fn get_voting_power_error(
+    context: &mut ProgramTestContext,
+    config: Pubkey,
+    stake: Pubkey,
+    slot: u64,
+) -> BanksClientError {
+    let ix = GetVotingPowerBuilder::new()
+        .config(config)
+        .stake(stake)
+        .slot(slot)
+        .instruction();
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .unwrap_err()
+}
+
+#[tokio::test]
+async fn get_voting_power_before_and_after_staking() {
+    let mut context = setup(&[]).await;
+
+    // Given a config and a validator stake account with 50 SOL staked.
+    let config_manager = ConfigManager::new(&mut context).await;
+    let validator_stake_manager =
+        ValidatorStakeManager::new(&mut context, &config_manager.config).await;
+
+    let mut account = get_account!(context, validator_stake_manager.stake);
+    let mut stake_account = ValidatorStake::from_bytes(account.data.as_ref()).unwrap();
+    stake_account.total_staked_lamports_amount = 50;
+    account.data = stake_account.try_to_vec().unwrap();
+    context.set_account(&validator_stake_manager.stake, &account.into());
+
+    let before = context
+        .banks_client
+        .get_sysvar::<Clock>()
+        .await
+        .unwrap()
+        .slot;
+
+    // When we stake 65 tokens on a later slot.
+    context.warp_to_slot(before + 10).unwrap();
+
+    mint_to(
+        &mut context,
+        &config_manager.mint,
+        &config_manager.mint_authority,
+        &config_manager.rewards_manager.owner_token_account,
+        65,
+    )
+    .await
+    .unwrap();
+
+    let stake_ix = ValidatorStakeTokensBuilder::new()
+        .config(config_manager.config)
+        .holder_rewards_pool(config_manager.rewards_manager.pool)
+        .holder_rewards_pool_token_account(config_manager.rewards_manager.pool_token_account)
+        .validator_stake(validator_stake_manager.stake)
+        .validator_stake_authority(validator_stake_manager.authority.pubkey())
+        .source_token_account(config_manager.rewards_manager.owner_token_account)
+        .source_token_account_authority(config_manager.rewards_manager.owner.pubkey())
+        .mint(config_manager.mint)
+        .vault(config_manager.vault)
+        .vault_pda(config_manager.vault_pda)
+        .vault_holder_rewards(config_manager.vault_holder_rewards)
+        .token_program(spl_token::ID)
+        .rewards_program(paladin_rewards_program_client::ID)
+        .amount(65)
+        .instruction();
+    let tx = Transaction::new_signed_with_payer(
+        &[stake_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &config_manager.rewards_manager.owner],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // And the tokens are activated on the next epoch.
+    activate_validator_stake(&mut context, &config_manager, &validator_stake_manager).await;
+
+    let activated = context
+        .banks_client
+        .get_sysvar::<Clock>()
+        .await
+        .unwrap()
+        .slot;
+
+    // Then the checkpoint is recorded at the slot of the activation.
+    let account = get_account!(context, validator_stake_manager.stake);
+    let stake_account = ValidatorStake::from_bytes(account.data.as_ref()).unwrap();
+    assert_eq!(stake_account.checkpoints.count, 1);
+    assert_eq!(stake_account.checkpoints.checkpoints[0].slot, activated);
+    assert_eq!(
+        stake_account.checkpoints.checkpoints[0].effective_amount,
+        65
+    );
+
+    // And the voting power reflects the effective amount at each past slot.
+    context.warp_to_slot(activated + 10).unwrap();
+
+    let config = config_manager.config;
+    let stake = validator_stake_manager.stake;
+    assert_eq!(
+        get_voting_power(&mut context, config, stake, before).await,
+        0
+    );
+    assert_eq!(
+        get_voting_power(&mut context, config, stake, activated - 1).await,
+        0
+    );
+    assert_eq!(
+        get_voting_power(&mut context, config, stake, activated).await,
+        65
+    );
+    assert_eq!(
+        get_voting_power(&mut context, config, stake, activated + 9).await,
+        65
+    );
+}
+
+#[tokio::test]
+async fn get_voting_power_with_merged_history() {
+    let mut context = setup(&[]).await;
+
+    // Given a validator stake account with more changes than the checkpoints
+    // it can hold (the checkpoints at slots 10 and 20 were merged into one
+    // holding the lower amount).
+    let config_manager = ConfigManager::new(&mut context).await;
+    let validator_stake_manager =
+        ValidatorStakeManager::new(&mut context, &config_manager.config).await;
+
+    let mut account = get_account!(context, validator_stake_manager.stake);
+    let mut stake_account = ValidatorStake::from_bytes(account.data.as_ref()).unwrap();
+    let len = stake_account.checkpoints.checkpoints.len() as u64;
+    for (index, checkpoint) in stake_account.checkpoints.checkpoints.iter_mut().enumerate() {
+        // Slots 170, 10 (merged), 30, ..., 160.
+        let slot = match index {
+            0 => len + 1,
+            1 => 1,
+            _ => index as u64 + 1,
+        };
+        checkpoint.slot = slot * 10;
+        checkpoint.effective_amount = slot;
+    }
+    stake_account.checkpoints.count = len + 1;
+    account.data = stake_account.try_to_vec().unwrap();
+    context.set_account(&validator_stake_manager.stake, &account.into());
+    context.warp_to_slot(200).unwrap();
+
+    // When we query the voting power within the merged checkpoints.
+    let voting_power = get_voting_power(
+        &mut context,
+        config_manager.config,
+        validator_stake_manager.stake,
+        25,
+    )
+    .await;
+
+    // Then we expect the lower amount of the merged checkpoints.
+    assert_eq!(voting_power, 1);
+
+    // And the history after the merged checkpoints is exact.
+    assert_eq!(
+        get_voting_power(
+            &mut context,
+            config_manager.config,
+            validator_stake_manager.stake,
+            35,
+        )
+        .await,
+        3
+    );
+    assert_eq!(
+        get_voting_power(
+            &mut context,
+            config_manager.config,
+            validator_stake_manager.stake,
+            5,
+        )
+        .await,
+        0
+    );
+}
+
+#[tokio::test]
+async fn fail_get_voting_power_at_current_slot() {
+    let mut context = setup(&[]).await;
+
+    // Given a validator stake account.
+    let config_manager = ConfigManager::new(&mut context).await;
+    let validator_stake_manager =
+        ValidatorStakeManager::new(&mut context, &config_manager.config).await;
+    context.warp_to_slot(100).unwrap();
+
+    let current = context
+        .banks_client
+        .get_sysvar::<Clock>()
+        .await
+        .unwrap()
+        .slot;
+
+    // When we query the voting power at the current slot, whose end is not
+    // known yet.
+    let err = get_voting_power_error(
+        &mut context,
+        config_manager.config,
+        validator_stake_manager.stake,
+        current,
+    )
+    .await;
+
+    // Then we expect an error.
+    assert_custom_error!(err, PaladinStakeProgramError::VotingPowerUnavailable);
+
+    // And the previous slot can be queried.
+    assert_eq!(
+        get_voting_power(
+            &mut context,
+            config_manager.config,
+            validator_stake_manager.stake,
+            current - 1,
+        )
+        .await,
+        0
+    );
+}
+
+#[tokio::test]
+async fn fail_get_voting_power_of_legacy_stake() {
+    let mut context = setup(&[]).await;
+
+    // Given a validator stake account with 65 effective tokens that was never
+    // migrated, so it has no voting power history.
+    let config_manager = ConfigManager::new(&mut context).await;
+    let validator_stake_manager =
+        ValidatorStakeManager::new(&mut context, &config_manager.config).await;
+
+    let mut account = get_account!(context, validator_stake_manager.stake);
+    let mut stake_account = ValidatorStake::from_bytes(account.data.as_ref()).unwrap();
+    stake_account.version = 0;
+    stake_account.delegation.staked_amount = 65;
+    stake_account.delegation.effective_amount = 65;
+    account.data = stake_account.try_to_vec().unwrap();
+    context.set_account(&validator_stake_manager.stake, &account.into());
+    context.warp_to_slot(100).unwrap();
+
+    // When we query its voting power.
+    let err = get_voting_power_error(
+        &mut context,
+        config_manager.config,
+        validator_stake_manager.stake,
+        50,
+    )
+    .await;
+
+    // Then we expect an error instead of a voting power of 0.
+    assert_custom_error!(err, PaladinStakeProgramError::AccountMigrationRequired);
+}
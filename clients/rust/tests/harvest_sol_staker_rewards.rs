@@ -1508,6 +1508,150 @@ async fn sync_sol_stake_sol_stake_redelegate_to_non_pal() {
     let config = get_account!(context, config_manager.config);
     let config = Config::from_bytes(&config.data).unwrap();
     assert_eq!(config.token_amount_effective, 0);
+
+    // And the second vote account is recorded until it has a validator stake.
+    assert_eq!(stake_account.delegation.validator_vote, second_vote);
+    assert_eq!(stake_account.awaiting_validator_stake, 1);
+}
+
+#[tokio::test]
+async fn sync_sol_stake_after_validator_stake_initialized() {
+    let mut context = setup(&[]).await;
+    let second_withdraw_authority = Pubkey::new_unique();
+    let second_vote = add_vote_account(
+        &mut context,
+        &Pubkey::new_unique(),
+        &second_withdraw_authority,
+    );
+
+    // Given a config, validator stake and sol staker stake accounts with 5 SOL staked
+    // with the first vote account.
+    let config_manager = ConfigManager::new(&mut context).await;
+    let validator_stake_manager =
+        ValidatorStakeManager::new(&mut context, &config_manager.config).await;
+    let sol_staker_stake_manager = SolStakerStakeManager::new(
+        &mut context,
+        &config_manager.config,
+        &validator_stake_manager.stake,
+        &validator_stake_manager.vote,
+        5_000_000_000, // 5 SOL staked
+    )
+    .await;
+
+    // And the SOL stake is redelegated to a vote account without a validator stake.
+    deactivate_stake_account(
+        &mut context,
+        &sol_staker_stake_manager.sol_stake,
+        &sol_staker_stake_manager.authority,
+    )
+    .await;
+    delegate_stake_account(
+        &mut context,
+        &sol_staker_stake_manager.sol_stake,
+        &second_vote,
+        &sol_staker_stake_manager.authority,
+    )
+    .await;
+
+    // Ensure the authority is rent exempt.
+    context.set_account(
+        &sol_staker_stake_manager.authority.pubkey(),
+        &AccountSharedData::from(Account {
+            // amount to cover the account rent
+            lamports: 100_000_000,
+            ..Default::default()
+        }),
+    );
+
+    let keeper = setup_keeper(&mut context);
+    let (second_validator_stake, _) = paladin_stake_program_client::pdas::find_validator_stake_pda(
+        &second_vote,
+        &config_manager.config,
+    );
+    let harvest_stake_rewards_ix = HarvestSolStakerRewardsBuilder::new()
+        .config(config_manager.config)
+        .vault_holder_rewards(config_manager.vault_holder_rewards)
+        .sol_staker_stake(sol_staker_stake_manager.stake)
+        .sol_staker_stake_authority(sol_staker_stake_manager.authority.pubkey())
+        .sol_staker_native_stake(sol_staker_stake_manager.sol_stake)
+        .previous_validator_stake(validator_stake_manager.stake)
+        .previous_validator_stake_authority(validator_stake_manager.authority.pubkey())
+        .current_validator_stake(second_validator_stake)
+        .current_validator_stake_authority(second_withdraw_authority)
+        .sol_stake_view_program(paladin_sol_stake_view_program_client::ID)
+        .keeper_recipient(Some(keeper))
+        .instruction();
+    let tx = Transaction::new_signed_with_payer(
+        &[harvest_stake_rewards_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // And no sync is required while the validator stake does not exist.
+    let harvest_stake_rewards_ix = HarvestSolStakerRewardsBuilder::new()
+        .config(config_manager.config)
+        .vault_holder_rewards(config_manager.vault_holder_rewards)
+        .sol_staker_stake(sol_staker_stake_manager.stake)
+        .sol_staker_stake_authority(sol_staker_stake_manager.authority.pubkey())
+        .sol_staker_native_stake(sol_staker_stake_manager.sol_stake)
+        .previous_validator_stake(second_validator_stake)
+        .previous_validator_stake_authority(second_withdraw_authority)
+        .current_validator_stake(second_validator_stake)
+        .current_validator_stake_authority(second_withdraw_authority)
+        .sol_stake_view_program(paladin_sol_stake_view_program_client::ID)
+        .instruction();
+    let tx = Transaction::new_signed_with_payer(
+        &[harvest_stake_rewards_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // When the validator initializes its stake account.
+    ValidatorStakeManager::new_with_vote(&mut context, &config_manager.config, second_vote).await;
+
+    // And we sync the SOL stake again.
+    let harvest_stake_rewards_ix = HarvestSolStakerRewardsBuilder::new()
+        .config(config_manager.config)
+        .vault_holder_rewards(config_manager.vault_holder_rewards)
+        .sol_staker_stake(sol_staker_stake_manager.stake)
+        .sol_staker_stake_authority(sol_staker_stake_manager.authority.pubkey())
+        .sol_staker_native_stake(sol_staker_stake_manager.sol_stake)
+        .previous_validator_stake(second_validator_stake)
+        .previous_validator_stake_authority(second_withdraw_authority)
+        .current_validator_stake(second_validator_stake)
+        .current_validator_stake_authority(second_withdraw_authority)
+        .sol_stake_view_program(paladin_sol_stake_view_program_client::ID)
+        .keeper_recipient(Some(keeper))
+        .instruction();
+    let tx = Transaction::new_signed_with_payer(
+        &[harvest_stake_rewards_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // Then the SOL stake is credited to the new validator stake.
+    let account = get_account!(context, sol_staker_stake_manager.stake);
+    let stake_account = SolStakerStake::from_bytes(account.data.as_ref()).unwrap();
+    assert_eq!(stake_account.delegation.validator_vote, second_vote);
+    assert_eq!(stake_account.awaiting_validator_stake, 0);
+    assert_eq!(stake_account.lamports_amount, 5_000_000_000);
+    let account = get_account!(context, second_validator_stake);
+    let validator_stake_account = ValidatorStake::from_bytes(account.data.as_ref()).unwrap();
+    assert_eq!(
+        validator_stake_account.total_staked_lamports_amount,
+        5_000_000_000
+    );
+
+    // And the first validator stake is unchanged.
+    let account = get_account!(context, validator_stake_manager.stake);
+    let validator_stake_account = ValidatorStake::from_bytes(account.data.as_ref()).unwrap();
+    assert_eq!(validator_stake_account.total_staked_lamports_amount, 0);
 }
 
 #[tokio::test]
@@ -9,6 +9,7 @@ use paladin_stake_program_client::{
     errors::PaladinStakeProgramError,
     instructions::HarvestValidatorRewardsBuilder,
     pdas::find_validator_stake_pda,
+    return_data::HarvestReturnData,
 };
 use setup::{
     config::{create_config, ConfigManager},
@@ -104,7 +105,15 @@ async fn harvest_validator_rewards() {
         &[&context.payer],
         context.last_blockhash,
     );
-    context.banks_client.process_transaction(tx).await.unwrap();
+    let return_data = context
+        .banks_client
+        .process_transaction_with_metadata(tx)
+        .await
+        .unwrap()
+        .metadata
+        .unwrap()
+        .return_data
+        .unwrap();
 
     // Then the authority account has the rewards.
     let account = get_account!(context, validator_stake_manager.authority.pubkey());
@@ -117,6 +126,20 @@ async fn harvest_validator_rewards() {
         stake_account.delegation.last_seen_stake_rewards_per_token,
         200_000_000_000_000_000 // 0.2 * 1e18
     );
+
+    // And the harvested amounts are returned.
+    assert_eq!(
+        HarvestReturnData::from_return_data(&return_data.program_id, &return_data.data),
+        Some(HarvestReturnData {
+            staking_reward: 13,
+            holder_reward: 0,
+            keeper_reward: 0,
+            last_seen_stake_rewards_per_token: 200_000_000_000_000_000,
+            last_seen_holder_rewards_per_token: stake_account
+                .delegation
+                .last_seen_holder_rewards_per_token,
+        })
+    );
 }
 
 #[tokio::test]
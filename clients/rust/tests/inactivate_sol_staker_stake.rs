@@ -11,7 +11,7 @@ use paladin_stake_program_client::{
 use setup::{
     config::{create_config, ConfigManager},
     setup,
-    sol_staker_stake::SolStakerStakeManager,
+    sol_staker_stake::{activate_sol_staker_stake, SolStakerStakeManager},
     token::mint_to,
     validator_stake::ValidatorStakeManager,
 };
@@ -105,6 +105,15 @@ async fn setup_fixture(context: &mut ProgramTestContext, active_cooldown: Option
     );
     context.banks_client.process_transaction(tx).await.unwrap();
 
+    // Activate the staked tokens.
+    activate_sol_staker_stake(
+        context,
+        &config_manager,
+        &sol_staker_stake_manager,
+        &validator_stake_manager,
+    )
+    .await;
+
     let mut account = get_account!(context, sol_staker_stake_manager.stake);
     let mut stake_account = SolStakerStake::from_bytes(account.data.as_ref()).unwrap();
     if let Some(cooldown) = active_cooldown {
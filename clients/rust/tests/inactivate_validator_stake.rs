@@ -27,7 +27,10 @@ use solana_sdk::{
 use spl_associated_token_account::get_associated_token_address;
 use spl_token::state::Account as TokenAccount;
 
-use crate::setup::{config::create_ata, validator_stake::ValidatorStakeManager};
+use crate::setup::{
+    config::create_ata,
+    validator_stake::{activate_validator_stake, ValidatorStakeManager},
+};
 
 struct Fixture {
     config_manager: ConfigManager,
@@ -42,9 +45,15 @@ async fn setup_fixture(context: &mut ProgramTestContext, active_cooldown: Option
     let account = get_account!(context, config_manager.config);
     let config_account = Config::from_bytes(account.data.as_ref()).unwrap();
 
-    // And a validator stake account.
+    // And a validator stake account with 1 SOL staked.
     let validator_stake_manager = ValidatorStakeManager::new(context, &config_manager.config).await;
 
+    let mut account = get_account!(context, validator_stake_manager.stake);
+    let mut stake_account = ValidatorStake::from_bytes(account.data.as_ref()).unwrap();
+    stake_account.total_staked_lamports_amount = 1_000_000_000;
+    account.data = stake_account.try_to_vec().unwrap();
+    context.set_account(&validator_stake_manager.stake, &account.into());
+
     // Setup the stake authorities receiving token account.
     let destination_token_account = get_associated_token_address(
         &validator_stake_manager.authority.pubkey(),
@@ -94,13 +103,15 @@ async fn setup_fixture(context: &mut ProgramTestContext, active_cooldown: Option
     );
     context.banks_client.process_transaction(tx).await.unwrap();
 
+    // Activate the staked tokens.
+    activate_validator_stake(context, &config_manager, &validator_stake_manager).await;
+
     let mut account = get_account!(context, validator_stake_manager.stake);
     let mut stake_account = ValidatorStake::from_bytes(account.data.as_ref()).unwrap();
     if let Some(cooldown) = active_cooldown {
         let clock: Clock = bincode::deserialize(&get_account!(context, Clock::id()).data).unwrap();
         stake_account.delegation.unstake_cooldown = clock.unix_timestamp as u64 + cooldown;
     }
-    stake_account.total_staked_lamports_amount = 1_000_000_000;
     account.data = stake_account.try_to_vec().unwrap();
     context.set_account(&validator_stake_manager.stake, &account.into());
 
@@ -7,19 +7,24 @@ use paladin_stake_program_client::{
     accounts::Config, errors::PaladinStakeProgramError, instructions::InitializeConfigBuilder,
     pdas::find_vault_pda,
 };
-use setup::token::create_mint;
-use solana_program_test::tokio;
+use setup::token::{create_mint, create_mint_2022};
+use solana_program_test::{tokio, BanksClientError, ProgramTestContext};
 use solana_sdk::{
     account::Account,
     instruction::InstructionError,
     program_option::COption,
     program_pack::Pack,
+    pubkey::Pubkey,
     signature::{Keypair, Signer},
     system_instruction,
     transaction::Transaction,
 };
-use spl_associated_token_account::get_associated_token_address;
+use spl_associated_token_account::{
+    get_associated_token_address, get_associated_token_address_with_program_id,
+    instruction::create_associated_token_account,
+};
 use spl_token::state::{Account as TokenAccount, Mint};
+use spl_token_2022::extension::ExtensionType;
 
 use crate::setup::{
     config::{create_ata, fund_account, get_duna_hash},
@@ -858,3 +863,171 @@ async fn fail_initialize_config_with_invalid_max_deactivation_basis_points() {
 
     assert_instruction_error!(err, InstructionError::InvalidArgument);
 }
+
+/// Initializes a config account for a new Token-2022 mint with the given
+/// extensions, returning the config address.
+async fn initialize_config_with_mint_2022(
+    context: &mut ProgramTestContext,
+    extensions: &[ExtensionType],
+) -> Result<Pubkey, BanksClientError> {
+    let config = Keypair::new();
+    let authority = Keypair::new().pubkey();
+
+    let mint = Keypair::new();
+    create_mint_2022(context, &mint, &authority, extensions, 6)
+        .await
+        .unwrap();
+
+    let (vault_pda, _) = find_vault_pda(&config.pubkey());
+    let vault = get_associated_token_address_with_program_id(
+        &vault_pda,
+        &mint.pubkey(),
+        &spl_token_2022::ID,
+    );
+    let (vault_holder_rewards, _) = HolderRewards::find_pda(&vault_pda);
+
+    // Fund vault pda
+    fund_account(context, &vault_pda, 0).await.unwrap();
+
+    let create_ata_ix = create_associated_token_account(
+        &context.payer.pubkey(),
+        &vault_pda,
+        &mint.pubkey(),
+        &spl_token_2022::ID,
+    );
+    let create_ix = system_instruction::create_account(
+        &context.payer.pubkey(),
+        &config.pubkey(),
+        context
+            .banks_client
+            .get_rent()
+            .await
+            .unwrap()
+            .minimum_balance(Config::LEN),
+        Config::LEN as u64,
+        &paladin_stake_program_client::ID,
+    );
+    let initialize_ix = InitializeConfigBuilder::new()
+        .config(config.pubkey())
+        .mint(mint.pubkey())
+        .vault(vault)
+        .vault_pda(vault_pda)
+        .vault_holder_rewards(vault_holder_rewards)
+        .rewards_program(paladin_rewards_program_client::ID)
+        .config_authority(authority)
+        .slash_authority(authority)
+        .cooldown_time_seconds(1)
+        .max_deactivation_basis_points(500)
+        .sync_rewards_lamports(1_000_000)
+        .duna_document_hash(get_duna_hash())
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[create_ata_ix, create_ix, initialize_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &config],
+        context.last_blockhash,
+    );
+    context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .map(|_| config.pubkey())
+}
+
+#[tokio::test]
+async fn initialize_config_with_token_2022_mint() {
+    let mut context = setup(&[]).await;
+
+    // Given a Token-2022 mint without extensions.
+
+    // When we initialize the config with a Token-2022 vault.
+    let config = initialize_config_with_mint_2022(&mut context, &[])
+        .await
+        .unwrap();
+
+    // Then an account was created with the correct data.
+    let account = get_account!(context, config);
+    assert_eq!(account.data.len(), Config::LEN);
+    let config = Config::from_bytes(&account.data).unwrap();
+    assert_eq!(config.token_amount_effective, 0);
+
+    let account = get_account!(context, config.vault);
+    assert_eq!(account.owner, spl_token_2022::ID);
+}
+
+#[tokio::test]
+async fn fail_initialize_config_with_permanent_delegate() {
+    let mut context = setup(&[]).await;
+
+    // Given a Token-2022 mint with a permanent delegate.
+
+    // When we try to initialize the config.
+    let err = initialize_config_with_mint_2022(&mut context, &[ExtensionType::PermanentDelegate])
+        .await
+        .unwrap_err();
+
+    // Then we expect an error.
+    assert_custom_error!(err, PaladinStakeProgramError::InvalidMintExtension);
+}
+
+#[tokio::test]
+async fn fail_initialize_config_with_transfer_fees() {
+    let mut context = setup(&[]).await;
+
+    // Given a Token-2022 mint with transfer fees.
+
+    // When we try to initialize the config.
+    let err = initialize_config_with_mint_2022(&mut context, &[ExtensionType::TransferFeeConfig])
+        .await
+        .unwrap_err();
+
+    // Then we expect an error.
+    assert_custom_error!(err, PaladinStakeProgramError::InvalidMintExtension);
+}
+
+#[tokio::test]
+async fn initialize_config_with_metadata_pointer() {
+    let mut context = setup(&[]).await;
+
+    // Given a Token-2022 mint with a metadata pointer.
+
+    // When we initialize the config.
+    let config = initialize_config_with_mint_2022(&mut context, &[ExtensionType::MetadataPointer])
+        .await
+        .unwrap();
+
+    // Then an account was created.
+    let account = get_account!(context, config);
+    assert_eq!(account.data.len(), Config::LEN);
+}
+
+#[tokio::test]
+async fn fail_initialize_config_with_default_account_state() {
+    let mut context = setup(&[]).await;
+
+    // Given a Token-2022 mint with a default account state.
+
+    // When we try to initialize the config.
+    let err = initialize_config_with_mint_2022(&mut context, &[ExtensionType::DefaultAccountState])
+        .await
+        .unwrap_err();
+
+    // Then we expect an error.
+    assert_custom_error!(err, PaladinStakeProgramError::InvalidMintExtension);
+}
+
+#[tokio::test]
+async fn fail_initialize_config_with_non_transferable_mint() {
+    let mut context = setup(&[]).await;
+
+    // Given a non-transferable Token-2022 mint.
+
+    // When we try to initialize the config.
+    let err = initialize_config_with_mint_2022(&mut context, &[ExtensionType::NonTransferable])
+        .await
+        .unwrap_err();
+
+    // Then we expect an error.
+    assert_custom_error!(err, PaladinStakeProgramError::InvalidMintExtension);
+}
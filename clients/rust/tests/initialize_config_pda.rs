@@ -0,0 +1,303 @@
+#![cfg(feature = "test-sbf")]
+
+mod setup;
+
+use borsh::BorshSerialize;
+use paladin_rewards_program_client::accounts::HolderRewards;
+use paladin_stake_program_client::{
+    accounts::{Config, ValidatorStake},
+    instructions::{InitializeConfigPdaBuilder, ValidatorStakeTokensBuilder},
+    pdas::{find_config_pda, find_validator_stake_pda, find_vault_pda},
+};
+use setup::{
+    config::{create_ata, fund_account, get_duna_hash, ConfigManager},
+    setup,
+    token::mint_to,
+    validator_stake::{activate_validator_stake, ValidatorStakeManager},
+};
+use solana_program_test::tokio;
+use solana_sdk::{
+    instruction::{Instruction, InstructionError},
+    program_pack::Pack,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    transaction::Transaction,
+};
+use spl_associated_token_account::get_associated_token_address;
+use spl_token::{
+    instruction::{set_authority, AuthorityType},
+    state::Account as TokenAccount,
+};
+
+#[tokio::test]
+async fn initialize_config_pda_for_two_mints() {
+    let mut context = setup(&[]).await;
+
+    // Given two config accounts created at the PDAs of different mints.
+    let first = ConfigManager::with_namespace(&mut context, 0).await;
+    let second = ConfigManager::with_namespace(&mut context, 0).await;
+
+    // Then the config addresses can be derived from the mints and config
+    // authorities.
+    assert_eq!(
+        first.config,
+        find_config_pda(&first.mint, &first.config_authority.pubkey(), 0).0
+    );
+    assert_eq!(
+        second.config,
+        find_config_pda(&second.mint, &second.config_authority.pubkey(), 0).0
+    );
+    assert_ne!(first.config, second.config);
+
+    // And each config uses a vault for its own mint.
+    for manager in [&first, &second] {
+        let account = get_account!(context, manager.config);
+        assert_eq!(account.data.len(), Config::LEN);
+        assert_eq!(account.owner, paladin_stake_program_client::ID);
+
+        let config = Config::from_bytes(account.data.as_ref()).unwrap();
+        assert_eq!(config.vault, manager.vault);
+        assert_eq!(config.version, 1);
+
+        let account = get_account!(context, manager.vault);
+        let vault = TokenAccount::unpack(&account.data).unwrap();
+        assert_eq!(vault.mint, manager.mint);
+    }
+
+    // And given the same validator has a stake account on both configs.
+    let first_stake = ValidatorStakeManager::new(&mut context, &first.config).await;
+    let second_stake =
+        ValidatorStakeManager::new_with_vote(&mut context, &second.config, first_stake.vote).await;
+    assert_eq!(
+        second_stake.stake,
+        find_validator_stake_pda(&first_stake.vote, &second.config).0
+    );
+    assert_ne!(first_stake.stake, second_stake.stake);
+
+    let mut account = get_account!(context, first_stake.stake);
+    let mut stake_account = ValidatorStake::from_bytes(account.data.as_ref()).unwrap();
+    stake_account.total_staked_lamports_amount = 50;
+    account.data = stake_account.try_to_vec().unwrap();
+    context.set_account(&first_stake.stake, &account.into());
+
+    // When we stake tokens on the first config.
+    mint_to(
+        &mut context,
+        &first.mint,
+        &first.mint_authority,
+        &first.rewards_manager.owner_token_account,
+        65,
+    )
+    .await
+    .unwrap();
+
+    let stake_ix = ValidatorStakeTokensBuilder::new()
+        .config(first.config)
+        .holder_rewards_pool(first.rewards_manager.pool)
+        .holder_rewards_pool_token_account(first.rewards_manager.pool_token_account)
+        .validator_stake(first_stake.stake)
+        .validator_stake_authority(first_stake.authority.pubkey())
+        .source_token_account(first.rewards_manager.owner_token_account)
+        .source_token_account_authority(first.rewards_manager.owner.pubkey())
+        .mint(first.mint)
+        .vault(first.vault)
+        .vault_pda(first.vault_pda)
+        .vault_holder_rewards(first.vault_holder_rewards)
+        .token_program(spl_token::ID)
+        .rewards_program(paladin_rewards_program_client::ID)
+        .amount(65)
+        .instruction();
+    let tx = Transaction::new_signed_with_payer(
+        &[stake_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &first.rewards_manager.owner],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+    activate_validator_stake(&mut context, &first, &first_stake).await;
+
+    // Then only the first config and its stake account are updated.
+    let account = get_account!(context, first.config);
+    let config = Config::from_bytes(account.data.as_ref()).unwrap();
+    assert_eq!(config.token_amount_effective, 65);
+
+    let account = get_account!(context, second.config);
+    let config = Config::from_bytes(account.data.as_ref()).unwrap();
+    assert_eq!(config.token_amount_effective, 0);
+
+    let account = get_account!(context, second_stake.stake);
+    let stake_account = ValidatorStake::from_bytes(account.data.as_ref()).unwrap();
+    assert_eq!(stake_account.delegation.staked_amount, 0);
+}
+
+/// Builds an `InitializeConfigPda` instruction for the given mint and
+/// namespace.
+fn initialize_config_pda_ix(
+    config: Pubkey,
+    mint: Pubkey,
+    namespace: u64,
+    config_authority: &Pubkey,
+) -> Instruction {
+    let (vault_pda, _) = find_vault_pda(&config);
+    InitializeConfigPdaBuilder::new()
+        .config(config)
+        .mint(mint)
+        .vault(get_associated_token_address(&vault_pda, &mint))
+        .vault_pda(vault_pda)
+        .vault_holder_rewards(HolderRewards::find_pda(&vault_pda).0)
+        .rewards_program(paladin_rewards_program_client::ID)
+        .config_authority(*config_authority)
+        .namespace(namespace)
+        .slash_authority(Pubkey::new_unique())
+        .cooldown_time_seconds(1)
+        .max_deactivation_basis_points(500)
+        .sync_rewards_lamports(1_000_000)
+        .duna_document_hash(get_duna_hash())
+        .instruction()
+}
+
+#[tokio::test]
+async fn initialize_config_pda_for_mint_without_authority() {
+    let mut context = setup(&[]).await;
+
+    // Given a mint whose mint authority was revoked.
+    let manager = ConfigManager::with_namespace(&mut context, 0).await;
+    let revoke_ix = set_authority(
+        &spl_token::ID,
+        &manager.mint,
+        None,
+        AuthorityType::MintTokens,
+        &manager.mint_authority.pubkey(),
+        &[],
+    )
+    .unwrap();
+    let tx = Transaction::new_signed_with_payer(
+        &[revoke_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &manager.mint_authority],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // And the vault accounts of a config PDA for namespace 1.
+    let config_authority = Keypair::new();
+    let (config, _) = find_config_pda(&manager.mint, &config_authority.pubkey(), 1);
+    let (vault_pda, _) = find_vault_pda(&config);
+    fund_account(&mut context, &vault_pda, 0).await.unwrap();
+    fund_account(
+        &mut context,
+        &HolderRewards::find_pda(&vault_pda).0,
+        HolderRewards::LEN,
+    )
+    .await
+    .unwrap();
+    create_ata(&mut context, &vault_pda, &manager.mint)
+        .await
+        .unwrap();
+    fund_account(&mut context, &config, Config::LEN)
+        .await
+        .unwrap();
+
+    // When its config authority initializes the config PDA.
+    let ix = initialize_config_pda_ix(config, manager.mint, 1, &config_authority.pubkey());
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &config_authority],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // Then the config is created with the signer as its authority.
+    let account = get_account!(context, config);
+    assert_eq!(account.owner, paladin_stake_program_client::ID);
+    let config_account = Config::from_bytes(account.data.as_ref()).unwrap();
+    assert_eq!(
+        config_account.authority.value(),
+        Some(config_authority.pubkey())
+    );
+}
+
+#[tokio::test]
+async fn fail_initialize_config_pda_with_wrong_namespace() {
+    let mut context = setup(&[]).await;
+
+    // Given the config PDA of a mint for namespace 1.
+    let mint = Pubkey::new_unique();
+    let config_authority = Keypair::new();
+    let (config, _) = find_config_pda(&mint, &config_authority.pubkey(), 1);
+
+    // When we try to initialize it using namespace 0.
+    let ix = initialize_config_pda_ix(config, mint, 0, &config_authority.pubkey());
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &config_authority],
+        context.last_blockhash,
+    );
+    let err = context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .unwrap_err();
+
+    // Then we expect an error.
+    assert_instruction_error!(err, InstructionError::InvalidSeeds);
+}
+
+#[tokio::test]
+async fn fail_initialize_config_pda_of_another_config_authority() {
+    let mut context = setup(&[]).await;
+
+    // Given the config PDA of a mint for a config authority.
+    let mint = Pubkey::new_unique();
+    let (config, _) = find_config_pda(&mint, &Pubkey::new_unique(), 0);
+
+    // When someone else tries to initialize it with their own authority.
+    let squatter = Keypair::new();
+    let ix = initialize_config_pda_ix(config, mint, 0, &squatter.pubkey());
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &squatter],
+        context.last_blockhash,
+    );
+    let err = context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .unwrap_err();
+
+    // Then we expect an error.
+    assert_instruction_error!(err, InstructionError::InvalidSeeds);
+}
+
+#[tokio::test]
+async fn fail_initialize_config_pda_twice() {
+    let mut context = setup(&[]).await;
+
+    // Given a config account created at the PDA of a mint.
+    let manager = ConfigManager::with_namespace(&mut context, 7).await;
+
+    // When we try to initialize the same config PDA again.
+    let ix = initialize_config_pda_ix(
+        manager.config,
+        manager.mint,
+        7,
+        &manager.config_authority.pubkey(),
+    );
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &manager.config_authority],
+        context.last_blockhash,
+    );
+    let err = context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .unwrap_err();
+
+    // Then we expect an error.
+    assert_instruction_error!(err, InstructionError::AccountAlreadyInitialized);
+}
@@ -5,12 +5,15 @@ mod setup;
 use paladin_stake_program_client::{
     accounts::{Config, SolStakerStake, ValidatorStake},
     instructions::InitializeSolStakerStakeBuilder,
-    pdas::{find_sol_staker_authority_override_pda, find_sol_staker_stake_pda},
+    pdas::{
+        find_sol_staker_authority_override_pda, find_sol_staker_stake_pda, find_validator_stake_pda,
+    },
 };
 use setup::{
     config::ConfigManager,
     stake::{create_stake_account, delegate_stake_account},
     validator_stake::ValidatorStakeManager,
+    vote::add_vote_account,
 };
 use solana_program_test::tokio;
 use solana_sdk::{
@@ -124,6 +127,81 @@ async fn initialize_sol_staker_stake_base() {
     );
 }
 
+#[tokio::test]
+async fn initialize_sol_staker_stake_awaiting_validator_stake() {
+    let mut context = setup(&[]).await;
+
+    // Given a config account and a vote account without a validator stake account.
+    let config_manager = ConfigManager::new(&mut context).await;
+    let vote = add_vote_account(&mut context, &Pubkey::new_unique(), &Pubkey::new_unique());
+    let (validator_stake, _) = find_validator_stake_pda(&vote, &config_manager.config);
+
+    // And we create a SOL stake account delegated to the vote account.
+    let stake_state = Keypair::new();
+    let withdrawer = Keypair::new();
+    create_stake_account(
+        &mut context,
+        &stake_state,
+        &Authorized::auto(&withdrawer.pubkey()),
+        &Lockup::default(),
+        1_000_000_000,
+    )
+    .await;
+    let stake_state = stake_state.pubkey();
+    delegate_stake_account(&mut context, &stake_state, &vote, &withdrawer).await;
+
+    // Sign duna document PDA.
+    let duna_document_pda = sign_duna_document(&mut context, &withdrawer.pubkey());
+
+    // When we initialize the SOL staker stake account.
+    let (stake_pda, _) = find_sol_staker_stake_pda(&stake_state, &config_manager.config);
+    let transfer_ix = system_instruction::transfer(
+        &context.payer.pubkey(),
+        &stake_pda,
+        context
+            .banks_client
+            .get_rent()
+            .await
+            .unwrap()
+            .minimum_balance(SolStakerStake::LEN),
+    );
+    let initialize_ix = InitializeSolStakerStakeBuilder::new()
+        .config(config_manager.config)
+        .sol_staker_stake(stake_pda)
+        .sol_staker_authority_override(
+            find_sol_staker_authority_override_pda(&withdrawer.pubkey(), &config_manager.config).0,
+        )
+        .validator_stake(validator_stake)
+        .sol_staker_native_stake(stake_state)
+        .sol_stake_view_program(paladin_sol_stake_view_program_client::ID)
+        .duna_document_pda(duna_document_pda)
+        .instruction();
+    let tx = Transaction::new_signed_with_payer(
+        &[transfer_ix, initialize_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // Then the SOL staker stake account is awaiting the validator stake account.
+    let account = get_account!(context, stake_pda);
+    let stake_account = SolStakerStake::from_bytes(account.data.as_ref()).unwrap();
+    assert_eq!(stake_account.delegation.validator_vote, vote);
+    assert_eq!(stake_account.delegation.authority, withdrawer.pubkey());
+    assert_eq!(stake_account.sol_stake, stake_state);
+    assert_eq!(stake_account.awaiting_validator_stake, 1);
+    assert_eq!(stake_account.lamports_amount, 0);
+
+    // And the validator stake account was not created.
+    let account = context
+        .banks_client
+        .get_account(validator_stake)
+        .await
+        .unwrap();
+    assert!(account.is_none());
+}
+
 #[tokio::test]
 async fn fail_initialize_sol_staker_stake_with_initialized_account() {
     let mut context = setup(&[]).await;
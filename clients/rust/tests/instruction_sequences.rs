@@ -0,0 +1,716 @@
+#![cfg(feature = "test-sbf")]
+
+//! Drives the program through random sequences of instructions, checking the
+//! resulting accounts against a reference model after every step.
+//!
+//! The sequences are derived from a seed, so a failure is reproduced by running
+//! the test with the reported seed:
+//!
+//! ```sh
+//! FUZZ_SEED=<seed> FUZZ_STEPS=<steps> cargo test-sbf --test instruction_sequences
+//! ```
+
+mod setup;
+
+use paladin_stake_program_client::{
+    accounts::{Config, SolStakerStake, ValidatorStake},
+    events::{parse_events, HarvestEvent, StakeEvent},
+    instructions::{
+        AuditInvariantsBuilder, HarvestSolStakerRewardsBuilder, HarvestValidatorRewardsBuilder,
+        SlashSolStakerStakeBuilder, SlashValidatorStakeBuilder, SolStakerMoveTokensBuilder,
+        SolStakerStakeTokensBuilder, UnstakeTokensBuilder, ValidatorStakeTokensBuilder,
+    },
+    return_data::AuditReturnData,
+};
+use setup::{
+    config::ConfigManager, setup, sol_staker_stake::SolStakerStakeManager,
+    stake::warp_to_next_epoch, token::mint_to, validator_stake::ValidatorStakeManager,
+};
+use solana_program_test::{tokio, ProgramTestContext};
+use solana_sdk::{
+    clock::Clock,
+    instruction::{AccountMeta, Instruction},
+    program_pack::Pack,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    system_instruction,
+    transaction::Transaction,
+};
+use spl_token::state::Account as TokenAccount;
+
+/// Seeds explored when `FUZZ_SEED` is not set.
+const DEFAULT_SEEDS: u64 = 4;
+
+/// Instructions executed for each seed when `FUZZ_STEPS` is not set.
+const DEFAULT_STEPS: u64 = 48;
+
+/// Tokens minted to the staker.
+const WALLET_AMOUNT: u64 = 10_000_000;
+
+/// Maximum deactivation of the config created by `ConfigManager::new` (5%).
+const MAX_DEACTIVATION_BASIS_POINTS: u64 = 500;
+
+/// Unstake cooldown of the config created by `ConfigManager::new`.
+const COOLDOWN_TIME_SECONDS: u64 = 1;
+
+/// Index of the validator stake account; the remaining stake accounts are SOL
+/// staker stake accounts.
+const VALIDATOR: usize = 0;
+
+const STAKES: usize = 3;
+
+/// Minimal xorshift generator, so the sequences are deterministic.
+struct Rng(u64);
+
+impl Rng {
+    fn next(&mut self, bound: u64) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0 % bound
+    }
+
+    /// Amount up to `bound`, biased towards the edge cases.
+    fn amount(&mut self, bound: u64) -> u64 {
+        match self.next(4) {
+            0 => 0,
+            1 => self.next(10),
+            _ => self.next(bound.saturating_add(1)),
+        }
+    }
+}
+
+#[derive(Debug)]
+enum Op {
+    Stake { stake: usize, amount: u64 },
+    Unstake { stake: usize, amount: u64 },
+    Move { source: usize, amount: u64 },
+    Slash { stake: usize, amount: u64 },
+    Harvest { stake: usize },
+    DepositLamports { lamports: u64 },
+    WarpEpoch,
+}
+
+/// Expected state of the accounts.
+#[derive(Default)]
+struct Model {
+    staked: [u64; STAKES],
+    effective: [u64; STAKES],
+    activating: [u64; STAKES],
+    activation_epoch: [u64; STAKES],
+    unstake_cooldown: [u64; STAKES],
+    /// SOL amount backing each stake account.
+    lamports: [u64; STAKES],
+    /// Minimum SOL amount backing the validator stake account.
+    validator_lamports_min: u64,
+    wallet: u64,
+    deposited: u64,
+    /// Clock at which the next operation is processed.
+    epoch: u64,
+    now: u64,
+}
+
+impl Model {
+    fn next_op(&self, rng: &mut Rng) -> Op {
+        let stake = rng.next(STAKES as u64) as usize;
+        let sol_staker = 1 + rng.next(STAKES as u64 - 1) as usize;
+
+        match rng.next(10) {
+            0 | 1 => Op::Stake {
+                stake,
+                amount: rng.amount(self.wallet / 2),
+            },
+            // Withdrawing 0 tokens is left to the rewards program, so at least
+            // one token is unstaked.
+            2 => Op::Unstake {
+                stake,
+                amount: std::cmp::max(rng.amount(self.staked[stake] / 10), 1),
+            },
+            3 => Op::Move {
+                source: sol_staker,
+                amount: rng.amount(self.staked[sol_staker] + self.staked[sol_staker] / 4),
+            },
+            4 => Op::Slash {
+                stake,
+                amount: rng.amount(self.staked[stake] / 2),
+            },
+            5 | 6 => Op::Harvest { stake },
+            7 | 8 => Op::DepositLamports {
+                lamports: rng.amount(1_000_000_000),
+            },
+            _ => Op::WarpEpoch,
+        }
+    }
+
+    /// Whether the program must reject the operation.
+    fn rejects(&self, op: &Op) -> bool {
+        match *op {
+            Op::Stake { amount, .. } => amount == 0 || amount > self.wallet,
+            Op::Unstake { stake, amount } => {
+                self.now < self.unstake_cooldown[stake]
+                    || amount > self.staked[stake] * MAX_DEACTIVATION_BASIS_POINTS / 10_000
+            }
+            Op::Move { source, amount } => amount > self.staked[source],
+            Op::Slash { amount, .. } => amount == 0,
+            _ => false,
+        }
+    }
+
+    /// Applies a successful operation, given the events it emitted.
+    fn apply(&mut self, op: &Op, events: &[StakeEvent]) {
+        match *op {
+            Op::Stake { stake, amount } => {
+                self.add_activating_stake(stake, amount);
+                self.wallet -= amount;
+                self.sync_effective(stake);
+            }
+            Op::Unstake { stake, amount } => {
+                self.staked[stake] -= amount;
+                self.wallet += amount;
+                self.unstake_cooldown[stake] = self.now + COOLDOWN_TIME_SECONDS;
+                self.sync_effective(stake);
+            }
+            Op::Move { source, amount } => {
+                // Activating tokens are the last to leave the source and keep
+                // activating on the destination.
+                let destination = destination(source);
+                let source_activating = self.activating_at(source);
+                self.staked[source] -= amount;
+                let activating = source_activating - self.activating_at(source);
+                self.staked[destination] += amount - activating;
+                if activating > 0 {
+                    self.add_activating_stake(destination, activating);
+                }
+                self.unstake_cooldown[destination] = std::cmp::max(
+                    self.unstake_cooldown[source],
+                    self.unstake_cooldown[destination],
+                );
+                self.sync_effective(source);
+                self.sync_effective(destination);
+            }
+            Op::Slash { stake, amount } => {
+                self.staked[stake] -= std::cmp::min(amount, self.staked[stake]);
+                self.sync_effective(stake);
+            }
+            Op::Harvest { stake } => {
+                // The SOL amount of the native stake is only known from the
+                // sync, which moves it to the validator stake account.
+                for event in events {
+                    if let StakeEvent::LamportsSync(event) = event {
+                        self.lamports[VALIDATOR] = self.lamports[VALIDATOR]
+                            - event.previous_lamports
+                            + event.current_lamports;
+                        self.lamports[stake] = event.current_lamports;
+                        self.sync_effective(VALIDATOR);
+                    }
+                }
+                self.sync_effective(stake);
+            }
+            Op::DepositLamports { lamports } => self.deposited += lamports,
+            Op::WarpEpoch => {}
+        }
+    }
+
+    fn activating_at(&self, stake: usize) -> u64 {
+        if self.epoch > self.activation_epoch[stake] {
+            0
+        } else {
+            std::cmp::min(self.activating[stake], self.staked[stake])
+        }
+    }
+
+    fn add_activating_stake(&mut self, stake: usize, amount: u64) {
+        self.staked[stake] += amount;
+        self.activating[stake] = self.activating_at(stake) + amount;
+        self.activation_epoch[stake] = self.epoch;
+    }
+
+    /// Active tokens count towards the effective amount up to 1.3 tokens per
+    /// lamport backing the stake.
+    fn sync_effective(&mut self, stake: usize) {
+        let lamports = match stake {
+            VALIDATOR => std::cmp::max(self.lamports[stake], self.validator_lamports_min),
+            _ => self.lamports[stake],
+        };
+        let limit = (lamports as u128 * 13 / 10) as u64;
+
+        self.activating[stake] = self.activating_at(stake);
+        self.effective[stake] = std::cmp::min(self.staked[stake] - self.activating[stake], limit);
+    }
+}
+
+/// The other SOL staker stake account, sharing the authority of `source`.
+fn destination(source: usize) -> usize {
+    STAKES - source
+}
+
+struct Harness {
+    context: ProgramTestContext,
+    config_manager: ConfigManager,
+    validator_stake_manager: ValidatorStakeManager,
+    sol_staker_stake_managers: Vec<SolStakerStakeManager>,
+    /// Lamports of the validator and SOL staker authorities before any
+    /// rewards were paid.
+    authority_lamports: u64,
+    /// Lamports paid to the payer for syncing SOL staker stake accounts.
+    keeper_rewards: u64,
+}
+
+impl Harness {
+    async fn new() -> Self {
+        let mut context = setup(&[]).await;
+
+        // A validator stake account and two SOL staker stake accounts of the
+        // same authority (5 SOL staked each) delegated to it.
+        let config_manager = ConfigManager::new(&mut context).await;
+        let validator_stake_manager =
+            ValidatorStakeManager::new(&mut context, &config_manager.config).await;
+        let authority = Keypair::new();
+        let mut sol_staker_stake_managers = Vec::with_capacity(STAKES - 1);
+        for _ in 1..STAKES {
+            sol_staker_stake_managers.push(
+                SolStakerStakeManager::new_with_authority(
+                    &mut context,
+                    &config_manager.config,
+                    &validator_stake_manager.stake,
+                    &validator_stake_manager.vote,
+                    authority.insecure_clone(),
+                    5_000_000_000,
+                )
+                .await,
+            );
+        }
+
+        // Tokens to stake.
+        mint_to(
+            &mut context,
+            &config_manager.mint,
+            &config_manager.mint_authority,
+            &config_manager.rewards_manager.owner_token_account,
+            WALLET_AMOUNT,
+        )
+        .await
+        .unwrap();
+
+        // Slashing burns from the vault token account, while staked tokens are
+        // deposited on the rewards program, so the vault holds tokens to burn.
+        mint_to(
+            &mut context,
+            &config_manager.mint,
+            &config_manager.mint_authority,
+            &config_manager.vault,
+            WALLET_AMOUNT,
+        )
+        .await
+        .unwrap();
+
+        let mut harness = Self {
+            context,
+            config_manager,
+            validator_stake_manager,
+            sol_staker_stake_managers,
+            authority_lamports: 0,
+            keeper_rewards: 0,
+        };
+
+        // Rent exempt authorities, so they can receive any amount of rewards.
+        let payer = harness.context.payer.pubkey();
+        let funding = [
+            harness.validator_stake_manager.authority.pubkey(),
+            authority.pubkey(),
+        ]
+        .map(|authority| system_instruction::transfer(&payer, &authority, 1_000_000_000));
+        assert!(harness.process(&funding, &[]).await.is_some());
+        harness.authority_lamports = harness.authorities_lamports().await;
+
+        harness
+    }
+
+    fn stake(&self, index: usize) -> Pubkey {
+        match index {
+            VALIDATOR => self.validator_stake_manager.stake,
+            _ => self.sol_staker_stake_managers[index - 1].stake,
+        }
+    }
+
+    fn authority(&self, index: usize) -> &Keypair {
+        match index {
+            VALIDATOR => &self.validator_stake_manager.authority,
+            _ => &self.sol_staker_stake_managers[index - 1].authority,
+        }
+    }
+
+    /// Processes the instructions in a transaction, returning the events
+    /// emitted when it succeeded.
+    async fn process(
+        &mut self,
+        instructions: &[Instruction],
+        signers: &[&Keypair],
+    ) -> Option<Vec<StakeEvent>> {
+        // Identical instructions would otherwise be rejected as already
+        // processed.
+        self.context.get_new_latest_blockhash().await.unwrap();
+
+        let mut all_signers = vec![&self.context.payer];
+        all_signers.extend_from_slice(signers);
+        let tx = Transaction::new_signed_with_payer(
+            instructions,
+            Some(&self.context.payer.pubkey()),
+            &all_signers,
+            self.context.last_blockhash,
+        );
+
+        let result = self
+            .context
+            .banks_client
+            .process_transaction_with_metadata(tx)
+            .await
+            .unwrap();
+        result.result.ok()?;
+        let events = parse_events(&result.metadata.unwrap().log_messages);
+
+        for event in &events {
+            if let StakeEvent::Harvest(HarvestEvent { keeper_reward, .. }) = event {
+                self.keeper_rewards += keeper_reward;
+            }
+        }
+
+        Some(events)
+    }
+
+    async fn execute(&mut self, op: &Op) -> Option<Vec<StakeEvent>> {
+        let config_manager = &self.config_manager;
+        let rewards_manager = &config_manager.rewards_manager;
+
+        let (instruction, signers) = match *op {
+            Op::Stake { stake, amount } if stake == VALIDATOR => (
+                ValidatorStakeTokensBuilder::new()
+                    .config(config_manager.config)
+                    .holder_rewards_pool(rewards_manager.pool)
+                    .holder_rewards_pool_token_account(rewards_manager.pool_token_account)
+                    .validator_stake(self.stake(stake))
+                    .validator_stake_authority(self.authority(stake).pubkey())
+                    .source_token_account(rewards_manager.owner_token_account)
+                    .source_token_account_authority(rewards_manager.owner.pubkey())
+                    .mint(config_manager.mint)
+                    .vault(config_manager.vault)
+                    .vault_pda(config_manager.vault_pda)
+                    .vault_holder_rewards(config_manager.vault_holder_rewards)
+                    .token_program(spl_token::ID)
+                    .rewards_program(paladin_rewards_program_client::ID)
+                    .amount(amount)
+                    .instruction(),
+                vec![&rewards_manager.owner],
+            ),
+            Op::Stake { stake, amount } => (
+                SolStakerStakeTokensBuilder::new()
+                    .config(config_manager.config)
+                    .holder_rewards_pool(rewards_manager.pool)
+                    .holder_rewards_pool_token_account(rewards_manager.pool_token_account)
+                    .sol_staker_stake(self.stake(stake))
+                    .sol_staker_stake_authority(self.authority(stake).pubkey())
+                    .source_token_account(rewards_manager.owner_token_account)
+                    .source_token_account_authority(rewards_manager.owner.pubkey())
+                    .mint(config_manager.mint)
+                    .vault(config_manager.vault)
+                    .vault_pda(config_manager.vault_pda)
+                    .vault_holder_rewards(config_manager.vault_holder_rewards)
+                    .token_program(spl_token::ID)
+                    .rewards_program(paladin_rewards_program_client::ID)
+                    .amount(amount)
+                    .instruction(),
+                vec![&rewards_manager.owner],
+            ),
+            Op::Unstake { stake, amount } => (
+                UnstakeTokensBuilder::new()
+                    .config(config_manager.config)
+                    .holder_rewards_pool(rewards_manager.pool)
+                    .holder_rewards_pool_token_account(rewards_manager.pool_token_account)
+                    .stake(self.stake(stake))
+                    .stake_authority(self.authority(stake).pubkey())
+                    .vault(config_manager.vault)
+                    .vault_pda(config_manager.vault_pda)
+                    .vault_holder_rewards(config_manager.vault_holder_rewards)
+                    .mint(config_manager.mint)
+                    .destination_token_account(rewards_manager.owner_token_account)
+                    .token_program(spl_token::ID)
+                    .rewards_program(paladin_rewards_program_client::ID)
+                    .amount(amount)
+                    .instruction(),
+                vec![self.authority(stake)],
+            ),
+            Op::Move { source, amount } => (
+                SolStakerMoveTokensBuilder::new()
+                    .config(config_manager.config)
+                    .vault_holder_rewards(config_manager.vault_holder_rewards)
+                    .sol_staker_authority(self.authority(source).pubkey())
+                    .source_sol_staker_stake(self.stake(source))
+                    .destination_sol_staker_stake(self.stake(destination(source)))
+                    .amount(amount)
+                    .instruction(),
+                vec![self.authority(source)],
+            ),
+            Op::Slash { stake, amount } if stake == VALIDATOR => (
+                SlashValidatorStakeBuilder::new()
+                    .config(config_manager.config)
+                    .validator_stake(self.stake(stake))
+                    .validator_stake_authority(self.authority(stake).pubkey())
+                    .slash_authority(config_manager.config_authority.pubkey())
+                    .vault(config_manager.vault)
+                    .vault_holder_rewards(config_manager.vault_holder_rewards)
+                    .vault_authority(config_manager.vault_pda)
+                    .mint(config_manager.mint)
+                    .token_program(spl_token::ID)
+                    .amount(amount)
+                    .instruction(),
+                vec![&config_manager.config_authority],
+            ),
+            Op::Slash { stake, amount } => (
+                SlashSolStakerStakeBuilder::new()
+                    .config(config_manager.config)
+                    .sol_staker_stake(self.stake(stake))
+                    .sol_staker_stake_authority(self.authority(stake).pubkey())
+                    .slash_authority(config_manager.config_authority.pubkey())
+                    .mint(config_manager.mint)
+                    .vault(config_manager.vault)
+                    .vault_holder_rewards(config_manager.vault_holder_rewards)
+                    .vault_authority(config_manager.vault_pda)
+                    .token_program(spl_token::ID)
+                    .amount(amount)
+                    .instruction(),
+                vec![&config_manager.config_authority],
+            ),
+            Op::Harvest { stake } if stake == VALIDATOR => (
+                HarvestValidatorRewardsBuilder::new()
+                    .config(config_manager.config)
+                    .vault_holder_rewards(config_manager.vault_holder_rewards)
+                    .validator_stake(self.stake(stake))
+                    .validator_stake_authority(self.authority(stake).pubkey())
+                    .instruction(),
+                vec![],
+            ),
+            Op::Harvest { stake } => (
+                HarvestSolStakerRewardsBuilder::new()
+                    .config(config_manager.config)
+                    .vault_holder_rewards(config_manager.vault_holder_rewards)
+                    .sol_staker_stake(self.stake(stake))
+                    .sol_staker_stake_authority(self.authority(stake).pubkey())
+                    .sol_staker_native_stake(self.sol_staker_stake_managers[stake - 1].sol_stake)
+                    .previous_validator_stake(self.stake(VALIDATOR))
+                    .previous_validator_stake_authority(self.authority(VALIDATOR).pubkey())
+                    .current_validator_stake(self.stake(VALIDATOR))
+                    .current_validator_stake_authority(self.authority(VALIDATOR).pubkey())
+                    .sol_stake_view_program(paladin_sol_stake_view_program_client::ID)
+                    .keeper_recipient(Some(self.context.payer.pubkey()))
+                    .instruction(),
+                vec![],
+            ),
+            Op::DepositLamports { lamports } => (
+                system_instruction::transfer(
+                    &self.context.payer.pubkey(),
+                    &config_manager.config,
+                    lamports,
+                ),
+                vec![],
+            ),
+            Op::WarpEpoch => {
+                // Activates the staked tokens and ends the unstake cooldown.
+                warp_to_next_epoch(&mut self.context).await;
+                let mut clock = self.clock().await;
+                clock.unix_timestamp += 2;
+                self.context.set_sysvar(&clock);
+
+                return Some(Vec::new());
+            }
+        };
+
+        let signers = signers
+            .into_iter()
+            .map(Keypair::insecure_clone)
+            .collect::<Vec<_>>();
+        self.process(&[instruction], &signers.iter().collect::<Vec<_>>())
+            .await
+    }
+
+    async fn clock(&mut self) -> Clock {
+        self.context
+            .banks_client
+            .get_sysvar::<Clock>()
+            .await
+            .unwrap()
+    }
+
+    /// Model of the accounts before any operation.
+    async fn model(&mut self) -> Model {
+        let mut model = Model {
+            wallet: WALLET_AMOUNT,
+            ..Default::default()
+        };
+
+        let account = get_account!(self.context, self.stake(VALIDATOR));
+        let validator_stake = ValidatorStake::from_bytes(account.data.as_ref()).unwrap();
+        model.lamports[VALIDATOR] = validator_stake.total_staked_lamports_amount;
+        model.validator_lamports_min = validator_stake.total_staked_lamports_amount_min;
+        for index in 1..STAKES {
+            let account = get_account!(self.context, self.stake(index));
+            model.lamports[index] = SolStakerStake::from_bytes(account.data.as_ref())
+                .unwrap()
+                .lamports_amount;
+        }
+
+        model
+    }
+
+    async fn authorities_lamports(&mut self) -> u64 {
+        let validator_authority = self.validator_stake_manager.authority.pubkey();
+        let sol_staker_authority = self.sol_staker_stake_managers[0].authority.pubkey();
+
+        let mut lamports = 0;
+        for authority in [validator_authority, sol_staker_authority] {
+            lamports += self
+                .context
+                .banks_client
+                .get_balance(authority)
+                .await
+                .unwrap();
+        }
+        lamports
+    }
+
+    /// Simulates `AuditInvariants` over every stake account of the config.
+    async fn audit(&mut self) -> AuditReturnData {
+        let stakes = (0..STAKES)
+            .map(|index| AccountMeta::new_readonly(self.stake(index), false))
+            .collect::<Vec<_>>();
+        let ix = AuditInvariantsBuilder::new()
+            .config(self.config_manager.config)
+            .vault(self.config_manager.vault)
+            .vault_holder_rewards(self.config_manager.vault_holder_rewards)
+            .add_remaining_accounts(&stakes)
+            .instruction();
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&self.context.payer.pubkey()),
+            &[&self.context.payer],
+            self.context.last_blockhash,
+        );
+
+        let simulation = self
+            .context
+            .banks_client
+            .simulate_transaction(tx)
+            .await
+            .unwrap();
+        assert!(matches!(simulation.result, Some(Ok(()))));
+        let return_data = simulation.simulation_details.unwrap().return_data.unwrap();
+
+        AuditReturnData::from_return_data(&return_data.program_id, &return_data.data).unwrap()
+    }
+
+    /// Checks the accounts against the model.
+    async fn check(&mut self, model: &Model, trace: &str) {
+        let account = get_account!(self.context, self.config_manager.config);
+        let config = Config::from_bytes(account.data.as_ref()).unwrap();
+
+        let mut effective_amount = 0;
+        for index in 0..STAKES {
+            let account = get_account!(self.context, self.stake(index));
+            let delegation = match index {
+                VALIDATOR => {
+                    ValidatorStake::from_bytes(account.data.as_ref())
+                        .unwrap()
+                        .delegation
+                }
+                _ => {
+                    SolStakerStake::from_bytes(account.data.as_ref())
+                        .unwrap()
+                        .delegation
+                }
+            };
+
+            assert_eq!(
+                delegation.staked_amount, model.staked[index],
+                "{trace}: stake {index}"
+            );
+            assert_eq!(
+                delegation.effective_amount, model.effective[index],
+                "{trace}: effective amount of stake {index}"
+            );
+            assert_eq!(
+                delegation.unstake_cooldown, model.unstake_cooldown[index],
+                "{trace}: unstake cooldown of stake {index}"
+            );
+            effective_amount += delegation.effective_amount;
+        }
+
+        // The effective amount of the config is the sum of its delegations.
+        assert_eq!(
+            config.token_amount_effective, effective_amount,
+            "{trace}: token amount effective"
+        );
+
+        // Tokens are only moved between the staker and the vault.
+        let account = get_account!(
+            self.context,
+            self.config_manager.rewards_manager.owner_token_account
+        );
+        let wallet = TokenAccount::unpack(&account.data).unwrap();
+        assert_eq!(wallet.amount, model.wallet, "{trace}: wallet");
+
+        // Rewards paid (to the authorities and to the keeper) and owed never
+        // exceed the lamports deposited.
+        let paid =
+            self.authorities_lamports().await - self.authority_lamports + self.keeper_rewards;
+        assert!(
+            paid + config.undistributed_rewards + config.unclaimed_protocol_fees <= model.deposited,
+            "{trace}: paid {paid}, undistributed {}, deposited {}",
+            config.undistributed_rewards,
+            model.deposited
+        );
+
+        let report = self.audit().await;
+        assert!(report.is_consistent(), "{trace}: {report:?}");
+    }
+}
+
+async fn run(seed: u64, steps: u64) {
+    let mut rng = Rng(seed.wrapping_mul(0x9e37_79b9_7f4a_7c15) | 1);
+    let mut harness = Harness::new().await;
+    let mut model = harness.model().await;
+
+    for step in 0..steps {
+        let op = model.next_op(&mut rng);
+        let trace = format!("seed {seed}, step {step}, {op:?}");
+
+        let clock = harness.clock().await;
+        model.epoch = clock.epoch;
+        model.now = clock.unix_timestamp as u64;
+
+        let rejected = model.rejects(&op);
+        let events = harness.execute(&op).await;
+        assert_eq!(events.is_none(), rejected, "{trace}: rejected");
+        if let Some(events) = events {
+            model.apply(&op, &events);
+        }
+
+        harness.check(&model, &trace).await;
+    }
+}
+
+fn env(name: &str) -> Option<u64> {
+    std::env::var(name).ok().map(|value| value.parse().unwrap())
+}
+
+#[tokio::test]
+async fn random_instruction_sequences() {
+    let steps = env("FUZZ_STEPS").unwrap_or(DEFAULT_STEPS);
+
+    match env("FUZZ_SEED") {
+        Some(seed) => run(seed, steps).await,
+        None => {
+            for seed in 1..=DEFAULT_SEEDS {
+                run(seed, steps).await;
+            }
+        }
+    }
+}
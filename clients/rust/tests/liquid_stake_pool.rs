@@ -0,0 +1,754 @@
+#![cfg(feature = "test-sbf")]
+
+mod setup;
+
+use borsh::BorshSerialize;
+use paladin_stake_program_client::{
+    accounts::{LiquidStakePool, ReceiptRewards, ValidatorStake},
+    errors::PaladinStakeProgramError,
+    instructions::{
+        HarvestReceiptRewardsBuilder, InitializeLiquidStakePoolBuilder,
+        InitializeReceiptRewardsBuilder, LiquidStakeTokensBuilder, LiquidUnstakeTokensBuilder,
+        SetValidatorAuthorityOverrideBuilder, ValidatorStakeTokensBuilder, ValidatorSyncAuthority,
+    },
+    pdas::{
+        find_liquid_stake_pool_pda, find_receipt_rewards_pda, find_validator_authority_override_pda,
+    },
+};
+use setup::{
+    config::{create_ata, ConfigManager},
+    setup,
+    stake::warp_to_next_epoch,
+    token::{create_mint, mint_to},
+    validator_stake::ValidatorStakeManager,
+};
+use solana_program_test::{tokio, BanksClientError, ProgramTestContext};
+use solana_sdk::{
+    account::{Account, AccountSharedData},
+    instruction::InstructionError,
+    program_pack::Pack,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    system_program,
+    transaction::Transaction,
+};
+use spl_associated_token_account::get_associated_token_address;
+use spl_token::state::Account as TokenAccount;
+
+struct Fixture {
+    config_manager: ConfigManager,
+    validator_stake_manager: ValidatorStakeManager,
+    pool: Pubkey,
+    receipt_mint: Pubkey,
+    holder: Holder,
+}
+
+struct Holder {
+    keypair: Keypair,
+    token_account: Pubkey,
+    receipt_account: Pubkey,
+    receipt_rewards: Pubkey,
+}
+
+/// Creates a system account with the given lamports.
+fn fund(context: &mut ProgramTestContext, address: &Pubkey, lamports: u64) {
+    context.set_account(
+        address,
+        &AccountSharedData::from(Account {
+            lamports,
+            ..Default::default()
+        }),
+    );
+}
+
+async fn initialize_pool(
+    context: &mut ProgramTestContext,
+    config_manager: &ConfigManager,
+    validator_stake_manager: &ValidatorStakeManager,
+    receipt_mint: &Pubkey,
+) -> Result<(), BanksClientError> {
+    let (pool, _) = find_liquid_stake_pool_pda(&validator_stake_manager.stake);
+    let rent = context.banks_client.get_rent().await.unwrap();
+    fund(context, &pool, rent.minimum_balance(LiquidStakePool::LEN));
+
+    let ix = InitializeLiquidStakePoolBuilder::new()
+        .config(config_manager.config)
+        .validator_stake(validator_stake_manager.stake)
+        .liquid_stake_pool(pool)
+        .vault(config_manager.vault)
+        .mint(config_manager.mint)
+        .receipt_mint(*receipt_mint)
+        .receipt_token_program(spl_token::ID)
+        .system_program(system_program::ID)
+        .instruction();
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await
+}
+
+async fn setup_fixture(context: &mut ProgramTestContext) -> Fixture {
+    // Given a config and a validator stake account with 1 SOL staked, whose
+    // authority is the liquid stake pool.
+    let config_manager = ConfigManager::new(context).await;
+    let validator_stake_manager = ValidatorStakeManager::new(context, &config_manager.config).await;
+    let (pool, _) = find_liquid_stake_pool_pda(&validator_stake_manager.stake);
+
+    let mut account = get_account!(context, validator_stake_manager.stake);
+    let mut stake_account = ValidatorStake::from_bytes(account.data.as_ref()).unwrap();
+    stake_account.total_staked_lamports_amount = 1_000_000_000;
+    stake_account.delegation.authority = pool;
+    account.data = stake_account.try_to_vec().unwrap();
+    context.set_account(&validator_stake_manager.stake, &account.into());
+
+    // And a receipt mint controlled by the pool.
+    let receipt_mint = Keypair::new();
+    create_mint(context, &receipt_mint, &pool, None, 0)
+        .await
+        .unwrap();
+
+    // And an initialized liquid stake pool.
+    initialize_pool(
+        context,
+        &config_manager,
+        &validator_stake_manager,
+        &receipt_mint.pubkey(),
+    )
+    .await
+    .unwrap();
+
+    // And a holder with 100 tokens and a receipt token account.
+    let holder = create_holder(context, &config_manager, &pool, &receipt_mint.pubkey()).await;
+
+    Fixture {
+        config_manager,
+        validator_stake_manager,
+        pool,
+        receipt_mint: receipt_mint.pubkey(),
+        holder,
+    }
+}
+
+/// Creates a holder with 100 tokens, a receipt token account and the rewards
+/// tracking account of the receipt token account.
+async fn create_holder(
+    context: &mut ProgramTestContext,
+    config_manager: &ConfigManager,
+    pool: &Pubkey,
+    receipt_mint: &Pubkey,
+) -> Holder {
+    let keypair = Keypair::new();
+    fund(context, &keypair.pubkey(), 100_000_000);
+
+    create_ata(context, &keypair.pubkey(), &config_manager.mint)
+        .await
+        .unwrap();
+    let token_account = get_associated_token_address(&keypair.pubkey(), &config_manager.mint);
+    mint_to(
+        context,
+        &config_manager.mint,
+        &config_manager.mint_authority,
+        &token_account,
+        100,
+    )
+    .await
+    .unwrap();
+
+    create_ata(context, &keypair.pubkey(), receipt_mint)
+        .await
+        .unwrap();
+    let receipt_account = get_associated_token_address(&keypair.pubkey(), receipt_mint);
+
+    let (receipt_rewards, _) = find_receipt_rewards_pda(&receipt_account, pool);
+    let rent = context.banks_client.get_rent().await.unwrap();
+    fund(
+        context,
+        &receipt_rewards,
+        rent.minimum_balance(ReceiptRewards::LEN),
+    );
+
+    let ix = InitializeReceiptRewardsBuilder::new()
+        .liquid_stake_pool(*pool)
+        .receipt_mint(*receipt_mint)
+        .receipt_token_account(receipt_account)
+        .receipt_rewards(receipt_rewards)
+        .system_program(system_program::ID)
+        .instruction();
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    Holder {
+        keypair,
+        token_account,
+        receipt_account,
+        receipt_rewards,
+    }
+}
+
+async fn liquid_stake(
+    context: &mut ProgramTestContext,
+    fixture: &Fixture,
+    holder: &Holder,
+    amount: u64,
+) {
+    let ix = LiquidStakeTokensBuilder::new()
+        .config(fixture.config_manager.config)
+        .holder_rewards_pool(fixture.config_manager.rewards_manager.pool)
+        .holder_rewards_pool_token_account(
+            fixture.config_manager.rewards_manager.pool_token_account,
+        )
+        .validator_stake(fixture.validator_stake_manager.stake)
+        .liquid_stake_pool(fixture.pool)
+        .source_token_account(holder.token_account)
+        .source_token_account_authority(holder.keypair.pubkey())
+        .mint(fixture.config_manager.mint)
+        .vault_pda(fixture.config_manager.vault_pda)
+        .vault(fixture.config_manager.vault)
+        .vault_holder_rewards(fixture.config_manager.vault_holder_rewards)
+        .token_program(spl_token::ID)
+        .rewards_program(paladin_rewards_program_client::ID)
+        .receipt_mint(fixture.receipt_mint)
+        .destination_receipt_account(holder.receipt_account)
+        .destination_receipt_rewards(holder.receipt_rewards)
+        .receipt_token_program(spl_token::ID)
+        .stake_program(paladin_stake_program_client::ID)
+        .amount(amount)
+        .instruction();
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &holder.keypair],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+}
+
+async fn liquid_unstake(
+    context: &mut ProgramTestContext,
+    fixture: &Fixture,
+    holder: &Holder,
+    amount: u64,
+) -> Result<(), BanksClientError> {
+    let ix = LiquidUnstakeTokensBuilder::new()
+        .config(fixture.config_manager.config)
+        .holder_rewards_pool(fixture.config_manager.rewards_manager.pool)
+        .holder_rewards_pool_token_account(
+            fixture.config_manager.rewards_manager.pool_token_account,
+        )
+        .validator_stake(fixture.validator_stake_manager.stake)
+        .liquid_stake_pool(fixture.pool)
+        .vault(fixture.config_manager.vault)
+        .vault_pda(fixture.config_manager.vault_pda)
+        .vault_holder_rewards(fixture.config_manager.vault_holder_rewards)
+        .mint(fixture.config_manager.mint)
+        .destination_token_account(holder.token_account)
+        .token_program(spl_token::ID)
+        .rewards_program(paladin_rewards_program_client::ID)
+        .receipt_mint(fixture.receipt_mint)
+        .source_receipt_account(holder.receipt_account)
+        .source_receipt_rewards(holder.receipt_rewards)
+        .source_receipt_authority(holder.keypair.pubkey())
+        .receipt_token_program(spl_token::ID)
+        .stake_program(paladin_stake_program_client::ID)
+        .amount(amount)
+        .instruction();
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &holder.keypair],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await
+}
+
+async fn harvest_receipt_rewards(
+    context: &mut ProgramTestContext,
+    fixture: &Fixture,
+    holder: &Holder,
+) {
+    let ix = HarvestReceiptRewardsBuilder::new()
+        .liquid_stake_pool(fixture.pool)
+        .receipt_mint(fixture.receipt_mint)
+        .receipt_token_account(holder.receipt_account)
+        .receipt_rewards(holder.receipt_rewards)
+        .receipt_token_account_owner(holder.keypair.pubkey())
+        .instruction();
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+}
+
+async fn token_balance(context: &mut ProgramTestContext, token_account: Pubkey) -> u64 {
+    let account = get_account!(context, token_account);
+    TokenAccount::unpack(&account.data).unwrap().amount
+}
+
+#[tokio::test]
+async fn liquid_stake_tokens_mints_receipt_tokens() {
+    let mut context = setup(&[]).await;
+    let fixture = setup_fixture(&mut context).await;
+
+    // When the holder stakes 100 tokens through the pool.
+    liquid_stake(&mut context, &fixture, &fixture.holder, 100).await;
+
+    // Then the tokens are staked on the pooled validator stake.
+    let account = get_account!(context, fixture.validator_stake_manager.stake);
+    let stake_account = ValidatorStake::from_bytes(account.data.as_ref()).unwrap();
+    assert_eq!(stake_account.delegation.staked_amount, 100);
+    assert_eq!(stake_account.delegation.authority, fixture.pool);
+
+    // And the holder received 100 receipt tokens.
+    assert_eq!(
+        token_balance(&mut context, fixture.holder.token_account).await,
+        0
+    );
+    assert_eq!(
+        token_balance(&mut context, fixture.holder.receipt_account).await,
+        100
+    );
+
+    let account = get_account!(context, fixture.holder.receipt_rewards);
+    let receipt_rewards = ReceiptRewards::from_bytes(account.data.as_ref()).unwrap();
+    assert_eq!(receipt_rewards.unclaimed_rewards, 0);
+
+    // And the receipt tokens are activating until the next epoch.
+    assert_eq!(receipt_rewards.balance, 0);
+    assert_eq!(receipt_rewards.activating_balance, 100);
+
+    let account = get_account!(context, fixture.pool);
+    let pool = LiquidStakePool::from_bytes(account.data.as_ref()).unwrap();
+    assert_eq!(pool.tracked_supply, 0);
+}
+
+#[tokio::test]
+async fn liquid_stake_tokens_after_donation_attempt_mints_receipt_tokens() {
+    let mut context = setup(&[]).await;
+    let fixture = setup_fixture(&mut context).await;
+
+    // Given a holder with 1 receipt token.
+    liquid_stake(&mut context, &fixture, &fixture.holder, 1).await;
+
+    // When the holder tries to donate tokens directly to the pooled stake.
+    let ix = ValidatorStakeTokensBuilder::new()
+        .config(fixture.config_manager.config)
+        .holder_rewards_pool(fixture.config_manager.rewards_manager.pool)
+        .holder_rewards_pool_token_account(
+            fixture.config_manager.rewards_manager.pool_token_account,
+        )
+        .validator_stake(fixture.validator_stake_manager.stake)
+        .validator_stake_authority(fixture.pool)
+        .source_token_account(fixture.holder.token_account)
+        .source_token_account_authority(fixture.holder.keypair.pubkey())
+        .mint(fixture.config_manager.mint)
+        .vault(fixture.config_manager.vault)
+        .vault_pda(fixture.config_manager.vault_pda)
+        .vault_holder_rewards(fixture.config_manager.vault_holder_rewards)
+        .token_program(spl_token::ID)
+        .rewards_program(paladin_rewards_program_client::ID)
+        .amount(99)
+        .instruction();
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &fixture.holder.keypair],
+        context.last_blockhash,
+    );
+    let err = context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .unwrap_err();
+
+    // Then we expect an error.
+    assert_instruction_error!(err, InstructionError::MissingRequiredSignature);
+
+    // When a second holder stakes 100 tokens through the pool.
+    let other_holder = create_holder(
+        &mut context,
+        &fixture.config_manager,
+        &fixture.pool,
+        &fixture.receipt_mint,
+    )
+    .await;
+    liquid_stake(&mut context, &fixture, &other_holder, 100).await;
+
+    // Then the second holder received 100 receipt tokens.
+    assert_eq!(
+        token_balance(&mut context, other_holder.receipt_account).await,
+        100
+    );
+
+    let account = get_account!(context, fixture.validator_stake_manager.stake);
+    let stake_account = ValidatorStake::from_bytes(account.data.as_ref()).unwrap();
+    assert_eq!(stake_account.delegation.staked_amount, 101);
+}
+
+#[tokio::test]
+async fn harvest_receipt_rewards_activates_receipt_tokens() {
+    let mut context = setup(&[]).await;
+    let fixture = setup_fixture(&mut context).await;
+    liquid_stake(&mut context, &fixture, &fixture.holder, 100).await;
+
+    // Given 50 lamports of rewards paid to the pool while the receipt tokens
+    // are activating.
+    let mut account = get_account!(context, fixture.pool);
+    account.lamports += 50;
+    context.set_account(&fixture.pool, &account.into());
+
+    // When the rewards of the receipt token account are harvested in the
+    // same epoch.
+    harvest_receipt_rewards(&mut context, &fixture, &fixture.holder).await;
+
+    // Then the holder did not receive any rewards.
+    let account = get_account!(context, fixture.holder.keypair.pubkey());
+    assert_eq!(account.lamports, 100_000_000);
+
+    // When the rewards are harvested on the next epoch.
+    warp_to_next_epoch(&mut context).await;
+    harvest_receipt_rewards(&mut context, &fixture, &fixture.holder).await;
+
+    // Then the receipt tokens are earning rewards.
+    let account = get_account!(context, fixture.holder.receipt_rewards);
+    let receipt_rewards = ReceiptRewards::from_bytes(account.data.as_ref()).unwrap();
+    assert_eq!(receipt_rewards.balance, 100);
+    assert_eq!(receipt_rewards.activating_balance, 0);
+
+    let account = get_account!(context, fixture.pool);
+    let pool = LiquidStakePool::from_bytes(account.data.as_ref()).unwrap();
+    assert_eq!(pool.tracked_supply, 100);
+}
+
+#[tokio::test]
+async fn harvest_receipt_rewards_pays_pool_rewards() {
+    let mut context = setup(&[]).await;
+    let fixture = setup_fixture(&mut context).await;
+    liquid_stake(&mut context, &fixture, &fixture.holder, 100).await;
+
+    // Given receipt tokens activated on the next epoch.
+    warp_to_next_epoch(&mut context).await;
+    harvest_receipt_rewards(&mut context, &fixture, &fixture.holder).await;
+    context.get_new_latest_blockhash().await.unwrap();
+
+    // And 50 lamports of rewards paid to the pool.
+    let mut account = get_account!(context, fixture.pool);
+    account.lamports += 50;
+    context.set_account(&fixture.pool, &account.into());
+
+    // When the rewards of the receipt token account are harvested.
+    harvest_receipt_rewards(&mut context, &fixture, &fixture.holder).await;
+
+    // Then the holder received all the rewards.
+    let account = get_account!(context, fixture.holder.keypair.pubkey());
+    assert_eq!(account.lamports, 100_000_050);
+
+    // And the pool no longer tracks them.
+    let account = get_account!(context, fixture.pool);
+    let pool = LiquidStakePool::from_bytes(account.data.as_ref()).unwrap();
+    assert_eq!(pool.lamports_last, account.lamports);
+    assert_eq!(pool.accumulated_rewards_per_token, 500_000_000_000_000_000);
+    assert_eq!(pool.tracked_supply, 100);
+}
+
+#[tokio::test]
+async fn harvest_receipt_rewards_redistributes_forfeited_rewards() {
+    let mut context = setup(&[]).await;
+    let fixture = setup_fixture(&mut context).await;
+    liquid_stake(&mut context, &fixture, &fixture.holder, 100).await;
+
+    // Given receipt tokens activated on the next epoch.
+    warp_to_next_epoch(&mut context).await;
+    harvest_receipt_rewards(&mut context, &fixture, &fixture.holder).await;
+    context.get_new_latest_blockhash().await.unwrap();
+
+    // And 50 receipt tokens moved to a token account without a receipt
+    // rewards account.
+    let other = Pubkey::new_unique();
+    create_ata(&mut context, &other, &fixture.receipt_mint)
+        .await
+        .unwrap();
+    let ix = spl_token::instruction::transfer(
+        &spl_token::ID,
+        &fixture.holder.receipt_account,
+        &get_associated_token_address(&other, &fixture.receipt_mint),
+        &fixture.holder.keypair.pubkey(),
+        &[],
+        50,
+    )
+    .unwrap();
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &fixture.holder.keypair],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // And 100 lamports of rewards paid to the pool.
+    let mut account = get_account!(context, fixture.pool);
+    account.lamports += 100;
+    context.set_account(&fixture.pool, &account.into());
+
+    // When the rewards of the holder are harvested.
+    harvest_receipt_rewards(&mut context, &fixture, &fixture.holder).await;
+
+    // Then the holder received the rewards of the 50 tokens it kept.
+    let account = get_account!(context, fixture.holder.keypair.pubkey());
+    assert_eq!(account.lamports, 100_000_050);
+
+    // And the rewards of the moved tokens were credited back to the pool.
+    let account = get_account!(context, fixture.pool);
+    let pool = LiquidStakePool::from_bytes(account.data.as_ref()).unwrap();
+    assert_eq!(pool.lamports_last + 50, account.lamports);
+    assert_eq!(pool.tracked_supply, 50);
+
+    // When the rewards of the holder are harvested again.
+    context.get_new_latest_blockhash().await.unwrap();
+    harvest_receipt_rewards(&mut context, &fixture, &fixture.holder).await;
+
+    // Then the holder received the rewards credited back to the pool.
+    let account = get_account!(context, fixture.holder.keypair.pubkey());
+    assert_eq!(account.lamports, 100_000_100);
+
+    // And no rewards are left in the pool.
+    let account = get_account!(context, fixture.pool);
+    let pool = LiquidStakePool::from_bytes(account.data.as_ref()).unwrap();
+    assert_eq!(pool.lamports_last, account.lamports);
+}
+
+#[tokio::test]
+async fn liquid_unstake_tokens_burns_receipt_tokens() {
+    let mut context = setup(&[]).await;
+    let fixture = setup_fixture(&mut context).await;
+    liquid_stake(&mut context, &fixture, &fixture.holder, 100).await;
+
+    // When the holder burns 5 receipt tokens (the maximum deactivation
+    // amount).
+    let ix = LiquidUnstakeTokensBuilder::new()
+        .config(fixture.config_manager.config)
+        .holder_rewards_pool(fixture.config_manager.rewards_manager.pool)
+        .holder_rewards_pool_token_account(
+            fixture.config_manager.rewards_manager.pool_token_account,
+        )
+        .validator_stake(fixture.validator_stake_manager.stake)
+        .liquid_stake_pool(fixture.pool)
+        .vault(fixture.config_manager.vault)
+        .vault_pda(fixture.config_manager.vault_pda)
+        .vault_holder_rewards(fixture.config_manager.vault_holder_rewards)
+        .mint(fixture.config_manager.mint)
+        .destination_token_account(fixture.holder.token_account)
+        .token_program(spl_token::ID)
+        .rewards_program(paladin_rewards_program_client::ID)
+        .receipt_mint(fixture.receipt_mint)
+        .source_receipt_account(fixture.holder.receipt_account)
+        .source_receipt_rewards(fixture.holder.receipt_rewards)
+        .source_receipt_authority(fixture.holder.keypair.pubkey())
+        .receipt_token_program(spl_token::ID)
+        .stake_program(paladin_stake_program_client::ID)
+        .amount(5)
+        .instruction();
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &fixture.holder.keypair],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // Then the holder received 5 tokens for the burned receipt tokens.
+    assert_eq!(
+        token_balance(&mut context, fixture.holder.token_account).await,
+        5
+    );
+    assert_eq!(
+        token_balance(&mut context, fixture.holder.receipt_account).await,
+        95
+    );
+
+    let account = get_account!(context, fixture.validator_stake_manager.stake);
+    let stake_account = ValidatorStake::from_bytes(account.data.as_ref()).unwrap();
+    assert_eq!(stake_account.delegation.staked_amount, 95);
+
+    let account = get_account!(context, fixture.holder.receipt_rewards);
+    let receipt_rewards = ReceiptRewards::from_bytes(account.data.as_ref()).unwrap();
+    assert_eq!(receipt_rewards.activating_balance, 95);
+
+    // And the cooldown of the pooled stake is set.
+    assert!(stake_account.delegation.unstake_cooldown > 0);
+}
+
+#[tokio::test]
+async fn fail_liquid_unstake_tokens_from_split_receipt_accounts() {
+    let mut context = setup(&[]).await;
+    let fixture = setup_fixture(&mut context).await;
+
+    // Given a holder with 100 receipt tokens.
+    liquid_stake(&mut context, &fixture, &fixture.holder, 100).await;
+
+    // And 50 receipt tokens moved to a second receipt token account.
+    let other_holder = create_holder(
+        &mut context,
+        &fixture.config_manager,
+        &fixture.pool,
+        &fixture.receipt_mint,
+    )
+    .await;
+    let ix = spl_token::instruction::transfer(
+        &spl_token::ID,
+        &fixture.holder.receipt_account,
+        &other_holder.receipt_account,
+        &fixture.holder.keypair.pubkey(),
+        &[],
+        50,
+    )
+    .unwrap();
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &fixture.holder.keypair],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // When the holder burns more than the maximum deactivation amount of the
+    // pooled stake.
+    let err = liquid_unstake(&mut context, &fixture, &fixture.holder, 6)
+        .await
+        .unwrap_err();
+
+    // Then we expect an error.
+    assert_custom_error!(
+        err,
+        PaladinStakeProgramError::MaximumDeactivationAmountExceeded
+    );
+
+    // When the holder burns the maximum deactivation amount of the pooled
+    // stake.
+    liquid_unstake(&mut context, &fixture, &fixture.holder, 5)
+        .await
+        .unwrap();
+
+    // And burns receipt tokens of the second account during the cooldown.
+    let err = liquid_unstake(&mut context, &fixture, &other_holder, 1)
+        .await
+        .unwrap_err();
+
+    // Then we expect an error.
+    assert_custom_error!(err, PaladinStakeProgramError::ActiveUnstakeCooldown);
+
+    // And only 5 tokens left the pool.
+    assert_eq!(
+        token_balance(&mut context, other_holder.receipt_account).await,
+        50
+    );
+
+    let account = get_account!(context, fixture.validator_stake_manager.stake);
+    let stake_account = ValidatorStake::from_bytes(account.data.as_ref()).unwrap();
+    assert_eq!(stake_account.delegation.staked_amount, 95);
+}
+
+#[tokio::test]
+async fn fail_validator_sync_authority_of_liquid_stake_pool() {
+    let mut context = setup(&[]).await;
+    let fixture = setup_fixture(&mut context).await;
+    liquid_stake(&mut context, &fixture, &fixture.holder, 100).await;
+
+    // Given the vote withdraw authority resets its authority override to
+    // itself.
+    let withdraw_authority = &fixture.validator_stake_manager.authority;
+    let (authority_override, _) = find_validator_authority_override_pda(
+        &fixture.validator_stake_manager.vote,
+        &fixture.config_manager.config,
+    );
+    let rent = context.banks_client.get_rent().await.unwrap();
+    fund(
+        &mut context,
+        &authority_override,
+        rent.minimum_balance(std::mem::size_of::<Pubkey>()),
+    );
+
+    let ix = SetValidatorAuthorityOverrideBuilder::new()
+        .config(fixture.config_manager.config)
+        .validator_vote(fixture.validator_stake_manager.vote)
+        .withdraw_authority(withdraw_authority.pubkey())
+        .validator_authority_override(authority_override)
+        .new_authority(withdraw_authority.pubkey())
+        .system_program(system_program::ID)
+        .instruction();
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, withdraw_authority],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // When it tries to take over the pooled stake by syncing its authority.
+    let ix = ValidatorSyncAuthority {
+        config: fixture.config_manager.config,
+        validator_stake: fixture.validator_stake_manager.stake,
+        validator_vote: fixture.validator_stake_manager.vote,
+        validator_authority_override: authority_override,
+    }
+    .instruction();
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    let err = context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .unwrap_err();
+
+    // Then we expect an error.
+    assert_custom_error!(err, PaladinStakeProgramError::InvalidAuthority);
+
+    // And the pool is still the authority of the pooled stake.
+    let account = get_account!(context, fixture.validator_stake_manager.stake);
+    let stake_account = ValidatorStake::from_bytes(account.data.as_ref()).unwrap();
+    assert_eq!(stake_account.delegation.authority, fixture.pool);
+    assert_eq!(stake_account.delegation.staked_amount, 100);
+}
+
+#[tokio::test]
+async fn fail_initialize_liquid_stake_pool_without_pool_authority() {
+    let mut context = setup(&[]).await;
+
+    // Given a validator stake account whose authority is not the pool.
+    let config_manager = ConfigManager::new(&mut context).await;
+    let validator_stake_manager =
+        ValidatorStakeManager::new(&mut context, &config_manager.config).await;
+    let (pool, _) = find_liquid_stake_pool_pda(&validator_stake_manager.stake);
+
+    let receipt_mint = Keypair::new();
+    create_mint(&mut context, &receipt_mint, &pool, None, 0)
+        .await
+        .unwrap();
+
+    // When we try to initialize the pool.
+    let err = initialize_pool(
+        &mut context,
+        &config_manager,
+        &validator_stake_manager,
+        &receipt_mint.pubkey(),
+    )
+    .await
+    .unwrap_err();
+
+    // Then we expect an error.
+    assert_custom_error!(err, PaladinStakeProgramError::InvalidAuthority);
+}
@@ -0,0 +1,415 @@
+#![cfg(feature = "test-sbf")]
+
+mod setup;
+
+use borsh::BorshSerialize;
+use paladin_stake_program_client::{
+    accounts::{Config, DelegatedVotingPower, ValidatorStake},
+    errors::PaladinStakeProgramError,
+    instructions::{
+        GetPendingRewardsBuilder, HarvestValidatorRewardsBuilder,
+        InitializeDelegatedVotingPowerBuilder, MigrateAccountBuilder, SetVoteDelegateBuilder,
+        ValidatorSyncAuthority,
+    },
+    pdas::{find_delegated_voting_power_pda, find_validator_authority_override_pda},
+    return_data::HarvestReturnData,
+};
+use setup::{config::ConfigManager, setup, validator_stake::ValidatorStakeManager};
+use solana_program_test::{tokio, ProgramTestContext};
+use solana_sdk::{
+    account::{Account, AccountSharedData},
+    instruction::{AccountMeta, Instruction, InstructionError},
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    system_instruction,
+    transaction::Transaction,
+};
+
+/// Size of a `ValidatorStake` account before the `version` field was added.
+const LEGACY_VALIDATOR_STAKE_LEN: usize = 144;
+
+/// Size of a `Config` account before the `version` field was added.
+const LEGACY_CONFIG_LEN: usize = 192;
+
+/// Rewrites the validator stake account using the legacy layout, which is the
+/// first `LEGACY_VALIDATOR_STAKE_LEN` bytes of the current layout, holding
+/// `lamports`.
+async fn downgrade_validator_stake(
+    context: &mut ProgramTestContext,
+    stake: &Pubkey,
+    lamports: u64,
+) {
+    let account = get_account!(context, *stake);
+
+    context.set_account(
+        stake,
+        &AccountSharedData::from(Account {
+            lamports,
+            data: account.data[..LEGACY_VALIDATOR_STAKE_LEN].to_vec(),
+            owner: account.owner,
+            ..Default::default()
+        }),
+    );
+}
+
+/// Rent-exempt minimum of a validator stake account using the legacy layout.
+async fn legacy_rent(context: &mut ProgramTestContext) -> u64 {
+    let rent = context.banks_client.get_rent().await.unwrap();
+    rent.minimum_balance(LEGACY_VALIDATOR_STAKE_LEN)
+}
+
+/// Builds a `ValidatorSyncAuthority` instruction, which only modifies fields
+/// present on the legacy layout.
+fn sync_authority_ix(
+    config: &Pubkey,
+    validator_stake_manager: &ValidatorStakeManager,
+) -> Instruction {
+    ValidatorSyncAuthority {
+        config: *config,
+        validator_stake: validator_stake_manager.stake,
+        validator_vote: validator_stake_manager.vote,
+        validator_authority_override: find_validator_authority_override_pda(
+            &validator_stake_manager.vote,
+            config,
+        )
+        .0,
+    }
+    .instruction()
+}
+
+#[tokio::test]
+async fn migrate_legacy_validator_stake() {
+    let mut context = setup(&[]).await;
+
+    // Given a validator stake account using the legacy layout.
+    let config_manager = ConfigManager::new(&mut context).await;
+    let validator_stake_manager =
+        ValidatorStakeManager::new(&mut context, &config_manager.config).await;
+    let expected = get_account!(context, validator_stake_manager.stake);
+    let lamports = legacy_rent(&mut context).await;
+    downgrade_validator_stake(&mut context, &validator_stake_manager.stake, lamports).await;
+
+    // And a payer for the additional rent.
+    let payer = Keypair::new();
+    context.set_account(
+        &payer.pubkey(),
+        &AccountSharedData::from(Account {
+            lamports: 100_000_000,
+            ..Default::default()
+        }),
+    );
+
+    // When we migrate the account.
+    let ix = MigrateAccountBuilder::new()
+        .account(validator_stake_manager.stake)
+        .payer(payer.pubkey())
+        .instruction();
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &payer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // Then the account has the current layout, with the existing data preserved.
+    let account = get_account!(context, validator_stake_manager.stake);
+    assert_eq!(account.data.len(), ValidatorStake::LEN);
+    assert_eq!(account.lamports, expected.lamports);
+    assert_eq!(
+        account.data[..LEGACY_VALIDATOR_STAKE_LEN],
+        expected.data[..LEGACY_VALIDATOR_STAKE_LEN]
+    );
+
+    let stake_account = ValidatorStake::from_bytes(account.data.as_ref()).unwrap();
+    assert_eq!(stake_account.version, 1);
+    assert_eq!(stake_account.vote_delegate.value(), None);
+    assert_eq!(stake_account.activation.activating_amount, 0);
+
+    // And the voting power history is seeded with the current effective amount.
+    assert_eq!(stake_account.checkpoints.count, 1);
+    assert_eq!(stake_account.checkpoints.checkpoints[0].slot, 0);
+    assert_eq!(
+        stake_account.checkpoints.checkpoints[0].effective_amount,
+        stake_account.delegation.effective_amount
+    );
+
+    // And the payer covered the additional rent.
+    let rent = context.banks_client.get_rent().await.unwrap();
+    let payer_account = get_account!(context, payer.pubkey());
+    assert_eq!(
+        payer_account.lamports,
+        100_000_000
+            - (rent.minimum_balance(ValidatorStake::LEN)
+                - rent.minimum_balance(LEGACY_VALIDATOR_STAKE_LEN))
+    );
+}
+
+#[tokio::test]
+async fn migrate_legacy_config_keeps_rewards_unchanged() {
+    let mut context = setup(&[]).await;
+
+    // Given a config account with version 0.
+    let config_manager = ConfigManager::new(&mut context).await;
+
+    let mut account = get_account!(context, config_manager.config);
+    let mut config_account = Config::from_bytes(account.data.as_ref()).unwrap();
+    config_account.version = 0;
+    account.data = config_account.try_to_vec().unwrap();
+    context.set_account(&config_manager.config, &account.into());
+
+    // When we migrate the account.
+    let ix = MigrateAccountBuilder::new()
+        .account(config_manager.config)
+        .payer(context.payer.pubkey())
+        .instruction();
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // Then the version is updated and no lamports were treated as rewards.
+    let account = get_account!(context, config_manager.config);
+    let migrated = Config::from_bytes(account.data.as_ref()).unwrap();
+    assert_eq!(migrated.version, 1);
+    assert_eq!(migrated.lamports_last, config_account.lamports_last);
+    assert_eq!(
+        migrated.accumulated_stake_rewards_per_token,
+        config_account.accumulated_stake_rewards_per_token
+    );
+}
+
+#[tokio::test]
+async fn harvest_with_legacy_config_without_migration() {
+    let mut context = setup(&[]).await;
+
+    // Given a validator stake account with a 65 staked amount.
+    let config_manager = ConfigManager::new(&mut context).await;
+    let validator_stake_manager =
+        ValidatorStakeManager::new(&mut context, &config_manager.config).await;
+
+    let mut account = get_account!(context, validator_stake_manager.stake);
+    let mut stake_account = ValidatorStake::from_bytes(account.data.as_ref()).unwrap();
+    stake_account.delegation.staked_amount = 65;
+    stake_account.delegation.effective_amount = 65;
+    stake_account.total_staked_lamports_amount = 50;
+    account.data = stake_account.try_to_vec().unwrap();
+    context.set_account(&validator_stake_manager.stake, &account.into());
+
+    // And a config account using the legacy layout, holding 26 lamports of new
+    // rewards for a 130 staked amount.
+    let account = get_account!(context, config_manager.config);
+    let mut config_account = Config::from_bytes(account.data.as_ref()).unwrap();
+    let rent = context.banks_client.get_rent().await.unwrap();
+    let lamports = rent.minimum_balance(LEGACY_CONFIG_LEN) + 26;
+    config_account.version = 0;
+    config_account.token_amount_effective = 130;
+    config_account.lamports_last = lamports - 26;
+    context.set_account(
+        &config_manager.config,
+        &AccountSharedData::from(Account {
+            lamports,
+            data: config_account.try_to_vec().unwrap()[..LEGACY_CONFIG_LEN].to_vec(),
+            owner: account.owner,
+            ..Default::default()
+        }),
+    );
+
+    // And the validator stake authority account covers its rent.
+    context.set_account(
+        &validator_stake_manager.authority.pubkey(),
+        &AccountSharedData::from(Account {
+            lamports: 100_000_000,
+            ..Default::default()
+        }),
+    );
+
+    // When we harvest rewards before migrating the config account.
+    let harvest_ix = HarvestValidatorRewardsBuilder::new()
+        .config(config_manager.config)
+        .vault_holder_rewards(config_manager.vault_holder_rewards)
+        .validator_stake(validator_stake_manager.stake)
+        .validator_stake_authority(validator_stake_manager.authority.pubkey())
+        .instruction();
+    let tx = Transaction::new_signed_with_payer(
+        &[harvest_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // Then the rewards are released as soon as they are received.
+    let account = get_account!(context, validator_stake_manager.authority.pubkey());
+    assert_eq!(account.lamports, 100_000_000 + 13);
+
+    // And the config account keeps the legacy layout.
+    let account = get_account!(context, config_manager.config);
+    assert_eq!(account.data.len(), LEGACY_CONFIG_LEN);
+    assert_eq!(account.lamports, lamports - 13);
+}
+
+#[tokio::test]
+async fn sync_authority_of_legacy_validator_stake_without_migration() {
+    let mut context = setup(&[]).await;
+
+    // Given a validator stake account using the legacy layout.
+    let config_manager = ConfigManager::new(&mut context).await;
+    let validator_stake_manager =
+        ValidatorStakeManager::new(&mut context, &config_manager.config).await;
+    let lamports = legacy_rent(&mut context).await;
+    downgrade_validator_stake(&mut context, &validator_stake_manager.stake, lamports).await;
+    let expected = get_account!(context, validator_stake_manager.stake);
+
+    // When we modify the account before migrating it.
+    let ix = sync_authority_ix(&config_manager.config, &validator_stake_manager);
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // Then the account keeps the legacy layout.
+    let account = get_account!(context, validator_stake_manager.stake);
+    assert_eq!(account.data.len(), LEGACY_VALIDATOR_STAKE_LEN);
+    assert_eq!(account.lamports, lamports);
+    assert_eq!(account.data, expected.data);
+}
+
+#[tokio::test]
+async fn fail_set_vote_delegate_on_legacy_validator_stake_without_migration() {
+    let mut context = setup(&[]).await;
+
+    // Given a validator stake account using the legacy layout.
+    let config_manager = ConfigManager::new(&mut context).await;
+    let validator_stake_manager =
+        ValidatorStakeManager::new(&mut context, &config_manager.config).await;
+    let lamports = legacy_rent(&mut context).await;
+    downgrade_validator_stake(&mut context, &validator_stake_manager.stake, lamports).await;
+
+    // And the delegated voting power account of a vote delegate.
+    let delegate = Keypair::new();
+    let (delegated_voting_power, _) =
+        find_delegated_voting_power_pda(&delegate.pubkey(), &config_manager.config);
+    let rent = context.banks_client.get_rent().await.unwrap();
+    let transfer_ix = system_instruction::transfer(
+        &context.payer.pubkey(),
+        &delegated_voting_power,
+        rent.minimum_balance(DelegatedVotingPower::LEN),
+    );
+    let initialize_ix = InitializeDelegatedVotingPowerBuilder::new()
+        .config(config_manager.config)
+        .vote_delegate(delegate.pubkey())
+        .delegated_voting_power(delegated_voting_power)
+        .instruction();
+    let tx = Transaction::new_signed_with_payer(
+        &[transfer_ix, initialize_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // When we try to set a field the legacy layout does not have before
+    // migrating the account.
+    let ix = SetVoteDelegateBuilder::new()
+        .config(config_manager.config)
+        .stake(validator_stake_manager.stake)
+        .stake_authority(validator_stake_manager.authority.pubkey())
+        .vote_delegate(Some(delegate.pubkey()))
+        .add_remaining_account(AccountMeta::new(delegated_voting_power, false))
+        .instruction();
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[
+            &context.payer,
+            &validator_stake_manager.authority,
+            &delegate,
+        ],
+        context.last_blockhash,
+    );
+    let err = context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .unwrap_err();
+
+    // Then we expect an error.
+    assert_custom_error!(err, PaladinStakeProgramError::AccountMigrationRequired);
+}
+
+#[tokio::test]
+async fn read_legacy_validator_stake_without_migration() {
+    let mut context = setup(&[]).await;
+
+    // Given a validator stake account using the legacy layout.
+    let config_manager = ConfigManager::new(&mut context).await;
+    let validator_stake_manager =
+        ValidatorStakeManager::new(&mut context, &config_manager.config).await;
+    let lamports = legacy_rent(&mut context).await;
+    downgrade_validator_stake(&mut context, &validator_stake_manager.stake, lamports).await;
+
+    // When we read the pending rewards of the account before migrating it.
+    let ix = GetPendingRewardsBuilder::new()
+        .config(config_manager.config)
+        .vault_holder_rewards(config_manager.vault_holder_rewards)
+        .stake(validator_stake_manager.stake)
+        .instruction();
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    let simulation = context.banks_client.simulate_transaction(tx).await.unwrap();
+
+    // Then the account is read with the fields it lacks zeroed.
+    simulation.result.unwrap().unwrap();
+    let return_data = simulation.simulation_details.unwrap().return_data.unwrap();
+    let pending =
+        HarvestReturnData::from_return_data(&return_data.program_id, &return_data.data).unwrap();
+    assert_eq!(pending.total_reward(), 0);
+}
+
+#[tokio::test]
+async fn fail_migrate_account_with_wrong_owner() {
+    let mut context = setup(&[]).await;
+
+    // Given an account not owned by the stake program.
+    let account = Pubkey::new_unique();
+    context.set_account(
+        &account,
+        &AccountSharedData::from(Account {
+            lamports: 100_000_000,
+            data: vec![0; ValidatorStake::LEN],
+            owner: Pubkey::new_unique(),
+            ..Default::default()
+        }),
+    );
+
+    // When we try to migrate the account.
+    let ix = MigrateAccountBuilder::new()
+        .account(account)
+        .payer(context.payer.pubkey())
+        .instruction();
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    let err = context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .unwrap_err();
+
+    // Then we expect an error.
+    assert_instruction_error!(err, InstructionError::InvalidAccountOwner);
+}
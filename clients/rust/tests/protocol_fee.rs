@@ -0,0 +1,308 @@
+#![cfg(feature = "test-sbf")]
+
+mod setup;
+
+use borsh::BorshSerialize;
+use paladin_stake_program_client::{
+    accounts::{Config, ValidatorStake},
+    errors::PaladinStakeProgramError,
+    instructions::{
+        HarvestValidatorRewardsBuilder, SetAuthorityBuilder, UpdateConfigBuilder,
+        WithdrawProtocolFeesBuilder,
+    },
+    types::{AuthorityType, ConfigField},
+};
+use setup::{config::ConfigManager, setup, validator_stake::ValidatorStakeManager};
+use solana_program_test::{tokio, BanksClientError, ProgramTestContext};
+use solana_sdk::{
+    account::{Account, AccountSharedData},
+    instruction::InstructionError,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    transaction::Transaction,
+};
+
+/// Creates a rent-exempt account to receive lamports.
+fn create_recipient(context: &mut ProgramTestContext) -> Pubkey {
+    let recipient = Pubkey::new_unique();
+    context.set_account(
+        &recipient,
+        &AccountSharedData::from(Account {
+            lamports: 100_000_000,
+            ..Default::default()
+        }),
+    );
+    recipient
+}
+
+async fn set_fee_recipient(
+    context: &mut ProgramTestContext,
+    config_manager: &ConfigManager,
+    fee_recipient: &Pubkey,
+) {
+    let ix = SetAuthorityBuilder::new()
+        .account(config_manager.config)
+        .authority(config_manager.config_authority.pubkey())
+        .new_authority(*fee_recipient)
+        .authority_type(AuthorityType::FeeRecipient)
+        .instruction();
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &config_manager.config_authority],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+}
+
+async fn withdraw(
+    context: &mut ProgramTestContext,
+    config_manager: &ConfigManager,
+    fee_recipient: &Pubkey,
+) -> Result<(), BanksClientError> {
+    let ix = WithdrawProtocolFeesBuilder::new()
+        .config(config_manager.config)
+        .config_authority(config_manager.config_authority.pubkey())
+        .fee_recipient(*fee_recipient)
+        .instruction();
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &config_manager.config_authority],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await
+}
+
+async fn set_protocol_fee(
+    context: &mut ProgramTestContext,
+    config_manager: &ConfigManager,
+    basis_points: u16,
+) -> Result<(), BanksClientError> {
+    let ix = UpdateConfigBuilder::new()
+        .config(config_manager.config)
+        .config_authority(config_manager.config_authority.pubkey())
+        .config_field(ConfigField::ProtocolFeeBasisPoints(basis_points))
+        .instruction();
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &config_manager.config_authority],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await
+}
+
+/// Creates a validator stake account with 65 effective tokens, out of 130
+/// effective tokens on the config.
+async fn create_stake(
+    context: &mut ProgramTestContext,
+    config_manager: &ConfigManager,
+) -> ValidatorStakeManager {
+    let validator_stake_manager = ValidatorStakeManager::new(context, &config_manager.config).await;
+
+    let mut account = get_account!(context, validator_stake_manager.stake);
+    let mut stake_account = ValidatorStake::from_bytes(account.data.as_ref()).unwrap();
+    stake_account.delegation.staked_amount = 65;
+    stake_account.delegation.effective_amount = 65;
+    stake_account.total_staked_lamports_amount = 50;
+    account.data = stake_account.try_to_vec().unwrap();
+    context.set_account(&validator_stake_manager.stake, &account.into());
+
+    context.set_account(
+        &validator_stake_manager.authority.pubkey(),
+        &AccountSharedData::from(Account {
+            lamports: 100_000_000,
+            ..Default::default()
+        }),
+    );
+
+    let mut account = get_account!(context, config_manager.config);
+    let mut config_account = Config::from_bytes(account.data.as_ref()).unwrap();
+    config_account.token_amount_effective = 130;
+    account.data = config_account.try_to_vec().unwrap();
+    context.set_account(&config_manager.config, &account.into());
+
+    validator_stake_manager
+}
+
+/// Sends the given lamports of stake rewards to the config account.
+async fn send_rewards(context: &mut ProgramTestContext, config: &Pubkey, lamports: u64) {
+    let mut account = get_account!(context, *config);
+    account.lamports += lamports;
+    context.set_account(config, &account.into());
+}
+
+async fn harvest(
+    context: &mut ProgramTestContext,
+    config_manager: &ConfigManager,
+    validator_stake_manager: &ValidatorStakeManager,
+) {
+    let ix = HarvestValidatorRewardsBuilder::new()
+        .config(config_manager.config)
+        .vault_holder_rewards(config_manager.vault_holder_rewards)
+        .validator_stake(validator_stake_manager.stake)
+        .validator_stake_authority(validator_stake_manager.authority.pubkey())
+        .instruction();
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+}
+
+#[tokio::test]
+async fn harvest_withholds_protocol_fee() {
+    let mut context = setup(&[]).await;
+
+    // Given a config account with a 10% protocol fee.
+    let config_manager = ConfigManager::new(&mut context).await;
+    let config = config_manager.config;
+    set_protocol_fee(&mut context, &config_manager, 1_000)
+        .await
+        .unwrap();
+
+    // And a validator stake account with 65 effective tokens, out of 130.
+    let validator_stake_manager = create_stake(&mut context, &config_manager).await;
+
+    // And 2,600 lamports of rewards.
+    send_rewards(&mut context, &config, 2_600).await;
+
+    // When we harvest the stake rewards.
+    //
+    // Calculation:
+    //   - protocol fee: 10% of 2,600 = 260 lamports
+    //   - rewards per token: 2,340 / 130 = 18
+    //   - rewards for 65 effective: 18 * 65 = 1,170 lamports
+    harvest(&mut context, &config_manager, &validator_stake_manager).await;
+
+    // Then the authority received the rewards net of the fee.
+    let account = get_account!(context, validator_stake_manager.authority.pubkey());
+    assert_eq!(account.lamports, 100_000_000 + 1_170);
+
+    // And the fee is kept on the config account.
+    let account = get_account!(context, config);
+    let config_account = Config::from_bytes(account.data.as_ref()).unwrap();
+    assert_eq!(config_account.unclaimed_protocol_fees, 260);
+    assert_eq!(config_account.lamports_last, account.lamports);
+}
+
+#[tokio::test]
+async fn protocol_fee_change_does_not_apply_to_received_rewards() {
+    let mut context = setup(&[]).await;
+
+    // Given a config account without a protocol fee.
+    let config_manager = ConfigManager::new(&mut context).await;
+    let config = config_manager.config;
+
+    // And a validator stake account with 65 effective tokens, out of 130.
+    let validator_stake_manager = create_stake(&mut context, &config_manager).await;
+
+    // And 2,600 lamports of rewards not harvested yet.
+    send_rewards(&mut context, &config, 2_600).await;
+
+    // When the config authority sets a 10% protocol fee.
+    set_protocol_fee(&mut context, &config_manager, 1_000)
+        .await
+        .unwrap();
+
+    // And we harvest the stake rewards.
+    harvest(&mut context, &config_manager, &validator_stake_manager).await;
+
+    // Then the authority received the rewards without the fee.
+    let account = get_account!(context, validator_stake_manager.authority.pubkey());
+    assert_eq!(account.lamports, 100_000_000 + 1_300);
+
+    let account = get_account!(context, config);
+    let config_account = Config::from_bytes(account.data.as_ref()).unwrap();
+    assert_eq!(config_account.unclaimed_protocol_fees, 0);
+}
+
+#[tokio::test]
+async fn fail_update_protocol_fee_above_maximum() {
+    let mut context = setup(&[]).await;
+    let config_manager = ConfigManager::new(&mut context).await;
+
+    // When the config authority sets a protocol fee above 10%.
+    let err = set_protocol_fee(&mut context, &config_manager, 1_001)
+        .await
+        .unwrap_err();
+
+    // Then we expect an error.
+    assert_instruction_error!(err, InstructionError::InvalidArgument);
+}
+
+#[tokio::test]
+async fn withdraw_protocol_fees() {
+    let mut context = setup(&[]).await;
+
+    // Given a config account with a fee recipient and 130 lamports of
+    // unclaimed protocol fees.
+    let config_manager = ConfigManager::new(&mut context).await;
+    let fee_recipient = create_recipient(&mut context);
+    set_fee_recipient(&mut context, &config_manager, &fee_recipient).await;
+
+    let mut account = get_account!(context, config_manager.config);
+    let config_lamports = account.lamports;
+    let mut config_account = Config::from_bytes(account.data.as_ref()).unwrap();
+    config_account.unclaimed_protocol_fees = 130;
+    account.lamports += 130;
+    config_account.lamports_last = account.lamports;
+    account.data = config_account.try_to_vec().unwrap();
+    context.set_account(&config_manager.config, &account.into());
+
+    // When the config authority withdraws the fees.
+    withdraw(&mut context, &config_manager, &fee_recipient)
+        .await
+        .unwrap();
+
+    // Then the fee recipient received the fees.
+    let account = get_account!(context, fee_recipient);
+    assert_eq!(account.lamports, 100_000_130);
+
+    // And the config no longer tracks them.
+    let account = get_account!(context, config_manager.config);
+    assert_eq!(account.lamports, config_lamports);
+    let config_account = Config::from_bytes(account.data.as_ref()).unwrap();
+    assert_eq!(config_account.unclaimed_protocol_fees, 0);
+    assert_eq!(config_account.lamports_last, config_lamports);
+    assert_eq!(config_account.accumulated_stake_rewards_per_token, 0);
+}
+
+#[tokio::test]
+async fn fail_withdraw_protocol_fees_with_wrong_fee_recipient() {
+    let mut context = setup(&[]).await;
+
+    // Given a config account with a fee recipient.
+    let config_manager = ConfigManager::new(&mut context).await;
+    let fee_recipient = create_recipient(&mut context);
+    set_fee_recipient(&mut context, &config_manager, &fee_recipient).await;
+
+    // When we try to withdraw the fees to a different account.
+    let other = create_recipient(&mut context);
+    let err = withdraw(&mut context, &config_manager, &other)
+        .await
+        .unwrap_err();
+
+    // Then we expect an error.
+    assert_custom_error!(err, PaladinStakeProgramError::InvalidDestinationAccount);
+}
+
+#[tokio::test]
+async fn fail_withdraw_protocol_fees_without_fee_recipient() {
+    let mut context = setup(&[]).await;
+
+    // Given a config account without a fee recipient.
+    let config_manager = ConfigManager::new(&mut context).await;
+
+    // When we try to withdraw the fees.
+    let recipient = create_recipient(&mut context);
+    let err = withdraw(&mut context, &config_manager, &recipient)
+        .await
+        .unwrap_err();
+
+    // Then we expect an error.
+    assert_custom_error!(err, PaladinStakeProgramError::AuthorityNotSet);
+}
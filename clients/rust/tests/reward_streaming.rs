@@ -0,0 +1,186 @@
+#![cfg(feature = "test-sbf")]
+
+mod setup;
+
+use borsh::BorshSerialize;
+use paladin_stake_program_client::{
+    accounts::{Config, ValidatorStake},
+    instructions::{
+        HarvestValidatorRewardsBuilder, UpdateConfigBuilder, ValidatorStakeTokensBuilder,
+    },
+    types::ConfigField,
+};
+use setup::{
+    config::ConfigManager, setup, stake::warp_to_next_epoch, token::mint_to,
+    validator_stake::ValidatorStakeManager,
+};
+use solana_program_test::{tokio, ProgramTestContext};
+use solana_sdk::{
+    account::{Account, AccountSharedData},
+    clock::Clock,
+    pubkey::Pubkey,
+    signature::Signer,
+    transaction::Transaction,
+};
+
+/// Period over which rewards are streamed.
+const PERIOD: u64 = 100;
+
+async fn set_timestamp(context: &mut ProgramTestContext, unix_timestamp: i64) {
+    let mut clock = context.banks_client.get_sysvar::<Clock>().await.unwrap();
+    clock.unix_timestamp = unix_timestamp;
+    context.set_sysvar(&clock);
+}
+
+/// Creates a validator stake with 50 SOL staked (stake limit of 65 tokens).
+async fn create_validator_stake(
+    context: &mut ProgramTestContext,
+    config: &Pubkey,
+) -> ValidatorStakeManager {
+    let manager = ValidatorStakeManager::new(context, config).await;
+
+    let mut account = get_account!(context, manager.stake);
+    let mut stake_account = ValidatorStake::from_bytes(account.data.as_ref()).unwrap();
+    stake_account.total_staked_lamports_amount = 50;
+    account.data = stake_account.try_to_vec().unwrap();
+    context.set_account(&manager.stake, &account.into());
+
+    // Cover the authority account's rent.
+    context.set_account(
+        &manager.authority.pubkey(),
+        &AccountSharedData::from(Account {
+            lamports: 100_000_000,
+            ..Default::default()
+        }),
+    );
+
+    manager
+}
+
+async fn stake_tokens(
+    context: &mut ProgramTestContext,
+    config_manager: &ConfigManager,
+    validator_stake_manager: &ValidatorStakeManager,
+    amount: u64,
+) {
+    mint_to(
+        context,
+        &config_manager.mint,
+        &config_manager.mint_authority,
+        &config_manager.rewards_manager.owner_token_account,
+        amount,
+    )
+    .await
+    .unwrap();
+
+    let ix = ValidatorStakeTokensBuilder::new()
+        .config(config_manager.config)
+        .holder_rewards_pool(config_manager.rewards_manager.pool)
+        .holder_rewards_pool_token_account(config_manager.rewards_manager.pool_token_account)
+        .validator_stake(validator_stake_manager.stake)
+        .validator_stake_authority(validator_stake_manager.authority.pubkey())
+        .source_token_account(config_manager.rewards_manager.owner_token_account)
+        .source_token_account_authority(config_manager.rewards_manager.owner.pubkey())
+        .mint(config_manager.mint)
+        .vault(config_manager.vault)
+        .vault_pda(config_manager.vault_pda)
+        .vault_holder_rewards(config_manager.vault_holder_rewards)
+        .token_program(spl_token::ID)
+        .rewards_program(paladin_rewards_program_client::ID)
+        .amount(amount)
+        .instruction();
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &config_manager.rewards_manager.owner],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+}
+
+/// Harvests the validator stake rewards, returning the lamports received by
+/// the authority.
+async fn harvest(
+    context: &mut ProgramTestContext,
+    config_manager: &ConfigManager,
+    validator_stake_manager: &ValidatorStakeManager,
+) -> u64 {
+    let before = get_account!(context, validator_stake_manager.authority.pubkey()).lamports;
+
+    let ix = HarvestValidatorRewardsBuilder::new()
+        .config(config_manager.config)
+        .vault_holder_rewards(config_manager.vault_holder_rewards)
+        .validator_stake(validator_stake_manager.stake)
+        .validator_stake_authority(validator_stake_manager.authority.pubkey())
+        .instruction();
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    get_account!(context, validator_stake_manager.authority.pubkey()).lamports - before
+}
+
+#[tokio::test]
+async fn streamed_rewards_are_proportional_to_time_staked() {
+    let mut context = setup(&[]).await;
+
+    // Given a config with reward streaming over 100 seconds.
+    let config_manager = ConfigManager::new(&mut context).await;
+    let ix = UpdateConfigBuilder::new()
+        .config(config_manager.config)
+        .config_authority(config_manager.config_authority.pubkey())
+        .config_field(ConfigField::RewardStreamingPeriodSeconds(PERIOD))
+        .instruction();
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &config_manager.config_authority],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // And two validators, the first with 50 tokens staked and activated.
+    let early = create_validator_stake(&mut context, &config_manager.config).await;
+    let jit = create_validator_stake(&mut context, &config_manager.config).await;
+    stake_tokens(&mut context, &config_manager, &early, 50).await;
+    warp_to_next_epoch(&mut context).await;
+    assert_eq!(harvest(&mut context, &config_manager, &early).await, 0);
+
+    // And a deposit of 100 lamports of rewards.
+    let start = context
+        .banks_client
+        .get_sysvar::<Clock>()
+        .await
+        .unwrap()
+        .unix_timestamp;
+    let mut account = get_account!(context, config_manager.config);
+    account.lamports += 100;
+    context.set_account(&config_manager.config, &account.into());
+
+    // When the deposit is synced, nothing is released yet.
+    assert_eq!(harvest(&mut context, &config_manager, &early).await, 0);
+
+    let account = get_account!(context, config_manager.config);
+    let config_account = Config::from_bytes(account.data.as_ref()).unwrap();
+    assert_eq!(config_account.reward_period_end, start as u64 + PERIOD);
+    assert_eq!(config_account.reward_rate, 1_000_000_000_000_000_000); // 1 lamport/s
+
+    // And a second validator stakes 50 tokens that are activated halfway
+    // through the period.
+    stake_tokens(&mut context, &config_manager, &jit, 50).await;
+    warp_to_next_epoch(&mut context).await;
+    set_timestamp(&mut context, start + PERIOD as i64 / 2).await;
+    assert_eq!(harvest(&mut context, &config_manager, &jit).await, 0);
+
+    // Then after the period ends, the first validator earned the full first
+    // half and half of the second half.
+    set_timestamp(&mut context, start + PERIOD as i64 * 2).await;
+    assert_eq!(harvest(&mut context, &config_manager, &early).await, 75);
+
+    // And the second validator only earned half of the second half.
+    assert_eq!(harvest(&mut context, &config_manager, &jit).await, 25);
+}
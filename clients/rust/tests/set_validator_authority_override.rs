@@ -0,0 +1,238 @@
+#![cfg(feature = "test-sbf")]
+
+mod setup;
+
+use paladin_stake_program_client::{
+    accounts::ValidatorStake,
+    errors::PaladinStakeProgramError,
+    instructions::{SetValidatorAuthorityOverrideBuilder, ValidatorSyncAuthority},
+    pdas::find_validator_authority_override_pda,
+};
+use setup::{config::ConfigManager, setup, validator_stake::ValidatorStakeManager};
+use solana_program_test::{tokio, ProgramTestContext};
+use solana_sdk::{
+    instruction::{Instruction, InstructionError},
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    system_instruction, system_program,
+    transaction::Transaction,
+};
+
+/// Funds the override account with the rent for a `Pubkey`.
+async fn fund_override(context: &mut ProgramTestContext, authority_override: &Pubkey) {
+    let rent = context.banks_client.get_rent().await.unwrap();
+    let tx = Transaction::new_signed_with_payer(
+        &[system_instruction::transfer(
+            &context.payer.pubkey(),
+            authority_override,
+            rent.minimum_balance(std::mem::size_of::<Pubkey>()),
+        )],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+}
+
+/// Builds a `ValidatorSyncAuthority` instruction with the given authority
+/// override account.
+fn sync_authority_ix(
+    config_manager: &ConfigManager,
+    validator_stake_manager: &ValidatorStakeManager,
+    authority_override: Pubkey,
+) -> Instruction {
+    ValidatorSyncAuthority {
+        config: config_manager.config,
+        validator_stake: validator_stake_manager.stake,
+        validator_vote: validator_stake_manager.vote,
+        validator_authority_override: authority_override,
+    }
+    .instruction()
+}
+
+/// Syncs the validator stake authority.
+async fn sync_authority(
+    context: &mut ProgramTestContext,
+    config_manager: &ConfigManager,
+    validator_stake_manager: &ValidatorStakeManager,
+) {
+    let (authority_override, _) = find_validator_authority_override_pda(
+        &validator_stake_manager.vote,
+        &config_manager.config,
+    );
+    let tx = Transaction::new_signed_with_payer(
+        &[sync_authority_ix(
+            config_manager,
+            validator_stake_manager,
+            authority_override,
+        )],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+}
+
+#[tokio::test]
+async fn set_validator_authority_override() {
+    let mut context = setup(&[]).await;
+
+    // Given a config and a validator stake account.
+    let config_manager = ConfigManager::new(&mut context).await;
+    let validator_stake_manager =
+        ValidatorStakeManager::new(&mut context, &config_manager.config).await;
+
+    // And a funded authority override account for the vote account.
+    let (authority_override, _) = find_validator_authority_override_pda(
+        &validator_stake_manager.vote,
+        &config_manager.config,
+    );
+    fund_override(&mut context, &authority_override).await;
+
+    // When the withdraw authority sets the override.
+    let new_authority = Pubkey::new_unique();
+    let ix = SetValidatorAuthorityOverrideBuilder::new()
+        .config(config_manager.config)
+        .validator_vote(validator_stake_manager.vote)
+        .withdraw_authority(validator_stake_manager.authority.pubkey())
+        .validator_authority_override(authority_override)
+        .new_authority(new_authority)
+        .system_program(system_program::ID)
+        .instruction();
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &validator_stake_manager.authority],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // Then the override account holds the new authority.
+    let account = get_account!(context, authority_override);
+    assert_eq!(account.owner, paladin_stake_program_client::ID);
+    assert_eq!(account.data, new_authority.to_bytes());
+
+    // And syncing the stake authority uses the override.
+    sync_authority(&mut context, &config_manager, &validator_stake_manager).await;
+
+    let account = get_account!(context, validator_stake_manager.stake);
+    let stake_account = ValidatorStake::from_bytes(account.data.as_ref()).unwrap();
+    assert_eq!(stake_account.delegation.authority, new_authority);
+}
+
+#[tokio::test]
+async fn validator_authority_override_survives_withdraw_authority_change() {
+    let mut context = setup(&[]).await;
+
+    // Given a validator stake account with an authority override.
+    let config_manager = ConfigManager::new(&mut context).await;
+    let validator_stake_manager =
+        ValidatorStakeManager::new(&mut context, &config_manager.config).await;
+
+    let (authority_override, _) = find_validator_authority_override_pda(
+        &validator_stake_manager.vote,
+        &config_manager.config,
+    );
+    fund_override(&mut context, &authority_override).await;
+
+    let new_authority = Pubkey::new_unique();
+    let ix = SetValidatorAuthorityOverrideBuilder::new()
+        .config(config_manager.config)
+        .validator_vote(validator_stake_manager.vote)
+        .withdraw_authority(validator_stake_manager.authority.pubkey())
+        .validator_authority_override(authority_override)
+        .new_authority(new_authority)
+        .system_program(system_program::ID)
+        .instruction();
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &validator_stake_manager.authority],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // And the withdraw authority of the vote account changes.
+    let withdraw_authority = Pubkey::new_unique();
+    let mut vote_account = get_account!(context, validator_stake_manager.vote);
+    vote_account.data[36..68].copy_from_slice(&withdraw_authority.to_bytes());
+    context.set_account(&validator_stake_manager.vote, &vote_account.into());
+
+    // When the stake authority is synced with the override account.
+    sync_authority(&mut context, &config_manager, &validator_stake_manager).await;
+
+    // Then the override still applies.
+    let account = get_account!(context, validator_stake_manager.stake);
+    let stake_account = ValidatorStake::from_bytes(account.data.as_ref()).unwrap();
+    assert_eq!(stake_account.delegation.authority, new_authority);
+
+    // And when the stake authority is synced without the override account.
+    for authority_override in [system_program::ID, Pubkey::new_unique()] {
+        context.get_new_latest_blockhash().await.unwrap();
+        let tx = Transaction::new_signed_with_payer(
+            &[sync_authority_ix(
+                &config_manager,
+                &validator_stake_manager,
+                authority_override,
+            )],
+            Some(&context.payer.pubkey()),
+            &[&context.payer],
+            context.last_blockhash,
+        );
+        let err = context
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err();
+
+        // Then we expect an error.
+        assert_instruction_error!(err, InstructionError::InvalidSeeds);
+    }
+
+    // And the override still applies.
+    let account = get_account!(context, validator_stake_manager.stake);
+    let stake_account = ValidatorStake::from_bytes(account.data.as_ref()).unwrap();
+    assert_eq!(stake_account.delegation.authority, new_authority);
+}
+
+#[tokio::test]
+async fn fail_set_validator_authority_override_with_wrong_authority() {
+    let mut context = setup(&[]).await;
+
+    // Given a config and a validator stake account.
+    let config_manager = ConfigManager::new(&mut context).await;
+    let validator_stake_manager =
+        ValidatorStakeManager::new(&mut context, &config_manager.config).await;
+
+    // And a signer that is not the vote withdraw authority.
+    let fake_authority = Keypair::new();
+    let (authority_override, _) = find_validator_authority_override_pda(
+        &validator_stake_manager.vote,
+        &config_manager.config,
+    );
+    fund_override(&mut context, &authority_override).await;
+
+    // When we try to set the override with the wrong authority.
+    let ix = SetValidatorAuthorityOverrideBuilder::new()
+        .config(config_manager.config)
+        .validator_vote(validator_stake_manager.vote)
+        .withdraw_authority(fake_authority.pubkey())
+        .validator_authority_override(authority_override)
+        .new_authority(fake_authority.pubkey())
+        .system_program(system_program::ID)
+        .instruction();
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &fake_authority],
+        context.last_blockhash,
+    );
+    let err = context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .unwrap_err();
+
+    // Then we expect an error.
+    assert_custom_error!(err, PaladinStakeProgramError::InvalidAuthority);
+}
@@ -0,0 +1,655 @@
+#![cfg(feature = "test-sbf")]
+
+mod setup;
+
+use borsh::BorshSerialize;
+use paladin_stake_program_client::{
+    accounts::{Config, DelegatedVotingPower, ValidatorStake},
+    errors::PaladinStakeProgramError,
+    instructions::{
+        GetVotingPowerBuilder, HarvestValidatorRewardsBuilder,
+        InitializeDelegatedVotingPowerBuilder, SetVoteDelegateBuilder, SlashValidatorStakeBuilder,
+        ValidatorStakeTokensBuilder,
+    },
+    pdas::find_delegated_voting_power_pda,
+    return_data::VotingPowerReturnData,
+    types::Checkpoint,
+};
+use setup::{
+    config::ConfigManager, setup, stake::warp_to_next_epoch, token::mint_to,
+    validator_stake::ValidatorStakeManager,
+};
+use solana_program_test::{tokio, BanksClientError, ProgramTestContext};
+use solana_sdk::{
+    instruction::{AccountMeta, Instruction, InstructionError},
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    system_instruction,
+    transaction::Transaction,
+};
+
+/// Creates the `DelegatedVotingPower` account of a vote delegate.
+async fn create_delegated_voting_power(
+    context: &mut ProgramTestContext,
+    config: &Pubkey,
+    delegate: &Pubkey,
+) -> Pubkey {
+    let (delegated_voting_power, _) = find_delegated_voting_power_pda(delegate, config);
+
+    let transfer_ix = system_instruction::transfer(
+        &context.payer.pubkey(),
+        &delegated_voting_power,
+        context
+            .banks_client
+            .get_rent()
+            .await
+            .unwrap()
+            .minimum_balance(DelegatedVotingPower::LEN),
+    );
+    let initialize_ix = InitializeDelegatedVotingPowerBuilder::new()
+        .config(*config)
+        .vote_delegate(*delegate)
+        .delegated_voting_power(delegated_voting_power)
+        .instruction();
+    let tx = Transaction::new_signed_with_payer(
+        &[transfer_ix, initialize_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    delegated_voting_power
+}
+
+async fn get_voting_power(context: &mut ProgramTestContext, delegated_voting_power: Pubkey) -> u64 {
+    let account = get_account!(context, delegated_voting_power);
+    DelegatedVotingPower::from_bytes(account.data.as_ref())
+        .unwrap()
+        .voting_power
+}
+
+/// Simulates `GetVotingPower` on a stake or delegated voting power account.
+async fn get_voting_power_at(
+    context: &mut ProgramTestContext,
+    config: &Pubkey,
+    account: Pubkey,
+    slot: u64,
+) -> u64 {
+    let ix = GetVotingPowerBuilder::new()
+        .config(*config)
+        .stake(account)
+        .slot(slot)
+        .instruction();
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    let simulation = context.banks_client.simulate_transaction(tx).await.unwrap();
+    simulation.result.unwrap().unwrap();
+    let return_data = simulation.simulation_details.unwrap().return_data.unwrap();
+    VotingPowerReturnData::from_return_data(&return_data.program_id, &return_data.data)
+        .unwrap()
+        .voting_power
+}
+
+/// Creates a validator stake account with 65 effective tokens (out of a
+/// maximum of 130).
+async fn setup_validator_stake(
+    context: &mut ProgramTestContext,
+    config_manager: &ConfigManager,
+) -> ValidatorStakeManager {
+    let validator_stake_manager = ValidatorStakeManager::new(context, &config_manager.config).await;
+
+    let mut account = get_account!(context, config_manager.config);
+    let mut config_account = Config::from_bytes(account.data.as_ref()).unwrap();
+    config_account.token_amount_effective = 65;
+    account.data = config_account.try_to_vec().unwrap();
+    context.set_account(&config_manager.config, &account.into());
+
+    let mut account = get_account!(context, validator_stake_manager.stake);
+    let mut stake_account = ValidatorStake::from_bytes(account.data.as_ref()).unwrap();
+    stake_account.delegation.staked_amount = 65;
+    stake_account.delegation.effective_amount = 65;
+    stake_account.total_staked_lamports_amount = 100;
+    account.data = stake_account.try_to_vec().unwrap();
+    context.set_account(&validator_stake_manager.stake, &account.into());
+
+    validator_stake_manager
+}
+
+async fn set_vote_delegate(
+    context: &mut ProgramTestContext,
+    config: &Pubkey,
+    validator_stake_manager: &ValidatorStakeManager,
+    delegate: Option<&Keypair>,
+    remaining_accounts: &[Pubkey],
+) -> Result<(), BanksClientError> {
+    let ix = SetVoteDelegateBuilder::new()
+        .config(*config)
+        .stake(validator_stake_manager.stake)
+        .stake_authority(validator_stake_manager.authority.pubkey())
+        .vote_delegate(delegate.map(|delegate| delegate.pubkey()))
+        .add_remaining_accounts(
+            &remaining_accounts
+                .iter()
+                .map(|account| AccountMeta::new(*account, false))
+                .collect::<Vec<_>>(),
+        )
+        .instruction();
+    let mut signers = vec![&context.payer, &validator_stake_manager.authority];
+    signers.extend(delegate);
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &signers,
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await
+}
+
+/// Builds a `ValidatorStakeTokens` instruction staking `amount` tokens.
+async fn stake_tokens_ix(
+    context: &mut ProgramTestContext,
+    config_manager: &ConfigManager,
+    validator_stake_manager: &ValidatorStakeManager,
+    amount: u64,
+) -> Instruction {
+    mint_to(
+        context,
+        &config_manager.mint,
+        &config_manager.mint_authority,
+        &config_manager.rewards_manager.owner_token_account,
+        amount,
+    )
+    .await
+    .unwrap();
+
+    ValidatorStakeTokensBuilder::new()
+        .config(config_manager.config)
+        .holder_rewards_pool(config_manager.rewards_manager.pool)
+        .holder_rewards_pool_token_account(config_manager.rewards_manager.pool_token_account)
+        .validator_stake(validator_stake_manager.stake)
+        .validator_stake_authority(validator_stake_manager.authority.pubkey())
+        .source_token_account(config_manager.rewards_manager.owner_token_account)
+        .source_token_account_authority(config_manager.rewards_manager.owner.pubkey())
+        .mint(config_manager.mint)
+        .vault(config_manager.vault)
+        .vault_pda(config_manager.vault_pda)
+        .vault_holder_rewards(config_manager.vault_holder_rewards)
+        .token_program(spl_token::ID)
+        .rewards_program(paladin_rewards_program_client::ID)
+        .amount(amount)
+        .instruction()
+}
+
+fn harvest_ix(
+    config_manager: &ConfigManager,
+    validator_stake_manager: &ValidatorStakeManager,
+) -> Instruction {
+    HarvestValidatorRewardsBuilder::new()
+        .config(config_manager.config)
+        .vault_holder_rewards(config_manager.vault_holder_rewards)
+        .validator_stake(validator_stake_manager.stake)
+        .validator_stake_authority(validator_stake_manager.authority.pubkey())
+        .instruction()
+}
+
+#[tokio::test]
+async fn delegated_voting_power_follows_effective_amount() {
+    let mut context = setup(&[]).await;
+
+    // Given a validator stake account with 65 effective tokens.
+    let config_manager = ConfigManager::new(&mut context).await;
+    let config = config_manager.config;
+    let validator_stake_manager = setup_validator_stake(&mut context, &config_manager).await;
+
+    // And the delegated voting power account of a vote delegate.
+    let delegate = Keypair::new();
+    let delegated_voting_power =
+        create_delegated_voting_power(&mut context, &config, &delegate.pubkey()).await;
+    assert_eq!(
+        get_voting_power(&mut context, delegated_voting_power).await,
+        0
+    );
+
+    // When the stake authority delegates its voting power.
+    set_vote_delegate(
+        &mut context,
+        &config,
+        &validator_stake_manager,
+        Some(&delegate),
+        &[delegated_voting_power],
+    )
+    .await
+    .unwrap();
+
+    // Then the delegate holds the effective amount of the stake.
+    let account = get_account!(context, validator_stake_manager.stake);
+    let stake_account = ValidatorStake::from_bytes(account.data.as_ref()).unwrap();
+    assert_eq!(stake_account.vote_delegate.value(), Some(delegate.pubkey()));
+    assert_eq!(
+        get_voting_power(&mut context, delegated_voting_power).await,
+        65
+    );
+
+    // And when 35 more tokens are staked.
+    let mut stake_ix =
+        stake_tokens_ix(&mut context, &config_manager, &validator_stake_manager, 35).await;
+    stake_ix
+        .accounts
+        .push(AccountMeta::new(delegated_voting_power, false));
+    let tx = Transaction::new_signed_with_payer(
+        &[stake_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &config_manager.rewards_manager.owner],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // Then the delegated voting power is unchanged while the tokens are
+    // activating.
+    assert_eq!(
+        get_voting_power(&mut context, delegated_voting_power).await,
+        65
+    );
+
+    // And when the tokens are activated on the next epoch.
+    warp_to_next_epoch(&mut context).await;
+
+    let mut harvest_ix = harvest_ix(&config_manager, &validator_stake_manager);
+    harvest_ix
+        .accounts
+        .push(AccountMeta::new(delegated_voting_power, false));
+    let tx = Transaction::new_signed_with_payer(
+        &[harvest_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // Then the delegated voting power is updated.
+    assert_eq!(
+        get_voting_power(&mut context, delegated_voting_power).await,
+        100
+    );
+
+    // And when the vote delegate is cleared.
+    set_vote_delegate(
+        &mut context,
+        &config,
+        &validator_stake_manager,
+        None,
+        &[delegated_voting_power],
+    )
+    .await
+    .unwrap();
+
+    // Then the delegate no longer holds any voting power.
+    assert_eq!(
+        get_voting_power(&mut context, delegated_voting_power).await,
+        0
+    );
+    let account = get_account!(context, validator_stake_manager.stake);
+    let stake_account = ValidatorStake::from_bytes(account.data.as_ref()).unwrap();
+    assert_eq!(stake_account.vote_delegate.value(), None);
+}
+
+#[tokio::test]
+async fn vote_delegate_takes_over_voting_power_history() {
+    let mut context = setup(&[]).await;
+
+    // Given a validator stake account with 65 effective tokens since slot 0.
+    let config_manager = ConfigManager::new(&mut context).await;
+    let config = config_manager.config;
+    let validator_stake_manager = setup_validator_stake(&mut context, &config_manager).await;
+
+    let mut account = get_account!(context, validator_stake_manager.stake);
+    let mut stake_account = ValidatorStake::from_bytes(account.data.as_ref()).unwrap();
+    stake_account.checkpoints.count = 1;
+    stake_account.checkpoints.checkpoints[0] = Checkpoint {
+        slot: 0,
+        effective_amount: 65,
+    };
+    account.data = stake_account.try_to_vec().unwrap();
+    context.set_account(&validator_stake_manager.stake, &account.into());
+
+    // And the delegated voting power account of a vote delegate.
+    let delegate = Keypair::new();
+    let delegated_voting_power =
+        create_delegated_voting_power(&mut context, &config, &delegate.pubkey()).await;
+
+    // When the stake authority delegates its voting power at slot 100.
+    context.warp_to_slot(100).unwrap();
+    set_vote_delegate(
+        &mut context,
+        &config,
+        &validator_stake_manager,
+        Some(&delegate),
+        &[delegated_voting_power],
+    )
+    .await
+    .unwrap();
+
+    // And clears the delegate at slot 200.
+    context.warp_to_slot(200).unwrap();
+    set_vote_delegate(
+        &mut context,
+        &config,
+        &validator_stake_manager,
+        None,
+        &[delegated_voting_power],
+    )
+    .await
+    .unwrap();
+    context.warp_to_slot(300).unwrap();
+
+    // Then the voting power is counted either on the stake account or on the
+    // delegate, never on both.
+    let stake = validator_stake_manager.stake;
+    for (slot, stake_voting_power, delegated) in [(99, 65, 0), (150, 0, 65), (250, 65, 0)] {
+        assert_eq!(
+            get_voting_power_at(&mut context, &config, stake, slot).await,
+            stake_voting_power
+        );
+        assert_eq!(
+            get_voting_power_at(&mut context, &config, delegated_voting_power, slot).await,
+            delegated
+        );
+    }
+}
+
+#[tokio::test]
+async fn keeper_sync_without_delegated_voting_power() {
+    let mut context = setup(&[]).await;
+
+    // Given a validator stake account delegating its voting power.
+    let config_manager = ConfigManager::new(&mut context).await;
+    let config = config_manager.config;
+    let validator_stake_manager = setup_validator_stake(&mut context, &config_manager).await;
+
+    let delegate = Keypair::new();
+    let delegated_voting_power =
+        create_delegated_voting_power(&mut context, &config, &delegate.pubkey()).await;
+    set_vote_delegate(
+        &mut context,
+        &config,
+        &validator_stake_manager,
+        Some(&delegate),
+        &[delegated_voting_power],
+    )
+    .await
+    .unwrap();
+
+    // And 35 more tokens staked.
+    let mut stake_ix =
+        stake_tokens_ix(&mut context, &config_manager, &validator_stake_manager, 35).await;
+    stake_ix
+        .accounts
+        .push(AccountMeta::new(delegated_voting_power, false));
+    let tx = Transaction::new_signed_with_payer(
+        &[stake_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &config_manager.rewards_manager.owner],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // When a keeper activates the tokens without the delegated voting power
+    // account.
+    warp_to_next_epoch(&mut context).await;
+
+    let tx = Transaction::new_signed_with_payer(
+        &[harvest_ix(&config_manager, &validator_stake_manager)],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // Then the tokens are effective while the voting power of the delegate is
+    // left behind.
+    let account = get_account!(context, validator_stake_manager.stake);
+    let stake_account = ValidatorStake::from_bytes(account.data.as_ref()).unwrap();
+    assert_eq!(stake_account.delegation.effective_amount, 100);
+    assert_eq!(stake_account.delegated_voting_power, 65);
+    assert_eq!(
+        get_voting_power(&mut context, delegated_voting_power).await,
+        65
+    );
+
+    // And when the stake is synced with the delegated voting power account.
+    let mut harvest_ix = harvest_ix(&config_manager, &validator_stake_manager);
+    harvest_ix
+        .accounts
+        .push(AccountMeta::new(delegated_voting_power, false));
+    let tx = Transaction::new_signed_with_payer(
+        &[harvest_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // Then the voting power of the delegate catches up.
+    let account = get_account!(context, validator_stake_manager.stake);
+    let stake_account = ValidatorStake::from_bytes(account.data.as_ref()).unwrap();
+    assert_eq!(stake_account.delegated_voting_power, 100);
+    assert_eq!(
+        get_voting_power(&mut context, delegated_voting_power).await,
+        100
+    );
+}
+
+#[tokio::test]
+async fn slash_without_delegated_voting_power() {
+    let mut context = setup(&[]).await;
+
+    // Given a validator stake account with 65 tokens delegating its voting
+    // power.
+    let config_manager = ConfigManager::new(&mut context).await;
+    let config = config_manager.config;
+    let validator_stake_manager = setup_validator_stake(&mut context, &config_manager).await;
+    mint_to(
+        &mut context,
+        &config_manager.mint,
+        &config_manager.mint_authority,
+        &config_manager.vault,
+        65,
+    )
+    .await
+    .unwrap();
+
+    let delegate = Keypair::new();
+    let delegated_voting_power =
+        create_delegated_voting_power(&mut context, &config, &delegate.pubkey()).await;
+    set_vote_delegate(
+        &mut context,
+        &config,
+        &validator_stake_manager,
+        Some(&delegate),
+        &[delegated_voting_power],
+    )
+    .await
+    .unwrap();
+
+    // When the slash authority slashes 15 tokens without the delegated voting
+    // power account.
+    let slash_ix = SlashValidatorStakeBuilder::new()
+        .config(config)
+        .validator_stake(validator_stake_manager.stake)
+        .validator_stake_authority(validator_stake_manager.authority.pubkey())
+        .slash_authority(config_manager.config_authority.pubkey())
+        .mint(config_manager.mint)
+        .vault(config_manager.vault)
+        .vault_holder_rewards(config_manager.vault_holder_rewards)
+        .vault_authority(config_manager.vault_pda)
+        .token_program(spl_token::ID)
+        .amount(15)
+        .instruction();
+    let tx = Transaction::new_signed_with_payer(
+        &[slash_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &config_manager.config_authority],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // Then the stake is slashed while the voting power of the delegate is
+    // left behind.
+    let account = get_account!(context, validator_stake_manager.stake);
+    let stake_account = ValidatorStake::from_bytes(account.data.as_ref()).unwrap();
+    assert_eq!(stake_account.delegation.staked_amount, 50);
+    assert_eq!(stake_account.delegation.effective_amount, 50);
+    assert_eq!(stake_account.delegated_voting_power, 65);
+    assert_eq!(
+        get_voting_power(&mut context, delegated_voting_power).await,
+        65
+    );
+
+    // And when the stake authority stakes more tokens without the delegated
+    // voting power account.
+    let stake_ix =
+        stake_tokens_ix(&mut context, &config_manager, &validator_stake_manager, 10).await;
+    let tx = Transaction::new_signed_with_payer(
+        &[stake_ix.clone()],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &config_manager.rewards_manager.owner],
+        context.last_blockhash,
+    );
+    let err = context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .unwrap_err();
+
+    // Then we expect an error.
+    assert_custom_error!(err, PaladinStakeProgramError::MissingDelegatedVotingPower);
+
+    // And when the tokens are staked with the delegated voting power account.
+    let mut stake_ix = stake_ix;
+    stake_ix
+        .accounts
+        .push(AccountMeta::new(delegated_voting_power, false));
+    let tx = Transaction::new_signed_with_payer(
+        &[stake_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &config_manager.rewards_manager.owner],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // Then the voting power of the delegate catches up with the slash.
+    let account = get_account!(context, validator_stake_manager.stake);
+    let stake_account = ValidatorStake::from_bytes(account.data.as_ref()).unwrap();
+    assert_eq!(stake_account.delegated_voting_power, 50);
+    assert_eq!(
+        get_voting_power(&mut context, delegated_voting_power).await,
+        50
+    );
+}
+
+#[tokio::test]
+async fn fail_set_vote_delegate_with_wrong_authority() {
+    let mut context = setup(&[]).await;
+
+    // Given a validator stake account and a delegated voting power account.
+    let config_manager = ConfigManager::new(&mut context).await;
+    let config = config_manager.config;
+    let mut validator_stake_manager = setup_validator_stake(&mut context, &config_manager).await;
+
+    let delegate = Keypair::new();
+    let delegated_voting_power =
+        create_delegated_voting_power(&mut context, &config, &delegate.pubkey()).await;
+
+    // When we try to set the vote delegate with a different authority.
+    validator_stake_manager.authority = Keypair::new();
+    let err = set_vote_delegate(
+        &mut context,
+        &config,
+        &validator_stake_manager,
+        Some(&delegate),
+        &[delegated_voting_power],
+    )
+    .await
+    .unwrap_err();
+
+    // Then we expect an error.
+    assert_custom_error!(err, PaladinStakeProgramError::InvalidAuthority);
+}
+
+#[tokio::test]
+async fn fail_set_vote_delegate_without_delegate_signature() {
+    let mut context = setup(&[]).await;
+
+    // Given a validator stake account and a delegated voting power account.
+    let config_manager = ConfigManager::new(&mut context).await;
+    let config = config_manager.config;
+    let validator_stake_manager = setup_validator_stake(&mut context, &config_manager).await;
+
+    let delegate = Pubkey::new_unique();
+    let delegated_voting_power =
+        create_delegated_voting_power(&mut context, &config, &delegate).await;
+
+    // When we try to set the vote delegate without its signature.
+    let mut ix = SetVoteDelegateBuilder::new()
+        .config(config)
+        .stake(validator_stake_manager.stake)
+        .stake_authority(validator_stake_manager.authority.pubkey())
+        .vote_delegate(Some(delegate))
+        .add_remaining_account(AccountMeta::new(delegated_voting_power, false))
+        .instruction();
+    ix.accounts[3].is_signer = false;
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &validator_stake_manager.authority],
+        context.last_blockhash,
+    );
+    let err = context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .unwrap_err();
+
+    // Then we expect an error.
+    assert_instruction_error!(err, InstructionError::MissingRequiredSignature);
+
+    // And the stake account is not delegated.
+    let account = get_account!(context, validator_stake_manager.stake);
+    let stake_account = ValidatorStake::from_bytes(account.data.as_ref()).unwrap();
+    assert_eq!(stake_account.vote_delegate.value(), None);
+}
+
+#[tokio::test]
+async fn fail_initialize_delegated_voting_power_with_wrong_seeds() {
+    let mut context = setup(&[]).await;
+
+    // Given a config account.
+    let config_manager = ConfigManager::new(&mut context).await;
+
+    // When we try to initialize a delegated voting power account at an address
+    // that is not the PDA of the delegate.
+    let ix = InitializeDelegatedVotingPowerBuilder::new()
+        .config(config_manager.config)
+        .vote_delegate(Pubkey::new_unique())
+        .delegated_voting_power(Pubkey::new_unique())
+        .instruction();
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    let err = context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .unwrap_err();
+
+    // Then we expect an error.
+    assert_instruction_error!(err, InstructionError::InvalidSeeds);
+}
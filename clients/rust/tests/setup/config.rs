@@ -3,7 +3,9 @@
 
 use paladin_rewards_program_client::accounts::HolderRewards;
 use paladin_stake_program_client::{
-    accounts::Config, instructions::InitializeConfigBuilder, pdas::find_vault_pda,
+    accounts::Config,
+    instructions::{InitializeConfigBuilder, InitializeConfigPdaBuilder},
+    pdas::{find_config_pda, find_vault_pda},
 };
 use solana_program_test::{BanksClientError, ProgramTestContext};
 use solana_sdk::{
@@ -45,7 +47,30 @@ impl ConfigManager {
     }
 
     pub async fn with_args(
+        context: &mut ProgramTestContext,
+        cooldown_time_seconds: u64,
+        max_deactivation_basis_points: u16,
+        sync_rewards_lamports: u64,
+    ) -> Self {
+        Self::create(
+            context,
+            None,
+            cooldown_time_seconds,
+            max_deactivation_basis_points,
+            sync_rewards_lamports,
+        )
+        .await
+    }
+
+    /// Creates a config account at the PDA derived from a new mint and the
+    /// given namespace.
+    pub async fn with_namespace(context: &mut ProgramTestContext, namespace: u64) -> Self {
+        Self::create(context, Some(namespace), 1, 500, 1_000_000).await
+    }
+
+    async fn create(
         mut context: &mut ProgramTestContext,
+        namespace: Option<u64>,
         cooldown_time_seconds: u64,
         max_deactivation_basis_points: u16,
         sync_rewards_lamports: u64,
@@ -54,6 +79,13 @@ impl ConfigManager {
         let mint = Keypair::new();
         let mint_authority = Keypair::new();
         let config = Keypair::new();
+        let config_authority = Keypair::new();
+        let config_address = match namespace {
+            Some(namespace) => {
+                find_config_pda(&mint.pubkey(), &config_authority.pubkey(), namespace).0
+            }
+            None => config.pubkey(),
+        };
 
         create_mint(
             context,
@@ -64,13 +96,13 @@ impl ConfigManager {
         )
         .await
         .unwrap();
-        let (vault_pda, _) = find_vault_pda(&config.pubkey());
+        let (vault_pda, _) = find_vault_pda(&config_address);
 
         let rewards_manager = RewardsManager::new(&mut context, &mint.pubkey(), &vault_pda).await;
 
         let mut manager = ConfigManager {
-            config: config.pubkey(),
-            config_authority: Keypair::new(),
+            config: config_address,
+            config_authority,
             vault: Pubkey::default(),
             vault_pda: Pubkey::default(),
             vault_holder_rewards: Pubkey::default(),
@@ -100,6 +132,41 @@ impl ConfigManager {
             .unwrap();
 
         // Initializes the config.
+        if let Some(namespace) = namespace {
+            // The config PDA is pre-funded and allocated by the program.
+            fund_account(&mut context, &config_address, Config::LEN)
+                .await
+                .unwrap();
+
+            let initialize_ix = InitializeConfigPdaBuilder::new()
+                .config(config_address)
+                .mint(mint.pubkey())
+                .vault(manager.vault)
+                .vault_pda(vault_pda)
+                .vault_holder_rewards(vault_holder_rewards)
+                .rewards_program(paladin_rewards_program_client::ID)
+                .config_authority(manager.config_authority.pubkey())
+                .namespace(namespace)
+                .slash_authority(manager.config_authority.pubkey())
+                .cooldown_time_seconds(cooldown_time_seconds)
+                .max_deactivation_basis_points(max_deactivation_basis_points)
+                .sync_rewards_lamports(sync_rewards_lamports)
+                .duna_document_hash(get_duna_hash())
+                .instruction();
+
+            context.get_new_latest_blockhash().await.unwrap();
+
+            let tx = Transaction::new_signed_with_payer(
+                &[initialize_ix],
+                Some(&context.payer.pubkey()),
+                &[&context.payer, &manager.config_authority],
+                context.last_blockhash,
+            );
+            context.banks_client.process_transaction(tx).await.unwrap();
+
+            return manager;
+        }
+
         let create_ix = system_instruction::create_account(
             &context.payer.pubkey(),
             &config.pubkey(),
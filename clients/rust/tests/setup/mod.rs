@@ -30,6 +30,12 @@ pub const SWAD: u64 = 10u64.pow(9);
 pub const DUNA_PROGRAM_ID: Pubkey = pubkey!("8TwDM3rkxQuFCiS2iPB1HB3Q3qnN7b6J4SCTDCpw9SS1");
 
 pub async fn setup(program_overrides: &[(&'static str, Pubkey)]) -> ProgramTestContext {
+    start(program_test(program_overrides)).await
+}
+
+/// Creates a `ProgramTest` with the stake program and its dependencies, so
+/// tests can add programs of their own before starting it.
+pub fn program_test(program_overrides: &[(&'static str, Pubkey)]) -> ProgramTest {
     let mut program_test = ProgramTest::new(
         "paladin_stake_program",
         paladin_stake_program_client::ID,
@@ -50,6 +56,11 @@ pub async fn setup(program_overrides: &[(&'static str, Pubkey)]) -> ProgramTestC
         program_test.add_program(name, *program_id, None);
     }
 
+    program_test
+}
+
+/// Starts the `ProgramTest` and warps to the first normal slot.
+pub async fn start(program_test: ProgramTest) -> ProgramTestContext {
     let mut context = program_test.start_with_context().await;
     context
         .warp_to_slot(context.genesis_config().epoch_schedule.first_normal_slot + 1)
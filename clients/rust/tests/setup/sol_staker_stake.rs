@@ -1,6 +1,6 @@
 use paladin_stake_program_client::{
     accounts::SolStakerStake,
-    instructions::InitializeSolStakerStakeBuilder,
+    instructions::{HarvestSolStakerRewardsBuilder, InitializeSolStakerStakeBuilder},
     pdas::{find_sol_staker_authority_override_pda, find_sol_staker_stake_pda},
 };
 use solana_program_test::ProgramTestContext;
@@ -15,7 +15,11 @@ use solana_sdk::{
 
 use crate::setup::sign_duna_document;
 
-use super::stake::{create_stake_account, delegate_stake_account};
+use super::{
+    config::ConfigManager,
+    stake::{create_stake_account, delegate_stake_account, warp_to_next_epoch},
+    validator_stake::ValidatorStakeManager,
+};
 
 pub struct SolStakerStakeManager {
     // Stake account.
@@ -87,6 +91,37 @@ impl SolStakerStakeManager {
     }
 }
 
+/// Warps to the next epoch and harvests the SOL staker stake, so the tokens
+/// activating are folded into the effective amount.
+pub async fn activate_sol_staker_stake(
+    context: &mut ProgramTestContext,
+    config_manager: &ConfigManager,
+    sol_staker_stake_manager: &SolStakerStakeManager,
+    validator_stake_manager: &ValidatorStakeManager,
+) {
+    warp_to_next_epoch(context).await;
+
+    let ix = HarvestSolStakerRewardsBuilder::new()
+        .config(config_manager.config)
+        .vault_holder_rewards(config_manager.vault_holder_rewards)
+        .sol_staker_stake(sol_staker_stake_manager.stake)
+        .sol_staker_stake_authority(sol_staker_stake_manager.authority.pubkey())
+        .sol_staker_native_stake(sol_staker_stake_manager.sol_stake)
+        .previous_validator_stake(validator_stake_manager.stake)
+        .previous_validator_stake_authority(validator_stake_manager.authority.pubkey())
+        .current_validator_stake(validator_stake_manager.stake)
+        .current_validator_stake_authority(validator_stake_manager.authority.pubkey())
+        .sol_stake_view_program(paladin_sol_stake_view_program_client::ID)
+        .instruction();
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+}
+
 pub async fn create_sol_staker_stake(
     context: &mut ProgramTestContext,
     sol_stake: &Pubkey,
@@ -11,6 +11,7 @@ use spl_token::{
     instruction::{initialize_account3, initialize_mint, mint_to as spl_mint_to},
     state::{Account as TokenAccount, Mint},
 };
+use spl_token_2022::{extension::ExtensionType, state::AccountState};
 
 pub async fn create_associated_token_account(
     context: &mut ProgramTestContext,
@@ -153,3 +154,97 @@ pub async fn mint_to(
 
     context.banks_client.process_transaction(tx).await
 }
+
+/// Creates a Token-2022 mint with the given extensions.
+///
+/// The mint authority is also used as the authority of the extensions.
+pub async fn create_mint_2022(
+    context: &mut ProgramTestContext,
+    mint: &Keypair,
+    mint_authority: &Pubkey,
+    extensions: &[ExtensionType],
+    decimals: u8,
+) -> Result<(), BanksClientError> {
+    let rent = context.banks_client.get_rent().await.unwrap();
+    let space = ExtensionType::try_calculate_account_len::<spl_token_2022::state::Mint>(extensions)
+        .unwrap();
+
+    let mut instructions = vec![system_instruction::create_account(
+        &context.payer.pubkey(),
+        &mint.pubkey(),
+        rent.minimum_balance(space),
+        space as u64,
+        &spl_token_2022::ID,
+    )];
+
+    for extension in extensions {
+        instructions.push(match extension {
+            ExtensionType::PermanentDelegate => {
+                spl_token_2022::instruction::initialize_permanent_delegate(
+                    &spl_token_2022::ID,
+                    &mint.pubkey(),
+                    mint_authority,
+                )
+                .unwrap()
+            }
+            ExtensionType::TransferFeeConfig => {
+                spl_token_2022::extension::transfer_fee::instruction::initialize_transfer_fee_config(
+                    &spl_token_2022::ID,
+                    &mint.pubkey(),
+                    Some(mint_authority),
+                    Some(mint_authority),
+                    100,
+                    1_000,
+                )
+                .unwrap()
+            }
+            ExtensionType::DefaultAccountState => {
+                spl_token_2022::extension::default_account_state::instruction::initialize_default_account_state(
+                    &spl_token_2022::ID,
+                    &mint.pubkey(),
+                    &AccountState::Initialized,
+                )
+                .unwrap()
+            }
+            ExtensionType::NonTransferable => {
+                spl_token_2022::instruction::initialize_non_transferable_mint(
+                    &spl_token_2022::ID,
+                    &mint.pubkey(),
+                )
+                .unwrap()
+            }
+            ExtensionType::MetadataPointer => {
+                spl_token_2022::extension::metadata_pointer::instruction::initialize(
+                    &spl_token_2022::ID,
+                    &mint.pubkey(),
+                    Some(*mint_authority),
+                    Some(mint.pubkey()),
+                )
+                .unwrap()
+            }
+            extension => panic!("unsupported extension: {extension:?}"),
+        });
+    }
+
+    instructions.push(
+        spl_token_2022::instruction::initialize_mint2(
+            &spl_token_2022::ID,
+            &mint.pubkey(),
+            mint_authority,
+            None,
+            decimals,
+        )
+        .unwrap(),
+    );
+
+    context.get_new_latest_blockhash().await.unwrap();
+
+    let tx = Transaction::new_signed_with_payer(
+        &instructions,
+        Some(&context.payer.pubkey()),
+        &[&context.payer, mint],
+        context.last_blockhash,
+    );
+
+    context.banks_client.process_transaction(tx).await
+}
@@ -1,5 +1,6 @@
 use paladin_stake_program_client::{
-    accounts::ValidatorStake, instructions::InitializeValidatorStakeBuilder,
+    accounts::ValidatorStake,
+    instructions::{HarvestValidatorRewardsBuilder, InitializeValidatorStakeBuilder},
     pdas::find_validator_stake_pda,
 };
 use solana_program_test::ProgramTestContext;
@@ -10,7 +11,7 @@ use solana_sdk::{
 
 use crate::setup::sign_duna_document_with_vote;
 
-use super::vote::create_vote_account;
+use super::{config::ConfigManager, stake::warp_to_next_epoch, vote::create_vote_account};
 
 pub struct ValidatorStakeManager {
     // Stake account.
@@ -62,6 +63,30 @@ impl ValidatorStakeManager {
     }
 }
 
+/// Warps to the next epoch and harvests the validator stake, so the tokens
+/// activating are folded into the effective amount.
+pub async fn activate_validator_stake(
+    context: &mut ProgramTestContext,
+    config_manager: &ConfigManager,
+    validator_stake_manager: &ValidatorStakeManager,
+) {
+    warp_to_next_epoch(context).await;
+
+    let ix = HarvestValidatorRewardsBuilder::new()
+        .config(config_manager.config)
+        .vault_holder_rewards(config_manager.vault_holder_rewards)
+        .validator_stake(validator_stake_manager.stake)
+        .validator_stake_authority(validator_stake_manager.authority.pubkey())
+        .instruction();
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+}
+
 pub async fn create_validator_stake(
     context: &mut ProgramTestContext,
     vote: &Pubkey,
@@ -8,6 +8,7 @@ use paladin_stake_program_client::{accounts::SolStakerStake, instructions::SolSt
 use setup::validator_stake::ValidatorStakeManager;
 use setup::{config::ConfigManager, sol_staker_stake::SolStakerStakeManager};
 use solana_program_test::tokio;
+use solana_sdk::clock::Clock;
 use solana_sdk::instruction::InstructionError;
 use solana_sdk::signature::Keypair;
 use solana_sdk::signer::Signer;
@@ -167,6 +168,87 @@ async fn transfer_to_not_empty() {
     assert_eq!(config.token_amount_effective, 20);
 }
 
+#[tokio::test]
+async fn transfer_keeps_tokens_activating() {
+    let mut context = setup::setup(&[]).await;
+
+    // Setup the relevant accounts.
+    let config_manager = ConfigManager::new(&mut context).await;
+    let validator_stake_manager =
+        ValidatorStakeManager::new(&mut context, &config_manager.config).await;
+    let stake_authority = Keypair::new();
+    let source_sol_staker_staker_manager = SolStakerStakeManager::new_with_authority(
+        &mut context,
+        &config_manager.config,
+        &validator_stake_manager.stake,
+        &validator_stake_manager.vote,
+        stake_authority.insecure_clone(),
+        5_000_000_000, // 5 SOL staked
+    )
+    .await;
+    let destination_sol_staker_staker_manager = SolStakerStakeManager::new_with_authority(
+        &mut context,
+        &config_manager.config,
+        &validator_stake_manager.stake,
+        &validator_stake_manager.vote,
+        stake_authority,
+        5_000_000_000, // 5 SOL staked
+    )
+    .await;
+
+    // Stake 10 PAL on the source account, 4 of them activating this epoch.
+    let epoch = context
+        .banks_client
+        .get_sysvar::<Clock>()
+        .await
+        .unwrap()
+        .epoch;
+    let mut source = get_account!(context, source_sol_staker_staker_manager.stake);
+    let mut source_state = SolStakerStake::from_bytes(&source.data).unwrap();
+    source_state.delegation.staked_amount = 10;
+    source_state.activation.activating_amount = 4;
+    source_state.activation.activation_epoch = epoch;
+    source.data = source_state.try_to_vec().unwrap();
+    context.set_account(&source_sol_staker_staker_manager.stake, &source.into());
+
+    // Act - Transfer 8 PAL to the destination sol staker stake.
+    let sol_staker_move_tokens = SolStakerMoveTokens {
+        config: config_manager.config,
+        vault_holder_rewards: config_manager.vault_holder_rewards,
+        sol_staker_authority: source_sol_staker_staker_manager.authority.pubkey(),
+        source_sol_staker_stake: source_sol_staker_staker_manager.stake,
+        destination_sol_staker_stake: destination_sol_staker_staker_manager.stake,
+    }
+    .instruction(SolStakerMoveTokensInstructionArgs { amount: 8 });
+    let tx = Transaction::new_signed_with_payer(
+        &[sol_staker_move_tokens],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &source_sol_staker_staker_manager.authority],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // Assert - Source account has 2 PAL staked, all of them activating.
+    let source = get_account!(context, source_sol_staker_staker_manager.stake);
+    let source = SolStakerStake::from_bytes(&source.data).unwrap();
+    assert_eq!(source.delegation.staked_amount, 2);
+    assert_eq!(source.delegation.effective_amount, 0);
+
+    // Assert - Destination account has 8 PAL staked, 2 of them still
+    // activating.
+    let destination = get_account!(context, destination_sol_staker_staker_manager.stake);
+    let destination = SolStakerStake::from_bytes(&destination.data).unwrap();
+    assert_eq!(destination.delegation.staked_amount, 8);
+    assert_eq!(destination.activation.activating_amount, 2);
+    assert_eq!(destination.activation.activation_epoch, epoch);
+    assert_eq!(destination.delegation.effective_amount, 6);
+
+    // Assert - Config has 6 effective.
+    let config = get_account!(context, config_manager.config);
+    let config = Config::from_bytes(&config.data).unwrap();
+    assert_eq!(config.token_amount_effective, 6);
+}
+
 #[tokio::test]
 async fn transfer_from_account_with_cooldown() {
     let mut context = setup::setup(&[]).await;
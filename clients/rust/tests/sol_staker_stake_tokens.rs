@@ -11,7 +11,7 @@ use paladin_stake_program_client::{
 use setup::{
     config::ConfigManager,
     setup,
-    sol_staker_stake::SolStakerStakeManager,
+    sol_staker_stake::{activate_sol_staker_stake, SolStakerStakeManager},
     token::{create_token_account, mint_to},
     validator_stake::ValidatorStakeManager,
 };
@@ -86,20 +86,22 @@ async fn sol_staker_stake_tokens_simple() {
     );
     context.banks_client.process_transaction(tx).await.unwrap();
 
-    // Then the tokens are staked.
+    // Then the tokens are staked and activating until the next epoch.
     let account = get_account!(context, sol_staker_staker_manager.stake);
     let stake_account = SolStakerStake::from_bytes(account.data.as_ref()).unwrap();
     assert_eq!(stake_account.delegation.staked_amount, 6_500_000_000);
+    assert_eq!(stake_account.activation.activating_amount, 6_500_000_000);
+    assert_eq!(stake_account.delegation.effective_amount, 0);
 
     // And the vault account has 0 tokens (because they are deposited into holder rewards program)
     let account = get_account!(context, config_manager.vault);
     let vault = TokenAccount::unpack(&account.data).unwrap();
     assert_eq!(vault.amount, 0);
 
-    // And the config account has 6_500_000_000 tokens delegated (staked).
+    // And the config account has no effective tokens yet.
     let account = get_account!(context, config_manager.config);
     let config = Config::from_bytes(account.data.as_ref()).unwrap();
-    assert_eq!(config.token_amount_effective, 6_500_000_000);
+    assert_eq!(config.token_amount_effective, 0);
 
     // Assert rewards program pool have 6_500_000_000 balance
     let vault = get_account!(context, config_manager.rewards_manager.pool_token_account);
@@ -459,7 +461,16 @@ async fn sol_staker_stake_tokens_with_insufficient_staked_sol_reduces_effective(
     );
     context.banks_client.process_transaction(tx).await.unwrap();
 
-    // Assert - The staker's effective stake is less than the total stake.
+    // Assert - Once activated, the staker's effective stake is less than the
+    // total stake.
+    activate_sol_staker_stake(
+        &mut context,
+        &config_manager,
+        &sol_staker_staker_manager,
+        &validator_stake_manager,
+    )
+    .await;
+
     let staker_stake = get_account!(context, sol_staker_staker_manager.stake);
     let staker_stake = SolStakerStake::from_bytes(&staker_stake.data).unwrap();
     assert_eq!(staker_stake.delegation.staked_amount, 6_500_000_001);
@@ -0,0 +1,208 @@
+#![cfg(feature = "test-sbf")]
+
+mod setup;
+
+use borsh::BorshSerialize;
+use paladin_stake_program_client::{
+    accounts::{SolStakerStake, ValidatorStake},
+    client::{StakeAccount, StakeClient, StakeClientError},
+};
+use setup::{
+    config::{create_ata, ConfigManager},
+    setup,
+    sol_staker_stake::SolStakerStakeManager,
+    stake::warp_to_next_epoch,
+    token::mint_to,
+    validator_stake::ValidatorStakeManager,
+};
+use solana_program_test::tokio;
+use solana_sdk::{
+    program_pack::Pack,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    transaction::Transaction,
+};
+use spl_associated_token_account::get_associated_token_address;
+use spl_token::state::Account as TokenAccount;
+
+#[tokio::test]
+async fn stake_harvest_and_unstake_validator_stake() {
+    let mut context = setup(&[]).await;
+
+    // Given a config account and a validator stake account with 1 SOL staked.
+    let config_manager = ConfigManager::new(&mut context).await;
+    let validator_stake_manager =
+        ValidatorStakeManager::new(&mut context, &config_manager.config).await;
+
+    let mut account = get_account!(context, validator_stake_manager.stake);
+    let mut stake_account = ValidatorStake::from_bytes(account.data.as_ref()).unwrap();
+    stake_account.total_staked_lamports_amount = 1_000_000_000;
+    account.data = stake_account.try_to_vec().unwrap();
+    context.set_account(&validator_stake_manager.stake, &account.into());
+
+    // And a token account of the validator authority with 100 tokens.
+    let authority = validator_stake_manager.authority.pubkey();
+    let token_account = get_associated_token_address(&authority, &config_manager.mint);
+    create_ata(&mut context, &authority, &config_manager.mint)
+        .await
+        .unwrap();
+    mint_to(
+        &mut context,
+        &config_manager.mint,
+        &config_manager.mint_authority,
+        &token_account,
+        100,
+    )
+    .await
+    .unwrap();
+
+    let mut client = StakeClient::new(context.banks_client.clone(), config_manager.config);
+    assert_eq!(
+        client.validator_stake_address(&validator_stake_manager.vote),
+        validator_stake_manager.stake
+    );
+
+    // When we stake 100 tokens with the instruction built by the client.
+    let ix = client
+        .stake(&validator_stake_manager.stake, &authority, 100)
+        .await
+        .unwrap();
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &validator_stake_manager.authority],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // And harvest in the next epoch.
+    warp_to_next_epoch(&mut context).await;
+
+    let ix = client
+        .harvest(&validator_stake_manager.stake)
+        .await
+        .unwrap();
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // Then the staked tokens are active.
+    let StakeAccount::Validator(stake_account) = client
+        .get_stake(&validator_stake_manager.stake)
+        .await
+        .unwrap()
+    else {
+        panic!("expected a validator stake account");
+    };
+    assert_eq!(stake_account.delegation.staked_amount, 100);
+    assert_eq!(stake_account.delegation.effective_amount, 100);
+
+    // And when we unstake 5 tokens.
+    let ix = client
+        .unstake(&validator_stake_manager.stake, 5)
+        .await
+        .unwrap();
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &validator_stake_manager.authority],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // Then the tokens are back on the authority token account.
+    let account = get_account!(context, token_account);
+    let account = TokenAccount::unpack(&account.data).unwrap();
+    assert_eq!(u64::from(account.amount), 5);
+}
+
+#[tokio::test]
+async fn move_and_harvest_sol_staker_stake() {
+    let mut context = setup(&[]).await;
+
+    // Given two SOL staker stake accounts of the same authority, the source
+    // with 10 PAL staked.
+    let config_manager = ConfigManager::new(&mut context).await;
+    let validator_stake_manager =
+        ValidatorStakeManager::new(&mut context, &config_manager.config).await;
+    let stake_authority = Keypair::new();
+    let source = SolStakerStakeManager::new_with_authority(
+        &mut context,
+        &config_manager.config,
+        &validator_stake_manager.stake,
+        &validator_stake_manager.vote,
+        stake_authority.insecure_clone(),
+        5_000_000_000, // 5 SOL staked
+    )
+    .await;
+    let destination = SolStakerStakeManager::new_with_authority(
+        &mut context,
+        &config_manager.config,
+        &validator_stake_manager.stake,
+        &validator_stake_manager.vote,
+        stake_authority,
+        5_000_000_000, // 5 SOL staked
+    )
+    .await;
+
+    let mut account = get_account!(context, source.stake);
+    let mut stake_account = SolStakerStake::from_bytes(&account.data).unwrap();
+    stake_account.delegation.staked_amount = 10;
+    account.data = stake_account.try_to_vec().unwrap();
+    context.set_account(&source.stake, &account.into());
+
+    let mut client = StakeClient::new(context.banks_client.clone(), config_manager.config);
+    assert_eq!(
+        client.sol_staker_stake_address(&source.sol_stake),
+        source.stake
+    );
+
+    // When we move 3 PAL to the destination.
+    let ix = client
+        .move_tokens(&source.stake, &destination.stake, 3)
+        .await
+        .unwrap();
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &source.authority],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // Then the destination has 3 PAL staked.
+    let account = get_account!(context, destination.stake);
+    let stake_account = SolStakerStake::from_bytes(&account.data).unwrap();
+    assert_eq!(stake_account.delegation.staked_amount, 3);
+
+    // And the harvest resolves the native stake and validator stake accounts.
+    let ix = client.harvest(&destination.stake).await.unwrap();
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+}
+
+#[tokio::test]
+async fn fail_stake_with_unknown_stake_account() {
+    let mut context = setup(&[]).await;
+    let config_manager = ConfigManager::new(&mut context).await;
+    let mut client = StakeClient::new(context.banks_client.clone(), config_manager.config);
+
+    // When we try to build an instruction for a missing stake account.
+    let stake = Pubkey::new_unique();
+    let err = client
+        .stake(&stake, &Pubkey::new_unique(), 100)
+        .await
+        .unwrap_err();
+
+    // Then we expect an error.
+    assert!(matches!(err, StakeClientError::AccountNotFound(address) if address == stake));
+}
@@ -0,0 +1,156 @@
+#![cfg(feature = "test-sbf")]
+
+mod setup;
+
+use borsh::BorshSerialize;
+use paladin_stake_program_client::{
+    accounts::Config, errors::PaladinStakeProgramError,
+    instructions::SweepUndistributedRewardsBuilder,
+};
+use setup::{config::ConfigManager, setup};
+use solana_program_test::{tokio, BanksClientError, ProgramTestContext};
+use solana_sdk::{
+    account::{Account, AccountSharedData},
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    transaction::Transaction,
+};
+
+/// Adds lamports (rewards) to the config account, optionally setting the
+/// amount of effective tokens.
+fn deposit_rewards(
+    context: &mut ProgramTestContext,
+    config: &Pubkey,
+    mut account: Account,
+    lamports: u64,
+    token_amount_effective: u64,
+) {
+    let mut config_account = Config::from_bytes(account.data.as_ref()).unwrap();
+    config_account.token_amount_effective = token_amount_effective;
+    account.data = config_account.try_to_vec().unwrap();
+    account.lamports += lamports;
+    context.set_account(config, &account.into());
+}
+
+/// Creates a rent-exempt destination account.
+fn create_destination(context: &mut ProgramTestContext) -> Pubkey {
+    let destination = Pubkey::new_unique();
+    context.set_account(
+        &destination,
+        &AccountSharedData::from(Account {
+            lamports: 100_000_000,
+            ..Default::default()
+        }),
+    );
+    destination
+}
+
+async fn sweep(
+    context: &mut ProgramTestContext,
+    config: &Pubkey,
+    config_authority: &Keypair,
+    destination: &Pubkey,
+) -> Result<(), BanksClientError> {
+    let ix = SweepUndistributedRewardsBuilder::new()
+        .config(*config)
+        .config_authority(config_authority.pubkey())
+        .destination(*destination)
+        .instruction();
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, config_authority],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await
+}
+
+#[tokio::test]
+async fn sweep_rewards_received_without_effective_tokens() {
+    let mut context = setup(&[]).await;
+
+    // Given a config without effective tokens that received 100 lamports.
+    let config_manager = ConfigManager::new(&mut context).await;
+    let account = get_account!(context, config_manager.config);
+    let config_lamports = account.lamports;
+    deposit_rewards(&mut context, &config_manager.config, account, 100, 0);
+
+    // When the config authority sweeps the undistributed rewards.
+    let destination = create_destination(&mut context);
+    sweep(
+        &mut context,
+        &config_manager.config,
+        &config_manager.config_authority,
+        &destination,
+    )
+    .await
+    .unwrap();
+
+    // Then the destination received the rewards.
+    let account = get_account!(context, destination);
+    assert_eq!(account.lamports, 100_000_100);
+
+    // And the config no longer tracks them.
+    let account = get_account!(context, config_manager.config);
+    assert_eq!(account.lamports, config_lamports);
+    let config_account = Config::from_bytes(account.data.as_ref()).unwrap();
+    assert_eq!(config_account.undistributed_rewards, 0);
+    assert_eq!(config_account.lamports_last, config_lamports);
+    assert_eq!(config_account.accumulated_stake_rewards_per_token, 0);
+}
+
+#[tokio::test]
+async fn sweep_does_not_take_distributed_rewards() {
+    let mut context = setup(&[]).await;
+
+    // Given a config with 65 effective tokens that received 130 lamports.
+    let config_manager = ConfigManager::new(&mut context).await;
+    let account = get_account!(context, config_manager.config);
+    let config_lamports = account.lamports;
+    deposit_rewards(&mut context, &config_manager.config, account, 130, 65);
+
+    // When the config authority sweeps the undistributed rewards.
+    let destination = create_destination(&mut context);
+    sweep(
+        &mut context,
+        &config_manager.config,
+        &config_manager.config_authority,
+        &destination,
+    )
+    .await
+    .unwrap();
+
+    // Then nothing is swept, since the rewards are owed to the stakers.
+    let account = get_account!(context, destination);
+    assert_eq!(account.lamports, 100_000_000);
+
+    let account = get_account!(context, config_manager.config);
+    assert_eq!(account.lamports, config_lamports + 130);
+    let config_account = Config::from_bytes(account.data.as_ref()).unwrap();
+    assert_eq!(
+        config_account.accumulated_stake_rewards_per_token,
+        2_000_000_000_000_000_000
+    );
+}
+
+#[tokio::test]
+async fn fail_sweep_undistributed_rewards_with_wrong_authority() {
+    let mut context = setup(&[]).await;
+
+    // Given a config account.
+    let config_manager = ConfigManager::new(&mut context).await;
+
+    // When we try to sweep with a different authority.
+    let destination = create_destination(&mut context);
+    let err = sweep(
+        &mut context,
+        &config_manager.config,
+        &Keypair::new(),
+        &destination,
+    )
+    .await
+    .unwrap_err();
+
+    // Then we expect an error.
+    assert_custom_error!(err, PaladinStakeProgramError::InvalidAuthority);
+}
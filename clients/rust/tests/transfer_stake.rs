@@ -0,0 +1,286 @@
+#![cfg(feature = "test-sbf")]
+
+mod setup;
+
+use borsh::BorshSerialize;
+use paladin_stake_program_client::{
+    accounts::{Config, SolStakerStake},
+    events::{parse_events, StakeEvent, TransferStakeEvent},
+    instructions::{TransferStake, TransferStakeInstructionArgs},
+    return_data::HarvestReturnData,
+};
+use setup::{
+    calculate_stake_rewards_per_token, config::ConfigManager,
+    sol_staker_stake::SolStakerStakeManager, validator_stake::ValidatorStakeManager,
+};
+use solana_program_test::{tokio, ProgramTestContext};
+use solana_sdk::{
+    account::{Account, AccountSharedData},
+    clock::Clock,
+    instruction::{Instruction, InstructionError},
+    pubkey::Pubkey,
+    signature::Keypair,
+    signer::Signer,
+    transaction::Transaction,
+};
+
+struct Fixture {
+    config_manager: ConfigManager,
+    source: SolStakerStakeManager,
+    destination: SolStakerStakeManager,
+}
+
+/// Creates two SOL staker stake accounts with different authorities, the source
+/// with 10 PAL staked.
+async fn setup_fixture(context: &mut ProgramTestContext) -> Fixture {
+    let config_manager = ConfigManager::new(context).await;
+    let validator_stake_manager = ValidatorStakeManager::new(context, &config_manager.config).await;
+    let source = SolStakerStakeManager::new_with_authority(
+        context,
+        &config_manager.config,
+        &validator_stake_manager.stake,
+        &validator_stake_manager.vote,
+        Keypair::new(),
+        5_000_000_000, // 5 SOL staked
+    )
+    .await;
+    let destination = SolStakerStakeManager::new_with_authority(
+        context,
+        &config_manager.config,
+        &validator_stake_manager.stake,
+        &validator_stake_manager.vote,
+        Keypair::new(),
+        5_000_000_000, // 5 SOL staked
+    )
+    .await;
+
+    update_stake(context, &source.stake, |stake| {
+        stake.delegation.staked_amount = 10;
+    })
+    .await;
+
+    Fixture {
+        config_manager,
+        source,
+        destination,
+    }
+}
+
+async fn update_stake(
+    context: &mut ProgramTestContext,
+    address: &Pubkey,
+    update: impl FnOnce(&mut SolStakerStake),
+) {
+    let mut account = get_account!(context, *address);
+    let mut stake = SolStakerStake::from_bytes(&account.data).unwrap();
+    update(&mut stake);
+    account.data = stake.try_to_vec().unwrap();
+    context.set_account(address, &account.into());
+}
+
+fn transfer_stake_ix(fixture: &Fixture, amount: u64) -> Instruction {
+    TransferStake {
+        config: fixture.config_manager.config,
+        vault_holder_rewards: fixture.config_manager.vault_holder_rewards,
+        source_sol_staker_stake: fixture.source.stake,
+        source_authority: fixture.source.authority.pubkey(),
+        destination_sol_staker_stake: fixture.destination.stake,
+        destination_authority: fixture.destination.authority.pubkey(),
+    }
+    .instruction(TransferStakeInstructionArgs { amount })
+}
+
+#[tokio::test]
+async fn transfer_stake_between_authorities() {
+    let mut context = setup::setup(&[]).await;
+    let fixture = setup_fixture(&mut context).await;
+
+    // Given a source stake with an active cooldown.
+    update_stake(&mut context, &fixture.source.stake, |stake| {
+        stake.delegation.unstake_cooldown = 50;
+    })
+    .await;
+
+    // When both authorities transfer 4 PAL to the destination.
+    let tx = Transaction::new_signed_with_payer(
+        &[transfer_stake_ix(&fixture, 4)],
+        Some(&context.payer.pubkey()),
+        &[
+            &context.payer,
+            &fixture.source.authority,
+            &fixture.destination.authority,
+        ],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // Then the source has 6 PAL staked.
+    let account = get_account!(context, fixture.source.stake);
+    let source = SolStakerStake::from_bytes(&account.data).unwrap();
+    assert_eq!(source.delegation.staked_amount, 6);
+    assert_eq!(source.delegation.effective_amount, 6);
+
+    // And the destination has 4 PAL staked, with the source cooldown.
+    let account = get_account!(context, fixture.destination.stake);
+    let destination = SolStakerStake::from_bytes(&account.data).unwrap();
+    assert_eq!(destination.delegation.staked_amount, 4);
+    assert_eq!(destination.delegation.effective_amount, 4);
+    assert_eq!(destination.delegation.unstake_cooldown, 50);
+
+    // And the config still has 10 effective.
+    let account = get_account!(context, fixture.config_manager.config);
+    let config = Config::from_bytes(&account.data).unwrap();
+    assert_eq!(config.token_amount_effective, 10);
+}
+
+#[tokio::test]
+async fn transfer_stake_returns_harvested_rewards_and_emits_event() {
+    let mut context = setup::setup(&[]).await;
+    let fixture = setup_fixture(&mut context).await;
+
+    // Given a source stake with 10 PAL effective and 20 lamports of rewards
+    // accrued for it.
+    update_stake(&mut context, &fixture.source.stake, |stake| {
+        stake.delegation.effective_amount = 10;
+    })
+    .await;
+
+    let mut account = get_account!(context, fixture.config_manager.config);
+    let mut config = Config::from_bytes(&account.data).unwrap();
+    config.token_amount_effective = 10;
+    config.accumulated_stake_rewards_per_token = calculate_stake_rewards_per_token(20, 10);
+    account.lamports += 20;
+    config.lamports_last = account.lamports;
+    account.data = config.try_to_vec().unwrap();
+    context.set_account(&fixture.config_manager.config, &account.into());
+
+    context.set_account(
+        &fixture.source.authority.pubkey(),
+        &AccountSharedData::from(Account {
+            lamports: 100_000_000,
+            ..Default::default()
+        }),
+    );
+
+    // When both authorities transfer 4 PAL to the destination.
+    let tx = Transaction::new_signed_with_payer(
+        &[transfer_stake_ix(&fixture, 4)],
+        Some(&context.payer.pubkey()),
+        &[
+            &context.payer,
+            &fixture.source.authority,
+            &fixture.destination.authority,
+        ],
+        context.last_blockhash,
+    );
+    let metadata = context
+        .banks_client
+        .process_transaction_with_metadata(tx)
+        .await
+        .unwrap()
+        .metadata
+        .unwrap();
+
+    // Then the source authority received the rewards.
+    let account = get_account!(context, fixture.source.authority.pubkey());
+    assert_eq!(account.lamports, 100_000_000 + 20);
+
+    // And the amounts harvested from the source are returned.
+    let account = get_account!(context, fixture.source.stake);
+    let source = SolStakerStake::from_bytes(&account.data).unwrap();
+    let return_data = metadata.return_data.unwrap();
+    assert_eq!(
+        HarvestReturnData::from_return_data(&return_data.program_id, &return_data.data),
+        Some(HarvestReturnData {
+            staking_reward: 20,
+            holder_reward: 0,
+            keeper_reward: 0,
+            last_seen_stake_rewards_per_token: 2_000_000_000_000_000_000,
+            last_seen_holder_rewards_per_token: source
+                .delegation
+                .last_seen_holder_rewards_per_token,
+        })
+    );
+
+    // And a transfer stake event is emitted.
+    assert!(
+        parse_events(&metadata.log_messages).contains(&StakeEvent::TransferStake(
+            TransferStakeEvent {
+                config: fixture.config_manager.config,
+                source: fixture.source.stake,
+                destination: fixture.destination.stake,
+                source_authority: fixture.source.authority.pubkey(),
+                destination_authority: fixture.destination.authority.pubkey(),
+                amount: 4,
+            }
+        ))
+    );
+}
+
+#[tokio::test]
+async fn transfer_stake_keeps_tokens_activating() {
+    let mut context = setup::setup(&[]).await;
+    let fixture = setup_fixture(&mut context).await;
+
+    // Given a source stake whose 10 PAL are activating.
+    let epoch = context
+        .banks_client
+        .get_sysvar::<Clock>()
+        .await
+        .unwrap()
+        .epoch;
+    update_stake(&mut context, &fixture.source.stake, |stake| {
+        stake.activation.activating_amount = 10;
+        stake.activation.activation_epoch = epoch;
+    })
+    .await;
+
+    // When both authorities transfer 4 PAL to the destination.
+    let tx = Transaction::new_signed_with_payer(
+        &[transfer_stake_ix(&fixture, 4)],
+        Some(&context.payer.pubkey()),
+        &[
+            &context.payer,
+            &fixture.source.authority,
+            &fixture.destination.authority,
+        ],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // Then the transferred tokens are still activating on the destination.
+    let account = get_account!(context, fixture.destination.stake);
+    let destination = SolStakerStake::from_bytes(&account.data).unwrap();
+    assert_eq!(destination.delegation.staked_amount, 4);
+    assert_eq!(destination.activation.activating_amount, 4);
+    assert_eq!(destination.delegation.effective_amount, 0);
+
+    let account = get_account!(context, fixture.source.stake);
+    let source = SolStakerStake::from_bytes(&account.data).unwrap();
+    assert_eq!(source.delegation.staked_amount, 6);
+    assert_eq!(source.delegation.effective_amount, 0);
+}
+
+#[tokio::test]
+async fn fail_transfer_stake_without_destination_signature() {
+    let mut context = setup::setup(&[]).await;
+    let fixture = setup_fixture(&mut context).await;
+
+    // When we try to transfer without the destination authority signature.
+    let mut ix = transfer_stake_ix(&fixture, 4);
+    ix.accounts[5].is_signer = false;
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &fixture.source.authority],
+        context.last_blockhash,
+    );
+    let err = context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .unwrap_err();
+
+    // Then we expect an error.
+    assert_instruction_error!(err, InstructionError::MissingRequiredSignature);
+}
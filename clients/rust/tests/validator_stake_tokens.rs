@@ -11,8 +11,12 @@ use paladin_stake_program_client::{
     pdas::find_validator_stake_pda,
 };
 use setup::{
-    config::ConfigManager, rewards::create_holder_rewards, setup, token::mint_to,
-    validator_stake::ValidatorStakeManager, vote::create_vote_account,
+    config::ConfigManager,
+    rewards::create_holder_rewards,
+    setup,
+    token::mint_to,
+    validator_stake::{activate_validator_stake, ValidatorStakeManager},
+    vote::create_vote_account,
 };
 use solana_program_test::tokio;
 use solana_sdk::{
@@ -82,20 +86,22 @@ async fn validator_stake_tokens_simple() {
     );
     context.banks_client.process_transaction(tx).await.unwrap();
 
-    // Assert - The tokens are staked.
+    // Assert - The tokens are staked and activating until the next epoch.
     let account = get_account!(context, stake_manager.stake);
     let stake_account = ValidatorStake::from_bytes(account.data.as_ref()).unwrap();
     assert_eq!(stake_account.delegation.staked_amount, 65);
+    assert_eq!(stake_account.activation.activating_amount, 65);
+    assert_eq!(stake_account.delegation.effective_amount, 0);
 
     // Assert - The vault account has 0 tokens. (they were deposited to holder rewards program)
     let account = get_account!(context, config_manager.vault);
     let vault = TokenAccount::unpack(&account.data).unwrap();
     assert_eq!(vault.amount, 0);
 
-    // Assert - The config account has 65 tokens delegated (staked).
+    // Assert - The config account has no effective tokens yet.
     let account = get_account!(context, config_manager.config);
     let config = Config::from_bytes(account.data.as_ref()).unwrap();
-    assert_eq!(config.token_amount_effective, 65);
+    assert_eq!(config.token_amount_effective, 0);
 
     // Assert rewards program pool have 65 balance
     let vault = get_account!(context, config_manager.rewards_manager.pool_token_account);
@@ -109,6 +115,70 @@ async fn validator_stake_tokens_simple() {
     assert_eq!(vault_holder_rewards.deposited, 65)
 }
 
+#[tokio::test]
+async fn validator_stake_tokens_become_effective_on_next_epoch() {
+    let mut context = setup(&[]).await;
+
+    // Given a config account and a validator stake with 50 SOL staked.
+    let config_manager = ConfigManager::new(&mut context).await;
+    let stake_manager = ValidatorStakeManager::new(&mut context, &config_manager.config).await;
+
+    let mut account = get_account!(context, stake_manager.stake);
+    let mut stake_account = ValidatorStake::from_bytes(account.data.as_ref()).unwrap();
+    stake_account.total_staked_lamports_amount = 50;
+    account.data = stake_account.try_to_vec().unwrap();
+    context.set_account(&stake_manager.stake, &account.into());
+
+    // And 65 tokens staked on the current epoch.
+    mint_to(
+        &mut context,
+        &config_manager.mint,
+        &config_manager.mint_authority,
+        &config_manager.rewards_manager.owner_token_account,
+        65,
+    )
+    .await
+    .unwrap();
+
+    let stake_ix = ValidatorStakeTokensBuilder::new()
+        .config(config_manager.config)
+        .holder_rewards_pool(config_manager.rewards_manager.pool)
+        .holder_rewards_pool_token_account(config_manager.rewards_manager.pool_token_account)
+        .validator_stake(stake_manager.stake)
+        .validator_stake_authority(stake_manager.authority.pubkey())
+        .source_token_account(config_manager.rewards_manager.owner_token_account)
+        .source_token_account_authority(config_manager.rewards_manager.owner.pubkey())
+        .mint(config_manager.mint)
+        .vault(config_manager.vault)
+        .vault_pda(config_manager.vault_pda)
+        .vault_holder_rewards(config_manager.vault_holder_rewards)
+        .token_program(spl_token::ID)
+        .rewards_program(paladin_rewards_program_client::ID)
+        .amount(65)
+        .instruction();
+    let tx = Transaction::new_signed_with_payer(
+        &[stake_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &config_manager.rewards_manager.owner],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // When the next epoch starts and the stake is harvested.
+    activate_validator_stake(&mut context, &config_manager, &stake_manager).await;
+
+    // Then the tokens are no longer activating and count as effective.
+    let account = get_account!(context, stake_manager.stake);
+    let stake_account = ValidatorStake::from_bytes(account.data.as_ref()).unwrap();
+    assert_eq!(stake_account.delegation.staked_amount, 65);
+    assert_eq!(stake_account.activation.activating_amount, 0);
+    assert_eq!(stake_account.delegation.effective_amount, 65);
+
+    let account = get_account!(context, config_manager.config);
+    let config = Config::from_bytes(account.data.as_ref()).unwrap();
+    assert_eq!(config.token_amount_effective, 65);
+}
+
 #[tokio::test]
 async fn fail_validator_stake_tokens_with_wrong_vault_holder_rewards_account() {
     let mut context = setup(&[]).await;
@@ -596,7 +666,10 @@ async fn validator_stake_tokens_with_insufficient_staked_sol_effective_capped()
     );
     context.banks_client.process_transaction(tx).await.unwrap();
 
-    // Assert - The tokens should be staked but their effective weight is capped.
+    // Assert - Once activated, the tokens are staked but their effective weight
+    // is capped.
+    activate_validator_stake(&mut context, &config_manager, &stake_manager).await;
+
     let account = get_account!(context, stake_manager.stake);
     let account = ValidatorStake::from_bytes(&account.data).unwrap();
     assert_eq!(
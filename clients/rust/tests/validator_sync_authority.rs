@@ -1,6 +1,7 @@
 #![cfg(feature = "test-sbf")]
 use paladin_stake_program_client::accounts::ValidatorStake;
 use paladin_stake_program_client::instructions::ValidatorSyncAuthority;
+use paladin_stake_program_client::pdas::find_validator_authority_override_pda;
 use setup::{config::ConfigManager, setup, validator_stake::ValidatorStakeManager};
 use solana_program_test::tokio;
 use solana_sdk::instruction::InstructionError;
@@ -30,6 +31,11 @@ async fn update_validator_authority_ok() {
         config: config_manager.config,
         validator_stake: validator_stake_manager.stake,
         validator_vote: validator_stake_manager.vote,
+        validator_authority_override: find_validator_authority_override_pda(
+            &validator_stake_manager.vote,
+            &config_manager.config,
+        )
+        .0,
     }
     .instruction();
     let tx = Transaction::new_signed_with_payer(
@@ -66,6 +72,11 @@ async fn update_validator_authority_err_invalid_config() {
         config: Pubkey::new_unique(),
         validator_stake: validator_stake_manager.stake,
         validator_vote: validator_stake_manager.vote,
+        validator_authority_override: find_validator_authority_override_pda(
+            &validator_stake_manager.vote,
+            &config_manager.config,
+        )
+        .0,
     }
     .instruction();
     let tx = Transaction::new_signed_with_payer(
@@ -109,6 +120,11 @@ async fn update_validator_authority_err_vote_mismatch() {
         config: config_manager.config,
         validator_stake: validator_stake_manager.stake,
         validator_vote: wrong_vote,
+        validator_authority_override: find_validator_authority_override_pda(
+            &wrong_vote,
+            &config_manager.config,
+        )
+        .0,
     }
     .instruction();
     let tx = Transaction::new_signed_with_payer(
@@ -152,6 +168,11 @@ async fn update_validator_authority_err_stake_mismatch() {
         config: config_manager.config,
         validator_stake: wrong_validator_stake,
         validator_vote: validator_stake_manager.vote,
+        validator_authority_override: find_validator_authority_override_pda(
+            &validator_stake_manager.vote,
+            &config_manager.config,
+        )
+        .0,
     }
     .instruction();
     let tx = Transaction::new_signed_with_payer(
@@ -169,3 +190,93 @@ async fn update_validator_authority_err_stake_mismatch() {
     // Assert
     assert_instruction_error!(err, InstructionError::InvalidSeeds);
 }
+
+#[tokio::test]
+async fn update_validator_authority_err_override_mismatch() {
+    let mut context = setup(&[]).await;
+
+    // Setup the relevant accounts.
+    let config_manager = ConfigManager::new(&mut context).await;
+    let validator_stake_manager =
+        ValidatorStakeManager::new(&mut context, &config_manager.config).await;
+
+    // Create an additional vote account.
+    let wrong_vote = ValidatorStakeManager::new(&mut context, &config_manager.config)
+        .await
+        .vote;
+
+    // Act - Update the authority with the override account of another vote account.
+    let validator_sync_authority = ValidatorSyncAuthority {
+        config: config_manager.config,
+        validator_stake: validator_stake_manager.stake,
+        validator_vote: validator_stake_manager.vote,
+        validator_authority_override: find_validator_authority_override_pda(
+            &wrong_vote,
+            &config_manager.config,
+        )
+        .0,
+    }
+    .instruction();
+    let tx = Transaction::new_signed_with_payer(
+        &[validator_sync_authority],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    let err = context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .unwrap_err();
+
+    // Assert
+    assert_instruction_error!(err, InstructionError::InvalidSeeds);
+}
+
+#[tokio::test]
+async fn update_validator_authority_with_previous_layout_ok() {
+    let mut context = setup(&[]).await;
+
+    // Setup the relevant accounts.
+    let config_manager = ConfigManager::new(&mut context).await;
+    let validator_stake_manager =
+        ValidatorStakeManager::new(&mut context, &config_manager.config).await;
+
+    // Rewrite the validator stake account using a previous (shorter) layout.
+    let mut stake_account = get_account!(context, validator_stake_manager.stake);
+    stake_account.data.truncate(ValidatorStake::LEN - 8);
+    context.set_account(&validator_stake_manager.stake, &stake_account.into());
+
+    // Update the withdraw authority on the vote account.
+    let new_authority = Pubkey::new_unique();
+    let mut vote_account = get_account!(context, validator_stake_manager.vote);
+    vote_account.data[36..68].copy_from_slice(&new_authority.to_bytes());
+    context.set_account(&validator_stake_manager.vote, &vote_account.into());
+
+    // Act - Update the authority.
+    let validator_sync_authority = ValidatorSyncAuthority {
+        config: config_manager.config,
+        validator_stake: validator_stake_manager.stake,
+        validator_vote: validator_stake_manager.vote,
+        validator_authority_override: find_validator_authority_override_pda(
+            &validator_stake_manager.vote,
+            &config_manager.config,
+        )
+        .0,
+    }
+    .instruction();
+    let tx = Transaction::new_signed_with_payer(
+        &[validator_sync_authority],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // Assert - Authority has been changed in place, keeping the layout.
+    let mut stake = get_account!(context, validator_stake_manager.stake);
+    assert_eq!(stake.data.len(), ValidatorStake::LEN - 8);
+    stake.data.resize(ValidatorStake::LEN, 0);
+    let stake = ValidatorStake::from_bytes(&stake.data).unwrap();
+    assert_eq!(stake.delegation.authority, new_authority);
+}
@@ -0,0 +1,1836 @@
+//! Cross-program invocation helpers.
+//!
+//! Each `*Cpi` struct takes the `AccountInfo`s of a [`StakeInstruction`] in
+//! the order expected by the program, so programs composing with the stake
+//! program do not have to mirror its account lists. Omitted optional accounts
+//! are replaced by the stake program account, which the program interprets as
+//! the account not being provided.
+//!
+//! Remaining accounts (e.g. the extra accounts of the transfer hook or the
+//! `DelegatedVotingPower` accounts of vote delegates) are appended after the
+//! instruction accounts.
+
+use solana_program::{
+    account_info::AccountInfo,
+    entrypoint::ProgramResult,
+    instruction::{AccountMeta, Instruction},
+    program::invoke_signed,
+    pubkey::Pubkey,
+};
+
+use crate::instruction::{AuthorityType, ConfigField, StakeInstruction};
+
+/// Account of an instruction, with the privileges required by the
+/// instruction.
+struct CpiAccount<'a, 'info> {
+    info: &'a AccountInfo<'info>,
+    is_writable: bool,
+    is_signer: bool,
+}
+
+impl<'a, 'info> CpiAccount<'a, 'info> {
+    fn readonly(info: &'a AccountInfo<'info>) -> Self {
+        Self {
+            info,
+            is_writable: false,
+            is_signer: false,
+        }
+    }
+
+    fn writable(info: &'a AccountInfo<'info>) -> Self {
+        Self {
+            info,
+            is_writable: true,
+            is_signer: false,
+        }
+    }
+
+    fn readonly_signer(info: &'a AccountInfo<'info>) -> Self {
+        Self {
+            info,
+            is_writable: false,
+            is_signer: true,
+        }
+    }
+
+    fn writable_signer(info: &'a AccountInfo<'info>) -> Self {
+        Self {
+            info,
+            is_writable: true,
+            is_signer: true,
+        }
+    }
+
+    /// Optional account, replaced by the (read-only) stake program account
+    /// when omitted.
+    fn optional(
+        info: Option<&'a AccountInfo<'info>>,
+        program: &'a AccountInfo<'info>,
+        account: fn(&'a AccountInfo<'info>) -> Self,
+    ) -> Self {
+        match info {
+            Some(info) => account(info),
+            None => Self::readonly(program),
+        }
+    }
+}
+
+/// Invokes the stake program with the given instruction and accounts.
+fn invoke_stake_program<'info>(
+    program: &AccountInfo<'info>,
+    instruction: StakeInstruction,
+    accounts: &[CpiAccount<'_, 'info>],
+    remaining_accounts: &[AccountInfo<'info>],
+    signers_seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let len = accounts.len() + remaining_accounts.len();
+    let mut account_metas = Vec::with_capacity(len);
+    let mut account_infos = Vec::with_capacity(len + 1);
+
+    for account in accounts {
+        account_metas.push(AccountMeta {
+            pubkey: *account.info.key,
+            is_signer: account.is_signer,
+            is_writable: account.is_writable,
+        });
+        account_infos.push(account.info.clone());
+    }
+    for account in remaining_accounts {
+        account_metas.push(AccountMeta {
+            pubkey: *account.key,
+            is_signer: account.is_signer,
+            is_writable: account.is_writable,
+        });
+        account_infos.push(account.clone());
+    }
+    account_infos.push(program.clone());
+
+    invoke_signed(
+        &Instruction {
+            program_id: *program.key,
+            accounts: account_metas,
+            data: instruction.pack(),
+        },
+        &account_infos,
+        signers_seeds,
+    )
+}
+
+/// Creates Stake config account which controls staking parameters.
+///
+/// See [`StakeInstruction::InitializeConfig`].
+pub struct InitializeConfigCpi<'a, 'info> {
+    /// Stake program.
+    pub program: &'a AccountInfo<'info>,
+    /// Stake config account.
+    pub config: &'a AccountInfo<'info>,
+    /// Stake token mint.
+    pub mint: &'a AccountInfo<'info>,
+    /// Stake vault pda.
+    pub vault_pda: &'a AccountInfo<'info>,
+    /// Stake vault token account.
+    pub vault: &'a AccountInfo<'info>,
+    /// Stake vault holder rewards account.
+    pub vault_holder_rewards: &'a AccountInfo<'info>,
+    /// System program.
+    pub system_program: &'a AccountInfo<'info>,
+    /// Paladin rewards program.
+    pub rewards_program: &'a AccountInfo<'info>,
+    /// Remaining accounts of the instruction.
+    pub remaining_accounts: &'a [AccountInfo<'info>],
+    /// Authority that can slash any stake.
+    pub slash_authority: Pubkey,
+    /// Authority that can modify the config.
+    pub config_authority: Pubkey,
+    /// Seconds between deactivation and inactivation.
+    pub cooldown_time_seconds: u64,
+    /// Proportion that can be deactivated at once, in basis points.
+    pub max_deactivation_basis_points: u16,
+    /// Lamports paid for syncing a SOL stake account.
+    pub sync_rewards_lamports: u64,
+    /// Hash of the DUNA document.
+    pub duna_document_hash: [u8; 32],
+}
+
+impl<'a, 'info> InitializeConfigCpi<'a, 'info> {
+    /// Invokes the instruction.
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    /// Invokes the instruction with the given PDA signer seeds.
+    pub fn invoke_signed(&self, signers_seeds: &[&[&[u8]]]) -> ProgramResult {
+        invoke_stake_program(
+            self.program,
+            StakeInstruction::InitializeConfig {
+                slash_authority: self.slash_authority,
+                config_authority: self.config_authority,
+                cooldown_time_seconds: self.cooldown_time_seconds,
+                max_deactivation_basis_points: self.max_deactivation_basis_points,
+                sync_rewards_lamports: self.sync_rewards_lamports,
+                duna_document_hash: self.duna_document_hash,
+            },
+            &[
+                CpiAccount::writable(self.config),
+                CpiAccount::readonly(self.mint),
+                CpiAccount::writable(self.vault_pda),
+                CpiAccount::readonly(self.vault),
+                CpiAccount::writable(self.vault_holder_rewards),
+                CpiAccount::readonly(self.system_program),
+                CpiAccount::readonly(self.rewards_program),
+            ],
+            self.remaining_accounts,
+            signers_seeds,
+        )
+    }
+}
+
+/// Initializes stake account data for a validator.
+///
+/// The validator authority override account (if any) is passed as the first
+/// remaining account.
+///
+/// See [`StakeInstruction::InitializeValidatorStake`].
+pub struct InitializeValidatorStakeCpi<'a, 'info> {
+    /// Stake program.
+    pub program: &'a AccountInfo<'info>,
+    /// Stake config account.
+    pub config: &'a AccountInfo<'info>,
+    /// DUNA document PDA account.
+    pub duna_document_pda: &'a AccountInfo<'info>,
+    /// Validator stake account.
+    pub validator_stake: &'a AccountInfo<'info>,
+    /// Validator vote account.
+    pub validator_vote: &'a AccountInfo<'info>,
+    /// System program.
+    pub system_program: &'a AccountInfo<'info>,
+    /// Remaining accounts of the instruction.
+    pub remaining_accounts: &'a [AccountInfo<'info>],
+}
+
+impl<'a, 'info> InitializeValidatorStakeCpi<'a, 'info> {
+    /// Invokes the instruction.
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    /// Invokes the instruction with the given PDA signer seeds.
+    pub fn invoke_signed(&self, signers_seeds: &[&[&[u8]]]) -> ProgramResult {
+        invoke_stake_program(
+            self.program,
+            StakeInstruction::InitializeValidatorStake,
+            &[
+                CpiAccount::readonly(self.config),
+                CpiAccount::readonly(self.duna_document_pda),
+                CpiAccount::writable(self.validator_stake),
+                CpiAccount::readonly(self.validator_vote),
+                CpiAccount::readonly(self.system_program),
+            ],
+            self.remaining_accounts,
+            signers_seeds,
+        )
+    }
+}
+
+/// Stakes tokens with the given config.
+///
+/// See [`StakeInstruction::ValidatorStakeTokens`].
+pub struct ValidatorStakeTokensCpi<'a, 'info> {
+    /// Stake program.
+    pub program: &'a AccountInfo<'info>,
+    /// Stake config account.
+    pub config: &'a AccountInfo<'info>,
+    /// Holder rewards pool account.
+    pub holder_rewards_pool: &'a AccountInfo<'info>,
+    /// Holder rewards pool account token account.
+    pub holder_rewards_pool_token_account: &'a AccountInfo<'info>,
+    /// Validator stake account.
+    pub validator_stake: &'a AccountInfo<'info>,
+    /// Validator stake account.
+    pub validator_stake_authority: &'a AccountInfo<'info>,
+    /// Token account.
+    pub source_token_account: &'a AccountInfo<'info>,
+    /// Owner or delegate of the token account.
+    pub source_token_account_authority: &'a AccountInfo<'info>,
+    /// Stake Token Mint.
+    pub mint: &'a AccountInfo<'info>,
+    /// Stake vault pda.
+    pub vault_pda: &'a AccountInfo<'info>,
+    /// Stake token Vault.
+    pub vault: &'a AccountInfo<'info>,
+    /// Holder rewards for the vault account (to facilitate harvest).
+    pub vault_holder_rewards: &'a AccountInfo<'info>,
+    /// Token program.
+    pub token_program: &'a AccountInfo<'info>,
+    /// Paladin rewards program.
+    pub rewards_program: &'a AccountInfo<'info>,
+    /// Remaining accounts of the instruction.
+    pub remaining_accounts: &'a [AccountInfo<'info>],
+    /// Amount of tokens.
+    pub amount: u64,
+}
+
+impl<'a, 'info> ValidatorStakeTokensCpi<'a, 'info> {
+    /// Invokes the instruction.
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    /// Invokes the instruction with the given PDA signer seeds.
+    pub fn invoke_signed(&self, signers_seeds: &[&[&[u8]]]) -> ProgramResult {
+        invoke_stake_program(
+            self.program,
+            StakeInstruction::ValidatorStakeTokens(self.amount),
+            &[
+                CpiAccount::writable(self.config),
+                CpiAccount::writable(self.holder_rewards_pool),
+                CpiAccount::writable(self.holder_rewards_pool_token_account),
+                CpiAccount::writable(self.validator_stake),
+                CpiAccount::readonly(self.validator_stake_authority),
+                CpiAccount::writable(self.source_token_account),
+                CpiAccount::readonly_signer(self.source_token_account_authority),
+                CpiAccount::readonly(self.mint),
+                CpiAccount::writable(self.vault_pda),
+                CpiAccount::writable(self.vault),
+                CpiAccount::writable(self.vault_holder_rewards),
+                CpiAccount::readonly(self.token_program),
+                CpiAccount::readonly(self.rewards_program),
+            ],
+            self.remaining_accounts,
+            signers_seeds,
+        )
+    }
+}
+
+/// Harvests holder SOL rewards earned by the given stake account.
+///
+/// See [`StakeInstruction::HarvestHolderRewards`].
+pub struct HarvestHolderRewardsCpi<'a, 'info> {
+    /// Stake program.
+    pub program: &'a AccountInfo<'info>,
+    /// Stake config account.
+    pub config: &'a AccountInfo<'info>,
+    /// Holder rewards pool account.
+    pub holder_rewards_pool: &'a AccountInfo<'info>,
+    /// Holder rewards pool account token account.
+    pub holder_rewards_pool_token_account: &'a AccountInfo<'info>,
+    /// Vault token account.
+    pub vault: &'a AccountInfo<'info>,
+    /// Holder rewards account for vault token account.
+    pub vault_holder_rewards: &'a AccountInfo<'info>,
+    /// Vault pda.
+    pub vault_pda: &'a AccountInfo<'info>,
+    /// Stake token mint.
+    pub mint: &'a AccountInfo<'info>,
+    /// Token program.
+    pub token_program: &'a AccountInfo<'info>,
+    /// Paladin rewards program.
+    pub paladin_rewards_program: &'a AccountInfo<'info>,
+    /// System program.
+    pub system_program: &'a AccountInfo<'info>,
+    /// Remaining accounts of the instruction.
+    pub remaining_accounts: &'a [AccountInfo<'info>],
+}
+
+impl<'a, 'info> HarvestHolderRewardsCpi<'a, 'info> {
+    /// Invokes the instruction.
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    /// Invokes the instruction with the given PDA signer seeds.
+    pub fn invoke_signed(&self, signers_seeds: &[&[&[u8]]]) -> ProgramResult {
+        invoke_stake_program(
+            self.program,
+            StakeInstruction::HarvestHolderRewards,
+            &[
+                CpiAccount::writable(self.config),
+                CpiAccount::writable(self.holder_rewards_pool),
+                CpiAccount::readonly(self.holder_rewards_pool_token_account),
+                CpiAccount::writable(self.vault),
+                CpiAccount::writable(self.vault_holder_rewards),
+                CpiAccount::writable(self.vault_pda),
+                CpiAccount::readonly(self.mint),
+                CpiAccount::readonly(self.token_program),
+                CpiAccount::readonly(self.paladin_rewards_program),
+                CpiAccount::readonly(self.system_program),
+            ],
+            self.remaining_accounts,
+            signers_seeds,
+        )
+    }
+}
+
+/// Harvests stake SOL rewards earned by the given validator stake account.
+///
+/// See [`StakeInstruction::HarvestValidatorRewards`].
+pub struct HarvestValidatorRewardsCpi<'a, 'info> {
+    /// Stake program.
+    pub program: &'a AccountInfo<'info>,
+    /// Stake config account.
+    pub config: &'a AccountInfo<'info>,
+    /// Holder rewards account.
+    pub vault_holder_rewards: &'a AccountInfo<'info>,
+    /// Validator stake account.
+    pub validator_stake: &'a AccountInfo<'info>,
+    /// Validator stake authority.
+    pub validator_stake_authority: &'a AccountInfo<'info>,
+    /// Remaining accounts of the instruction.
+    pub remaining_accounts: &'a [AccountInfo<'info>],
+}
+
+impl<'a, 'info> HarvestValidatorRewardsCpi<'a, 'info> {
+    /// Invokes the instruction.
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    /// Invokes the instruction with the given PDA signer seeds.
+    pub fn invoke_signed(&self, signers_seeds: &[&[&[u8]]]) -> ProgramResult {
+        invoke_stake_program(
+            self.program,
+            StakeInstruction::HarvestValidatorRewards,
+            &[
+                CpiAccount::writable(self.config),
+                CpiAccount::readonly(self.vault_holder_rewards),
+                CpiAccount::writable(self.validator_stake),
+                CpiAccount::writable(self.validator_stake_authority),
+            ],
+            self.remaining_accounts,
+            signers_seeds,
+        )
+    }
+}
+
+/// Slashes a validator stake account for the given amount.
+///
+/// See [`StakeInstruction::SlashValidatorStake`].
+pub struct SlashValidatorStakeCpi<'a, 'info> {
+    /// Stake program.
+    pub program: &'a AccountInfo<'info>,
+    /// Stake config account.
+    pub config: &'a AccountInfo<'info>,
+    /// Validator stake account.
+    pub validator_stake: &'a AccountInfo<'info>,
+    /// Validator stake authority account.
+    pub validator_stake_authority: &'a AccountInfo<'info>,
+    /// Config slash authority.
+    pub slash_authority: &'a AccountInfo<'info>,
+    /// Vault token account.
+    pub vault: &'a AccountInfo<'info>,
+    /// Vault token account.
+    pub vault_holder_rewards: &'a AccountInfo<'info>,
+    /// Vault authority.
+    pub vault_authority: &'a AccountInfo<'info>,
+    /// Stake Token Mint.
+    pub mint: &'a AccountInfo<'info>,
+    /// Token program.
+    pub token_program: &'a AccountInfo<'info>,
+    /// Remaining accounts of the instruction.
+    pub remaining_accounts: &'a [AccountInfo<'info>],
+    /// Amount of tokens.
+    pub amount: u64,
+}
+
+impl<'a, 'info> SlashValidatorStakeCpi<'a, 'info> {
+    /// Invokes the instruction.
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    /// Invokes the instruction with the given PDA signer seeds.
+    pub fn invoke_signed(&self, signers_seeds: &[&[&[u8]]]) -> ProgramResult {
+        invoke_stake_program(
+            self.program,
+            StakeInstruction::SlashValidatorStake(self.amount),
+            &[
+                CpiAccount::writable(self.config),
+                CpiAccount::writable(self.validator_stake),
+                CpiAccount::writable(self.validator_stake_authority),
+                CpiAccount::readonly_signer(self.slash_authority),
+                CpiAccount::writable(self.vault),
+                CpiAccount::readonly(self.vault_holder_rewards),
+                CpiAccount::readonly(self.vault_authority),
+                CpiAccount::writable(self.mint),
+                CpiAccount::readonly(self.token_program),
+            ],
+            self.remaining_accounts,
+            signers_seeds,
+        )
+    }
+}
+
+/// Sets new authority on a config or stake account.
+///
+/// See [`StakeInstruction::SetAuthority`].
+pub struct SetAuthorityCpi<'a, 'info> {
+    /// Stake program.
+    pub program: &'a AccountInfo<'info>,
+    /// Config or Stake account.
+    pub account: &'a AccountInfo<'info>,
+    /// Current authority on the account.
+    pub authority: &'a AccountInfo<'info>,
+    /// Authority to set.
+    pub new_authority: &'a AccountInfo<'info>,
+    /// Remaining accounts of the instruction.
+    pub remaining_accounts: &'a [AccountInfo<'info>],
+    /// Authority to set.
+    pub authority_type: AuthorityType,
+}
+
+impl<'a, 'info> SetAuthorityCpi<'a, 'info> {
+    /// Invokes the instruction.
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    /// Invokes the instruction with the given PDA signer seeds.
+    pub fn invoke_signed(&self, signers_seeds: &[&[&[u8]]]) -> ProgramResult {
+        invoke_stake_program(
+            self.program,
+            StakeInstruction::SetAuthority(self.authority_type.clone()),
+            &[
+                CpiAccount::writable(self.account),
+                CpiAccount::readonly_signer(self.authority),
+                CpiAccount::readonly(self.new_authority),
+            ],
+            self.remaining_accounts,
+            signers_seeds,
+        )
+    }
+}
+
+/// Updates configuration parameters.
+///
+/// See [`StakeInstruction::UpdateConfig`].
+pub struct UpdateConfigCpi<'a, 'info> {
+    /// Stake program.
+    pub program: &'a AccountInfo<'info>,
+    /// Stake config account.
+    pub config: &'a AccountInfo<'info>,
+    /// Stake config authority.
+    pub config_authority: &'a AccountInfo<'info>,
+    /// Remaining accounts of the instruction.
+    pub remaining_accounts: &'a [AccountInfo<'info>],
+    /// Config field to update.
+    pub config_field: ConfigField,
+}
+
+impl<'a, 'info> UpdateConfigCpi<'a, 'info> {
+    /// Invokes the instruction.
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    /// Invokes the instruction with the given PDA signer seeds.
+    pub fn invoke_signed(&self, signers_seeds: &[&[&[u8]]]) -> ProgramResult {
+        invoke_stake_program(
+            self.program,
+            StakeInstruction::UpdateConfig(self.config_field.clone()),
+            &[
+                CpiAccount::writable(self.config),
+                CpiAccount::readonly_signer(self.config_authority),
+            ],
+            self.remaining_accounts,
+            signers_seeds,
+        )
+    }
+}
+
+/// Initializes stake account data for a SOL staker.
+///
+/// See [`StakeInstruction::InitializeSolStakerStake`].
+pub struct InitializeSolStakerStakeCpi<'a, 'info> {
+    /// Stake program.
+    pub program: &'a AccountInfo<'info>,
+    /// Stake config.
+    pub config: &'a AccountInfo<'info>,
+    /// DUNA document PDA account.
+    pub duna_document_pda: &'a AccountInfo<'info>,
+    /// Sol staker stake.
+    pub sol_staker_stake: &'a AccountInfo<'info>,
+    /// Sol staker authority override.
+    pub sol_staker_authority_override: &'a AccountInfo<'info>,
+    /// Validator stake.
+    pub validator_stake: &'a AccountInfo<'info>,
+    /// Sol staker native stake.
+    pub sol_staker_native_stake: &'a AccountInfo<'info>,
+    /// Sysvar stake history.
+    pub sysvar_stake_history: &'a AccountInfo<'info>,
+    /// System program.
+    pub system_program: &'a AccountInfo<'info>,
+    /// Paladin SOL Stake View program.
+    pub sol_stake_view_program: &'a AccountInfo<'info>,
+    /// Remaining accounts of the instruction.
+    pub remaining_accounts: &'a [AccountInfo<'info>],
+}
+
+impl<'a, 'info> InitializeSolStakerStakeCpi<'a, 'info> {
+    /// Invokes the instruction.
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    /// Invokes the instruction with the given PDA signer seeds.
+    pub fn invoke_signed(&self, signers_seeds: &[&[&[u8]]]) -> ProgramResult {
+        invoke_stake_program(
+            self.program,
+            StakeInstruction::InitializeSolStakerStake,
+            &[
+                CpiAccount::readonly(self.config),
+                CpiAccount::readonly(self.duna_document_pda),
+                CpiAccount::writable(self.sol_staker_stake),
+                CpiAccount::readonly(self.sol_staker_authority_override),
+                CpiAccount::writable(self.validator_stake),
+                CpiAccount::readonly(self.sol_staker_native_stake),
+                CpiAccount::readonly(self.sysvar_stake_history),
+                CpiAccount::readonly(self.system_program),
+                CpiAccount::readonly(self.sol_stake_view_program),
+            ],
+            self.remaining_accounts,
+            signers_seeds,
+        )
+    }
+}
+
+/// Stakes tokens with the given config.
+///
+/// See [`StakeInstruction::SolStakerStakeTokens`].
+pub struct SolStakerStakeTokensCpi<'a, 'info> {
+    /// Stake program.
+    pub program: &'a AccountInfo<'info>,
+    /// Stake config account.
+    pub config: &'a AccountInfo<'info>,
+    /// Holder rewards pool account.
+    pub holder_rewards_pool: &'a AccountInfo<'info>,
+    /// Holder rewards pool account token account.
+    pub holder_rewards_pool_token_account: &'a AccountInfo<'info>,
+    /// SOL staker stake account.
+    pub sol_staker_stake: &'a AccountInfo<'info>,
+    /// SOL staker stake authority account.
+    pub sol_staker_stake_authority: &'a AccountInfo<'info>,
+    /// Token account.
+    pub source_token_account: &'a AccountInfo<'info>,
+    /// Owner or delegate of the token account.
+    pub source_token_account_authority: &'a AccountInfo<'info>,
+    /// Stake Token Mint.
+    pub mint: &'a AccountInfo<'info>,
+    /// Stake vault pda.
+    pub vault_pda: &'a AccountInfo<'info>,
+    /// Stake token Vault.
+    pub vault: &'a AccountInfo<'info>,
+    /// Stake token Vault.
+    pub vault_holder_rewards: &'a AccountInfo<'info>,
+    /// Token program.
+    pub token_program: &'a AccountInfo<'info>,
+    /// Paladin rewards program.
+    pub rewards_program: &'a AccountInfo<'info>,
+    /// Remaining accounts of the instruction.
+    pub remaining_accounts: &'a [AccountInfo<'info>],
+    /// Amount of tokens.
+    pub amount: u64,
+}
+
+impl<'a, 'info> SolStakerStakeTokensCpi<'a, 'info> {
+    /// Invokes the instruction.
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    /// Invokes the instruction with the given PDA signer seeds.
+    pub fn invoke_signed(&self, signers_seeds: &[&[&[u8]]]) -> ProgramResult {
+        invoke_stake_program(
+            self.program,
+            StakeInstruction::SolStakerStakeTokens(self.amount),
+            &[
+                CpiAccount::writable(self.config),
+                CpiAccount::writable(self.holder_rewards_pool),
+                CpiAccount::writable(self.holder_rewards_pool_token_account),
+                CpiAccount::writable(self.sol_staker_stake),
+                CpiAccount::writable(self.sol_staker_stake_authority),
+                CpiAccount::writable(self.source_token_account),
+                CpiAccount::readonly_signer(self.source_token_account_authority),
+                CpiAccount::readonly(self.mint),
+                CpiAccount::writable(self.vault_pda),
+                CpiAccount::writable(self.vault),
+                CpiAccount::writable(self.vault_holder_rewards),
+                CpiAccount::readonly(self.token_program),
+                CpiAccount::readonly(self.rewards_program),
+            ],
+            self.remaining_accounts,
+            signers_seeds,
+        )
+    }
+}
+
+/// Harvests stake SOL rewards earned by the given sol staker stake account.
+///
+/// See [`StakeInstruction::HarvestSolStakerRewards`].
+pub struct HarvestSolStakerRewardsCpi<'a, 'info> {
+    /// Stake program.
+    pub program: &'a AccountInfo<'info>,
+    /// Sol stake view program.
+    pub sol_stake_view_program: &'a AccountInfo<'info>,
+    /// Stake config account.
+    pub config: &'a AccountInfo<'info>,
+    /// Holder rewards account.
+    pub vault_holder_rewards: &'a AccountInfo<'info>,
+    /// SOL staker stake account.
+    pub sol_staker_stake: &'a AccountInfo<'info>,
+    /// SOL staker stake authority.
+    pub sol_staker_stake_authority: &'a AccountInfo<'info>,
+    /// Native stake account.
+    pub sol_staker_native_stake: &'a AccountInfo<'info>,
+    /// Validator stake account.
+    pub previous_validator_stake: &'a AccountInfo<'info>,
+    /// Validator stake authority.
+    pub previous_validator_stake_authority: &'a AccountInfo<'info>,
+    /// Validator stake account.
+    pub current_validator_stake: &'a AccountInfo<'info>,
+    /// Validator stake authority.
+    pub current_validator_stake_authority: &'a AccountInfo<'info>,
+    /// Stake history sysvar.
+    pub sysvar_stake_history: &'a AccountInfo<'info>,
+    /// Recipient for sol sync bounty.
+    pub keeper_recipient: Option<&'a AccountInfo<'info>>,
+    /// Remaining accounts of the instruction.
+    pub remaining_accounts: &'a [AccountInfo<'info>],
+}
+
+impl<'a, 'info> HarvestSolStakerRewardsCpi<'a, 'info> {
+    /// Invokes the instruction.
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    /// Invokes the instruction with the given PDA signer seeds.
+    pub fn invoke_signed(&self, signers_seeds: &[&[&[u8]]]) -> ProgramResult {
+        invoke_stake_program(
+            self.program,
+            StakeInstruction::HarvestSolStakerRewards,
+            &[
+                CpiAccount::readonly(self.sol_stake_view_program),
+                CpiAccount::writable(self.config),
+                CpiAccount::readonly(self.vault_holder_rewards),
+                CpiAccount::writable(self.sol_staker_stake),
+                CpiAccount::writable(self.sol_staker_stake_authority),
+                CpiAccount::readonly(self.sol_staker_native_stake),
+                CpiAccount::writable(self.previous_validator_stake),
+                CpiAccount::writable(self.previous_validator_stake_authority),
+                CpiAccount::writable(self.current_validator_stake),
+                CpiAccount::writable(self.current_validator_stake_authority),
+                CpiAccount::readonly(self.sysvar_stake_history),
+                CpiAccount::optional(self.keeper_recipient, self.program, CpiAccount::writable),
+            ],
+            self.remaining_accounts,
+            signers_seeds,
+        )
+    }
+}
+
+/// Move tokens from deactivating to inactive.
+///
+/// See [`StakeInstruction::UnstakeTokens`].
+pub struct UnstakeTokensCpi<'a, 'info> {
+    /// Stake program.
+    pub program: &'a AccountInfo<'info>,
+    /// Stake config account.
+    pub config: &'a AccountInfo<'info>,
+    /// Holder rewards pool account.
+    pub holder_rewards_pool: &'a AccountInfo<'info>,
+    /// Holder rewards pool account token account.
+    pub holder_rewards_pool_token_account: &'a AccountInfo<'info>,
+    /// Sol staker/validator stake account.
+    pub stake: &'a AccountInfo<'info>,
+    /// Stake authority account.
+    pub stake_authority: &'a AccountInfo<'info>,
+    /// Vault account.
+    pub vault: &'a AccountInfo<'info>,
+    /// Vault authority.
+    pub vault_pda: &'a AccountInfo<'info>,
+    /// Vault holder rewards account.
+    pub vault_holder_rewards: &'a AccountInfo<'info>,
+    /// Mint account.
+    pub mint: &'a AccountInfo<'info>,
+    /// Destination token account.
+    pub destination_token_account: &'a AccountInfo<'info>,
+    /// Token program.
+    pub token_program: &'a AccountInfo<'info>,
+    /// Paladin rewards program.
+    pub rewards_program: &'a AccountInfo<'info>,
+    /// Remaining accounts of the instruction.
+    pub remaining_accounts: &'a [AccountInfo<'info>],
+    /// Amount of tokens.
+    pub amount: u64,
+}
+
+impl<'a, 'info> UnstakeTokensCpi<'a, 'info> {
+    /// Invokes the instruction.
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    /// Invokes the instruction with the given PDA signer seeds.
+    pub fn invoke_signed(&self, signers_seeds: &[&[&[u8]]]) -> ProgramResult {
+        invoke_stake_program(
+            self.program,
+            StakeInstruction::UnstakeTokens {
+                amount: self.amount,
+            },
+            &[
+                CpiAccount::writable(self.config),
+                CpiAccount::writable(self.holder_rewards_pool),
+                CpiAccount::writable(self.holder_rewards_pool_token_account),
+                CpiAccount::writable(self.stake),
+                CpiAccount::writable_signer(self.stake_authority),
+                CpiAccount::writable(self.vault),
+                CpiAccount::writable(self.vault_pda),
+                CpiAccount::writable(self.vault_holder_rewards),
+                CpiAccount::readonly(self.mint),
+                CpiAccount::writable(self.destination_token_account),
+                CpiAccount::readonly(self.token_program),
+                CpiAccount::readonly(self.rewards_program),
+            ],
+            self.remaining_accounts,
+            signers_seeds,
+        )
+    }
+}
+
+/// Slashes a validator stake account for the given amount.
+///
+/// See [`StakeInstruction::SlashSolStakerStake`].
+pub struct SlashSolStakerStakeCpi<'a, 'info> {
+    /// Stake program.
+    pub program: &'a AccountInfo<'info>,
+    /// Stake config account.
+    pub config: &'a AccountInfo<'info>,
+    /// SOL staker stake account.
+    pub sol_staker_stake: &'a AccountInfo<'info>,
+    /// SOL staker stake authority account.
+    pub sol_staker_stake_authority: &'a AccountInfo<'info>,
+    /// Config slash authority.
+    pub slash_authority: &'a AccountInfo<'info>,
+    /// Vault token mint.
+    pub mint: &'a AccountInfo<'info>,
+    /// Vault token account.
+    pub vault: &'a AccountInfo<'info>,
+    /// Vault holder rewards account.
+    pub vault_holder_rewards: &'a AccountInfo<'info>,
+    /// Vault authority.
+    pub vault_authority: &'a AccountInfo<'info>,
+    /// Token program.
+    pub token_program: &'a AccountInfo<'info>,
+    /// Remaining accounts of the instruction.
+    pub remaining_accounts: &'a [AccountInfo<'info>],
+    /// Amount of tokens.
+    pub amount: u64,
+}
+
+impl<'a, 'info> SlashSolStakerStakeCpi<'a, 'info> {
+    /// Invokes the instruction.
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    /// Invokes the instruction with the given PDA signer seeds.
+    pub fn invoke_signed(&self, signers_seeds: &[&[&[u8]]]) -> ProgramResult {
+        invoke_stake_program(
+            self.program,
+            StakeInstruction::SlashSolStakerStake(self.amount),
+            &[
+                CpiAccount::writable(self.config),
+                CpiAccount::writable(self.sol_staker_stake),
+                CpiAccount::writable(self.sol_staker_stake_authority),
+                CpiAccount::readonly_signer(self.slash_authority),
+                CpiAccount::writable(self.mint),
+                CpiAccount::writable(self.vault),
+                CpiAccount::readonly(self.vault_holder_rewards),
+                CpiAccount::readonly(self.vault_authority),
+                CpiAccount::readonly(self.token_program),
+            ],
+            self.remaining_accounts,
+            signers_seeds,
+        )
+    }
+}
+
+/// Moves staked PAL between two stake accounts controlled by the same
+/// authority.
+///
+/// See [`StakeInstruction::SolStakerMoveTokens`].
+pub struct SolStakerMoveTokensCpi<'a, 'info> {
+    /// Stake program.
+    pub program: &'a AccountInfo<'info>,
+    /// Staking config.
+    pub config: &'a AccountInfo<'info>,
+    /// Vault holder rewards.
+    pub vault_holder_rewards: &'a AccountInfo<'info>,
+    /// Sol staker authority.
+    pub sol_staker_authority: &'a AccountInfo<'info>,
+    /// Source sol staker stake.
+    pub source_sol_staker_stake: &'a AccountInfo<'info>,
+    /// Destination sol staker stake.
+    pub destination_sol_staker_stake: &'a AccountInfo<'info>,
+    /// Remaining accounts of the instruction.
+    pub remaining_accounts: &'a [AccountInfo<'info>],
+    /// Amount of tokens.
+    pub amount: u64,
+}
+
+impl<'a, 'info> SolStakerMoveTokensCpi<'a, 'info> {
+    /// Invokes the instruction.
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    /// Invokes the instruction with the given PDA signer seeds.
+    pub fn invoke_signed(&self, signers_seeds: &[&[&[u8]]]) -> ProgramResult {
+        invoke_stake_program(
+            self.program,
+            StakeInstruction::SolStakerMoveTokens {
+                amount: self.amount,
+            },
+            &[
+                CpiAccount::writable(self.config),
+                CpiAccount::readonly(self.vault_holder_rewards),
+                CpiAccount::readonly_signer(self.sol_staker_authority),
+                CpiAccount::writable(self.source_sol_staker_stake),
+                CpiAccount::writable(self.destination_sol_staker_stake),
+            ],
+            self.remaining_accounts,
+            signers_seeds,
+        )
+    }
+}
+
+///
+/// See [`StakeInstruction::ValidatorOverrideStakedLamports`].
+pub struct ValidatorOverrideStakedLamportsCpi<'a, 'info> {
+    /// Stake program.
+    pub program: &'a AccountInfo<'info>,
+    /// Config.
+    pub config: &'a AccountInfo<'info>,
+    /// Config authority.
+    pub config_authority: &'a AccountInfo<'info>,
+    /// Validator stake.
+    pub validator_stake: &'a AccountInfo<'info>,
+    /// Validator stake authority.
+    pub validator_stake_authority: &'a AccountInfo<'info>,
+    /// Vault holder rewards.
+    pub vault_holder_rewards: &'a AccountInfo<'info>,
+    /// Remaining accounts of the instruction.
+    pub remaining_accounts: &'a [AccountInfo<'info>],
+    /// Minimum amount of SOL staked to the validator.
+    pub amount_min: u64,
+}
+
+impl<'a, 'info> ValidatorOverrideStakedLamportsCpi<'a, 'info> {
+    /// Invokes the instruction.
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    /// Invokes the instruction with the given PDA signer seeds.
+    pub fn invoke_signed(&self, signers_seeds: &[&[&[u8]]]) -> ProgramResult {
+        invoke_stake_program(
+            self.program,
+            StakeInstruction::ValidatorOverrideStakedLamports {
+                amount_min: self.amount_min,
+            },
+            &[
+                CpiAccount::writable(self.config),
+                CpiAccount::readonly_signer(self.config_authority),
+                CpiAccount::writable(self.validator_stake),
+                CpiAccount::writable(self.validator_stake_authority),
+                CpiAccount::writable(self.vault_holder_rewards),
+            ],
+            self.remaining_accounts,
+            signers_seeds,
+        )
+    }
+}
+
+/// Syncs the authority of a validator stake account with the withdraw
+/// authority of the vote account, or with its authority override.
+///
+/// See [`StakeInstruction::ValidatorSyncAuthority`].
+pub struct ValidatorSyncAuthorityCpi<'a, 'info> {
+    /// Stake program.
+    pub program: &'a AccountInfo<'info>,
+    /// Config.
+    pub config: &'a AccountInfo<'info>,
+    /// Validator stake.
+    pub validator_stake: &'a AccountInfo<'info>,
+    /// Validator vote.
+    pub validator_vote: &'a AccountInfo<'info>,
+    /// Validator authority override.
+    pub validator_authority_override: &'a AccountInfo<'info>,
+    /// Remaining accounts of the instruction.
+    pub remaining_accounts: &'a [AccountInfo<'info>],
+}
+
+impl<'a, 'info> ValidatorSyncAuthorityCpi<'a, 'info> {
+    /// Invokes the instruction.
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    /// Invokes the instruction with the given PDA signer seeds.
+    pub fn invoke_signed(&self, signers_seeds: &[&[&[u8]]]) -> ProgramResult {
+        invoke_stake_program(
+            self.program,
+            StakeInstruction::ValidatorSyncAuthority,
+            &[
+                CpiAccount::writable(self.config),
+                CpiAccount::writable(self.validator_stake),
+                CpiAccount::writable(self.validator_vote),
+                CpiAccount::readonly(self.validator_authority_override),
+            ],
+            self.remaining_accounts,
+            signers_seeds,
+        )
+    }
+}
+
+/// Computes the rewards that a harvest would pay out for the given stake
+/// account, without modifying any account.
+///
+/// See [`StakeInstruction::GetPendingRewards`].
+pub struct GetPendingRewardsCpi<'a, 'info> {
+    /// Stake program.
+    pub program: &'a AccountInfo<'info>,
+    /// Stake config account.
+    pub config: &'a AccountInfo<'info>,
+    /// Vault holder rewards.
+    pub vault_holder_rewards: &'a AccountInfo<'info>,
+    /// Validator or sol staker stake account.
+    pub stake: &'a AccountInfo<'info>,
+    /// Remaining accounts of the instruction.
+    pub remaining_accounts: &'a [AccountInfo<'info>],
+}
+
+impl<'a, 'info> GetPendingRewardsCpi<'a, 'info> {
+    /// Invokes the instruction.
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    /// Invokes the instruction with the given PDA signer seeds.
+    pub fn invoke_signed(&self, signers_seeds: &[&[&[u8]]]) -> ProgramResult {
+        invoke_stake_program(
+            self.program,
+            StakeInstruction::GetPendingRewards,
+            &[
+                CpiAccount::readonly(self.config),
+                CpiAccount::readonly(self.vault_holder_rewards),
+                CpiAccount::readonly(self.stake),
+            ],
+            self.remaining_accounts,
+            signers_seeds,
+        )
+    }
+}
+
+/// Upgrades a config or stake account to the current layout version.
+///
+/// See [`StakeInstruction::MigrateAccount`].
+pub struct MigrateAccountCpi<'a, 'info> {
+    /// Stake program.
+    pub program: &'a AccountInfo<'info>,
+    /// Config or stake account to migrate.
+    pub account: &'a AccountInfo<'info>,
+    /// Payer of the additional rent.
+    pub payer: &'a AccountInfo<'info>,
+    /// System program.
+    pub system_program: &'a AccountInfo<'info>,
+    /// Remaining accounts of the instruction.
+    pub remaining_accounts: &'a [AccountInfo<'info>],
+}
+
+impl<'a, 'info> MigrateAccountCpi<'a, 'info> {
+    /// Invokes the instruction.
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    /// Invokes the instruction with the given PDA signer seeds.
+    pub fn invoke_signed(&self, signers_seeds: &[&[&[u8]]]) -> ProgramResult {
+        invoke_stake_program(
+            self.program,
+            StakeInstruction::MigrateAccount,
+            &[
+                CpiAccount::writable(self.account),
+                CpiAccount::writable_signer(self.payer),
+                CpiAccount::readonly(self.system_program),
+            ],
+            self.remaining_accounts,
+            signers_seeds,
+        )
+    }
+}
+
+/// Creates the stake config account at the PDA derived from the stake mint, the
+/// config authority and a namespace, so the config governing a mint can be
+/// looked up by clients.
+///
+/// See [`StakeInstruction::InitializeConfigPda`].
+pub struct InitializeConfigPdaCpi<'a, 'info> {
+    /// Stake program.
+    pub program: &'a AccountInfo<'info>,
+    /// Stake config account.
+    pub config: &'a AccountInfo<'info>,
+    /// Stake token mint.
+    pub mint: &'a AccountInfo<'info>,
+    /// Stake vault pda.
+    pub vault_pda: &'a AccountInfo<'info>,
+    /// Stake vault token account.
+    pub vault: &'a AccountInfo<'info>,
+    /// Stake vault holder rewards account.
+    pub vault_holder_rewards: &'a AccountInfo<'info>,
+    /// System program.
+    pub system_program: &'a AccountInfo<'info>,
+    /// Paladin rewards program.
+    pub rewards_program: &'a AccountInfo<'info>,
+    /// Authority that can modify the config.
+    pub config_authority: &'a AccountInfo<'info>,
+    /// Remaining accounts of the instruction.
+    pub remaining_accounts: &'a [AccountInfo<'info>],
+    /// Namespace of the config PDA.
+    pub namespace: u64,
+    /// Authority that can slash any stake.
+    pub slash_authority: Pubkey,
+    /// Seconds between deactivation and inactivation.
+    pub cooldown_time_seconds: u64,
+    /// Proportion that can be deactivated at once, in basis points.
+    pub max_deactivation_basis_points: u16,
+    /// Lamports paid for syncing a SOL stake account.
+    pub sync_rewards_lamports: u64,
+    /// Hash of the DUNA document.
+    pub duna_document_hash: [u8; 32],
+}
+
+impl<'a, 'info> InitializeConfigPdaCpi<'a, 'info> {
+    /// Invokes the instruction.
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    /// Invokes the instruction with the given PDA signer seeds.
+    pub fn invoke_signed(&self, signers_seeds: &[&[&[u8]]]) -> ProgramResult {
+        invoke_stake_program(
+            self.program,
+            StakeInstruction::InitializeConfigPda {
+                namespace: self.namespace,
+                slash_authority: self.slash_authority,
+                cooldown_time_seconds: self.cooldown_time_seconds,
+                max_deactivation_basis_points: self.max_deactivation_basis_points,
+                sync_rewards_lamports: self.sync_rewards_lamports,
+                duna_document_hash: self.duna_document_hash,
+            },
+            &[
+                CpiAccount::writable(self.config),
+                CpiAccount::readonly(self.mint),
+                CpiAccount::writable(self.vault_pda),
+                CpiAccount::readonly(self.vault),
+                CpiAccount::writable(self.vault_holder_rewards),
+                CpiAccount::readonly(self.system_program),
+                CpiAccount::readonly(self.rewards_program),
+                CpiAccount::readonly_signer(self.config_authority),
+            ],
+            self.remaining_accounts,
+            signers_seeds,
+        )
+    }
+}
+
+/// Returns the voting power of a stake account or a vote delegate at the end of
+/// the given slot.
+///
+/// See [`StakeInstruction::GetVotingPower`].
+pub struct GetVotingPowerCpi<'a, 'info> {
+    /// Stake program.
+    pub program: &'a AccountInfo<'info>,
+    /// Stake config account.
+    pub config: &'a AccountInfo<'info>,
+    /// Validator or sol staker stake, or delegated voting power account.
+    pub stake: &'a AccountInfo<'info>,
+    /// Remaining accounts of the instruction.
+    pub remaining_accounts: &'a [AccountInfo<'info>],
+    /// Slot to get the voting power at.
+    pub slot: u64,
+}
+
+impl<'a, 'info> GetVotingPowerCpi<'a, 'info> {
+    /// Invokes the instruction.
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    /// Invokes the instruction with the given PDA signer seeds.
+    pub fn invoke_signed(&self, signers_seeds: &[&[&[u8]]]) -> ProgramResult {
+        invoke_stake_program(
+            self.program,
+            StakeInstruction::GetVotingPower { slot: self.slot },
+            &[
+                CpiAccount::readonly(self.config),
+                CpiAccount::readonly(self.stake),
+            ],
+            self.remaining_accounts,
+            signers_seeds,
+        )
+    }
+}
+
+/// Initializes the account aggregating the voting power delegated to a vote
+/// delegate.
+///
+/// See [`StakeInstruction::InitializeDelegatedVotingPower`].
+pub struct InitializeDelegatedVotingPowerCpi<'a, 'info> {
+    /// Stake program.
+    pub program: &'a AccountInfo<'info>,
+    /// Stake config account.
+    pub config: &'a AccountInfo<'info>,
+    /// Vote delegate.
+    pub vote_delegate: &'a AccountInfo<'info>,
+    /// Delegated voting power account.
+    pub delegated_voting_power: &'a AccountInfo<'info>,
+    /// System program.
+    pub system_program: &'a AccountInfo<'info>,
+    /// Remaining accounts of the instruction.
+    pub remaining_accounts: &'a [AccountInfo<'info>],
+}
+
+impl<'a, 'info> InitializeDelegatedVotingPowerCpi<'a, 'info> {
+    /// Invokes the instruction.
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    /// Invokes the instruction with the given PDA signer seeds.
+    pub fn invoke_signed(&self, signers_seeds: &[&[&[u8]]]) -> ProgramResult {
+        invoke_stake_program(
+            self.program,
+            StakeInstruction::InitializeDelegatedVotingPower,
+            &[
+                CpiAccount::readonly(self.config),
+                CpiAccount::readonly(self.vote_delegate),
+                CpiAccount::writable(self.delegated_voting_power),
+                CpiAccount::readonly(self.system_program),
+            ],
+            self.remaining_accounts,
+            signers_seeds,
+        )
+    }
+}
+
+/// Sets the vote delegate of a stake account, or clears it when no delegate is
+/// provided.
+///
+/// See [`StakeInstruction::SetVoteDelegate`].
+pub struct SetVoteDelegateCpi<'a, 'info> {
+    /// Stake program.
+    pub program: &'a AccountInfo<'info>,
+    /// Stake config account.
+    pub config: &'a AccountInfo<'info>,
+    /// Validator or sol staker stake account.
+    pub stake: &'a AccountInfo<'info>,
+    /// Stake authority.
+    pub stake_authority: &'a AccountInfo<'info>,
+    /// Vote delegate (clears the delegate when omitted).
+    pub vote_delegate: Option<&'a AccountInfo<'info>>,
+    /// Remaining accounts of the instruction.
+    pub remaining_accounts: &'a [AccountInfo<'info>],
+}
+
+impl<'a, 'info> SetVoteDelegateCpi<'a, 'info> {
+    /// Invokes the instruction.
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    /// Invokes the instruction with the given PDA signer seeds.
+    pub fn invoke_signed(&self, signers_seeds: &[&[&[u8]]]) -> ProgramResult {
+        invoke_stake_program(
+            self.program,
+            StakeInstruction::SetVoteDelegate,
+            &[
+                CpiAccount::readonly(self.config),
+                CpiAccount::writable(self.stake),
+                CpiAccount::readonly_signer(self.stake_authority),
+                CpiAccount::optional(
+                    self.vote_delegate,
+                    self.program,
+                    CpiAccount::readonly_signer,
+                ),
+            ],
+            self.remaining_accounts,
+            signers_seeds,
+        )
+    }
+}
+
+/// Sets the authority used for the validator stake of a vote account, instead
+/// of the withdraw authority of the vote account.
+///
+/// See [`StakeInstruction::SetValidatorAuthorityOverride`].
+pub struct SetValidatorAuthorityOverrideCpi<'a, 'info> {
+    /// Stake program.
+    pub program: &'a AccountInfo<'info>,
+    /// Stake config account.
+    pub config: &'a AccountInfo<'info>,
+    /// Validator vote account.
+    pub validator_vote: &'a AccountInfo<'info>,
+    /// Validator vote account withdraw authority.
+    pub withdraw_authority: &'a AccountInfo<'info>,
+    /// Validator authority override.
+    pub validator_authority_override: &'a AccountInfo<'info>,
+    /// Authority to use for the validator stake accounts.
+    pub new_authority: &'a AccountInfo<'info>,
+    /// System program.
+    pub system_program: &'a AccountInfo<'info>,
+    /// Remaining accounts of the instruction.
+    pub remaining_accounts: &'a [AccountInfo<'info>],
+}
+
+impl<'a, 'info> SetValidatorAuthorityOverrideCpi<'a, 'info> {
+    /// Invokes the instruction.
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    /// Invokes the instruction with the given PDA signer seeds.
+    pub fn invoke_signed(&self, signers_seeds: &[&[&[u8]]]) -> ProgramResult {
+        invoke_stake_program(
+            self.program,
+            StakeInstruction::SetValidatorAuthorityOverride,
+            &[
+                CpiAccount::readonly(self.config),
+                CpiAccount::readonly(self.validator_vote),
+                CpiAccount::readonly_signer(self.withdraw_authority),
+                CpiAccount::writable(self.validator_authority_override),
+                CpiAccount::readonly(self.new_authority),
+                CpiAccount::readonly(self.system_program),
+            ],
+            self.remaining_accounts,
+            signers_seeds,
+        )
+    }
+}
+
+/// Transfers the stake rewards released while no tokens were effective to the
+/// destination account.
+///
+/// See [`StakeInstruction::SweepUndistributedRewards`].
+pub struct SweepUndistributedRewardsCpi<'a, 'info> {
+    /// Stake program.
+    pub program: &'a AccountInfo<'info>,
+    /// Stake config account.
+    pub config: &'a AccountInfo<'info>,
+    /// Stake config authority.
+    pub config_authority: &'a AccountInfo<'info>,
+    /// Destination account for the undistributed rewards.
+    pub destination: &'a AccountInfo<'info>,
+    /// Remaining accounts of the instruction.
+    pub remaining_accounts: &'a [AccountInfo<'info>],
+}
+
+impl<'a, 'info> SweepUndistributedRewardsCpi<'a, 'info> {
+    /// Invokes the instruction.
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    /// Invokes the instruction with the given PDA signer seeds.
+    pub fn invoke_signed(&self, signers_seeds: &[&[&[u8]]]) -> ProgramResult {
+        invoke_stake_program(
+            self.program,
+            StakeInstruction::SweepUndistributedRewards,
+            &[
+                CpiAccount::writable(self.config),
+                CpiAccount::readonly_signer(self.config_authority),
+                CpiAccount::writable(self.destination),
+            ],
+            self.remaining_accounts,
+            signers_seeds,
+        )
+    }
+}
+
+/// Initializes the liquid stake pool of a validator stake account.
+///
+/// See [`StakeInstruction::InitializeLiquidStakePool`].
+pub struct InitializeLiquidStakePoolCpi<'a, 'info> {
+    /// Stake program.
+    pub program: &'a AccountInfo<'info>,
+    /// Stake config account.
+    pub config: &'a AccountInfo<'info>,
+    /// Pooled validator stake account.
+    pub validator_stake: &'a AccountInfo<'info>,
+    /// Liquid stake pool account.
+    pub liquid_stake_pool: &'a AccountInfo<'info>,
+    /// Vault token account.
+    pub vault: &'a AccountInfo<'info>,
+    /// Stake token mint.
+    pub mint: &'a AccountInfo<'info>,
+    /// Receipt token mint.
+    pub receipt_mint: &'a AccountInfo<'info>,
+    /// Receipt token program.
+    pub receipt_token_program: &'a AccountInfo<'info>,
+    /// System program.
+    pub system_program: &'a AccountInfo<'info>,
+    /// Remaining accounts of the instruction.
+    pub remaining_accounts: &'a [AccountInfo<'info>],
+}
+
+impl<'a, 'info> InitializeLiquidStakePoolCpi<'a, 'info> {
+    /// Invokes the instruction.
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    /// Invokes the instruction with the given PDA signer seeds.
+    pub fn invoke_signed(&self, signers_seeds: &[&[&[u8]]]) -> ProgramResult {
+        invoke_stake_program(
+            self.program,
+            StakeInstruction::InitializeLiquidStakePool,
+            &[
+                CpiAccount::readonly(self.config),
+                CpiAccount::readonly(self.validator_stake),
+                CpiAccount::writable(self.liquid_stake_pool),
+                CpiAccount::readonly(self.vault),
+                CpiAccount::readonly(self.mint),
+                CpiAccount::readonly(self.receipt_mint),
+                CpiAccount::readonly(self.receipt_token_program),
+                CpiAccount::readonly(self.system_program),
+            ],
+            self.remaining_accounts,
+            signers_seeds,
+        )
+    }
+}
+
+/// Initializes the rewards tracking account of a receipt token account of a
+/// liquid stake pool.
+///
+/// See [`StakeInstruction::InitializeReceiptRewards`].
+pub struct InitializeReceiptRewardsCpi<'a, 'info> {
+    /// Stake program.
+    pub program: &'a AccountInfo<'info>,
+    /// Liquid stake pool account.
+    pub liquid_stake_pool: &'a AccountInfo<'info>,
+    /// Receipt token mint.
+    pub receipt_mint: &'a AccountInfo<'info>,
+    /// Receipt token account.
+    pub receipt_token_account: &'a AccountInfo<'info>,
+    /// Receipt rewards account.
+    pub receipt_rewards: &'a AccountInfo<'info>,
+    /// System program.
+    pub system_program: &'a AccountInfo<'info>,
+    /// Remaining accounts of the instruction.
+    pub remaining_accounts: &'a [AccountInfo<'info>],
+}
+
+impl<'a, 'info> InitializeReceiptRewardsCpi<'a, 'info> {
+    /// Invokes the instruction.
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    /// Invokes the instruction with the given PDA signer seeds.
+    pub fn invoke_signed(&self, signers_seeds: &[&[&[u8]]]) -> ProgramResult {
+        invoke_stake_program(
+            self.program,
+            StakeInstruction::InitializeReceiptRewards,
+            &[
+                CpiAccount::writable(self.liquid_stake_pool),
+                CpiAccount::readonly(self.receipt_mint),
+                CpiAccount::readonly(self.receipt_token_account),
+                CpiAccount::writable(self.receipt_rewards),
+                CpiAccount::readonly(self.system_program),
+            ],
+            self.remaining_accounts,
+            signers_seeds,
+        )
+    }
+}
+
+/// Stakes tokens into a liquid stake pool, minting receipt tokens.
+///
+/// See [`StakeInstruction::LiquidStakeTokens`].
+pub struct LiquidStakeTokensCpi<'a, 'info> {
+    /// Stake program.
+    pub program: &'a AccountInfo<'info>,
+    /// Stake config account.
+    pub config: &'a AccountInfo<'info>,
+    /// Holder rewards pool account.
+    pub holder_rewards_pool: &'a AccountInfo<'info>,
+    /// Holder rewards pool account token account.
+    pub holder_rewards_pool_token_account: &'a AccountInfo<'info>,
+    /// Pooled validator stake account.
+    pub validator_stake: &'a AccountInfo<'info>,
+    /// Liquid stake pool account.
+    pub liquid_stake_pool: &'a AccountInfo<'info>,
+    /// Token account.
+    pub source_token_account: &'a AccountInfo<'info>,
+    /// Owner or delegate of the token account.
+    pub source_token_account_authority: &'a AccountInfo<'info>,
+    /// Stake Token Mint.
+    pub mint: &'a AccountInfo<'info>,
+    /// Vault authority.
+    pub vault_pda: &'a AccountInfo<'info>,
+    /// Stake token Vault.
+    pub vault: &'a AccountInfo<'info>,
+    /// Vault holder rewards account.
+    pub vault_holder_rewards: &'a AccountInfo<'info>,
+    /// Token program.
+    pub token_program: &'a AccountInfo<'info>,
+    /// Paladin rewards program.
+    pub rewards_program: &'a AccountInfo<'info>,
+    /// Receipt token mint.
+    pub receipt_mint: &'a AccountInfo<'info>,
+    /// Destination receipt token account.
+    pub destination_receipt_account: &'a AccountInfo<'info>,
+    /// Receipt rewards account of the destination.
+    pub destination_receipt_rewards: &'a AccountInfo<'info>,
+    /// Receipt token program.
+    pub receipt_token_program: &'a AccountInfo<'info>,
+    /// Stake program.
+    pub stake_program: &'a AccountInfo<'info>,
+    /// Remaining accounts of the instruction.
+    pub remaining_accounts: &'a [AccountInfo<'info>],
+    /// Amount of tokens.
+    pub amount: u64,
+}
+
+impl<'a, 'info> LiquidStakeTokensCpi<'a, 'info> {
+    /// Invokes the instruction.
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    /// Invokes the instruction with the given PDA signer seeds.
+    pub fn invoke_signed(&self, signers_seeds: &[&[&[u8]]]) -> ProgramResult {
+        invoke_stake_program(
+            self.program,
+            StakeInstruction::LiquidStakeTokens {
+                amount: self.amount,
+            },
+            &[
+                CpiAccount::writable(self.config),
+                CpiAccount::writable(self.holder_rewards_pool),
+                CpiAccount::writable(self.holder_rewards_pool_token_account),
+                CpiAccount::writable(self.validator_stake),
+                CpiAccount::writable(self.liquid_stake_pool),
+                CpiAccount::writable(self.source_token_account),
+                CpiAccount::readonly_signer(self.source_token_account_authority),
+                CpiAccount::readonly(self.mint),
+                CpiAccount::writable(self.vault_pda),
+                CpiAccount::writable(self.vault),
+                CpiAccount::writable(self.vault_holder_rewards),
+                CpiAccount::readonly(self.token_program),
+                CpiAccount::readonly(self.rewards_program),
+                CpiAccount::writable(self.receipt_mint),
+                CpiAccount::writable(self.destination_receipt_account),
+                CpiAccount::writable(self.destination_receipt_rewards),
+                CpiAccount::readonly(self.receipt_token_program),
+                CpiAccount::readonly(self.stake_program),
+            ],
+            self.remaining_accounts,
+            signers_seeds,
+        )
+    }
+}
+
+/// Burns receipt tokens of a liquid stake pool, unstaking the corresponding
+/// amount of tokens to the destination token account.
+///
+/// See [`StakeInstruction::LiquidUnstakeTokens`].
+pub struct LiquidUnstakeTokensCpi<'a, 'info> {
+    /// Stake program.
+    pub program: &'a AccountInfo<'info>,
+    /// Stake config account.
+    pub config: &'a AccountInfo<'info>,
+    /// Holder rewards pool account.
+    pub holder_rewards_pool: &'a AccountInfo<'info>,
+    /// Holder rewards pool account token account.
+    pub holder_rewards_pool_token_account: &'a AccountInfo<'info>,
+    /// Pooled validator stake account.
+    pub validator_stake: &'a AccountInfo<'info>,
+    /// Liquid stake pool account.
+    pub liquid_stake_pool: &'a AccountInfo<'info>,
+    /// Vault account.
+    pub vault: &'a AccountInfo<'info>,
+    /// Vault authority.
+    pub vault_pda: &'a AccountInfo<'info>,
+    /// Vault holder rewards account.
+    pub vault_holder_rewards: &'a AccountInfo<'info>,
+    /// Mint account.
+    pub mint: &'a AccountInfo<'info>,
+    /// Destination token account.
+    pub destination_token_account: &'a AccountInfo<'info>,
+    /// Token program.
+    pub token_program: &'a AccountInfo<'info>,
+    /// Paladin rewards program.
+    pub rewards_program: &'a AccountInfo<'info>,
+    /// Receipt token mint.
+    pub receipt_mint: &'a AccountInfo<'info>,
+    /// Source receipt token account.
+    pub source_receipt_account: &'a AccountInfo<'info>,
+    /// Receipt rewards account of the source.
+    pub source_receipt_rewards: &'a AccountInfo<'info>,
+    /// Owner or delegate of the source receipt token account.
+    pub source_receipt_authority: &'a AccountInfo<'info>,
+    /// Receipt token program.
+    pub receipt_token_program: &'a AccountInfo<'info>,
+    /// Stake program.
+    pub stake_program: &'a AccountInfo<'info>,
+    /// Remaining accounts of the instruction.
+    pub remaining_accounts: &'a [AccountInfo<'info>],
+    /// Amount of tokens.
+    pub amount: u64,
+}
+
+impl<'a, 'info> LiquidUnstakeTokensCpi<'a, 'info> {
+    /// Invokes the instruction.
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    /// Invokes the instruction with the given PDA signer seeds.
+    pub fn invoke_signed(&self, signers_seeds: &[&[&[u8]]]) -> ProgramResult {
+        invoke_stake_program(
+            self.program,
+            StakeInstruction::LiquidUnstakeTokens {
+                amount: self.amount,
+            },
+            &[
+                CpiAccount::writable(self.config),
+                CpiAccount::writable(self.holder_rewards_pool),
+                CpiAccount::writable(self.holder_rewards_pool_token_account),
+                CpiAccount::writable(self.validator_stake),
+                CpiAccount::writable(self.liquid_stake_pool),
+                CpiAccount::writable(self.vault),
+                CpiAccount::writable(self.vault_pda),
+                CpiAccount::writable(self.vault_holder_rewards),
+                CpiAccount::readonly(self.mint),
+                CpiAccount::writable(self.destination_token_account),
+                CpiAccount::readonly(self.token_program),
+                CpiAccount::readonly(self.rewards_program),
+                CpiAccount::writable(self.receipt_mint),
+                CpiAccount::writable(self.source_receipt_account),
+                CpiAccount::writable(self.source_receipt_rewards),
+                CpiAccount::readonly_signer(self.source_receipt_authority),
+                CpiAccount::readonly(self.receipt_token_program),
+                CpiAccount::readonly(self.stake_program),
+            ],
+            self.remaining_accounts,
+            signers_seeds,
+        )
+    }
+}
+
+/// Harvests the liquid stake pool rewards of a receipt token account.
+///
+/// See [`StakeInstruction::HarvestReceiptRewards`].
+pub struct HarvestReceiptRewardsCpi<'a, 'info> {
+    /// Stake program.
+    pub program: &'a AccountInfo<'info>,
+    /// Liquid stake pool account.
+    pub liquid_stake_pool: &'a AccountInfo<'info>,
+    /// Receipt token mint.
+    pub receipt_mint: &'a AccountInfo<'info>,
+    /// Receipt token account.
+    pub receipt_token_account: &'a AccountInfo<'info>,
+    /// Receipt rewards account.
+    pub receipt_rewards: &'a AccountInfo<'info>,
+    /// Owner of the receipt token account.
+    pub receipt_token_account_owner: &'a AccountInfo<'info>,
+    /// Remaining accounts of the instruction.
+    pub remaining_accounts: &'a [AccountInfo<'info>],
+}
+
+impl<'a, 'info> HarvestReceiptRewardsCpi<'a, 'info> {
+    /// Invokes the instruction.
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    /// Invokes the instruction with the given PDA signer seeds.
+    pub fn invoke_signed(&self, signers_seeds: &[&[&[u8]]]) -> ProgramResult {
+        invoke_stake_program(
+            self.program,
+            StakeInstruction::HarvestReceiptRewards,
+            &[
+                CpiAccount::writable(self.liquid_stake_pool),
+                CpiAccount::readonly(self.receipt_mint),
+                CpiAccount::readonly(self.receipt_token_account),
+                CpiAccount::writable(self.receipt_rewards),
+                CpiAccount::writable(self.receipt_token_account_owner),
+            ],
+            self.remaining_accounts,
+            signers_seeds,
+        )
+    }
+}
+
+/// Transfers staked PAL between the SOL staker stake accounts of two different
+/// authorities, without going through the unstake cooldown.
+///
+/// See [`StakeInstruction::TransferStake`].
+pub struct TransferStakeCpi<'a, 'info> {
+    /// Stake program.
+    pub program: &'a AccountInfo<'info>,
+    /// Stake config account.
+    pub config: &'a AccountInfo<'info>,
+    /// Vault holder rewards account.
+    pub vault_holder_rewards: &'a AccountInfo<'info>,
+    /// Source SOL staker stake account.
+    pub source_sol_staker_stake: &'a AccountInfo<'info>,
+    /// Source SOL staker stake authority.
+    pub source_authority: &'a AccountInfo<'info>,
+    /// Destination SOL staker stake account.
+    pub destination_sol_staker_stake: &'a AccountInfo<'info>,
+    /// Destination SOL staker stake authority.
+    pub destination_authority: &'a AccountInfo<'info>,
+    /// Remaining accounts of the instruction.
+    pub remaining_accounts: &'a [AccountInfo<'info>],
+    /// Amount of tokens.
+    pub amount: u64,
+}
+
+impl<'a, 'info> TransferStakeCpi<'a, 'info> {
+    /// Invokes the instruction.
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    /// Invokes the instruction with the given PDA signer seeds.
+    pub fn invoke_signed(&self, signers_seeds: &[&[&[u8]]]) -> ProgramResult {
+        invoke_stake_program(
+            self.program,
+            StakeInstruction::TransferStake {
+                amount: self.amount,
+            },
+            &[
+                CpiAccount::writable(self.config),
+                CpiAccount::readonly(self.vault_holder_rewards),
+                CpiAccount::writable(self.source_sol_staker_stake),
+                CpiAccount::writable_signer(self.source_authority),
+                CpiAccount::writable(self.destination_sol_staker_stake),
+                CpiAccount::writable_signer(self.destination_authority),
+            ],
+            self.remaining_accounts,
+            signers_seeds,
+        )
+    }
+}
+
+/// Transfers the unclaimed protocol fees to the fee recipient.
+///
+/// See [`StakeInstruction::WithdrawProtocolFees`].
+pub struct WithdrawProtocolFeesCpi<'a, 'info> {
+    /// Stake program.
+    pub program: &'a AccountInfo<'info>,
+    /// Stake config account.
+    pub config: &'a AccountInfo<'info>,
+    /// Stake config authority.
+    pub config_authority: &'a AccountInfo<'info>,
+    /// Fee recipient set on the config.
+    pub fee_recipient: &'a AccountInfo<'info>,
+    /// Remaining accounts of the instruction.
+    pub remaining_accounts: &'a [AccountInfo<'info>],
+}
+
+impl<'a, 'info> WithdrawProtocolFeesCpi<'a, 'info> {
+    /// Invokes the instruction.
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    /// Invokes the instruction with the given PDA signer seeds.
+    pub fn invoke_signed(&self, signers_seeds: &[&[&[u8]]]) -> ProgramResult {
+        invoke_stake_program(
+            self.program,
+            StakeInstruction::WithdrawProtocolFees,
+            &[
+                CpiAccount::writable(self.config),
+                CpiAccount::readonly_signer(self.config_authority),
+                CpiAccount::writable(self.fee_recipient),
+            ],
+            self.remaining_accounts,
+            signers_seeds,
+        )
+    }
+}
+
+/// Checks the accounting invariants of a config against its vault and the
+/// stake accounts provided as remaining accounts.
+///
+/// See [`StakeInstruction::AuditInvariants`].
+pub struct AuditInvariantsCpi<'a, 'info> {
+    /// Stake program.
+    pub program: &'a AccountInfo<'info>,
+    /// Stake config account.
+    pub config: &'a AccountInfo<'info>,
+    /// Vault token account.
+    pub vault: &'a AccountInfo<'info>,
+    /// Vault holder rewards.
+    pub vault_holder_rewards: &'a AccountInfo<'info>,
+    /// Validator or sol staker stake accounts to audit.
+    pub remaining_accounts: &'a [AccountInfo<'info>],
+}
+
+impl<'a, 'info> AuditInvariantsCpi<'a, 'info> {
+    /// Invokes the instruction.
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    /// Invokes the instruction with the given PDA signer seeds.
+    pub fn invoke_signed(&self, signers_seeds: &[&[&[u8]]]) -> ProgramResult {
+        invoke_stake_program(
+            self.program,
+            StakeInstruction::AuditInvariants,
+            &[
+                CpiAccount::readonly(self.config),
+                CpiAccount::readonly(self.vault),
+                CpiAccount::readonly(self.vault_holder_rewards),
+            ],
+            self.remaining_accounts,
+            signers_seeds,
+        )
+    }
+}
@@ -114,6 +114,22 @@ pub enum StakeError {
     /// 25 - Invalid vault holder rewards seeds
     #[error("Invalid vault holder rewards seeds")]
     InvalidVaultHolderRewardsSeeds,
+
+    /// 26 - Account must be migrated to the current layout
+    #[error("Account must be migrated to the current layout")]
+    AccountMigrationRequired,
+
+    /// 27 - Invalid mint extension
+    #[error("Invalid mint extension")]
+    InvalidMintExtension,
+
+    /// 28 - Voting power history is not available for the slot
+    #[error("Voting power history is not available for the slot")]
+    VotingPowerUnavailable,
+
+    /// 29 - Missing delegated voting power account
+    #[error("Missing delegated voting power account")]
+    MissingDelegatedVotingPower,
 }
 
 impl PrintProgramError for StakeError {
@@ -0,0 +1,250 @@
+//! Structured events emitted by the stake program.
+//!
+//! Every event is written with `sol_log_data` as two fields: the 8-byte event
+//! discriminator followed by the `Pod` bytes of the event. Discriminators are
+//! derived from a versioned hash input (`stake::event::<name>::v<n>`), so any
+//! change to the layout of an event must bump its version suffix.
+
+use bytemuck::{Pod, Zeroable};
+use solana_program::{log::sol_log_data, pubkey::Pubkey};
+use spl_discriminator::SplDiscriminate;
+
+/// Emits an event to the program logs.
+pub trait Event: Pod + SplDiscriminate {
+    #[inline(always)]
+    fn emit(&self) {
+        sol_log_data(&[Self::SPL_DISCRIMINATOR_SLICE, bytemuck::bytes_of(self)]);
+    }
+}
+
+/// Tokens were staked into a stake account.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Pod, SplDiscriminate, Zeroable)]
+#[discriminator_hash_input("stake::event::stake_tokens::v1")]
+pub struct StakeTokensEvent {
+    pub config: Pubkey,
+    pub stake: Pubkey,
+    pub authority: Pubkey,
+    /// Amount of tokens staked by the instruction.
+    pub amount: u64,
+    /// Staked amount of the stake account after the instruction.
+    pub staked_amount: u64,
+    /// Effective amount of the stake account after the instruction.
+    pub effective_amount: u64,
+}
+
+/// Tokens were unstaked from a stake account.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Pod, SplDiscriminate, Zeroable)]
+#[discriminator_hash_input("stake::event::unstake_tokens::v1")]
+pub struct UnstakeTokensEvent {
+    pub config: Pubkey,
+    pub stake: Pubkey,
+    pub authority: Pubkey,
+    /// Amount of tokens unstaked by the instruction.
+    pub amount: u64,
+    /// Staked amount of the stake account after the instruction.
+    pub staked_amount: u64,
+    /// Effective amount of the stake account after the instruction.
+    pub effective_amount: u64,
+    /// Timestamp at which the stake account can next unstake.
+    pub unstake_cooldown: u64,
+}
+
+/// Rewards were harvested for a stake account.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Pod, SplDiscriminate, Zeroable)]
+#[discriminator_hash_input("stake::event::harvest::v1")]
+pub struct HarvestEvent {
+    pub config: Pubkey,
+    pub stake: Pubkey,
+    pub authority: Pubkey,
+    /// Lamports earned from staking rewards (net of the protocol fee).
+    pub staking_reward: u64,
+    /// Lamports earned from holder rewards.
+    pub holder_reward: u64,
+    /// Lamports of the total reward paid to the keeper (if any).
+    pub keeper_reward: u64,
+}
+
+/// A stake account was slashed.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Pod, SplDiscriminate, Zeroable)]
+#[discriminator_hash_input("stake::event::slash::v1")]
+pub struct SlashEvent {
+    pub config: Pubkey,
+    pub stake: Pubkey,
+    /// Amount requested to be slashed.
+    pub amount: u64,
+    /// Amount actually slashed (capped at the staked amount).
+    pub slashed_amount: u64,
+    /// Staked amount of the stake account after the slash.
+    pub staked_amount: u64,
+    /// Effective amount of the stake account after the slash.
+    pub effective_amount: u64,
+}
+
+/// Tokens were moved between two SOL staker stake accounts.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Pod, SplDiscriminate, Zeroable)]
+#[discriminator_hash_input("stake::event::move_tokens::v1")]
+pub struct MoveTokensEvent {
+    pub config: Pubkey,
+    pub source: Pubkey,
+    pub destination: Pubkey,
+    pub authority: Pubkey,
+    pub amount: u64,
+}
+
+/// A config field was updated.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Pod, SplDiscriminate, Zeroable)]
+#[discriminator_hash_input("stake::event::update_config::v1")]
+pub struct UpdateConfigEvent {
+    pub config: Pubkey,
+    /// Discriminant of the updated `ConfigField`.
+    pub field: u8,
+    pub _padding: [u8; 7],
+    /// New value of the field, widened to `u64`.
+    pub value: u64,
+}
+
+/// Kind of authority changed by an `AuthorityChangeEvent`.
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum AuthorityKind {
+    Config = 0,
+    Slash = 1,
+    Stake = 2,
+    FeeRecipient = 3,
+    VoteDelegate = 4,
+    ValidatorOverride = 5,
+}
+
+/// An authority was changed.
+///
+/// Vote delegates are reported on the stake account and validator authority
+/// overrides on the `ValidatorAuthorityOverride` account.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Pod, SplDiscriminate, Zeroable)]
+#[discriminator_hash_input("stake::event::authority_change::v1")]
+pub struct AuthorityChangeEvent {
+    /// Account holding the authority (config or stake account).
+    pub account: Pubkey,
+    /// Previous authority (`Pubkey::default()` when unset).
+    pub previous_authority: Pubkey,
+    /// New authority (`Pubkey::default()` when unset).
+    pub new_authority: Pubkey,
+    /// Discriminant of the `AuthorityKind`.
+    pub kind: u8,
+    pub _padding: [u8; 7],
+}
+
+/// The SOL stake lamports tracked by a SOL staker stake account were synced.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Pod, SplDiscriminate, Zeroable)]
+#[discriminator_hash_input("stake::event::lamports_sync::v1")]
+pub struct LamportsSyncEvent {
+    pub config: Pubkey,
+    pub stake: Pubkey,
+    pub previous_validator_vote: Pubkey,
+    pub current_validator_vote: Pubkey,
+    pub previous_lamports: u64,
+    pub current_lamports: u64,
+}
+
+/// Undistributed stake rewards were swept from the config account.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Pod, SplDiscriminate, Zeroable)]
+#[discriminator_hash_input("stake::event::sweep_undistributed_rewards::v1")]
+pub struct SweepUndistributedRewardsEvent {
+    pub config: Pubkey,
+    pub destination: Pubkey,
+    /// Lamports transferred to the destination.
+    pub amount: u64,
+}
+
+/// Staked tokens were transferred between stake accounts of different
+/// authorities.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Pod, SplDiscriminate, Zeroable)]
+#[discriminator_hash_input("stake::event::transfer_stake::v1")]
+pub struct TransferStakeEvent {
+    pub config: Pubkey,
+    pub source: Pubkey,
+    pub destination: Pubkey,
+    pub source_authority: Pubkey,
+    pub destination_authority: Pubkey,
+    pub amount: u64,
+}
+
+/// Unclaimed protocol fees were withdrawn from the config account.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Pod, SplDiscriminate, Zeroable)]
+#[discriminator_hash_input("stake::event::withdraw_protocol_fees::v1")]
+pub struct WithdrawProtocolFeesEvent {
+    pub config: Pubkey,
+    pub fee_recipient: Pubkey,
+    /// Lamports transferred to the fee recipient.
+    pub amount: u64,
+}
+
+/// A config or stake account was migrated to the current layout version.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Pod, SplDiscriminate, Zeroable)]
+#[discriminator_hash_input("stake::event::migrate_account::v1")]
+pub struct MigrateAccountEvent {
+    pub account: Pubkey,
+    /// Layout version of the account before the migration.
+    pub previous_version: u8,
+    /// Layout version of the account after the migration.
+    pub version: u8,
+    pub _padding: [u8; 6],
+}
+
+/// Tokens were staked into a liquid stake pool for receipt tokens.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Pod, SplDiscriminate, Zeroable)]
+#[discriminator_hash_input("stake::event::liquid_stake_tokens::v1")]
+pub struct LiquidStakeTokensEvent {
+    pub liquid_stake_pool: Pubkey,
+    pub validator_stake: Pubkey,
+    /// Authority of the source token account.
+    pub authority: Pubkey,
+    /// Amount of tokens staked.
+    pub amount: u64,
+    /// Amount of receipt tokens minted.
+    pub receipt_amount: u64,
+}
+
+/// Receipt tokens of a liquid stake pool were burned to unstake tokens.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Pod, SplDiscriminate, Zeroable)]
+#[discriminator_hash_input("stake::event::liquid_unstake_tokens::v1")]
+pub struct LiquidUnstakeTokensEvent {
+    pub liquid_stake_pool: Pubkey,
+    pub validator_stake: Pubkey,
+    /// Authority of the source receipt token account.
+    pub authority: Pubkey,
+    /// Amount of receipt tokens burned.
+    pub receipt_amount: u64,
+    /// Amount of tokens unstaked.
+    pub amount: u64,
+    /// Unstake cooldown of the pooled stake.
+    pub unstake_cooldown: u64,
+}
+
+impl Event for StakeTokensEvent {}
+impl Event for UnstakeTokensEvent {}
+impl Event for HarvestEvent {}
+impl Event for SlashEvent {}
+impl Event for MoveTokensEvent {}
+impl Event for UpdateConfigEvent {}
+impl Event for AuthorityChangeEvent {}
+impl Event for LamportsSyncEvent {}
+impl Event for SweepUndistributedRewardsEvent {}
+impl Event for TransferStakeEvent {}
+impl Event for WithdrawProtocolFeesEvent {}
+impl Event for MigrateAccountEvent {}
+impl Event for LiquidStakeTokensEvent {}
+impl Event for LiquidUnstakeTokensEvent {}
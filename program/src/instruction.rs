@@ -63,7 +63,11 @@ pub enum StakeInstruction {
     ///
     /// NOTE: Anybody can create the stake account for a validator. For new
     /// accounts, the authority is initialized to the validator vote account's
-    /// withdraw authority.
+    /// withdraw authority, unless an authority override is set for the vote
+    /// account.
+    ///
+    /// The validator authority override account can be provided as the first
+    /// remaining account; when it is omitted, no override is applied.
     #[account(
         0,
         name = "config",
@@ -97,6 +101,15 @@ pub enum StakeInstruction {
     /// NOTE: This instruction is used by validator stake accounts. The total amount of staked
     /// tokens is limited to the 1.3 * current amount of SOL staked to the validator.
     ///
+    /// The staked tokens are activating until the end of the current epoch, so they only
+    /// count towards the effective amount from the next harvest in a later epoch.
+    ///
+    /// Tokens can only be staked on the validator stake of a liquid stake pool through
+    /// `LiquidStakeTokens`, which signs as the pool.
+    ///
+    /// Extra accounts required by the transfer hook of the mint (if any) must be
+    /// provided as remaining accounts.
+    ///
     /// Instruction data: amount of tokens to stake, as a little-endian `u64`.
     #[account(
         0,
@@ -245,6 +258,10 @@ pub enum StakeInstruction {
     /// NOTE: This is very similar to the logic in the rewards program. Since the
     /// staking rewards are held in a separate account, they must be distributed
     /// based on the proportion of total stake.
+    ///
+    /// Tokens that finished activating are folded into the effective amount.
+    ///
+    /// The harvested amounts are set as return data (`HarvestReturnData`).
     #[account(
         0,
         writable,
@@ -330,6 +347,8 @@ pub enum StakeInstruction {
     SlashValidatorStake(u64),
 
     /// Sets new authority on a config or stake account.
+    ///
+    /// The fee recipient of a config account is set by its config authority.
     #[account(
         0,
         writable,
@@ -368,6 +387,8 @@ pub enum StakeInstruction {
     ///
     /// NOTE: Anybody can create the stake account for a SOL staker. For new
     /// accounts, the authority is initialized to the stake state account's withdrawer.
+    /// The validator stake account does not need to be initialized; the stake
+    /// is then credited to the validator once it is.
     #[account(
         0,
         name = "config",
@@ -422,6 +443,12 @@ pub enum StakeInstruction {
     /// NOTE: This instruction is used by SOL staker stake accounts. The total amount of staked
     /// tokens is limited to the 1.3 * current amount of SOL staked by the SOL staker.
     ///
+    /// The staked tokens are activating until the end of the current epoch, so they only
+    /// count towards the effective amount from the next harvest in a later epoch.
+    ///
+    /// Extra accounts required by the transfer hook of the mint (if any) must be
+    /// provided as remaining accounts.
+    ///
     /// Instruction data: amount of tokens to stake, as a little-endian `u64`.
     #[account(
         0,
@@ -505,6 +532,10 @@ pub enum StakeInstruction {
     /// NOTE: This is very similar to the logic in the rewards program. Since the
     /// staking rewards are held in a separate account, they must be distributed
     /// based on the proportion of total stake.
+    ///
+    /// Tokens that finished activating are folded into the effective amount.
+    ///
+    /// The harvested amounts are set as return data (`HarvestReturnData`).
     #[account(
         0,
         name = "sol_stake_view_program",
@@ -583,6 +614,9 @@ pub enum StakeInstruction {
     ///
     /// NOTE: This instruction is permissionless, so anybody can finish
     /// deactivating someone's tokens, preparing them to be withdrawn.
+    ///
+    /// Extra accounts required by the transfer hook of the mint (if any) must be
+    /// provided as remaining accounts.
     #[account(
         0,
         writable,
@@ -777,6 +811,11 @@ pub enum StakeInstruction {
     )]
     ValidatorOverrideStakedLamports { amount_min: u64 },
 
+    /// Syncs the authority of a validator stake account with the withdraw
+    /// authority of the vote account, or with its authority override.
+    ///
+    /// The validator authority override account must always be provided; when
+    /// it is uninitialized, the authority is synced with the withdraw authority.
     #[account(
         0,
         writable,
@@ -795,157 +834,949 @@ pub enum StakeInstruction {
         name = "validator_vote",
         desc = "Validator vote"
     )]
+    #[account(
+        3,
+        name = "validator_authority_override",
+        desc = "Validator authority override"
+    )]
     ValidatorSyncAuthority,
-}
 
-impl StakeInstruction {
-    /// Packs a [StakeInstruction](enum.StakeInstruction.html) into a byte buffer.
-    pub fn pack(&self) -> Vec<u8> {
-        match self {
-            StakeInstruction::InitializeConfig {
-                slash_authority,
-                config_authority,
-                cooldown_time_seconds,
-                max_deactivation_basis_points,
-                sync_rewards_lamports,
-                duna_document_hash,
-            } => {
-                let mut data = Vec::with_capacity(11);
-                data.push(0);
-                data.extend_from_slice(&slash_authority.to_bytes());
-                data.extend_from_slice(&config_authority.to_bytes());
-                data.extend_from_slice(&cooldown_time_seconds.to_le_bytes());
-                data.extend_from_slice(&max_deactivation_basis_points.to_le_bytes());
-                data.extend_from_slice(&sync_rewards_lamports.to_le_bytes());
-                data.extend_from_slice(duna_document_hash);
-                data
-            }
-            StakeInstruction::InitializeValidatorStake => vec![1],
-            StakeInstruction::ValidatorStakeTokens(amount) => {
-                let mut data = Vec::with_capacity(9);
-                data.push(2);
-                data.extend_from_slice(&amount.to_le_bytes());
-                data
-            }
-            StakeInstruction::HarvestHolderRewards => vec![3],
-            StakeInstruction::HarvestValidatorRewards => vec![4],
-            StakeInstruction::SlashValidatorStake(amount) => {
-                let mut data = Vec::with_capacity(9);
-                data.push(5);
-                data.extend_from_slice(&amount.to_le_bytes());
-                data
-            }
-            StakeInstruction::SetAuthority(authority_type) => {
-                vec![
-                    6,
-                    match authority_type {
-                        AuthorityType::Config => 0,
-                        AuthorityType::Slash => 1,
-                    },
-                ]
-            }
-            StakeInstruction::UpdateConfig(field) => {
-                let mut data = Vec::with_capacity(11);
-                data.push(7);
-                match field {
-                    ConfigField::CooldownTimeSeconds(value) => {
-                        data.push(0);
-                        data.extend_from_slice(&value.to_le_bytes());
-                    }
-                    ConfigField::MaxDeactivationBasisPoints(value) => {
-                        data.push(1);
-                        data.extend_from_slice(&value.to_le_bytes());
-                    }
-                    ConfigField::SyncRewardsLamports(value) => {
-                        data.push(2);
-                        data.extend_from_slice(&value.to_le_bytes());
-                    }
-                }
-                data
-            }
-            StakeInstruction::InitializeSolStakerStake => vec![8],
-            StakeInstruction::SolStakerStakeTokens(amount) => {
-                let mut data = Vec::with_capacity(9);
-                data.push(9);
-                data.extend_from_slice(&amount.to_le_bytes());
-                data
-            }
-            StakeInstruction::HarvestSolStakerRewards => vec![10],
-            StakeInstruction::UnstakeTokens { amount } => {
-                let mut data = Vec::with_capacity(9);
-                data.push(11);
-                data.extend_from_slice(&amount.to_le_bytes());
-                data
-            }
-            StakeInstruction::SlashSolStakerStake(amount) => {
-                let mut data = Vec::with_capacity(9);
-                data.push(12);
-                data.extend_from_slice(&amount.to_le_bytes());
-                data
-            }
-            StakeInstruction::SolStakerMoveTokens { amount } => {
-                let mut data = Vec::with_capacity(9);
-                data.push(13);
-                data.extend_from_slice(&amount.to_le_bytes());
-                data
-            }
-            StakeInstruction::ValidatorOverrideStakedLamports { amount_min } => {
-                let mut data = Vec::with_capacity(9);
-                data.push(14);
-                data.extend_from_slice(&amount_min.to_le_bytes());
-                data
-            }
-            StakeInstruction::ValidatorSyncAuthority => vec![15],
-        }
-    }
+    /// Computes the rewards that a harvest would pay out for the given stake
+    /// account, without modifying any account.
+    ///
+    /// The amounts are set as return data (`HarvestReturnData`), so this
+    /// instruction is meant to be executed with `simulateTransaction`.
+    #[account(
+        0,
+        name = "config",
+        desc = "Stake config account"
+    )]
+    #[account(
+        1,
+        name = "vault_holder_rewards",
+        desc = "Vault holder rewards"
+    )]
+    #[account(
+        2,
+        name = "stake",
+        desc = "Validator or sol staker stake account"
+    )]
+    GetPendingRewards,
 
-    /// Unpacks a byte buffer into a [StakeInstruction](enum.StakeInstruction.html).
-    pub fn unpack(input: &[u8]) -> Result<Self, ProgramError> {
-        match input.split_first() {
-            // 0 - InitializeConfig: u64 (8) + u16 (2) + u64 (8)
-            Some((&0, rest)) if rest.len() == 32 + 32 + 8 + 2 + 8 + 32 => {
-                let slash_authority = Pubkey::new_from_array(*array_ref![rest, 0, 32]);
-                let config_authority = Pubkey::new_from_array(*array_ref![rest, 32, 32]);
-                let cooldown_time_seconds = u64::from_le_bytes(*array_ref![rest, 64, 8]);
-                let max_deactivation_basis_points = u16::from_le_bytes(*array_ref![rest, 72, 2]);
-                let sync_rewards_lamports = u64::from_le_bytes(*array_ref![rest, 74, 8]);
-                let duna_document_hash = *array_ref![rest, 82, 32];
+    /// Upgrades a config or stake account to the current layout version.
+    ///
+    /// The account is reallocated to the current size (new fields are zero
+    /// initialized) and the payer tops up the lamports required for rent
+    /// exemption. This instruction is permissionless.
+    #[account(
+        0,
+        writable,
+        name = "account",
+        desc = "Config or stake account to migrate"
+    )]
+    #[account(
+        1,
+        writable,
+        signer,
+        name = "payer",
+        desc = "Payer of the additional rent"
+    )]
+    #[account(
+        2,
+        name = "system_program",
+        desc = "System program"
+    )]
+    MigrateAccount,
 
-                Ok(StakeInstruction::InitializeConfig {
-                    slash_authority,
-                    config_authority,
-                    cooldown_time_seconds,
-                    max_deactivation_basis_points,
-                    sync_rewards_lamports,
-                    duna_document_hash,
-                })
-            }
-            // 1 - InitializeValidatorStake
-            Some((&1, _)) => Ok(StakeInstruction::InitializeValidatorStake),
-            // 2 - StakeTokens: u64 (8)
-            Some((&2, rest)) if rest.len() == 8 => {
-                let amount = u64::from_le_bytes(*array_ref![rest, 0, 8]);
+    /// Creates the stake config account at the PDA derived from the stake
+    /// mint, the config authority and a namespace, so the config governing a
+    /// mint can be looked up by clients.
+    ///
+    /// The config authority must sign, so no one else can take the address of
+    /// its config first. The mint authority is not required, so configs can be
+    /// created for mints whose mint authority was revoked.
+    ///
+    /// NOTE: The config account must be pre-funded with the minimum rent
+    /// balance by the caller; it is allocated and assigned by the program.
+    #[account(
+        0,
+        writable,
+        name = "config",
+        desc = "Stake config account"
+    )]
+    #[account(
+        1,
+        name = "mint",
+        desc = "Stake token mint"
+    )]
+    #[account(
+        2,
+        writable,
+        name = "vault_pda",
+        desc = "Stake vault pda"
+    )]
+    #[account(
+        3,
+        name = "vault",
+        desc = "Stake vault token account"
+    )]
+    #[account(
+        4,
+        writable,
+        name = "vault_holder_rewards",
+        desc = "Stake vault holder rewards account"
+    )]
+    #[account(
+        5,
+        name = "system_program",
+        desc = "System program.",
+    )]
+    #[account(
+        6,
+        name = "rewards_program", 
+        desc = "Paladin rewards program",
+    )]
+    #[account(
+        7,
+        signer,
+        name = "config_authority",
+        desc = "Config authority"
+    )]
+    InitializeConfigPda {
+        namespace: u64,
+        slash_authority: Pubkey,
+        cooldown_time_seconds: u64,
+        max_deactivation_basis_points: u16,
+        sync_rewards_lamports: u64,
+        duna_document_hash: [u8; 32],
+    },
 
-                Ok(StakeInstruction::ValidatorStakeTokens(amount))
-            }
-            // 3 - HarvestHolderRewards
-            Some((&3, _)) => Ok(StakeInstruction::HarvestHolderRewards),
-            // 4 - HarvestStakeRewards
-            Some((&4, _)) => Ok(StakeInstruction::HarvestValidatorRewards),
-            // 5 - SlashValidatorStake: u64 (8)
-            Some((&5, rest)) if rest.len() == 8 => {
-                let amount = u64::from_le_bytes(*array_ref![rest, 0, 8]);
+    /// Returns the voting power of a stake account or a vote delegate at the
+    /// end of the given slot.
+    ///
+    /// The voting power of a stake account is its effective amount, or `0`
+    /// while it is delegated to a vote delegate; the voting power of a vote
+    /// delegate is read from its `DelegatedVotingPower` account.
+    ///
+    /// The voting power is set as return data. Only past slots can be queried
+    /// and only the latest changes of the voting power are kept: once the
+    /// history is full, the oldest changes are merged, so the value returned
+    /// for an older slot may be a lower bound of the voting power. The
+    /// instruction fails for the current (or a future) slot, or if the stake
+    /// account was not migrated to a layout with the history.
+    #[account(
+        0,
+        name = "config",
+        desc = "Stake config account"
+    )]
+    #[account(
+        1,
+        name = "stake",
+        desc = "Validator or sol staker stake, or delegated voting power account"
+    )]
+    GetVotingPower {
+        slot: u64,
+    },
 
-                Ok(StakeInstruction::SlashValidatorStake(amount))
-            }
-            // 6 - SetAuthority: AuthorityType (u8))
-            Some((&6, rest)) if rest.len() == 1 => {
-                let authority_type =
-                    FromPrimitive::from_u8(rest[0]).ok_or(ProgramError::InvalidInstructionData)?;
-                Ok(StakeInstruction::SetAuthority(authority_type))
-            }
-            // 7 - UpdateConfig: ConfigField (u64 or u16)
-            Some((&7, rest)) => {
+    /// Initializes the account aggregating the voting power delegated to a
+    /// vote delegate.
+    ///
+    /// NOTE: The account must be pre-funded with the minimum rent balance by
+    /// the caller; it is allocated and assigned by the program. Anybody can
+    /// initialize the account for a delegate.
+    #[account(
+        0,
+        name = "config",
+        desc = "Stake config account"
+    )]
+    #[account(
+        1,
+        name = "vote_delegate",
+        desc = "Vote delegate"
+    )]
+    #[account(
+        2,
+        writable,
+        name = "delegated_voting_power",
+        desc = "Delegated voting power account"
+    )]
+    #[account(
+        3,
+        name = "system_program",
+        desc = "System program"
+    )]
+    InitializeDelegatedVotingPower,
+
+    /// Sets the vote delegate of a stake account, or clears it when no
+    /// delegate is provided.
+    ///
+    /// The new delegate must sign, so voting power (and the checkpoints it
+    /// records) cannot be pushed onto a delegate without its consent.
+    ///
+    /// The `DelegatedVotingPower` accounts of the previous and new delegates
+    /// must be provided as remaining accounts. Once a delegate is set, every
+    /// instruction of the stake authority that changes the effective amount
+    /// of the stake account requires the `DelegatedVotingPower` account of the
+    /// delegate as a remaining account. Slashes and keeper syncs do not fail
+    /// without it; the voting power of the delegate then catches up on the
+    /// next sync with the account.
+    #[account(
+        0,
+        name = "config",
+        desc = "Stake config account"
+    )]
+    #[account(
+        1,
+        writable,
+        name = "stake",
+        desc = "Validator or sol staker stake account"
+    )]
+    #[account(
+        2,
+        signer,
+        name = "stake_authority",
+        desc = "Stake authority"
+    )]
+    #[account(
+        3,
+        optional,
+        signer,
+        name = "vote_delegate",
+        desc = "Vote delegate (clears the delegate when omitted)"
+    )]
+    SetVoteDelegate,
+
+    /// Sets the authority used for the validator stake of a vote account,
+    /// instead of the withdraw authority of the vote account.
+    ///
+    /// The override is seeded by the vote account, so it keeps applying when
+    /// the withdraw authority changes. It is applied by `InitializeValidatorStake` and
+    /// `ValidatorSyncAuthority`. Setting the override to the withdraw authority
+    /// restores the default behaviour. A validator stake whose authority is
+    /// its liquid stake pool keeps the pool as its authority.
+    ///
+    /// NOTE: The override account must be pre-funded with the minimum rent
+    /// balance by the caller when it is created.
+    #[account(
+        0,
+        name = "config",
+        desc = "Stake config account"
+    )]
+    #[account(
+        1,
+        name = "validator_vote",
+        desc = "Validator vote account"
+    )]
+    #[account(
+        2,
+        signer,
+        name = "withdraw_authority",
+        desc = "Validator vote account withdraw authority"
+    )]
+    #[account(
+        3,
+        writable,
+        name = "validator_authority_override",
+        desc = "Validator authority override"
+    )]
+    #[account(
+        4,
+        name = "new_authority",
+        desc = "Authority to use for the validator stake accounts"
+    )]
+    #[account(
+        5,
+        name = "system_program",
+        desc = "System program"
+    )]
+    SetValidatorAuthorityOverride,
+
+    /// Transfers the stake rewards released while no tokens were effective
+    /// to the destination account.
+    ///
+    /// These rewards are tracked on the config as `undistributed_rewards`,
+    /// since they are not owed to any stake account.
+    #[account(
+        0,
+        writable,
+        name = "config",
+        desc = "Stake config account"
+    )]
+    #[account(
+        1,
+        signer,
+        name = "config_authority",
+        desc = "Stake config authority"
+    )]
+    #[account(
+        2,
+        writable,
+        name = "destination",
+        desc = "Destination account for the undistributed rewards"
+    )]
+    SweepUndistributedRewards,
+
+    /// Initializes the liquid stake pool of a validator stake account.
+    ///
+    /// Staking through the pool mints receipt tokens, and the SOL stake and
+    /// holder rewards earned by the pooled stake are distributed to the receipt
+    /// token holders.
+    ///
+    /// NOTE: The validator stake must have the pool PDA as its authority (set
+    /// through the validator authority override) and no staked tokens. The
+    /// receipt mint must be empty, use the decimals of the stake mint and have
+    /// the pool PDA as its mint authority and no freeze authority. The pool
+    /// account must be pre-funded with the minimum rent balance by the caller.
+    #[account(
+        0,
+        name = "config",
+        desc = "Stake config account"
+    )]
+    #[account(
+        1,
+        name = "validator_stake",
+        desc = "Pooled validator stake account"
+    )]
+    #[account(
+        2,
+        writable,
+        name = "liquid_stake_pool",
+        desc = "Liquid stake pool account"
+    )]
+    #[account(
+        3,
+        name = "vault",
+        desc = "Vault token account"
+    )]
+    #[account(
+        4,
+        name = "mint",
+        desc = "Stake token mint"
+    )]
+    #[account(
+        5,
+        name = "receipt_mint",
+        desc = "Receipt token mint"
+    )]
+    #[account(
+        6,
+        name = "receipt_token_program",
+        desc = "Receipt token program"
+    )]
+    #[account(
+        7,
+        name = "system_program",
+        desc = "System program"
+    )]
+    InitializeLiquidStakePool,
+
+    /// Initializes the rewards tracking account of a receipt token account of
+    /// a liquid stake pool.
+    ///
+    /// NOTE: The account must be pre-funded with the minimum rent balance by
+    /// the caller. Anybody can initialize the account for a token account.
+    #[account(
+        0,
+        writable,
+        name = "liquid_stake_pool",
+        desc = "Liquid stake pool account"
+    )]
+    #[account(
+        1,
+        name = "receipt_mint",
+        desc = "Receipt token mint"
+    )]
+    #[account(
+        2,
+        name = "receipt_token_account",
+        desc = "Receipt token account"
+    )]
+    #[account(
+        3,
+        writable,
+        name = "receipt_rewards",
+        desc = "Receipt rewards account"
+    )]
+    #[account(
+        4,
+        name = "system_program",
+        desc = "System program"
+    )]
+    InitializeReceiptRewards,
+
+    /// Stakes tokens into a liquid stake pool, minting receipt tokens.
+    ///
+    /// Receipt tokens are minted 1:1 with the staked tokens, or in proportion
+    /// to the receipt token supply once the pooled stake was slashed. They
+    /// only earn rewards from the next epoch, as the staked tokens.
+    ///
+    /// Extra accounts required by the transfer hook of the mint and the
+    /// delegated voting power account of the pooled stake vote delegate (if
+    /// any) must be provided as remaining accounts.
+    #[account(
+        0,
+        writable,
+        name = "config",
+        desc = "Stake config account"
+    )]
+    #[account(
+        1,
+        writable,
+        name = "holder_rewards_pool",
+        desc = "Holder rewards pool account"
+    )]
+    #[account(
+        2,
+        writable,
+        name = "holder_rewards_pool_token_account",
+        desc = "Holder rewards pool account token account"
+    )]
+    #[account(
+        3,
+        writable,
+        name = "validator_stake",
+        desc = "Pooled validator stake account"
+    )]
+    #[account(
+        4,
+        writable,
+        name = "liquid_stake_pool",
+        desc = "Liquid stake pool account"
+    )]
+    #[account(
+        5,
+        writable,
+        name = "source_token_account",
+        desc = "Token account"
+    )]
+    #[account(
+        6,
+        signer,
+        name = "source_token_account_authority",
+        desc = "Owner or delegate of the token account"
+    )]
+    #[account(
+        7,
+        name = "mint",
+        desc = "Stake Token Mint"
+    )]
+    #[account(
+        8,
+        writable,
+        name = "vault_pda",
+        desc = "Vault authority"
+    )]
+    #[account(
+        9,
+        writable,
+        name = "vault",
+        desc = "Stake token Vault"
+    )]
+    #[account(
+        10,
+        writable,
+        name = "vault_holder_rewards",
+        desc = "Vault holder rewards account"
+    )]
+    #[account(
+        11,
+        name = "token_program",
+        desc = "Token program"
+    )]
+    #[account(
+        12,
+        name = "rewards_program",
+        desc = "Paladin rewards program"
+    )]
+    #[account(
+        13,
+        writable,
+        name = "receipt_mint",
+        desc = "Receipt token mint"
+    )]
+    #[account(
+        14,
+        writable,
+        name = "destination_receipt_account",
+        desc = "Destination receipt token account"
+    )]
+    #[account(
+        15,
+        writable,
+        name = "destination_receipt_rewards",
+        desc = "Receipt rewards account of the destination"
+    )]
+    #[account(
+        16,
+        name = "receipt_token_program",
+        desc = "Receipt token program"
+    )]
+    #[account(
+        17,
+        name = "stake_program",
+        desc = "Stake program"
+    )]
+    LiquidStakeTokens { amount: u64 },
+
+    /// Burns receipt tokens of a liquid stake pool, unstaking the
+    /// corresponding amount of tokens to the destination token account.
+    ///
+    /// NOTE: The unstake cooldown and maximum deactivation amount of the pooled
+    /// stake apply, so they are shared by all receipt token holders.
+    ///
+    /// Extra accounts required by the transfer hook of the mint and the
+    /// delegated voting power account of the pooled stake vote delegate (if
+    /// any) must be provided as remaining accounts.
+    #[account(
+        0,
+        writable,
+        name = "config",
+        desc = "Stake config account"
+    )]
+    #[account(
+        1,
+        writable,
+        name = "holder_rewards_pool",
+        desc = "Holder rewards pool account"
+    )]
+    #[account(
+        2,
+        writable,
+        name = "holder_rewards_pool_token_account",
+        desc = "Holder rewards pool account token account"
+    )]
+    #[account(
+        3,
+        writable,
+        name = "validator_stake",
+        desc = "Pooled validator stake account"
+    )]
+    #[account(
+        4,
+        writable,
+        name = "liquid_stake_pool",
+        desc = "Liquid stake pool account"
+    )]
+    #[account(
+        5,
+        writable,
+        name = "vault",
+        desc = "Vault account"
+    )]
+    #[account(
+        6,
+        writable,
+        name = "vault_pda",
+        desc = "Vault authority"
+    )]
+    #[account(
+        7,
+        writable,
+        name = "vault_holder_rewards",
+        desc = "Vault holder rewards account"
+    )]
+    #[account(
+        8,
+        name = "mint",
+        desc = "Mint account"
+    )]
+    #[account(
+        9,
+        writable,
+        name = "destination_token_account",
+        desc = "Destination token account"
+    )]
+    #[account(
+        10,
+        name = "token_program",
+        desc = "Token program"
+    )]
+    #[account(
+        11,
+        name = "rewards_program",
+        desc = "Paladin rewards program"
+    )]
+    #[account(
+        12,
+        writable,
+        name = "receipt_mint",
+        desc = "Receipt token mint"
+    )]
+    #[account(
+        13,
+        writable,
+        name = "source_receipt_account",
+        desc = "Source receipt token account"
+    )]
+    #[account(
+        14,
+        writable,
+        name = "source_receipt_rewards",
+        desc = "Receipt rewards account of the source"
+    )]
+    #[account(
+        15,
+        signer,
+        name = "source_receipt_authority",
+        desc = "Owner or delegate of the source receipt token account"
+    )]
+    #[account(
+        16,
+        name = "receipt_token_program",
+        desc = "Receipt token program"
+    )]
+    #[account(
+        17,
+        name = "stake_program",
+        desc = "Stake program"
+    )]
+    LiquidUnstakeTokens { amount: u64 },
+
+    /// Harvests the liquid stake pool rewards of a receipt token account.
+    ///
+    /// NOTE: This instruction is permissionless; the rewards are always paid
+    /// to the owner of the receipt token account.
+    #[account(
+        0,
+        writable,
+        name = "liquid_stake_pool",
+        desc = "Liquid stake pool account"
+    )]
+    #[account(
+        1,
+        name = "receipt_mint",
+        desc = "Receipt token mint"
+    )]
+    #[account(
+        2,
+        name = "receipt_token_account",
+        desc = "Receipt token account"
+    )]
+    #[account(
+        3,
+        writable,
+        name = "receipt_rewards",
+        desc = "Receipt rewards account"
+    )]
+    #[account(
+        4,
+        writable,
+        name = "receipt_token_account_owner",
+        desc = "Owner of the receipt token account"
+    )]
+    HarvestReceiptRewards,
+
+    /// Transfers staked PAL between the SOL staker stake accounts of two
+    /// different authorities, without going through the unstake cooldown.
+    ///
+    /// Both stake accounts are harvested before the transfer. The destination
+    /// keeps the latest unstake cooldown of the two accounts, and tokens still
+    /// activating on the source keep activating on the destination.
+    ///
+    /// The amounts harvested from the source stake account are set as return
+    /// data (`HarvestReturnData`); the destination is harvested to its own
+    /// authority.
+    ///
+    /// The delegated voting power accounts of the vote delegates of both stake
+    /// accounts (if any) must be provided as remaining accounts.
+    #[account(
+        0,
+        writable,
+        name = "config",
+        desc = "Stake config account"
+    )]
+    #[account(
+        1,
+        name = "vault_holder_rewards",
+        desc = "Vault holder rewards account"
+    )]
+    #[account(
+        2,
+        writable,
+        name = "source_sol_staker_stake",
+        desc = "Source SOL staker stake account"
+    )]
+    #[account(
+        3,
+        signer,
+        writable,
+        name = "source_authority",
+        desc = "Source SOL staker stake authority"
+    )]
+    #[account(
+        4,
+        writable,
+        name = "destination_sol_staker_stake",
+        desc = "Destination SOL staker stake account"
+    )]
+    #[account(
+        5,
+        signer,
+        writable,
+        name = "destination_authority",
+        desc = "Destination SOL staker stake authority"
+    )]
+    TransferStake { amount: u64 },
+
+    /// Transfers the unclaimed protocol fees to the fee recipient.
+    ///
+    /// Protocol fees are withheld from the stake rewards received by the config
+    /// account and kept on the config account until withdrawn.
+    #[account(
+        0,
+        writable,
+        name = "config",
+        desc = "Stake config account"
+    )]
+    #[account(
+        1,
+        signer,
+        name = "config_authority",
+        desc = "Stake config authority"
+    )]
+    #[account(
+        2,
+        writable,
+        name = "fee_recipient",
+        desc = "Fee recipient set on the config"
+    )]
+    WithdrawProtocolFees,
+
+    /// Checks the accounting invariants of a config against its vault and the
+    /// given stake accounts, without modifying any account.
+    ///
+    /// The validator and sol staker stake accounts to audit are provided as
+    /// remaining accounts. The report (`AuditReturnData`) is set as return
+    /// data, flagging the invariants that do not hold; this instruction is
+    /// permissionless and meant to be executed with `simulateTransaction`.
+    #[account(
+        0,
+        name = "config",
+        desc = "Stake config account"
+    )]
+    #[account(
+        1,
+        name = "vault",
+        desc = "Vault token account"
+    )]
+    #[account(
+        2,
+        name = "vault_holder_rewards",
+        desc = "Vault holder rewards"
+    )]
+    AuditInvariants,
+}
+
+impl StakeInstruction {
+    /// Packs a [StakeInstruction](enum.StakeInstruction.html) into a byte buffer.
+    pub fn pack(&self) -> Vec<u8> {
+        match self {
+            StakeInstruction::InitializeConfig {
+                slash_authority,
+                config_authority,
+                cooldown_time_seconds,
+                max_deactivation_basis_points,
+                sync_rewards_lamports,
+                duna_document_hash,
+            } => {
+                let mut data = Vec::with_capacity(11);
+                data.push(0);
+                data.extend_from_slice(&slash_authority.to_bytes());
+                data.extend_from_slice(&config_authority.to_bytes());
+                data.extend_from_slice(&cooldown_time_seconds.to_le_bytes());
+                data.extend_from_slice(&max_deactivation_basis_points.to_le_bytes());
+                data.extend_from_slice(&sync_rewards_lamports.to_le_bytes());
+                data.extend_from_slice(duna_document_hash);
+                data
+            }
+            StakeInstruction::InitializeValidatorStake => vec![1],
+            StakeInstruction::ValidatorStakeTokens(amount) => {
+                let mut data = Vec::with_capacity(9);
+                data.push(2);
+                data.extend_from_slice(&amount.to_le_bytes());
+                data
+            }
+            StakeInstruction::HarvestHolderRewards => vec![3],
+            StakeInstruction::HarvestValidatorRewards => vec![4],
+            StakeInstruction::SlashValidatorStake(amount) => {
+                let mut data = Vec::with_capacity(9);
+                data.push(5);
+                data.extend_from_slice(&amount.to_le_bytes());
+                data
+            }
+            StakeInstruction::SetAuthority(authority_type) => {
+                vec![
+                    6,
+                    match authority_type {
+                        AuthorityType::Config => 0,
+                        AuthorityType::Slash => 1,
+                        AuthorityType::FeeRecipient => 2,
+                    },
+                ]
+            }
+            StakeInstruction::UpdateConfig(field) => {
+                let mut data = Vec::with_capacity(11);
+                data.push(7);
+                match field {
+                    ConfigField::CooldownTimeSeconds(value) => {
+                        data.push(0);
+                        data.extend_from_slice(&value.to_le_bytes());
+                    }
+                    ConfigField::MaxDeactivationBasisPoints(value) => {
+                        data.push(1);
+                        data.extend_from_slice(&value.to_le_bytes());
+                    }
+                    ConfigField::SyncRewardsLamports(value) => {
+                        data.push(2);
+                        data.extend_from_slice(&value.to_le_bytes());
+                    }
+                    ConfigField::RewardStreamingPeriodSeconds(value) => {
+                        data.push(3);
+                        data.extend_from_slice(&value.to_le_bytes());
+                    }
+                    ConfigField::ProtocolFeeBasisPoints(value) => {
+                        data.push(4);
+                        data.extend_from_slice(&value.to_le_bytes());
+                    }
+                }
+                data
+            }
+            StakeInstruction::InitializeSolStakerStake => vec![8],
+            StakeInstruction::SolStakerStakeTokens(amount) => {
+                let mut data = Vec::with_capacity(9);
+                data.push(9);
+                data.extend_from_slice(&amount.to_le_bytes());
+                data
+            }
+            StakeInstruction::HarvestSolStakerRewards => vec![10],
+            StakeInstruction::UnstakeTokens { amount } => {
+                let mut data = Vec::with_capacity(9);
+                data.push(11);
+                data.extend_from_slice(&amount.to_le_bytes());
+                data
+            }
+            StakeInstruction::SlashSolStakerStake(amount) => {
+                let mut data = Vec::with_capacity(9);
+                data.push(12);
+                data.extend_from_slice(&amount.to_le_bytes());
+                data
+            }
+            StakeInstruction::SolStakerMoveTokens { amount } => {
+                let mut data = Vec::with_capacity(9);
+                data.push(13);
+                data.extend_from_slice(&amount.to_le_bytes());
+                data
+            }
+            StakeInstruction::ValidatorOverrideStakedLamports { amount_min } => {
+                let mut data = Vec::with_capacity(9);
+                data.push(14);
+                data.extend_from_slice(&amount_min.to_le_bytes());
+                data
+            }
+            StakeInstruction::ValidatorSyncAuthority => vec![15],
+            StakeInstruction::GetPendingRewards => vec![16],
+            StakeInstruction::MigrateAccount => vec![17],
+            StakeInstruction::InitializeConfigPda {
+                namespace,
+                slash_authority,
+                cooldown_time_seconds,
+                max_deactivation_basis_points,
+                sync_rewards_lamports,
+                duna_document_hash,
+            } => {
+                let mut data = Vec::with_capacity(1 + 8 + 32 + 8 + 2 + 8 + 32);
+                data.push(18);
+                data.extend_from_slice(&namespace.to_le_bytes());
+                data.extend_from_slice(&slash_authority.to_bytes());
+                data.extend_from_slice(&cooldown_time_seconds.to_le_bytes());
+                data.extend_from_slice(&max_deactivation_basis_points.to_le_bytes());
+                data.extend_from_slice(&sync_rewards_lamports.to_le_bytes());
+                data.extend_from_slice(duna_document_hash);
+                data
+            }
+            StakeInstruction::GetVotingPower { slot } => {
+                let mut data = Vec::with_capacity(9);
+                data.push(19);
+                data.extend_from_slice(&slot.to_le_bytes());
+                data
+            }
+            StakeInstruction::InitializeDelegatedVotingPower => vec![20],
+            StakeInstruction::SetVoteDelegate => vec![21],
+            StakeInstruction::SetValidatorAuthorityOverride => vec![22],
+            StakeInstruction::SweepUndistributedRewards => vec![23],
+            StakeInstruction::InitializeLiquidStakePool => vec![24],
+            StakeInstruction::InitializeReceiptRewards => vec![25],
+            StakeInstruction::LiquidStakeTokens { amount } => {
+                let mut data = Vec::with_capacity(9);
+                data.push(26);
+                data.extend_from_slice(&amount.to_le_bytes());
+                data
+            }
+            StakeInstruction::LiquidUnstakeTokens { amount } => {
+                let mut data = Vec::with_capacity(9);
+                data.push(27);
+                data.extend_from_slice(&amount.to_le_bytes());
+                data
+            }
+            StakeInstruction::HarvestReceiptRewards => vec![28],
+            StakeInstruction::TransferStake { amount } => {
+                let mut data = Vec::with_capacity(9);
+                data.push(29);
+                data.extend_from_slice(&amount.to_le_bytes());
+                data
+            }
+            StakeInstruction::WithdrawProtocolFees => vec![30],
+            StakeInstruction::AuditInvariants => vec![31],
+        }
+    }
+
+    /// Unpacks a byte buffer into a [StakeInstruction](enum.StakeInstruction.html).
+    pub fn unpack(input: &[u8]) -> Result<Self, ProgramError> {
+        match input.split_first() {
+            // 0 - InitializeConfig: u64 (8) + u16 (2) + u64 (8)
+            Some((&0, rest)) if rest.len() == 32 + 32 + 8 + 2 + 8 + 32 => {
+                let slash_authority = Pubkey::new_from_array(*array_ref![rest, 0, 32]);
+                let config_authority = Pubkey::new_from_array(*array_ref![rest, 32, 32]);
+                let cooldown_time_seconds = u64::from_le_bytes(*array_ref![rest, 64, 8]);
+                let max_deactivation_basis_points = u16::from_le_bytes(*array_ref![rest, 72, 2]);
+                let sync_rewards_lamports = u64::from_le_bytes(*array_ref![rest, 74, 8]);
+                let duna_document_hash = *array_ref![rest, 82, 32];
+
+                Ok(StakeInstruction::InitializeConfig {
+                    slash_authority,
+                    config_authority,
+                    cooldown_time_seconds,
+                    max_deactivation_basis_points,
+                    sync_rewards_lamports,
+                    duna_document_hash,
+                })
+            }
+            // 1 - InitializeValidatorStake
+            Some((&1, _)) => Ok(StakeInstruction::InitializeValidatorStake),
+            // 2 - StakeTokens: u64 (8)
+            Some((&2, rest)) if rest.len() == 8 => {
+                let amount = u64::from_le_bytes(*array_ref![rest, 0, 8]);
+
+                Ok(StakeInstruction::ValidatorStakeTokens(amount))
+            }
+            // 3 - HarvestHolderRewards
+            Some((&3, _)) => Ok(StakeInstruction::HarvestHolderRewards),
+            // 4 - HarvestStakeRewards
+            Some((&4, _)) => Ok(StakeInstruction::HarvestValidatorRewards),
+            // 5 - SlashValidatorStake: u64 (8)
+            Some((&5, rest)) if rest.len() == 8 => {
+                let amount = u64::from_le_bytes(*array_ref![rest, 0, 8]);
+
+                Ok(StakeInstruction::SlashValidatorStake(amount))
+            }
+            // 6 - SetAuthority: AuthorityType (u8))
+            Some((&6, rest)) if rest.len() == 1 => {
+                let authority_type =
+                    FromPrimitive::from_u8(rest[0]).ok_or(ProgramError::InvalidInstructionData)?;
+                Ok(StakeInstruction::SetAuthority(authority_type))
+            }
+            // 7 - UpdateConfig: ConfigField (u64 or u16)
+            Some((&7, rest)) => {
                 let field = match rest.split_first() {
                     Some((&0, rest)) if rest.len() == 8 => {
                         ConfigField::CooldownTimeSeconds(u64::from_le_bytes(*array_ref![
@@ -962,6 +1793,16 @@ impl StakeInstruction {
                             rest, 0, 8
                         ]))
                     }
+                    Some((&3, rest)) if rest.len() == 8 => {
+                        ConfigField::RewardStreamingPeriodSeconds(u64::from_le_bytes(*array_ref![
+                            rest, 0, 8
+                        ]))
+                    }
+                    Some((&4, rest)) if rest.len() == 2 => {
+                        ConfigField::ProtocolFeeBasisPoints(u16::from_le_bytes(*array_ref![
+                            rest, 0, 2
+                        ]))
+                    }
                     _ => return Err(ProgramError::InvalidInstructionData),
                 };
 
@@ -1003,6 +1844,71 @@ impl StakeInstruction {
             }
             // 15
             Some((&15, _)) => Ok(StakeInstruction::ValidatorSyncAuthority),
+            // 16 - GetPendingRewards
+            Some((&16, _)) => Ok(StakeInstruction::GetPendingRewards),
+            // 17 - MigrateAccount
+            Some((&17, _)) => Ok(StakeInstruction::MigrateAccount),
+            // 18 - InitializeConfigPda: u64 (8) + InitializeConfig arguments
+            //      without the config authority, which signs the instruction
+            Some((&18, rest)) if rest.len() == 8 + 32 + 8 + 2 + 8 + 32 => {
+                let namespace = u64::from_le_bytes(*array_ref![rest, 0, 8]);
+                let slash_authority = Pubkey::new_from_array(*array_ref![rest, 8, 32]);
+                let cooldown_time_seconds = u64::from_le_bytes(*array_ref![rest, 40, 8]);
+                let max_deactivation_basis_points = u16::from_le_bytes(*array_ref![rest, 48, 2]);
+                let sync_rewards_lamports = u64::from_le_bytes(*array_ref![rest, 50, 8]);
+                let duna_document_hash = *array_ref![rest, 58, 32];
+
+                Ok(StakeInstruction::InitializeConfigPda {
+                    namespace,
+                    slash_authority,
+                    cooldown_time_seconds,
+                    max_deactivation_basis_points,
+                    sync_rewards_lamports,
+                    duna_document_hash,
+                })
+            }
+            // 19 - GetVotingPower: u64 (8)
+            Some((&19, rest)) if rest.len() == 8 => {
+                let slot = u64::from_le_bytes(*array_ref![rest, 0, 8]);
+
+                Ok(StakeInstruction::GetVotingPower { slot })
+            }
+            // 20 - InitializeDelegatedVotingPower
+            Some((&20, _)) => Ok(StakeInstruction::InitializeDelegatedVotingPower),
+            // 21 - SetVoteDelegate
+            Some((&21, _)) => Ok(StakeInstruction::SetVoteDelegate),
+            // 22 - SetValidatorAuthorityOverride
+            Some((&22, _)) => Ok(StakeInstruction::SetValidatorAuthorityOverride),
+            // 23 - SweepUndistributedRewards
+            Some((&23, _)) => Ok(StakeInstruction::SweepUndistributedRewards),
+            // 24 - InitializeLiquidStakePool
+            Some((&24, _)) => Ok(StakeInstruction::InitializeLiquidStakePool),
+            // 25 - InitializeReceiptRewards
+            Some((&25, _)) => Ok(StakeInstruction::InitializeReceiptRewards),
+            // 26 - LiquidStakeTokens: u64 (8)
+            Some((&26, rest)) if rest.len() == 8 => {
+                let amount = u64::from_le_bytes(*array_ref![rest, 0, 8]);
+
+                Ok(StakeInstruction::LiquidStakeTokens { amount })
+            }
+            // 27 - LiquidUnstakeTokens: u64 (8)
+            Some((&27, rest)) if rest.len() == 8 => {
+                let amount = u64::from_le_bytes(*array_ref![rest, 0, 8]);
+
+                Ok(StakeInstruction::LiquidUnstakeTokens { amount })
+            }
+            // 28 - HarvestReceiptRewards
+            Some((&28, _)) => Ok(StakeInstruction::HarvestReceiptRewards),
+            // 29 - TransferStake: u64 (8)
+            Some((&29, rest)) if rest.len() == 8 => {
+                let amount = u64::from_le_bytes(*array_ref![rest, 0, 8]);
+
+                Ok(StakeInstruction::TransferStake { amount })
+            }
+            // 30 - WithdrawProtocolFees
+            Some((&30, _)) => Ok(StakeInstruction::WithdrawProtocolFees),
+            // 31 - AuditInvariants
+            Some((&31, _)) => Ok(StakeInstruction::AuditInvariants),
             _ => Err(ProgramError::InvalidInstructionData),
         }
     }
@@ -1013,6 +1919,7 @@ impl StakeInstruction {
 pub enum AuthorityType {
     Config,
     Slash,
+    FeeRecipient,
 }
 
 /// Enum to allow updating the config account in the same instruction
@@ -1024,6 +1931,12 @@ pub enum ConfigField {
     MaxDeactivationBasisPoints(u16),
     /// Lamports amount paid to for syncing a SOL stake account.
     SyncRewardsLamports(u64),
+    /// Period (in seconds) over which stake rewards are released; `0` releases
+    /// them as soon as they are received
+    RewardStreamingPeriodSeconds(u64),
+    /// Proportion of the received stake rewards withheld as protocol fee, in
+    /// basis points (at most 10%)
+    ProtocolFeeBasisPoints(u16),
 }
 
 #[cfg(test)]
@@ -1112,6 +2025,17 @@ mod tests {
         let packed = original.pack();
         let unpacked = StakeInstruction::unpack(&packed).unwrap();
         assert_eq!(original, unpacked);
+
+        let original =
+            StakeInstruction::UpdateConfig(ConfigField::RewardStreamingPeriodSeconds(172_800));
+        let packed = original.pack();
+        let unpacked = StakeInstruction::unpack(&packed).unwrap();
+        assert_eq!(original, unpacked);
+
+        let original = StakeInstruction::UpdateConfig(ConfigField::ProtocolFeeBasisPoints(250));
+        let packed = original.pack();
+        let unpacked = StakeInstruction::unpack(&packed).unwrap();
+        assert_eq!(original, unpacked);
     }
 
     #[test]
@@ -1129,4 +2053,139 @@ mod tests {
         let unpacked = StakeInstruction::unpack(&packed).unwrap();
         assert_eq!(original, unpacked);
     }
+
+    #[test]
+    fn test_pack_unpack_get_pending_rewards() {
+        let original = StakeInstruction::GetPendingRewards;
+        let packed = original.pack();
+        let unpacked = StakeInstruction::unpack(&packed).unwrap();
+        assert_eq!(original, unpacked);
+    }
+
+    #[test]
+    fn test_pack_unpack_migrate_account() {
+        let original = StakeInstruction::MigrateAccount;
+        let packed = original.pack();
+        let unpacked = StakeInstruction::unpack(&packed).unwrap();
+        assert_eq!(original, unpacked);
+    }
+
+    #[test]
+    fn test_pack_unpack_initialize_config_pda() {
+        let original = StakeInstruction::InitializeConfigPda {
+            namespace: 7,
+            slash_authority: Pubkey::new_unique(),
+            cooldown_time_seconds: 120,
+            max_deactivation_basis_points: 500,
+            sync_rewards_lamports: 100,
+            duna_document_hash: [8; 32],
+        };
+        let packed = original.pack();
+        let unpacked = StakeInstruction::unpack(&packed).unwrap();
+        assert_eq!(original, unpacked);
+    }
+
+    #[test]
+    fn test_pack_unpack_get_voting_power() {
+        let original = StakeInstruction::GetVotingPower { slot: 42 };
+        let packed = original.pack();
+        let unpacked = StakeInstruction::unpack(&packed).unwrap();
+        assert_eq!(original, unpacked);
+    }
+
+    #[test]
+    fn test_pack_unpack_initialize_delegated_voting_power() {
+        let original = StakeInstruction::InitializeDelegatedVotingPower;
+        let packed = original.pack();
+        let unpacked = StakeInstruction::unpack(&packed).unwrap();
+        assert_eq!(original, unpacked);
+    }
+
+    #[test]
+    fn test_pack_unpack_set_vote_delegate() {
+        let original = StakeInstruction::SetVoteDelegate;
+        let packed = original.pack();
+        let unpacked = StakeInstruction::unpack(&packed).unwrap();
+        assert_eq!(original, unpacked);
+    }
+
+    #[test]
+    fn test_pack_unpack_set_validator_authority_override() {
+        let original = StakeInstruction::SetValidatorAuthorityOverride;
+        let packed = original.pack();
+        let unpacked = StakeInstruction::unpack(&packed).unwrap();
+        assert_eq!(original, unpacked);
+    }
+
+    #[test]
+    fn test_pack_unpack_sweep_undistributed_rewards() {
+        let original = StakeInstruction::SweepUndistributedRewards;
+        let packed = original.pack();
+        let unpacked = StakeInstruction::unpack(&packed).unwrap();
+        assert_eq!(original, unpacked);
+    }
+
+    #[test]
+    fn test_pack_unpack_initialize_liquid_stake_pool() {
+        let original = StakeInstruction::InitializeLiquidStakePool;
+        let packed = original.pack();
+        let unpacked = StakeInstruction::unpack(&packed).unwrap();
+        assert_eq!(original, unpacked);
+    }
+
+    #[test]
+    fn test_pack_unpack_initialize_receipt_rewards() {
+        let original = StakeInstruction::InitializeReceiptRewards;
+        let packed = original.pack();
+        let unpacked = StakeInstruction::unpack(&packed).unwrap();
+        assert_eq!(original, unpacked);
+    }
+
+    #[test]
+    fn test_pack_unpack_liquid_stake_tokens() {
+        let original = StakeInstruction::LiquidStakeTokens { amount: 100 };
+        let packed = original.pack();
+        let unpacked = StakeInstruction::unpack(&packed).unwrap();
+        assert_eq!(original, unpacked);
+    }
+
+    #[test]
+    fn test_pack_unpack_liquid_unstake_tokens() {
+        let original = StakeInstruction::LiquidUnstakeTokens { amount: 50 };
+        let packed = original.pack();
+        let unpacked = StakeInstruction::unpack(&packed).unwrap();
+        assert_eq!(original, unpacked);
+    }
+
+    #[test]
+    fn test_pack_unpack_harvest_receipt_rewards() {
+        let original = StakeInstruction::HarvestReceiptRewards;
+        let packed = original.pack();
+        let unpacked = StakeInstruction::unpack(&packed).unwrap();
+        assert_eq!(original, unpacked);
+    }
+
+    #[test]
+    fn test_pack_unpack_transfer_stake() {
+        let original = StakeInstruction::TransferStake { amount: 25 };
+        let packed = original.pack();
+        let unpacked = StakeInstruction::unpack(&packed).unwrap();
+        assert_eq!(original, unpacked);
+    }
+
+    #[test]
+    fn test_pack_unpack_withdraw_protocol_fees() {
+        let original = StakeInstruction::WithdrawProtocolFees;
+        let packed = original.pack();
+        let unpacked = StakeInstruction::unpack(&packed).unwrap();
+        assert_eq!(original, unpacked);
+    }
+
+    #[test]
+    fn test_pack_unpack_audit_invariants() {
+        let original = StakeInstruction::AuditInvariants;
+        let packed = original.pack();
+        let unpacked = StakeInstruction::unpack(&packed).unwrap();
+        assert_eq!(original, unpacked);
+    }
 }
@@ -1,8 +1,12 @@
+#[cfg(feature = "cpi")]
+pub mod cpi;
 #[cfg(feature = "bpf-entrypoint")]
 pub mod entrypoint;
 pub mod error;
+pub mod events;
 pub mod instruction;
 pub mod processor;
+pub mod return_data;
 pub mod state;
 
 solana_program::declare_id!("GQurxHCYQCNfYR37nHNb6ZiLWg3jpbh2fWv2RpzwGqRK");
@@ -0,0 +1,249 @@
+use paladin_rewards_program_client::accounts::HolderRewards;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey, rent::Rent, sysvar::Sysvar,
+};
+use spl_discriminator::{ArrayDiscriminator, SplDiscriminate};
+
+use crate::{
+    error::StakeError,
+    instruction::accounts::{AuditInvariantsAccounts, Context},
+    processor::{unpack_delegation_checked, unpack_initialized, unpack_token_account},
+    require,
+    return_data::AuditReturnData,
+    state::{
+        calculate_eligible_rewards, calculate_maximum_stake_for_lamports_amount, find_vault_pda,
+        Config, SolStakerStake, ValidatorStake,
+    },
+};
+
+/// Checks the accounting invariants of a config against its vault and the
+/// stake accounts provided as remaining accounts, without modifying any
+/// account.
+///
+/// The invariants checked are:
+///
+///   * the vault holds at least the staked tokens of the stake accounts and
+///     the effective tokens of the config, counting the tokens it deposited
+///     on the rewards program;
+///   * the stake accounts do not have more effective tokens than the config;
+///   * the effective tokens of each stake account are capped by its staked
+///     tokens and by the SOL backing it;
+///   * each validator stake account has at least the lamports of its SOL
+///     staker stake accounts;
+///   * the config account holds the rent exempt minimum plus the lamports
+///     not paid out yet.
+///
+/// The report is set as return data.
+///
+/// 0. `[ ]` Config account
+/// 1. `[ ]` Vault token account
+/// 2. `[ ]` Vault holder rewards
+/// 3..N. `[ ]` Validator or sol staker stake accounts
+pub fn process_audit_invariants(
+    program_id: &Pubkey,
+    ctx: Context<AuditInvariantsAccounts>,
+) -> ProgramResult {
+    // config
+    // - owner must be the stake program
+    // - must be initialized
+    require!(
+        ctx.accounts.config.owner == program_id,
+        ProgramError::InvalidAccountOwner,
+        "config"
+    );
+    let config_data = ctx.accounts.config.try_borrow_data()?;
+    let config = unpack_initialized::<Config>(&config_data)?;
+
+    // vault
+    // - must be the vault of the config
+    require!(
+        ctx.accounts.vault.key == &config.vault,
+        StakeError::IncorrectVaultAccount,
+        "vault"
+    );
+    let vault_data = ctx.accounts.vault.try_borrow_data()?;
+    let vault = unpack_token_account(&vault_data)?;
+
+    // vault holder rewards
+    // - must be the holder rewards account of the vault authority
+    let (vault_authority, _) = find_vault_pda(ctx.accounts.config.key, program_id);
+    let (derivation, _) = HolderRewards::find_pda(&vault_authority);
+    require!(
+        ctx.accounts.vault_holder_rewards.key == &derivation,
+        StakeError::InvalidVaultHolderRewardsSeeds,
+        "holder rewards",
+    );
+    let vault_holder_rewards = HolderRewards::try_from(ctx.accounts.vault_holder_rewards)?;
+
+    // Staked tokens are deposited on the rewards program by the vault.
+    let vault_amount = vault
+        .amount
+        .checked_add(vault_holder_rewards.deposited)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+
+    let mut report = AuditReturnData {
+        vault_amount,
+        config_lamports: ctx.accounts.config.lamports(),
+        ..Default::default()
+    };
+
+    // Rewards are paid out of the config account, along with the protocol fees.
+    report.unpaid_rewards = config
+        .undistributed_rewards
+        .checked_add(config.unclaimed_protocol_fees)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+
+    // stakes
+    // - must not be repeated
+    let mut keys = ctx
+        .remaining_accounts
+        .iter()
+        .map(|stake| stake.key)
+        .collect::<Vec<_>>();
+    keys.sort_unstable();
+    require!(
+        keys.windows(2).all(|pair| pair[0] != pair[1]),
+        ProgramError::InvalidArgument,
+        "duplicate stake"
+    );
+
+    let delegated_lamports = delegated_lamports(ctx.remaining_accounts)?;
+
+    for stake in ctx.remaining_accounts {
+        // stake
+        // - owner must be the stake program
+        // - must be a ValidatorStake or SolStakerStake account
+        // - must be initialized
+        // - must have the correct derivation (validates the config account)
+        require!(
+            stake.owner == program_id,
+            ProgramError::InvalidAccountOwner,
+            "stake"
+        );
+        let stake_data = stake.try_borrow_data()?;
+        let delegation =
+            unpack_delegation_checked(&stake_data, stake.key, ctx.accounts.config.key, program_id)?;
+
+        report.stake_accounts += 1;
+        report.staked_amount = report
+            .staked_amount
+            .checked_add(delegation.staked_amount)
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+        report.effective_amount = report
+            .effective_amount
+            .checked_add(delegation.effective_amount)
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+
+        // Effective tokens are capped by the SOL backing the stake.
+        let lamports_stake = match &stake_data[..ArrayDiscriminator::LENGTH] {
+            ValidatorStake::SPL_DISCRIMINATOR_SLICE => {
+                let validator_stake = unpack_initialized::<ValidatorStake>(&stake_data)?;
+                let delegated_lamports = match delegated_lamports.binary_search_by(|(vote, _)| {
+                    vote.cmp(&validator_stake.delegation.validator_vote)
+                }) {
+                    Ok(position) => delegated_lamports[position].1,
+                    Err(_) => 0,
+                };
+                if delegated_lamports > validator_stake.total_staked_lamports_amount {
+                    msg!("Invariant failed: validator lamports of {}", stake.key);
+                    report.failed_invariants |= AuditReturnData::VALIDATOR_LAMPORTS;
+                }
+
+                std::cmp::max(
+                    validator_stake.total_staked_lamports_amount,
+                    validator_stake.total_staked_lamports_amount_min,
+                )
+            }
+            _ => unpack_initialized::<SolStakerStake>(&stake_data)?.lamports_amount,
+        };
+        let limit = std::cmp::min(
+            delegation.staked_amount,
+            calculate_maximum_stake_for_lamports_amount(lamports_stake)?,
+        );
+        if delegation.effective_amount > limit {
+            msg!("Invariant failed: effective cap of {}", stake.key);
+            report.failed_invariants |= AuditReturnData::EFFECTIVE_CAP;
+        }
+
+        // Rewards owed to the stake.
+        let staking_reward = calculate_eligible_rewards(
+            config.accumulated_stake_rewards_per_token.into(),
+            delegation.last_seen_stake_rewards_per_token.into(),
+            delegation.effective_amount,
+        )?;
+        let holder_reward = calculate_eligible_rewards(
+            vault_holder_rewards.last_accumulated_rewards_per_token,
+            delegation.last_seen_holder_rewards_per_token.into(),
+            delegation.staked_amount,
+        )?;
+        report.unpaid_rewards = report
+            .unpaid_rewards
+            .checked_add(staking_reward)
+            .and_then(|unpaid| unpaid.checked_add(holder_reward))
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+    }
+
+    if report.vault_amount < std::cmp::max(report.staked_amount, config.token_amount_effective) {
+        msg!("Invariant failed: vault balance");
+        report.failed_invariants |= AuditReturnData::VAULT_BALANCE;
+    }
+
+    if report.effective_amount > config.token_amount_effective {
+        msg!("Invariant failed: token amount effective");
+        report.failed_invariants |= AuditReturnData::TOKEN_AMOUNT_EFFECTIVE;
+    }
+
+    report.required_lamports = Rent::get()?
+        .minimum_balance(ctx.accounts.config.data_len())
+        .checked_add(report.unpaid_rewards)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    if report.config_lamports < report.required_lamports {
+        msg!("Invariant failed: config lamports");
+        report.failed_invariants |= AuditReturnData::CONFIG_LAMPORTS;
+    }
+
+    report.set();
+
+    Ok(())
+}
+
+/// Sums the lamports of the SOL staker stake accounts per validator vote
+/// account, sorted by vote account.
+///
+/// The stake accounts are all validated by the audit, which fails on any
+/// account that is not a stake account of the config.
+fn delegated_lamports(stakes: &[AccountInfo]) -> Result<Vec<(Pubkey, u64)>, ProgramError> {
+    let mut delegations = Vec::with_capacity(stakes.len());
+
+    for stake in stakes {
+        let stake_data = stake.try_borrow_data()?;
+        if stake_data.get(..ArrayDiscriminator::LENGTH)
+            != Some(SolStakerStake::SPL_DISCRIMINATOR_SLICE)
+        {
+            continue;
+        }
+
+        let sol_staker_stake = unpack_initialized::<SolStakerStake>(&stake_data)?;
+        delegations.push((
+            sol_staker_stake.delegation.validator_vote,
+            sol_staker_stake.lamports_amount,
+        ));
+    }
+
+    // Merge the delegations to the same vote account.
+    delegations.sort_unstable_by(|(a, _), (b, _)| a.cmp(b));
+    let mut lamports: Vec<(Pubkey, u64)> = Vec::with_capacity(delegations.len());
+    for (vote, amount) in delegations {
+        match lamports.last_mut() {
+            Some((last, total)) if *last == vote => {
+                *total = total
+                    .checked_add(amount)
+                    .ok_or(ProgramError::ArithmeticOverflow)?;
+            }
+            _ => lamports.push((vote, amount)),
+        }
+    }
+
+    Ok(lamports)
+}
@@ -0,0 +1,106 @@
+use paladin_rewards_program_client::accounts::HolderRewards;
+use solana_program::{
+    clock::Clock, entrypoint::ProgramResult, program_error::ProgramError, pubkey::Pubkey,
+    sysvar::Sysvar,
+};
+
+use crate::{
+    error::StakeError,
+    instruction::accounts::{Context, GetPendingRewardsAccounts},
+    processor::{unpack_delegation_checked, unpack_initialized},
+    require,
+    return_data::HarvestReturnData,
+    state::{calculate_eligible_rewards, find_vault_pda, Config},
+};
+
+/// Computes the rewards that a harvest would pay out for the given stake
+/// account, without modifying any account.
+///
+/// The computation mirrors `harvest`: the config lamports received since the
+/// last sync (and released by the reward stream) are accounted for before
+/// computing the staking rewards. The amounts are set as return data.
+///
+/// 0. `[ ]` Config account
+/// 1. `[ ]` Vault holder rewards
+/// 2. `[ ]` Validator or sol staker stake account
+pub fn process_get_pending_rewards(
+    program_id: &Pubkey,
+    ctx: Context<GetPendingRewardsAccounts>,
+) -> ProgramResult {
+    // config
+    // - owner must be the stake program
+    // - must be initialized
+    require!(
+        ctx.accounts.config.owner == program_id,
+        ProgramError::InvalidAccountOwner,
+        "config"
+    );
+    let config_data = ctx.accounts.config.try_borrow_data()?;
+    // NB: The reward stream is updated on a copy, since no account is modified.
+    let mut config = *unpack_initialized::<Config>(&config_data)?;
+
+    // stake
+    // - owner must be the stake program
+    // - must be a ValidatorStake or SolStakerStake account
+    // - must be initialized
+    // - must have the correct derivation (validates the config account)
+    require!(
+        ctx.accounts.stake.owner == program_id,
+        ProgramError::InvalidAccountOwner,
+        "stake"
+    );
+    let stake_data = ctx.accounts.stake.try_borrow_data()?;
+    let delegation = unpack_delegation_checked(
+        &stake_data,
+        ctx.accounts.stake.key,
+        ctx.accounts.config.key,
+        program_id,
+    )?;
+
+    // Account for the lamports received (and released) since the last sync.
+    let lamport_delta = ctx
+        .accounts
+        .config
+        .lamports()
+        .checked_sub(config.lamports_last)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    config.receive_stake_rewards(lamport_delta, Clock::get()?.unix_timestamp as u64)?;
+
+    // Compute the staking rewards.
+    let staking_reward = calculate_eligible_rewards(
+        config.accumulated_stake_rewards_per_token.into(),
+        delegation.last_seen_stake_rewards_per_token.into(),
+        delegation.effective_amount,
+    )?;
+
+    // vault holder rewards
+    // - must be the holder rewards account of the vault authority
+    let (vault_authority, _) = find_vault_pda(ctx.accounts.config.key, program_id);
+    let (derivation, _) = HolderRewards::find_pda(&vault_authority);
+    require!(
+        ctx.accounts.vault_holder_rewards.key == &derivation,
+        StakeError::InvalidVaultHolderRewardsSeeds,
+        "holder rewards",
+    );
+    let vault_holder_rewards = HolderRewards::try_from(ctx.accounts.vault_holder_rewards)?;
+
+    // Compute the holder rewards.
+    let holder_reward = calculate_eligible_rewards(
+        vault_holder_rewards.last_accumulated_rewards_per_token,
+        delegation.last_seen_holder_rewards_per_token.into(),
+        delegation.staked_amount,
+    )?;
+
+    HarvestReturnData {
+        staking_reward,
+        holder_reward,
+        keeper_reward: 0,
+        last_seen_stake_rewards_per_token: config.accumulated_stake_rewards_per_token,
+        last_seen_holder_rewards_per_token: vault_holder_rewards
+            .last_accumulated_rewards_per_token
+            .into(),
+    }
+    .set();
+
+    Ok(())
+}
@@ -0,0 +1,117 @@
+use solana_program::{
+    clock::Clock, entrypoint::ProgramResult, program_error::ProgramError, pubkey::Pubkey,
+    sysvar::Sysvar,
+};
+use spl_discriminator::{ArrayDiscriminator, SplDiscriminate};
+
+use crate::{
+    error::StakeError,
+    instruction::accounts::{Context, GetVotingPowerAccounts},
+    processor::{unpack_delegation_checked, unpack_initialized},
+    require,
+    return_data::VotingPowerReturnData,
+    state::{
+        find_delegated_voting_power_pda, Config, DelegatedVotingPower, SolStakerStake,
+        ValidatorStake,
+    },
+};
+
+/// Returns the voting power of the given stake or delegated voting power
+/// account at the end of a slot, without modifying any account.
+///
+/// The voting power of a stake account is the effective amount of the
+/// delegation, or `0` while it is delegated to a vote delegate; the voting
+/// power of a vote delegate is the sum of the effective amounts delegated to
+/// it. The voting power is set as return data. Only past slots can be queried,
+/// since the voting power at the end of the current slot can still change.
+/// Stake accounts created before versioning have no voting power history and
+/// must be migrated first.
+///
+/// Slots older than the retained history return a lower bound of the voting
+/// power (see `VotingPowerCheckpoints`).
+///
+/// 0. `[ ]` Config account
+/// 1. `[ ]` Validator or sol staker stake, or delegated voting power account
+pub fn process_get_voting_power(
+    program_id: &Pubkey,
+    ctx: Context<GetVotingPowerAccounts>,
+    slot: u64,
+) -> ProgramResult {
+    // slot
+    // - must be before the current slot
+    require!(
+        slot < Clock::get()?.slot,
+        StakeError::VotingPowerUnavailable,
+        "slot"
+    );
+
+    // config
+    // - owner must be the stake program
+    // - must be initialized
+    require!(
+        ctx.accounts.config.owner == program_id,
+        ProgramError::InvalidAccountOwner,
+        "config"
+    );
+    let config_data = ctx.accounts.config.try_borrow_data()?;
+    let _config = unpack_initialized::<Config>(&config_data)?;
+
+    // stake
+    // - owner must be the stake program
+    // - must be a ValidatorStake, SolStakerStake or DelegatedVotingPower
+    //   account
+    // - must be initialized
+    // - must have the correct derivation (validates the config account)
+    require!(
+        ctx.accounts.stake.owner == program_id,
+        ProgramError::InvalidAccountOwner,
+        "stake"
+    );
+    let stake_data = ctx.accounts.stake.try_borrow_data()?;
+
+    let (version, checkpoints) = match stake_data.get(..ArrayDiscriminator::LENGTH) {
+        Some(DelegatedVotingPower::SPL_DISCRIMINATOR_SLICE) => {
+            let delegated = unpack_initialized::<DelegatedVotingPower>(&stake_data)?;
+            let (derivation, _) = find_delegated_voting_power_pda(
+                &delegated.delegate,
+                ctx.accounts.config.key,
+                program_id,
+            );
+            require!(
+                ctx.accounts.stake.key == &derivation,
+                ProgramError::InvalidSeeds,
+                "delegated voting power"
+            );
+            (delegated.version, delegated.checkpoints)
+        }
+        _ => {
+            unpack_delegation_checked(
+                &stake_data,
+                ctx.accounts.stake.key,
+                ctx.accounts.config.key,
+                program_id,
+            )?;
+
+            match stake_data.get(..ArrayDiscriminator::LENGTH) {
+                Some(SolStakerStake::SPL_DISCRIMINATOR_SLICE) => {
+                    let stake = unpack_initialized::<SolStakerStake>(&stake_data)?;
+                    (stake.version, stake.checkpoints)
+                }
+                _ => {
+                    let stake = unpack_initialized::<ValidatorStake>(&stake_data)?;
+                    (stake.version, stake.checkpoints)
+                }
+            }
+        }
+    };
+
+    // The voting power history is only recorded once the account is migrated
+    // (legacy accounts are read with an empty history).
+    require!(version > 0, StakeError::AccountMigrationRequired, "stake");
+
+    let voting_power = checkpoints.voting_power_at(slot);
+
+    VotingPowerReturnData { slot, voting_power }.set();
+
+    Ok(())
+}
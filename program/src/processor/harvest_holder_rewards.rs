@@ -1,14 +1,15 @@
 use paladin_rewards_program_client::accounts::HolderRewards;
 use solana_program::{
-    entrypoint::ProgramResult, program::invoke_signed, program_error::ProgramError,
-    program_pack::Pack, pubkey::Pubkey,
+    entrypoint::ProgramResult, program::invoke_signed, program_error::ProgramError, pubkey::Pubkey,
 };
-use spl_token::state::Account as TokenAccount;
 
 use crate::{
     error::StakeError,
     instruction::accounts::{Context, HarvestHolderRewardsAccounts},
-    processor::{sync_config_lamports, transfer_excess_lamports, unpack_initialized_mut},
+    processor::{
+        sync_config_lamports, transfer_excess_lamports, unpack_initialized_mut,
+        unpack_token_account,
+    },
     require,
     state::{get_vault_pda_signer_seeds, Config},
 };
@@ -49,7 +50,8 @@ pub fn process_harvest_holder_rewards(
         "config"
     );
     let mut config_data = ctx.accounts.config.try_borrow_mut_data()?;
-    let config = unpack_initialized_mut::<Config>(&mut config_data)?;
+    let mut config = unpack_initialized_mut::<Config>(&mut config_data)?;
+    let config = &mut *config;
 
     // vault
     // - must be the token account on the stake config account
@@ -58,7 +60,7 @@ pub fn process_harvest_holder_rewards(
         StakeError::IncorrectVaultAccount,
     );
     let vault_data = ctx.accounts.vault.try_borrow_data()?;
-    let vault = TokenAccount::unpack(&vault_data)?;
+    let vault = unpack_token_account(&vault_data)?;
 
     // mint
     // - must match the stake vault mint
@@ -125,7 +127,8 @@ pub fn process_harvest_holder_rewards(
 
     // Update the configs last seen lamports again.
     let mut config = ctx.accounts.config.try_borrow_mut_data()?;
-    let config = unpack_initialized_mut::<Config>(&mut config)?;
+    let mut config = unpack_initialized_mut::<Config>(&mut config)?;
+    let config = &mut *config;
     config.lamports_last = ctx.accounts.config.lamports();
 
     Ok(())
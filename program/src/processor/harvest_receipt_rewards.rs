@@ -0,0 +1,121 @@
+use solana_program::{
+    clock::Clock, entrypoint::ProgramResult, program_error::ProgramError, pubkey::Pubkey,
+    sysvar::Sysvar,
+};
+
+use crate::{
+    error::StakeError,
+    instruction::accounts::{Context, HarvestReceiptRewardsAccounts},
+    processor::{sync_liquid_stake_pool_lamports, unpack_initialized_mut, unpack_token_account},
+    require,
+    state::{LiquidStakePool, ReceiptRewards},
+};
+
+/// Harvests the liquid stake pool rewards of a receipt token account.
+///
+/// NOTE: This instruction is permissionless; the rewards are always paid to the
+/// owner of the receipt token account.
+///
+/// 0. `[w]` Liquid stake pool
+/// 1. `[ ]` Receipt mint
+/// 2. `[ ]` Receipt token account
+/// 3. `[w]` Receipt rewards
+/// 4. `[w]` Receipt token account owner
+pub fn process_harvest_receipt_rewards(
+    program_id: &Pubkey,
+    ctx: Context<HarvestReceiptRewardsAccounts>,
+) -> ProgramResult {
+    // Accounts validation.
+
+    // liquid stake pool
+    // - owner must be this program
+    // - must be initialized
+    require!(
+        ctx.accounts.liquid_stake_pool.owner == program_id,
+        ProgramError::InvalidAccountOwner,
+        "liquid stake pool"
+    );
+    let mut pool_data = ctx.accounts.liquid_stake_pool.try_borrow_mut_data()?;
+    let mut pool = unpack_initialized_mut::<LiquidStakePool>(&mut pool_data)?;
+    let pool = &mut *pool;
+
+    // receipt mint
+    // - must match the pool receipt mint
+    require!(
+        ctx.accounts.receipt_mint.key == &pool.receipt_mint,
+        StakeError::InvalidMint,
+        "receipt mint"
+    );
+
+    // receipt rewards
+    // - owner must be this program
+    // - must be initialized
+    // - must belong to the pool and the receipt token account
+    require!(
+        ctx.accounts.receipt_rewards.owner == program_id,
+        ProgramError::InvalidAccountOwner,
+        "receipt rewards"
+    );
+    let mut receipt_rewards_data = ctx.accounts.receipt_rewards.try_borrow_mut_data()?;
+    let mut receipt_rewards = unpack_initialized_mut::<ReceiptRewards>(&mut receipt_rewards_data)?;
+    let receipt_rewards = &mut *receipt_rewards;
+    require!(
+        &receipt_rewards.liquid_stake_pool == ctx.accounts.liquid_stake_pool.key,
+        ProgramError::InvalidAccountData,
+        "receipt rewards pool"
+    );
+    require!(
+        &receipt_rewards.token_account == ctx.accounts.receipt_token_account.key,
+        ProgramError::InvalidAccountData,
+        "receipt rewards token account"
+    );
+
+    // receipt token account
+    // - owner must be the receipt token program
+    // - owner of the token account must be the recipient
+    require!(
+        ctx.accounts.receipt_token_account.owner == ctx.accounts.receipt_mint.owner,
+        ProgramError::InvalidAccountOwner,
+        "receipt token account"
+    );
+    let token_account_data = ctx.accounts.receipt_token_account.try_borrow_data()?;
+    let token_account = unpack_token_account(&token_account_data)?;
+    require!(
+        &token_account.owner == ctx.accounts.receipt_token_account_owner.key,
+        StakeError::InvalidDestinationAccount,
+        "receipt token account owner"
+    );
+
+    // Settle the rewards earned since the last sync.
+    sync_liquid_stake_pool_lamports(ctx.accounts.liquid_stake_pool, pool)?;
+    pool.settle(receipt_rewards, token_account.amount, Clock::get()?.epoch)?;
+
+    let amount = receipt_rewards.unclaimed_rewards;
+    receipt_rewards.unclaimed_rewards = 0;
+    pool.lamports_last = pool
+        .lamports_last
+        .checked_sub(amount)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+
+    // Move the lamports to the receipt token account owner.
+    let pool_lamports = ctx
+        .accounts
+        .liquid_stake_pool
+        .lamports()
+        .checked_sub(amount)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    let owner_lamports = ctx
+        .accounts
+        .receipt_token_account_owner
+        .lamports()
+        .checked_add(amount)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+
+    **ctx.accounts.liquid_stake_pool.try_borrow_mut_lamports()? = pool_lamports;
+    **ctx
+        .accounts
+        .receipt_token_account_owner
+        .try_borrow_mut_lamports()? = owner_lamports;
+
+    Ok(())
+}
@@ -10,8 +10,11 @@ use solana_program::{
 
 use crate::{
     error::StakeError,
+    events::{Event, LamportsSyncEvent},
     instruction::accounts::{Context, HarvestSolStakerRewardsAccounts},
-    processor::{harvest, sync_effective, unpack_initialized_mut, HarvestAccounts},
+    processor::{
+        harvest, sync_effective, unpack_initialized_mut, HarvestAccounts, VotingPowerAccounts,
+    },
     require,
     state::{
         find_sol_staker_stake_pda, find_validator_stake_pda, find_vault_pda, Config,
@@ -52,7 +55,8 @@ pub fn process_harvest_sol_staker_rewards(
         "config"
     );
     let mut config = ctx.accounts.config.data.borrow_mut();
-    let config = unpack_initialized_mut::<Config>(&mut config)?;
+    let mut config = unpack_initialized_mut::<Config>(&mut config)?;
+    let config = &mut *config;
 
     // sol staker stake
     // - owner must be the stake program
@@ -64,7 +68,9 @@ pub fn process_harvest_sol_staker_rewards(
         "sol staker stake"
     );
     let mut sol_staker_stake_data = ctx.accounts.sol_staker_stake.try_borrow_mut_data()?;
-    let sol_staker_stake = unpack_initialized_mut::<SolStakerStake>(&mut sol_staker_stake_data)?;
+    let mut sol_staker_stake =
+        unpack_initialized_mut::<SolStakerStake>(&mut sol_staker_stake_data)?;
+    let sol_staker_stake = &mut *sol_staker_stake;
     let (derivation, _) = find_sol_staker_stake_pda(
         &sol_staker_stake.sol_stake,
         ctx.accounts.config.key,
@@ -120,17 +126,24 @@ pub fn process_harvest_sol_staker_rewards(
     let stake_state_data =
         bytemuck::try_from_bytes::<GetStakeActivatingAndDeactivatingReturnData>(&return_data)
             .map_err(|_| ProgramError::InvalidAccountData)?;
-    let mut current_delegation = stake_state_data.delegated_vote.get().unwrap_or_default();
+    let current_delegation = stake_state_data.delegated_vote.get().unwrap_or_default();
     let mut current_stake = stake_state_data.effective.into();
-    let requires_sync = current_stake != sol_staker_stake.lamports_amount
-        || current_delegation != sol_staker_stake.delegation.validator_vote;
+    let was_awaiting_validator_stake = sol_staker_stake.awaiting_validator_stake != 0;
+    // NB: While the stake is awaiting the validator stake account, only its
+    // initialization (or a change of vote account) requires a sync.
+    let requires_sync = current_delegation != sol_staker_stake.delegation.validator_vote
+        || match was_awaiting_validator_stake {
+            true => ctx.accounts.current_validator_stake.owner == program_id,
+            false => current_stake != sol_staker_stake.lamports_amount,
+        };
 
     // Harvest the staker.
-    harvest(
+    let harvested = harvest(
         HarvestAccounts {
             config: ctx.accounts.config,
             vault_holder_rewards: ctx.accounts.vault_holder_rewards,
             authority: ctx.accounts.sol_staker_stake_authority,
+            stake: ctx.accounts.sol_staker_stake,
         },
         config,
         &vault_authority,
@@ -145,13 +158,40 @@ pub fn process_harvest_sol_staker_rewards(
         },
     )?;
 
-    // If no sync is required, then we are done.
+    // Voting power accounts of vote delegates (remaining accounts).
+    let voting_power = VotingPowerAccounts::new_optional(
+        program_id,
+        ctx.accounts.config.key,
+        ctx.remaining_accounts,
+    );
+
+    // If no sync is required, then we are done (after folding any tokens that
+    // finished activating into the effective amount).
     if !requires_sync {
+        sync_effective(
+            config,
+            &mut sol_staker_stake.delegation,
+            &mut sol_staker_stake.activation,
+            &mut sol_staker_stake.checkpoints,
+            (
+                sol_staker_stake.vote_delegate,
+                &mut sol_staker_stake.delegated_voting_power,
+            ),
+            (sol_staker_stake.lamports_amount, 0),
+            &voting_power,
+        )?;
+
+        // Return the harvested amounts.
+        harvested.set();
+
         return Ok(());
     }
 
-    // If the user has a previous delegation, their old stake is removed.
-    if sol_staker_stake.delegation.validator_vote != Pubkey::default() {
+    // If the user has a previous delegation, their old stake is removed (unless
+    // it was never credited to the validator).
+    if sol_staker_stake.delegation.validator_vote != Pubkey::default()
+        && !was_awaiting_validator_stake
+    {
         // Previous validator.
         // - owner must be the stake program
         // - must have the correct derivation (validates both the validator vote
@@ -177,8 +217,9 @@ pub fn process_harvest_sol_staker_rewards(
             .accounts
             .previous_validator_stake
             .try_borrow_mut_data()?;
-        let previous_validator_stake =
+        let mut previous_validator_stake =
             unpack_initialized_mut::<ValidatorStake>(&mut previous_validator_data)?;
+        let previous_validator_stake = &mut *previous_validator_stake;
 
         // Harvest the previous validator to flush rewards before we update their stake.
         harvest(
@@ -186,6 +227,7 @@ pub fn process_harvest_sol_staker_rewards(
                 config: ctx.accounts.config,
                 vault_holder_rewards: ctx.accounts.vault_holder_rewards,
                 authority: ctx.accounts.previous_validator_stake_authority,
+                stake: ctx.accounts.previous_validator_stake,
             },
             config,
             &vault_authority,
@@ -203,16 +245,24 @@ pub fn process_harvest_sol_staker_rewards(
         sync_effective(
             config,
             &mut previous_validator_stake.delegation,
+            &mut previous_validator_stake.activation,
+            &mut previous_validator_stake.checkpoints,
+            (
+                previous_validator_stake.vote_delegate,
+                &mut previous_validator_stake.delegated_voting_power,
+            ),
             (
                 previous_validator_stake.total_staked_lamports_amount,
                 previous_validator_stake.total_staked_lamports_amount_min,
             ),
+            &voting_power,
         )?;
     } else {
         assert_eq!(sol_staker_stake.lamports_amount, 0);
     }
 
     // If the user has a current delegation, their new stake is added here.
+    let mut awaiting_validator_stake = false;
     if current_delegation != Pubkey::default() {
         // Current validator.
         // - owner must be the stake program
@@ -232,8 +282,9 @@ pub fn process_harvest_sol_staker_rewards(
         if ctx.accounts.current_validator_stake.owner == program_id {
             let mut current_validator_data =
                 ctx.accounts.current_validator_stake.try_borrow_mut_data()?;
-            let current_validator_stake =
+            let mut current_validator_stake =
                 unpack_initialized_mut::<ValidatorStake>(&mut current_validator_data)?;
+            let current_validator_stake = &mut *current_validator_stake;
 
             // Harvest the current validator to flush rewards before we update their stake.
             harvest(
@@ -241,6 +292,7 @@ pub fn process_harvest_sol_staker_rewards(
                     config: ctx.accounts.config,
                     vault_holder_rewards: ctx.accounts.vault_holder_rewards,
                     authority: ctx.accounts.current_validator_stake_authority,
+                    stake: ctx.accounts.current_validator_stake,
                 },
                 config,
                 &vault_authority,
@@ -258,21 +310,55 @@ pub fn process_harvest_sol_staker_rewards(
             sync_effective(
                 config,
                 &mut current_validator_stake.delegation,
+                &mut current_validator_stake.activation,
+                &mut current_validator_stake.checkpoints,
+                (
+                    current_validator_stake.vote_delegate,
+                    &mut current_validator_stake.delegated_voting_power,
+                ),
                 (
                     current_validator_stake.total_staked_lamports_amount,
                     current_validator_stake.total_staked_lamports_amount_min,
                 ),
+                &voting_power,
             )?;
         } else {
-            current_delegation = Pubkey::default();
+            // The validator does not have a stake account yet, so the vote account
+            // is recorded and the stake is credited on a later sync.
+            awaiting_validator_stake = true;
             current_stake = 0;
         }
     }
 
     // Finally, the user's stake is updated.
+    let event = LamportsSyncEvent {
+        config: *ctx.accounts.config.key,
+        stake: *ctx.accounts.sol_staker_stake.key,
+        previous_validator_vote: sol_staker_stake.delegation.validator_vote,
+        current_validator_vote: current_delegation,
+        previous_lamports: sol_staker_stake.lamports_amount,
+        current_lamports: current_stake,
+    };
     sol_staker_stake.lamports_amount = current_stake;
     sol_staker_stake.delegation.validator_vote = current_delegation;
-    sync_effective(config, &mut sol_staker_stake.delegation, (current_stake, 0))?;
+    sol_staker_stake.awaiting_validator_stake = awaiting_validator_stake.into();
+    sync_effective(
+        config,
+        &mut sol_staker_stake.delegation,
+        &mut sol_staker_stake.activation,
+        &mut sol_staker_stake.checkpoints,
+        (
+            sol_staker_stake.vote_delegate,
+            &mut sol_staker_stake.delegated_voting_power,
+        ),
+        (current_stake, 0),
+        &voting_power,
+    )?;
+
+    event.emit();
+
+    // Return the harvested amounts.
+    harvested.set();
 
     Ok(())
 }
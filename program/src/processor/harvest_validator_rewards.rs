@@ -4,7 +4,9 @@ use solana_program::{entrypoint::ProgramResult, program_error::ProgramError, pub
 use crate::{
     error::StakeError,
     instruction::accounts::{Context, HarvestValidatorRewardsAccounts},
-    processor::{harvest, unpack_initialized_mut, HarvestAccounts},
+    processor::{
+        harvest, sync_effective, unpack_initialized_mut, HarvestAccounts, VotingPowerAccounts,
+    },
     require,
     state::{find_validator_stake_pda, find_vault_pda, Config, ValidatorStake},
 };
@@ -15,6 +17,9 @@ use crate::{
 /// staking rewards are held in a separate account, they must be distributed
 /// based on the proportion of total stake.
 ///
+/// Tokens that finished activating are folded into the effective amount after
+/// the rewards are harvested.
+///
 /// 0. `[w]` Config account
 /// 1. `[ ]` Vault holder rewards
 /// 2. `[w]` Validator stake
@@ -34,7 +39,8 @@ pub fn process_harvest_validator_rewards(
         "config"
     );
     let mut config = ctx.accounts.config.data.borrow_mut();
-    let config = unpack_initialized_mut::<Config>(&mut config)?;
+    let mut config = unpack_initialized_mut::<Config>(&mut config)?;
+    let config = &mut *config;
 
     // stake
     // - owner must be the stake program
@@ -46,7 +52,8 @@ pub fn process_harvest_validator_rewards(
         "validator stake"
     );
     let mut validator_stake_data = ctx.accounts.validator_stake.try_borrow_mut_data()?;
-    let validator_stake = unpack_initialized_mut::<ValidatorStake>(&mut validator_stake_data)?;
+    let mut validator_stake = unpack_initialized_mut::<ValidatorStake>(&mut validator_stake_data)?;
+    let validator_stake = &mut *validator_stake;
     let (derivation, _) = find_validator_stake_pda(
         &validator_stake.delegation.validator_vote,
         ctx.accounts.config.key,
@@ -77,11 +84,12 @@ pub fn process_harvest_validator_rewards(
     );
 
     // Process the harvest.
-    harvest(
+    let harvested = harvest(
         HarvestAccounts {
             config: ctx.accounts.config,
             vault_holder_rewards: ctx.accounts.vault_holder_rewards,
             authority: ctx.accounts.validator_stake_authority,
+            stake: ctx.accounts.validator_stake,
         },
         config,
         &vault_authority,
@@ -89,5 +97,30 @@ pub fn process_harvest_validator_rewards(
         None,
     )?;
 
+    // Fold any tokens that finished activating into the effective amount.
+    let voting_power = VotingPowerAccounts::new_optional(
+        program_id,
+        ctx.accounts.config.key,
+        ctx.remaining_accounts,
+    );
+    sync_effective(
+        config,
+        &mut validator_stake.delegation,
+        &mut validator_stake.activation,
+        &mut validator_stake.checkpoints,
+        (
+            validator_stake.vote_delegate,
+            &mut validator_stake.delegated_voting_power,
+        ),
+        (
+            validator_stake.total_staked_lamports_amount,
+            validator_stake.total_staked_lamports_amount_min,
+        ),
+        &voting_power,
+    )?;
+
+    // Return the harvested amounts.
+    harvested.set();
+
     Ok(())
 }
@@ -1,12 +1,15 @@
 use paladin_rewards_program_client::accounts::HolderRewards;
 use solana_program::{
     entrypoint::ProgramResult, program::invoke_signed, program_error::ProgramError,
-    program_option::COption, program_pack::Pack, pubkey::Pubkey, rent::Rent, system_instruction,
-    sysvar::Sysvar,
+    program_option::COption, pubkey::Pubkey, rent::Rent, system_instruction, sysvar::Sysvar,
 };
 use spl_discriminator::SplDiscriminate;
 use spl_pod::optional_keys::OptionalNonZeroPubkey;
-use spl_token::state::{Account as TokenAccount, Mint};
+use spl_token_2022::{
+    check_spl_token_program_account,
+    extension::{BaseStateWithExtensions, ExtensionType, StateWithExtensions},
+    state::{Account as TokenAccount, Mint},
+};
 
 use crate::{
     error::StakeError,
@@ -30,10 +33,31 @@ pub fn process_initialize_config(
     // Accounts validation.
 
     // mint
+    // - owner must be SPL Token or Token-2022
     // - must be initialized (checked by unpack)
-    // - have rewards transfer hook
+    // - only the transfer hook, metadata and group extensions, since others
+    //   could move tokens out of (or withhold them from) the vault, or block
+    //   transfers to and from it
+    check_spl_token_program_account(ctx.accounts.mint.owner)?;
     let mint_data = ctx.accounts.mint.try_borrow_data()?;
-    Mint::unpack(&mint_data)?;
+    let mint = StateWithExtensions::<Mint>::unpack(&mint_data)?;
+    for extension in mint.get_extension_types()? {
+        require!(
+            matches!(
+                extension,
+                ExtensionType::TransferHook
+                    | ExtensionType::MetadataPointer
+                    | ExtensionType::TokenMetadata
+                    | ExtensionType::GroupPointer
+                    | ExtensionType::TokenGroup
+                    | ExtensionType::GroupMemberPointer
+                    | ExtensionType::TokenGroupMember
+            ),
+            StakeError::InvalidMintExtension,
+            "mint extension {:?}",
+            extension
+        );
+    }
 
     // Verify vault PDA
     let (vault_signer, signer_bump) = find_vault_pda(ctx.accounts.config.key, program_id);
@@ -69,14 +93,33 @@ pub fn process_initialize_config(
     );
 
     // vault (token account)
+    // - owner must be the token program of the mint
     // - must be initialized (checked by unpack)
+    // - only the immutable owner and transfer hook account extensions
     // - have the vault signer (PDA) as owner
     // - no close authority
     // - no delegate
     // - have the correct mint
     // - amount equal to 0
+    require!(
+        ctx.accounts.vault.owner == ctx.accounts.mint.owner,
+        ProgramError::InvalidAccountOwner,
+        "vault"
+    );
     let vault_data = ctx.accounts.vault.try_borrow_data()?;
-    let vault = TokenAccount::unpack(&vault_data)?;
+    let vault_state = StateWithExtensions::<TokenAccount>::unpack(&vault_data)?;
+    for extension in vault_state.get_extension_types()? {
+        require!(
+            matches!(
+                extension,
+                ExtensionType::ImmutableOwner | ExtensionType::TransferHookAccount
+            ),
+            StakeError::InvalidTokenAccountExtension,
+            "vault extension {:?}",
+            extension
+        );
+    }
+    let vault = vault_state.base;
     require!(
         vault.owner == vault_signer,
         StakeError::InvalidTokenOwner,
@@ -153,7 +196,19 @@ pub fn process_initialize_config(
         duna_document_hash,
         sync_rewards_lamports,
         vault_authority_bump: signer_bump[0],
-        _padding: [0; 5],
+        version: Config::VERSION,
+        _padding: [0; 4],
+        reward_streaming_period_seconds: 0,
+        reward_period_end: 0,
+        reward_last_update: 0,
+        reward_rate: 0.into(),
+        stake_rewards_remainder: 0,
+        undistributed_rewards: 0,
+        fee_recipient: OptionalNonZeroPubkey::default(),
+        unclaimed_protocol_fees: 0,
+        protocol_fee_basis_points: 0,
+        _fee_padding: [0; 6],
+        reward_stream_remainder: 0,
     };
 
     Ok(())
@@ -0,0 +1,117 @@
+use solana_program::{
+    entrypoint::ProgramResult, program::invoke_signed, program_error::ProgramError, pubkey::Pubkey,
+    rent::Rent, system_instruction, sysvar::Sysvar,
+};
+
+use crate::{
+    instruction::accounts::{Context, InitializeConfigAccounts, InitializeConfigPdaAccounts},
+    processor::initialize_config::process_initialize_config,
+    require,
+    state::{find_config_pda, get_config_pda_signer_seeds, Config},
+};
+
+/// Creates the stake config account at the PDA derived from the stake mint,
+/// the config authority and a namespace.
+///
+/// NOTE: The config account is created and assigned to the stake program, so
+/// it needs to be pre-funded with the minimum rent balance by the caller. The
+/// remaining validation and initialization is shared with `InitializeConfig`.
+///
+/// 0. `[w]` Config account
+/// 1. `[ ]` Stake token mint
+/// 2. `[w]` Vault PDA
+/// 3. `[ ]` Vault token account
+/// 4. `[w]` Vault holder rewards
+/// 5. `[ ]` System program
+/// 6. `[ ]` Paladin rewards program
+/// 7. `[s]` Config authority
+#[allow(clippy::too_many_arguments)]
+pub fn process_initialize_config_pda(
+    program_id: &Pubkey,
+    ctx: Context<InitializeConfigPdaAccounts>,
+    namespace: u64,
+    slash_authority: Pubkey,
+    cooldown_time_seconds: u64,
+    max_deactivation_basis_points: u16,
+    sync_rewards_lamports: u64,
+    duna_document_hash: [u8; 32],
+) -> ProgramResult {
+    // Accounts validation.
+
+    // config_authority
+    // - must be a signer
+    require!(
+        ctx.accounts.config_authority.is_signer,
+        ProgramError::MissingRequiredSignature,
+        "config_authority"
+    );
+    let config_authority = *ctx.accounts.config_authority.key;
+
+    // config
+    // - have the correct PDA derivation
+    // - be uninitialized (empty data)
+    // - be rent exempt
+    let (derivation, bump) = find_config_pda(
+        ctx.accounts.mint.key,
+        &config_authority,
+        namespace,
+        program_id,
+    );
+    require!(
+        ctx.accounts.config.key == &derivation,
+        ProgramError::InvalidSeeds,
+        "config"
+    );
+    require!(
+        ctx.accounts.config.data_is_empty(),
+        ProgramError::AccountAlreadyInitialized,
+        "config"
+    );
+    require!(
+        ctx.accounts.config.lamports() >= Rent::get()?.minimum_balance(Config::LEN),
+        ProgramError::AccountNotRentExempt,
+        "config",
+    );
+
+    // Allocate and assign.
+    let namespace_seed = namespace.to_le_bytes();
+    let bump_seed = [bump];
+    let signer_seeds = get_config_pda_signer_seeds(
+        ctx.accounts.mint.key,
+        &config_authority,
+        &namespace_seed,
+        &bump_seed,
+    );
+    invoke_signed(
+        &system_instruction::allocate(ctx.accounts.config.key, Config::LEN as u64),
+        &[ctx.accounts.config.clone()],
+        &[&signer_seeds],
+    )?;
+    invoke_signed(
+        &system_instruction::assign(ctx.accounts.config.key, program_id),
+        &[ctx.accounts.config.clone()],
+        &[&signer_seeds],
+    )?;
+
+    process_initialize_config(
+        program_id,
+        Context {
+            accounts: InitializeConfigAccounts {
+                config: ctx.accounts.config,
+                mint: ctx.accounts.mint,
+                vault_pda: ctx.accounts.vault_pda,
+                vault: ctx.accounts.vault,
+                vault_holder_rewards: ctx.accounts.vault_holder_rewards,
+                system_program: ctx.accounts.system_program,
+                rewards_program: ctx.accounts.rewards_program,
+            },
+            remaining_accounts: ctx.remaining_accounts,
+        },
+        slash_authority,
+        config_authority,
+        cooldown_time_seconds,
+        max_deactivation_basis_points,
+        sync_rewards_lamports,
+        duna_document_hash,
+    )
+}
@@ -0,0 +1,106 @@
+use solana_program::{
+    entrypoint::ProgramResult, program::invoke_signed, program_error::ProgramError, pubkey::Pubkey,
+    rent::Rent, system_instruction, sysvar::Sysvar,
+};
+use spl_discriminator::SplDiscriminate;
+
+use crate::{
+    instruction::accounts::{Context, InitializeDelegatedVotingPowerAccounts},
+    processor::unpack_initialized,
+    require,
+    state::{
+        find_delegated_voting_power_pda, get_delegated_voting_power_pda_signer_seeds, Config,
+        DelegatedVotingPower, VotingPowerCheckpoints,
+    },
+};
+
+/// Initializes the account aggregating the voting power delegated to a vote
+/// delegate.
+///
+/// NOTE: Anybody can create the account for a delegate. The account is created
+/// and assigned to the stake program, so it needs to be pre-funded with the
+/// minimum rent balance by the caller.
+///
+/// 0. `[ ]` Stake config
+/// 1. `[ ]` Vote delegate
+/// 2. `[w]` Delegated voting power
+/// 3. `[ ]` System program
+pub fn process_initialize_delegated_voting_power(
+    program_id: &Pubkey,
+    ctx: Context<InitializeDelegatedVotingPowerAccounts>,
+) -> ProgramResult {
+    // Accounts validation.
+
+    // config
+    // - owner must be this program
+    // - must be initialized
+    require!(
+        ctx.accounts.config.owner == program_id,
+        ProgramError::InvalidAccountOwner,
+        "config"
+    );
+    let data = ctx.accounts.config.try_borrow_data()?;
+    let _config = unpack_initialized::<Config>(&data)?;
+
+    // delegated voting power
+    // - have the correct PDA derivation
+    // - be uninitialized (empty data)
+    // - be rent exempt
+    let (derivation, bump) = find_delegated_voting_power_pda(
+        ctx.accounts.vote_delegate.key,
+        ctx.accounts.config.key,
+        program_id,
+    );
+    require!(
+        ctx.accounts.delegated_voting_power.key == &derivation,
+        ProgramError::InvalidSeeds,
+        "delegated voting power"
+    );
+    require!(
+        ctx.accounts.delegated_voting_power.data_is_empty(),
+        ProgramError::AccountAlreadyInitialized,
+        "delegated voting power"
+    );
+    require!(
+        ctx.accounts.delegated_voting_power.lamports()
+            >= Rent::get()?.minimum_balance(DelegatedVotingPower::LEN),
+        ProgramError::AccountNotRentExempt,
+        "delegated voting power",
+    );
+
+    // Allocate and assign.
+    let bump_seed = [bump];
+    let signer_seeds = get_delegated_voting_power_pda_signer_seeds(
+        ctx.accounts.vote_delegate.key,
+        ctx.accounts.config.key,
+        &bump_seed,
+    );
+    invoke_signed(
+        &system_instruction::allocate(
+            ctx.accounts.delegated_voting_power.key,
+            DelegatedVotingPower::LEN as u64,
+        ),
+        &[ctx.accounts.delegated_voting_power.clone()],
+        &[&signer_seeds],
+    )?;
+    invoke_signed(
+        &system_instruction::assign(ctx.accounts.delegated_voting_power.key, program_id),
+        &[ctx.accounts.delegated_voting_power.clone()],
+        &[&signer_seeds],
+    )?;
+
+    // Initialize the delegated voting power account.
+    let mut data = ctx.accounts.delegated_voting_power.try_borrow_mut_data()?;
+    let delegated_voting_power = bytemuck::from_bytes_mut::<DelegatedVotingPower>(&mut data);
+    *delegated_voting_power = DelegatedVotingPower {
+        _discriminator: DelegatedVotingPower::SPL_DISCRIMINATOR.into(),
+        config: *ctx.accounts.config.key,
+        delegate: *ctx.accounts.vote_delegate.key,
+        voting_power: 0,
+        version: DelegatedVotingPower::VERSION,
+        _padding: [0; 7],
+        checkpoints: VotingPowerCheckpoints::default(),
+    };
+
+    Ok(())
+}
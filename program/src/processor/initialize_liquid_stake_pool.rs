@@ -0,0 +1,197 @@
+use solana_program::{
+    entrypoint::ProgramResult, program::invoke_signed, program_error::ProgramError,
+    program_option::COption, pubkey::Pubkey, rent::Rent, system_instruction, sysvar::Sysvar,
+};
+use spl_discriminator::SplDiscriminate;
+
+use crate::{
+    error::StakeError,
+    instruction::accounts::{Context, InitializeLiquidStakePoolAccounts},
+    processor::{check_token_program, unpack_initialized, unpack_mint, unpack_token_account},
+    require,
+    state::{
+        find_liquid_stake_pool_pda, find_validator_stake_pda,
+        get_liquid_stake_pool_pda_signer_seeds, Config, LiquidStakePool, ValidatorStake,
+    },
+};
+
+/// Initializes the liquid stake pool of a validator stake account.
+///
+/// NOTE: The validator stake account must have the pool PDA as its authority
+/// (set through the validator authority override) and no staked tokens. The
+/// receipt mint must be empty, use the decimals of the stake mint and have the
+/// pool PDA as its mint authority and no freeze authority. The pool account
+/// needs to be pre-funded with the minimum rent balance by the caller.
+///
+/// 0. `[ ]` Stake config
+/// 1. `[ ]` Validator stake
+/// 2. `[w]` Liquid stake pool
+/// 3. `[ ]` Vault
+/// 4. `[ ]` Stake mint
+/// 5. `[ ]` Receipt mint
+/// 6. `[ ]` Receipt token program
+/// 7. `[ ]` System program
+pub fn process_initialize_liquid_stake_pool(
+    program_id: &Pubkey,
+    ctx: Context<InitializeLiquidStakePoolAccounts>,
+) -> ProgramResult {
+    // Accounts validation.
+
+    // config
+    // - owner must be this program
+    // - must be initialized
+    require!(
+        ctx.accounts.config.owner == program_id,
+        ProgramError::InvalidAccountOwner,
+        "config"
+    );
+    let config_data = ctx.accounts.config.try_borrow_data()?;
+    let config = unpack_initialized::<Config>(&config_data)?;
+
+    // validator stake
+    // - owner must be this program
+    // - must be initialized
+    // - must have the correct derivation
+    // - must not have staked tokens
+    require!(
+        ctx.accounts.validator_stake.owner == program_id,
+        ProgramError::InvalidAccountOwner,
+        "validator stake"
+    );
+    let stake_data = ctx.accounts.validator_stake.try_borrow_data()?;
+    let stake = unpack_initialized::<ValidatorStake>(&stake_data)?;
+    let (derivation, _) = find_validator_stake_pda(
+        &stake.delegation.validator_vote,
+        ctx.accounts.config.key,
+        program_id,
+    );
+    require!(
+        ctx.accounts.validator_stake.key == &derivation,
+        ProgramError::InvalidSeeds,
+        "validator stake",
+    );
+    require!(
+        stake.delegation.staked_amount == 0,
+        StakeError::InvalidAmount,
+        "validator stake has staked tokens"
+    );
+
+    // liquid stake pool
+    // - have the correct PDA derivation
+    // - be uninitialized (empty data)
+    // - be rent exempt
+    // - be the authority of the validator stake
+    let (derivation, bump) =
+        find_liquid_stake_pool_pda(ctx.accounts.validator_stake.key, program_id);
+    require!(
+        ctx.accounts.liquid_stake_pool.key == &derivation,
+        ProgramError::InvalidSeeds,
+        "liquid stake pool"
+    );
+    require!(
+        ctx.accounts.liquid_stake_pool.data_is_empty(),
+        ProgramError::AccountAlreadyInitialized,
+        "liquid stake pool"
+    );
+    require!(
+        ctx.accounts.liquid_stake_pool.lamports()
+            >= Rent::get()?.minimum_balance(LiquidStakePool::LEN),
+        ProgramError::AccountNotRentExempt,
+        "liquid stake pool",
+    );
+    require!(
+        stake.delegation.authority == derivation,
+        StakeError::InvalidAuthority,
+        "validator stake authority"
+    );
+
+    // vault
+    // - must be the token account on the stake config account
+    require!(
+        ctx.accounts.vault.key == &config.vault,
+        StakeError::IncorrectVaultAccount,
+    );
+    let vault_data = ctx.accounts.vault.try_borrow_data()?;
+    let vault = unpack_token_account(&vault_data)?;
+
+    // mint
+    // - must match the stake vault mint
+    require!(
+        &vault.mint == ctx.accounts.mint.key,
+        StakeError::InvalidMint,
+        "mint"
+    );
+    let mint_data = ctx.accounts.mint.try_borrow_data()?;
+    let mint = unpack_mint(&mint_data)?;
+
+    // receipt token program
+    // - must be SPL Token or Token-2022 and own the receipt mint
+    check_token_program(
+        ctx.accounts.receipt_token_program,
+        ctx.accounts.receipt_mint,
+    )?;
+
+    // receipt mint
+    // - must have the pool as its mint authority
+    // - must not have a freeze authority
+    // - must have no supply
+    // - must use the decimals of the stake mint
+    let receipt_mint_data = ctx.accounts.receipt_mint.try_borrow_data()?;
+    let receipt_mint = unpack_mint(&receipt_mint_data)?;
+    require!(
+        receipt_mint.mint_authority == COption::Some(derivation),
+        StakeError::InvalidAuthority,
+        "receipt mint authority"
+    );
+    require!(
+        receipt_mint.freeze_authority.is_none(),
+        StakeError::InvalidMint,
+        "receipt mint freeze authority"
+    );
+    require!(
+        receipt_mint.supply == 0,
+        StakeError::InvalidMint,
+        "receipt mint supply"
+    );
+    require!(
+        receipt_mint.decimals == mint.decimals,
+        StakeError::InvalidMint,
+        "receipt mint decimals"
+    );
+
+    // Allocate and assign.
+    let bump_seed = [bump];
+    let signer_seeds =
+        get_liquid_stake_pool_pda_signer_seeds(ctx.accounts.validator_stake.key, &bump_seed);
+    invoke_signed(
+        &system_instruction::allocate(
+            ctx.accounts.liquid_stake_pool.key,
+            LiquidStakePool::LEN as u64,
+        ),
+        &[ctx.accounts.liquid_stake_pool.clone()],
+        &[&signer_seeds],
+    )?;
+    invoke_signed(
+        &system_instruction::assign(ctx.accounts.liquid_stake_pool.key, program_id),
+        &[ctx.accounts.liquid_stake_pool.clone()],
+        &[&signer_seeds],
+    )?;
+
+    // Initialize the liquid stake pool account.
+    let mut data = ctx.accounts.liquid_stake_pool.try_borrow_mut_data()?;
+    let pool = bytemuck::from_bytes_mut::<LiquidStakePool>(&mut data);
+    *pool = LiquidStakePool {
+        _discriminator: LiquidStakePool::SPL_DISCRIMINATOR.into(),
+        config: *ctx.accounts.config.key,
+        validator_stake: *ctx.accounts.validator_stake.key,
+        receipt_mint: *ctx.accounts.receipt_mint.key,
+        accumulated_rewards_per_token: 0.into(),
+        rewards_remainder: 0,
+        lamports_last: ctx.accounts.liquid_stake_pool.lamports(),
+        tracked_supply: 0,
+        version: LiquidStakePool::VERSION,
+        _padding: [0; 7],
+    };
+
+    Ok(())
+}
@@ -0,0 +1,141 @@
+use solana_program::{
+    clock::Clock, entrypoint::ProgramResult, program::invoke_signed, program_error::ProgramError,
+    pubkey::Pubkey, rent::Rent, system_instruction, sysvar::Sysvar,
+};
+use spl_discriminator::SplDiscriminate;
+
+use crate::{
+    error::StakeError,
+    instruction::accounts::{Context, InitializeReceiptRewardsAccounts},
+    processor::{sync_liquid_stake_pool_lamports, unpack_initialized_mut, unpack_token_account},
+    require,
+    state::{
+        find_receipt_rewards_pda, get_receipt_rewards_pda_signer_seeds, LiquidStakePool,
+        ReceiptRewards,
+    },
+};
+
+/// Initializes the rewards tracking account of a receipt token account.
+///
+/// NOTE: Anybody can create the account for a receipt token account. Rewards
+/// are only earned from the moment the account is created. The account is
+/// created and assigned to the stake program, so it needs to be pre-funded with
+/// the minimum rent balance by the caller.
+///
+/// 0. `[w]` Liquid stake pool
+/// 1. `[ ]` Receipt mint
+/// 2. `[ ]` Receipt token account
+/// 3. `[w]` Receipt rewards
+/// 4. `[ ]` System program
+pub fn process_initialize_receipt_rewards(
+    program_id: &Pubkey,
+    ctx: Context<InitializeReceiptRewardsAccounts>,
+) -> ProgramResult {
+    // Accounts validation.
+
+    // liquid stake pool
+    // - owner must be this program
+    // - must be initialized
+    require!(
+        ctx.accounts.liquid_stake_pool.owner == program_id,
+        ProgramError::InvalidAccountOwner,
+        "liquid stake pool"
+    );
+    let mut pool_data = ctx.accounts.liquid_stake_pool.try_borrow_mut_data()?;
+    let mut pool = unpack_initialized_mut::<LiquidStakePool>(&mut pool_data)?;
+    let pool = &mut *pool;
+
+    // receipt mint
+    // - must match the pool receipt mint
+    require!(
+        ctx.accounts.receipt_mint.key == &pool.receipt_mint,
+        StakeError::InvalidMint,
+        "receipt mint"
+    );
+
+    // receipt token account
+    // - owner must be the receipt token program
+    // - must be a token account of the receipt mint
+    require!(
+        ctx.accounts.receipt_token_account.owner == ctx.accounts.receipt_mint.owner,
+        ProgramError::InvalidAccountOwner,
+        "receipt token account"
+    );
+    let token_account_data = ctx.accounts.receipt_token_account.try_borrow_data()?;
+    let token_account = unpack_token_account(&token_account_data)?;
+    require!(
+        token_account.mint == pool.receipt_mint,
+        StakeError::InvalidMint,
+        "receipt token account"
+    );
+
+    // receipt rewards
+    // - have the correct PDA derivation
+    // - be uninitialized (empty data)
+    // - be rent exempt
+    let (derivation, bump) = find_receipt_rewards_pda(
+        ctx.accounts.receipt_token_account.key,
+        ctx.accounts.liquid_stake_pool.key,
+        program_id,
+    );
+    require!(
+        ctx.accounts.receipt_rewards.key == &derivation,
+        ProgramError::InvalidSeeds,
+        "receipt rewards"
+    );
+    require!(
+        ctx.accounts.receipt_rewards.data_is_empty(),
+        ProgramError::AccountAlreadyInitialized,
+        "receipt rewards"
+    );
+    require!(
+        ctx.accounts.receipt_rewards.lamports()
+            >= Rent::get()?.minimum_balance(ReceiptRewards::LEN),
+        ProgramError::AccountNotRentExempt,
+        "receipt rewards",
+    );
+
+    // Account for the rewards received by the pool, so they are not paid to
+    // the new account.
+    sync_liquid_stake_pool_lamports(ctx.accounts.liquid_stake_pool, pool)?;
+
+    // Allocate and assign.
+    let bump_seed = [bump];
+    let signer_seeds = get_receipt_rewards_pda_signer_seeds(
+        ctx.accounts.receipt_token_account.key,
+        ctx.accounts.liquid_stake_pool.key,
+        &bump_seed,
+    );
+    invoke_signed(
+        &system_instruction::allocate(ctx.accounts.receipt_rewards.key, ReceiptRewards::LEN as u64),
+        &[ctx.accounts.receipt_rewards.clone()],
+        &[&signer_seeds],
+    )?;
+    invoke_signed(
+        &system_instruction::assign(ctx.accounts.receipt_rewards.key, program_id),
+        &[ctx.accounts.receipt_rewards.clone()],
+        &[&signer_seeds],
+    )?;
+
+    // Initialize the receipt rewards account.
+    let mut data = ctx.accounts.receipt_rewards.try_borrow_mut_data()?;
+    let receipt_rewards = bytemuck::from_bytes_mut::<ReceiptRewards>(&mut data);
+    *receipt_rewards = ReceiptRewards {
+        _discriminator: ReceiptRewards::SPL_DISCRIMINATOR.into(),
+        liquid_stake_pool: *ctx.accounts.liquid_stake_pool.key,
+        token_account: *ctx.accounts.receipt_token_account.key,
+        last_accumulated_rewards_per_token: pool.accumulated_rewards_per_token,
+        balance: 0,
+        unclaimed_rewards: 0,
+        activating_balance: 0,
+        activation_epoch: 0,
+        version: ReceiptRewards::VERSION,
+        _padding: [0; 7],
+    };
+
+    // Start tracking the receipt tokens of the account, which are activating
+    // until the end of the epoch.
+    pool.record_balance(receipt_rewards, token_account.amount, Clock::get()?.epoch)?;
+
+    Ok(())
+}
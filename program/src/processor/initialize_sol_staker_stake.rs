@@ -1,4 +1,3 @@
-use arrayref::array_ref;
 use paladin_sol_stake_view_program_client::{
     instructions::GetStakeActivatingAndDeactivatingCpiBuilder,
     GetStakeActivatingAndDeactivatingReturnData,
@@ -13,17 +12,21 @@ use solana_program::{
     sysvar::Sysvar,
 };
 use spl_discriminator::SplDiscriminate;
+use spl_pod::optional_keys::OptionalNonZeroPubkey;
 
 use crate::{
     err,
     error::StakeError,
     instruction::accounts::{Context, InitializeSolStakerStakeAccounts},
-    processor::{check_duna_document_signed, unpack_initialized, unpack_initialized_mut},
+    processor::{
+        check_duna_document_signed, unpack_authority_override, unpack_initialized,
+        unpack_initialized_mut,
+    },
     require,
     state::{
         find_sol_staker_authority_override_pda, find_sol_staker_stake_pda,
-        find_validator_stake_pda, get_sol_staker_stake_pda_signer_seeds, Config, Delegation,
-        SolStakerStake, ValidatorStake,
+        find_validator_stake_pda, get_sol_staker_stake_pda_signer_seeds, Activation, Config,
+        Delegation, SolStakerStake, ValidatorStake, VotingPowerCheckpoints,
     },
 };
 
@@ -34,6 +37,10 @@ use crate::{
 ///
 /// NOTE: Anybody can create the stake account for a SOL staker. For new
 /// accounts, the authority is initialized to the stake state account's withdrawer.
+///
+/// When the validator of the stake state does not have a `ValidatorStake`
+/// account yet, the stake account is created awaiting it and its lamports are
+/// credited to the validator on the first sync after it is initialized.
 #[allow(clippy::useless_conversion)]
 pub fn process_initialize_sol_staker_stake(
     program_id: &Pubkey,
@@ -89,14 +96,9 @@ pub fn process_initialize_sol_staker_stake(
     )?;
 
     // validator stake
-    // - owner must be the stake program
     // - must have the correct derivation
-    // - must be initialized
-    require!(
-        ctx.accounts.validator_stake.owner == program_id,
-        ProgramError::InvalidAccountOwner,
-        "validator stake"
-    );
+    // - must be initialized when owned by the stake program (otherwise the
+    //   validator does not have a stake account yet)
 
     // Validator vote must match the stake state account's validator vote (validation
     // done on the derivation of the expected address).
@@ -107,8 +109,15 @@ pub fn process_initialize_sol_staker_stake(
         ProgramError::InvalidSeeds,
         "validator stake",
     );
-    let mut stake_data = ctx.accounts.validator_stake.try_borrow_mut_data()?;
-    let validator_stake = unpack_initialized_mut::<ValidatorStake>(&mut stake_data)?;
+    let mut stake_data = match ctx.accounts.validator_stake.owner == program_id {
+        true => Some(ctx.accounts.validator_stake.try_borrow_mut_data()?),
+        false => None,
+    };
+    let validator_stake = stake_data
+        .as_mut()
+        .map(|stake_data| unpack_initialized_mut::<ValidatorStake>(stake_data))
+        .transpose()?;
+    let awaiting_validator_stake = validator_stake.is_none();
 
     // Sol staker stake
     // - Have the correct PDA derivation.
@@ -142,16 +151,8 @@ pub fn process_initialize_sol_staker_stake(
         ProgramError::InvalidSeeds,
         "sol staker authority"
     );
-    let sol_staker_authority_override = ctx.accounts.sol_staker_authority_override.data.borrow();
     let sol_staker_authority_override =
-        match ctx.accounts.sol_staker_authority_override.data_is_empty() {
-            true => None,
-            false => Some(Pubkey::new_from_array(*array_ref![
-                sol_staker_authority_override,
-                0,
-                32
-            ])),
-        };
+        unpack_authority_override(ctx.accounts.sol_staker_authority_override)?;
 
     // Ensure the account is rent exempt.
     require!(
@@ -198,15 +199,29 @@ pub fn process_initialize_sol_staker_stake(
             // NB: Will be set on the first stake.
             last_seen_stake_rewards_per_token: 0.into(),
         },
-        lamports_amount: stake_state_data.effective.into(),
+        // NB: Credited on the first sync after the validator stake account is
+        // initialized when awaiting it.
+        lamports_amount: match awaiting_validator_stake {
+            true => 0,
+            false => stake_state_data.effective.into(),
+        },
         sol_stake: *ctx.accounts.sol_staker_native_stake.key,
+        version: SolStakerStake::VERSION,
+        awaiting_validator_stake: awaiting_validator_stake.into(),
+        _padding: [0; 6],
+        checkpoints: VotingPowerCheckpoints::default(),
+        vote_delegate: OptionalNonZeroPubkey::default(),
+        activation: Activation::default(),
+        delegated_voting_power: 0,
     };
 
     // Update the validator stake account to increment the total SOL staked.
-    validator_stake.total_staked_lamports_amount = validator_stake
-        .total_staked_lamports_amount
-        .checked_add(stake.lamports_amount)
-        .ok_or(ProgramError::ArithmeticOverflow)?;
+    if let Some(mut validator_stake) = validator_stake {
+        validator_stake.total_staked_lamports_amount = validator_stake
+            .total_staked_lamports_amount
+            .checked_add(stake.lamports_amount)
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+    }
 
     Ok(())
 }
@@ -4,14 +4,15 @@ use solana_program::{
     rent::Rent, system_instruction, sysvar::Sysvar, vote::state::VoteState,
 };
 use spl_discriminator::SplDiscriminate;
+use spl_pod::optional_keys::OptionalNonZeroPubkey;
 
 use crate::{
     instruction::accounts::{Context, InitializeValidatorStakeAccounts},
-    processor::check_duna_document_signed,
+    processor::{check_duna_document_signed, unpack_optional_validator_authority_override},
     require,
     state::{
-        find_validator_stake_pda, get_validator_stake_pda_signer_seeds, Config, Delegation,
-        ValidatorStake,
+        find_validator_stake_pda, get_validator_stake_pda_signer_seeds, Activation, Config,
+        Delegation, ValidatorStake, VotingPowerCheckpoints,
     },
 };
 
@@ -19,12 +20,15 @@ use crate::{
 ///
 /// NOTE: Anybody can create the stake account for a validator. For new
 /// accounts, the authority is initialized to the validator vote account's
-/// withdraw authority.
+/// withdraw authority, unless an authority override is set for the vote
+/// account.
 ///
 /// 0. `[ ]` Stake config
-/// 1. `[w]` Validator stake
-/// 2. `[ ]` Validator vote
-/// 3. `[ ]` System program
+/// 1. `[ ]` DUNA document PDA
+/// 2. `[w]` Validator stake
+/// 3. `[ ]` Validator vote
+/// 4. `[ ]` System program
+/// 5. `[ ]` (Optional) Validator authority override
 pub fn process_initialize_validator_stake(
     program_id: &Pubkey,
     ctx: Context<InitializeValidatorStakeAccounts>,
@@ -70,6 +74,18 @@ pub fn process_initialize_validator_stake(
         &config.duna_document_hash,
     )?;
 
+    // validator authority override
+    // - optional
+    // - must have the correct derivation (if provided)
+    // - allowed to be uninitialized
+    let authority = unpack_optional_validator_authority_override(
+        ctx.remaining_accounts.first(),
+        ctx.accounts.validator_vote.key,
+        ctx.accounts.config.key,
+        program_id,
+    )?
+    .unwrap_or(withdraw_authority);
+
     // stake
     // - have the correct PDA derivation
     // - be uninitialized (empty data)
@@ -127,7 +143,7 @@ pub fn process_initialize_validator_stake(
             staked_amount: 0,
             effective_amount: 0,
             unstake_cooldown: 0,
-            authority: withdraw_authority,
+            authority,
             validator_vote: *ctx.accounts.validator_vote.key,
             // NB: Will be set on the first stake.
             last_seen_holder_rewards_per_token: 0.into(),
@@ -136,6 +152,12 @@ pub fn process_initialize_validator_stake(
         },
         total_staked_lamports_amount: 0,
         total_staked_lamports_amount_min: 0,
+        version: ValidatorStake::VERSION,
+        _padding: [0; 7],
+        checkpoints: VotingPowerCheckpoints::default(),
+        vote_delegate: OptionalNonZeroPubkey::default(),
+        activation: Activation::default(),
+        delegated_voting_power: 0,
     };
 
     Ok(())
@@ -0,0 +1,285 @@
+use solana_program::{
+    clock::Clock,
+    entrypoint::ProgramResult,
+    instruction::{AccountMeta, Instruction},
+    program::invoke_signed,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvar::Sysvar,
+};
+use spl_token_2022::instruction::mint_to_checked;
+
+use crate::{
+    error::StakeError,
+    events::{Event, LiquidStakeTokensEvent},
+    instruction::{
+        accounts::{Context, LiquidStakeTokensAccounts},
+        StakeInstruction,
+    },
+    processor::{
+        check_token_program, sync_liquid_stake_pool_lamports, unpack_initialized,
+        unpack_initialized_mut, unpack_mint, unpack_token_account,
+    },
+    require,
+    state::{
+        find_liquid_stake_pool_pda, get_liquid_stake_pool_pda_signer_seeds, LiquidStakePool,
+        ReceiptRewards, ValidatorStake,
+    },
+};
+
+/// Stakes tokens into the validator stake of a liquid stake pool, minting
+/// receipt tokens to the destination receipt token account.
+///
+/// The tokens are staked with a `ValidatorStakeTokens` instruction on behalf of
+/// the pool. Receipt tokens are minted 1:1 with the staked tokens, or in
+/// proportion to the receipt token supply once the pooled stake was slashed.
+///
+/// 0. `[w]` Config
+/// 1. `[w]` Holder rewards pool
+/// 2. `[w]` Holder rewards pool token account
+/// 3. `[w]` Validator stake
+/// 4. `[w]` Liquid stake pool
+/// 5. `[w]` Source token account
+/// 6. `[s]` Source token authority (owner or delegate)
+/// 7. `[ ]` Mint
+/// 8. `[w]` Vault PDA
+/// 9. `[w]` Vault
+/// 10. `[w]` Vault holder rewards
+/// 11. `[ ]` Token program
+/// 12. `[ ]` Rewards program
+/// 13. `[w]` Receipt mint
+/// 14. `[w]` Destination receipt token account
+/// 15. `[w]` Destination receipt rewards
+/// 16. `[ ]` Receipt token program
+/// 17. `[ ]` Stake program
+/// 18. Extra accounts required for the transfer hook and vote delegates
+///
+/// Instruction data: amount of tokens to stake, as a little-endian `u64`.
+pub fn process_liquid_stake_tokens<'a>(
+    program_id: &Pubkey,
+    ctx: Context<'a, LiquidStakeTokensAccounts<'a>>,
+    amount: u64,
+) -> ProgramResult {
+    // Accounts validation.
+
+    // stake program
+    // - must be this program
+    require!(
+        ctx.accounts.stake_program.key == program_id,
+        ProgramError::IncorrectProgramId,
+        "stake program"
+    );
+
+    // liquid stake pool
+    // - owner must be this program
+    // - must be initialized
+    // - must have the correct derivation
+    // - must belong to the config and validator stake
+    require!(
+        ctx.accounts.liquid_stake_pool.owner == program_id,
+        ProgramError::InvalidAccountOwner,
+        "liquid stake pool"
+    );
+    let pool_data = ctx.accounts.liquid_stake_pool.try_borrow_data()?;
+    let pool = unpack_initialized::<LiquidStakePool>(&pool_data)?;
+    let (derivation, bump) =
+        find_liquid_stake_pool_pda(ctx.accounts.validator_stake.key, program_id);
+    require!(
+        ctx.accounts.liquid_stake_pool.key == &derivation,
+        ProgramError::InvalidSeeds,
+        "liquid stake pool"
+    );
+    require!(
+        &pool.config == ctx.accounts.config.key,
+        ProgramError::InvalidAccountData,
+        "liquid stake pool config"
+    );
+
+    // receipt mint
+    // - must match the pool receipt mint
+    require!(
+        ctx.accounts.receipt_mint.key == &pool.receipt_mint,
+        StakeError::InvalidMint,
+        "receipt mint"
+    );
+    drop(pool_data);
+
+    // receipt token program
+    // - must be SPL Token or Token-2022 and own the receipt mint
+    check_token_program(
+        ctx.accounts.receipt_token_program,
+        ctx.accounts.receipt_mint,
+    )?;
+
+    // Compute the receipt tokens.
+    let receipt_mint_data = ctx.accounts.receipt_mint.try_borrow_data()?;
+    let receipt_mint = unpack_mint(&receipt_mint_data)?;
+    let receipt_supply = receipt_mint.supply;
+    let receipt_decimals = receipt_mint.decimals;
+    drop(receipt_mint_data);
+
+    // NB: The validator stake account is fully validated when staking.
+    require!(
+        ctx.accounts.validator_stake.owner == program_id,
+        ProgramError::InvalidAccountOwner,
+        "validator stake"
+    );
+    let stake_data = ctx.accounts.validator_stake.try_borrow_data()?;
+    let staked_amount = unpack_initialized::<ValidatorStake>(&stake_data)?
+        .delegation
+        .staked_amount;
+    drop(stake_data);
+
+    require!(amount > 0, StakeError::InvalidAmount);
+    let receipt_amount = LiquidStakePool::receipt_amount(amount, receipt_supply, staked_amount)?;
+    require!(
+        receipt_amount > 0,
+        StakeError::InvalidAmount,
+        "receipt amount"
+    );
+
+    // Stake the tokens on behalf of the pool (this also harvests the pool
+    // rewards). The pool signs, since tokens can only be staked on a pooled
+    // stake through this instruction.
+    let bump_seed = [bump];
+    let signer_seeds =
+        get_liquid_stake_pool_pda_signer_seeds(ctx.accounts.validator_stake.key, &bump_seed);
+
+    let mut accounts = vec![
+        AccountMeta::new(*ctx.accounts.config.key, false),
+        AccountMeta::new(*ctx.accounts.holder_rewards_pool.key, false),
+        AccountMeta::new(*ctx.accounts.holder_rewards_pool_token_account.key, false),
+        AccountMeta::new(*ctx.accounts.validator_stake.key, false),
+        AccountMeta::new(*ctx.accounts.liquid_stake_pool.key, true),
+        AccountMeta::new(*ctx.accounts.source_token_account.key, false),
+        AccountMeta::new_readonly(*ctx.accounts.source_token_account_authority.key, true),
+        AccountMeta::new_readonly(*ctx.accounts.mint.key, false),
+        AccountMeta::new(*ctx.accounts.vault_pda.key, false),
+        AccountMeta::new(*ctx.accounts.vault.key, false),
+        AccountMeta::new(*ctx.accounts.vault_holder_rewards.key, false),
+        AccountMeta::new_readonly(*ctx.accounts.token_program.key, false),
+        AccountMeta::new_readonly(*ctx.accounts.rewards_program.key, false),
+    ];
+    accounts.extend(ctx.remaining_accounts.iter().map(|account| AccountMeta {
+        pubkey: *account.key,
+        is_signer: account.is_signer,
+        is_writable: account.is_writable,
+    }));
+    let mut account_infos = vec![
+        ctx.accounts.config.clone(),
+        ctx.accounts.holder_rewards_pool.clone(),
+        ctx.accounts.holder_rewards_pool_token_account.clone(),
+        ctx.accounts.validator_stake.clone(),
+        ctx.accounts.liquid_stake_pool.clone(),
+        ctx.accounts.source_token_account.clone(),
+        ctx.accounts.source_token_account_authority.clone(),
+        ctx.accounts.mint.clone(),
+        ctx.accounts.vault_pda.clone(),
+        ctx.accounts.vault.clone(),
+        ctx.accounts.vault_holder_rewards.clone(),
+        ctx.accounts.token_program.clone(),
+        ctx.accounts.rewards_program.clone(),
+    ];
+    account_infos.extend_from_slice(ctx.remaining_accounts);
+
+    invoke_signed(
+        &Instruction {
+            program_id: *program_id,
+            accounts,
+            data: StakeInstruction::ValidatorStakeTokens(amount).pack(),
+        },
+        &account_infos,
+        &[&signer_seeds],
+    )?;
+
+    // destination receipt token account
+    // - must be a token account of the receipt mint
+    require!(
+        ctx.accounts.destination_receipt_account.owner == ctx.accounts.receipt_mint.owner,
+        ProgramError::InvalidAccountOwner,
+        "destination receipt account"
+    );
+    let destination_data = ctx.accounts.destination_receipt_account.try_borrow_data()?;
+    let destination = unpack_token_account(&destination_data)?;
+    require!(
+        destination.mint == *ctx.accounts.receipt_mint.key,
+        StakeError::InvalidMint,
+        "destination receipt account"
+    );
+    let destination_balance = destination.amount;
+    drop(destination_data);
+
+    // destination receipt rewards
+    // - owner must be this program
+    // - must be initialized
+    // - must belong to the pool and the destination receipt token account
+    require!(
+        ctx.accounts.destination_receipt_rewards.owner == program_id,
+        ProgramError::InvalidAccountOwner,
+        "destination receipt rewards"
+    );
+    let mut receipt_rewards_data = ctx
+        .accounts
+        .destination_receipt_rewards
+        .try_borrow_mut_data()?;
+    let mut receipt_rewards = unpack_initialized_mut::<ReceiptRewards>(&mut receipt_rewards_data)?;
+    let receipt_rewards = &mut *receipt_rewards;
+    require!(
+        &receipt_rewards.liquid_stake_pool == ctx.accounts.liquid_stake_pool.key,
+        ProgramError::InvalidAccountData,
+        "destination receipt rewards pool"
+    );
+    require!(
+        &receipt_rewards.token_account == ctx.accounts.destination_receipt_account.key,
+        ProgramError::InvalidAccountData,
+        "destination receipt rewards token account"
+    );
+
+    // Distribute the harvested rewards to the current holders and settle the
+    // destination before its balance changes. The minted receipt tokens are
+    // activating until the end of the epoch, as the tokens staked for them.
+    let epoch = Clock::get()?.epoch;
+    let mut pool_data = ctx.accounts.liquid_stake_pool.try_borrow_mut_data()?;
+    let mut pool = unpack_initialized_mut::<LiquidStakePool>(&mut pool_data)?;
+    let pool = &mut *pool;
+    sync_liquid_stake_pool_lamports(ctx.accounts.liquid_stake_pool, pool)?;
+    pool.settle(receipt_rewards, destination_balance, epoch)?;
+    pool.record_balance(
+        receipt_rewards,
+        destination_balance
+            .checked_add(receipt_amount)
+            .ok_or(ProgramError::ArithmeticOverflow)?,
+        epoch,
+    )?;
+    drop(pool_data);
+
+    // Mint the receipt tokens.
+    invoke_signed(
+        &mint_to_checked(
+            ctx.accounts.receipt_token_program.key,
+            ctx.accounts.receipt_mint.key,
+            ctx.accounts.destination_receipt_account.key,
+            ctx.accounts.liquid_stake_pool.key,
+            &[],
+            receipt_amount,
+            receipt_decimals,
+        )?,
+        &[
+            ctx.accounts.receipt_mint.clone(),
+            ctx.accounts.destination_receipt_account.clone(),
+            ctx.accounts.liquid_stake_pool.clone(),
+        ],
+        &[&signer_seeds],
+    )?;
+
+    LiquidStakeTokensEvent {
+        liquid_stake_pool: *ctx.accounts.liquid_stake_pool.key,
+        validator_stake: *ctx.accounts.validator_stake.key,
+        authority: *ctx.accounts.source_token_account_authority.key,
+        amount,
+        receipt_amount,
+    }
+    .emit();
+
+    Ok(())
+}
@@ -0,0 +1,306 @@
+use solana_program::{
+    clock::Clock,
+    entrypoint::ProgramResult,
+    instruction::{AccountMeta, Instruction},
+    program::{invoke, invoke_signed},
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvar::Sysvar,
+};
+use spl_token_2022::instruction::burn_checked;
+
+use crate::{
+    error::StakeError,
+    events::{Event, LiquidUnstakeTokensEvent},
+    instruction::{
+        accounts::{Context, LiquidUnstakeTokensAccounts},
+        StakeInstruction,
+    },
+    processor::{
+        check_token_program, sync_liquid_stake_pool_lamports, unpack_initialized,
+        unpack_initialized_mut, unpack_mint, unpack_token_account,
+    },
+    require,
+    state::{
+        find_liquid_stake_pool_pda, get_liquid_stake_pool_pda_signer_seeds, Config,
+        LiquidStakePool, ReceiptRewards, ValidatorStake,
+    },
+};
+
+/// Burns receipt tokens of a liquid stake pool, unstaking the corresponding
+/// amount of tokens from the pooled validator stake.
+///
+/// The tokens are unstaked with an `UnstakeTokens` instruction signed by the
+/// pool, so the unstake cooldown and maximum deactivation amount of the pooled
+/// stake are shared by all receipt token holders.
+///
+/// 0. `[w]` Config
+/// 1. `[w]` Holder rewards pool
+/// 2. `[w]` Holder rewards pool token account
+/// 3. `[w]` Validator stake
+/// 4. `[w]` Liquid stake pool
+/// 5. `[w]` Vault
+/// 6. `[w]` Vault PDA
+/// 7. `[w]` Vault holder rewards
+/// 8. `[ ]` Mint
+/// 9. `[w]` Destination token account
+/// 10. `[ ]` Token program
+/// 11. `[ ]` Rewards program
+/// 12. `[w]` Receipt mint
+/// 13. `[w]` Source receipt token account
+/// 14. `[w]` Source receipt rewards
+/// 15. `[s]` Source receipt token authority (owner or delegate)
+/// 16. `[ ]` Receipt token program
+/// 17. `[ ]` Stake program
+/// 18. Extra accounts required for the transfer hook and vote delegates
+///
+/// Instruction data: amount of receipt tokens to burn, as a little-endian `u64`.
+pub fn process_liquid_unstake_tokens<'a>(
+    program_id: &Pubkey,
+    ctx: Context<'a, LiquidUnstakeTokensAccounts<'a>>,
+    amount: u64,
+) -> ProgramResult {
+    // Accounts validation.
+
+    // stake program
+    // - must be this program
+    require!(
+        ctx.accounts.stake_program.key == program_id,
+        ProgramError::IncorrectProgramId,
+        "stake program"
+    );
+
+    // config
+    // - owner must be this program
+    // - must be initialized
+    require!(
+        ctx.accounts.config.owner == program_id,
+        ProgramError::InvalidAccountOwner,
+        "config"
+    );
+    let config_data = ctx.accounts.config.try_borrow_data()?;
+    unpack_initialized::<Config>(&config_data)?;
+    drop(config_data);
+
+    // liquid stake pool
+    // - owner must be this program
+    // - must be initialized
+    // - must have the correct derivation
+    // - must belong to the config and validator stake
+    require!(
+        ctx.accounts.liquid_stake_pool.owner == program_id,
+        ProgramError::InvalidAccountOwner,
+        "liquid stake pool"
+    );
+    let pool_data = ctx.accounts.liquid_stake_pool.try_borrow_data()?;
+    let pool = unpack_initialized::<LiquidStakePool>(&pool_data)?;
+    let (derivation, bump) =
+        find_liquid_stake_pool_pda(ctx.accounts.validator_stake.key, program_id);
+    require!(
+        ctx.accounts.liquid_stake_pool.key == &derivation,
+        ProgramError::InvalidSeeds,
+        "liquid stake pool"
+    );
+    require!(
+        &pool.config == ctx.accounts.config.key,
+        ProgramError::InvalidAccountData,
+        "liquid stake pool config"
+    );
+
+    // receipt mint
+    // - must match the pool receipt mint
+    require!(
+        ctx.accounts.receipt_mint.key == &pool.receipt_mint,
+        StakeError::InvalidMint,
+        "receipt mint"
+    );
+    drop(pool_data);
+
+    // receipt token program
+    // - must be SPL Token or Token-2022 and own the receipt mint
+    check_token_program(
+        ctx.accounts.receipt_token_program,
+        ctx.accounts.receipt_mint,
+    )?;
+
+    // source receipt token account
+    // - must be a token account of the receipt mint
+    require!(
+        ctx.accounts.source_receipt_account.owner == ctx.accounts.receipt_mint.owner,
+        ProgramError::InvalidAccountOwner,
+        "source receipt account"
+    );
+    let source_data = ctx.accounts.source_receipt_account.try_borrow_data()?;
+    let source = unpack_token_account(&source_data)?;
+    require!(
+        source.mint == *ctx.accounts.receipt_mint.key,
+        StakeError::InvalidMint,
+        "source receipt account"
+    );
+    let source_balance = source.amount;
+    drop(source_data);
+
+    // source receipt rewards
+    // - owner must be this program
+    // - must be initialized
+    // - must belong to the pool and the source receipt token account
+    require!(
+        ctx.accounts.source_receipt_rewards.owner == program_id,
+        ProgramError::InvalidAccountOwner,
+        "source receipt rewards"
+    );
+    let receipt_rewards_data = ctx.accounts.source_receipt_rewards.try_borrow_data()?;
+    let receipt_rewards = unpack_initialized::<ReceiptRewards>(&receipt_rewards_data)?;
+    require!(
+        &receipt_rewards.liquid_stake_pool == ctx.accounts.liquid_stake_pool.key,
+        ProgramError::InvalidAccountData,
+        "source receipt rewards pool"
+    );
+    require!(
+        &receipt_rewards.token_account == ctx.accounts.source_receipt_account.key,
+        ProgramError::InvalidAccountData,
+        "source receipt rewards token account"
+    );
+
+    drop(receipt_rewards_data);
+
+    // Compute the tokens backing the receipt tokens.
+    let receipt_mint_data = ctx.accounts.receipt_mint.try_borrow_data()?;
+    let receipt_mint = unpack_mint(&receipt_mint_data)?;
+    let receipt_supply = receipt_mint.supply;
+    let receipt_decimals = receipt_mint.decimals;
+    drop(receipt_mint_data);
+
+    // NB: The validator stake account is fully validated when unstaking.
+    require!(
+        ctx.accounts.validator_stake.owner == program_id,
+        ProgramError::InvalidAccountOwner,
+        "validator stake"
+    );
+    let stake_data = ctx.accounts.validator_stake.try_borrow_data()?;
+    let staked_amount = unpack_initialized::<ValidatorStake>(&stake_data)?
+        .delegation
+        .staked_amount;
+    drop(stake_data);
+
+    require!(amount > 0, StakeError::InvalidAmount);
+    require!(
+        amount <= source_balance,
+        StakeError::InsufficientStakeAmount,
+        "receipt amount"
+    );
+    let token_amount = LiquidStakePool::token_amount(amount, receipt_supply, staked_amount)?;
+    require!(token_amount > 0, StakeError::InvalidAmount, "token amount");
+
+    // Unstake the tokens on behalf of the pool (this also harvests the pool
+    // rewards and applies the unstake cooldown and maximum deactivation amount
+    // of the pooled stake).
+    let bump_seed = [bump];
+    let signer_seeds =
+        get_liquid_stake_pool_pda_signer_seeds(ctx.accounts.validator_stake.key, &bump_seed);
+
+    let mut accounts = vec![
+        AccountMeta::new(*ctx.accounts.config.key, false),
+        AccountMeta::new(*ctx.accounts.holder_rewards_pool.key, false),
+        AccountMeta::new(*ctx.accounts.holder_rewards_pool_token_account.key, false),
+        AccountMeta::new(*ctx.accounts.validator_stake.key, false),
+        AccountMeta::new(*ctx.accounts.liquid_stake_pool.key, true),
+        AccountMeta::new(*ctx.accounts.vault.key, false),
+        AccountMeta::new(*ctx.accounts.vault_pda.key, false),
+        AccountMeta::new(*ctx.accounts.vault_holder_rewards.key, false),
+        AccountMeta::new_readonly(*ctx.accounts.mint.key, false),
+        AccountMeta::new(*ctx.accounts.destination_token_account.key, false),
+        AccountMeta::new_readonly(*ctx.accounts.token_program.key, false),
+        AccountMeta::new_readonly(*ctx.accounts.rewards_program.key, false),
+    ];
+    accounts.extend(ctx.remaining_accounts.iter().map(|account| AccountMeta {
+        pubkey: *account.key,
+        is_signer: account.is_signer,
+        is_writable: account.is_writable,
+    }));
+    let mut account_infos = vec![
+        ctx.accounts.config.clone(),
+        ctx.accounts.holder_rewards_pool.clone(),
+        ctx.accounts.holder_rewards_pool_token_account.clone(),
+        ctx.accounts.validator_stake.clone(),
+        ctx.accounts.liquid_stake_pool.clone(),
+        ctx.accounts.vault.clone(),
+        ctx.accounts.vault_pda.clone(),
+        ctx.accounts.vault_holder_rewards.clone(),
+        ctx.accounts.mint.clone(),
+        ctx.accounts.destination_token_account.clone(),
+        ctx.accounts.token_program.clone(),
+        ctx.accounts.rewards_program.clone(),
+    ];
+    account_infos.extend_from_slice(ctx.remaining_accounts);
+
+    invoke_signed(
+        &Instruction {
+            program_id: *program_id,
+            accounts,
+            data: StakeInstruction::UnstakeTokens {
+                amount: token_amount,
+            }
+            .pack(),
+        },
+        &account_infos,
+        &[&signer_seeds],
+    )?;
+
+    // Distribute the harvested rewards to the current holders and settle the
+    // source before its balance changes.
+    let epoch = Clock::get()?.epoch;
+    let mut receipt_rewards_data = ctx.accounts.source_receipt_rewards.try_borrow_mut_data()?;
+    let mut receipt_rewards = unpack_initialized_mut::<ReceiptRewards>(&mut receipt_rewards_data)?;
+    let receipt_rewards = &mut *receipt_rewards;
+    let mut pool_data = ctx.accounts.liquid_stake_pool.try_borrow_mut_data()?;
+    let mut pool = unpack_initialized_mut::<LiquidStakePool>(&mut pool_data)?;
+    let pool = &mut *pool;
+    sync_liquid_stake_pool_lamports(ctx.accounts.liquid_stake_pool, pool)?;
+    pool.settle(receipt_rewards, source_balance, epoch)?;
+    pool.record_balance(
+        receipt_rewards,
+        source_balance
+            .checked_sub(amount)
+            .ok_or(ProgramError::ArithmeticOverflow)?,
+        epoch,
+    )?;
+    drop(pool_data);
+    drop(receipt_rewards_data);
+
+    let stake_data = ctx.accounts.validator_stake.try_borrow_data()?;
+    let unstake_cooldown = unpack_initialized::<ValidatorStake>(&stake_data)?
+        .delegation
+        .unstake_cooldown;
+    drop(stake_data);
+
+    // Burn the receipt tokens.
+    invoke(
+        &burn_checked(
+            ctx.accounts.receipt_token_program.key,
+            ctx.accounts.source_receipt_account.key,
+            ctx.accounts.receipt_mint.key,
+            ctx.accounts.source_receipt_authority.key,
+            &[],
+            amount,
+            receipt_decimals,
+        )?,
+        &[
+            ctx.accounts.source_receipt_account.clone(),
+            ctx.accounts.receipt_mint.clone(),
+            ctx.accounts.source_receipt_authority.clone(),
+        ],
+    )?;
+
+    LiquidUnstakeTokensEvent {
+        liquid_stake_pool: *ctx.accounts.liquid_stake_pool.key,
+        validator_stake: *ctx.accounts.validator_stake.key,
+        authority: *ctx.accounts.source_receipt_authority.key,
+        receipt_amount: amount,
+        amount: token_amount,
+        unstake_cooldown,
+    }
+    .emit();
+
+    Ok(())
+}
@@ -0,0 +1,165 @@
+use arrayref::array_ref;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program::invoke,
+    program_error::ProgramError, pubkey::Pubkey, rent::Rent, system_instruction, system_program,
+    sysvar::Sysvar,
+};
+use spl_discriminator::{ArrayDiscriminator, SplDiscriminate};
+
+use crate::{
+    events::{Event, MigrateAccountEvent},
+    instruction::accounts::{Context, MigrateAccountAccounts},
+    processor::unpack_initialized_mut,
+    require,
+    state::{Config, SolStakerStake, ValidatorStake},
+};
+
+/// Upgrades a config or stake account to the current layout version.
+///
+/// NOTE: This instruction is permissionless. Fields are only ever appended to
+/// the account layouts, so the account is extended with the new fields zero
+/// initialized, which must always be a valid value for a migrated account.
+/// Migrated stake accounts are seeded with a checkpoint at slot `0` holding
+/// the current effective amount, since the earlier history is not known; they
+/// have no tokens activating and no vote delegate.
+///
+/// Accounts using the layout from before versioning can also be modified
+/// in place without being migrated (see `unpack_initialized_mut`), but this
+/// instruction must be used before setting any of the fields they lack.
+///
+/// 0. `[w]` Config or stake account
+/// 1. `[w, s]` Payer
+/// 2. `[ ]` System program
+pub fn process_migrate_account(
+    program_id: &Pubkey,
+    ctx: Context<MigrateAccountAccounts>,
+) -> ProgramResult {
+    // Accounts validation.
+
+    // account
+    // - owner must be the stake program
+    // - must be an initialized config or stake account (checked below)
+    require!(
+        ctx.accounts.account.owner == program_id,
+        ProgramError::InvalidAccountOwner,
+        "account"
+    );
+
+    // payer
+    // - must be a signer
+    require!(
+        ctx.accounts.payer.is_signer,
+        ProgramError::MissingRequiredSignature,
+        "payer"
+    );
+
+    // system program
+    // - must be the system program
+    require!(
+        ctx.accounts.system_program.key == &system_program::ID,
+        ProgramError::IncorrectProgramId,
+        "system program"
+    );
+
+    let data = ctx.accounts.account.try_borrow_data()?;
+    require!(
+        data.len() >= ArrayDiscriminator::LENGTH,
+        ProgramError::InvalidAccountData,
+        "account"
+    );
+    let discriminator = *array_ref![data, 0, 8];
+    drop(data);
+
+    let (previous_version, version) = match discriminator.as_slice() {
+        Config::SPL_DISCRIMINATOR_SLICE => {
+            let top_up = resize(&ctx, Config::LEN)?;
+
+            let mut data = ctx.accounts.account.try_borrow_mut_data()?;
+            let mut config = unpack_initialized_mut::<Config>(&mut data)?;
+            let config = &mut *config;
+            // The rent top-up must not be distributed as rewards.
+            config.lamports_last = config
+                .lamports_last
+                .checked_add(top_up)
+                .ok_or(ProgramError::ArithmeticOverflow)?;
+            let previous_version = config.version;
+            config.version = Config::VERSION;
+
+            (previous_version, Config::VERSION)
+        }
+        SolStakerStake::SPL_DISCRIMINATOR_SLICE => {
+            resize(&ctx, SolStakerStake::LEN)?;
+
+            let mut data = ctx.accounts.account.try_borrow_mut_data()?;
+            let mut stake = unpack_initialized_mut::<SolStakerStake>(&mut data)?;
+            let stake = &mut *stake;
+            if stake.version < SolStakerStake::VERSION {
+                stake
+                    .checkpoints
+                    .record(0, stake.delegation.effective_amount);
+            }
+            let previous_version = stake.version;
+            stake.version = SolStakerStake::VERSION;
+
+            (previous_version, SolStakerStake::VERSION)
+        }
+        ValidatorStake::SPL_DISCRIMINATOR_SLICE => {
+            resize(&ctx, ValidatorStake::LEN)?;
+
+            let mut data = ctx.accounts.account.try_borrow_mut_data()?;
+            let mut stake = unpack_initialized_mut::<ValidatorStake>(&mut data)?;
+            let stake = &mut *stake;
+            if stake.version < ValidatorStake::VERSION {
+                stake
+                    .checkpoints
+                    .record(0, stake.delegation.effective_amount);
+            }
+            let previous_version = stake.version;
+            stake.version = ValidatorStake::VERSION;
+
+            (previous_version, ValidatorStake::VERSION)
+        }
+        _ => return Err(ProgramError::InvalidAccountData),
+    };
+
+    if previous_version != version {
+        MigrateAccountEvent {
+            account: *ctx.accounts.account.key,
+            previous_version,
+            version,
+            _padding: [0; 6],
+        }
+        .emit();
+    }
+
+    Ok(())
+}
+
+/// Reallocates the account to `len` bytes (if smaller), with the payer covering
+/// the additional rent.
+///
+/// Returns the amount of lamports transferred by the payer.
+fn resize(ctx: &Context<MigrateAccountAccounts>, len: usize) -> Result<u64, ProgramError> {
+    let account: &AccountInfo = ctx.accounts.account;
+    let current_len = account.data_len();
+
+    if current_len >= len {
+        return Ok(0);
+    }
+
+    let rent = Rent::get()?;
+    let top_up = rent
+        .minimum_balance(len)
+        .saturating_sub(rent.minimum_balance(current_len));
+
+    if top_up > 0 {
+        invoke(
+            &system_instruction::transfer(ctx.accounts.payer.key, account.key, top_up),
+            &[ctx.accounts.payer.clone(), account.clone()],
+        )?;
+    }
+
+    account.realloc(len, true)?;
+
+    Ok(top_up)
+}
@@ -1,50 +1,87 @@
 use bytemuck::Pod;
 use paladin_rewards_program_client::accounts::HolderRewards;
 use solana_program::{
-    account_info::AccountInfo, entrypoint::ProgramResult, msg, program::invoke_signed,
-    program_error::ProgramError, program_pack::IsInitialized, program_pack::Pack, pubkey::Pubkey,
-    rent::Rent, sysvar::Sysvar,
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg,
+    program::invoke_signed, program_error::ProgramError, program_pack::IsInitialized,
+    pubkey::Pubkey, rent::Rent, sysvar::Sysvar,
 };
 use spl_discriminator::{ArrayDiscriminator, SplDiscriminate};
-use spl_token::{instruction::burn_checked, state::Mint};
+use spl_pod::optional_keys::OptionalNonZeroPubkey;
+use spl_token_2022::{
+    check_spl_token_program_account,
+    extension::StateWithExtensions,
+    instruction::burn_checked,
+    state::{Account as TokenAccount, Mint},
+};
+use std::{
+    borrow::Cow,
+    ops::{Deref, DerefMut},
+};
 
 use crate::{
     error::StakeError,
+    events::{Event, HarvestEvent},
     instruction::{
         accounts::{
-            HarvestHolderRewardsAccounts, HarvestSolStakerRewardsAccounts,
-            HarvestValidatorRewardsAccounts, InitializeConfigAccounts,
-            InitializeSolStakerStakeAccounts, InitializeValidatorStakeAccounts,
-            SetAuthorityAccounts, SlashSolStakerStakeAccounts, SlashValidatorStakeAccounts,
-            SolStakerMoveTokensAccounts, SolStakerStakeTokensAccounts, UnstakeTokensAccounts,
-            UpdateConfigAccounts, ValidatorOverrideStakedLamportsAccounts,
+            AuditInvariantsAccounts, GetPendingRewardsAccounts, GetVotingPowerAccounts,
+            HarvestHolderRewardsAccounts, HarvestReceiptRewardsAccounts,
+            HarvestSolStakerRewardsAccounts, HarvestValidatorRewardsAccounts,
+            InitializeConfigAccounts, InitializeConfigPdaAccounts,
+            InitializeDelegatedVotingPowerAccounts, InitializeLiquidStakePoolAccounts,
+            InitializeReceiptRewardsAccounts, InitializeSolStakerStakeAccounts,
+            InitializeValidatorStakeAccounts, LiquidStakeTokensAccounts,
+            LiquidUnstakeTokensAccounts, MigrateAccountAccounts, SetAuthorityAccounts,
+            SetValidatorAuthorityOverrideAccounts, SetVoteDelegateAccounts,
+            SlashSolStakerStakeAccounts, SlashValidatorStakeAccounts, SolStakerMoveTokensAccounts,
+            SolStakerStakeTokensAccounts, SweepUndistributedRewardsAccounts, TransferStakeAccounts,
+            UnstakeTokensAccounts, UpdateConfigAccounts, ValidatorOverrideStakedLamportsAccounts,
             ValidatorStakeTokensAccounts, ValidatorSyncAuthorityAccounts,
+            WithdrawProtocolFeesAccounts,
         },
         StakeInstruction,
     },
+    return_data::HarvestReturnData,
     state::{
         calculate_eligible_rewards, calculate_maximum_stake_for_lamports_amount,
-        calculate_stake_rewards_per_token, find_duna_document_pda, find_sol_staker_stake_pda,
-        find_validator_stake_pda, Config, Delegation, SolStakerStake, ValidatorStake,
+        find_delegated_voting_power_pda, find_duna_document_pda, find_sol_staker_stake_pda,
+        find_validator_authority_override_pda, find_validator_stake_pda, Activation, Config,
+        DelegatedVotingPower, Delegation, LiquidStakePool, SolStakerStake, ValidatorStake,
+        VotingPowerCheckpoints,
     },
 };
 
+mod audit_invariants;
+mod get_pending_rewards;
+mod get_voting_power;
 mod harvest_holder_rewards;
+mod harvest_receipt_rewards;
 mod harvest_sol_staker_rewards;
 mod harvest_validator_rewards;
 mod initialize_config;
+mod initialize_config_pda;
+mod initialize_delegated_voting_power;
+mod initialize_liquid_stake_pool;
+mod initialize_receipt_rewards;
 mod initialize_sol_staker_stake;
 mod initialize_validator_stake;
+mod liquid_stake_tokens;
+mod liquid_unstake_tokens;
+mod migrate_account;
 mod set_authority;
+mod set_validator_authority_override;
+mod set_vote_delegate;
 mod slash_sol_staker_stake;
 mod slash_validator_stake;
 mod sol_staker_move_tokens;
 mod sol_staker_stake_tokens;
+mod sweep_undistributed_rewards;
+mod transfer_stake;
 mod unstake_tokens;
 mod update_config;
 mod validator_override_staked_lamports;
 mod validator_stake_tokens;
 mod validator_sync_authority;
+mod withdraw_protocol_fees;
 
 #[inline(always)]
 pub fn process_instruction<'a>(
@@ -189,6 +226,135 @@ pub fn process_instruction<'a>(
                 ValidatorSyncAuthorityAccounts::context(accounts)?,
             )
         }
+        StakeInstruction::GetPendingRewards => {
+            msg!("Instruction: GetPendingRewards");
+            get_pending_rewards::process_get_pending_rewards(
+                program_id,
+                GetPendingRewardsAccounts::context(accounts)?,
+            )
+        }
+        StakeInstruction::MigrateAccount => {
+            msg!("Instruction: MigrateAccount");
+            migrate_account::process_migrate_account(
+                program_id,
+                MigrateAccountAccounts::context(accounts)?,
+            )
+        }
+        StakeInstruction::InitializeConfigPda {
+            namespace,
+            slash_authority,
+            cooldown_time_seconds,
+            max_deactivation_basis_points,
+            sync_rewards_lamports,
+            duna_document_hash,
+        } => {
+            msg!("Instruction: InitializeConfigPda");
+            initialize_config_pda::process_initialize_config_pda(
+                program_id,
+                InitializeConfigPdaAccounts::context(accounts)?,
+                namespace,
+                slash_authority,
+                cooldown_time_seconds,
+                max_deactivation_basis_points,
+                sync_rewards_lamports,
+                duna_document_hash,
+            )
+        }
+        StakeInstruction::GetVotingPower { slot } => {
+            msg!("Instruction: GetVotingPower");
+            get_voting_power::process_get_voting_power(
+                program_id,
+                GetVotingPowerAccounts::context(accounts)?,
+                slot,
+            )
+        }
+        StakeInstruction::InitializeDelegatedVotingPower => {
+            msg!("Instruction: InitializeDelegatedVotingPower");
+            initialize_delegated_voting_power::process_initialize_delegated_voting_power(
+                program_id,
+                InitializeDelegatedVotingPowerAccounts::context(accounts)?,
+            )
+        }
+        StakeInstruction::SetVoteDelegate => {
+            msg!("Instruction: SetVoteDelegate");
+            set_vote_delegate::process_set_vote_delegate(
+                program_id,
+                SetVoteDelegateAccounts::context(accounts)?,
+            )
+        }
+        StakeInstruction::SetValidatorAuthorityOverride => {
+            msg!("Instruction: SetValidatorAuthorityOverride");
+            set_validator_authority_override::process_set_validator_authority_override(
+                program_id,
+                SetValidatorAuthorityOverrideAccounts::context(accounts)?,
+            )
+        }
+        StakeInstruction::SweepUndistributedRewards => {
+            msg!("Instruction: SweepUndistributedRewards");
+            sweep_undistributed_rewards::process_sweep_undistributed_rewards(
+                program_id,
+                SweepUndistributedRewardsAccounts::context(accounts)?,
+            )
+        }
+        StakeInstruction::InitializeLiquidStakePool => {
+            msg!("Instruction: InitializeLiquidStakePool");
+            initialize_liquid_stake_pool::process_initialize_liquid_stake_pool(
+                program_id,
+                InitializeLiquidStakePoolAccounts::context(accounts)?,
+            )
+        }
+        StakeInstruction::InitializeReceiptRewards => {
+            msg!("Instruction: InitializeReceiptRewards");
+            initialize_receipt_rewards::process_initialize_receipt_rewards(
+                program_id,
+                InitializeReceiptRewardsAccounts::context(accounts)?,
+            )
+        }
+        StakeInstruction::LiquidStakeTokens { amount } => {
+            msg!("Instruction: LiquidStakeTokens");
+            liquid_stake_tokens::process_liquid_stake_tokens(
+                program_id,
+                LiquidStakeTokensAccounts::context(accounts)?,
+                amount,
+            )
+        }
+        StakeInstruction::LiquidUnstakeTokens { amount } => {
+            msg!("Instruction: LiquidUnstakeTokens");
+            liquid_unstake_tokens::process_liquid_unstake_tokens(
+                program_id,
+                LiquidUnstakeTokensAccounts::context(accounts)?,
+                amount,
+            )
+        }
+        StakeInstruction::HarvestReceiptRewards => {
+            msg!("Instruction: HarvestReceiptRewards");
+            harvest_receipt_rewards::process_harvest_receipt_rewards(
+                program_id,
+                HarvestReceiptRewardsAccounts::context(accounts)?,
+            )
+        }
+        StakeInstruction::TransferStake { amount } => {
+            msg!("Instruction: TransferStake");
+            transfer_stake::process_transfer_stake(
+                program_id,
+                TransferStakeAccounts::context(accounts)?,
+                amount,
+            )
+        }
+        StakeInstruction::WithdrawProtocolFees => {
+            msg!("Instruction: WithdrawProtocolFees");
+            withdraw_protocol_fees::process_withdraw_protocol_fees(
+                program_id,
+                WithdrawProtocolFeesAccounts::context(accounts)?,
+            )
+        }
+        StakeInstruction::AuditInvariants => {
+            msg!("Instruction: AuditInvariants");
+            audit_invariants::process_audit_invariants(
+                program_id,
+                AuditInvariantsAccounts::context(accounts)?,
+            )
+        }
     }
 }
 
@@ -210,30 +376,128 @@ macro_rules! require {
     };
 }
 
+/// Unpacks an initialized account from the given data.
+///
+/// Fields are only ever appended to the account layouts, so accounts using an
+/// older (shorter) layout of `T` are read with the fields they lack zeroed.
 #[inline]
-pub fn unpack_initialized<T: Pod + IsInitialized>(data: &[u8]) -> Result<&T, ProgramError> {
-    let account =
-        bytemuck::try_from_bytes::<T>(data).map_err(|_error| ProgramError::InvalidAccountData)?;
+pub fn unpack_initialized<T: Pod + IsInitialized>(data: &[u8]) -> Result<Cow<'_, T>, ProgramError> {
+    let account = if data.len() < std::mem::size_of::<T>() {
+        let mut account = T::zeroed();
+        bytemuck::bytes_of_mut(&mut account)[..data.len()].copy_from_slice(data);
+        Cow::Owned(account)
+    } else {
+        Cow::Borrowed(
+            bytemuck::try_from_bytes::<T>(data)
+                .map_err(|_error| ProgramError::InvalidAccountData)?,
+        )
+    };
 
     require!(account.is_initialized(), ProgramError::UninitializedAccount);
 
     Ok(account)
 }
 
+/// Mutable counterpart of the account returned by [`unpack_initialized`].
+///
+/// An account using an older (shorter) layout of `T` is modified on a copy with
+/// the fields it lacks zeroed, and the fields present on the account are written
+/// back when the copy is dropped. The account data is never reallocated, so
+/// processors must not set the fields an account lacks (accounts created before
+/// versioning have a `version` of `0`): the voting power history is not kept,
+/// and setting any other field requires the account to be migrated first.
+pub enum AccountMut<'a, T: Pod> {
+    /// Account using the current layout.
+    Current(&'a mut T),
+    /// Copy of an account using an older layout.
+    Legacy { data: &'a mut [u8], account: Box<T> },
+}
+
+impl<T: Pod> Deref for AccountMut<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        match self {
+            Self::Current(account) => account,
+            Self::Legacy { account, .. } => account,
+        }
+    }
+}
+
+impl<T: Pod> DerefMut for AccountMut<'_, T> {
+    fn deref_mut(&mut self) -> &mut T {
+        match self {
+            Self::Current(account) => account,
+            Self::Legacy { account, .. } => account,
+        }
+    }
+}
+
+impl<T: Pod> Drop for AccountMut<'_, T> {
+    fn drop(&mut self) {
+        if let Self::Legacy { data, account } = self {
+            let len = data.len();
+            data.copy_from_slice(&bytemuck::bytes_of(account.as_ref())[..len]);
+        }
+    }
+}
+
 /// Unpacks an initialized account from the given data and
 /// returns a mutable reference to it.
+///
+/// Accounts using an older (shorter) layout of `T` are modified in place,
+/// without being reallocated (see [`AccountMut`]).
 #[inline]
 pub fn unpack_initialized_mut<T: Pod + IsInitialized>(
     data: &mut [u8],
-) -> Result<&mut T, ProgramError> {
-    let account = bytemuck::try_from_bytes_mut::<T>(data)
-        .map_err(|_error| ProgramError::InvalidAccountData)?;
+) -> Result<AccountMut<'_, T>, ProgramError> {
+    let account = if data.len() < std::mem::size_of::<T>() {
+        let mut account = Box::new(T::zeroed());
+        bytemuck::bytes_of_mut(account.as_mut())[..data.len()].copy_from_slice(data);
+        AccountMut::Legacy { data, account }
+    } else {
+        AccountMut::Current(
+            bytemuck::try_from_bytes_mut::<T>(data)
+                .map_err(|_error| ProgramError::InvalidAccountData)?,
+        )
+    };
 
     require!(account.is_initialized(), ProgramError::UninitializedAccount);
 
     Ok(account)
 }
 
+/// Unpacks the base state of an SPL Token or Token-2022 token account,
+/// ignoring any extensions.
+#[inline]
+pub fn unpack_token_account(data: &[u8]) -> Result<TokenAccount, ProgramError> {
+    StateWithExtensions::<TokenAccount>::unpack(data).map(|account| account.base)
+}
+
+/// Unpacks the base state of an SPL Token or Token-2022 mint, ignoring any
+/// extensions.
+#[inline]
+pub fn unpack_mint(data: &[u8]) -> Result<Mint, ProgramError> {
+    StateWithExtensions::<Mint>::unpack(data).map(|mint| mint.base)
+}
+
+/// Checks that the token program is either SPL Token or Token-2022 and that
+/// it is the owner of the given mint.
+pub fn check_token_program(
+    token_program_info: &AccountInfo,
+    mint_info: &AccountInfo,
+) -> ProgramResult {
+    check_spl_token_program_account(token_program_info.key)?;
+
+    require!(
+        mint_info.owner == token_program_info.key,
+        ProgramError::IncorrectProgramId,
+        "token program"
+    );
+
+    Ok(())
+}
+
 /// Unpacks the delegation information from either a `SolStakerStake` and `ValidatorStake`
 /// accounts.
 ///
@@ -246,22 +510,65 @@ pub fn unpack_delegation_mut_checked<'a>(
     config: &Pubkey,
     program_id: &Pubkey,
 ) -> Result<&'a mut Delegation, ProgramError> {
-    let (delegation, derivation) = match &stake_data[..ArrayDiscriminator::LENGTH] {
-        SolStakerStake::SPL_DISCRIMINATOR_SLICE => {
-            let sol_staker = unpack_initialized_mut::<SolStakerStake>(stake_data)?;
+    let derivation = match stake_data.get(..ArrayDiscriminator::LENGTH) {
+        Some(SolStakerStake::SPL_DISCRIMINATOR_SLICE) => {
+            let sol_staker = unpack_initialized::<SolStakerStake>(stake_data)?;
+            find_sol_staker_stake_pda(&sol_staker.sol_stake, config, program_id).0
+        }
+        Some(ValidatorStake::SPL_DISCRIMINATOR_SLICE) => {
+            let validator = unpack_initialized::<ValidatorStake>(stake_data)?;
+            find_validator_stake_pda(&validator.delegation.validator_vote, config, program_id).0
+        }
+        _ => return Err(ProgramError::InvalidAccountData),
+    };
+
+    require!(stake == &derivation, ProgramError::InvalidSeeds, "stake");
+
+    // NB: The delegation follows the discriminator on both stake accounts, so it
+    // is present on every layout.
+    stake_data
+        .get_mut(ArrayDiscriminator::LENGTH..)
+        .and_then(|data| data.get_mut(..std::mem::size_of::<Delegation>()))
+        .and_then(|data| bytemuck::try_from_bytes_mut::<Delegation>(data).ok())
+        .ok_or(ProgramError::InvalidAccountData)
+}
+
+/// Unpacks the delegation information from either a `SolStakerStake` and `ValidatorStake`
+/// accounts.
+///
+/// Read-only counterpart of [`unpack_delegation_mut_checked`], which also accepts
+/// accounts using an older layout.
+#[inline]
+pub fn unpack_delegation_checked<'a>(
+    stake_data: &'a [u8],
+    stake: &Pubkey,
+    config: &Pubkey,
+    program_id: &Pubkey,
+) -> Result<Cow<'a, Delegation>, ProgramError> {
+    let (delegation, derivation) = match stake_data.get(..ArrayDiscriminator::LENGTH) {
+        Some(SolStakerStake::SPL_DISCRIMINATOR_SLICE) => {
+            let sol_staker = unpack_initialized::<SolStakerStake>(stake_data)?;
 
             let (derivation, _) =
                 find_sol_staker_stake_pda(&sol_staker.sol_stake, config, program_id);
 
-            (&mut sol_staker.delegation, derivation)
+            let delegation = match sol_staker {
+                Cow::Borrowed(sol_staker) => Cow::Borrowed(&sol_staker.delegation),
+                Cow::Owned(sol_staker) => Cow::Owned(sol_staker.delegation),
+            };
+            (delegation, derivation)
         }
-        ValidatorStake::SPL_DISCRIMINATOR_SLICE => {
-            let validator = unpack_initialized_mut::<ValidatorStake>(stake_data)?;
+        Some(ValidatorStake::SPL_DISCRIMINATOR_SLICE) => {
+            let validator = unpack_initialized::<ValidatorStake>(stake_data)?;
 
             let (derivation, _) =
                 find_validator_stake_pda(&validator.delegation.validator_vote, config, program_id);
 
-            (&mut validator.delegation, derivation)
+            let delegation = match validator {
+                Cow::Borrowed(validator) => Cow::Borrowed(&validator.delegation),
+                Cow::Owned(validator) => Cow::Owned(validator.delegation),
+            };
+            (delegation, derivation)
         }
         _ => return Err(ProgramError::InvalidAccountData),
     };
@@ -271,6 +578,9 @@ pub fn unpack_delegation_mut_checked<'a>(
     Ok(delegation)
 }
 
+/// Releases the rewards received by the config account since the last sync.
+///
+/// See [`Config::receive_stake_rewards`].
 pub(crate) fn sync_config_lamports(
     config: &AccountInfo,
     config_state: &mut Config,
@@ -279,30 +589,50 @@ pub(crate) fn sync_config_lamports(
         .lamports()
         .checked_sub(config_state.lamports_last)
         .ok_or(ProgramError::ArithmeticOverflow)?;
-    let rewards_per_token =
-        calculate_stake_rewards_per_token(lamport_delta, config_state.token_amount_effective)?;
-    config_state.accumulated_stake_rewards_per_token =
-        u128::from(config_state.accumulated_stake_rewards_per_token)
-            .wrapping_add(rewards_per_token)
-            .into();
+    config_state.receive_stake_rewards(lamport_delta, Clock::get()?.unix_timestamp as u64)?;
     config_state.lamports_last = config.lamports();
 
     Ok(())
 }
 
+/// Distributes the lamports received by a liquid stake pool since the last sync
+/// across the receipt tokens tracked by the pool.
+///
+/// Lamports received while there are no tracked receipt tokens are kept for
+/// the first holders.
+pub(crate) fn sync_liquid_stake_pool_lamports(
+    liquid_stake_pool: &AccountInfo,
+    pool_state: &mut LiquidStakePool,
+) -> ProgramResult {
+    if pool_state.tracked_supply == 0 {
+        return Ok(());
+    }
+
+    let rewards = liquid_stake_pool
+        .lamports()
+        .checked_sub(pool_state.lamports_last)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    pool_state.accrue_rewards(rewards, pool_state.tracked_supply)?;
+    pool_state.lamports_last = liquid_stake_pool.lamports();
+
+    Ok(())
+}
+
 pub(crate) struct HarvestAccounts<'a, 'info> {
     pub(crate) config: &'a AccountInfo<'info>,
     pub(crate) vault_holder_rewards: &'a AccountInfo<'info>,
     pub(crate) authority: &'a AccountInfo<'info>,
+    pub(crate) stake: &'a AccountInfo<'info>,
 }
 
+/// Harvests the rewards of a delegation, returning the amounts paid out.
 pub(crate) fn harvest(
     accounts: HarvestAccounts,
     config_state: &mut Config,
     vault_authority: &Pubkey,
     delegation: &mut Delegation,
     keeper: Option<&AccountInfo>,
-) -> ProgramResult {
+) -> Result<HarvestReturnData, ProgramError> {
     // Provided authority must match expected.
     require!(
         accounts.authority.key == &delegation.authority,
@@ -344,7 +674,7 @@ pub(crate) fn harvest(
         .into();
 
     // Withdraw the lamports from the config account.
-    let rent_exempt_minimum = Rent::get()?.minimum_balance(Config::LEN);
+    let rent_exempt_minimum = Rent::get()?.minimum_balance(accounts.config.data_len());
     let config_lamports = accounts
         .config
         .lamports()
@@ -382,17 +712,162 @@ pub(crate) fn harvest(
     **accounts.config.try_borrow_mut_lamports()? = config_lamports;
     **accounts.authority.try_borrow_mut_lamports()? = recipient_lamports;
 
-    Ok(())
+    HarvestEvent {
+        config: *accounts.config.key,
+        stake: *accounts.stake.key,
+        authority: *accounts.authority.key,
+        staking_reward,
+        holder_reward,
+        keeper_reward,
+    }
+    .emit();
+
+    Ok(HarvestReturnData {
+        staking_reward,
+        holder_reward,
+        keeper_reward,
+        last_seen_stake_rewards_per_token: delegation.last_seen_stake_rewards_per_token,
+        last_seen_holder_rewards_per_token: delegation.last_seen_holder_rewards_per_token,
+    })
+}
+
+/// `DelegatedVotingPower` accounts of vote delegates, provided as remaining
+/// accounts of the instruction.
+pub(crate) struct VotingPowerAccounts<'a, 'info> {
+    pub program_id: &'a Pubkey,
+    pub config: &'a Pubkey,
+    pub accounts: &'a [AccountInfo<'info>],
+    /// Whether a missing account fails the instruction.
+    pub required: bool,
+}
+
+impl<'a, 'info> VotingPowerAccounts<'a, 'info> {
+    pub fn new(
+        program_id: &'a Pubkey,
+        config: &'a Pubkey,
+        accounts: &'a [AccountInfo<'info>],
+    ) -> Self {
+        Self {
+            program_id,
+            config,
+            accounts,
+            required: true,
+        }
+    }
+
+    /// Accounts of instructions that must not be blocked by a missing
+    /// `DelegatedVotingPower` account, such as slashes and keeper syncs.
+    ///
+    /// The voting power of a delegate whose account is missing is updated by
+    /// a later sync of the stake account.
+    pub fn new_optional(
+        program_id: &'a Pubkey,
+        config: &'a Pubkey,
+        accounts: &'a [AccountInfo<'info>],
+    ) -> Self {
+        Self {
+            required: false,
+            ..Self::new(program_id, config, accounts)
+        }
+    }
+
+    /// Replaces `previous_amount` by `amount` on the voting power of the given
+    /// delegate.
+    ///
+    /// The `DelegatedVotingPower` account of the delegate is looked up by
+    /// address on the remaining accounts, and the change is recorded on its
+    /// voting power checkpoints. Returns whether the voting power was updated,
+    /// which is not the case when an optional account is missing.
+    pub fn update(
+        &self,
+        delegate: &Pubkey,
+        previous_amount: u64,
+        amount: u64,
+    ) -> Result<bool, ProgramError> {
+        let (derivation, _) =
+            find_delegated_voting_power_pda(delegate, self.config, self.program_id);
+
+        // delegated voting power
+        // - must be present on the remaining accounts (when required)
+        // - owner must be the stake program
+        // - must be initialized
+        let account = match self
+            .accounts
+            .iter()
+            .find(|account| account.key == &derivation)
+        {
+            Some(account) => account,
+            None => {
+                require!(!self.required, StakeError::MissingDelegatedVotingPower);
+                return Ok(false);
+            }
+        };
+        require!(
+            account.owner == self.program_id,
+            ProgramError::InvalidAccountOwner,
+            "delegated voting power"
+        );
+
+        let mut data = account.try_borrow_mut_data()?;
+        let mut delegated = unpack_initialized_mut::<DelegatedVotingPower>(&mut data)?;
+        let delegated = &mut *delegated;
+        delegated.voting_power = delegated
+            .voting_power
+            .checked_sub(previous_amount)
+            .and_then(|voting_power| voting_power.checked_add(amount))
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+        delegated
+            .checkpoints
+            .record(Clock::get()?.slot, delegated.voting_power);
+
+        Ok(true)
+    }
 }
 
+/// Updates the effective amount of the delegation given the SOL amount backing it.
+///
+/// Tokens activating in the current epoch do not count towards the effective
+/// amount; once the epoch is over, they are folded into the effective amount on
+/// the next sync.
+///
+/// Changes to the effective amount are recorded as voting power checkpoints or,
+/// when the delegation has a vote delegate, on the `DelegatedVotingPower` account
+/// of the delegate (the voting power of the delegation itself is then `0`).
+/// `delegated_voting_power` tracks the amount counted on the account of the
+/// delegate, which is left behind when an optional account is missing.
 pub(crate) fn sync_effective(
     config: &mut Config,
     delegation: &mut Delegation,
+    activation: &mut Activation,
+    checkpoints: &mut VotingPowerCheckpoints,
+    (vote_delegate, delegated_voting_power): (OptionalNonZeroPubkey, &mut u64),
     (lamports_stake, lamports_stake_min): (u64, u64),
+    voting_power: &VotingPowerAccounts,
 ) -> ProgramResult {
+    let clock = Clock::get()?;
     let lamports_stake = std::cmp::max(lamports_stake, lamports_stake_min);
     let limit = calculate_maximum_stake_for_lamports_amount(lamports_stake)?;
-    let new_effective_amount = std::cmp::min(delegation.staked_amount, limit);
+    activation.activating_amount = delegation.activating_at(activation, clock.epoch);
+    let active_amount = delegation
+        .staked_amount
+        .checked_sub(activation.activating_amount)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    let new_effective_amount = std::cmp::min(active_amount, limit);
+
+    match Option::<Pubkey>::from(vote_delegate) {
+        Some(delegate) => {
+            if *delegated_voting_power != new_effective_amount
+                && voting_power.update(&delegate, *delegated_voting_power, new_effective_amount)?
+            {
+                *delegated_voting_power = new_effective_amount;
+            }
+        }
+        None => {
+            if new_effective_amount != delegation.effective_amount {
+                checkpoints.record(clock.slot, new_effective_amount);
+            }
+        }
+    }
 
     // Update states.
     config.token_amount_effective = config
@@ -418,7 +893,9 @@ struct SlashArgs<'a, 'b> {
 }
 
 /// Processes the slash for a stake delegation.
-fn process_slash_for_delegation(args: SlashArgs) -> ProgramResult {
+///
+/// Returns the amount of tokens actually slashed.
+fn process_slash_for_delegation(args: SlashArgs) -> Result<u64, ProgramError> {
     let SlashArgs {
         delegation,
         mint_info,
@@ -429,11 +906,7 @@ fn process_slash_for_delegation(args: SlashArgs) -> ProgramResult {
         amount,
     } = args;
 
-    require!(
-        token_program_info.key == &spl_token::ID,
-        ProgramError::IncorrectProgramId,
-        "token program"
-    );
+    check_token_program(token_program_info, mint_info)?;
 
     // Update the stake amount on both stake and config accounts:
     //
@@ -467,7 +940,7 @@ fn process_slash_for_delegation(args: SlashArgs) -> ProgramResult {
     // Burn the tokens from the vault account (if there are tokens to slash).
     if actual_slash > 0 {
         let mint_data = mint_info.try_borrow_data()?;
-        let mint = Mint::unpack(&mint_data)?;
+        let mint = unpack_mint(&mint_data)?;
         let decimals = mint.decimals;
 
         drop(mint_data);
@@ -492,7 +965,7 @@ fn process_slash_for_delegation(args: SlashArgs) -> ProgramResult {
         )?;
     }
 
-    Ok(())
+    Ok(actual_slash)
 }
 
 pub fn transfer_excess_lamports(
@@ -524,6 +997,81 @@ pub fn transfer_excess_lamports(
     Ok(())
 }
 
+/// Reads the authority stored on an authority override PDA.
+///
+/// Returns `None` when the override account is empty (no override set).
+pub(crate) fn unpack_authority_override(
+    authority_override: &AccountInfo,
+) -> Result<Option<Pubkey>, ProgramError> {
+    if authority_override.data_is_empty() {
+        return Ok(None);
+    }
+
+    let data = authority_override.try_borrow_data()?;
+    let authority = data
+        .get(..std::mem::size_of::<Pubkey>())
+        .and_then(|authority| Pubkey::try_from(authority).ok())
+        .ok_or(ProgramError::InvalidAccountData)?;
+
+    Ok(Some(authority))
+}
+
+/// Unpacks the authority override of a validator stake.
+///
+/// The override account must be the override PDA of the vote account. It is
+/// allowed to be uninitialized, in which case the validator stake has no
+/// override.
+pub(crate) fn unpack_validator_authority_override(
+    authority_override: &AccountInfo,
+    validator_vote: &Pubkey,
+    config: &Pubkey,
+    program_id: &Pubkey,
+) -> Result<Option<Pubkey>, ProgramError> {
+    let (derivation, _) = find_validator_authority_override_pda(validator_vote, config, program_id);
+    require!(
+        authority_override.key == &derivation,
+        ProgramError::InvalidSeeds,
+        "validator authority override"
+    );
+
+    if authority_override.data_is_empty() {
+        return Ok(None);
+    }
+
+    require!(
+        authority_override.owner == program_id,
+        ProgramError::InvalidAccountOwner,
+        "validator authority override"
+    );
+
+    unpack_authority_override(authority_override)
+}
+
+/// Unpacks the authority override of a validator stake, when provided.
+///
+/// The override account is optional (it is passed as the first remaining
+/// account): when it is missing or the system program is passed in its place,
+/// the validator stake has no override. Otherwise it is unpacked with
+/// [`unpack_validator_authority_override`].
+pub(crate) fn unpack_optional_validator_authority_override(
+    authority_override: Option<&AccountInfo>,
+    validator_vote: &Pubkey,
+    config: &Pubkey,
+    program_id: &Pubkey,
+) -> Result<Option<Pubkey>, ProgramError> {
+    match authority_override
+        .filter(|authority_override| authority_override.key != &solana_program::system_program::ID)
+    {
+        Some(authority_override) => unpack_validator_authority_override(
+            authority_override,
+            validator_vote,
+            config,
+            program_id,
+        ),
+        None => Ok(None),
+    }
+}
+
 /// Checks that the provided duna document PDA is signed by the signer and initialized.
 pub(crate) fn check_duna_document_signed(
     signer: &Pubkey,
@@ -3,6 +3,7 @@ use spl_pod::optional_keys::OptionalNonZeroPubkey;
 
 use crate::{
     error::StakeError,
+    events::{AuthorityChangeEvent, AuthorityKind, Event},
     instruction::{
         accounts::{Context, SetAuthorityAccounts},
         AuthorityType,
@@ -47,9 +48,10 @@ pub fn process_set_authority(
     );
 
     let data = &mut ctx.accounts.account.try_borrow_mut_data()?;
-    match authority_type {
+    let (previous_authority, kind) = match authority_type {
         AuthorityType::Config => {
-            let config = unpack_initialized_mut::<Config>(data)?;
+            let mut config = unpack_initialized_mut::<Config>(data)?;
+            let config = &mut *config;
             let config_authority =
                 Option::<Pubkey>::from(config.authority).ok_or(StakeError::AuthorityNotSet)?;
             require!(
@@ -58,10 +60,12 @@ pub fn process_set_authority(
                 "authority (config)"
             );
 
-            config.authority = OptionalNonZeroPubkey(*ctx.accounts.new_authority.key)
+            config.authority = OptionalNonZeroPubkey(*ctx.accounts.new_authority.key);
+            (config_authority, AuthorityKind::Config)
         }
         AuthorityType::Slash => {
-            let config = unpack_initialized_mut::<Config>(data)?;
+            let mut config = unpack_initialized_mut::<Config>(data)?;
+            let config = &mut *config;
             let slash_authority = Option::<Pubkey>::from(config.slash_authority)
                 .ok_or(StakeError::AuthorityNotSet)?;
             require!(
@@ -71,8 +75,39 @@ pub fn process_set_authority(
             );
 
             config.slash_authority = OptionalNonZeroPubkey(*ctx.accounts.new_authority.key);
+            (slash_authority, AuthorityKind::Slash)
         }
+        AuthorityType::FeeRecipient => {
+            let mut config = unpack_initialized_mut::<Config>(data)?;
+            let config = &mut *config;
+            let config_authority =
+                Option::<Pubkey>::from(config.authority).ok_or(StakeError::AuthorityNotSet)?;
+            require!(
+                *ctx.accounts.authority.key == config_authority,
+                StakeError::InvalidAuthority,
+                "authority (fee recipient)"
+            );
+            require!(
+                config.version > 0,
+                StakeError::AccountMigrationRequired,
+                "config"
+            );
+
+            let previous_fee_recipient =
+                Option::<Pubkey>::from(config.fee_recipient).unwrap_or_default();
+            config.fee_recipient = OptionalNonZeroPubkey(*ctx.accounts.new_authority.key);
+            (previous_fee_recipient, AuthorityKind::FeeRecipient)
+        }
+    };
+
+    AuthorityChangeEvent {
+        account: *ctx.accounts.account.key,
+        previous_authority,
+        new_authority: *ctx.accounts.new_authority.key,
+        kind: kind as u8,
+        _padding: [0; 7],
     }
+    .emit();
 
     Ok(())
 }
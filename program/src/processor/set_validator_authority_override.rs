@@ -0,0 +1,143 @@
+use arrayref::array_ref;
+use solana_program::{
+    entrypoint::ProgramResult, program::invoke_signed, program_error::ProgramError, pubkey::Pubkey,
+    rent::Rent, system_instruction, sysvar::Sysvar, vote::state::VoteState,
+};
+
+use crate::{
+    error::StakeError,
+    events::{AuthorityChangeEvent, AuthorityKind, Event},
+    instruction::accounts::{Context, SetValidatorAuthorityOverrideAccounts},
+    processor::unpack_initialized,
+    require,
+    state::{
+        find_validator_authority_override_pda, get_validator_authority_override_pda_signer_seeds,
+        Config,
+    },
+};
+
+/// Sets the authority override for the validator stake of a vote account.
+///
+/// The override is seeded by the vote account, so it keeps applying when the
+/// withdraw authority of the vote account changes.
+///
+/// NOTE: The override account is created on first use and assigned to the
+/// stake program, so it needs to be pre-funded with the minimum rent balance
+/// by the caller.
+///
+/// 0. `[ ]` Stake config
+/// 1. `[ ]` Validator vote
+/// 2. `[s]` Validator vote withdraw authority
+/// 3. `[w]` Validator authority override
+/// 4. `[ ]` New authority
+/// 5. `[ ]` System program
+pub fn process_set_validator_authority_override(
+    program_id: &Pubkey,
+    ctx: Context<SetValidatorAuthorityOverrideAccounts>,
+) -> ProgramResult {
+    // Accounts validation.
+
+    // config
+    // - owner must be this program
+    // - must be initialized
+    require!(
+        ctx.accounts.config.owner == program_id,
+        ProgramError::InvalidAccountOwner,
+        "config"
+    );
+    let data = ctx.accounts.config.try_borrow_data()?;
+    let _config = unpack_initialized::<Config>(&data)?;
+
+    // validator_vote
+    // - owner must be the vote program
+    // - must be initialized
+    require!(
+        ctx.accounts.validator_vote.owner == &solana_program::vote::program::ID,
+        ProgramError::InvalidAccountOwner,
+        "validator_vote"
+    );
+    let data = ctx.accounts.validator_vote.try_borrow_data()?;
+    require!(
+        VoteState::is_correct_size_and_initialized(&data),
+        ProgramError::InvalidAccountData,
+        "validator_vote"
+    );
+    let withdraw_authority = Pubkey::from(*array_ref!(data, 36, 32));
+
+    // withdraw authority
+    // - must be a signer
+    // - must match the withdraw authority of the vote account
+    require!(
+        ctx.accounts.withdraw_authority.is_signer,
+        ProgramError::MissingRequiredSignature,
+        "withdraw authority"
+    );
+    require!(
+        ctx.accounts.withdraw_authority.key == &withdraw_authority,
+        StakeError::InvalidAuthority,
+        "withdraw authority"
+    );
+
+    // validator authority override
+    // - must have the correct derivation
+    // - must be rent exempt (if uninitialized)
+    let (derivation, bump) = find_validator_authority_override_pda(
+        ctx.accounts.validator_vote.key,
+        ctx.accounts.config.key,
+        program_id,
+    );
+    require!(
+        ctx.accounts.validator_authority_override.key == &derivation,
+        ProgramError::InvalidSeeds,
+        "validator authority override"
+    );
+
+    if ctx.accounts.validator_authority_override.data_is_empty() {
+        require!(
+            ctx.accounts.validator_authority_override.lamports()
+                >= Rent::get()?.minimum_balance(std::mem::size_of::<Pubkey>()),
+            ProgramError::AccountNotRentExempt,
+            "validator authority override",
+        );
+
+        // Allocate and assign.
+        let bump_seed = [bump];
+        let signer_seeds = get_validator_authority_override_pda_signer_seeds(
+            ctx.accounts.validator_vote.key,
+            ctx.accounts.config.key,
+            &bump_seed,
+        );
+        invoke_signed(
+            &system_instruction::allocate(
+                ctx.accounts.validator_authority_override.key,
+                std::mem::size_of::<Pubkey>() as u64,
+            ),
+            &[ctx.accounts.validator_authority_override.clone()],
+            &[&signer_seeds],
+        )?;
+        invoke_signed(
+            &system_instruction::assign(ctx.accounts.validator_authority_override.key, program_id),
+            &[ctx.accounts.validator_authority_override.clone()],
+            &[&signer_seeds],
+        )?;
+    }
+
+    // Store the new authority.
+    let mut data = ctx
+        .accounts
+        .validator_authority_override
+        .try_borrow_mut_data()?;
+    let previous_authority = Pubkey::from(*array_ref!(data, 0, 32));
+    data.copy_from_slice(ctx.accounts.new_authority.key.as_ref());
+
+    AuthorityChangeEvent {
+        account: *ctx.accounts.validator_authority_override.key,
+        previous_authority,
+        new_authority: *ctx.accounts.new_authority.key,
+        kind: AuthorityKind::ValidatorOverride as u8,
+        _padding: [0; 7],
+    }
+    .emit();
+
+    Ok(())
+}
@@ -0,0 +1,167 @@
+use solana_program::{
+    clock::Clock, entrypoint::ProgramResult, program_error::ProgramError, pubkey::Pubkey,
+    sysvar::Sysvar,
+};
+use spl_discriminator::{ArrayDiscriminator, SplDiscriminate};
+use spl_pod::optional_keys::OptionalNonZeroPubkey;
+
+use crate::{
+    error::StakeError,
+    events::{AuthorityChangeEvent, AuthorityKind, Event},
+    instruction::accounts::{Context, SetVoteDelegateAccounts},
+    processor::{
+        unpack_delegation_mut_checked, unpack_initialized, unpack_initialized_mut,
+        VotingPowerAccounts,
+    },
+    require,
+    state::{Config, SolStakerStake, ValidatorStake},
+};
+
+/// Sets (or clears) the vote delegate of a stake account.
+///
+/// The voting power counted for the previous delegate is removed and the
+/// effective amount of the stake is added to the voting power of the new
+/// delegate. The `DelegatedVotingPower` accounts of both delegates must be
+/// provided as remaining accounts. While a delegate is set, the voting power
+/// of the stake account itself is `0`.
+///
+/// The new delegate must sign the instruction, so it needs to accept every
+/// delegation that is counted on its `DelegatedVotingPower` account.
+///
+/// 0. `[ ]` Stake config
+/// 1. `[w]` Validator or sol staker stake
+/// 2. `[s]` Stake authority
+/// 3. `[s]` (Optional) Vote delegate
+pub fn process_set_vote_delegate(
+    program_id: &Pubkey,
+    ctx: Context<SetVoteDelegateAccounts>,
+) -> ProgramResult {
+    // Accounts validation.
+
+    // config
+    // - owner must be this program
+    // - must be initialized
+    require!(
+        ctx.accounts.config.owner == program_id,
+        ProgramError::InvalidAccountOwner,
+        "config"
+    );
+    let config_data = ctx.accounts.config.try_borrow_data()?;
+    let _config = unpack_initialized::<Config>(&config_data)?;
+
+    // stake
+    // - owner must be this program
+    // - must be a ValidatorStake or SolStakerStake account
+    // - must be initialized
+    // - must have the correct derivation (validates the config account)
+    require!(
+        ctx.accounts.stake.owner == program_id,
+        ProgramError::InvalidAccountOwner,
+        "stake"
+    );
+    let mut stake_data = ctx.accounts.stake.try_borrow_mut_data()?;
+    let delegation = *unpack_delegation_mut_checked(
+        &mut stake_data,
+        ctx.accounts.stake.key,
+        ctx.accounts.config.key,
+        program_id,
+    )?;
+
+    // stake authority
+    // - must be a signer
+    // - must match the authority on the stake delegation
+    require!(
+        ctx.accounts.stake_authority.is_signer,
+        ProgramError::MissingRequiredSignature,
+        "stake authority"
+    );
+    require!(
+        ctx.accounts.stake_authority.key == &delegation.authority,
+        StakeError::InvalidAuthority,
+        "stake authority"
+    );
+
+    // vote delegate
+    // - must be a signer (when provided)
+    if let Some(vote_delegate) = ctx.accounts.vote_delegate {
+        require!(
+            vote_delegate.is_signer,
+            ProgramError::MissingRequiredSignature,
+            "vote delegate"
+        );
+    }
+
+    let mut sol_staker_stake;
+    let mut validator_stake;
+    let (version, vote_delegate, delegated_voting_power, checkpoints) =
+        match &stake_data[..ArrayDiscriminator::LENGTH] {
+            SolStakerStake::SPL_DISCRIMINATOR_SLICE => {
+                sol_staker_stake = unpack_initialized_mut::<SolStakerStake>(&mut stake_data)?;
+                let stake = &mut *sol_staker_stake;
+                (
+                    stake.version,
+                    &mut stake.vote_delegate,
+                    &mut stake.delegated_voting_power,
+                    &mut stake.checkpoints,
+                )
+            }
+            _ => {
+                validator_stake = unpack_initialized_mut::<ValidatorStake>(&mut stake_data)?;
+                let stake = &mut *validator_stake;
+                (
+                    stake.version,
+                    &mut stake.vote_delegate,
+                    &mut stake.delegated_voting_power,
+                    &mut stake.checkpoints,
+                )
+            }
+        };
+
+    let previous_delegate = Option::<Pubkey>::from(*vote_delegate);
+    let delegate = ctx.accounts.vote_delegate.map(|account| *account.key);
+
+    if previous_delegate == delegate {
+        return Ok(());
+    }
+
+    // The vote delegate is not part of the legacy layout.
+    if delegate.is_some() {
+        require!(version > 0, StakeError::AccountMigrationRequired, "stake");
+    }
+
+    // Move the voting power between the delegates.
+    let voting_power =
+        VotingPowerAccounts::new(program_id, ctx.accounts.config.key, ctx.remaining_accounts);
+    if let Some(previous_delegate) = previous_delegate {
+        voting_power.update(&previous_delegate, *delegated_voting_power, 0)?;
+    }
+    *delegated_voting_power = 0;
+
+    if let Some(delegate) = delegate {
+        voting_power.update(&delegate, 0, delegation.effective_amount)?;
+        *delegated_voting_power = delegation.effective_amount;
+    }
+
+    // The voting power of the stake account itself only changes when a
+    // delegate is set or cleared.
+    if previous_delegate.is_none() || delegate.is_none() {
+        let own_voting_power = match delegate {
+            Some(_) => 0,
+            None => delegation.effective_amount,
+        };
+        checkpoints.record(Clock::get()?.slot, own_voting_power);
+    }
+
+    *vote_delegate = OptionalNonZeroPubkey(delegate.unwrap_or_default());
+
+    AuthorityChangeEvent {
+        account: *ctx.accounts.stake.key,
+        previous_authority: previous_delegate.unwrap_or_default(),
+        new_authority: delegate.unwrap_or_default(),
+        kind: AuthorityKind::VoteDelegate as u8,
+        _padding: [0; 7],
+    }
+    .emit();
+
+    Ok(())
+}
@@ -1,15 +1,13 @@
-use solana_program::{
-    entrypoint::ProgramResult, program_error::ProgramError, program_pack::Pack, pubkey::Pubkey,
-};
-use spl_token::state::Account as TokenAccount;
+use solana_program::{entrypoint::ProgramResult, program_error::ProgramError, pubkey::Pubkey};
 
 use crate::{
     err,
     error::StakeError,
+    events::{Event, SlashEvent},
     instruction::accounts::{Context, SlashSolStakerStakeAccounts},
     processor::{
         harvest, process_slash_for_delegation, sync_effective, unpack_initialized_mut,
-        HarvestAccounts, SlashArgs,
+        unpack_token_account, HarvestAccounts, SlashArgs, VotingPowerAccounts,
     },
     require,
     state::{
@@ -50,7 +48,8 @@ pub fn process_slash_sol_staker_stake(
         "config"
     );
     let mut config = ctx.accounts.config.data.borrow_mut();
-    let config = unpack_initialized_mut::<Config>(&mut config)?;
+    let mut config = unpack_initialized_mut::<Config>(&mut config)?;
+    let config = &mut *config;
 
     // stake
     // - owner must be the stake program
@@ -63,7 +62,8 @@ pub fn process_slash_sol_staker_stake(
         "stake"
     );
     let mut stake_data = ctx.accounts.sol_staker_stake.try_borrow_mut_data()?;
-    let sol_staker_stake = unpack_initialized_mut::<SolStakerStake>(&mut stake_data)?;
+    let mut sol_staker_stake = unpack_initialized_mut::<SolStakerStake>(&mut stake_data)?;
+    let sol_staker_stake = &mut *sol_staker_stake;
     let (derivation, _) = find_sol_staker_stake_pda(
         &sol_staker_stake.sol_stake,
         ctx.accounts.config.key,
@@ -87,11 +87,12 @@ pub fn process_slash_sol_staker_stake(
     let signer_seeds = get_vault_pda_signer_seeds(ctx.accounts.config.key, &signer_bump);
 
     // Harvest rewards & update last claim tracking.
-    harvest(
+    let harvested = harvest(
         HarvestAccounts {
             config: ctx.accounts.config,
             vault_holder_rewards: ctx.accounts.vault_holder_rewards,
             authority: ctx.accounts.sol_staker_stake_authority,
+            stake: ctx.accounts.sol_staker_stake,
         },
         config,
         ctx.accounts.vault_authority.key,
@@ -126,7 +127,7 @@ pub fn process_slash_sol_staker_stake(
         StakeError::IncorrectVaultAccount,
     );
     let vault_data = ctx.accounts.vault.try_borrow_data()?;
-    let vault = TokenAccount::unpack(&vault_data)?;
+    let vault = unpack_token_account(&vault_data)?;
 
     // mint
     // - must match the stake vault mint
@@ -141,7 +142,7 @@ pub fn process_slash_sol_staker_stake(
     // This will burn the given amount of tokens from the vault account, and
     // update the stake delegation on the stake and config accounts.
     drop(vault_data);
-    process_slash_for_delegation(SlashArgs {
+    let slashed_amount = process_slash_for_delegation(SlashArgs {
         delegation: &mut sol_staker_stake.delegation,
         mint_info: ctx.accounts.mint,
         vault_info: ctx.accounts.vault,
@@ -152,11 +153,36 @@ pub fn process_slash_sol_staker_stake(
     })?;
 
     // Sync the new effective stake.
+    let voting_power = VotingPowerAccounts::new_optional(
+        program_id,
+        ctx.accounts.config.key,
+        ctx.remaining_accounts,
+    );
     sync_effective(
         config,
         &mut sol_staker_stake.delegation,
+        &mut sol_staker_stake.activation,
+        &mut sol_staker_stake.checkpoints,
+        (
+            sol_staker_stake.vote_delegate,
+            &mut sol_staker_stake.delegated_voting_power,
+        ),
         (sol_staker_stake.lamports_amount, 0),
+        &voting_power,
     )?;
 
+    SlashEvent {
+        config: *ctx.accounts.config.key,
+        stake: *ctx.accounts.sol_staker_stake.key,
+        amount,
+        slashed_amount,
+        staked_amount: sol_staker_stake.delegation.staked_amount,
+        effective_amount: sol_staker_stake.delegation.effective_amount,
+    }
+    .emit();
+
+    // Return the harvested amounts.
+    harvested.set();
+
     Ok(())
 }
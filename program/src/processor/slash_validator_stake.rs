@@ -1,15 +1,13 @@
-use solana_program::{
-    entrypoint::ProgramResult, program_error::ProgramError, program_pack::Pack, pubkey::Pubkey,
-};
-use spl_token::state::Account as TokenAccount;
+use solana_program::{entrypoint::ProgramResult, program_error::ProgramError, pubkey::Pubkey};
 
 use crate::{
     err,
     error::StakeError,
+    events::{Event, SlashEvent},
     instruction::accounts::{Context, SlashValidatorStakeAccounts},
     processor::{
         harvest, process_slash_for_delegation, sync_effective, unpack_initialized_mut,
-        HarvestAccounts, SlashArgs,
+        unpack_token_account, HarvestAccounts, SlashArgs, VotingPowerAccounts,
     },
     require,
     state::{
@@ -50,7 +48,8 @@ pub fn process_slash_validator_stake(
         "config"
     );
     let mut config = ctx.accounts.config.data.borrow_mut();
-    let config = unpack_initialized_mut::<Config>(&mut config)?;
+    let mut config = unpack_initialized_mut::<Config>(&mut config)?;
+    let config = &mut *config;
 
     // stake
     // - owner must be the stake program
@@ -63,7 +62,8 @@ pub fn process_slash_validator_stake(
         "stake"
     );
     let mut stake_data = ctx.accounts.validator_stake.try_borrow_mut_data()?;
-    let validator_stake = unpack_initialized_mut::<ValidatorStake>(&mut stake_data)?;
+    let mut validator_stake = unpack_initialized_mut::<ValidatorStake>(&mut stake_data)?;
+    let validator_stake = &mut *validator_stake;
     let (derivation, _) = find_validator_stake_pda(
         &validator_stake.delegation.validator_vote,
         ctx.accounts.config.key,
@@ -87,11 +87,12 @@ pub fn process_slash_validator_stake(
     let signer_seeds = get_vault_pda_signer_seeds(ctx.accounts.config.key, &signer_bump);
 
     // Harvest rewards & update last claim tracking.
-    harvest(
+    let harvested = harvest(
         HarvestAccounts {
             config: ctx.accounts.config,
             vault_holder_rewards: ctx.accounts.vault_holder_rewards,
             authority: ctx.accounts.validator_stake_authority,
+            stake: ctx.accounts.validator_stake,
         },
         config,
         ctx.accounts.vault_authority.key,
@@ -126,7 +127,7 @@ pub fn process_slash_validator_stake(
         StakeError::IncorrectVaultAccount,
     );
     let vault_data = ctx.accounts.vault.try_borrow_data()?;
-    let vault = TokenAccount::unpack(&vault_data)?;
+    let vault = unpack_token_account(&vault_data)?;
 
     // mint
     // - must match the stake vault mint
@@ -138,7 +139,7 @@ pub fn process_slash_validator_stake(
 
     // Process the slash for the stake delegation.
     drop(vault_data);
-    process_slash_for_delegation(SlashArgs {
+    let slashed_amount = process_slash_for_delegation(SlashArgs {
         delegation: &mut validator_stake.delegation,
         mint_info: ctx.accounts.mint,
         vault_info: ctx.accounts.vault,
@@ -149,14 +150,39 @@ pub fn process_slash_validator_stake(
     })?;
 
     // Sync the new effective stake.
+    let voting_power = VotingPowerAccounts::new_optional(
+        program_id,
+        ctx.accounts.config.key,
+        ctx.remaining_accounts,
+    );
     sync_effective(
         config,
         &mut validator_stake.delegation,
+        &mut validator_stake.activation,
+        &mut validator_stake.checkpoints,
+        (
+            validator_stake.vote_delegate,
+            &mut validator_stake.delegated_voting_power,
+        ),
         (
             validator_stake.total_staked_lamports_amount,
             validator_stake.total_staked_lamports_amount_min,
         ),
+        &voting_power,
     )?;
 
+    SlashEvent {
+        config: *ctx.accounts.config.key,
+        stake: *ctx.accounts.validator_stake.key,
+        amount,
+        slashed_amount,
+        staked_amount: validator_stake.delegation.staked_amount,
+        effective_amount: validator_stake.delegation.effective_amount,
+    }
+    .emit();
+
+    // Return the harvested amounts.
+    harvested.set();
+
     Ok(())
 }
@@ -1,10 +1,17 @@
-use solana_program::{entrypoint::ProgramResult, program_error::ProgramError, pubkey::Pubkey};
+use solana_program::{
+    clock::Clock, entrypoint::ProgramResult, program_error::ProgramError, pubkey::Pubkey,
+    sysvar::Sysvar,
+};
 
 use crate::{
     error::StakeError,
+    events::{Event, MoveTokensEvent},
     instruction::accounts::{Context, SolStakerMoveTokensAccounts},
-    processor::{harvest, sync_effective, unpack_initialized_mut, HarvestAccounts},
+    processor::{
+        harvest, sync_effective, unpack_initialized_mut, HarvestAccounts, VotingPowerAccounts,
+    },
     require,
+    return_data::HarvestReturnData,
     state::{find_sol_staker_stake_pda, find_vault_pda, Config, SolStakerStake},
 };
 
@@ -22,7 +29,8 @@ pub(crate) fn process_sol_staker_move_tokens(
         "config"
     );
     let mut config = ctx.accounts.config.data.borrow_mut();
-    let config = unpack_initialized_mut::<Config>(&mut config)?;
+    let mut config = unpack_initialized_mut::<Config>(&mut config)?;
+    let config = &mut *config;
 
     // Sol staker authority.
     // - Must be signer.
@@ -44,8 +52,9 @@ pub(crate) fn process_sol_staker_move_tokens(
     );
     let mut source_sol_staker_stake_data =
         ctx.accounts.source_sol_staker_stake.try_borrow_mut_data()?;
-    let source_sol_staker_stake =
+    let mut source_sol_staker_stake =
         unpack_initialized_mut::<SolStakerStake>(&mut source_sol_staker_stake_data)?;
+    let source_sol_staker_stake = &mut *source_sol_staker_stake;
     let (derivation, _) = find_sol_staker_stake_pda(
         &source_sol_staker_stake.sol_stake,
         ctx.accounts.config.key,
@@ -63,11 +72,12 @@ pub(crate) fn process_sol_staker_move_tokens(
     );
 
     let vault_authority = find_vault_pda(ctx.accounts.config.key, program_id).0;
-    harvest(
+    let source_harvested = harvest(
         HarvestAccounts {
             config: ctx.accounts.config,
             vault_holder_rewards: ctx.accounts.vault_holder_rewards,
             authority: ctx.accounts.sol_staker_authority,
+            stake: ctx.accounts.source_sol_staker_stake,
         },
         config,
         &vault_authority,
@@ -88,8 +98,9 @@ pub(crate) fn process_sol_staker_move_tokens(
         .accounts
         .destination_sol_staker_stake
         .try_borrow_mut_data()?;
-    let destination_sol_staker_stake =
+    let mut destination_sol_staker_stake =
         unpack_initialized_mut::<SolStakerStake>(&mut destination_sol_staker_stake_data)?;
+    let destination_sol_staker_stake = &mut *destination_sol_staker_stake;
     let (derivation, _) = find_sol_staker_stake_pda(
         &destination_sol_staker_stake.sol_stake,
         ctx.accounts.config.key,
@@ -101,11 +112,12 @@ pub(crate) fn process_sol_staker_move_tokens(
         "destination sol staker stake",
     );
 
-    harvest(
+    let destination_harvested = harvest(
         HarvestAccounts {
             config: ctx.accounts.config,
             vault_holder_rewards: ctx.accounts.vault_holder_rewards,
             authority: ctx.accounts.sol_staker_authority,
+            stake: ctx.accounts.destination_sol_staker_stake,
         },
         config,
         &vault_authority,
@@ -119,19 +131,50 @@ pub(crate) fn process_sol_staker_move_tokens(
         destination_sol_staker_stake.delegation.authority
     );
 
-    // Decrease the staked balance of the source.
+    // Decrease the staked balance of the source. Activating tokens are the
+    // last to be removed, so any activating tokens moved keep activating on
+    // the destination.
+    let epoch = Clock::get()?.epoch;
+    let source_activating = source_sol_staker_stake
+        .delegation
+        .activating_at(&source_sol_staker_stake.activation, epoch);
     source_sol_staker_stake.delegation.staked_amount = source_sol_staker_stake
         .delegation
         .staked_amount
         .checked_sub(amount)
         .ok_or(ProgramError::ArithmeticOverflow)?;
+    let activating_amount = source_activating
+        .checked_sub(
+            source_sol_staker_stake
+                .delegation
+                .activating_at(&source_sol_staker_stake.activation, epoch),
+        )
+        .ok_or(ProgramError::ArithmeticOverflow)?;
 
     // Increase the staked balance of the destination.
     destination_sol_staker_stake.delegation.staked_amount = destination_sol_staker_stake
         .delegation
         .staked_amount
-        .checked_add(amount)
+        .checked_add(
+            amount
+                .checked_sub(activating_amount)
+                .ok_or(ProgramError::ArithmeticOverflow)?,
+        )
         .ok_or(ProgramError::ArithmeticOverflow)?;
+    if activating_amount > 0 {
+        require!(
+            destination_sol_staker_stake.version > 0,
+            StakeError::AccountMigrationRequired,
+            "destination sol staker stake"
+        );
+        destination_sol_staker_stake
+            .delegation
+            .add_activating_stake(
+                &mut destination_sol_staker_stake.activation,
+                activating_amount,
+                epoch,
+            )?;
+    }
 
     // The new stake account's cooldown will be the max of the two cooldowns (to
     // prevent resetting the cooldown and unstaking more than intended).
@@ -141,16 +184,56 @@ pub(crate) fn process_sol_staker_move_tokens(
     );
 
     // Synchronize both delegation's new effective amounts.
+    let voting_power =
+        VotingPowerAccounts::new(program_id, ctx.accounts.config.key, ctx.remaining_accounts);
     sync_effective(
         config,
         &mut source_sol_staker_stake.delegation,
+        &mut source_sol_staker_stake.activation,
+        &mut source_sol_staker_stake.checkpoints,
+        (
+            source_sol_staker_stake.vote_delegate,
+            &mut source_sol_staker_stake.delegated_voting_power,
+        ),
         (source_sol_staker_stake.lamports_amount, 0),
+        &voting_power,
     )?;
     sync_effective(
         config,
         &mut destination_sol_staker_stake.delegation,
+        &mut destination_sol_staker_stake.activation,
+        &mut destination_sol_staker_stake.checkpoints,
+        (
+            destination_sol_staker_stake.vote_delegate,
+            &mut destination_sol_staker_stake.delegated_voting_power,
+        ),
         (destination_sol_staker_stake.lamports_amount, 0),
+        &voting_power,
     )?;
 
+    MoveTokensEvent {
+        config: *ctx.accounts.config.key,
+        source: *ctx.accounts.source_sol_staker_stake.key,
+        destination: *ctx.accounts.destination_sol_staker_stake.key,
+        authority: *ctx.accounts.sol_staker_authority.key,
+        amount,
+    }
+    .emit();
+
+    // Return the harvested amounts (both harvests pay the same authority and
+    // share the same checkpoints).
+    HarvestReturnData {
+        staking_reward: source_harvested
+            .staking_reward
+            .checked_add(destination_harvested.staking_reward)
+            .ok_or(ProgramError::ArithmeticOverflow)?,
+        holder_reward: source_harvested
+            .holder_reward
+            .checked_add(destination_harvested.holder_reward)
+            .ok_or(ProgramError::ArithmeticOverflow)?,
+        ..destination_harvested
+    }
+    .set();
+
     Ok(())
 }
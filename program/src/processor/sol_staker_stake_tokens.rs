@@ -1,20 +1,18 @@
 use paladin_rewards_program_client::instructions::DepositBuilder;
 use solana_program::{
-    entrypoint::ProgramResult,
-    program::{invoke, invoke_signed},
-    program_error::ProgramError,
-    program_pack::Pack,
-    pubkey::Pubkey,
-};
-use spl_token::{
-    instruction::transfer_checked,
-    state::{Account as TokenAccount, Mint},
+    clock::Clock, entrypoint::ProgramResult, program::invoke_signed, program_error::ProgramError,
+    pubkey::Pubkey, sysvar::Sysvar,
 };
+use spl_token_2022::onchain::invoke_transfer_checked;
 
 use crate::{
     error::StakeError,
+    events::{Event, StakeTokensEvent},
     instruction::accounts::{Context, SolStakerStakeTokensAccounts},
-    processor::{harvest, sync_effective, unpack_initialized_mut, HarvestAccounts},
+    processor::{
+        check_token_program, harvest, sync_effective, unpack_initialized_mut, unpack_mint,
+        unpack_token_account, HarvestAccounts, VotingPowerAccounts,
+    },
     require,
     state::{
         find_sol_staker_stake_pda, find_vault_pda, get_vault_pda_signer_seeds, Config,
@@ -55,7 +53,8 @@ pub fn process_sol_staker_stake_tokens<'a>(
         "config"
     );
     let mut config = ctx.accounts.config.data.borrow_mut();
-    let config = unpack_initialized_mut::<Config>(&mut config)?;
+    let mut config = unpack_initialized_mut::<Config>(&mut config)?;
+    let config = &mut *config;
 
     // sol staker stake
     // - owner must be the stake program
@@ -67,7 +66,9 @@ pub fn process_sol_staker_stake_tokens<'a>(
         "sol staker stake"
     );
     let mut sol_staker_stake_data = ctx.accounts.sol_staker_stake.try_borrow_mut_data()?;
-    let sol_staker_stake = unpack_initialized_mut::<SolStakerStake>(&mut sol_staker_stake_data)?;
+    let mut sol_staker_stake =
+        unpack_initialized_mut::<SolStakerStake>(&mut sol_staker_stake_data)?;
+    let sol_staker_stake = &mut *sol_staker_stake;
     let (derivation, _) = find_sol_staker_stake_pda(
         &sol_staker_stake.sol_stake,
         ctx.accounts.config.key,
@@ -81,11 +82,12 @@ pub fn process_sol_staker_stake_tokens<'a>(
 
     // Harvest rewards & update last claim tracking.
     let (vault_signer, signer_bump) = find_vault_pda(ctx.accounts.config.key, program_id);
-    harvest(
+    let harvested = harvest(
         HarvestAccounts {
             config: ctx.accounts.config,
             vault_holder_rewards: ctx.accounts.vault_holder_rewards,
             authority: ctx.accounts.sol_staker_stake_authority,
+            stake: ctx.accounts.sol_staker_stake,
         },
         config,
         &vault_signer,
@@ -111,7 +113,7 @@ pub fn process_sol_staker_stake_tokens<'a>(
         StakeError::IncorrectVaultAccount,
     );
     let vault_data = ctx.accounts.vault.try_borrow_data()?;
-    let vault = TokenAccount::unpack(&vault_data)?;
+    let vault = unpack_token_account(&vault_data)?;
 
     require!(
         &vault.owner == ctx.accounts.vault_pda.key,
@@ -127,45 +129,56 @@ pub fn process_sol_staker_stake_tokens<'a>(
         "mint"
     );
     let mint_data = ctx.accounts.mint.try_borrow_data()?;
-    let mint = Mint::unpack(&mint_data)?;
+    let mint = unpack_mint(&mint_data)?;
     let decimals = mint.decimals;
 
-    // Compute staker total & effective stakes.
+    // token program
+    // - must be SPL Token or Token-2022 and own the mint
+    check_token_program(ctx.accounts.token_program, ctx.accounts.mint)?;
+
+    // Compute staker total & effective stakes (the new tokens are activating
+    // until the next epoch).
     require!(amount > 0, StakeError::InvalidAmount);
-    let staker_active = sol_staker_stake
-        .delegation
-        .staked_amount
-        .checked_add(amount)
-        .ok_or(ProgramError::ArithmeticOverflow)?;
+    require!(
+        sol_staker_stake.version > 0,
+        StakeError::AccountMigrationRequired,
+        "sol staker stake"
+    );
+    sol_staker_stake.delegation.add_activating_stake(
+        &mut sol_staker_stake.activation,
+        amount,
+        Clock::get()?.epoch,
+    )?;
 
     // Update states.
-    sol_staker_stake.delegation.staked_amount = staker_active;
+    let voting_power =
+        VotingPowerAccounts::new(program_id, ctx.accounts.config.key, ctx.remaining_accounts);
     sync_effective(
         config,
         &mut sol_staker_stake.delegation,
+        &mut sol_staker_stake.activation,
+        &mut sol_staker_stake.checkpoints,
+        (
+            sol_staker_stake.vote_delegate,
+            &mut sol_staker_stake.delegated_voting_power,
+        ),
         (sol_staker_stake.lamports_amount, 0),
+        &voting_power,
     )?;
 
     // Transfer the tokens to the vault (stakes them).
     drop(mint_data);
     drop(vault_data);
-    invoke(
-        &transfer_checked(
-            &spl_token::ID,
-            ctx.accounts.source_token_account.key,
-            ctx.accounts.mint.key,
-            ctx.accounts.vault.key,
-            ctx.accounts.source_token_account_authority.key,
-            &[],
-            amount,
-            decimals,
-        )?,
-        &[
-            ctx.accounts.source_token_account.clone(),
-            ctx.accounts.mint.clone(),
-            ctx.accounts.vault.clone(),
-            ctx.accounts.source_token_account_authority.clone(),
-        ],
+    invoke_transfer_checked(
+        ctx.accounts.token_program.key,
+        ctx.accounts.source_token_account.clone(),
+        ctx.accounts.mint.clone(),
+        ctx.accounts.vault.clone(),
+        ctx.accounts.source_token_account_authority.clone(),
+        ctx.remaining_accounts,
+        amount,
+        decimals,
+        &[],
     )?;
 
     // Deposit vault tokens into rewards program
@@ -192,5 +205,18 @@ pub fn process_sol_staker_stake_tokens<'a>(
         &[&vault_seeds],
     )?;
 
+    StakeTokensEvent {
+        config: *ctx.accounts.config.key,
+        stake: *ctx.accounts.sol_staker_stake.key,
+        authority: sol_staker_stake.delegation.authority,
+        amount,
+        staked_amount: sol_staker_stake.delegation.staked_amount,
+        effective_amount: sol_staker_stake.delegation.effective_amount,
+    }
+    .emit();
+
+    // Return the harvested amounts.
+    harvested.set();
+
     Ok(())
 }
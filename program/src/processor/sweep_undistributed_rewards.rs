@@ -0,0 +1,99 @@
+use solana_program::{entrypoint::ProgramResult, program_error::ProgramError, pubkey::Pubkey};
+
+use crate::{
+    err,
+    error::StakeError,
+    events::{Event, SweepUndistributedRewardsEvent},
+    instruction::accounts::{Context, SweepUndistributedRewardsAccounts},
+    processor::{sync_config_lamports, unpack_initialized_mut},
+    require,
+    state::Config,
+};
+
+/// Transfers the stake rewards released while no tokens were effective to the
+/// destination account.
+///
+/// ### Accounts:
+///
+///   0. `[w]` Stake config account
+///   1. `[s]` Stake config authority
+///   2. `[w]` Destination account
+pub fn process_sweep_undistributed_rewards(
+    program_id: &Pubkey,
+    ctx: Context<SweepUndistributedRewardsAccounts>,
+) -> ProgramResult {
+    // Accounts validation.
+
+    // config
+    // - owner must be the stake program
+    // - must be initialized
+    require!(
+        ctx.accounts.config.owner == program_id,
+        ProgramError::InvalidAccountOwner,
+        "config"
+    );
+    let mut config_data = ctx.accounts.config.try_borrow_mut_data()?;
+    let mut config = unpack_initialized_mut::<Config>(&mut config_data)?;
+    let config = &mut *config;
+
+    // config_authority
+    // - config_authority must match the authority in the config
+    // - must be a signer
+    let Some(authority) = Option::<Pubkey>::from(config.authority) else {
+        return err!(StakeError::AuthorityNotSet);
+    };
+    require!(
+        ctx.accounts.config_authority.key == &authority,
+        StakeError::InvalidAuthority,
+        "config_authority"
+    );
+    require!(
+        ctx.accounts.config_authority.is_signer,
+        ProgramError::MissingRequiredSignature,
+        "config_authority"
+    );
+
+    // destination
+    // - must not be the config account
+    require!(
+        ctx.accounts.destination.key != ctx.accounts.config.key,
+        ProgramError::InvalidArgument,
+        "destination"
+    );
+
+    // Account for the rewards released since the last sync.
+    sync_config_lamports(ctx.accounts.config, config)?;
+
+    let amount = config.undistributed_rewards;
+    config.undistributed_rewards = 0;
+    config.lamports_last = config
+        .lamports_last
+        .checked_sub(amount)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+
+    // Move the lamports to the destination.
+    let config_lamports = ctx
+        .accounts
+        .config
+        .lamports()
+        .checked_sub(amount)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    let destination_lamports = ctx
+        .accounts
+        .destination
+        .lamports()
+        .checked_add(amount)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+
+    **ctx.accounts.config.try_borrow_mut_lamports()? = config_lamports;
+    **ctx.accounts.destination.try_borrow_mut_lamports()? = destination_lamports;
+
+    SweepUndistributedRewardsEvent {
+        config: *ctx.accounts.config.key,
+        destination: *ctx.accounts.destination.key,
+        amount,
+    }
+    .emit();
+
+    Ok(())
+}
@@ -0,0 +1,229 @@
+use solana_program::{
+    clock::Clock, entrypoint::ProgramResult, program_error::ProgramError, pubkey::Pubkey,
+    sysvar::Sysvar,
+};
+
+use crate::{
+    error::StakeError,
+    events::{Event, TransferStakeEvent},
+    instruction::accounts::{Context, TransferStakeAccounts},
+    processor::{
+        harvest, sync_effective, unpack_initialized_mut, HarvestAccounts, VotingPowerAccounts,
+    },
+    require,
+    return_data::HarvestReturnData,
+    state::{find_sol_staker_stake_pda, find_vault_pda, Config, SolStakerStake},
+};
+
+/// Transfers staked tokens between the SOL staker stake accounts of two
+/// different authorities.
+///
+/// 0. `[w]` Config
+/// 1. `[ ]` Vault holder rewards
+/// 2. `[w]` Source SOL staker stake
+/// 3. `[s, w]` Source SOL staker stake authority
+/// 4. `[w]` Destination SOL staker stake
+/// 5. `[s, w]` Destination SOL staker stake authority
+/// 6. Delegated voting power accounts of the vote delegates (if any)
+///
+/// Instruction data: amount of tokens to transfer, as a little-endian `u64`.
+pub(crate) fn process_transfer_stake(
+    program_id: &Pubkey,
+    ctx: Context<TransferStakeAccounts>,
+    amount: u64,
+) -> ProgramResult {
+    // Config
+    // - Owner must be this program
+    // - Must be initialized
+    require!(
+        ctx.accounts.config.owner == program_id,
+        ProgramError::InvalidAccountOwner,
+        "config"
+    );
+    let mut config = ctx.accounts.config.data.borrow_mut();
+    let mut config = unpack_initialized_mut::<Config>(&mut config)?;
+    let config = &mut *config;
+
+    // Authorities
+    // - Must both be signers.
+    require!(
+        ctx.accounts.source_authority.is_signer,
+        ProgramError::MissingRequiredSignature,
+        "source authority"
+    );
+    require!(
+        ctx.accounts.destination_authority.is_signer,
+        ProgramError::MissingRequiredSignature,
+        "destination authority"
+    );
+
+    // Source sol staker stake
+    // - Owner must be the stake program.
+    // - Must be initialized.
+    // - Must have the correct derivation (validates the config account).
+    // - Must not be the destination.
+    require!(
+        ctx.accounts.source_sol_staker_stake.owner == program_id,
+        ProgramError::InvalidAccountOwner,
+        "source sol staker stake"
+    );
+    require!(
+        ctx.accounts.source_sol_staker_stake.key != ctx.accounts.destination_sol_staker_stake.key,
+        StakeError::InvalidDestinationAccount,
+        "destination sol staker stake"
+    );
+    let mut source_data = ctx.accounts.source_sol_staker_stake.try_borrow_mut_data()?;
+    let mut source = unpack_initialized_mut::<SolStakerStake>(&mut source_data)?;
+    let source = &mut *source;
+    let (derivation, _) =
+        find_sol_staker_stake_pda(&source.sol_stake, ctx.accounts.config.key, program_id);
+    require!(
+        ctx.accounts.source_sol_staker_stake.key == &derivation,
+        ProgramError::InvalidSeeds,
+        "source sol staker stake",
+    );
+
+    // Harvest the source rewards (validates the source authority).
+    let vault_authority = find_vault_pda(ctx.accounts.config.key, program_id).0;
+    let source_harvested = harvest(
+        HarvestAccounts {
+            config: ctx.accounts.config,
+            vault_holder_rewards: ctx.accounts.vault_holder_rewards,
+            authority: ctx.accounts.source_authority,
+            stake: ctx.accounts.source_sol_staker_stake,
+        },
+        config,
+        &vault_authority,
+        &mut source.delegation,
+        None,
+    )?;
+
+    // Destination sol staker stake
+    // - Owner must be the stake program.
+    // - Must be initialized.
+    // - Must have the correct derivation (validates the config account).
+    require!(
+        ctx.accounts.destination_sol_staker_stake.owner == program_id,
+        ProgramError::InvalidAccountOwner,
+        "destination sol staker stake"
+    );
+    let mut destination_data = ctx
+        .accounts
+        .destination_sol_staker_stake
+        .try_borrow_mut_data()?;
+    let mut destination = unpack_initialized_mut::<SolStakerStake>(&mut destination_data)?;
+    let destination = &mut *destination;
+    let (derivation, _) =
+        find_sol_staker_stake_pda(&destination.sol_stake, ctx.accounts.config.key, program_id);
+    require!(
+        ctx.accounts.destination_sol_staker_stake.key == &derivation,
+        ProgramError::InvalidSeeds,
+        "destination sol staker stake",
+    );
+
+    // Harvest the destination rewards (validates the destination authority).
+    harvest(
+        HarvestAccounts {
+            config: ctx.accounts.config,
+            vault_holder_rewards: ctx.accounts.vault_holder_rewards,
+            authority: ctx.accounts.destination_authority,
+            stake: ctx.accounts.destination_sol_staker_stake,
+        },
+        config,
+        &vault_authority,
+        &mut destination.delegation,
+        None,
+    )?;
+
+    // Validate the amount.
+    require!(amount > 0, StakeError::InvalidAmount);
+    require!(
+        amount <= source.delegation.staked_amount,
+        StakeError::InsufficientStakeAmount
+    );
+
+    // Decrease the staked balance of the source. Activating tokens are the
+    // last to be removed, so any activating tokens transferred keep activating
+    // on the destination.
+    let epoch = Clock::get()?.epoch;
+    let source_activating = source.delegation.activating_at(&source.activation, epoch);
+    source.delegation.staked_amount = source
+        .delegation
+        .staked_amount
+        .checked_sub(amount)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    let activating_amount = source_activating
+        .checked_sub(source.delegation.activating_at(&source.activation, epoch))
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+
+    // Increase the staked balance of the destination.
+    destination.delegation.staked_amount = destination
+        .delegation
+        .staked_amount
+        .checked_add(
+            amount
+                .checked_sub(activating_amount)
+                .ok_or(ProgramError::ArithmeticOverflow)?,
+        )
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    if activating_amount > 0 {
+        require!(
+            destination.version > 0,
+            StakeError::AccountMigrationRequired,
+            "destination sol staker stake"
+        );
+        destination.delegation.add_activating_stake(
+            &mut destination.activation,
+            activating_amount,
+            epoch,
+        )?;
+    }
+
+    // The destination cooldown will be the max of the two cooldowns (to
+    // prevent resetting the cooldown and unstaking more than intended).
+    destination.delegation.unstake_cooldown = std::cmp::max(
+        source.delegation.unstake_cooldown,
+        destination.delegation.unstake_cooldown,
+    );
+
+    // Synchronize both delegation's new effective amounts.
+    let voting_power =
+        VotingPowerAccounts::new(program_id, ctx.accounts.config.key, ctx.remaining_accounts);
+    sync_effective(
+        config,
+        &mut source.delegation,
+        &mut source.activation,
+        &mut source.checkpoints,
+        (source.vote_delegate, &mut source.delegated_voting_power),
+        (source.lamports_amount, 0),
+        &voting_power,
+    )?;
+    sync_effective(
+        config,
+        &mut destination.delegation,
+        &mut destination.activation,
+        &mut destination.checkpoints,
+        (
+            destination.vote_delegate,
+            &mut destination.delegated_voting_power,
+        ),
+        (destination.lamports_amount, 0),
+        &voting_power,
+    )?;
+
+    TransferStakeEvent {
+        config: *ctx.accounts.config.key,
+        source: *ctx.accounts.source_sol_staker_stake.key,
+        destination: *ctx.accounts.destination_sol_staker_stake.key,
+        source_authority: *ctx.accounts.source_authority.key,
+        destination_authority: *ctx.accounts.destination_authority.key,
+        amount,
+    }
+    .emit();
+
+    // Return the amounts harvested from the source, whose authority transfers
+    // the stake.
+    source_harvested.set();
+
+    Ok(())
+}
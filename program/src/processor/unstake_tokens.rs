@@ -1,16 +1,20 @@
 use paladin_rewards_program_client::instructions::WithdrawBuilder;
 use solana_program::{
     clock::Clock, entrypoint::ProgramResult, program::invoke_signed, program_error::ProgramError,
-    program_pack::Pack, pubkey::Pubkey, sysvar::Sysvar,
+    pubkey::Pubkey, sysvar::Sysvar,
 };
-use spl_token::state::Account as TokenAccount;
-use spl_token::{instruction::transfer_checked, state::Mint};
+use spl_discriminator::{ArrayDiscriminator, SplDiscriminate};
+use spl_token_2022::onchain::invoke_transfer_checked;
 
 use crate::state::find_vault_pda;
 use crate::{
     error::StakeError,
+    events::{Event, UnstakeTokensEvent},
     instruction::accounts::{Context, UnstakeTokensAccounts},
-    processor::{harvest, sync_effective, unpack_initialized_mut, HarvestAccounts},
+    processor::{
+        check_token_program, harvest, sync_effective, unpack_initialized_mut, unpack_mint,
+        unpack_token_account, HarvestAccounts, VotingPowerAccounts,
+    },
     require,
     state::{
         find_sol_staker_stake_pda, find_validator_stake_pda, get_vault_pda_signer_seeds, Config,
@@ -34,7 +38,8 @@ pub fn process_unstake_tokens<'info>(
         "config"
     );
     let mut config_borrow = ctx.accounts.config.data.borrow_mut();
-    let config = unpack_initialized_mut::<Config>(&mut config_borrow)?;
+    let mut config = unpack_initialized_mut::<Config>(&mut config_borrow)?;
+    let config = &mut *config;
 
     // vault
     // - must be the token account on the stake config account
@@ -48,7 +53,7 @@ pub fn process_unstake_tokens<'info>(
         "vault matches destination token account"
     );
     let vault_borrow = ctx.accounts.vault.try_borrow_data()?;
-    let vault = TokenAccount::unpack(&vault_borrow)?;
+    let vault = unpack_token_account(&vault_borrow)?;
 
     // Verify vault PDA
     let (vault_signer, signer_bump) = find_vault_pda(ctx.accounts.config.key, program_id);
@@ -73,34 +78,50 @@ pub fn process_unstake_tokens<'info>(
         "stake"
     );
     let stake_borrow = &mut ctx.accounts.stake.try_borrow_mut_data()?;
-    let (derivation, lamports, lamports_min, delegation) = match stake_borrow.len() {
-        ValidatorStake::LEN => {
-            let stake = unpack_initialized_mut::<ValidatorStake>(stake_borrow)?;
-
-            (
-                find_validator_stake_pda(
-                    &stake.delegation.validator_vote,
-                    ctx.accounts.config.key,
-                    program_id,
+    let mut validator_stake;
+    let mut sol_staker_stake;
+    let (derivation, lamports, lamports_min, delegation, activation, checkpoints, vote_delegation) =
+        match stake_borrow.get(..ArrayDiscriminator::LENGTH) {
+            Some(ValidatorStake::SPL_DISCRIMINATOR_SLICE) => {
+                validator_stake = unpack_initialized_mut::<ValidatorStake>(stake_borrow)?;
+                let stake = &mut *validator_stake;
+
+                (
+                    find_validator_stake_pda(
+                        &stake.delegation.validator_vote,
+                        ctx.accounts.config.key,
+                        program_id,
+                    )
+                    .0,
+                    stake.total_staked_lamports_amount,
+                    stake.total_staked_lamports_amount_min,
+                    &mut stake.delegation,
+                    &mut stake.activation,
+                    &mut stake.checkpoints,
+                    (stake.vote_delegate, &mut stake.delegated_voting_power),
                 )
-                .0,
-                stake.total_staked_lamports_amount,
-                stake.total_staked_lamports_amount_min,
-                &mut stake.delegation,
-            )
-        }
-        SolStakerStake::LEN => {
-            let stake = unpack_initialized_mut::<SolStakerStake>(stake_borrow)?;
-
-            (
-                find_sol_staker_stake_pda(&stake.sol_stake, ctx.accounts.config.key, program_id).0,
-                stake.lamports_amount,
-                0,
-                &mut stake.delegation,
-            )
-        }
-        _ => return Err(ProgramError::InvalidAccountData),
-    };
+            }
+            Some(SolStakerStake::SPL_DISCRIMINATOR_SLICE) => {
+                sol_staker_stake = unpack_initialized_mut::<SolStakerStake>(stake_borrow)?;
+                let stake = &mut *sol_staker_stake;
+
+                (
+                    find_sol_staker_stake_pda(
+                        &stake.sol_stake,
+                        ctx.accounts.config.key,
+                        program_id,
+                    )
+                    .0,
+                    stake.lamports_amount,
+                    0,
+                    &mut stake.delegation,
+                    &mut stake.activation,
+                    &mut stake.checkpoints,
+                    (stake.vote_delegate, &mut stake.delegated_voting_power),
+                )
+            }
+            _ => return Err(ProgramError::InvalidAccountData),
+        };
     require!(
         ctx.accounts.stake.key == &derivation,
         ProgramError::InvalidSeeds,
@@ -129,15 +150,20 @@ pub fn process_unstake_tokens<'info>(
         "mint"
     );
     let mint_borrow = ctx.accounts.mint.try_borrow_data()?;
-    let mint = Mint::unpack(&mint_borrow)?;
+    let mint = unpack_mint(&mint_borrow)?;
     let decimals = mint.decimals;
 
+    // token program
+    // - must be SPL Token or Token-2022 and own the mint
+    check_token_program(ctx.accounts.token_program, ctx.accounts.mint)?;
+
     // Harvest rewards & update last claim tracking.
-    harvest(
+    let harvested = harvest(
         HarvestAccounts {
             config: ctx.accounts.config,
             vault_holder_rewards: ctx.accounts.vault_holder_rewards,
             authority: ctx.accounts.stake_authority,
+            stake: ctx.accounts.stake,
         },
         config,
         &vault_signer,
@@ -178,7 +204,17 @@ pub fn process_unstake_tokens<'info>(
     delegation.staked_amount = staked_amount;
     delegation.unstake_cooldown = now.saturating_add(config.cooldown_time_seconds);
 
-    sync_effective(config, delegation, (lamports, lamports_min))?;
+    let voting_power =
+        VotingPowerAccounts::new(program_id, ctx.accounts.config.key, ctx.remaining_accounts);
+    sync_effective(
+        config,
+        delegation,
+        activation,
+        checkpoints,
+        vote_delegation,
+        (lamports, lamports_min),
+        &voting_power,
+    )?;
 
     drop(mint_borrow);
     drop(vault_borrow);
@@ -207,26 +243,31 @@ pub fn process_unstake_tokens<'info>(
         &[&vault_seeds],
     )?;
 
-    invoke_signed(
-        &transfer_checked(
-            &spl_token::ID,
-            ctx.accounts.vault.key,
-            ctx.accounts.mint.key,
-            ctx.accounts.destination_token_account.key,
-            ctx.accounts.vault_pda.key,
-            &[],
-            amount,
-            decimals,
-        )?,
-        &[
-            ctx.accounts.vault.clone(),
-            ctx.accounts.mint.clone(),
-            ctx.accounts.destination_token_account.clone(),
-            ctx.accounts.vault_pda.clone(),
-            ctx.accounts.token_program.clone(),
-        ],
+    invoke_transfer_checked(
+        ctx.accounts.token_program.key,
+        ctx.accounts.vault.clone(),
+        ctx.accounts.mint.clone(),
+        ctx.accounts.destination_token_account.clone(),
+        ctx.accounts.vault_pda.clone(),
+        ctx.remaining_accounts,
+        amount,
+        decimals,
         &[&vault_seeds],
     )?;
 
+    UnstakeTokensEvent {
+        config: *ctx.accounts.config.key,
+        stake: *ctx.accounts.stake.key,
+        authority: delegation.authority,
+        amount,
+        staked_amount: delegation.staked_amount,
+        effective_amount: delegation.effective_amount,
+        unstake_cooldown: delegation.unstake_cooldown,
+    }
+    .emit();
+
+    // Return the harvested amounts.
+    harvested.set();
+
     Ok(())
 }
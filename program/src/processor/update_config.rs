@@ -3,13 +3,14 @@ use solana_program::{entrypoint::ProgramResult, program_error::ProgramError, pub
 use crate::{
     err,
     error::StakeError,
+    events::{Event, UpdateConfigEvent},
     instruction::{
         accounts::{Context, UpdateConfigAccounts},
         ConfigField,
     },
-    processor::unpack_initialized_mut,
+    processor::{sync_config_lamports, unpack_initialized_mut},
     require,
-    state::{Config, MAX_BASIS_POINTS},
+    state::{Config, MAX_BASIS_POINTS, MAX_PROTOCOL_FEE_BASIS_POINTS},
 };
 
 /// Updates configuration parameters.
@@ -34,7 +35,8 @@ pub fn process_update_config(
         "config"
     );
     let mut config = ctx.accounts.config.try_borrow_mut_data()?;
-    let config = unpack_initialized_mut::<Config>(&mut config)?;
+    let mut config = unpack_initialized_mut::<Config>(&mut config)?;
+    let config = &mut *config;
 
     let authority: Option<Pubkey> = config.authority.into();
     if let Some(authority) = authority {
@@ -53,9 +55,10 @@ pub fn process_update_config(
         );
 
         // Updates the config account.
-        match field {
+        let (field, value) = match field {
             ConfigField::CooldownTimeSeconds(seconds) => {
                 config.cooldown_time_seconds = seconds;
+                (0, seconds)
             }
             ConfigField::MaxDeactivationBasisPoints(points) => {
                 require!(
@@ -66,11 +69,53 @@ pub fn process_update_config(
                 );
 
                 config.max_deactivation_basis_points = points;
+                (1, u64::from(points))
             }
             ConfigField::SyncRewardsLamports(lamports) => {
                 config.sync_rewards_lamports = lamports;
+                (2, lamports)
             }
+            ConfigField::RewardStreamingPeriodSeconds(seconds) => {
+                require!(
+                    config.version > 0,
+                    StakeError::AccountMigrationRequired,
+                    "config"
+                );
+
+                // Release the rewards streamed so far; the new period applies to
+                // rewards received from now on.
+                sync_config_lamports(ctx.accounts.config, config)?;
+                config.reward_streaming_period_seconds = seconds;
+                (3, seconds)
+            }
+            ConfigField::ProtocolFeeBasisPoints(points) => {
+                require!(
+                    points <= MAX_PROTOCOL_FEE_BASIS_POINTS,
+                    ProgramError::InvalidArgument,
+                    "basis points exceeds maximum allowed value of {}",
+                    MAX_PROTOCOL_FEE_BASIS_POINTS
+                );
+                require!(
+                    config.version > 0,
+                    StakeError::AccountMigrationRequired,
+                    "config"
+                );
+
+                // Withhold the fee of the rewards received so far; the new fee
+                // applies to rewards received from now on.
+                sync_config_lamports(ctx.accounts.config, config)?;
+                config.protocol_fee_basis_points = points;
+                (4, u64::from(points))
+            }
+        };
+
+        UpdateConfigEvent {
+            config: *ctx.accounts.config.key,
+            field,
+            _padding: [0; 7],
+            value,
         }
+        .emit();
     } else {
         return err!(StakeError::AuthorityNotSet);
     }
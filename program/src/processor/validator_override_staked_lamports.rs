@@ -4,7 +4,9 @@ use crate::{
     err,
     error::StakeError,
     instruction::accounts::{Context, ValidatorOverrideStakedLamportsAccounts},
-    processor::{harvest, sync_effective, unpack_initialized_mut, HarvestAccounts},
+    processor::{
+        harvest, sync_effective, unpack_initialized_mut, HarvestAccounts, VotingPowerAccounts,
+    },
     require,
     state::{find_validator_stake_pda, find_vault_pda, Config, ValidatorStake},
 };
@@ -23,7 +25,8 @@ pub(crate) fn process_validator_override_staked_lamports(
         "config"
     );
     let mut config = ctx.accounts.config.data.borrow_mut();
-    let config = unpack_initialized_mut::<Config>(&mut config)?;
+    let mut config = unpack_initialized_mut::<Config>(&mut config)?;
+    let config = &mut *config;
 
     // Config Authority.
     // - Must match the provided authority account.
@@ -53,7 +56,8 @@ pub(crate) fn process_validator_override_staked_lamports(
         "validator stake"
     );
     let mut stake_data = ctx.accounts.validator_stake.try_borrow_mut_data()?;
-    let stake = unpack_initialized_mut::<ValidatorStake>(&mut stake_data)?;
+    let mut stake = unpack_initialized_mut::<ValidatorStake>(&mut stake_data)?;
+    let stake = &mut *stake;
     let (derivation, _) = find_validator_stake_pda(
         &stake.delegation.validator_vote,
         ctx.accounts.config.key,
@@ -67,11 +71,12 @@ pub(crate) fn process_validator_override_staked_lamports(
 
     // Harvest rewards & update last claim tracking.
     let vault_authority = find_vault_pda(ctx.accounts.config.key, program_id).0;
-    harvest(
+    let harvested = harvest(
         HarvestAccounts {
             config: ctx.accounts.config,
             vault_holder_rewards: ctx.accounts.vault_holder_rewards,
             authority: ctx.accounts.validator_stake_authority,
+            stake: ctx.accounts.validator_stake,
         },
         config,
         &vault_authority,
@@ -83,14 +88,26 @@ pub(crate) fn process_validator_override_staked_lamports(
     stake.total_staked_lamports_amount_min = amount_min;
 
     // Sync the effective stake.
+    let voting_power = VotingPowerAccounts::new_optional(
+        program_id,
+        ctx.accounts.config.key,
+        ctx.remaining_accounts,
+    );
     sync_effective(
         config,
         &mut stake.delegation,
+        &mut stake.activation,
+        &mut stake.checkpoints,
+        (stake.vote_delegate, &mut stake.delegated_voting_power),
         (
             stake.total_staked_lamports_amount,
             stake.total_staked_lamports_amount_min,
         ),
+        &voting_power,
     )?;
 
+    // Return the harvested amounts.
+    harvested.set();
+
     Ok(())
 }
@@ -1,19 +1,25 @@
 use paladin_rewards_program_client::instructions::DepositBuilder;
 use solana_program::program::invoke_signed;
 use solana_program::{
-    entrypoint::ProgramResult, program::invoke, program_error::ProgramError, program_pack::Pack,
-    pubkey::Pubkey,
+    clock::Clock, entrypoint::ProgramResult, program_error::ProgramError, pubkey::Pubkey,
+    sysvar::Sysvar,
 };
-use spl_token::state::Account as TokenAccount;
-use spl_token::{instruction::transfer_checked, state::Mint};
+use spl_token_2022::onchain::invoke_transfer_checked;
 
 use crate::state::get_vault_pda_signer_seeds;
 use crate::{
     error::StakeError,
+    events::{Event, StakeTokensEvent},
     instruction::accounts::{Context, ValidatorStakeTokensAccounts},
-    processor::{harvest, sync_effective, unpack_initialized_mut, HarvestAccounts},
+    processor::{
+        check_token_program, harvest, sync_effective, unpack_initialized_mut, unpack_mint,
+        unpack_token_account, HarvestAccounts, VotingPowerAccounts,
+    },
     require,
-    state::{find_validator_stake_pda, find_vault_pda, Config, ValidatorStake},
+    state::{
+        find_liquid_stake_pool_pda, find_validator_stake_pda, find_vault_pda, Config,
+        ValidatorStake,
+    },
 };
 
 /// Stakes tokens with the given config.
@@ -21,6 +27,9 @@ use crate::{
 /// NOTE: This instruction is used by validator stake accounts. The total amount of effective
 /// staked tokens is limited to the 1.3 * current amount of SOL staked to the validator.
 ///
+/// Tokens can only be staked on the validator stake of a liquid stake pool
+/// through `LiquidStakeTokens`, which signs as the pool.
+///
 /// 0. `[w]` Config
 /// 1. `[w]` Validator stake
 /// 2. `[w]` Validator stake authority
@@ -49,7 +58,8 @@ pub fn process_validator_stake_tokens<'a>(
         "config"
     );
     let mut config = ctx.accounts.config.data.borrow_mut();
-    let config = unpack_initialized_mut::<Config>(&mut config)?;
+    let mut config = unpack_initialized_mut::<Config>(&mut config)?;
+    let config = &mut *config;
 
     // validator stake
     // - owner must be the stake program
@@ -61,7 +71,8 @@ pub fn process_validator_stake_tokens<'a>(
         "validator stake"
     );
     let mut stake_data = ctx.accounts.validator_stake.try_borrow_mut_data()?;
-    let stake = unpack_initialized_mut::<ValidatorStake>(&mut stake_data)?;
+    let mut stake = unpack_initialized_mut::<ValidatorStake>(&mut stake_data)?;
+    let stake = &mut *stake;
     let (derivation, _) = find_validator_stake_pda(
         &stake.delegation.validator_vote,
         ctx.accounts.config.key,
@@ -73,13 +84,24 @@ pub fn process_validator_stake_tokens<'a>(
         "validator stake",
     );
 
+    // validator stake authority
+    // - must sign when it is the liquid stake pool of the validator stake, so
+    //   tokens are only staked on a pooled stake through `LiquidStakeTokens`
+    let (pool, _) = find_liquid_stake_pool_pda(ctx.accounts.validator_stake.key, program_id);
+    require!(
+        stake.delegation.authority != pool || ctx.accounts.validator_stake_authority.is_signer,
+        ProgramError::MissingRequiredSignature,
+        "liquid stake pool"
+    );
+
     // Harvest rewards & update last claim tracking.
     let (vault_signer, signer_bump) = find_vault_pda(ctx.accounts.config.key, program_id);
-    harvest(
+    let harvested = harvest(
         HarvestAccounts {
             config: ctx.accounts.config,
             vault_holder_rewards: ctx.accounts.vault_holder_rewards,
             authority: ctx.accounts.validator_stake_authority,
+            stake: ctx.accounts.validator_stake,
         },
         config,
         &vault_signer,
@@ -105,7 +127,7 @@ pub fn process_validator_stake_tokens<'a>(
         StakeError::IncorrectVaultAccount,
     );
     let vault_data = ctx.accounts.vault.try_borrow_data()?;
-    let vault = TokenAccount::unpack(&vault_data)?;
+    let vault = unpack_token_account(&vault_data)?;
 
     // mint
     // - must match the stake vault mint
@@ -115,48 +137,54 @@ pub fn process_validator_stake_tokens<'a>(
         "mint"
     );
     let mint_data = ctx.accounts.mint.try_borrow_data()?;
-    let mint = Mint::unpack(&mint_data)?;
+    let mint = unpack_mint(&mint_data)?;
     let decimals = mint.decimals;
 
-    // Compute the new total & effective stakes.
+    // token program
+    // - must be SPL Token or Token-2022 and own the mint
+    check_token_program(ctx.accounts.token_program, ctx.accounts.mint)?;
+
+    // Compute the new total & effective stakes (the new tokens are activating
+    // until the next epoch).
     require!(amount > 0, StakeError::InvalidAmount);
-    let validator_active = stake
+    require!(
+        stake.version > 0,
+        StakeError::AccountMigrationRequired,
+        "validator stake"
+    );
+    stake
         .delegation
-        .staked_amount
-        .checked_add(amount)
-        .ok_or(ProgramError::ArithmeticOverflow)?;
+        .add_activating_stake(&mut stake.activation, amount, Clock::get()?.epoch)?;
 
     // Update states.
-    stake.delegation.staked_amount = validator_active;
+    let voting_power =
+        VotingPowerAccounts::new(program_id, ctx.accounts.config.key, ctx.remaining_accounts);
     sync_effective(
         config,
         &mut stake.delegation,
+        &mut stake.activation,
+        &mut stake.checkpoints,
+        (stake.vote_delegate, &mut stake.delegated_voting_power),
         (
             stake.total_staked_lamports_amount,
             stake.total_staked_lamports_amount_min,
         ),
+        &voting_power,
     )?;
 
     // Transfer the tokens to the vault (stakes them).
     drop(mint_data);
     drop(vault_data);
-    invoke(
-        &transfer_checked(
-            &spl_token::ID,
-            ctx.accounts.source_token_account.key,
-            ctx.accounts.mint.key,
-            ctx.accounts.vault.key,
-            ctx.accounts.source_token_account_authority.key,
-            &[],
-            amount,
-            decimals,
-        )?,
-        &[
-            ctx.accounts.source_token_account.clone(),
-            ctx.accounts.mint.clone(),
-            ctx.accounts.vault.clone(),
-            ctx.accounts.source_token_account_authority.clone(),
-        ],
+    invoke_transfer_checked(
+        ctx.accounts.token_program.key,
+        ctx.accounts.source_token_account.clone(),
+        ctx.accounts.mint.clone(),
+        ctx.accounts.vault.clone(),
+        ctx.accounts.source_token_account_authority.clone(),
+        ctx.remaining_accounts,
+        amount,
+        decimals,
+        &[],
     )?;
 
     // Deposit tokens into holder rewards
@@ -183,5 +211,18 @@ pub fn process_validator_stake_tokens<'a>(
         &[&vault_seeds],
     )?;
 
+    StakeTokensEvent {
+        config: *ctx.accounts.config.key,
+        stake: *ctx.accounts.validator_stake.key,
+        authority: stake.delegation.authority,
+        amount,
+        staked_amount: stake.delegation.staked_amount,
+        effective_amount: stake.delegation.effective_amount,
+    }
+    .emit();
+
+    // Return the harvested amounts.
+    harvested.set();
+
     Ok(())
 }
@@ -4,12 +4,29 @@ use solana_program::{
 };
 
 use crate::{
+    error::StakeError,
+    events::{AuthorityChangeEvent, AuthorityKind, Event},
     instruction::accounts::{Context, ValidatorSyncAuthorityAccounts},
-    processor::unpack_initialized_mut,
+    processor::{unpack_initialized, unpack_initialized_mut, unpack_validator_authority_override},
     require,
-    state::{find_validator_stake_pda, Config, ValidatorStake},
+    state::{find_liquid_stake_pool_pda, find_validator_stake_pda, Config, ValidatorStake},
 };
 
+/// Syncs the authority of a validator stake account with the withdraw
+/// authority of the vote account, or with its authority override (if set).
+///
+/// NOTE: The tokens staked on a validator stake account whose authority is its
+/// liquid stake pool belong to the receipt token holders, so the authority of
+/// the account can not be changed anymore.
+///
+/// NOTE: The authority override account is required, so a sync can not drop
+/// the override by omitting it; an uninitialized override account means that
+/// the validator stake has no override.
+///
+/// 0. `[w]` Config
+/// 1. `[w]` Validator stake
+/// 2. `[w]` Validator vote
+/// 3. `[ ]` Validator authority override
 pub(crate) fn process_validator_sync_authority(
     program_id: &Pubkey,
     ctx: Context<ValidatorSyncAuthorityAccounts>,
@@ -22,14 +39,8 @@ pub(crate) fn process_validator_sync_authority(
         ProgramError::InvalidAccountOwner,
         "config"
     );
-    let data = &ctx.accounts.config.try_borrow_data()?;
-    let config =
-        bytemuck::try_from_bytes::<Config>(data).map_err(|_| ProgramError::InvalidAccountData)?;
-    require!(
-        config.is_initialized(),
-        ProgramError::UninitializedAccount,
-        "config"
-    );
+    let config_data = ctx.accounts.config.try_borrow_data()?;
+    let _config = unpack_initialized::<Config>(&config_data)?;
 
     // stake
     // - have the correct PDA derivation
@@ -44,8 +55,9 @@ pub(crate) fn process_validator_sync_authority(
         ProgramError::InvalidSeeds,
         "stake"
     );
-    let mut validator_stake = ctx.accounts.validator_stake.data.borrow_mut();
-    let validator_stake = unpack_initialized_mut::<ValidatorStake>(&mut validator_stake).unwrap();
+    let mut stake_data = ctx.accounts.validator_stake.try_borrow_mut_data()?;
+    let mut validator_stake = unpack_initialized_mut::<ValidatorStake>(&mut stake_data)?;
+    let validator_stake = &mut *validator_stake;
 
     // validator_vote
     // - owner must be the vote program
@@ -63,8 +75,39 @@ pub(crate) fn process_validator_sync_authority(
     );
     let withdraw_authority = Pubkey::from(*array_ref!(data, 36, 32));
 
-    // Sync the authority to match the current withdraw authority.
-    validator_stake.delegation.authority = withdraw_authority;
+    // validator authority override
+    // - must have the correct derivation
+    // - allowed to be uninitialized
+    let authority = unpack_validator_authority_override(
+        ctx.accounts.validator_authority_override,
+        ctx.accounts.validator_vote.key,
+        ctx.accounts.config.key,
+        program_id,
+    )?
+    .unwrap_or(withdraw_authority);
+
+    // Sync the authority to match the current withdraw authority (or its
+    // override).
+    let previous_authority = validator_stake.delegation.authority;
+    let (liquid_stake_pool, _) =
+        find_liquid_stake_pool_pda(ctx.accounts.validator_stake.key, program_id);
+    require!(
+        previous_authority != liquid_stake_pool || authority == liquid_stake_pool,
+        StakeError::InvalidAuthority,
+        "validator stake backs a liquid stake pool"
+    );
+    validator_stake.delegation.authority = authority;
+
+    if previous_authority != authority {
+        AuthorityChangeEvent {
+            account: *ctx.accounts.validator_stake.key,
+            previous_authority,
+            new_authority: authority,
+            kind: AuthorityKind::Stake as u8,
+            _padding: [0; 7],
+        }
+        .emit();
+    }
 
     Ok(())
 }
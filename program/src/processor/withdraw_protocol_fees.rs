@@ -0,0 +1,106 @@
+use solana_program::{entrypoint::ProgramResult, program_error::ProgramError, pubkey::Pubkey};
+
+use crate::{
+    err,
+    error::StakeError,
+    events::{Event, WithdrawProtocolFeesEvent},
+    instruction::accounts::{Context, WithdrawProtocolFeesAccounts},
+    processor::unpack_initialized_mut,
+    require,
+    state::Config,
+};
+
+/// Transfers the unclaimed protocol fees to the fee recipient.
+///
+/// ### Accounts:
+///
+///   0. `[w]` Stake config account
+///   1. `[s]` Stake config authority
+///   2. `[w]` Fee recipient
+pub fn process_withdraw_protocol_fees(
+    program_id: &Pubkey,
+    ctx: Context<WithdrawProtocolFeesAccounts>,
+) -> ProgramResult {
+    // Accounts validation.
+
+    // config
+    // - owner must be the stake program
+    // - must be initialized
+    require!(
+        ctx.accounts.config.owner == program_id,
+        ProgramError::InvalidAccountOwner,
+        "config"
+    );
+    let mut config_data = ctx.accounts.config.try_borrow_mut_data()?;
+    let mut config = unpack_initialized_mut::<Config>(&mut config_data)?;
+    let config = &mut *config;
+
+    // config_authority
+    // - config_authority must match the authority in the config
+    // - must be a signer
+    let Some(authority) = Option::<Pubkey>::from(config.authority) else {
+        return err!(StakeError::AuthorityNotSet);
+    };
+    require!(
+        ctx.accounts.config_authority.key == &authority,
+        StakeError::InvalidAuthority,
+        "config_authority"
+    );
+    require!(
+        ctx.accounts.config_authority.is_signer,
+        ProgramError::MissingRequiredSignature,
+        "config_authority"
+    );
+
+    // fee_recipient
+    // - must match the fee recipient in the config
+    // - must not be the config account
+    let Some(fee_recipient) = Option::<Pubkey>::from(config.fee_recipient) else {
+        return err!(StakeError::AuthorityNotSet, "fee recipient");
+    };
+    require!(
+        ctx.accounts.fee_recipient.key == &fee_recipient,
+        StakeError::InvalidDestinationAccount,
+        "fee_recipient"
+    );
+    require!(
+        ctx.accounts.fee_recipient.key != ctx.accounts.config.key,
+        ProgramError::InvalidArgument,
+        "fee_recipient"
+    );
+
+    // NB: The fees are already accounted for on the last seen lamports, so
+    // there is no need to sync the config lamports.
+    let amount = config.unclaimed_protocol_fees;
+    config.unclaimed_protocol_fees = 0;
+    config.lamports_last = config
+        .lamports_last
+        .checked_sub(amount)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+
+    // Move the lamports to the fee recipient.
+    let config_lamports = ctx
+        .accounts
+        .config
+        .lamports()
+        .checked_sub(amount)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    let fee_recipient_lamports = ctx
+        .accounts
+        .fee_recipient
+        .lamports()
+        .checked_add(amount)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+
+    **ctx.accounts.config.try_borrow_mut_lamports()? = config_lamports;
+    **ctx.accounts.fee_recipient.try_borrow_mut_lamports()? = fee_recipient_lamports;
+
+    WithdrawProtocolFeesEvent {
+        config: *ctx.accounts.config.key,
+        fee_recipient,
+        amount,
+    }
+    .emit();
+
+    Ok(())
+}
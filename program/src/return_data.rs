@@ -0,0 +1,146 @@
+//! Return data set by the stake program.
+//!
+//! Return data is written as the 8-byte discriminator of its type followed by
+//! its `Pod` bytes. Discriminators are derived from a versioned hash input
+//! (`stake::return_data::<name>::v<n>`), so any change to the layout of a
+//! return data type must bump its version suffix.
+
+use bytemuck::{Pod, Zeroable};
+use solana_program::program::set_return_data;
+use spl_discriminator::SplDiscriminate;
+use spl_pod::primitives::PodU128;
+
+/// Sets the discriminator and `Pod` bytes of the value as the return data of
+/// the instruction.
+#[inline(always)]
+fn set<T: Pod + SplDiscriminate>(value: &T) {
+    set_return_data(&[T::SPL_DISCRIMINATOR_SLICE, bytemuck::bytes_of(value)].concat());
+}
+
+/// Rewards paid out by a harvest.
+///
+/// Every instruction that harvests a stake account sets this as its return
+/// data, so programs invoking the stake program through CPI can read how much
+/// was paid. The checkpoints are the values of the delegation after the
+/// harvest.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Pod, SplDiscriminate, Zeroable)]
+#[discriminator_hash_input("stake::return_data::harvest::v1")]
+pub struct HarvestReturnData {
+    /// Lamports earned from staking rewards (net of the protocol fee).
+    pub staking_reward: u64,
+
+    /// Lamports earned from holder rewards.
+    pub holder_reward: u64,
+
+    /// Lamports of the total reward paid to the keeper (if any).
+    pub keeper_reward: u64,
+
+    /// Stake rewards per token checkpoint of the delegation after the harvest.
+    pub last_seen_stake_rewards_per_token: PodU128,
+
+    /// Holder rewards per token checkpoint of the delegation after the harvest.
+    pub last_seen_holder_rewards_per_token: PodU128,
+}
+
+impl HarvestReturnData {
+    pub const LEN: usize = std::mem::size_of::<HarvestReturnData>();
+
+    /// Sets the harvest amounts as the return data of the instruction.
+    ///
+    /// This must be called after any CPI, since invoking another program
+    /// overwrites the return data.
+    #[inline(always)]
+    pub fn set(&self) {
+        set(self);
+    }
+}
+
+/// Voting power of a stake account at a given slot.
+///
+/// Set as the return data of `GetVotingPower`.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Pod, SplDiscriminate, Zeroable)]
+#[discriminator_hash_input("stake::return_data::voting_power::v1")]
+pub struct VotingPowerReturnData {
+    /// Slot the voting power refers to.
+    pub slot: u64,
+
+    /// Effective stake amount at the end of the slot.
+    pub voting_power: u64,
+}
+
+impl VotingPowerReturnData {
+    pub const LEN: usize = std::mem::size_of::<VotingPowerReturnData>();
+
+    /// Sets the voting power as the return data of the instruction.
+    #[inline(always)]
+    pub fn set(&self) {
+        set(self);
+    }
+}
+
+/// Report of the accounting invariants of a config.
+///
+/// Set as the return data of `AuditInvariants`. The totals are those of the
+/// audited stake accounts, and `failed_invariants` is a bit mask of the
+/// invariants that do not hold.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Pod, SplDiscriminate, Zeroable)]
+#[discriminator_hash_input("stake::return_data::audit::v1")]
+pub struct AuditReturnData {
+    /// Number of stake accounts audited.
+    pub stake_accounts: u64,
+
+    /// Staked tokens of the audited stake accounts.
+    pub staked_amount: u64,
+
+    /// Effective tokens of the audited stake accounts.
+    pub effective_amount: u64,
+
+    /// Token balance of the vault, including the tokens it deposited on the
+    /// rewards program.
+    pub vault_amount: u64,
+
+    /// Lamports balance of the config account.
+    pub config_lamports: u64,
+
+    /// Lamports not paid out yet: the undistributed rewards, the unclaimed
+    /// protocol fees and the rewards owed to the audited stake accounts.
+    pub unpaid_rewards: u64,
+
+    /// Lamports the config account must hold: the rent exempt minimum plus the
+    /// unpaid rewards.
+    pub required_lamports: u64,
+
+    /// Bit mask of the failed invariants.
+    pub failed_invariants: u64,
+}
+
+impl AuditReturnData {
+    pub const LEN: usize = std::mem::size_of::<AuditReturnData>();
+
+    /// The vault holds fewer tokens than the audited stake accounts or than
+    /// the effective tokens of the config.
+    pub const VAULT_BALANCE: u64 = 1 << 0;
+
+    /// The audited stake accounts have more effective tokens than the config.
+    pub const TOKEN_AMOUNT_EFFECTIVE: u64 = 1 << 1;
+
+    /// A stake account has more effective tokens than its staked tokens or
+    /// than the limit for the SOL backing it.
+    pub const EFFECTIVE_CAP: u64 = 1 << 2;
+
+    /// The SOL staker stake accounts of a validator hold more lamports than
+    /// the total staked lamports of the validator stake account.
+    pub const VALIDATOR_LAMPORTS: u64 = 1 << 3;
+
+    /// The config account holds fewer lamports than required.
+    pub const CONFIG_LAMPORTS: u64 = 1 << 4;
+
+    /// Sets the report as the return data of the instruction.
+    #[inline(always)]
+    pub fn set(&self) {
+        set(self);
+    }
+}
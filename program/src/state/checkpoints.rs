@@ -0,0 +1,161 @@
+use bytemuck::{Pod, Zeroable};
+use shank::ShankType;
+
+/// Maximum number of checkpoints kept by a stake or delegated voting power
+/// account.
+pub const MAX_CHECKPOINTS: usize = 16;
+
+/// Effective stake amount (voting power) recorded at a given slot.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Pod, ShankType, Zeroable)]
+pub struct Checkpoint {
+    /// Slot when the effective amount was recorded.
+    pub slot: u64,
+
+    /// Effective amount at the end of the slot.
+    pub effective_amount: u64,
+}
+
+/// History of the voting power of a delegation or a vote delegate.
+///
+/// Checkpoints are stored in a ring buffer of `MAX_CHECKPOINTS` entries. Once
+/// the buffer is full, the two oldest checkpoints are merged into one holding
+/// the lower of both amounts (at the slot of the oldest), so the history never
+/// loses a slot: the voting power at a slot covered by a merged checkpoint is a
+/// lower bound of the actual voting power, and it is exact for every slot after
+/// the latest merged checkpoint.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Pod, ShankType, Zeroable)]
+pub struct VotingPowerCheckpoints {
+    /// Total number of checkpoints recorded.
+    ///
+    /// The next checkpoint is written at `count % MAX_CHECKPOINTS`.
+    pub count: u64,
+
+    /// Checkpoints ring buffer.
+    pub checkpoints: [Checkpoint; MAX_CHECKPOINTS],
+}
+
+impl VotingPowerCheckpoints {
+    /// Records the effective amount at the given slot.
+    ///
+    /// Multiple changes on the same slot update the same checkpoint, so the
+    /// checkpoint holds the effective amount at the end of the slot.
+    pub fn record(&mut self, slot: u64, effective_amount: u64) {
+        if let Some(latest) = self.latest_mut() {
+            if latest.slot == slot {
+                latest.effective_amount = effective_amount;
+                return;
+            }
+        }
+
+        let index = (self.count % MAX_CHECKPOINTS as u64) as usize;
+
+        if self.count >= MAX_CHECKPOINTS as u64 {
+            // The slot at `index` holds the oldest checkpoint, which is merged
+            // into the next oldest before being overwritten.
+            let oldest = self.checkpoints[index];
+            let next = &mut self.checkpoints[(index + 1) % MAX_CHECKPOINTS];
+            *next = Checkpoint {
+                slot: oldest.slot,
+                effective_amount: std::cmp::min(oldest.effective_amount, next.effective_amount),
+            };
+        }
+
+        self.checkpoints[index] = Checkpoint {
+            slot,
+            effective_amount,
+        };
+        self.count = self.count.saturating_add(1);
+    }
+
+    /// Returns the effective amount at the end of the given slot.
+    ///
+    /// When the slot is covered by a merged checkpoint, the returned amount is
+    /// the lowest effective amount of the merged range.
+    pub fn voting_power_at(&self, slot: u64) -> u64 {
+        self.iter()
+            .filter(|checkpoint| checkpoint.slot <= slot)
+            .max_by_key(|checkpoint| checkpoint.slot)
+            .map(|checkpoint| checkpoint.effective_amount)
+            .unwrap_or_default()
+    }
+
+    /// Iterates over the recorded checkpoints (in storage order).
+    fn iter(&self) -> impl Iterator<Item = &Checkpoint> {
+        let len = std::cmp::min(self.count, MAX_CHECKPOINTS as u64) as usize;
+        self.checkpoints[..len].iter()
+    }
+
+    fn latest_mut(&mut self) -> Option<&mut Checkpoint> {
+        if self.count == 0 {
+            return None;
+        }
+        let index = ((self.count - 1) % MAX_CHECKPOINTS as u64) as usize;
+        self.checkpoints.get_mut(index)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn empty_history_has_no_voting_power() {
+        let checkpoints = VotingPowerCheckpoints::default();
+        assert_eq!(checkpoints.voting_power_at(0), 0);
+        assert_eq!(checkpoints.voting_power_at(u64::MAX), 0);
+    }
+
+    #[test]
+    fn voting_power_at_slot() {
+        let mut checkpoints = VotingPowerCheckpoints::default();
+        checkpoints.record(10, 100);
+        checkpoints.record(20, 50);
+
+        assert_eq!(checkpoints.voting_power_at(9), 0);
+        assert_eq!(checkpoints.voting_power_at(10), 100);
+        assert_eq!(checkpoints.voting_power_at(19), 100);
+        assert_eq!(checkpoints.voting_power_at(20), 50);
+        assert_eq!(checkpoints.voting_power_at(1_000), 50);
+    }
+
+    #[test]
+    fn same_slot_updates_checkpoint() {
+        let mut checkpoints = VotingPowerCheckpoints::default();
+        checkpoints.record(10, 100);
+        checkpoints.record(10, 150);
+
+        assert_eq!(checkpoints.count, 1);
+        assert_eq!(checkpoints.voting_power_at(10), 150);
+    }
+
+    #[test]
+    fn full_history_merges_oldest_checkpoints() {
+        let mut checkpoints = VotingPowerCheckpoints::default();
+        checkpoints.record(10, 100);
+        checkpoints.record(20, 50);
+        for slot in 3..=MAX_CHECKPOINTS as u64 {
+            checkpoints.record(slot * 10, 200);
+        }
+
+        // Merges the checkpoints for slots 10 and 20.
+        checkpoints.record(1_000, 300);
+
+        assert_eq!(checkpoints.voting_power_at(5), 0);
+        assert_eq!(checkpoints.voting_power_at(15), 50);
+        assert_eq!(checkpoints.voting_power_at(25), 50);
+        assert_eq!(checkpoints.voting_power_at(35), 200);
+        assert_eq!(checkpoints.voting_power_at(1_000), 300);
+
+        // Merges the merged checkpoint with the checkpoint for slot 30.
+        checkpoints.record(1_001, 400);
+
+        assert_eq!(checkpoints.count, MAX_CHECKPOINTS as u64 + 2);
+        assert_eq!(checkpoints.voting_power_at(5), 0);
+        assert_eq!(checkpoints.voting_power_at(35), 50);
+        assert_eq!(checkpoints.voting_power_at(45), 200);
+        assert_eq!(checkpoints.voting_power_at(1_000), 300);
+        assert_eq!(checkpoints.voting_power_at(u64::MAX), 400);
+    }
+}
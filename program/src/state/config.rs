@@ -1,9 +1,13 @@
 use bytemuck::{Pod, Zeroable};
 use shank::ShankAccount;
-use solana_program::{program_pack::IsInitialized, pubkey::Pubkey};
+use solana_program::{program_error::ProgramError, program_pack::IsInitialized, pubkey::Pubkey};
 use spl_discriminator::{ArrayDiscriminator, SplDiscriminate};
 use spl_pod::{optional_keys::OptionalNonZeroPubkey, primitives::PodU128};
 
+use super::{
+    calculate_stake_rewards_per_token, MAX_BASIS_POINTS, REWARDS_PER_TOKEN_SCALING_FACTOR,
+};
+
 /// Configuration for a staking system.
 #[repr(C)]
 #[derive(Clone, Copy, Default, Pod, ShankAccount, SplDiscriminate, Zeroable)]
@@ -56,13 +60,73 @@ pub struct Config {
     /// Bump seed for the `Vault` signer authority.
     pub vault_authority_bump: u8,
 
+    /// Layout version of the account.
+    ///
+    /// Accounts created before versioning was introduced have a version of `0`
+    /// and do not have any of the fields below it, which are read as `0`.
+    pub version: u8,
+
+    /// Padding for alignment.
+    pub _padding: [u8; 4],
+
+    /// Period (in seconds) over which stake rewards received by the config
+    /// account are released.
+    ///
+    /// When `0`, rewards are released as soon as they are received.
+    pub reward_streaming_period_seconds: u64,
+
+    /// Timestamp when the current reward stream ends.
+    pub reward_period_end: u64,
+
+    /// Timestamp of the last update of the reward stream.
+    pub reward_last_update: u64,
+
+    /// Rate (in lamports per second) at which the current reward stream is
+    /// released.
+    ///
+    /// Stored as a `u128`, which includes a scaling factor of `1e18` to
+    /// represent the rate with 18 decimal places of precision.
+    pub reward_rate: PodU128,
+
+    /// Stake rewards (scaled by `1e18`) left over from the truncation of the
+    /// rewards per token, carried over to the next accrual.
+    pub stake_rewards_remainder: u64,
+
+    /// Lamports released as stake rewards while no tokens were effective.
+    ///
+    /// These are not owed to any stake account and can be swept by the config
+    /// authority with `SweepUndistributedRewards`.
+    pub undistributed_rewards: u64,
+
+    /// Account that receives the protocol fees withdrawn with
+    /// `WithdrawProtocolFees`.
+    pub fee_recipient: OptionalNonZeroPubkey,
+
+    /// Protocol fees withheld from the stake rewards received by the config
+    /// account that were not withdrawn yet.
+    ///
+    /// These lamports are kept on the config account until they are withdrawn
+    /// by the config authority.
+    pub unclaimed_protocol_fees: u64,
+
+    /// The proportion of the stake rewards withheld as protocol fee when they
+    /// are received, given as basis points (1 / 10,000).
+    pub protocol_fee_basis_points: u16,
+
     /// Padding for alignment.
-    pub _padding: [u8; 5],
+    pub _fee_padding: [u8; 6],
+
+    /// Streamed rewards (scaled by `1e18`) left over from the truncation of
+    /// the released lamports, carried over to the next release.
+    pub reward_stream_remainder: u64,
 }
 
 impl Config {
     pub const LEN: usize = std::mem::size_of::<Config>();
 
+    /// Current layout version.
+    pub const VERSION: u8 = 1;
+
     #[inline(always)]
     pub fn is_initialized(&self) -> bool {
         self.discriminator.as_slice() == Config::SPL_DISCRIMINATOR_SLICE
@@ -72,6 +136,144 @@ impl Config {
     pub fn is_uninitialized(&self) -> bool {
         self.discriminator.as_slice() == ArrayDiscriminator::UNINITIALIZED.as_slice()
     }
+
+    /// Releases the stake rewards received by the config account since the
+    /// last sync at the given timestamp.
+    ///
+    /// The protocol fee is withheld from the rewards as they are received,
+    /// before they are streamed, so a change of the fee only applies to later
+    /// rewards. Configs created before versioning do not have the fields used
+    /// for the fee and the stream, so they keep releasing the rewards as soon
+    /// as they are received until they are migrated.
+    pub fn receive_stake_rewards(
+        &mut self,
+        lamports_received: u64,
+        timestamp: u64,
+    ) -> Result<(), ProgramError> {
+        if self.version == 0 {
+            let rewards_per_token =
+                calculate_stake_rewards_per_token(lamports_received, self.token_amount_effective)?;
+            self.accumulated_stake_rewards_per_token =
+                u128::from(self.accumulated_stake_rewards_per_token)
+                    .wrapping_add(rewards_per_token)
+                    .into();
+            return Ok(());
+        }
+
+        let rewards_received = self.withhold_protocol_fee(lamports_received)?;
+        let rewards = self.stream_rewards(rewards_received, timestamp)?;
+        self.accrue_stake_rewards(rewards)
+    }
+
+    /// Updates the reward stream with the lamports received since the last
+    /// sync, returning the amount of lamports released as rewards at the given
+    /// timestamp.
+    ///
+    /// Lamports received while streaming is enabled are added to the remaining
+    /// lamports of the current stream, which is restarted to end after a full
+    /// streaming period. The fraction of a lamport left over from the release
+    /// is carried over to the next release.
+    pub fn stream_rewards(
+        &mut self,
+        lamports_received: u64,
+        timestamp: u64,
+    ) -> Result<u64, ProgramError> {
+        // Release the lamports streamed since the last update.
+        let rate = u128::from(self.reward_rate);
+        let elapsed = std::cmp::min(timestamp, self.reward_period_end)
+            .saturating_sub(self.reward_last_update);
+        let streamed = rate
+            .checked_mul(elapsed as u128)
+            .and_then(|streamed| streamed.checked_add(self.reward_stream_remainder as u128))
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+        let mut released = u64::try_from(streamed / REWARDS_PER_TOKEN_SCALING_FACTOR)
+            .map_err(|_error| ProgramError::ArithmeticOverflow)?;
+        // NB: The remainder is smaller than the scaling factor, so it fits in
+        // a `u64`.
+        self.reward_stream_remainder = (streamed % REWARDS_PER_TOKEN_SCALING_FACTOR) as u64;
+        self.reward_last_update = std::cmp::max(self.reward_last_update, timestamp);
+
+        if lamports_received > 0 {
+            if self.reward_streaming_period_seconds == 0 {
+                released = released
+                    .checked_add(lamports_received)
+                    .ok_or(ProgramError::ArithmeticOverflow)?;
+            } else {
+                let remaining = rate
+                    .checked_mul(self.reward_period_end.saturating_sub(timestamp) as u128)
+                    .ok_or(ProgramError::ArithmeticOverflow)?;
+                self.reward_rate = (lamports_received as u128)
+                    .checked_mul(REWARDS_PER_TOKEN_SCALING_FACTOR)
+                    .and_then(|received| received.checked_add(remaining))
+                    .map(|total| total / self.reward_streaming_period_seconds as u128)
+                    .ok_or(ProgramError::ArithmeticOverflow)?
+                    .into();
+                self.reward_period_end = timestamp
+                    .checked_add(self.reward_streaming_period_seconds)
+                    .ok_or(ProgramError::ArithmeticOverflow)?;
+            }
+        }
+
+        Ok(released)
+    }
+
+    /// Distributes the given stake rewards over the effective tokens, updating
+    /// the accumulated stake rewards per token.
+    ///
+    /// The part of the rewards lost to the truncation of the rewards per token
+    /// is carried over to the next accrual, while rewards received when no
+    /// tokens are effective are set aside as undistributed.
+    pub fn accrue_stake_rewards(&mut self, rewards: u64) -> Result<(), ProgramError> {
+        if self.token_amount_effective == 0 {
+            self.undistributed_rewards = self
+                .undistributed_rewards
+                .checked_add(rewards)
+                .ok_or(ProgramError::ArithmeticOverflow)?;
+            return Ok(());
+        }
+
+        let token_amount_effective = self.token_amount_effective as u128;
+        let scaled_rewards = (rewards as u128)
+            .checked_mul(REWARDS_PER_TOKEN_SCALING_FACTOR)
+            .and_then(|scaled| scaled.checked_add(self.stake_rewards_remainder as u128))
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+
+        let rewards_per_token = scaled_rewards / token_amount_effective;
+        // NB: The remainder is smaller than the effective amount, so it fits
+        // in a `u64`.
+        self.stake_rewards_remainder = (scaled_rewards % token_amount_effective) as u64;
+        self.accumulated_stake_rewards_per_token =
+            u128::from(self.accumulated_stake_rewards_per_token)
+                .wrapping_add(rewards_per_token)
+                .into();
+
+        Ok(())
+    }
+
+    /// Portion of the given reward withheld as protocol fee.
+    pub fn protocol_fee(&self, reward: u64) -> Result<u64, ProgramError> {
+        (reward as u128)
+            .checked_mul(self.protocol_fee_basis_points as u128)
+            .map(|product| product / MAX_BASIS_POINTS)
+            .and_then(|fee| u64::try_from(fee).ok())
+            .ok_or(ProgramError::ArithmeticOverflow)
+    }
+
+    /// Withholds the protocol fee from the given reward, returning the amount
+    /// left for the delegators.
+    ///
+    /// The fee is added to the unclaimed protocol fees of the config.
+    pub fn withhold_protocol_fee(&mut self, reward: u64) -> Result<u64, ProgramError> {
+        let fee = self.protocol_fee(reward)?;
+        self.unclaimed_protocol_fees = self
+            .unclaimed_protocol_fees
+            .checked_add(fee)
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+
+        reward
+            .checked_sub(fee)
+            .ok_or(ProgramError::ArithmeticOverflow)
+    }
 }
 
 impl IsInitialized for Config {
@@ -79,3 +281,193 @@ impl IsInitialized for Config {
         self.is_initialized()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::calculate_eligible_rewards;
+
+    /// Minimal xorshift generator, so the property tests are deterministic.
+    struct Rng(u64);
+
+    impl Rng {
+        fn next(&mut self, bound: u64) -> u64 {
+            self.0 ^= self.0 << 13;
+            self.0 ^= self.0 >> 7;
+            self.0 ^= self.0 << 17;
+            self.0 % bound
+        }
+    }
+
+    /// Effective amount and last seen rewards per token of a delegation.
+    struct Staker {
+        effective_amount: u64,
+        last_seen: u128,
+    }
+
+    fn harvest(config: &Config, staker: &mut Staker) -> u64 {
+        let accumulated = u128::from(config.accumulated_stake_rewards_per_token);
+        let reward =
+            calculate_eligible_rewards(accumulated, staker.last_seen, staker.effective_amount)
+                .unwrap();
+        staker.last_seen = accumulated;
+        reward
+    }
+
+    #[test]
+    fn remainder_rolls_into_next_accrual() {
+        let mut config = Config {
+            token_amount_effective: 3,
+            ..Default::default()
+        };
+
+        // 1 lamport over 3 tokens truncates the rewards per token.
+        config.accrue_stake_rewards(1).unwrap();
+        config.accrue_stake_rewards(1).unwrap();
+        config.accrue_stake_rewards(1).unwrap();
+
+        // The remainders add up to a whole lamport per token.
+        assert_eq!(
+            u128::from(config.accumulated_stake_rewards_per_token),
+            REWARDS_PER_TOKEN_SCALING_FACTOR
+        );
+        assert_eq!(config.stake_rewards_remainder, 0);
+    }
+
+    #[test]
+    fn stream_remainder_rolls_into_next_release() {
+        let mut config = Config {
+            reward_streaming_period_seconds: 2,
+            ..Default::default()
+        };
+
+        // 1 lamport streamed over 2 seconds releases half a lamport per second.
+        assert_eq!(config.stream_rewards(1, 0).unwrap(), 0);
+        assert_eq!(config.stream_rewards(0, 1).unwrap(), 0);
+        assert_eq!(
+            config.reward_stream_remainder as u128,
+            REWARDS_PER_TOKEN_SCALING_FACTOR / 2
+        );
+
+        // The halves add up to the whole lamport by the end of the stream.
+        assert_eq!(config.stream_rewards(0, 2).unwrap(), 1);
+        assert_eq!(config.reward_stream_remainder, 0);
+    }
+
+    #[test]
+    fn rewards_without_effective_tokens_are_undistributed() {
+        let mut config = Config::default();
+
+        config.accrue_stake_rewards(100).unwrap();
+
+        assert_eq!(config.undistributed_rewards, 100);
+        assert_eq!(u128::from(config.accumulated_stake_rewards_per_token), 0);
+    }
+
+    #[test]
+    fn protocol_fee_is_withheld_from_rewards() {
+        let mut config = Config {
+            protocol_fee_basis_points: 250,
+            ..Default::default()
+        };
+
+        // 2.5% of 1,000 lamports, rounded down for the fee.
+        assert_eq!(config.withhold_protocol_fee(1_000).unwrap(), 975);
+        assert_eq!(config.withhold_protocol_fee(39).unwrap(), 39);
+        assert_eq!(config.unclaimed_protocol_fees, 25);
+
+        // The whole reward is withheld at 100%.
+        config.protocol_fee_basis_points = MAX_BASIS_POINTS as u16;
+        assert_eq!(config.withhold_protocol_fee(100).unwrap(), 0);
+        assert_eq!(config.unclaimed_protocol_fees, 125);
+    }
+
+    #[test]
+    fn legacy_config_releases_rewards_without_fee_or_stream() {
+        let mut config = Config {
+            token_amount_effective: 4,
+            reward_streaming_period_seconds: 100,
+            protocol_fee_basis_points: 1_000,
+            ..Default::default()
+        };
+
+        // The fields of the current layout are ignored on a legacy config.
+        config.receive_stake_rewards(1_000, 10).unwrap();
+
+        assert_eq!(
+            u128::from(config.accumulated_stake_rewards_per_token),
+            250 * REWARDS_PER_TOKEN_SCALING_FACTOR
+        );
+        assert_eq!(config.unclaimed_protocol_fees, 0);
+        assert_eq!(config.reward_last_update, 0);
+
+        // Once migrated, the fee is withheld and the rewards are streamed.
+        config.version = Config::VERSION;
+        config.receive_stake_rewards(1_000, 10).unwrap();
+
+        assert_eq!(config.unclaimed_protocol_fees, 100);
+        assert_eq!(config.reward_last_update, 10);
+    }
+
+    #[test]
+    fn paid_rewards_never_exceed_deposits() {
+        for seed in 1..=64 {
+            let mut rng = Rng(seed * 0x9e37_79b9_7f4a_7c15);
+            let mut config = Config::default();
+            let mut stakers: Vec<Staker> = (0..4)
+                .map(|_| Staker {
+                    effective_amount: 0,
+                    last_seen: 0,
+                })
+                .collect();
+
+            let mut deposited = 0u64;
+            let mut paid = 0u64;
+            let mut harvests = 0u64;
+
+            for _ in 0..500 {
+                match rng.next(3) {
+                    // Rewards are received.
+                    0 => {
+                        let rewards = rng.next(1_000_000);
+                        deposited += rewards;
+                        config.accrue_stake_rewards(rewards).unwrap();
+                    }
+                    // A staker harvests.
+                    1 => {
+                        let staker = &mut stakers[rng.next(4) as usize];
+                        paid += harvest(&config, staker);
+                        harvests += 1;
+                    }
+                    // A staker changes its effective amount (after harvesting).
+                    _ => {
+                        let staker = &mut stakers[rng.next(4) as usize];
+                        paid += harvest(&config, staker);
+                        harvests += 1;
+
+                        let effective_amount = match rng.next(4) {
+                            0 => 0,
+                            1 => rng.next(10),
+                            _ => rng.next(1_000_000_000_000_000),
+                        };
+                        config.token_amount_effective = config.token_amount_effective
+                            - staker.effective_amount
+                            + effective_amount;
+                        staker.effective_amount = effective_amount;
+                    }
+                }
+
+                assert!(paid + config.undistributed_rewards <= deposited);
+            }
+
+            // Once every staker harvests, each harvest leaked less than a
+            // lamport, plus less than a lamport of carried over remainder.
+            for staker in stakers.iter_mut() {
+                paid += harvest(&config, staker);
+                harvests += 1;
+            }
+            let leaked = deposited - paid - config.undistributed_rewards;
+            assert!(leaked <= harvests + 1, "seed {seed}: leaked {leaked}");
+        }
+    }
+}
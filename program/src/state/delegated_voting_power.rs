@@ -0,0 +1,62 @@
+use bytemuck::{Pod, Zeroable};
+use shank::ShankAccount;
+use solana_program::{program_pack::IsInitialized, pubkey::Pubkey};
+use spl_discriminator::SplDiscriminate;
+
+use super::VotingPowerCheckpoints;
+
+/// Aggregated voting power of a vote delegate.
+///
+/// Holds the sum of the effective amount of every stake account (of a config)
+/// that delegated its voting power to the delegate, so governance programs can
+/// read a single account per voter.
+#[repr(C)]
+#[derive(Clone, Copy, Default, Pod, ShankAccount, SplDiscriminate, Zeroable)]
+#[discriminator_hash_input("delegated_voting_power")]
+pub struct DelegatedVotingPower {
+    /// Account discriminator.
+    ///
+    /// The discriminator is equal to `ArrayDiscriminator::UNINITIALIZED` when
+    /// the account is empty, and equal to `DelegatedVotingPower::DISCRIMINATOR`
+    /// when the account is initialized.
+    pub _discriminator: [u8; 8],
+
+    /// The config account of the stake accounts delegating to the delegate.
+    pub config: Pubkey,
+
+    /// The vote delegate.
+    pub delegate: Pubkey,
+
+    /// Total effective amount delegated to the vote delegate.
+    pub voting_power: u64,
+
+    /// Layout version of the account.
+    pub version: u8,
+
+    /// Padding for alignment.
+    pub _padding: [u8; 7],
+
+    /// History of the voting power, used to determine the voting power at a
+    /// given slot.
+    pub checkpoints: VotingPowerCheckpoints,
+}
+
+impl DelegatedVotingPower {
+    pub const LEN: usize = std::mem::size_of::<DelegatedVotingPower>();
+
+    /// Current layout version.
+    pub const VERSION: u8 = 1;
+
+    /// Checks whether the discriminator has been set and it is equal to
+    /// `DelegatedVotingPower::SPL_DISCRIMINATOR_SLICE` or not.
+    #[inline(always)]
+    pub fn is_initialized(&self) -> bool {
+        self._discriminator.as_slice() == DelegatedVotingPower::SPL_DISCRIMINATOR_SLICE
+    }
+}
+
+impl IsInitialized for DelegatedVotingPower {
+    fn is_initialized(&self) -> bool {
+        self.is_initialized()
+    }
+}
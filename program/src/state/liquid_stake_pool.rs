@@ -0,0 +1,331 @@
+use bytemuck::{Pod, Zeroable};
+use shank::ShankAccount;
+use solana_program::{program_error::ProgramError, program_pack::IsInitialized, pubkey::Pubkey};
+use spl_discriminator::SplDiscriminate;
+use spl_pod::primitives::PodU128;
+
+use super::{ReceiptRewards, REWARDS_PER_TOKEN_SCALING_FACTOR};
+
+/// Pooled validator stake backing a liquid receipt token.
+///
+/// The pool PDA is the delegation authority of its validator stake account,
+/// so every token staked through the pool mints the same amount of receipt
+/// tokens, and the SOL stake and holder rewards harvested for the stake are
+/// paid to the pool and distributed to the receipt token holders with a
+/// `ReceiptRewards` account.
+#[repr(C)]
+#[derive(Clone, Copy, Default, Pod, ShankAccount, SplDiscriminate, Zeroable)]
+#[discriminator_hash_input("liquid_stake_pool")]
+pub struct LiquidStakePool {
+    /// Account discriminator.
+    ///
+    /// The discriminator is equal to `ArrayDiscriminator::UNINITIALIZED` when
+    /// the account is empty, and equal to `LiquidStakePool::DISCRIMINATOR`
+    /// when the account is initialized.
+    pub _discriminator: [u8; 8],
+
+    /// The config account of the validator stake.
+    pub config: Pubkey,
+
+    /// The pooled validator stake account.
+    pub validator_stake: Pubkey,
+
+    /// Mint of the receipt token.
+    pub receipt_mint: Pubkey,
+
+    /// Rewards (in lamports) earned per receipt token, scaled by 1e18.
+    pub accumulated_rewards_per_token: PodU128,
+
+    /// Scaled rewards that were not yet added to the accumulator because of
+    /// the rounding of the division by the receipt token supply.
+    pub rewards_remainder: u64,
+
+    /// Lamports balance of the pool already accounted for.
+    pub lamports_last: u64,
+
+    /// Receipt tokens earning rewards on the `ReceiptRewards` accounts of the
+    /// pool, across which rewards are distributed.
+    ///
+    /// Activating receipt tokens are not included (see `ReceiptRewards`).
+    pub tracked_supply: u64,
+
+    /// Layout version of the account.
+    pub version: u8,
+
+    /// Padding for alignment.
+    pub _padding: [u8; 7],
+}
+
+impl LiquidStakePool {
+    pub const LEN: usize = std::mem::size_of::<LiquidStakePool>();
+
+    /// Current layout version.
+    pub const VERSION: u8 = 1;
+
+    /// Checks whether the discriminator has been set and it is equal to
+    /// `LiquidStakePool::SPL_DISCRIMINATOR_SLICE` or not.
+    #[inline(always)]
+    pub fn is_initialized(&self) -> bool {
+        self._discriminator.as_slice() == LiquidStakePool::SPL_DISCRIMINATOR_SLICE
+    }
+
+    /// Distributes the given rewards across the receipt token supply.
+    ///
+    /// The rounding remainder is carried over to the next distribution, so no
+    /// lamports are lost to truncation.
+    pub fn accrue_rewards(
+        &mut self,
+        rewards: u64,
+        receipt_supply: u64,
+    ) -> Result<(), ProgramError> {
+        if receipt_supply == 0 {
+            return Ok(());
+        }
+
+        let receipt_supply = receipt_supply as u128;
+        let scaled_rewards = (rewards as u128)
+            .checked_mul(REWARDS_PER_TOKEN_SCALING_FACTOR)
+            .and_then(|scaled| scaled.checked_add(self.rewards_remainder as u128))
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+
+        // NB: The remainder is smaller than the supply, so it fits in a `u64`.
+        self.rewards_remainder = (scaled_rewards % receipt_supply) as u64;
+        self.accumulated_rewards_per_token = u128::from(self.accumulated_rewards_per_token)
+            .wrapping_add(scaled_rewards / receipt_supply)
+            .into();
+
+        Ok(())
+    }
+
+    /// Receipt tokens minted for the given amount of staked tokens.
+    ///
+    /// Receipt tokens are minted 1:1 while the pool holds the same amount of
+    /// staked tokens as receipt tokens. The conversion counts one virtual
+    /// staked token and receipt token, so inflating the staked amount of the
+    /// pool never rounds the receipt tokens of later depositors down to zero
+    /// in favour of the existing holders.
+    pub fn receipt_amount(
+        amount: u64,
+        receipt_supply: u64,
+        staked_amount: u64,
+    ) -> Result<u64, ProgramError> {
+        if receipt_supply == 0 {
+            return Ok(amount);
+        }
+
+        convert(amount, receipt_supply, staked_amount)
+    }
+
+    /// Staked tokens backing the given amount of receipt tokens.
+    ///
+    /// This is the inverse of `receipt_amount`, rounded down in favour of the
+    /// pool.
+    pub fn token_amount(
+        receipt_amount: u64,
+        receipt_supply: u64,
+        staked_amount: u64,
+    ) -> Result<u64, ProgramError> {
+        convert(receipt_amount, staked_amount, receipt_supply)
+    }
+
+    /// Settles the rewards of a receipt token account and records its current
+    /// balance at the given epoch.
+    ///
+    /// Rewards forfeited by the account (earned on tokens that left it since
+    /// the last settlement) are credited back to the pool, so they are
+    /// distributed again on the next sync.
+    pub fn settle(
+        &mut self,
+        receipt_rewards: &mut ReceiptRewards,
+        balance: u64,
+        epoch: u64,
+    ) -> Result<(), ProgramError> {
+        let forfeited_rewards =
+            receipt_rewards.settle(self.accumulated_rewards_per_token.into(), balance)?;
+        self.lamports_last = self
+            .lamports_last
+            .checked_sub(forfeited_rewards)
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+
+        self.record_balance(receipt_rewards, balance, epoch)
+    }
+
+    /// Records the balance of a receipt token account at the given epoch,
+    /// updating the tracked supply.
+    ///
+    /// Tokens activated since the last record start earning rewards, while
+    /// tokens added to the balance are activating until the end of the epoch.
+    /// Activating tokens are the first to be removed when the balance
+    /// decreases.
+    pub fn record_balance(
+        &mut self,
+        receipt_rewards: &mut ReceiptRewards,
+        balance: u64,
+        epoch: u64,
+    ) -> Result<(), ProgramError> {
+        let activating = receipt_rewards.activating_at(epoch);
+        let earning = receipt_rewards
+            .balance
+            .checked_add(
+                receipt_rewards
+                    .activating_balance
+                    .checked_sub(activating)
+                    .ok_or(ProgramError::ArithmeticOverflow)?,
+            )
+            .ok_or(ProgramError::ArithmeticOverflow)?
+            .min(balance);
+
+        self.tracked_supply = self
+            .tracked_supply
+            .checked_sub(receipt_rewards.balance)
+            .and_then(|supply| supply.checked_add(earning))
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+        receipt_rewards.balance = earning;
+
+        let new_activating = balance
+            .checked_sub(earning)
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+        if new_activating > activating {
+            receipt_rewards.activation_epoch = epoch;
+        }
+        receipt_rewards.activating_balance = new_activating;
+
+        Ok(())
+    }
+}
+
+/// Converts `amount` with the ratio `(numerator + 1) / (denominator + 1)`,
+/// rounding down.
+fn convert(amount: u64, numerator: u64, denominator: u64) -> Result<u64, ProgramError> {
+    (amount as u128)
+        .checked_mul((numerator as u128).saturating_add(1))
+        .and_then(|product| product.checked_div((denominator as u128).saturating_add(1)))
+        .and_then(|amount| u64::try_from(amount).ok())
+        .ok_or(ProgramError::ArithmeticOverflow)
+}
+
+impl IsInitialized for LiquidStakePool {
+    fn is_initialized(&self) -> bool {
+        self.is_initialized()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn accrue_rewards_carries_the_remainder() {
+        let mut pool = LiquidStakePool::default();
+
+        // 1 lamport over 3 tokens leaves a remainder of 1.
+        pool.accrue_rewards(1, 3).unwrap();
+        assert_eq!(
+            u128::from(pool.accumulated_rewards_per_token),
+            333_333_333_333_333_333
+        );
+        assert_eq!(pool.rewards_remainder, 1);
+
+        // The remainder completes the next distribution.
+        pool.accrue_rewards(2, 3).unwrap();
+        assert_eq!(
+            u128::from(pool.accumulated_rewards_per_token),
+            1_000_000_000_000_000_000
+        );
+        assert_eq!(pool.rewards_remainder, 0);
+    }
+
+    #[test]
+    fn accrue_rewards_without_supply_is_deferred() {
+        let mut pool = LiquidStakePool::default();
+
+        pool.accrue_rewards(100, 0).unwrap();
+        assert_eq!(u128::from(pool.accumulated_rewards_per_token), 0);
+    }
+
+    #[test]
+    fn receipt_amount_is_one_to_one_until_slashed() {
+        assert_eq!(LiquidStakePool::receipt_amount(100, 0, 0).unwrap(), 100);
+        assert_eq!(LiquidStakePool::receipt_amount(100, 50, 50).unwrap(), 100);
+        assert_eq!(LiquidStakePool::token_amount(100, 150, 150).unwrap(), 100);
+
+        // Half of the pooled stake was slashed.
+        assert_eq!(LiquidStakePool::receipt_amount(50, 99, 49).unwrap(), 100);
+        assert_eq!(LiquidStakePool::token_amount(100, 199, 99).unwrap(), 50);
+    }
+
+    #[test]
+    fn receipt_amount_of_inflated_pool_is_not_rounded_to_zero() {
+        // 1 receipt token backed by 1_000_001 staked tokens.
+        let receipt_amount = LiquidStakePool::receipt_amount(1_000_000, 1, 1_000_001).unwrap();
+        assert_eq!(receipt_amount, 1);
+
+        // The depositor can redeem the receipt token for more than half of the
+        // deposit, so the inflation does not profit the first holder.
+        let token_amount = LiquidStakePool::token_amount(receipt_amount, 2, 2_000_001).unwrap();
+        assert!(token_amount > 500_000);
+    }
+
+    #[test]
+    fn record_balance_activates_tokens_on_the_next_epoch() {
+        let mut pool = LiquidStakePool::default();
+        let mut receipt_rewards = ReceiptRewards::default();
+
+        // Received tokens are activating until the end of the epoch.
+        pool.record_balance(&mut receipt_rewards, 100, 5).unwrap();
+        pool.record_balance(&mut receipt_rewards, 150, 5).unwrap();
+        assert_eq!(receipt_rewards.balance, 0);
+        assert_eq!(receipt_rewards.activating_balance, 150);
+        assert_eq!(pool.tracked_supply, 0);
+
+        // And earn rewards once recorded on a later epoch.
+        pool.record_balance(&mut receipt_rewards, 150, 6).unwrap();
+        assert_eq!(receipt_rewards.balance, 150);
+        assert_eq!(receipt_rewards.activating_balance, 0);
+        assert_eq!(pool.tracked_supply, 150);
+
+        // Activating tokens are the first to be removed.
+        pool.record_balance(&mut receipt_rewards, 160, 7).unwrap();
+        pool.record_balance(&mut receipt_rewards, 155, 7).unwrap();
+        assert_eq!(receipt_rewards.balance, 150);
+        assert_eq!(receipt_rewards.activating_balance, 5);
+        assert_eq!(receipt_rewards.activation_epoch, 7);
+
+        pool.record_balance(&mut receipt_rewards, 120, 7).unwrap();
+        assert_eq!(receipt_rewards.balance, 120);
+        assert_eq!(receipt_rewards.activating_balance, 0);
+        assert_eq!(pool.tracked_supply, 120);
+    }
+
+    #[test]
+    fn settle_credits_back_forfeited_rewards() {
+        let mut pool = LiquidStakePool {
+            lamports_last: 1_000,
+            ..Default::default()
+        };
+        let mut sender = ReceiptRewards::default();
+        let mut receiver = ReceiptRewards::default();
+        pool.record_balance(&mut sender, 100, 0).unwrap();
+        pool.record_balance(&mut receiver, 0, 0).unwrap();
+        pool.settle(&mut sender, 100, 1).unwrap();
+        assert_eq!(pool.tracked_supply, 100);
+
+        // 200 lamports distributed while the sender held all the tokens.
+        pool.accrue_rewards(200, pool.tracked_supply).unwrap();
+
+        // The sender moved 50 tokens to the receiver, so it only earns on the
+        // 50 tokens it kept and the rest is credited back to the pool.
+        pool.settle(&mut sender, 50, 1).unwrap();
+        assert_eq!(sender.unclaimed_rewards, 100);
+        assert_eq!(pool.lamports_last, 900);
+
+        // The receiver did not hold the tokens, so it does not earn on them,
+        // and they are activating until the end of the epoch.
+        pool.settle(&mut receiver, 50, 1).unwrap();
+        assert_eq!(receiver.unclaimed_rewards, 0);
+        assert_eq!(receiver.activating_balance, 50);
+        assert_eq!(pool.lamports_last, 900);
+
+        assert_eq!(pool.tracked_supply, 50);
+    }
+}
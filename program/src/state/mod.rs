@@ -1,9 +1,17 @@
+pub mod checkpoints;
 pub mod config;
+pub mod delegated_voting_power;
+pub mod liquid_stake_pool;
+pub mod receipt_rewards;
 pub mod sol_staker_stake;
 pub mod validator_stake;
 
+pub use checkpoints::*;
 pub use config::*;
+pub use delegated_voting_power::*;
+pub use liquid_stake_pool::*;
 use paladin_sol_stake_view_program_client::GetStakeActivatingAndDeactivatingReturnData;
+pub use receipt_rewards::*;
 pub use sol_staker_stake::*;
 use spl_pod::primitives::PodU128;
 pub use validator_stake::*;
@@ -22,6 +30,9 @@ const REWARDS_PER_TOKEN_SCALING_FACTOR: u128 = 1_000_000_000_000_000_000;
 /// Defined the maximum value for basis points (100%).
 pub const MAX_BASIS_POINTS: u128 = 10_000;
 
+/// Maximum protocol fee, as basis points (10%).
+pub const MAX_PROTOCOL_FEE_BASIS_POINTS: u16 = 1_000;
+
 /// Stake factor for the maximum amount of staked tokens as a percentage of the total
 /// SOL staked (STAKE_FACTOR / STAKE_SCALING_FACTOR).
 pub const STAKE_FACTOR: u128 = 13;
@@ -50,13 +61,37 @@ pub fn find_vault_pda(config: &Pubkey, program_id: &Pubkey) -> (Pubkey, u8) {
 }
 
 #[inline(always)]
-pub fn find_config_pda(program_id: &Pubkey) -> (Pubkey, u8) {
-    Pubkey::find_program_address(&[b"config"], program_id)
+pub fn find_config_pda(
+    mint: &Pubkey,
+    config_authority: &Pubkey,
+    namespace: u64,
+    program_id: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            b"config",
+            mint.as_ref(),
+            config_authority.as_ref(),
+            &namespace.to_le_bytes(),
+        ],
+        program_id,
+    )
 }
 
 #[inline(always)]
-pub fn get_config_pda_signer_seeds(bump_seed: &[u8]) -> [&[u8]; 2] {
-    [b"config", bump_seed]
+pub fn get_config_pda_signer_seeds<'a>(
+    mint: &'a Pubkey,
+    config_authority: &'a Pubkey,
+    namespace: &'a [u8],
+    bump_seed: &'a [u8],
+) -> [&'a [u8]; 5] {
+    [
+        b"config",
+        mint.as_ref(),
+        config_authority.as_ref(),
+        namespace,
+        bump_seed,
+    ]
 }
 
 #[inline(always)]
@@ -105,6 +140,36 @@ pub fn get_sol_staker_authority_override_pda_signer_seeds<'a>(
     ]
 }
 
+#[inline(always)]
+pub fn find_validator_authority_override_pda(
+    validator_vote: &Pubkey,
+    config: &Pubkey,
+    program_id: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            "validator_authority_override".as_bytes(),
+            validator_vote.as_ref(),
+            config.as_ref(),
+        ],
+        program_id,
+    )
+}
+
+#[inline(always)]
+pub fn get_validator_authority_override_pda_signer_seeds<'a>(
+    validator_vote: &'a Pubkey,
+    config: &'a Pubkey,
+    bump_seed: &'a [u8],
+) -> [&'a [u8]; 4] {
+    [
+        "validator_authority_override".as_bytes(),
+        validator_vote.as_ref(),
+        config.as_ref(),
+        bump_seed,
+    ]
+}
+
 #[inline(always)]
 pub fn find_validator_stake_pda(
     validator_vote: &Pubkey,
@@ -121,6 +186,86 @@ pub fn find_validator_stake_pda(
     )
 }
 
+#[inline(always)]
+pub fn find_delegated_voting_power_pda(
+    delegate: &Pubkey,
+    config: &Pubkey,
+    program_id: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            "delegated_voting_power".as_bytes(),
+            delegate.as_ref(),
+            config.as_ref(),
+        ],
+        program_id,
+    )
+}
+
+#[inline(always)]
+pub fn get_delegated_voting_power_pda_signer_seeds<'a>(
+    delegate: &'a Pubkey,
+    config: &'a Pubkey,
+    bump_seed: &'a [u8],
+) -> [&'a [u8]; 4] {
+    [
+        "delegated_voting_power".as_bytes(),
+        delegate.as_ref(),
+        config.as_ref(),
+        bump_seed,
+    ]
+}
+
+#[inline(always)]
+pub fn find_liquid_stake_pool_pda(validator_stake: &Pubkey, program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &["liquid_stake_pool".as_bytes(), validator_stake.as_ref()],
+        program_id,
+    )
+}
+
+#[inline(always)]
+pub fn get_liquid_stake_pool_pda_signer_seeds<'a>(
+    validator_stake: &'a Pubkey,
+    bump_seed: &'a [u8],
+) -> [&'a [u8]; 3] {
+    [
+        "liquid_stake_pool".as_bytes(),
+        validator_stake.as_ref(),
+        bump_seed,
+    ]
+}
+
+#[inline(always)]
+pub fn find_receipt_rewards_pda(
+    token_account: &Pubkey,
+    liquid_stake_pool: &Pubkey,
+    program_id: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            "receipt_rewards".as_bytes(),
+            token_account.as_ref(),
+            liquid_stake_pool.as_ref(),
+        ],
+        program_id,
+    )
+}
+
+#[inline(always)]
+pub fn get_receipt_rewards_pda_signer_seeds<'a>(
+    token_account: &'a Pubkey,
+    liquid_stake_pool: &'a Pubkey,
+    bump_seed: &'a [u8],
+) -> [&'a [u8]; 4] {
+    [
+        "receipt_rewards".as_bytes(),
+        token_account.as_ref(),
+        liquid_stake_pool.as_ref(),
+        bump_seed,
+    ]
+}
+
 #[inline(always)]
 pub fn get_vault_pda_signer_seeds<'a>(config: &'a Pubkey, bump_seed: &'a [u8]) -> [&'a [u8]; 3] {
     ["token-owner".as_bytes(), config.as_ref(), bump_seed]
@@ -251,6 +396,58 @@ pub struct Delegation {
     pub last_seen_stake_rewards_per_token: PodU128,
 }
 
+impl Delegation {
+    /// Adds newly staked tokens to the staked amount.
+    ///
+    /// The tokens are activating until the end of the given epoch, so they only
+    /// count towards the effective amount from the next epoch.
+    pub fn add_activating_stake(
+        &mut self,
+        activation: &mut Activation,
+        amount: u64,
+        epoch: u64,
+    ) -> Result<(), ProgramError> {
+        self.staked_amount = self
+            .staked_amount
+            .checked_add(amount)
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+        activation.activating_amount = self
+            .activating_at(activation, epoch)
+            .checked_add(amount)
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+        activation.activation_epoch = epoch;
+
+        Ok(())
+    }
+
+    /// Amount of staked tokens still activating at the given epoch.
+    ///
+    /// Activating tokens are the last to be removed when the staked amount
+    /// decreases, so the amount is capped by the staked amount.
+    pub fn activating_at(&self, activation: &Activation, epoch: u64) -> u64 {
+        if epoch > activation.activation_epoch {
+            0
+        } else {
+            std::cmp::min(activation.activating_amount, self.staked_amount)
+        }
+    }
+}
+
+/// Staked tokens of a delegation that do not count towards its effective
+/// amount yet.
+///
+/// Stored at the end of `SolStakerStake` and `ValidatorStake` accounts.
+#[repr(C)]
+#[derive(Clone, Copy, Default, Pod, ShankType, Zeroable)]
+pub struct Activation {
+    /// Amount of staked tokens activating, which only count towards the
+    /// effective amount from the epoch after `activation_epoch`.
+    pub activating_amount: u64,
+
+    /// Epoch of the latest increase of the `activating_amount`.
+    pub activation_epoch: u64,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -355,6 +552,32 @@ mod tests {
         .unwrap();
     }
 
+    #[test]
+    fn activating_stake_warms_up_after_the_epoch() {
+        let mut delegation = Delegation::default();
+        let mut activation = Activation::default();
+
+        delegation
+            .add_activating_stake(&mut activation, 100, 10)
+            .unwrap();
+        delegation
+            .add_activating_stake(&mut activation, 50, 10)
+            .unwrap();
+        assert_eq!(delegation.activating_at(&activation, 10), 150);
+        assert_eq!(delegation.activating_at(&activation, 11), 0);
+
+        // Stake added on a later epoch does not include the activated tokens.
+        delegation
+            .add_activating_stake(&mut activation, 25, 11)
+            .unwrap();
+        assert_eq!(delegation.staked_amount, 175);
+        assert_eq!(delegation.activating_at(&activation, 11), 25);
+
+        // Activating tokens are capped by the staked amount.
+        delegation.staked_amount = 10;
+        assert_eq!(delegation.activating_at(&activation, 11), 10);
+    }
+
     #[test]
     fn wrapping_eligible_rewards() {
         // Set up current to be less than rate, simulating a scenario where the
@@ -0,0 +1,154 @@
+use bytemuck::{Pod, Zeroable};
+use shank::ShankAccount;
+use solana_program::{program_error::ProgramError, program_pack::IsInitialized, pubkey::Pubkey};
+use spl_discriminator::SplDiscriminate;
+use spl_pod::primitives::PodU128;
+
+use super::calculate_eligible_rewards;
+
+/// Rewards tracking of a receipt token account of a `LiquidStakePool`.
+///
+/// Rewards are only earned on the balance recorded at the last settlement (or
+/// the current balance, if lower), so receipt tokens moved between accounts
+/// never earn rewards twice for the same period. The rewards forfeited on the
+/// tokens moved out are credited back to the pool.
+///
+/// Receipt tokens received by the account (minted or transferred) are
+/// activating until the end of the epoch they were recorded in, mirroring the
+/// warmup of the pooled stake, and only start earning rewards when the account
+/// is settled on a later epoch.
+#[repr(C)]
+#[derive(Clone, Copy, Default, Pod, ShankAccount, SplDiscriminate, Zeroable)]
+#[discriminator_hash_input("receipt_rewards")]
+pub struct ReceiptRewards {
+    /// Account discriminator.
+    ///
+    /// The discriminator is equal to `ArrayDiscriminator::UNINITIALIZED` when
+    /// the account is empty, and equal to `ReceiptRewards::DISCRIMINATOR`
+    /// when the account is initialized.
+    pub _discriminator: [u8; 8],
+
+    /// The liquid stake pool of the receipt token.
+    pub liquid_stake_pool: Pubkey,
+
+    /// The receipt token account.
+    pub token_account: Pubkey,
+
+    /// Pool rewards per token at the last settlement.
+    pub last_accumulated_rewards_per_token: PodU128,
+
+    /// Receipt token balance earning rewards at the last settlement.
+    pub balance: u64,
+
+    /// Rewards (in lamports) settled but not yet harvested.
+    pub unclaimed_rewards: u64,
+
+    /// Receipt tokens activating, which only earn rewards once the account is
+    /// settled after `activation_epoch`.
+    pub activating_balance: u64,
+
+    /// Epoch of the latest increase of the `activating_balance`.
+    pub activation_epoch: u64,
+
+    /// Layout version of the account.
+    pub version: u8,
+
+    /// Padding for alignment.
+    pub _padding: [u8; 7],
+}
+
+impl ReceiptRewards {
+    pub const LEN: usize = std::mem::size_of::<ReceiptRewards>();
+
+    /// Current layout version.
+    pub const VERSION: u8 = 1;
+
+    /// Checks whether the discriminator has been set and it is equal to
+    /// `ReceiptRewards::SPL_DISCRIMINATOR_SLICE` or not.
+    #[inline(always)]
+    pub fn is_initialized(&self) -> bool {
+        self._discriminator.as_slice() == ReceiptRewards::SPL_DISCRIMINATOR_SLICE
+    }
+
+    /// Receipt tokens still activating at the given epoch.
+    pub fn activating_at(&self, epoch: u64) -> u64 {
+        if epoch > self.activation_epoch {
+            0
+        } else {
+            self.activating_balance
+        }
+    }
+
+    /// Adds the rewards earned since the last settlement to the unclaimed
+    /// rewards, returning the rewards forfeited on the recorded balance that
+    /// is no longer held by the token account.
+    ///
+    /// NOTE: The current balance is recorded by the pool (see
+    /// `LiquidStakePool::settle`).
+    pub fn settle(
+        &mut self,
+        accumulated_rewards_per_token: u128,
+        balance: u64,
+    ) -> Result<u64, ProgramError> {
+        let last_accumulated_rewards_per_token = self.last_accumulated_rewards_per_token.into();
+        let recorded_rewards = calculate_eligible_rewards(
+            accumulated_rewards_per_token,
+            last_accumulated_rewards_per_token,
+            self.balance,
+        )?;
+        let eligible_rewards = calculate_eligible_rewards(
+            accumulated_rewards_per_token,
+            last_accumulated_rewards_per_token,
+            std::cmp::min(self.balance, balance),
+        )?;
+
+        self.unclaimed_rewards = self
+            .unclaimed_rewards
+            .checked_add(eligible_rewards)
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+        self.last_accumulated_rewards_per_token = accumulated_rewards_per_token.into();
+
+        recorded_rewards
+            .checked_sub(eligible_rewards)
+            .ok_or(ProgramError::ArithmeticOverflow)
+    }
+}
+
+impl IsInitialized for ReceiptRewards {
+    fn is_initialized(&self) -> bool {
+        self.is_initialized()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn settle_uses_the_lowest_balance() {
+        let mut receipt_rewards = ReceiptRewards {
+            balance: 100,
+            ..Default::default()
+        };
+
+        // Tokens received since the last settlement do not earn rewards.
+        let forfeited_rewards = receipt_rewards
+            .settle(2_000_000_000_000_000_000, 150)
+            .unwrap();
+        assert_eq!(receipt_rewards.unclaimed_rewards, 200);
+        assert_eq!(forfeited_rewards, 0);
+        receipt_rewards.balance = 150;
+
+        // Tokens sent since the last settlement do not earn rewards, and the
+        // rewards on them are forfeited.
+        let forfeited_rewards = receipt_rewards
+            .settle(3_000_000_000_000_000_000, 50)
+            .unwrap();
+        assert_eq!(receipt_rewards.unclaimed_rewards, 250);
+        assert_eq!(forfeited_rewards, 100);
+        assert_eq!(
+            u128::from(receipt_rewards.last_accumulated_rewards_per_token),
+            3_000_000_000_000_000_000
+        );
+    }
+}
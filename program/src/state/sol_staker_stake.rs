@@ -2,8 +2,9 @@ use bytemuck::{Pod, Zeroable};
 use shank::ShankAccount;
 use solana_program::{program_pack::IsInitialized, pubkey::Pubkey};
 use spl_discriminator::SplDiscriminate;
+use spl_pod::optional_keys::OptionalNonZeroPubkey;
 
-use super::Delegation;
+use super::{Activation, Delegation, VotingPowerCheckpoints};
 
 /// Data for an amount of tokens staked by a SOL staker.
 ///
@@ -37,11 +38,53 @@ pub struct SolStakerStake {
     /// The `voter_pubkey` on the `StakeState` account must be equal to the `validator_vote`
     /// on the `delegation` struct.
     pub sol_stake: Pubkey,
+
+    /// Layout version of the account.
+    ///
+    /// Accounts created before versioning was introduced do not have this field
+    /// or any of the fields below, which are read as `0`.
+    pub version: u8,
+
+    /// Indicates whether the SOL stake is delegated to a vote account without
+    /// a `ValidatorStake` account (`1`) or not (`0`).
+    ///
+    /// While set, the `validator_vote` on the `delegation` holds the vote account
+    /// of the SOL stake and `lamports_amount` is `0`. The lamports are credited to
+    /// the validator on the first sync after its `ValidatorStake` account is
+    /// initialized.
+    pub awaiting_validator_stake: u8,
+
+    /// Padding for alignment.
+    pub _padding: [u8; 6],
+
+    /// History of the voting power of the account: the effective amount, or
+    /// `0` while it is delegated to a vote delegate.
+    pub checkpoints: VotingPowerCheckpoints,
+
+    /// Optional address voting with the effective amount of the delegation.
+    ///
+    /// The effective amount is aggregated on the `DelegatedVotingPower`
+    /// account of the delegate.
+    pub vote_delegate: OptionalNonZeroPubkey,
+
+    /// Staked tokens not counting towards the effective amount yet.
+    pub activation: Activation,
+
+    /// Effective amount counted on the `DelegatedVotingPower` account of the
+    /// vote delegate.
+    ///
+    /// It lags behind the effective amount when the account of the delegate
+    /// is not provided to a slash or keeper sync, and catches up on the next
+    /// sync with the account.
+    pub delegated_voting_power: u64,
 }
 
 impl SolStakerStake {
     pub const LEN: usize = std::mem::size_of::<SolStakerStake>();
 
+    /// Current layout version.
+    pub const VERSION: u8 = 1;
+
     /// Checks whether the discriminator has been set and it is equal to
     /// `SolStakerStake::SPL_DISCRIMINATOR_SLICE` or not.
     #[inline(always)]
@@ -2,8 +2,9 @@ use bytemuck::{Pod, Zeroable};
 use shank::ShankAccount;
 use solana_program::program_pack::IsInitialized;
 use spl_discriminator::SplDiscriminate;
+use spl_pod::optional_keys::OptionalNonZeroPubkey;
 
-use super::Delegation;
+use super::{Activation, Delegation, VotingPowerCheckpoints};
 
 /// Data for an amount of tokens staked by a validator.
 ///
@@ -36,11 +37,44 @@ pub struct ValidatorStake {
     /// Can be set by governance to artificially increase an accounts stake.
     /// Intended only for use on the dev fund account.
     pub total_staked_lamports_amount_min: u64,
+
+    /// Layout version of the account.
+    ///
+    /// Accounts created before versioning was introduced do not have this field
+    /// or any of the fields below, which are read as `0`.
+    pub version: u8,
+
+    /// Padding for alignment.
+    pub _padding: [u8; 7],
+
+    /// History of the voting power of the account: the effective amount, or
+    /// `0` while it is delegated to a vote delegate.
+    pub checkpoints: VotingPowerCheckpoints,
+
+    /// Optional address voting with the effective amount of the delegation.
+    ///
+    /// The effective amount is aggregated on the `DelegatedVotingPower`
+    /// account of the delegate.
+    pub vote_delegate: OptionalNonZeroPubkey,
+
+    /// Staked tokens not counting towards the effective amount yet.
+    pub activation: Activation,
+
+    /// Effective amount counted on the `DelegatedVotingPower` account of the
+    /// vote delegate.
+    ///
+    /// It lags behind the effective amount when the account of the delegate
+    /// is not provided to a slash or keeper sync, and catches up on the next
+    /// sync with the account.
+    pub delegated_voting_power: u64,
 }
 
 impl ValidatorStake {
     pub const LEN: usize = std::mem::size_of::<ValidatorStake>();
 
+    /// Current layout version.
+    pub const VERSION: u8 = 1;
+
     /// Checks whether the discriminator has been set and it is equal to
     /// `ValidatorStake::SPL_DISCRIMINATOR_SLICE` or not.
     #[inline(always)]
@@ -66,7 +66,12 @@ kinobi.update(
     {
       // OptionalNonZeroPubkey -> NullableAddress
       select: (node) => {
-        const names = ["authority", "slashAuthority"];
+        const names = [
+          "authority",
+          "slashAuthority",
+          "voteDelegate",
+          "feeRecipient",
+        ];
         return (
           names.includes(node.name) &&
           k.isNode(node, "structFieldTypeNode") &&
@@ -212,13 +217,22 @@ kinobi.update(
 kinobi.update(
   k.updateAccountsVisitor({
     config: {
-      size: 192,
+      size: 296,
     },
-    validatorStake: {
+    delegatedVotingPower: {
+      size: 88,
+    },
+    liquidStakePool: {
       size: 144,
     },
+    receiptRewards: {
+      size: 112,
+    },
+    validatorStake: {
+      size: 464,
+    },
     solStakerStake: {
-      size: 168,
+      size: 488,
     },
   })
 );